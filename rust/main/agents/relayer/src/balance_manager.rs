@@ -0,0 +1,385 @@
+//! Guardrails for the balance of the relayer wallet on destination chains.
+//!
+//! The balance manager periodically checks the relayer balance on a destination.
+//! While the balance is below the configured floor, submission to the destination
+//! is paused and operations are sent back to the prepare queue. If a funder key is
+//! configured, the relayer wallet is topped up once its balance drops below the
+//! top up threshold. Top ups are spaced by a minimum interval and the amount
+//! transferred within a window is capped, so the funder can't be drained.
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use eyre::{eyre, Result};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, Instant};
+use tracing::{error, info, info_span, warn, Instrument};
+
+use hyperlane_base::settings::{
+    BuildableWithSignerConf, ChainConf, ChainConnectionConf, ChainSigner, SignerConf,
+};
+use hyperlane_base::CoreMetrics;
+use hyperlane_core::{
+    ChainResult, ContractLocator, HyperlaneDomain, HyperlaneProvider, H256, H512, U256,
+};
+use hyperlane_ethereum::{NativeTransfer, NativeTransferBuilder, Signers};
+
+use crate::settings::{BalanceGuardrailConf, TopUpConf};
+
+/// Shared between the balance manager and the submit task of a destination.
+/// Tells the submit task whether submission to the destination is paused.
+#[derive(Debug, Clone, Default)]
+pub struct BalanceGuard {
+    paused: Arc<AtomicBool>,
+    retry_after: Duration,
+}
+
+impl BalanceGuard {
+    /// Create a guard which is not paused. Operations held back while the guard
+    /// is paused are retried after `retry_after`.
+    pub fn new(retry_after: Duration) -> Self {
+        Self {
+            paused: Arc::new(AtomicBool::new(false)),
+            retry_after,
+        }
+    }
+
+    /// Whether submission to the destination is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    /// How long operations held back by the guard should wait before being retried
+    pub fn retry_after(&self) -> Duration {
+        self.retry_after
+    }
+
+    /// Returns the previous value
+    fn set_paused(&self, paused: bool) -> bool {
+        self.paused.swap(paused, Ordering::Relaxed)
+    }
+}
+
+/// Transfers native tokens from a funder wallet to the relayer wallet
+#[async_trait]
+pub trait Funder: Send + Sync {
+    /// Address of the funder wallet, used for audit logging
+    fn address(&self) -> String;
+
+    /// Transfer `amount` to `recipient` and return the transaction hash once
+    /// the transfer is included
+    async fn transfer(&self, recipient: H256, amount: U256) -> ChainResult<H512>;
+}
+
+struct EvmFunder {
+    address: String,
+    transfer: Arc<dyn NativeTransfer>,
+}
+
+#[async_trait]
+impl Funder for EvmFunder {
+    fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    async fn transfer(&self, recipient: H256, amount: U256) -> ChainResult<H512> {
+        self.transfer
+            .transfer_native(ethers::types::H160::from(recipient), amount)
+            .await
+    }
+}
+
+/// Top ups sent recently, to space them and cap the amount per window
+#[derive(Debug, Default)]
+struct TopUpHistory {
+    /// Time and amount of the top ups sent within the last window, oldest first
+    sent: VecDeque<(Instant, U256)>,
+    /// Whether reaching the limit of the window was already reported
+    limit_reported: bool,
+}
+
+impl TopUpHistory {
+    /// Forget the top ups sent before the window ending at `now`
+    fn forget_older_than(&mut self, window: Duration, now: Instant) {
+        while let Some((sent_at, _)) = self.sent.front() {
+            if now.duration_since(*sent_at) < window {
+                break;
+            }
+            self.sent.pop_front();
+        }
+    }
+
+    fn last_sent_at(&self) -> Option<Instant> {
+        self.sent.back().map(|(sent_at, _)| *sent_at)
+    }
+
+    fn amount_sent(&self) -> U256 {
+        self.sent.iter().fold(U256::zero(), |total, (_, amount)| {
+            total.saturating_add(*amount)
+        })
+    }
+}
+
+/// Keeps an eye on the balance of the relayer wallet on a single destination
+pub struct BalanceManager {
+    domain: HyperlaneDomain,
+    provider: Arc<dyn HyperlaneProvider>,
+    /// Relayer address in the format of the destination, used to query the balance
+    relayer_address: String,
+    /// Relayer address which receives top ups
+    relayer_address_h256: H256,
+    conf: BalanceGuardrailConf,
+    funder: Option<Arc<dyn Funder>>,
+    guard: BalanceGuard,
+    top_up_history: Mutex<TopUpHistory>,
+}
+
+impl Debug for BalanceManager {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BalanceManager {{ domain: {}, relayer_address: {}, conf: {:?} }}",
+            self.domain.name(),
+            self.relayer_address,
+            self.conf
+        )
+    }
+}
+
+impl BalanceManager {
+    pub fn new(
+        domain: HyperlaneDomain,
+        provider: Arc<dyn HyperlaneProvider>,
+        relayer_address: String,
+        relayer_address_h256: H256,
+        conf: BalanceGuardrailConf,
+        funder: Option<Arc<dyn Funder>>,
+    ) -> Self {
+        let guard = BalanceGuard::new(conf.check_interval);
+        Self {
+            domain,
+            provider,
+            relayer_address,
+            relayer_address_h256,
+            conf,
+            funder,
+            guard,
+            top_up_history: Mutex::default(),
+        }
+    }
+
+    /// Build a balance manager for the destination described by `chain_conf`
+    pub async fn build(
+        chain_conf: &ChainConf,
+        conf: BalanceGuardrailConf,
+        metrics: &CoreMetrics,
+    ) -> Result<Self> {
+        let provider: Arc<dyn HyperlaneProvider> = chain_conf.build_provider(metrics).await?.into();
+        let signer = chain_conf
+            .chain_signer()
+            .await?
+            .ok_or_else(|| eyre!("No relayer signer configured for balance guardrails"))?;
+        let funder = match &conf.top_up {
+            Some(top_up) => Some(build_funder(chain_conf, &top_up.funder, metrics).await?),
+            None => None,
+        };
+        Ok(Self::new(
+            chain_conf.domain.clone(),
+            provider,
+            signer.address_string(),
+            signer.address_h256(),
+            conf,
+            funder,
+        ))
+    }
+
+    /// The guard which tells the submit task whether submission is paused
+    pub fn guard(&self) -> BalanceGuard {
+        self.guard.clone()
+    }
+
+    pub fn spawn(self) -> JoinHandle<()> {
+        let span = info_span!("BalanceManager", destination=%self.domain);
+        let name = format!("balance_manager::{}", self.domain.name());
+        tokio::task::Builder::new()
+            .name(&name)
+            .spawn(async move { self.run().await }.instrument(span))
+            .expect("spawning tokio task from Builder is infallible")
+    }
+
+    async fn run(self) {
+        loop {
+            if let Err(err) = self.check_balance().await {
+                warn!(?err, "Failed to check relayer balance");
+            }
+            sleep(self.conf.check_interval).await;
+        }
+    }
+
+    /// Tops up the relayer wallet if needed and pauses or resumes submission
+    /// depending on the resulting balance
+    pub async fn check_balance(&self) -> ChainResult<()> {
+        let mut balance = self.balance().await?;
+
+        if let Some(top_up) = &self.conf.top_up {
+            if balance < top_up.threshold {
+                if let Some(new_balance) = self.top_up(top_up, balance).await {
+                    balance = new_balance;
+                }
+            }
+        }
+
+        let paused = balance < self.conf.min_balance;
+        let was_paused = self.guard.set_paused(paused);
+        match (was_paused, paused) {
+            (false, true) => warn!(
+                %balance,
+                min_balance = %self.conf.min_balance,
+                relayer = self.relayer_address,
+                "Relayer balance below configured minimum, pausing submission"
+            ),
+            (true, false) => info!(
+                %balance,
+                min_balance = %self.conf.min_balance,
+                relayer = self.relayer_address,
+                "Relayer balance restored, resuming submission"
+            ),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn balance(&self) -> ChainResult<U256> {
+        self.provider
+            .get_balance(self.relayer_address.clone())
+            .await
+    }
+
+    /// Transfers the configured amount from the funder and returns the balance
+    /// of the relayer wallet after the transfer. Every transfer is logged for audit.
+    /// Nothing is transferred within the minimum interval since the last top
+    /// up, nor beyond the limit of the window.
+    async fn top_up(&self, top_up: &TopUpConf, balance_before: U256) -> Option<U256> {
+        let Some(funder) = &self.funder else {
+            warn!("Relayer balance below top up threshold, but no funder is available");
+            return None;
+        };
+        let funder_address = funder.address();
+
+        let mut history = self.top_up_history.lock().await;
+        let now = Instant::now();
+        history.forget_older_than(top_up.window, now);
+        if let Some(last_sent_at) = history.last_sent_at() {
+            let since_last = now.duration_since(last_sent_at);
+            if since_last < top_up.min_interval {
+                info!(
+                    ?since_last,
+                    min_interval = ?top_up.min_interval,
+                    %balance_before,
+                    "Relayer balance below top up threshold, waiting for the minimum interval between top ups"
+                );
+                return None;
+            }
+        }
+        let amount_sent = history.amount_sent();
+        if amount_sent.saturating_add(top_up.amount) > top_up.max_amount_per_window {
+            if !history.limit_reported {
+                error!(
+                    domain = self.domain.name(),
+                    funder = funder_address,
+                    recipient = self.relayer_address,
+                    %amount_sent,
+                    max_amount_per_window = %top_up.max_amount_per_window,
+                    window = ?top_up.window,
+                    %balance_before,
+                    "Relayer wallet top up limit reached, no more top ups until the window allows it"
+                );
+                history.limit_reported = true;
+            }
+            return None;
+        }
+        history.limit_reported = false;
+        // recorded before sending, since a failed transfer may still have
+        // been broadcast and spent funds
+        history.sent.push_back((now, top_up.amount));
+        drop(history);
+
+        info!(
+            domain = self.domain.name(),
+            funder = funder_address,
+            recipient = self.relayer_address,
+            amount = %top_up.amount,
+            %balance_before,
+            "Sending relayer wallet top up"
+        );
+        let tx_hash = match funder
+            .transfer(self.relayer_address_h256, top_up.amount)
+            .await
+        {
+            Ok(tx_hash) => tx_hash,
+            Err(err) => {
+                error!(
+                    domain = self.domain.name(),
+                    funder = funder_address,
+                    recipient = self.relayer_address,
+                    amount = %top_up.amount,
+                    %balance_before,
+                    ?err,
+                    "Relayer wallet top up failed"
+                );
+                return None;
+            }
+        };
+
+        let balance_after = self.balance().await.ok();
+        info!(
+            domain = self.domain.name(),
+            funder = funder_address,
+            recipient = self.relayer_address,
+            amount = %top_up.amount,
+            %balance_before,
+            ?balance_after,
+            ?tx_hash,
+            "Relayer wallet topped up"
+        );
+        balance_after
+    }
+}
+
+async fn build_funder(
+    chain_conf: &ChainConf,
+    signer_conf: &SignerConf,
+    metrics: &CoreMetrics,
+) -> Result<Arc<dyn Funder>> {
+    let ChainConnectionConf::Ethereum(connection_conf) = &chain_conf.connection else {
+        return Err(eyre!(
+            "Relayer wallet top ups are only supported on EVM chains, got {:?}",
+            chain_conf.protocol()
+        ));
+    };
+
+    let signer: Signers = signer_conf.build().await?;
+    let address = signer.address_string();
+    let locator = ContractLocator {
+        domain: &chain_conf.domain,
+        address: H256::zero(),
+    };
+    let transfer = chain_conf
+        .build_ethereum_with_signer(
+            connection_conf,
+            &locator,
+            metrics,
+            NativeTransferBuilder {},
+            Some(signer),
+        )
+        .await?;
+    Ok(Arc::new(EvmFunder { address, transfer }))
+}
+
+#[cfg(test)]
+mod tests;
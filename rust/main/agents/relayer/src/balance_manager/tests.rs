@@ -0,0 +1,297 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use mockall::mock;
+
+use hyperlane_base::settings::SignerConf;
+use hyperlane_core::{
+    BlockInfo, ChainCommunicationError, ChainInfo, ChainResult, HyperlaneChain, HyperlaneDomain,
+    HyperlaneProvider, KnownHyperlaneDomain, TxnInfo, H256, H512, U256,
+};
+
+use crate::settings::{BalanceGuardrailConf, TopUpConf};
+
+use super::{BalanceManager, Funder};
+
+mock! {
+    #[derive(Debug)]
+    pub Provider {}
+
+    #[async_trait]
+    impl HyperlaneProvider for Provider {
+        async fn get_block_by_height(&self, height: u64) -> ChainResult<BlockInfo>;
+        async fn get_txn_by_hash(&self, hash: &H512) -> ChainResult<TxnInfo>;
+        async fn is_contract(&self, address: &H256) -> ChainResult<bool>;
+        async fn get_balance(&self, address: String) -> ChainResult<U256>;
+        async fn get_chain_metrics(&self) -> ChainResult<Option<ChainInfo>>;
+    }
+
+    impl HyperlaneChain for Provider {
+        fn domain(&self) -> &HyperlaneDomain;
+        fn provider(&self) -> Box<dyn HyperlaneProvider>;
+    }
+}
+
+mock! {
+    pub FunderWallet {}
+
+    #[async_trait]
+    impl Funder for FunderWallet {
+        fn address(&self) -> String;
+        async fn transfer(&self, recipient: H256, amount: U256) -> ChainResult<H512>;
+    }
+}
+
+const RELAYER_ADDRESS: &str = "0x000000000000000000000000000000000000dead";
+
+fn relayer_address_h256() -> H256 {
+    H256::from_low_u64_be(0xdead)
+}
+
+fn provider_with_balance(balance: Arc<Mutex<U256>>) -> MockProvider {
+    let mut provider = MockProvider::new();
+    provider
+        .expect_get_balance()
+        .withf(|address| address == RELAYER_ADDRESS)
+        .returning(move |_| Ok(*balance.lock().unwrap()));
+    provider
+}
+
+fn guardrail_conf(top_up: Option<TopUpConf>) -> BalanceGuardrailConf {
+    BalanceGuardrailConf {
+        min_balance: U256::from(100),
+        check_interval: Duration::from_secs(60),
+        top_up,
+    }
+}
+
+fn top_up_conf() -> TopUpConf {
+    TopUpConf {
+        threshold: U256::from(500),
+        amount: U256::from(1000),
+        min_interval: Duration::ZERO,
+        max_amount_per_window: U256::from(10_000),
+        window: Duration::from_secs(86_400),
+        funder: SignerConf::Node,
+    }
+}
+
+fn balance_manager(
+    provider: MockProvider,
+    conf: BalanceGuardrailConf,
+    funder: Option<MockFunderWallet>,
+) -> BalanceManager {
+    BalanceManager::new(
+        HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum),
+        Arc::new(provider),
+        RELAYER_ADDRESS.to_string(),
+        relayer_address_h256(),
+        conf,
+        funder.map(|funder| Arc::new(funder) as Arc<dyn Funder>),
+    )
+}
+
+fn funder_crediting(balance: Arc<Mutex<U256>>) -> MockFunderWallet {
+    let mut funder = MockFunderWallet::new();
+    funder.expect_address().returning(|| "funder".to_string());
+    funder
+        .expect_transfer()
+        .withf(|recipient, amount| {
+            *recipient == relayer_address_h256() && *amount == U256::from(1000)
+        })
+        .times(1)
+        .returning(move |_, amount| {
+            let mut balance = balance.lock().unwrap();
+            *balance = balance.saturating_add(amount);
+            Ok(H512::random())
+        });
+    funder
+}
+
+#[tokio::test]
+async fn test_submission_is_paused_below_min_balance_and_resumed_after_funding() {
+    let balance = Arc::new(Mutex::new(U256::from(1000)));
+    let manager = balance_manager(
+        provider_with_balance(balance.clone()),
+        guardrail_conf(None),
+        None,
+    );
+    let guard = manager.guard();
+
+    manager.check_balance().await.unwrap();
+    assert!(!guard.is_paused());
+
+    *balance.lock().unwrap() = U256::from(99);
+    manager.check_balance().await.unwrap();
+    assert!(guard.is_paused());
+
+    *balance.lock().unwrap() = U256::from(100);
+    manager.check_balance().await.unwrap();
+    assert!(!guard.is_paused());
+}
+
+#[tokio::test]
+async fn test_wallet_is_topped_up_below_threshold() {
+    let balance = Arc::new(Mutex::new(U256::from(50)));
+    let manager = balance_manager(
+        provider_with_balance(balance.clone()),
+        guardrail_conf(Some(top_up_conf())),
+        Some(funder_crediting(balance.clone())),
+    );
+    let guard = manager.guard();
+
+    manager.check_balance().await.unwrap();
+
+    assert_eq!(*balance.lock().unwrap(), U256::from(1050));
+    // the wallet was topped up before it had to be paused
+    assert!(!guard.is_paused());
+}
+
+#[tokio::test]
+async fn test_wallet_is_not_topped_up_above_threshold() {
+    let balance = Arc::new(Mutex::new(U256::from(500)));
+    let mut funder = MockFunderWallet::new();
+    funder.expect_address().returning(|| "funder".to_string());
+    funder.expect_transfer().never();
+    let manager = balance_manager(
+        provider_with_balance(balance.clone()),
+        guardrail_conf(Some(top_up_conf())),
+        Some(funder),
+    );
+
+    manager.check_balance().await.unwrap();
+
+    assert!(!manager.guard().is_paused());
+}
+
+#[tokio::test]
+async fn test_submission_stays_paused_when_top_up_fails() {
+    let balance = Arc::new(Mutex::new(U256::from(50)));
+    let mut funder = MockFunderWallet::new();
+    funder.expect_address().returning(|| "funder".to_string());
+    funder.expect_transfer().times(1).returning(|_, _| {
+        Err(ChainCommunicationError::CustomError(
+            "insufficient funds".to_string(),
+        ))
+    });
+    let manager = balance_manager(
+        provider_with_balance(balance.clone()),
+        guardrail_conf(Some(top_up_conf())),
+        Some(funder),
+    );
+
+    manager.check_balance().await.unwrap();
+
+    assert!(manager.guard().is_paused());
+}
+
+/// A funder whose transfers never show up in the balance, as when the
+/// destination burns all the gas, and which counts its transfers
+fn funder_counting(transfers: Arc<Mutex<u32>>) -> MockFunderWallet {
+    let mut funder = MockFunderWallet::new();
+    funder.expect_address().returning(|| "funder".to_string());
+    funder.expect_transfer().returning(move |_, _| {
+        *transfers.lock().unwrap() += 1;
+        Ok(H512::random())
+    });
+    funder
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_top_ups_are_spaced_by_min_interval() {
+    let balance = Arc::new(Mutex::new(U256::from(50)));
+    let transfers = Arc::new(Mutex::new(0));
+    let top_up = TopUpConf {
+        min_interval: Duration::from_secs(600),
+        ..top_up_conf()
+    };
+    let manager = balance_manager(
+        provider_with_balance(balance),
+        guardrail_conf(Some(top_up)),
+        Some(funder_counting(transfers.clone())),
+    );
+
+    manager.check_balance().await.unwrap();
+    assert_eq!(*transfers.lock().unwrap(), 1);
+
+    tokio::time::advance(Duration::from_secs(599)).await;
+    manager.check_balance().await.unwrap();
+    assert_eq!(*transfers.lock().unwrap(), 1);
+
+    tokio::time::advance(Duration::from_secs(1)).await;
+    manager.check_balance().await.unwrap();
+    assert_eq!(*transfers.lock().unwrap(), 2);
+}
+
+#[tracing_test::traced_test]
+#[tokio::test(start_paused = true)]
+async fn test_top_ups_stop_at_window_limit() {
+    let balance = Arc::new(Mutex::new(U256::from(50)));
+    let transfers = Arc::new(Mutex::new(0));
+    let top_up = TopUpConf {
+        max_amount_per_window: U256::from(2500),
+        window: Duration::from_secs(3_600),
+        ..top_up_conf()
+    };
+    let manager = balance_manager(
+        provider_with_balance(balance),
+        guardrail_conf(Some(top_up)),
+        Some(funder_counting(transfers.clone())),
+    );
+    let guard = manager.guard();
+
+    for _ in 0..5 {
+        manager.check_balance().await.unwrap();
+        tokio::time::advance(Duration::from_secs(60)).await;
+    }
+    // a third top up would exceed the limit of the window
+    assert_eq!(*transfers.lock().unwrap(), 2);
+    assert!(guard.is_paused());
+    assert!(logs_contain("Relayer wallet top up limit reached"));
+
+    // the first top ups leave the window an hour after they were sent
+    tokio::time::advance(Duration::from_secs(3_600)).await;
+    manager.check_balance().await.unwrap();
+    assert_eq!(*transfers.lock().unwrap(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_failed_top_ups_count_towards_the_limit() {
+    let balance = Arc::new(Mutex::new(U256::from(50)));
+    let mut funder = MockFunderWallet::new();
+    funder.expect_address().returning(|| "funder".to_string());
+    funder.expect_transfer().times(1).returning(|_, _| {
+        Err(ChainCommunicationError::CustomError(
+            "transaction dropped".to_string(),
+        ))
+    });
+    let top_up = TopUpConf {
+        max_amount_per_window: U256::from(1000),
+        ..top_up_conf()
+    };
+    let manager = balance_manager(
+        provider_with_balance(balance),
+        guardrail_conf(Some(top_up)),
+        Some(funder),
+    );
+
+    manager.check_balance().await.unwrap();
+    tokio::time::advance(Duration::from_secs(60)).await;
+    manager.check_balance().await.unwrap();
+
+    assert!(manager.guard().is_paused());
+}
+
+#[tokio::test]
+async fn test_guard_is_unchanged_when_balance_cannot_be_fetched() {
+    let mut provider = MockProvider::new();
+    provider
+        .expect_get_balance()
+        .returning(|_| Err(ChainCommunicationError::CustomError("rpc".to_string())));
+    let manager = balance_manager(provider, guardrail_conf(None), None);
+    let guard = manager.guard();
+
+    assert!(manager.check_balance().await.is_err());
+    assert!(!guard.is_paused());
+}
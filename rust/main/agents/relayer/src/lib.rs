@@ -4,6 +4,7 @@
 pub mod msg;
 pub mod relay_api;
 
+mod balance_manager;
 mod db_loader;
 mod merkle_tree;
 mod metrics;
@@ -9,7 +9,10 @@ use tokio_metrics::TaskMonitor;
 use tracing::info_span;
 
 use hyperlane_base::{
-    cache::{LocalCache, MeteredCache, MeteredCacheConfig, MeteredCacheMetrics, OptionalCache},
+    cache::{
+        CacheBackend, LocalCache, MeteredCache, MeteredCacheConfig, MeteredCacheMetrics,
+        OptionalCache,
+    },
     db::{test_utils, HyperlaneRocksDB},
     tests::mock_hyperlane_db::MockHyperlaneDb as MockDb,
 };
@@ -67,7 +70,7 @@ fn dummy_message_loader(
     origin_domain: &HyperlaneDomain,
     destination_domain: &HyperlaneDomain,
     db: &HyperlaneRocksDB,
-    cache: OptionalCache<MeteredCache<LocalCache>>,
+    cache: OptionalCache<MeteredCache<CacheBackend>>,
 ) -> (MessageDbLoader, UnboundedReceiver<QueueOperation>) {
     let base_metadata_builder =
         dummy_metadata_builder(origin_domain, destination_domain, db, cache.clone());
@@ -137,7 +140,7 @@ async fn get_first_n_operations_from_db_loader(
     origin_domain: &HyperlaneDomain,
     destination_domain: &HyperlaneDomain,
     db: &HyperlaneRocksDB,
-    cache: OptionalCache<MeteredCache<LocalCache>>,
+    cache: OptionalCache<MeteredCache<CacheBackend>>,
     num_operations: usize,
 ) -> Vec<QueueOperation> {
     let (message_db_loader, mut receive_channel) =
@@ -169,7 +172,7 @@ async fn test_full_pending_message_persistence_flow() {
         let destination_domain = dummy_domain(1, "dummy_destination_domain");
         let db = HyperlaneRocksDB::new(&origin_domain, db);
         let cache = OptionalCache::new(Some(MeteredCache::new(
-            LocalCache::new("test-cache"),
+            CacheBackend::from(LocalCache::new("test-cache")),
             dummy_cache_metrics(),
             MeteredCacheConfig {
                 cache_name: "test-cache".to_owned(),
@@ -231,6 +234,57 @@ async fn test_full_pending_message_persistence_flow() {
     .await;
 }
 
+#[tokio::test]
+async fn test_pruned_messages_are_not_relayed_again() {
+    test_utils::run_test_db(|db| async move {
+        let origin_domain = dummy_domain(0, "dummy_origin_domain");
+        let destination_domain = dummy_domain(1, "dummy_destination_domain");
+        let db = HyperlaneRocksDB::new(&origin_domain, db);
+        let cache = OptionalCache::new(Some(MeteredCache::new(
+            CacheBackend::from(LocalCache::new("test-cache")),
+            dummy_cache_metrics(),
+            MeteredCacheConfig {
+                cache_name: "test-cache".to_owned(),
+            },
+        )));
+
+        let messages = (0..4)
+            .map(|nonce| HyperlaneMessage {
+                body: vec![1, 2, 3],
+                ..dummy_hyperlane_message(&destination_domain, nonce)
+            })
+            .collect::<Vec<_>>();
+        for message in &messages {
+            add_db_entry(&db, message, 0);
+        }
+        // the first two messages were delivered long ago
+        for nonce in 0..2 {
+            db.record_message_delivery(nonce, 100).unwrap();
+            db.store_processed_by_nonce(&nonce, &true).unwrap();
+        }
+        assert_eq!(db.prune_delivered_messages(200, 10).unwrap(), 2);
+
+        let pending_messages = get_first_n_operations_from_db_loader(
+            &origin_domain,
+            &destination_domain,
+            &db,
+            cache,
+            2,
+        )
+        .await;
+        // only the undelivered messages are loaded, with their bodies intact
+        let mut ids = pending_messages
+            .iter()
+            .map(|op| op.id())
+            .collect::<Vec<_>>();
+        ids.sort();
+        let mut expected = vec![messages[2].id(), messages[3].id()];
+        expected.sort();
+        assert_eq!(ids, expected);
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn test_forward_backward_iterator() {
     let mut mock_db = MockDb::new();
@@ -18,6 +18,7 @@ use tokio_metrics::TaskMonitor;
 use tracing::{debug, error, info, info_span, instrument, trace, warn, Instrument};
 
 use hyperlane_base::db::{HyperlaneDb, HyperlaneRocksDB};
+use hyperlane_base::settings::otlp::{link_handoff, record_handoff};
 use hyperlane_base::CoreMetrics;
 use hyperlane_core::PendingOperationStatus::ReadyToSubmit;
 use hyperlane_core::{
@@ -28,6 +29,7 @@ use lander::{
     DispatcherEntrypoint, Entrypoint, FullPayload, LanderError, PayloadStatus, PayloadUuid,
 };
 
+use crate::balance_manager::BalanceGuard;
 use crate::msg::pending_message::CONFIRM_DELAY;
 use crate::server::operations::message_retry::MessageRetryRequest;
 
@@ -99,6 +101,8 @@ pub struct MessageProcessor {
     confirm_queue: OpQueue,
     payload_dispatcher_entrypoint: Option<DispatcherEntrypoint>,
     db: Arc<dyn HyperlaneDb>,
+    /// Pauses submission while the relayer balance is below the configured minimum
+    balance_guard: BalanceGuard,
 }
 
 impl MessageProcessor {
@@ -113,6 +117,7 @@ impl MessageProcessor {
         task_monitor: TaskMonitor,
         payload_dispatcher_entrypoint: Option<DispatcherEntrypoint>,
         db: HyperlaneRocksDB,
+        balance_guard: BalanceGuard,
     ) -> Self {
         let prepare_queue = OpQueue::new(
             metrics.processor_queue_length.clone(),
@@ -143,6 +148,7 @@ impl MessageProcessor {
             confirm_queue,
             payload_dispatcher_entrypoint,
             db: Arc::new(db),
+            balance_guard,
         }
     }
 
@@ -205,7 +211,13 @@ impl MessageProcessor {
             .name(&name)
             .spawn(TaskMonitor::instrument(
                 &self.task_monitor,
-                receive_task(self.domain.clone(), rx_prepare, self.prepare_queue.clone()),
+                receive_task(
+                    self.domain.clone(),
+                    rx_prepare,
+                    self.prepare_queue.clone(),
+                    self.submit_queue.clone(),
+                    self.confirm_queue.clone(),
+                ),
             ))
             .expect("spawning tokio task from Builder is infallible")
     }
@@ -242,6 +254,7 @@ impl MessageProcessor {
                     self.confirm_queue.clone(),
                     self.max_batch_size,
                     self.metrics.clone(),
+                    self.balance_guard.clone(),
                 ),
             ))
             .expect("spawning tokio task from Builder is infallible")
@@ -301,6 +314,7 @@ impl MessageProcessor {
                     self.max_batch_size,
                     self.metrics.clone(),
                     self.db.clone(),
+                    self.balance_guard.clone(),
                 ),
             ))
             .expect("spawning tokio task from Builder is infallible")
@@ -336,6 +350,8 @@ async fn receive_task(
     domain: HyperlaneDomain,
     mut rx: mpsc::UnboundedReceiver<QueueOperation>,
     prepare_queue: OpQueue,
+    submit_queue: OpQueue,
+    confirm_queue: OpQueue,
 ) {
     // Pull any messages sent to this message processor
     while let Some(op) = rx.recv().await {
@@ -344,7 +360,19 @@ async fn receive_task(
         // should also be valid in production.
         debug_assert_eq!(*op.destination_domain(), domain);
         let op_status = op.status();
-        prepare_queue.push(op, Some(op_status)).await;
+        // Operations restored from a snapshot skip the stages they already went
+        // through before the relayer restarted
+        match (op.is_restored_from_snapshot(), &op_status) {
+            (true, PendingOperationStatus::ReadyToSubmit) => {
+                debug!(id = ?op.id(), "Restoring operation into submit queue");
+                submit_queue.push(op, Some(op_status)).await;
+            }
+            (true, PendingOperationStatus::Confirm(_)) => {
+                debug!(id = ?op.id(), "Restoring operation into confirm queue");
+                confirm_queue.push(op, Some(op_status)).await;
+            }
+            _ => prepare_queue.push(op, Some(op_status)).await,
+        }
     }
 }
 
@@ -520,6 +548,7 @@ async fn process_batch(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 #[instrument(skip_all, fields(%domain))]
 async fn submit_classic_task(
     domain: HyperlaneDomain,
@@ -528,11 +557,17 @@ async fn submit_classic_task(
     mut confirm_queue: OpQueue,
     max_batch_size: u32,
     metrics: MessageProcessorMetrics,
+    balance_guard: BalanceGuard,
 ) {
     let recv_limit = max_batch_size as usize;
     loop {
         let mut batch = submit_queue.pop_many(recv_limit).await;
 
+        if balance_guard.is_paused() && !batch.is_empty() {
+            hold_back_operations(batch, &prepare_queue, &balance_guard).await;
+            continue;
+        }
+
         match batch.len().cmp(&1) {
             std::cmp::Ordering::Less => {
                 // The queue is empty, so give some time before checking again to prevent burning CPU
@@ -563,6 +598,7 @@ async fn submit_lander_task(
     max_batch_size: u32,
     metrics: MessageProcessorMetrics,
     db: Arc<dyn HyperlaneDb>,
+    balance_guard: BalanceGuard,
 ) {
     let recv_limit = max_batch_size as usize;
     loop {
@@ -586,6 +622,13 @@ async fn submit_lander_task(
             continue;
         }
 
+        // Operations which already have payloads in flight are tracked by the filter above,
+        // only new submissions are held back while the relayer balance is too low
+        if balance_guard.is_paused() {
+            hold_back_operations(operations_to_process, &prepare_queue, &balance_guard).await;
+            continue;
+        }
+
         // Process remaining operations in submit queue
         for op in operations_to_process {
             // Operation needs a new payload created and sent
@@ -594,6 +637,7 @@ async fn submit_lander_task(
     }
 }
 
+#[instrument(skip_all, fields(id=?op.id(), payload_uuid=tracing::field::Empty))]
 async fn submit_via_lander(
     op: QueueOperation,
     entrypoint: &Arc<DispatcherEntrypoint>,
@@ -623,11 +667,14 @@ async fn submit_via_lander(
 
     let message_id = op.id();
     let metadata = format!("{message_id:?}");
+    link_handoff(&metadata);
     let mailbox = op
         .try_get_mailbox()
         .expect("Operation should contain Mailbox address")
         .address();
     let payload_uuid = PayloadUuid::random();
+    tracing::Span::current().record("payload_uuid", payload_uuid.to_string());
+    record_handoff(&payload_uuid.to_string());
     let payload = FullPayload::new(
         payload_uuid,
         metadata,
@@ -659,6 +706,27 @@ async fn submit_via_lander(
     submit_queue.push(op, Some(ReadyToSubmit)).await;
 }
 
+/// Sends operations back to the prepare queue while submission is paused because
+/// the relayer balance is below the configured minimum. This is not the fault of
+/// the operations, so it does not count towards their retries.
+async fn hold_back_operations(
+    batch: Vec<QueueOperation>,
+    prepare_queue: &OpQueue,
+    balance_guard: &BalanceGuard,
+) {
+    use PendingOperationStatus::Retry;
+
+    debug!(
+        count = batch.len(),
+        "Relayer balance below configured minimum, holding back operations"
+    );
+    for mut op in batch {
+        op.set_next_attempt_after(balance_guard.retry_after());
+        let status = Retry(ReprepareReason::InsufficientRelayerBalance);
+        prepare_queue.push(op, Some(status)).await;
+    }
+}
+
 async fn prepare_op(
     mut op: QueueOperation,
     prepare_queue: &OpQueue,
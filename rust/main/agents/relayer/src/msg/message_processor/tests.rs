@@ -1 +1,4 @@
 pub(crate) mod tests_common;
+
+mod tests_balance_guard;
+mod tests_restore;
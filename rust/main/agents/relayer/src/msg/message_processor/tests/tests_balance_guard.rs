@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use hyperlane_core::{PendingOperationStatus, QueueOperation, ReprepareReason, H256};
+
+use crate::balance_manager::BalanceGuard;
+use crate::msg::message_processor::hold_back_operations;
+use crate::msg::message_processor::tests::tests_common::{create_test_queue, MockQueueOperation};
+
+#[tokio::test]
+async fn test_held_back_operations_are_sent_to_prepare_queue() {
+    let mut prepare_queue = create_test_queue();
+    let guard = BalanceGuard::new(Duration::from_secs(60));
+    let batch: Vec<QueueOperation> = (1..=2)
+        .map(|id| {
+            Box::new(MockQueueOperation::with_first_prepare(
+                H256::from_low_u64_be(id),
+            )) as QueueOperation
+        })
+        .collect();
+
+    hold_back_operations(batch, &prepare_queue, &guard).await;
+
+    assert_eq!(prepare_queue.len().await, 2);
+    while let Some(op) = prepare_queue.pop().await {
+        assert!(matches!(
+            op.status(),
+            PendingOperationStatus::Retry(ReprepareReason::InsufficientRelayerBalance)
+        ));
+    }
+}
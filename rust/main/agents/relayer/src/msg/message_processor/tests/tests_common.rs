@@ -27,6 +27,7 @@ pub struct MockQueueOperation {
     pub id: H256,
     pub status: PendingOperationStatus,
     pub destination: HyperlaneDomain,
+    pub restored: bool,
 }
 
 impl MockQueueOperation {
@@ -35,6 +36,7 @@ impl MockQueueOperation {
             id,
             status,
             destination,
+            restored: false,
         }
     }
 
@@ -96,6 +98,9 @@ impl PendingOperation for MockQueueOperation {
     fn status(&self) -> PendingOperationStatus {
         self.status.clone()
     }
+    fn is_restored_from_snapshot(&self) -> bool {
+        self.restored
+    }
     fn set_status(&mut self, status: PendingOperationStatus) {
         self.status = status;
     }
@@ -0,0 +1,61 @@
+use hyperlane_core::{
+    ConfirmReason, HyperlaneDomain, PendingOperationStatus, QueueOperation, H256,
+};
+use tokio::sync::mpsc;
+
+use crate::msg::message_processor::receive_task;
+use crate::msg::message_processor::tests::tests_common::{create_test_queue, MockQueueOperation};
+
+fn operation(id: u64, status: PendingOperationStatus, restored: bool) -> QueueOperation {
+    let mut op = MockQueueOperation::new(
+        H256::from_low_u64_be(id),
+        status,
+        HyperlaneDomain::new_test_domain("test"),
+    );
+    op.restored = restored;
+    Box::new(op)
+}
+
+#[tokio::test]
+async fn test_restored_operations_are_received_into_their_queue() {
+    let domain = HyperlaneDomain::new_test_domain("test");
+    let mut prepare_queue = create_test_queue();
+    let mut submit_queue = create_test_queue();
+    let mut confirm_queue = create_test_queue();
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    let confirm_status = PendingOperationStatus::Confirm(ConfirmReason::SubmittedBySelf);
+    tx.send(operation(1, PendingOperationStatus::ReadyToSubmit, true))
+        .unwrap();
+    tx.send(operation(2, confirm_status.clone(), true)).unwrap();
+    // not restored from a snapshot, so prepared again whatever its status
+    tx.send(operation(3, PendingOperationStatus::ReadyToSubmit, false))
+        .unwrap();
+    drop(tx);
+
+    receive_task(
+        domain.clone(),
+        rx,
+        prepare_queue.clone(),
+        submit_queue.clone(),
+        confirm_queue.clone(),
+    )
+    .await;
+
+    assert_eq!(submit_queue.len().await, 1);
+    let op = submit_queue.pop().await.unwrap();
+    assert_eq!(op.id(), H256::from_low_u64_be(1));
+    assert_eq!(op.status(), PendingOperationStatus::ReadyToSubmit);
+    assert_eq!(op.destination_domain(), &domain);
+
+    assert_eq!(confirm_queue.len().await, 1);
+    let op = confirm_queue.pop().await.unwrap();
+    assert_eq!(op.id(), H256::from_low_u64_be(2));
+    assert_eq!(op.status(), confirm_status);
+    assert_eq!(op.destination_domain(), &domain);
+
+    assert_eq!(prepare_queue.len().await, 1);
+    let op = prepare_queue.pop().await.unwrap();
+    assert_eq!(op.id(), H256::from_low_u64_be(3));
+    assert_eq!(op.destination_domain(), &domain);
+}
@@ -11,7 +11,7 @@ use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 
 use hyperlane_base::{
-    cache::{LocalCache, MeteredCache, OptionalCache},
+    cache::{CacheBackend, MeteredCache, OptionalCache},
     db::{HyperlaneDb, HyperlaneRocksDB},
     settings::{ChainConf, CheckpointSyncerBuildError, CheckpointSyncerConf},
     CheckpointSyncer, CoreMetrics, MultisigCheckpointSyncer,
@@ -51,7 +51,7 @@ pub struct BaseMetadataBuilder {
     origin_validator_announce: Arc<dyn ValidatorAnnounce>,
     allow_local_checkpoint_syncers: bool,
     metrics: Arc<CoreMetrics>,
-    cache: OptionalCache<MeteredCache<LocalCache>>,
+    cache: OptionalCache<MeteredCache<CacheBackend>>,
     db: HyperlaneRocksDB,
     app_context_classifier: IsmAwareAppContextClassifier,
     ism_cache_policy_classifier: IsmCachePolicyClassifier,
@@ -75,7 +75,7 @@ pub trait BuildsBaseMetadata: Send + Sync + Debug {
     fn destination_domain(&self) -> &HyperlaneDomain;
     fn app_context_classifier(&self) -> &IsmAwareAppContextClassifier;
     fn ism_cache_policy_classifier(&self) -> &IsmCachePolicyClassifier;
-    fn cache(&self) -> &OptionalCache<MeteredCache<LocalCache>>;
+    fn cache(&self) -> &OptionalCache<MeteredCache<CacheBackend>>;
     fn get_signer(&self) -> Option<&Signers>;
 
     fn update_ism_metric(&self, params: IsmBuildMetricsParams);
@@ -122,7 +122,7 @@ impl BuildsBaseMetadata for BaseMetadataBuilder {
         &self.ism_cache_policy_classifier
     }
 
-    fn cache(&self) -> &OptionalCache<MeteredCache<LocalCache>> {
+    fn cache(&self) -> &OptionalCache<MeteredCache<CacheBackend>> {
         &self.cache
     }
 
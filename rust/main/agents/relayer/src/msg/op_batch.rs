@@ -187,7 +187,7 @@ mod tests {
     };
     use ethers::utils::hex;
     use hyperlane_base::{
-        cache::{LocalCache, MeteredCache, MeteredCacheConfig, OptionalCache},
+        cache::{CacheBackend, LocalCache, MeteredCache, MeteredCacheConfig, OptionalCache},
         db::{HyperlaneRocksDB, DB},
         settings::{ChainConf, ChainConnectionConf, CoreContractAddresses},
         CoreMetrics,
@@ -325,6 +325,7 @@ mod tests {
             domain: HyperlaneDomain::Known(hyperlane_core::KnownHyperlaneDomain::Arbitrum),
             // TODO
             signer: None,
+            additional_signers: vec![],
             submitter: SubmitterType::Classic,
             estimated_block_time: Duration::from_secs(1),
             reorg_period: ReorgPeriod::from_blocks(10),
@@ -354,6 +355,9 @@ mod tests {
                     ..Default::default()
                 },
                 consider_null_transaction_receipt: false,
+                private_relay: None,
+                rpc_rate_limits: Default::default(),
+                rpc_fallback: Default::default(),
             }),
             metrics_conf: Default::default(),
             index: Default::default(),
@@ -380,7 +384,7 @@ mod tests {
             .into();
 
         let cache = OptionalCache::new(Some(MeteredCache::new(
-            LocalCache::new("test-cache"),
+            CacheBackend::from(LocalCache::new("test-cache")),
             dummy_cache_metrics(),
             MeteredCacheConfig {
                 cache_name: "test-cache".to_owned(),
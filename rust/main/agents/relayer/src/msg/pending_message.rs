@@ -3,7 +3,7 @@
 use std::{
     fmt::{Debug, Formatter},
     sync::Arc,
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use async_trait::async_trait;
@@ -15,14 +15,16 @@ use tokio::sync::RwLock;
 use tracing::{debug, error, info, info_span, instrument, trace, warn, Instrument, Level};
 
 use hyperlane_base::{
-    cache::{FunctionCallCache, LocalCache, MeteredCache, OptionalCache},
+    cache::{CacheBackend, FunctionCallCache, MeteredCache, OptionalCache},
     db::HyperlaneDb,
+    settings::otlp::{link_handoff, record_handoff},
 };
 use hyperlane_core::{
     gas_used_by_operation, BatchItem, ChainCommunicationError, ChainResult, ConfirmReason,
     FixedPointNumber, HyperlaneChain, HyperlaneDomain, HyperlaneMessage, Mailbox,
     MessageSubmissionData, Metadata, PendingOperation, PendingOperationResult,
-    PendingOperationStatus, ReprepareReason, TryBatchAs, TxCostEstimate, TxOutcome, H256, U256,
+    PendingOperationSnapshot, PendingOperationStatus, ReprepareReason, TryBatchAs, TxCostEstimate,
+    TxOutcome, H256, U256,
 };
 use hyperlane_operation_verifier::ApplicationOperationVerifier;
 
@@ -53,6 +55,10 @@ pub const USE_CACHE_METADATA_LOG: &str = "Reusing cached metadata";
 pub const INVALIDATE_CACHE_METADATA_LOG: &str = "Invalidating cached metadata";
 pub const ISM_MAX_DEPTH: u32 = 13;
 pub const ISM_MAX_COUNT: u32 = 100;
+/// Metadata persisted in an operation snapshot is only reused if the snapshot
+/// is younger than this. Offchain lookup responses may expire, so old metadata
+/// is rebuilt instead of failing the dry-run before submission.
+pub const SNAPSHOT_METADATA_MAX_AGE: Duration = Duration::from_secs(60 * 60);
 
 /// Revert string emitted by the ICA router when the originating commit has not
 /// yet been confirmed on-chain. There is no typed error variant for this today —
@@ -84,7 +90,7 @@ pub struct MessageContext {
     /// Origin chain database to verify gas payments.
     pub origin_db: Arc<dyn HyperlaneDb>,
     /// Cache to store commonly used data calls.
-    pub cache: OptionalCache<MeteredCache<LocalCache>>,
+    pub cache: OptionalCache<MeteredCache<CacheBackend>>,
     /// Used to construct the ISM metadata needed to verify a message from the
     /// origin.
     pub metadata_builder: Arc<dyn BuildsBaseMetadata>,
@@ -152,6 +158,11 @@ pub struct PendingMessage {
     #[new(default)]
     #[serde(skip_serializing)]
     ica_reveal_attempts: u32,
+    /// Set when the message was restored from a persisted snapshot and can skip
+    /// preparation after a restart
+    #[new(default)]
+    #[serde(skip_serializing)]
+    restored_from_snapshot: bool,
 }
 
 impl Debug for PendingMessage {
@@ -219,6 +230,7 @@ impl PendingOperation for PendingMessage {
         {
             warn!(message_id = ?self.message.id(), err = %e, status = %status, "Persisting `status` failed for message");
         }
+        self.persist_snapshot(&status);
         self.status = status;
     }
 
@@ -443,11 +455,13 @@ impl PendingOperation for PendingMessage {
             metadata,
             gas_limit,
         }));
+        record_handoff(&format!("{:?}", self.id()));
         PendingOperationResult::Success
     }
 
     #[instrument(skip(self), fields(id=?self.id(), domain=%self.destination_domain()))]
     async fn submit(&mut self) -> PendingOperationResult {
+        link_handoff(&format!("{:?}", self.id()));
         if self.submitted {
             // this message has already been submitted, possibly not by us
             return PendingOperationResult::Success;
@@ -624,6 +638,10 @@ impl PendingOperation for PendingMessage {
         Some(self.ctx.destination_mailbox.clone())
     }
 
+    fn is_restored_from_snapshot(&self) -> bool {
+        self.restored_from_snapshot
+    }
+
     fn get_metric(&self) -> Option<Arc<IntGauge>> {
         self.metric.clone()
     }
@@ -661,6 +679,8 @@ impl PendingOperation for PendingMessage {
 
 impl PendingMessage {
     /// Constructor that tries reading the retry count from the HyperlaneDB in order to recompute the `next_attempt_after`.
+    /// If a snapshot of the message was persisted, the message is restored from it so that it
+    /// can skip the stages it already went through.
     /// If the message has been retried more than `max_retries`, it will return `None`.
     /// In case of failure, behaves like `Self::new(...)`.
     pub fn maybe_from_persisted_retries(
@@ -682,9 +702,115 @@ impl PendingMessage {
             pending_message.num_retries = num_retries;
             pending_message.next_attempt_after = next_attempt_after;
         }
+        if let Some(snapshot) = Self::get_snapshot(
+            pending_message.ctx.origin_db.clone(),
+            &pending_message.message,
+        ) {
+            pending_message.restore_from_snapshot(snapshot, unix_timestamp_now());
+        }
         Some(pending_message)
     }
 
+    fn get_snapshot(
+        origin_db: Arc<dyn HyperlaneDb>,
+        message: &HyperlaneMessage,
+    ) -> Option<PendingOperationSnapshot> {
+        match origin_db.retrieve_operation_snapshot_by_message_id(&message.id()) {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                warn!(message_id = ?message.id(), err = %e, "Failed to read operation snapshot from HyperlaneDB for message");
+                None
+            }
+        }
+    }
+
+    /// Restore the state captured in `snapshot`. Messages that were ready to be
+    /// submitted get their submission data back if their metadata is recent
+    /// enough, messages that were awaiting confirmation are marked as submitted.
+    /// Everything else is prepared again.
+    fn restore_from_snapshot(&mut self, snapshot: PendingOperationSnapshot, now: u64) {
+        // The status is persisted alongside every snapshot, so a mismatch means the
+        // snapshot is outdated
+        if snapshot.status != self.status {
+            debug!(
+                message_id = ?self.message.id(),
+                snapshot_status = %snapshot.status,
+                status = %self.status,
+                "Ignoring outdated operation snapshot"
+            );
+            return;
+        }
+
+        if snapshot.num_retries == self.num_retries {
+            self.next_attempt_after = snapshot.next_attempt_at.and_then(|at| {
+                Instant::now().checked_add(Duration::from_secs(at.saturating_sub(now)))
+            });
+        }
+
+        let metadata_age = Duration::from_secs(now.saturating_sub(snapshot.taken_at));
+        if metadata_age <= SNAPSHOT_METADATA_MAX_AGE {
+            self.metadata = snapshot.metadata.map(Metadata::new);
+        }
+
+        match (&snapshot.status, &self.metadata, snapshot.gas_limit) {
+            (PendingOperationStatus::ReadyToSubmit, Some(metadata), Some(gas_limit)) => {
+                self.submission_data = Some(Box::new(MessageSubmissionData {
+                    metadata: metadata.clone(),
+                    gas_limit,
+                }));
+                self.restored_from_snapshot = true;
+            }
+            (PendingOperationStatus::Confirm(_), _, _) => {
+                self.submitted = true;
+                self.restored_from_snapshot = true;
+            }
+            _ => {}
+        }
+    }
+
+    /// Persist a snapshot of the message state, so that it can be restored into
+    /// the right queue after a restart. Only the statuses which let the message
+    /// skip preparation are snapshotted, a snapshot taken with another status
+    /// than the persisted one is ignored on restore.
+    fn persist_snapshot(&self, status: &PendingOperationStatus) {
+        if !matches!(
+            status,
+            PendingOperationStatus::ReadyToSubmit | PendingOperationStatus::Confirm(_)
+        ) {
+            return;
+        }
+        let now = unix_timestamp_now();
+        let next_attempt_at = self
+            .next_attempt_after
+            .map(|at| now.saturating_add(at.saturating_duration_since(Instant::now()).as_secs()));
+        let gas_limit = match status {
+            PendingOperationStatus::ReadyToSubmit => {
+                self.submission_data.as_ref().map(|data| data.gas_limit)
+            }
+            _ => None,
+        };
+        // the metadata is only needed to submit the message again
+        let metadata = match status {
+            PendingOperationStatus::ReadyToSubmit => self.metadata.as_ref().map(|m| m.to_owned()),
+            _ => None,
+        };
+        let snapshot = PendingOperationSnapshot {
+            status: status.clone(),
+            num_retries: self.num_retries,
+            next_attempt_at,
+            taken_at: now,
+            metadata,
+            gas_limit,
+        };
+        if let Err(e) = self
+            .ctx
+            .origin_db
+            .store_operation_snapshot_by_message_id(&self.message.id(), &snapshot)
+        {
+            warn!(message_id = ?self.message.id(), err = %e, "Persisting operation snapshot failed for message");
+        }
+    }
+
     /// Set fail-fast mode: drop the message immediately when `num_retries` exceeds
     /// `max_retries` rather than parking it in the final long-backoff arm.
     /// Use this for relay API messages where a small retry budget must be enforced strictly.
@@ -1017,6 +1143,14 @@ impl PendingMessage {
         self.ctx
             .origin_db
             .store_processed_by_nonce(&self.message.nonce, &true)?;
+        // a delivered message is never restored
+        if let Err(e) = self
+            .ctx
+            .origin_db
+            .delete_operation_snapshot_by_message_id(&self.message.id())
+        {
+            warn!(message_id = ?self.message.id(), err = %e, "Deleting operation snapshot failed for message");
+        }
         self.ctx.metrics.update_nonce(&self.message);
         self.ctx.metrics.messages_processed.inc();
         Ok(())
@@ -1241,6 +1375,13 @@ impl PendingMessage {
     }
 }
 
+fn unix_timestamp_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod test {
     use std::{
@@ -1249,7 +1390,6 @@ mod test {
     };
 
     use chrono::TimeDelta;
-    use hyperlane_base::tests::mock_hyperlane_db::MockHyperlaneDb as MockDb;
     use hyperlane_base::{cache::OptionalCache, db::*};
     use hyperlane_core::*;
 
@@ -1344,10 +1484,16 @@ mod test {
         format!("{hours}:{minutes}:{seconds}")
     }
 
-    fn dummy_db_with_retries(retries: u32) -> MockDb {
-        let mut db = MockDb::new();
-        db.expect_retrieve_pending_message_retry_count_by_message_id()
-            .returning(move |_| Ok(Some(retries)));
+    fn dummy_db_with_retries(retries: u32) -> HyperlaneRocksDB {
+        let db = HyperlaneRocksDB::new(
+            &hyperlane_core::test_utils::dummy_domain(0, "dummy_domain"),
+            DB::in_memory(),
+        );
+        db.store_pending_message_retry_count_by_message_id(
+            &HyperlaneMessage::default().id(),
+            &retries,
+        )
+        .unwrap();
         db
     }
 
@@ -1517,6 +1663,166 @@ mod test {
         assert_eq!(db_status, expected_status);
     }
 
+    fn dummy_pending_message(status: PendingOperationStatus) -> PendingMessage {
+        let origin_domain = HyperlaneDomain::Known(hyperlane_core::KnownHyperlaneDomain::Arbitrum);
+        let destination_domain =
+            HyperlaneDomain::Known(hyperlane_core::KnownHyperlaneDomain::Arbitrum);
+        let cache = OptionalCache::new(None);
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = DB::from_path(temp_dir.path()).unwrap();
+        let base_db = HyperlaneRocksDB::new(&origin_domain, db);
+
+        let message = HyperlaneMessage {
+            nonce: 0,
+            origin: KnownHyperlaneDomain::Arbitrum as u32,
+            destination: KnownHyperlaneDomain::Arbitrum as u32,
+            ..Default::default()
+        };
+
+        let base_metadata_builder =
+            dummy_metadata_builder(&origin_domain, &destination_domain, &base_db, cache.clone());
+        let message_context =
+            dummy_message_context(Arc::new(base_metadata_builder), &base_db, cache);
+
+        PendingMessage::new(
+            message,
+            Arc::new(message_context),
+            status,
+            Some(format!("test-{}", 0)),
+            2,
+        )
+    }
+
+    fn reload(pending_message: &PendingMessage) -> PendingMessage {
+        PendingMessage::maybe_from_persisted_retries(
+            pending_message.message.clone(),
+            pending_message.ctx.clone(),
+            pending_message.app_context.clone(),
+            pending_message.max_retries,
+        )
+        .expect("Message should not be skipped")
+    }
+
+    #[tokio::test]
+    async fn ready_to_submit_message_is_restored_from_snapshot() {
+        let mut pending_message =
+            dummy_pending_message(PendingOperationStatus::FirstPrepareAttempt);
+        let metadata = Metadata::new(vec![1, 2, 3]);
+        pending_message.metadata = Some(metadata.clone());
+        pending_message.submission_data = Some(Box::new(MessageSubmissionData {
+            metadata: metadata.clone(),
+            gas_limit: U256::from(150_000),
+        }));
+        pending_message.set_status(PendingOperationStatus::ReadyToSubmit);
+
+        let restored = reload(&pending_message);
+
+        assert!(restored.is_restored_from_snapshot());
+        assert_eq!(restored.status(), PendingOperationStatus::ReadyToSubmit);
+        let submission_data = restored
+            .submission_data
+            .expect("Submission data not restored");
+        assert_eq!(submission_data.metadata, metadata);
+        assert_eq!(submission_data.gas_limit, U256::from(150_000));
+    }
+
+    #[tokio::test]
+    async fn submitted_message_is_restored_into_confirm() {
+        let mut pending_message = dummy_pending_message(PendingOperationStatus::ReadyToSubmit);
+        pending_message.set_next_attempt_after(Duration::from_secs(600));
+        pending_message.set_status(PendingOperationStatus::Confirm(
+            ConfirmReason::SubmittedBySelf,
+        ));
+
+        let restored = reload(&pending_message);
+
+        assert!(restored.is_restored_from_snapshot());
+        assert!(restored.submitted);
+        let next_attempt_in = restored
+            .next_attempt_after
+            .expect("Next attempt not restored")
+            .saturating_duration_since(Instant::now());
+        assert!(next_attempt_in > Duration::from_secs(590));
+    }
+
+    #[tokio::test]
+    async fn message_without_snapshot_is_prepared_again() {
+        let pending_message = dummy_pending_message(PendingOperationStatus::FirstPrepareAttempt);
+        // the status was persisted by a relayer which did not take snapshots
+        pending_message
+            .ctx
+            .origin_db
+            .store_status_by_message_id(
+                &pending_message.id(),
+                &PendingOperationStatus::ReadyToSubmit,
+            )
+            .unwrap();
+
+        let restored = reload(&pending_message);
+
+        assert!(!restored.is_restored_from_snapshot());
+        assert!(restored.submission_data.is_none());
+    }
+
+    #[tokio::test]
+    async fn old_snapshot_metadata_is_not_reused() {
+        let mut pending_message = dummy_pending_message(PendingOperationStatus::ReadyToSubmit);
+        // the snapshot was taken two hours ago
+        let now = 1_700_007_200;
+        let snapshot = PendingOperationSnapshot {
+            status: PendingOperationStatus::ReadyToSubmit,
+            num_retries: 0,
+            next_attempt_at: None,
+            taken_at: 1_700_000_000,
+            metadata: Some(vec![1, 2, 3]),
+            gas_limit: Some(U256::from(150_000)),
+        };
+
+        pending_message.restore_from_snapshot(snapshot, now);
+
+        assert!(!pending_message.is_restored_from_snapshot());
+        assert!(pending_message.metadata.is_none());
+        assert!(pending_message.submission_data.is_none());
+    }
+
+    #[tokio::test]
+    async fn snapshot_is_not_persisted_for_retried_message() {
+        let mut pending_message =
+            dummy_pending_message(PendingOperationStatus::FirstPrepareAttempt);
+        pending_message.set_status(PendingOperationStatus::Retry(
+            ReprepareReason::CouldNotFetchMetadata,
+        ));
+
+        let snapshot = pending_message
+            .ctx
+            .origin_db
+            .retrieve_operation_snapshot_by_message_id(&pending_message.id())
+            .unwrap();
+
+        assert!(snapshot.is_none());
+    }
+
+    #[tokio::test]
+    async fn snapshot_is_deleted_on_delivery() {
+        let mut pending_message = dummy_pending_message(PendingOperationStatus::ReadyToSubmit);
+        pending_message.set_status(PendingOperationStatus::Confirm(
+            ConfirmReason::SubmittedBySelf,
+        ));
+        let origin_db = pending_message.ctx.origin_db.clone();
+        assert!(origin_db
+            .retrieve_operation_snapshot_by_message_id(&pending_message.id())
+            .unwrap()
+            .is_some());
+
+        pending_message.record_message_process_success().unwrap();
+
+        assert!(origin_db
+            .retrieve_operation_snapshot_by_message_id(&pending_message.id())
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn check_debug_print() {
         let origin_domain = HyperlaneDomain::Known(hyperlane_core::KnownHyperlaneDomain::Arbitrum);
@@ -24,8 +24,8 @@ use tracing::{debug, error, info, info_span, Instrument};
 
 use hyperlane_base::{
     broadcast::BroadcastMpscSender,
-    cache::{LocalCache, MeteredCache, MeteredCacheConfig, OptionalCache},
-    db::{HyperlaneRocksDB, DB},
+    cache::{CacheBackend, DbCache, MeteredCache, MeteredCacheConfig, OptionalCache},
+    db::{DbPruner, DbPruningMetrics, HyperlaneRocksDB, DB},
     metrics::{AgentMetrics, ChainSpecificMetricsUpdater},
     settings::IndexSettings,
     AgentMetadata, BaseAgent, ChainMetrics, ContractSyncMetrics, ContractSyncer, CoreMetrics,
@@ -42,7 +42,12 @@ use crate::relay_api::{
     handlers::{RateLimiter, ServerState as RelayApiState, TxHashCache},
     RelayApiMetrics,
 };
-use crate::{db_loader::DbLoader, relayer::origin::Origin, server::ENDPOINT_MESSAGES_QUEUE_SIZE};
+use crate::{
+    balance_manager::{BalanceGuard, BalanceManager},
+    db_loader::DbLoader,
+    relayer::origin::Origin,
+    server::ENDPOINT_MESSAGES_QUEUE_SIZE,
+};
 use crate::{
     db_loader::DbLoaderExt,
     merkle_tree::db_loader::{MerkleTreeDbLoader, MerkleTreeDbLoaderMetrics},
@@ -88,7 +93,7 @@ pub struct Relayer {
     /// sent between
     msg_ctxs: HashMap<ContextKey, Arc<MessageContext>>,
     /// The original reference to the relayer cache
-    _cache: OptionalCache<MeteredCache<LocalCache>>,
+    _cache: OptionalCache<MeteredCache<CacheBackend>>,
     message_whitelist: Arc<MatchingList>,
     message_blacklist: Arc<MatchingList>,
     address_blacklist: Arc<AddressBlacklist>,
@@ -102,6 +107,12 @@ pub struct Relayer {
     relay_api_rate_limit_max_requests: Option<usize>,
     relay_api_rate_limit_window_secs: Option<u64>,
     relay_api_cors_origins: Vec<String>,
+    /// Balance managers of the destinations with balance guardrails
+    balance_managers: HashMap<HyperlaneDomain, BalanceManager>,
+    /// Prunes delivered messages from the database, if a retention is set
+    db_pruner: Option<DbPruner>,
+    /// Removes expired entries of the call cache, if kept in the database
+    cache_sweeper: Option<DbCache>,
     core_metrics: Arc<CoreMetrics>,
     // TODO: decide whether to consolidate `agent_metrics` and `chain_metrics` into a single struct
     // or move them in `core_metrics`, like the validator metrics
@@ -160,12 +171,24 @@ impl BaseAgent for Relayer {
 
         let core = settings.build_hyperlane_core(core_metrics.clone());
 
+        let column_families = if settings.allow_contract_call_caching {
+            settings.contract_call_cache.column_families()
+        } else {
+            &[]
+        };
+        let db = DB::open(settings.db_backend, &settings.db, column_families)?;
+
         let mut start_entity_init = Instant::now();
 
         let cache_name = "relayer_cache";
+        let mut cache_sweeper = None;
         let inner_cache = if settings.allow_contract_call_caching {
+            let backend = CacheBackend::new(&settings.contract_call_cache, cache_name, &db)?;
+            if let CacheBackend::Db(cache) = &backend {
+                cache_sweeper = Some(cache.clone());
+            }
             Some(MeteredCache::new(
-                LocalCache::new(cache_name),
+                backend,
                 core_metrics.cache_metrics(),
                 MeteredCacheConfig {
                     cache_name: cache_name.to_owned(),
@@ -177,8 +200,6 @@ impl BaseAgent for Relayer {
         let cache = OptionalCache::new(inner_cache);
         debug!(elapsed = ?start_entity_init.elapsed(), event = "initialized cache", "Relayer startup duration measurement");
 
-        let db = DB::from_path(&settings.db)?;
-
         start_entity_init = Instant::now();
         let origins =
             Self::build_origins(&settings, db.clone(), core_metrics.clone(), &chain_metrics).await;
@@ -197,6 +218,43 @@ impl BaseAgent for Relayer {
         .await;
         debug!(elapsed = ?start_entity_init.elapsed(), event = "initialized destination chains", "Relayer startup duration measurement");
 
+        // a destination without its balance manager would keep submitting below
+        // its minimum balance, so the relayer doesn't start without it
+        let mut balance_managers = HashMap::new();
+        for (domain, conf) in &settings.balance_guardrails {
+            let Some(destination) = destinations.get(domain) else {
+                continue;
+            };
+            let balance_manager =
+                BalanceManager::build(&destination.chain_conf, conf.clone(), &core_metrics)
+                    .await
+                    .map_err(|err| {
+                        Self::record_critical_error(
+                            domain,
+                            &chain_metrics,
+                            &err,
+                            "Failed to build balance manager",
+                        );
+                        err.wrap_err(format!(
+                            "Failed to build balance manager for {}",
+                            domain.name()
+                        ))
+                    })?;
+            balance_managers.insert(domain.clone(), balance_manager);
+        }
+
+        let db_pruner = match settings.db_pruning {
+            Some(conf) => {
+                let origin_dbs = origins
+                    .values()
+                    .map(|origin| origin.database.clone())
+                    .collect();
+                let metrics = DbPruningMetrics::new(&core_metrics)?;
+                Some(DbPruner::new(db.clone(), origin_dbs, conf, metrics))
+            }
+            None => None,
+        };
+
         let message_whitelist = Arc::new(settings.whitelist);
         let message_blacklist = Arc::new(settings.blacklist);
         let address_blacklist = Arc::new(AddressBlacklist::new(settings.address_blacklist));
@@ -304,6 +362,9 @@ impl BaseAgent for Relayer {
             relay_api_rate_limit_max_requests: settings.relay_api_rate_limit_max_requests,
             relay_api_rate_limit_window_secs: settings.relay_api_rate_limit_window_secs,
             relay_api_cors_origins: settings.relay_api_cors_origins,
+            balance_managers,
+            db_pruner,
+            cache_sweeper,
             core_metrics,
             agent_metrics,
             chain_metrics,
@@ -388,6 +449,15 @@ impl BaseAgent for Relayer {
                             .operation_submission_config()
                             .and_then(|c| c.max_submit_queue_length)
                     });
+            let balance_guard = match self.balance_managers.remove(dest_domain) {
+                Some(balance_manager) => {
+                    let guard = balance_manager.guard();
+                    tasks.push(balance_manager.spawn());
+                    guard
+                }
+                None => BalanceGuard::default(),
+            };
+
             let message_processor = MessageProcessor::new(
                 dest_domain.clone(),
                 receive_channel,
@@ -398,6 +468,7 @@ impl BaseAgent for Relayer {
                 task_monitor.clone(),
                 dispatcher_entrypoint,
                 db,
+                balance_guard,
             );
             prep_queues.insert(dest_domain.id(), message_processor.prepare_queue().await);
 
@@ -582,6 +653,14 @@ impl BaseAgent for Relayer {
 
         tasks.push(self.runtime_metrics.spawn());
 
+        if let Some(db_pruner) = self.db_pruner.take() {
+            tasks.push(db_pruner.spawn());
+        }
+
+        if let Some(cache_sweeper) = self.cache_sweeper.take() {
+            tasks.push(cache_sweeper.spawn_sweeper());
+        }
+
         debug!(elapsed = ?start.elapsed(), event = "fully started", "Relayer startup duration measurement");
 
         if let Err(err) = try_join_all(tasks).await {
@@ -1026,6 +1105,7 @@ impl Relayer {
             ADVANCED_LOG_META,
             settings.tx_id_indexing_enabled,
             settings.igp_indexing_enabled,
+            settings.db_pruning.is_some(),
         );
 
         let origin_futures: Vec<_> = settings
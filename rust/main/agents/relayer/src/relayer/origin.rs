@@ -79,6 +79,7 @@ pub struct OriginFactory {
     advanced_log_meta: bool,
     tx_id_indexing_enabled: bool,
     igp_indexing_enabled: bool,
+    db_pruning_enabled: bool,
 }
 
 impl OriginFactory {
@@ -89,6 +90,7 @@ impl OriginFactory {
         advanced_log_meta: bool,
         tx_id_indexing_enabled: bool,
         igp_indexing_enabled: bool,
+        db_pruning_enabled: bool,
     ) -> Self {
         Self {
             db,
@@ -97,6 +99,7 @@ impl OriginFactory {
             advanced_log_meta,
             tx_id_indexing_enabled,
             igp_indexing_enabled,
+            db_pruning_enabled,
         }
     }
 }
@@ -108,7 +111,10 @@ impl Factory for OriginFactory {
         chain_conf: &ChainConf,
         gas_payment_enforcement: Vec<GasPaymentEnforcementConf>,
     ) -> Result<Origin, FactoryError> {
-        let db = HyperlaneRocksDB::new(&domain, self.db.clone());
+        let mut db = HyperlaneRocksDB::new(&domain, self.db.clone());
+        if self.db_pruning_enabled {
+            db = db.with_pruning();
+        }
 
         let validator_announce = {
             let start_entity_init = Instant::now();
@@ -10,7 +10,7 @@ use prometheus::Registry;
 use reqwest::Url;
 use tokio::time::error::Elapsed;
 
-use hyperlane_base::db::DB;
+use hyperlane_base::db::{DbBackendConf, DB};
 use hyperlane_base::settings::{
     ChainConf, ChainConnectionConf, CoreContractAddresses, IndexSettings, Settings, SignerConf,
     TracingConfig,
@@ -24,7 +24,7 @@ use hyperlane_core::{
 use hyperlane_ethereum as h_eth;
 use lander::DispatcherMetrics;
 
-use crate::settings::{matching_list::MatchingList, RelayerSettings};
+use crate::settings::{matching_list::MatchingList, BalanceGuardrailConf, RelayerSettings};
 
 use super::Relayer;
 
@@ -61,6 +61,7 @@ fn generate_test_chain_conf(
     ChainConf {
         domain,
         signer,
+        additional_signers: vec![],
         submitter: Default::default(),
         estimated_block_time: Duration::from_secs_f64(1.1),
         reorg_period: ReorgPeriod::None,
@@ -82,6 +83,9 @@ fn generate_test_chain_conf(
                 ..Default::default()
             },
             consider_null_transaction_receipt: false,
+            private_relay: None,
+            rpc_rate_limits: Default::default(),
+            rpc_fallback: Default::default(),
         }),
         metrics_conf: PrometheusMiddlewareConf {
             contracts: HashMap::new(),
@@ -129,6 +133,7 @@ fn generate_test_relayer_settings(
             tracing: TracingConfig::default(),
         },
         db: db_path.to_path_buf(),
+        db_backend: DbBackendConf::RocksDb,
         origin_chains: origin_chains.iter().cloned().collect(),
         destination_chains: destination_chains.iter().cloned().collect(),
         gas_payment_enforcement: Vec::new(),
@@ -140,6 +145,7 @@ fn generate_test_relayer_settings(
         allow_local_checkpoint_syncers: true,
         metric_app_contexts: Vec::new().into(),
         allow_contract_call_caching: true,
+        contract_call_cache: Default::default(),
         ism_cache_configs: Default::default(),
         max_retries: 1,
         tx_id_indexing_enabled: true,
@@ -149,6 +155,8 @@ fn generate_test_relayer_settings(
         relay_api_rate_limit_max_requests: None,
         relay_api_rate_limit_window_secs: None,
         relay_api_cors_origins: Vec::new(),
+        balance_guardrails: HashMap::new(),
+        db_pruning: None,
     }
 }
 
@@ -590,3 +598,37 @@ async fn test_from_settings_and_run_bad_signer() {
             .is_ok()
     );
 }
+
+#[tracing_test::traced_test]
+#[tokio::test]
+async fn test_from_settings_fails_when_balance_manager_cannot_be_built() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let db_path = temp_dir.path();
+    let arbitrum = HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum);
+    // without a relayer signer, the balance of the relayer can't be checked
+    let chains = vec![(
+        "arbitrum".to_string(),
+        generate_test_chain_conf(arbitrum.clone(), None, "http://localhost:8545"),
+    )];
+    let mut settings = generate_test_relayer_settings(
+        db_path,
+        chains,
+        &[arbitrum.clone()],
+        &[arbitrum.clone()],
+        27008,
+    );
+    settings.balance_guardrails.insert(
+        arbitrum,
+        BalanceGuardrailConf {
+            min_balance: 1000.into(),
+            check_interval: Duration::from_secs(60),
+            top_up: None,
+        },
+    );
+
+    let err = build_relayer(settings).await.unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("Failed to build balance manager for arbitrum"));
+}
@@ -116,6 +116,31 @@ async fn handler(
             )
         })?;
 
+    // a pruned message was delivered long ago and has no body left to relay
+    let pruned = db
+        .retrieve_message_pruned_by_nonce(&message.nonce)
+        .map_err(|err| {
+            let error_msg = "Failed to fetch message";
+            tracing::debug!(domain_id, ?message_id, ?err, "{error_msg}");
+            ServerErrorResponse::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ServerErrorBody {
+                    message: error_msg.to_string(),
+                },
+            )
+        })?
+        .unwrap_or_default();
+    if pruned {
+        let error_msg = "Message was pruned";
+        tracing::debug!(domain_id, ?message_id, "{error_msg}");
+        return Err(ServerErrorResponse::new(
+            StatusCode::CONFLICT,
+            ServerErrorBody {
+                message: error_msg.to_string(),
+            },
+        ));
+    }
+
     let app_context = state
         .msg_ctxs
         .get(&(message.origin, message.destination))
@@ -178,7 +203,7 @@ mod tests {
     use tower::ServiceExt;
 
     use hyperlane_base::{
-        cache::{LocalCache, MeteredCache, MeteredCacheConfig, OptionalCache},
+        cache::{CacheBackend, LocalCache, MeteredCache, MeteredCacheConfig, OptionalCache},
         db::{HyperlaneRocksDB, DB},
     };
     use hyperlane_core::{HyperlaneDomain, HyperlaneMessage, KnownHyperlaneDomain};
@@ -213,7 +238,7 @@ mod tests {
             .collect();
 
         let cache = OptionalCache::new(Some(MeteredCache::new(
-            LocalCache::new("test-cache"),
+            CacheBackend::from(LocalCache::new("test-cache")),
             dummy_cache_metrics(),
             MeteredCacheConfig {
                 cache_name: "test-cache".to_owned(),
@@ -347,4 +372,45 @@ mod tests {
             .len();
         assert_eq!(op_queue_len, 0);
     }
+
+    #[tracing_test::traced_test]
+    #[tokio::test]
+    async fn test_reprocess_message_pruned() {
+        let domains = &[
+            HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum),
+            HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum),
+        ];
+        let TestServerSetup {
+            app,
+            dbs,
+            op_queues,
+        } = setup_test_server(domains);
+
+        let message = HyperlaneMessage {
+            version: 0,
+            nonce: 100,
+            origin: KnownHyperlaneDomain::Arbitrum as u32,
+            sender: H256::from_low_u64_be(100),
+            destination: KnownHyperlaneDomain::Ethereum as u32,
+            recipient: H256::from_low_u64_be(200),
+            body: vec![1, 2, 3],
+        };
+
+        insert_message(&dbs, &domains[0], &message, 1000);
+        let db = dbs.get(&domains[0].id()).unwrap().clone().with_pruning();
+        db.record_message_delivery(message.nonce, 100).unwrap();
+        assert_eq!(db.prune_delivered_messages(u64::MAX, 10).unwrap(), 1);
+
+        let message_id = format!("0x{:x}", message.id());
+        let response = send_request(app, KnownHyperlaneDomain::Arbitrum as u32, message_id).await;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let op_queue_len = op_queues
+            .get(&(KnownHyperlaneDomain::Ethereum as u32))
+            .expect("Queue not found")
+            .lock()
+            .await
+            .len();
+        assert_eq!(op_queue_len, 0);
+    }
 }
@@ -4,22 +4,34 @@
 //! and validations it defines are not applied here, we should mirror them.
 //! ANY CHANGES HERE NEED TO BE REFLECTED IN THE TYPESCRIPT SDK.
 
-use std::{collections::HashSet, ops::Add, path::PathBuf, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Add,
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
 
 use derive_more::{AsMut, AsRef, Deref, DerefMut};
 use ethers::utils::hex;
 use eyre::{eyre, Context};
 use hyperlane_base::{
+    cache::CacheBackendConf,
+    db::{DbBackendConf, DbPruningConf, DEFAULT_PRUNING_BATCH_SIZE, DEFAULT_PRUNING_INTERVAL},
     impl_loadable_from_settings,
     settings::{
-        parser::{parse_json_array, parse_matching_list, RawAgentConf, ValueParser},
-        Settings,
+        parser::{parse_json_array, parse_matching_list, parse_signer, RawAgentConf, ValueParser},
+        schema::{
+            address, boolean, comma_separated_list, integer, matching_list_schema,
+            object_properties, port, signer_schema, RawConfSchema,
+        },
+        Settings, SignerConf,
     },
 };
 use hyperlane_core::{cfg_unwrap_all, config::*, HyperlaneDomain, H160, U256};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Map, Value};
 
 use crate::{
     msg::{metadata::IsmCacheConfig, pending_message::DEFAULT_MAX_MESSAGE_RETRIES},
@@ -39,6 +51,8 @@ pub struct RelayerSettings {
 
     /// Database path
     pub db: PathBuf,
+    /// Where the database is kept
+    pub db_backend: DbBackendConf,
     /// The chain to relay messages from
     pub origin_chains: HashSet<HyperlaneDomain>,
     /// Chains to relay messages to
@@ -65,6 +79,8 @@ pub struct RelayerSettings {
     pub metric_app_contexts: Arc<Vec<(MatchingList, String)>>,
     /// Whether to allow contract call caching at all.
     pub allow_contract_call_caching: bool,
+    /// Where contract call results are cached
+    pub contract_call_cache: CacheBackendConf,
     /// The ISM cache policies to use
     pub ism_cache_configs: Vec<IsmCacheConfig>,
     /// Maximum number of retries per operation
@@ -97,6 +113,49 @@ pub struct RelayerSettings {
     pub relay_api_rate_limit_window_secs: Option<u64>,
     /// Relay API allowed CORS origins (comma-separated). Defaults to https://nexus.hyperlane.xyz.
     pub relay_api_cors_origins: Vec<String>,
+    /// Balance guardrails for the relayer wallet, by destination chain
+    pub balance_guardrails: HashMap<HyperlaneDomain, BalanceGuardrailConf>,
+    /// Retention of delivered messages in the database, kept forever if not set
+    pub db_pruning: Option<DbPruningConf>,
+}
+
+/// Default interval between two checks of the relayer wallet balance
+pub const DEFAULT_BALANCE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// Default minimum time between two top ups of the relayer wallet
+pub const DEFAULT_TOP_UP_MIN_INTERVAL: Duration = Duration::from_secs(3_600);
+/// Default window over which the amount of top ups is limited
+pub const DEFAULT_TOP_UP_WINDOW: Duration = Duration::from_secs(86_400);
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Config for the balance guardrails of the relayer wallet on a destination chain
+#[derive(Debug, Clone)]
+pub struct BalanceGuardrailConf {
+    /// Submission to the destination is paused while the relayer balance is
+    /// below this amount
+    pub min_balance: U256,
+    /// How often the relayer balance is checked
+    pub check_interval: Duration,
+    /// Automatic top up of the relayer wallet, if configured
+    pub top_up: Option<TopUpConf>,
+}
+
+/// Config for topping up the relayer wallet from a funder key on the same chain
+#[derive(Debug, Clone)]
+pub struct TopUpConf {
+    /// The relayer wallet is topped up when its balance is below this amount
+    pub threshold: U256,
+    /// Amount transferred to the relayer wallet with every top up
+    pub amount: U256,
+    /// Minimum time between two top ups
+    pub min_interval: Duration,
+    /// Most that can be transferred within `window`. Top ups stop once it
+    /// is reached, so that a destination burning gas can't drain the funder.
+    pub max_amount_per_window: U256,
+    /// Window over which `max_amount_per_window` applies
+    pub window: Duration,
+    /// Signer of the wallet which funds the top ups
+    pub funder: SignerConf,
 }
 
 /// Config for gas payment enforcement
@@ -138,6 +197,104 @@ struct RawRelayerSettings(Value);
 
 impl_loadable_from_settings!(Relayer, RawRelayerSettings -> RelayerSettings);
 
+impl RawConfSchema for RawRelayerSettings {
+    fn agent_properties() -> Map<String, Value> {
+        let mut properties = object_properties(json!({
+            "relayChains": comma_separated_list(),
+            "db": { "type": "string" },
+            "dbBackend": { "enum": ["rocksdb", "memory"] },
+            "gasPaymentEnforcement": {
+                "type": ["array", "string"],
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "type": { "enum": ["none", "minimum", "onChainFeeQuoting"] },
+                        "payment": integer(),
+                        "minimum": {
+                            "description": "Legacy form of a `minimum` policy, used when `type` is not set",
+                        },
+                        "gasFraction": { "type": "string" },
+                        "feeToken": address(),
+                        "matchingList": matching_list_schema(),
+                    },
+                },
+            },
+            "whitelist": matching_list_schema(),
+            "blacklist": matching_list_schema(),
+            "addressBlacklist": comma_separated_list(),
+            "transactionGasLimit": integer(),
+            "skipTransactionGasLimitFor": comma_separated_list(),
+            "allowLocalCheckpointSyncers": boolean(),
+            "metricAppContexts": {
+                "type": ["array", "string"],
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "matchingList": matching_list_schema(),
+                    },
+                    "required": ["name", "matchingList"],
+                },
+            },
+            "ismCacheConfigs": { "type": ["array", "string"] },
+            "contractCallCache": {
+                "type": "object",
+                "properties": {
+                    "backend": { "enum": ["local", "db", "redis"] },
+                    "url": { "type": "string" },
+                },
+            },
+        }));
+        properties.extend(object_properties(json!({
+            "maxMessageRetries": integer(),
+            "txIdIndexingEnabled": boolean(),
+            "igpIndexingEnabled": boolean(),
+            "relayApiEnabled": boolean(),
+            "relayApiPort": port(),
+            "relayApiRateLimitMaxRequests": integer(),
+            "relayApiRateLimitWindowSecs": integer(),
+            "relayApiCorsOrigins": comma_separated_list(),
+            "balanceGuardrails": {
+                "type": "object",
+                "additionalProperties": {
+                    "type": "object",
+                    "properties": {
+                        "minBalance": integer(),
+                        "checkInterval": integer(),
+                        "topUp": {
+                            "type": "object",
+                            "properties": {
+                                "threshold": integer(),
+                                "amount": integer(),
+                                "minInterval": integer(),
+                                "maxAmountPerWindow": integer(),
+                                "window": integer(),
+                                "funder": signer_schema(),
+                            },
+                            "required": ["threshold", "amount", "funder"],
+                        },
+                    },
+                    "required": ["minBalance"],
+                },
+            },
+            "dbPruning": {
+                "type": "object",
+                "properties": {
+                    "retentionDays": integer(),
+                    "interval": integer(),
+                    "batchSize": integer(),
+                },
+                "required": ["retentionDays"],
+            },
+        })));
+        properties
+    }
+
+    fn agent_required() -> Vec<&'static str> {
+        vec!["relayChains"]
+    }
+}
+
 impl FromRawConf<RawRelayerSettings> for RelayerSettings {
     fn from_config_filtered(
         raw: RawRelayerSettings,
@@ -172,6 +329,12 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             .parse_from_str("Expected database path")
             .unwrap_or_else(|| current_dir.join("hyperlane_db"));
 
+        let db_backend = p
+            .chain(&mut err)
+            .get_opt_key("dbBackend")
+            .and_then(parse_db_backend)
+            .unwrap_or_default();
+
         // is_gas_payment_enforcement_set determines if we should be checking for the correct gas payment enforcement policy has been provided with "gasPaymentEnforcement" key
         let (
             raw_gas_payment_enforcement_path,
@@ -325,8 +488,37 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             .parse_bool()
             .unwrap_or(false);
 
+        let raw_balance_guardrails = p
+            .chain(&mut err)
+            .get_opt_key("balanceGuardrails")
+            .into_obj_iter()
+            .map(|itr| {
+                itr.filter_map(|(chain, guardrail)| {
+                    parse_balance_guardrail(&guardrail, &mut err).map(|conf| (chain, conf))
+                })
+                .collect_vec()
+            })
+            .unwrap_or_default();
+
+        let db_pruning = p
+            .chain(&mut err)
+            .get_opt_key("dbPruning")
+            .end()
+            .and_then(|pruning| parse_db_pruning(&pruning, &mut err));
+
         cfg_unwrap_all!(cwp, err: [base]);
 
+        let balance_guardrails = raw_balance_guardrails
+            .into_iter()
+            .filter_map(|(chain, conf)| {
+                base.lookup_domain(&chain)
+                    .context("Missing configuration for a chain in `balanceGuardrails`")
+                    .into_config_result(|| cwp.add("balance_guardrails").add(&chain))
+                    .take_config_err(&mut err)
+                    .map(|domain| (domain, conf))
+            })
+            .collect();
+
         let skip_transaction_gas_limit_for = skip_transaction_gas_limit_for_names
             .into_iter()
             .filter_map(|chain| {
@@ -388,6 +580,12 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             .and_then(parse_ism_cache_configs)
             .unwrap_or_default();
 
+        let contract_call_cache = p
+            .chain(&mut err)
+            .get_opt_key("contractCallCache")
+            .and_then(parse_cache_backend)
+            .unwrap_or_default();
+
         let max_message_retries = p
             .chain(&mut err)
             .get_opt_key("maxMessageRetries")
@@ -467,6 +665,7 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
         err.into_result(RelayerSettings {
             base,
             db,
+            db_backend,
             origin_chains: relay_chains.clone(),
             destination_chains: relay_chains,
             gas_payment_enforcement,
@@ -478,6 +677,7 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             allow_local_checkpoint_syncers,
             metric_app_contexts,
             allow_contract_call_caching,
+            contract_call_cache,
             ism_cache_configs,
             max_retries: max_message_retries,
             tx_id_indexing_enabled,
@@ -487,8 +687,120 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             relay_api_rate_limit_max_requests,
             relay_api_rate_limit_window_secs,
             relay_api_cors_origins,
+            balance_guardrails,
+            db_pruning,
+        })
+    }
+}
+
+fn parse_balance_guardrail(
+    p: &ValueParser,
+    err: &mut ConfigParsingError,
+) -> Option<BalanceGuardrailConf> {
+    let min_balance = p.chain(err).get_key("minBalance").parse_u256().end();
+
+    let check_interval = p
+        .chain(err)
+        .get_opt_key("checkInterval")
+        .parse_u64()
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_BALANCE_CHECK_INTERVAL);
+
+    let top_up = p.chain(err).get_opt_key("topUp").end().and_then(|top_up| {
+        let threshold = top_up.chain(err).get_key("threshold").parse_u256().end();
+        let amount = top_up.chain(err).get_key("amount").parse_u256().end();
+        let min_interval = top_up
+            .chain(err)
+            .get_opt_key("minInterval")
+            .parse_u64()
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_TOP_UP_MIN_INTERVAL);
+        // a single top up per window unless configured otherwise
+        let max_amount_per_window = top_up
+            .chain(err)
+            .get_opt_key("maxAmountPerWindow")
+            .parse_u256()
+            .end()
+            .or(amount);
+        let window = top_up
+            .chain(err)
+            .get_opt_key("window")
+            .parse_u64()
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_TOP_UP_WINDOW);
+        let funder = top_up
+            .chain(err)
+            .get_key("funder")
+            .and_then(parse_signer)
+            .end();
+
+        if amount.is_some_and(|amount| amount.is_zero()) {
+            err.push((&top_up.cwp).add("amount"), eyre!("must be greater than 0"));
+        }
+        if window.is_zero() {
+            err.push((&top_up.cwp).add("window"), eyre!("must be greater than 0"));
+        }
+        if let (Some(max_amount_per_window), Some(amount)) = (max_amount_per_window, amount) {
+            if max_amount_per_window < amount {
+                err.push(
+                    (&top_up.cwp).add("maxAmountPerWindow"),
+                    eyre!("must not be lower than `amount`"),
+                );
+            }
+        }
+        if let (Some(threshold), Some(min_balance)) = (threshold, min_balance) {
+            if threshold < min_balance {
+                err.push(
+                    (&top_up.cwp).add("threshold"),
+                    eyre!("must not be lower than `minBalance`"),
+                );
+            }
+        }
+
+        Some(TopUpConf {
+            threshold: threshold?,
+            amount: amount?,
+            min_interval,
+            max_amount_per_window: max_amount_per_window?,
+            window,
+            funder: funder?,
         })
+    });
+
+    Some(BalanceGuardrailConf {
+        min_balance: min_balance?,
+        check_interval,
+        top_up,
+    })
+}
+
+fn parse_db_pruning(p: &ValueParser, err: &mut ConfigParsingError) -> Option<DbPruningConf> {
+    let retention_days = p.chain(err).get_key("retentionDays").parse_u64().end();
+    if retention_days == Some(0) {
+        err.push(
+            (&p.cwp).add("retentionDays"),
+            eyre!("must be greater than 0"),
+        );
     }
+
+    let interval = p
+        .chain(err)
+        .get_opt_key("interval")
+        .parse_u64()
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_PRUNING_INTERVAL);
+
+    let batch_size = p
+        .chain(err)
+        .get_opt_key("batchSize")
+        .parse_u32()
+        .unwrap_or(DEFAULT_PRUNING_BATCH_SIZE);
+
+    Some(DbPruningConf {
+        retention: Duration::from_secs(retention_days?.saturating_mul(SECONDS_PER_DAY)),
+        interval,
+        batch_size,
+    })
 }
 
 fn parse_ism_cache_configs(p: ValueParser) -> ConfigResult<Vec<IsmCacheConfig>> {
@@ -507,6 +819,48 @@ fn parse_ism_cache_configs(p: ValueParser) -> ConfigResult<Vec<IsmCacheConfig>>
     err.into_result(ml)
 }
 
+fn parse_db_backend(p: ValueParser) -> ConfigResult<DbBackendConf> {
+    let backend = p.parse_string()?;
+    match backend.to_lowercase().as_str() {
+        "rocksdb" => Ok(DbBackendConf::RocksDb),
+        "memory" => Ok(DbBackendConf::Memory),
+        _ => Err(eyre!(
+            "Unknown database backend `{backend}`, expected `rocksdb` or `memory`"
+        ))
+        .into_config_result(|| p.cwp.clone()),
+    }
+}
+
+fn parse_cache_backend(p: ValueParser) -> ConfigResult<CacheBackendConf> {
+    let mut err = ConfigParsingError::default();
+
+    let backend = p
+        .chain(&mut err)
+        .get_opt_key("backend")
+        .parse_string()
+        .unwrap_or("local");
+    let conf = match backend.to_lowercase().as_str() {
+        "local" => CacheBackendConf::Local,
+        "db" => CacheBackendConf::Db,
+        "redis" => p
+            .chain(&mut err)
+            .get_key("url")
+            .parse_from_str("Invalid redis url")
+            .end()
+            .map(|url| CacheBackendConf::Redis { url })
+            .unwrap_or_default(),
+        _ => {
+            err.push(
+                &p.cwp + "backend",
+                eyre!("Unknown cache backend `{backend}`, expected `local`, `db` or `redis`"),
+            );
+            CacheBackendConf::Local
+        }
+    };
+
+    err.into_result(conf)
+}
+
 fn parse_address_list(
     str: &str,
     err: &mut ConfigParsingError,
@@ -526,6 +880,7 @@ fn parse_address_list(
 #[cfg(test)]
 mod test {
     use super::*;
+    use hyperlane_base::settings::schema::describes_key;
     use hyperlane_core::H160;
     use serde_json::json;
 
@@ -618,6 +973,28 @@ mod test {
         assert_eq!(configs.len(), 2);
     }
 
+    #[test]
+    fn test_parse_cache_backend() {
+        let parse = |value: Value| {
+            let p = ValueParser::new(ConfigPath::default(), &value);
+            parse_cache_backend(p)
+        };
+
+        assert_eq!(parse(json!({})).unwrap(), CacheBackendConf::Local);
+        assert_eq!(
+            parse(json!({ "backend": "db" })).unwrap(),
+            CacheBackendConf::Db
+        );
+        assert_eq!(
+            parse(json!({ "backend": "redis", "url": "redis://localhost:6379/1" })).unwrap(),
+            CacheBackendConf::Redis {
+                url: "redis://localhost:6379/1".parse().unwrap()
+            }
+        );
+        assert!(parse(json!({ "backend": "redis" })).is_err());
+        assert!(parse(json!({ "backend": "memcached" })).is_err());
+    }
+
     #[test]
     fn fee_token_policy_rejects_non_zero_fee_token() {
         let settings = parse_settings(json!({
@@ -663,4 +1040,298 @@ mod test {
         }))
         .expect("zero feeToken should parse");
     }
+
+    #[test]
+    fn test_parse_db_pruning() {
+        let chains = json!({ "legacy": chain_config("legacy", 1000) });
+        let settings = parse_settings(json!({
+            "relaychains": "legacy",
+            "chains": chains.clone(),
+        }))
+        .expect("settings without pruning should parse");
+        assert_eq!(settings.db_pruning, None);
+
+        let settings = parse_settings(json!({
+            "relaychains": "legacy",
+            "chains": chains.clone(),
+            "dbpruning": { "retentiondays": 30, "batchsize": 500 },
+        }))
+        .expect("db pruning should parse");
+        assert_eq!(
+            settings.db_pruning,
+            Some(DbPruningConf {
+                retention: Duration::from_secs(30 * 86_400),
+                interval: DEFAULT_PRUNING_INTERVAL,
+                batch_size: 500,
+            })
+        );
+
+        assert!(parse_settings(json!({
+            "relaychains": "legacy",
+            "chains": chains,
+            "dbpruning": { "retentiondays": 0 },
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_db_backend() {
+        let chains = json!({ "legacy": chain_config("legacy", 1000) });
+        let settings = parse_settings(json!({
+            "relaychains": "legacy",
+            "chains": chains.clone(),
+        }))
+        .expect("settings without a db backend should parse");
+        assert_eq!(settings.db_backend, DbBackendConf::RocksDb);
+
+        let settings = parse_settings(json!({
+            "relaychains": "legacy",
+            "chains": chains.clone(),
+            "dbbackend": "memory",
+        }))
+        .expect("memory db backend should parse");
+        assert_eq!(settings.db_backend, DbBackendConf::Memory);
+
+        assert!(parse_settings(json!({
+            "relaychains": "legacy",
+            "chains": chains,
+            "dbbackend": "postgres",
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_balance_guardrails() {
+        let settings = parse_settings(json!({
+            "relaychains": "legacy,other",
+            "chains": {
+                "legacy": chain_config("legacy", 1000),
+                "other": chain_config("other", 2000),
+            },
+            "balanceguardrails": {
+                "legacy": {
+                    "minbalance": "1000",
+                    "checkinterval": 30,
+                    "topup": {
+                        "threshold": "5000",
+                        "amount": "10000",
+                        "mininterval": 600,
+                        "maxamountperwindow": "30000",
+                        "window": 3600,
+                        "funder": {
+                            "type": "hexKey",
+                            "key": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        },
+                    },
+                },
+                "other": {
+                    "minbalance": "1",
+                },
+            },
+        }))
+        .expect("balance guardrails should parse");
+
+        let legacy = settings
+            .balance_guardrails
+            .iter()
+            .find(|(domain, _)| domain.id() == 1000)
+            .map(|(_, conf)| conf)
+            .unwrap();
+        assert_eq!(legacy.min_balance, U256::from(1000));
+        assert_eq!(legacy.check_interval, Duration::from_secs(30));
+        let top_up = legacy.top_up.as_ref().unwrap();
+        assert_eq!(top_up.threshold, U256::from(5000));
+        assert_eq!(top_up.amount, U256::from(10000));
+        assert_eq!(top_up.min_interval, Duration::from_secs(600));
+        assert_eq!(top_up.max_amount_per_window, U256::from(30000));
+        assert_eq!(top_up.window, Duration::from_secs(3600));
+        assert!(matches!(top_up.funder, SignerConf::HexKey { .. }));
+
+        let other = settings
+            .balance_guardrails
+            .iter()
+            .find(|(domain, _)| domain.id() == 2000)
+            .map(|(_, conf)| conf)
+            .unwrap();
+        assert_eq!(other.min_balance, U256::from(1));
+        assert_eq!(other.check_interval, DEFAULT_BALANCE_CHECK_INTERVAL);
+        assert!(other.top_up.is_none());
+    }
+
+    #[test]
+    fn test_top_up_threshold_below_min_balance_is_rejected() {
+        let settings = parse_settings(json!({
+            "relaychains": "legacy",
+            "chains": {
+                "legacy": chain_config("legacy", 1000),
+            },
+            "balanceguardrails": {
+                "legacy": {
+                    "minbalance": "1000",
+                    "topup": {
+                        "threshold": "500",
+                        "amount": "10000",
+                        "funder": {
+                            "type": "hexKey",
+                            "key": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        },
+                    },
+                },
+            },
+        }));
+
+        let error = settings
+            .expect_err("threshold below minBalance must reject")
+            .to_string();
+        assert!(
+            error.contains("must not be lower than `minBalance`"),
+            "unexpected error: {error}",
+        );
+    }
+
+    #[test]
+    fn test_top_up_limits_default_to_one_top_up_per_day() {
+        let settings = parse_settings(json!({
+            "relaychains": "legacy",
+            "chains": {
+                "legacy": chain_config("legacy", 1000),
+            },
+            "balanceguardrails": {
+                "legacy": {
+                    "minbalance": "1000",
+                    "topup": {
+                        "threshold": "5000",
+                        "amount": "10000",
+                        "funder": {
+                            "type": "hexKey",
+                            "key": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        },
+                    },
+                },
+            },
+        }))
+        .expect("balance guardrails should parse");
+
+        let top_up = settings
+            .balance_guardrails
+            .values()
+            .next()
+            .and_then(|conf| conf.top_up.as_ref())
+            .unwrap();
+        assert_eq!(top_up.min_interval, DEFAULT_TOP_UP_MIN_INTERVAL);
+        assert_eq!(top_up.max_amount_per_window, U256::from(10000));
+        assert_eq!(top_up.window, DEFAULT_TOP_UP_WINDOW);
+    }
+
+    #[test]
+    fn test_top_up_limit_below_amount_is_rejected() {
+        let settings = parse_settings(json!({
+            "relaychains": "legacy",
+            "chains": {
+                "legacy": chain_config("legacy", 1000),
+            },
+            "balanceguardrails": {
+                "legacy": {
+                    "minbalance": "1000",
+                    "topup": {
+                        "threshold": "5000",
+                        "amount": "10000",
+                        "maxamountperwindow": "5000",
+                        "funder": {
+                            "type": "hexKey",
+                            "key": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        },
+                    },
+                },
+            },
+        }));
+
+        let error = settings
+            .expect_err("maxAmountPerWindow below amount must reject")
+            .to_string();
+        assert!(
+            error.contains("must not be lower than `amount`"),
+            "unexpected error: {error}",
+        );
+    }
+
+    #[test]
+    fn test_balance_guardrails_for_unknown_chain_are_rejected() {
+        let settings = parse_settings(json!({
+            "relaychains": "legacy",
+            "chains": {
+                "legacy": chain_config("legacy", 1000),
+            },
+            "balanceguardrails": {
+                "unknown": {
+                    "minbalance": "1000",
+                },
+            },
+        }));
+
+        assert!(settings.is_err());
+    }
+
+    #[test]
+    fn test_config_schema() {
+        use hyperlane_base::LoadableFromSettings;
+
+        let schema = RelayerSettings::config_schema("relayer");
+        assert_eq!(schema["required"], json!(["relayChains"]));
+
+        // The loader matches keys case-insensitively
+        let properties: HashSet<String> = schema["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|key| key.to_lowercase())
+            .collect();
+        for key in [
+            "chains",
+            "defaultsigner",
+            "relaychains",
+            "db",
+            "gaspaymentenforcement",
+            "balanceguardrails",
+        ] {
+            assert!(properties.contains(key), "missing `{key}`");
+        }
+    }
+
+    /// Every key the relayer reads must be described by its schema
+    #[test]
+    fn test_config_schema_describes_parsed_keys() {
+        use hyperlane_base::{settings::parser::record_parsed_keys, LoadableFromSettings};
+
+        let (settings, keys) = record_parsed_keys(|| {
+            parse_settings(json!({
+                "relaychains": "legacy",
+                "chains": {
+                    "legacy": chain_config("legacy", 1000),
+                },
+                "db": "/tmp/relayer",
+                "dbbackend": "rocksdb",
+                "gaspaymentenforcement": [
+                    { "minimum": { "payment": "1" }, "payment": "1" },
+                    { "type": "onChainFeeQuoting", "gasfraction": "1/2", "matchinglist": [] },
+                ],
+                "whitelist": [],
+                "blacklist": [],
+                "addressblacklist": "0x01",
+                "transactiongaslimit": "1000000",
+                "skiptransactiongaslimitfor": "1000",
+                "metricappcontexts": [{ "name": "app", "matchinglist": [] }],
+                "contractcallcache": { "backend": "local" },
+                "dbpruning": { "retentiondays": 30, "interval": 60, "batchsize": 500 },
+                "balanceguardrails": { "legacy": { "minbalance": "1" } },
+            }))
+        });
+        settings.expect("settings should parse");
+
+        let schema = RelayerSettings::config_schema("relayer");
+        assert!(!keys.is_empty());
+        for key in keys {
+            assert!(describes_key(&schema, &key), "`{key}` is not in the schema");
+        }
+    }
 }
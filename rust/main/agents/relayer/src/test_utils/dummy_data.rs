@@ -5,7 +5,7 @@ use prometheus::{CounterVec, IntCounter, IntCounterVec, IntGauge, Opts, Registry
 use tokio::sync::RwLock;
 
 use hyperlane_base::{
-    cache::{LocalCache, MeteredCache, OptionalCache},
+    cache::{CacheBackend, MeteredCache, OptionalCache},
     db::HyperlaneRocksDB,
     settings::{ChainConf, ChainConnectionConf, Settings},
     CoreMetrics,
@@ -31,6 +31,7 @@ pub fn dummy_chain_conf(domain: &HyperlaneDomain) -> ChainConf {
     ChainConf {
         domain: domain.clone(),
         signer: Default::default(),
+        additional_signers: vec![],
         submitter: Default::default(),
         estimated_block_time: Duration::from_secs_f64(1.1),
         reorg_period: Default::default(),
@@ -42,6 +43,9 @@ pub fn dummy_chain_conf(domain: &HyperlaneDomain) -> ChainConf {
             transaction_overrides: Default::default(),
             op_submission_config: Default::default(),
             consider_null_transaction_receipt: false,
+            private_relay: None,
+            rpc_rate_limits: Default::default(),
+            rpc_fallback: Default::default(),
         }),
         metrics_conf: Default::default(),
         index: Default::default(),
@@ -56,7 +60,7 @@ pub fn dummy_metadata_builder(
     origin_domain: &HyperlaneDomain,
     destination_domain: &HyperlaneDomain,
     db: &HyperlaneRocksDB,
-    cache: OptionalCache<MeteredCache<LocalCache>>,
+    cache: OptionalCache<MeteredCache<CacheBackend>>,
 ) -> BaseMetadataBuilder {
     let mut settings = Settings::default();
     let domains = HashMap::from([
@@ -117,7 +121,7 @@ pub fn dummy_submission_metrics() -> MessageSubmissionMetrics {
 pub fn dummy_message_context(
     base_metadata_builder: Arc<BaseMetadataBuilder>,
     db: &HyperlaneRocksDB,
-    cache: OptionalCache<MeteredCache<LocalCache>>,
+    cache: OptionalCache<MeteredCache<CacheBackend>>,
 ) -> MessageContext {
     MessageContext {
         destination_mailbox: Arc::new(MockMailboxContract::new_with_default_ism(H256::zero())),
@@ -6,6 +6,7 @@ use std::{
 };
 
 use async_trait::async_trait;
+use axum::Router;
 use derive_more::AsRef;
 use futures::{future::try_join_all, FutureExt};
 use hyperlane_core::{
@@ -24,6 +25,7 @@ use hyperlane_base::{
 
 use crate::{
     db::ScraperDb,
+    server::ServerState,
     settings::ScraperSettings,
     store::{HyperlaneDbStore, RawDispatchRetryBackoff},
 };
@@ -47,6 +49,7 @@ pub struct Scraper {
     chain_metrics: ChainMetrics,
     runtime_metrics: RuntimeMetrics,
     raw_dispatch_unenriched_max_age: IntGaugeVec,
+    db: ScraperDb,
 }
 
 #[derive(Debug)]
@@ -107,6 +110,7 @@ impl BaseAgent for Scraper {
             chain_metrics,
             runtime_metrics,
             raw_dispatch_unenriched_max_age,
+            db,
         })
     }
 
@@ -120,9 +124,14 @@ impl BaseAgent for Scraper {
             .settings
             .server(self.core_metrics.clone())
             .expect("Failed to create server");
+        let router = if self.settings.query_api_enabled {
+            ServerState::new(self.db.clone()).router()
+        } else {
+            Router::new()
+        };
         let server_task = tokio::spawn(
             async move {
-                server.run();
+                server.run_with_custom_router(router);
             }
             .instrument(info_span!("Scraper server")),
         );
@@ -688,6 +697,7 @@ mod test {
             ChainConf {
                 domain: HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum),
                 signer: None,
+                additional_signers: vec![],
                 submitter: Default::default(),
                 estimated_block_time: Duration::from_secs_f64(1.1),
                 reorg_period: ReorgPeriod::None,
@@ -738,6 +748,9 @@ mod test {
                         ..Default::default()
                     },
                     consider_null_transaction_receipt: false,
+                    private_relay: None,
+                    rpc_rate_limits: Default::default(),
+                    rpc_fallback: Default::default(),
                 }),
                 metrics_conf: PrometheusMiddlewareConf {
                     contracts: HashMap::new(),
@@ -775,6 +788,7 @@ mod test {
             db: String::new(),
             chains_to_scrape: vec![],
             ccr_routers: HashMap::new(),
+            query_api_enabled: false,
         }
     }
 
@@ -1,19 +1,23 @@
 #![allow(dead_code)] // TODO: `rustc` 1.80.1 clippy issue
 
+use std::collections::HashMap;
+
 use eyre::Result;
 use itertools::Itertools;
 use sea_orm::{
-    prelude::*, ActiveValue::*, DeriveColumn, EnumIter, Insert, QuerySelect, TransactionTrait,
+    prelude::*, sea_query::Query, ActiveValue::*, DeriveColumn, EnumIter, Insert, QueryOrder,
+    QuerySelect, TransactionTrait,
 };
 use tracing::{debug, instrument, trace};
 
 use hyperlane_core::{
     address_to_bytes, bytes_to_address, h256_to_bytes, Delivery, HyperlaneMessage, LogMeta, H256,
+    H512,
 };
 use migration::OnConflict;
 
 use crate::date_time;
-use crate::db::ScraperDb;
+use crate::db::{Pagination, ScraperDb};
 
 use super::generated::{delivered_message, message};
 
@@ -26,6 +30,35 @@ pub struct StorableDelivery<'a> {
     pub txn_id: i64,
 }
 
+/// Filters for querying dispatched messages. Unset filters match everything.
+#[derive(Debug, Clone, Default)]
+pub struct MessageFilter {
+    pub origin: Option<u32>,
+    pub destination: Option<u32>,
+    pub sender: Option<H256>,
+    pub recipient: Option<H256>,
+    /// Hash of the transaction the message was dispatched in
+    pub origin_tx_hash: Option<H512>,
+    /// Only match messages which were (or were not) delivered
+    pub delivered: Option<bool>,
+}
+
+/// A dispatched message with its delivery, if it was delivered
+#[derive(Debug, Clone)]
+pub struct MessageRecord {
+    pub id: H256,
+    pub message: HyperlaneMessage,
+    pub origin_tx_hash: H512,
+    pub dispatched_at: TimeDateTime,
+    pub delivery: Option<DeliveryRecord>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeliveryRecord {
+    pub destination_tx_hash: H512,
+    pub delivered_at: TimeDateTime,
+}
+
 pub struct StorableMessage<'a> {
     pub msg: HyperlaneMessage,
     pub meta: &'a LogMeta,
@@ -365,6 +398,160 @@ impl ScraperDb {
         );
         Ok(new_dispatch_count)
     }
+
+    /// Get a dispatched message and its delivery by message id.
+    #[instrument(skip(self))]
+    pub async fn retrieve_message_by_id(&self, msg_id: &H256) -> Result<Option<MessageRecord>> {
+        let Some(message) = message::Entity::find()
+            .filter(message::Column::MsgId.eq(h256_to_bytes(msg_id)))
+            .one(&self.0)
+            .await?
+        else {
+            return Ok(None);
+        };
+        Ok(self.message_records(vec![message]).await?.pop())
+    }
+
+    /// Get the dispatched messages matching `filter`, newest first.
+    #[instrument(skip(self))]
+    pub async fn retrieve_messages(
+        &self,
+        filter: &MessageFilter,
+        page: &Pagination,
+    ) -> Result<Vec<MessageRecord>> {
+        let mut query = message::Entity::find();
+        if let Some(origin) = filter.origin {
+            query = query.filter(message::Column::Origin.eq(origin));
+        }
+        if let Some(destination) = filter.destination {
+            query = query.filter(message::Column::Destination.eq(destination));
+        }
+        if let Some(sender) = &filter.sender {
+            query = query.filter(message::Column::Sender.eq(address_to_bytes(sender)));
+        }
+        if let Some(recipient) = &filter.recipient {
+            query = query.filter(message::Column::Recipient.eq(address_to_bytes(recipient)));
+        }
+        if let Some(tx_hash) = &filter.origin_tx_hash {
+            let txn_ids = self.get_txn_ids(std::iter::once(tx_hash)).await?;
+            query = query.filter(message::Column::OriginTxId.is_in(txn_ids.into_values()));
+        }
+        if let Some(delivered) = filter.delivered {
+            let deliveries = Query::select()
+                .column(delivered_message::Column::MsgId)
+                .from(delivered_message::Entity)
+                .to_owned();
+            query = if delivered {
+                query.filter(message::Column::MsgId.in_subquery(deliveries))
+            } else {
+                query.filter(message::Column::MsgId.not_in_subquery(deliveries))
+            };
+        }
+
+        let messages = query
+            .order_by_desc(message::Column::Id)
+            .offset(page.offset)
+            .limit(page.limit)
+            .all(&self.0)
+            .await?;
+        self.message_records(messages).await
+    }
+
+    /// Count dispatched messages per origin and delivered messages per destination.
+    #[instrument(skip(self))]
+    pub async fn retrieve_message_counts(&self) -> Result<(HashMap<u32, u64>, HashMap<u32, u64>)> {
+        let dispatched = message::Entity::find()
+            .select_only()
+            .column(message::Column::Origin)
+            .column_as(message::Column::Id.count(), "count")
+            .group_by(message::Column::Origin)
+            .into_tuple::<(i32, i64)>()
+            .all(&self.0)
+            .await?;
+        let delivered = delivered_message::Entity::find()
+            .select_only()
+            .column(delivered_message::Column::Domain)
+            .column_as(delivered_message::Column::Id.count(), "count")
+            .group_by(delivered_message::Column::Domain)
+            .into_tuple::<(i32, i64)>()
+            .all(&self.0)
+            .await?;
+
+        let to_map = |counts: Vec<(i32, i64)>| {
+            counts
+                .into_iter()
+                .map(|(domain, count)| (domain as u32, count as u64))
+                .collect::<HashMap<_, _>>()
+        };
+        Ok((to_map(dispatched), to_map(delivered)))
+    }
+
+    /// Attach deliveries and transaction hashes to dispatched messages.
+    async fn message_records(&self, messages: Vec<message::Model>) -> Result<Vec<MessageRecord>> {
+        if messages.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let deliveries = delivered_message::Entity::find()
+            .filter(
+                delivered_message::Column::MsgId
+                    .is_in(messages.iter().map(|message| message.msg_id.clone())),
+            )
+            .all(&self.0)
+            .await?
+            .into_iter()
+            .map(|delivery| (delivery.msg_id.clone(), delivery))
+            .collect::<HashMap<_, _>>();
+
+        let txn_ids = messages
+            .iter()
+            .map(|message| message.origin_tx_id)
+            .chain(
+                deliveries
+                    .values()
+                    .map(|delivery| delivery.destination_tx_id),
+            )
+            .unique()
+            .collect_vec();
+        let txn_hashes = self.get_txn_hashes(txn_ids.into_iter()).await?;
+        let txn_hash = |txn_id: i64| {
+            txn_hashes
+                .get(&txn_id)
+                .copied()
+                .ok_or_else(|| eyre::eyre!("Missing transaction {txn_id}"))
+        };
+
+        messages
+            .into_iter()
+            .map(|message| {
+                let delivery = deliveries
+                    .get(&message.msg_id)
+                    .map(|delivery| {
+                        Ok::<_, eyre::Report>(DeliveryRecord {
+                            destination_tx_hash: txn_hash(delivery.destination_tx_id)?,
+                            delivered_at: delivery.time_created,
+                        })
+                    })
+                    .transpose()?;
+                Ok(MessageRecord {
+                    id: H256::from_slice(&message.msg_id),
+                    origin_tx_hash: txn_hash(message.origin_tx_id)?,
+                    dispatched_at: message.time_created,
+                    delivery,
+                    message: HyperlaneMessage {
+                        // We do not write version to the DB.
+                        version: 3,
+                        origin: message.origin as u32,
+                        destination: message.destination as u32,
+                        nonce: message.nonce as u32,
+                        sender: bytes_to_address(message.sender)?,
+                        recipient: bytes_to_address(message.recipient)?,
+                        body: message.msg_body.unwrap_or(Vec::new()),
+                    },
+                })
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
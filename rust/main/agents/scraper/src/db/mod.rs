@@ -10,7 +10,7 @@ use tracing::instrument;
 pub use txn::*;
 
 #[allow(clippy::all)]
-mod generated;
+pub(crate) mod generated;
 
 // These modules implement additional functionality for the ScraperDb
 mod block;
@@ -26,6 +26,13 @@ mod txn;
 #[derive(Debug)]
 pub struct ScraperDb(DbConn);
 
+/// Page of results to return from a query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pagination {
+    pub limit: u64,
+    pub offset: u64,
+}
+
 impl ScraperDb {
     #[instrument]
     pub async fn connect(url: &str) -> Result<Self> {
@@ -1,14 +1,18 @@
+use std::collections::HashMap;
+
 use eyre::{eyre, Result};
 use itertools::Itertools;
-use sea_orm::{prelude::*, ActiveValue::*, Insert, QuerySelect};
+use sea_orm::{prelude::*, ActiveValue::*, Insert, QueryOrder, QuerySelect};
 use tracing::{debug, instrument};
 
-use hyperlane_core::{address_to_bytes, h256_to_bytes, InterchainGasPayment, LogMeta, H256};
+use hyperlane_core::{
+    address_to_bytes, h256_to_bytes, InterchainGasPayment, LogMeta, H256, H512, U256,
+};
 use migration::OnConflict;
 
 use crate::conversions::{decimal_to_u256, u256_to_decimal};
 use crate::date_time;
-use crate::db::ScraperDb;
+use crate::db::{Pagination, ScraperDb};
 
 use super::generated::gas_payment;
 
@@ -21,6 +25,31 @@ pub struct StorablePayment<'a> {
     pub txn_id: i64,
 }
 
+/// Filters for querying gas payments. Unset filters match everything.
+#[derive(Debug, Clone, Default)]
+pub struct PaymentFilter {
+    pub msg_id: Option<H256>,
+    pub origin: Option<u32>,
+    pub destination: Option<u32>,
+}
+
+/// A gas payment with the transaction it was made in
+#[derive(Debug, Clone)]
+pub struct PaymentRecord {
+    pub payment: InterchainGasPayment,
+    pub origin: u32,
+    pub tx_hash: H512,
+    pub paid_at: TimeDateTime,
+}
+
+/// Number and sum of the gas payments made on a domain
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaymentTotals {
+    pub count: u64,
+    pub total_payment: U256,
+    pub total_gas_amount: U256,
+}
+
 impl ScraperDb {
     /// Get the payment associated with a sequence.
     #[instrument(skip(self))]
@@ -146,6 +175,82 @@ impl ScraperDb {
         Ok(new_payments_count)
     }
 
+    /// Get the gas payments matching `filter`, newest first.
+    #[instrument(skip(self))]
+    pub async fn retrieve_payments(
+        &self,
+        filter: &PaymentFilter,
+        page: &Pagination,
+    ) -> Result<Vec<PaymentRecord>> {
+        let mut query = gas_payment::Entity::find();
+        if let Some(msg_id) = &filter.msg_id {
+            query = query.filter(gas_payment::Column::MsgId.eq(h256_to_bytes(msg_id)));
+        }
+        if let Some(origin) = filter.origin {
+            query = query.filter(gas_payment::Column::Origin.eq(origin));
+        }
+        if let Some(destination) = filter.destination {
+            query = query.filter(gas_payment::Column::Destination.eq(destination));
+        }
+
+        let payments = query
+            .order_by_desc(gas_payment::Column::Id)
+            .offset(page.offset)
+            .limit(page.limit)
+            .all(&self.0)
+            .await?;
+
+        let txn_hashes = self
+            .get_txn_hashes(payments.iter().map(|payment| payment.tx_id).unique())
+            .await?;
+        payments
+            .into_iter()
+            .map(|payment| {
+                let tx_hash = txn_hashes
+                    .get(&payment.tx_id)
+                    .copied()
+                    .ok_or_else(|| eyre!("Missing transaction {}", payment.tx_id))?;
+                Ok(PaymentRecord {
+                    payment: InterchainGasPayment {
+                        message_id: H256::from_slice(&payment.msg_id),
+                        destination: payment.destination as u32,
+                        payment: decimal_to_u256(payment.payment),
+                        gas_amount: decimal_to_u256(payment.gas_amount),
+                    },
+                    origin: payment.origin as u32,
+                    tx_hash,
+                    paid_at: payment.time_created,
+                })
+            })
+            .collect()
+    }
+
+    /// Count and sum the gas payments made on each origin.
+    #[instrument(skip(self))]
+    pub async fn retrieve_payment_totals(&self) -> Result<HashMap<u32, PaymentTotals>> {
+        let totals = gas_payment::Entity::find()
+            .select_only()
+            .column(gas_payment::Column::Origin)
+            .column_as(gas_payment::Column::Id.count(), "count")
+            .column_as(gas_payment::Column::Payment.sum(), "total_payment")
+            .column_as(gas_payment::Column::GasAmount.sum(), "total_gas_amount")
+            .group_by(gas_payment::Column::Origin)
+            .into_tuple::<(i32, i64, Option<BigDecimal>, Option<BigDecimal>)>()
+            .all(&self.0)
+            .await?
+            .into_iter()
+            .map(|(origin, count, total_payment, total_gas_amount)| {
+                let totals = PaymentTotals {
+                    count: count as u64,
+                    total_payment: total_payment.map(decimal_to_u256).unwrap_or_default(),
+                    total_gas_amount: total_gas_amount.map(decimal_to_u256).unwrap_or_default(),
+                };
+                (origin as u32, totals)
+            })
+            .collect();
+        Ok(totals)
+    }
+
     async fn latest_payment_id(&self, domain: u32) -> Result<i64> {
         let result = gas_payment::Entity::find()
             .select_only()
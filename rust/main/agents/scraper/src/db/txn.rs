@@ -67,6 +67,35 @@ impl ScraperDb {
         Ok(txns)
     }
 
+    /// Lookup transactions by their ids and find their hashes. Any transactions
+    /// which are not found are excluded from the hashmap.
+    pub async fn get_txn_hashes(
+        &self,
+        ids: impl Iterator<Item = i64>,
+    ) -> Result<HashMap<i64, H512>> {
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
+        enum QueryAs {
+            Id,
+            Hash,
+        }
+
+        let txns = transaction::Entity::find()
+            .filter(transaction::Column::Id.is_in(ids))
+            .select_only()
+            .column_as(transaction::Column::Id, QueryAs::Id)
+            .column_as(transaction::Column::Hash, QueryAs::Hash)
+            .into_values::<(i64, Vec<u8>), QueryAs>()
+            .all(&self.0)
+            .await
+            .context("When querying transactions")?
+            .into_iter()
+            .map(|(id, hash)| (id, bytes_to_h512(&hash)))
+            .collect::<HashMap<_, _>>();
+
+        trace!(?txns, "Queried transaction hashes for ids");
+        Ok(txns)
+    }
+
     /// Store a new transaction into the database (or update an existing one).
     #[instrument(skip_all)]
     pub async fn store_txns(&self, txns: impl Iterator<Item = StorableTxn>) -> Result<()> {
@@ -23,6 +23,7 @@ mod agent;
 mod conversions;
 mod date_time;
 mod db;
+mod server;
 mod settings;
 mod store;
 
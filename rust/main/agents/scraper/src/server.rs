@@ -0,0 +1,122 @@
+//! Read-only HTTP API over the scraped data.
+//!
+//! Lets consumers look up messages, their delivery status and gas payments
+//! without coupling to the database schema. Served next to the metrics
+//! endpoint when `queryApiEnabled` is set.
+
+use axum::{http::StatusCode, routing::get, Router};
+use sea_orm::prelude::TimeDateTime;
+
+use hyperlane_base::server::utils::{ServerErrorBody, ServerErrorResponse, ServerResult};
+use hyperlane_core::{bytes_to_h512, utils::hex_or_base58_or_bech32_to_h256, H256, H512};
+
+use crate::db::{Pagination, ScraperDb};
+
+mod messages;
+mod payments;
+mod stats;
+
+/// Number of results returned when the request does not set a `limit`
+pub const DEFAULT_PAGE_SIZE: u64 = 100;
+/// Largest `limit` a request may set
+pub const MAX_PAGE_SIZE: u64 = 1000;
+
+#[derive(Clone, Debug)]
+pub struct ServerState {
+    db: ScraperDb,
+}
+
+impl ServerState {
+    pub fn new(db: ScraperDb) -> Self {
+        Self { db }
+    }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/messages", get(messages::list_messages))
+            .route("/messages/{message_id}", get(messages::get_message))
+            .route("/gas_payments", get(payments::list_payments))
+            .route("/stats", get(stats::domain_stats))
+            .with_state(self)
+    }
+}
+
+fn bad_request(message: impl Into<String>) -> ServerErrorResponse {
+    ServerErrorResponse::new(
+        StatusCode::BAD_REQUEST,
+        ServerErrorBody {
+            message: message.into(),
+        },
+    )
+}
+
+fn not_found(message: impl Into<String>) -> ServerErrorResponse {
+    ServerErrorResponse::new(
+        StatusCode::NOT_FOUND,
+        ServerErrorBody {
+            message: message.into(),
+        },
+    )
+}
+
+fn internal_error(err: eyre::Report) -> ServerErrorResponse {
+    let error_msg = "Failed to query database";
+    tracing::warn!(?err, "{error_msg}");
+    ServerErrorResponse::new(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        ServerErrorBody {
+            message: error_msg.to_string(),
+        },
+    )
+}
+
+fn pagination(limit: Option<u64>, offset: Option<u64>) -> ServerResult<Pagination> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        return Err(bad_request(format!(
+            "limit must be between 1 and {MAX_PAGE_SIZE}"
+        )));
+    }
+    Ok(Pagination {
+        limit,
+        offset: offset.unwrap_or_default(),
+    })
+}
+
+fn parse_address(name: &str, value: Option<&str>) -> ServerResult<Option<H256>> {
+    value
+        .map(|value| {
+            hex_or_base58_or_bech32_to_h256(value)
+                .map_err(|_| bad_request(format!("invalid {name}")))
+        })
+        .transpose()
+}
+
+fn parse_message_id(value: &str) -> ServerResult<H256> {
+    value
+        .parse::<H256>()
+        .map_err(|_| bad_request("invalid message_id"))
+}
+
+/// Transaction hashes are 32 bytes on most chains and 64 bytes on some
+fn parse_tx_hash(value: Option<&str>) -> ServerResult<Option<H512>> {
+    value
+        .map(|value| {
+            let parsed = match value.len() {
+                66 => value
+                    .parse::<H256>()
+                    .map(|hash| bytes_to_h512(hash.as_bytes())),
+                130 => value.parse::<H512>(),
+                _ => return Err(bad_request("invalid tx_hash")),
+            };
+            parsed.map_err(|_| bad_request("invalid tx_hash"))
+        })
+        .transpose()
+}
+
+fn unix_timestamp(time: TimeDateTime) -> i64 {
+    time.assume_utc().unix_timestamp()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,164 @@
+use axum::extract::{Path, Query, State};
+use serde::{Deserialize, Serialize};
+
+use hyperlane_base::server::utils::{ServerResult, ServerSuccessResponse};
+use hyperlane_core::{utils::bytes_to_hex, H256, H512};
+
+use crate::db::{MessageFilter, MessageRecord, Pagination, PaymentFilter};
+
+use super::{
+    bad_request, internal_error, not_found, pagination, parse_address, parse_message_id,
+    parse_tx_hash, payments::PaymentResponse, unix_timestamp, ServerState, MAX_PAGE_SIZE,
+};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct QueryParams {
+    pub origin: Option<u32>,
+    pub destination: Option<u32>,
+    pub sender: Option<String>,
+    pub recipient: Option<String>,
+    /// Hash of the transaction the message was dispatched in
+    pub tx_hash: Option<String>,
+    /// `delivered` or `pending`
+    pub status: Option<String>,
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct DeliveryResponse {
+    pub destination_tx_hash: H512,
+    pub delivered_at: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct MessageResponse {
+    pub message_id: H256,
+    pub origin: u32,
+    pub destination: u32,
+    pub nonce: u32,
+    pub sender: H256,
+    pub recipient: H256,
+    pub body: String,
+    pub origin_tx_hash: H512,
+    pub dispatched_at: i64,
+    /// `delivered` or `pending`
+    pub status: String,
+    pub delivery: Option<DeliveryResponse>,
+}
+
+impl From<MessageRecord> for MessageResponse {
+    fn from(record: MessageRecord) -> Self {
+        let status = if record.delivery.is_some() {
+            "delivered"
+        } else {
+            "pending"
+        };
+        Self {
+            message_id: record.id,
+            origin: record.message.origin,
+            destination: record.message.destination,
+            nonce: record.message.nonce,
+            sender: record.message.sender,
+            recipient: record.message.recipient,
+            body: bytes_to_hex(&record.message.body),
+            origin_tx_hash: record.origin_tx_hash,
+            dispatched_at: unix_timestamp(record.dispatched_at),
+            status: status.to_string(),
+            delivery: record.delivery.map(|delivery| DeliveryResponse {
+                destination_tx_hash: delivery.destination_tx_hash,
+                delivered_at: unix_timestamp(delivery.delivered_at),
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ListResponseBody {
+    pub messages: Vec<MessageResponse>,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageResponseBody {
+    pub message: MessageResponse,
+    pub gas_payments: Vec<PaymentResponse>,
+}
+
+/// List dispatched messages, newest first
+pub async fn list_messages(
+    State(state): State<ServerState>,
+    Query(query_params): Query<QueryParams>,
+) -> ServerResult<ServerSuccessResponse<ListResponseBody>> {
+    tracing::debug!(?query_params, "Listing messages");
+
+    let delivered = match query_params.status.as_deref() {
+        None => None,
+        Some("delivered") => Some(true),
+        Some("pending") => Some(false),
+        Some(_) => return Err(bad_request("status must be `delivered` or `pending`")),
+    };
+    let filter = MessageFilter {
+        origin: query_params.origin,
+        destination: query_params.destination,
+        sender: parse_address("sender", query_params.sender.as_deref())?,
+        recipient: parse_address("recipient", query_params.recipient.as_deref())?,
+        origin_tx_hash: parse_tx_hash(query_params.tx_hash.as_deref())?,
+        delivered,
+    };
+    let page = pagination(query_params.limit, query_params.offset)?;
+
+    let messages = state
+        .db
+        .retrieve_messages(&filter, &page)
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .map(MessageResponse::from)
+        .collect();
+
+    Ok(ServerSuccessResponse::new(ListResponseBody {
+        messages,
+        limit: page.limit,
+        offset: page.offset,
+    }))
+}
+
+/// Get a message by id, with its delivery status and gas payments
+pub async fn get_message(
+    State(state): State<ServerState>,
+    Path(message_id): Path<String>,
+) -> ServerResult<ServerSuccessResponse<MessageResponseBody>> {
+    let message_id = parse_message_id(&message_id)?;
+    tracing::debug!(?message_id, "Fetching message");
+
+    let message = state
+        .db
+        .retrieve_message_by_id(&message_id)
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(|| not_found("Message not found"))?;
+
+    let payment_filter = PaymentFilter {
+        msg_id: Some(message_id),
+        ..Default::default()
+    };
+    let page = Pagination {
+        limit: MAX_PAGE_SIZE,
+        offset: 0,
+    };
+    let gas_payments = state
+        .db
+        .retrieve_payments(&payment_filter, &page)
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .map(PaymentResponse::from)
+        .collect();
+
+    Ok(ServerSuccessResponse::new(MessageResponseBody {
+        message: message.into(),
+        gas_payments,
+    }))
+}
@@ -0,0 +1,85 @@
+use axum::extract::{Query, State};
+use serde::{Deserialize, Serialize};
+
+use hyperlane_base::server::utils::{ServerResult, ServerSuccessResponse};
+use hyperlane_core::{H256, H512};
+
+use crate::db::{PaymentFilter, PaymentRecord};
+
+use super::{internal_error, pagination, parse_message_id, unix_timestamp, ServerState};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct QueryParams {
+    pub message_id: Option<String>,
+    pub origin: Option<u32>,
+    pub destination: Option<u32>,
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct PaymentResponse {
+    pub message_id: H256,
+    pub origin: u32,
+    pub destination: u32,
+    /// Amount paid, in the smallest unit of the origin native token
+    pub payment: String,
+    pub gas_amount: String,
+    pub tx_hash: H512,
+    pub paid_at: i64,
+}
+
+impl From<PaymentRecord> for PaymentResponse {
+    fn from(record: PaymentRecord) -> Self {
+        Self {
+            message_id: record.payment.message_id,
+            origin: record.origin,
+            destination: record.payment.destination,
+            payment: record.payment.payment.to_string(),
+            gas_amount: record.payment.gas_amount.to_string(),
+            tx_hash: record.tx_hash,
+            paid_at: unix_timestamp(record.paid_at),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ResponseBody {
+    pub gas_payments: Vec<PaymentResponse>,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+/// List gas payments, newest first
+pub async fn list_payments(
+    State(state): State<ServerState>,
+    Query(query_params): Query<QueryParams>,
+) -> ServerResult<ServerSuccessResponse<ResponseBody>> {
+    tracing::debug!(?query_params, "Listing gas payments");
+
+    let filter = PaymentFilter {
+        msg_id: query_params
+            .message_id
+            .as_deref()
+            .map(parse_message_id)
+            .transpose()?,
+        origin: query_params.origin,
+        destination: query_params.destination,
+    };
+    let page = pagination(query_params.limit, query_params.offset)?;
+
+    let gas_payments = state
+        .db
+        .retrieve_payments(&filter, &page)
+        .await
+        .map_err(internal_error)?
+        .into_iter()
+        .map(PaymentResponse::from)
+        .collect();
+
+    Ok(ServerSuccessResponse::new(ResponseBody {
+        gas_payments,
+        limit: page.limit,
+        offset: page.offset,
+    }))
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeSet;
+
+use axum::extract::{Query, State};
+use serde::{Deserialize, Serialize};
+
+use hyperlane_base::server::utils::{ServerResult, ServerSuccessResponse};
+
+use super::{internal_error, ServerState};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct QueryParams {
+    pub domain: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct DomainStats {
+    pub domain: u32,
+    /// Messages dispatched from the domain
+    pub dispatched: u64,
+    /// Messages delivered to the domain
+    pub delivered: u64,
+    /// Gas payments made on the domain
+    pub gas_payments: u64,
+    pub total_gas_payment: String,
+    pub total_gas_amount: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ResponseBody {
+    pub domains: Vec<DomainStats>,
+}
+
+/// Per-domain message and gas payment counts
+pub async fn domain_stats(
+    State(state): State<ServerState>,
+    Query(query_params): Query<QueryParams>,
+) -> ServerResult<ServerSuccessResponse<ResponseBody>> {
+    tracing::debug!(?query_params, "Fetching domain stats");
+
+    let (dispatched, delivered) = state
+        .db
+        .retrieve_message_counts()
+        .await
+        .map_err(internal_error)?;
+    let payments = state
+        .db
+        .retrieve_payment_totals()
+        .await
+        .map_err(internal_error)?;
+
+    let domains: BTreeSet<u32> = dispatched
+        .keys()
+        .chain(delivered.keys())
+        .chain(payments.keys())
+        .copied()
+        .filter(|domain| query_params.domain.is_none_or(|d| d == *domain))
+        .collect();
+
+    let domains = domains
+        .into_iter()
+        .map(|domain| {
+            let totals = payments.get(&domain).cloned().unwrap_or_default();
+            DomainStats {
+                domain,
+                dispatched: dispatched.get(&domain).copied().unwrap_or_default(),
+                delivered: delivered.get(&domain).copied().unwrap_or_default(),
+                gas_payments: totals.count,
+                total_gas_payment: totals.total_payment.to_string(),
+                total_gas_amount: totals.total_gas_amount.to_string(),
+            }
+        })
+        .collect();
+
+    Ok(ServerSuccessResponse::new(ResponseBody { domains }))
+}
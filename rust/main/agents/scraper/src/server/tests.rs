@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use axum::{
+    body::{self, Body},
+    http::{Request, Response, StatusCode},
+    Router,
+};
+use sea_orm::{DatabaseBackend, MockDatabase, Value};
+use time::{macros::*, PrimitiveDateTime};
+use tower::ServiceExt;
+
+use hyperlane_core::{h256_to_bytes, H256};
+
+use crate::db::{
+    generated::{delivered_message, message},
+    ScraperDb,
+};
+
+use super::{messages::ListResponseBody, ServerState};
+
+const DISPATCHED_AT: PrimitiveDateTime = PrimitiveDateTime::new(date!(2024 - 01 - 01), time!(0:00));
+const DELIVERED_AT: PrimitiveDateTime = PrimitiveDateTime::new(date!(2024 - 01 - 01), time!(0:05));
+
+fn app(mock_db: MockDatabase) -> Router {
+    ServerState::new(ScraperDb::with_connection(mock_db.into_connection())).router()
+}
+
+fn empty_db() -> MockDatabase {
+    MockDatabase::new(DatabaseBackend::Postgres)
+}
+
+async fn get(app: Router, uri: &str) -> Response<Body> {
+    let request = Request::builder()
+        .uri(uri)
+        .body(Body::empty())
+        .expect("Failed to build request");
+    app.oneshot(request).await.expect("Failed to send request")
+}
+
+fn message_model(id: i64, msg_id: H256) -> message::Model {
+    message::Model {
+        id,
+        time_created: DISPATCHED_AT,
+        msg_id: h256_to_bytes(&msg_id),
+        origin: 1,
+        destination: 10,
+        nonce: 5,
+        sender: vec![0x11; 20],
+        recipient: vec![0x22; 20],
+        msg_body: Some(vec![0xab, 0xcd]),
+        origin_mailbox: vec![0x33; 20],
+        origin_tx_id: 100,
+    }
+}
+
+fn delivery_model(msg_id: H256) -> delivered_message::Model {
+    delivered_message::Model {
+        id: 1,
+        time_created: DELIVERED_AT,
+        msg_id: h256_to_bytes(&msg_id),
+        domain: 10,
+        destination_mailbox: vec![0x44; 20],
+        destination_tx_id: 200,
+        sequence: Some(0),
+    }
+}
+
+fn txn_row(id: i64, hash: H256) -> BTreeMap<&'static str, Value> {
+    [
+        ("id", Into::<Value>::into(id)),
+        ("hash", Into::<Value>::into(h256_to_bytes(&hash))),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[tokio::test]
+async fn test_list_messages_with_delivery_status() {
+    let delivered_id = H256::from_low_u64_be(1);
+    let pending_id = H256::from_low_u64_be(2);
+    let mock_db = empty_db()
+        .append_query_results([[message_model(2, pending_id), message_model(1, delivered_id)]])
+        .append_query_results([[delivery_model(delivered_id)]])
+        .append_query_results([[
+            txn_row(100, H256::from_low_u64_be(100)),
+            txn_row(200, H256::from_low_u64_be(200)),
+        ]]);
+
+    let response = get(app(mock_db), "/messages?origin=1&limit=2").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read body");
+    let body: ListResponseBody = serde_json::from_slice(&body).expect("Failed to parse body");
+
+    assert_eq!(body.limit, 2);
+    assert_eq!(body.messages.len(), 2);
+
+    let pending = &body.messages[0];
+    assert_eq!(pending.message_id, pending_id);
+    assert_eq!(pending.status, "pending");
+    assert_eq!(pending.body, "0xabcd");
+    assert!(pending.delivery.is_none());
+
+    let delivered = &body.messages[1];
+    assert_eq!(delivered.message_id, delivered_id);
+    assert_eq!(delivered.status, "delivered");
+    let delivery = delivered.delivery.as_ref().expect("Delivery missing");
+    assert_eq!(
+        delivery.delivered_at,
+        DELIVERED_AT.assume_utc().unix_timestamp()
+    );
+}
+
+#[tokio::test]
+async fn test_get_message_not_found() {
+    let mock_db = empty_db().append_query_results([Vec::<message::Model>::new()]);
+
+    let response = get(app(mock_db), &format!("/messages/{:?}", H256::zero())).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_invalid_query_params_are_rejected() {
+    for uri in [
+        "/messages/not-a-message-id",
+        "/messages?limit=0",
+        "/messages?limit=100000",
+        "/messages?status=unknown",
+        "/messages?sender=0x1234",
+        "/messages?tx_hash=0x1234",
+        "/gas_payments?message_id=0x1234",
+    ] {
+        let response = get(app(empty_db()), uri).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{uri}");
+    }
+}
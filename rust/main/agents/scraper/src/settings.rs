@@ -17,12 +17,13 @@ use hyperlane_base::{
     impl_loadable_from_settings,
     settings::{
         parser::{RawAgentConf, ValueParser},
+        schema::{boolean, comma_separated_list, object_properties, RawConfSchema},
         Settings,
     },
 };
 use hyperlane_core::{cfg_unwrap_all, config::*, HyperlaneDomain, H160};
 use serde::Deserialize;
-use serde_json::Value;
+use serde_json::{json, Map, Value};
 
 /// Settings for `Scraper`
 #[derive(Debug, AsRef, AsMut, Deref, DerefMut)]
@@ -39,6 +40,8 @@ pub struct ScraperSettings {
     /// Domain ID → { router_address → token_address }.
     /// Only domains present here will spawn a CCR swap indexer.
     pub ccr_routers: HashMap<u32, HashMap<H160, H160>>,
+    /// Whether to serve the HTTP query API over the scraped data
+    pub query_api_enabled: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -47,6 +50,31 @@ struct RawScraperSettings(Value);
 
 impl_loadable_from_settings!(Scraper, RawScraperSettings -> ScraperSettings);
 
+impl RawConfSchema for RawScraperSettings {
+    fn agent_properties() -> Map<String, Value> {
+        object_properties(json!({
+            "chainsToScrape": comma_separated_list(),
+            "db": {
+                "description": "Postgres connection url",
+                "type": "string",
+            },
+            "ccrRouters": {
+                "description": "CCR router to underlying ERC20 token addresses, by domain id",
+                "type": "object",
+                "additionalProperties": {
+                    "type": "object",
+                    "additionalProperties": { "type": "string" },
+                },
+            },
+            "queryApiEnabled": boolean(),
+        }))
+    }
+
+    fn agent_required() -> Vec<&'static str> {
+        vec!["chainsToScrape", "db"]
+    }
+}
+
 impl FromRawConf<RawScraperSettings> for ScraperSettings {
     fn from_config_filtered(
         raw: RawScraperSettings,
@@ -137,6 +165,12 @@ impl FromRawConf<RawScraperSettings> for ScraperSettings {
             ccr_routers.insert(domain_id, domain_routers);
         }
 
+        let query_api_enabled = p
+            .chain(&mut err)
+            .get_opt_key("queryApiEnabled")
+            .parse_bool()
+            .unwrap_or(false);
+
         cfg_unwrap_all!(&p.cwp, err: [base, db]);
 
         err.into_result(Self {
@@ -144,6 +178,7 @@ impl FromRawConf<RawScraperSettings> for ScraperSettings {
             db,
             chains_to_scrape,
             ccr_routers,
+            query_api_enabled,
         })
     }
 }
@@ -0,0 +1,471 @@
+//! The `audit` subcommand re-derives the checkpoints a validator should have
+//! signed from the origin chain and verifies the ones in its checkpoint syncer.
+//!
+//! `MerkleTreeInsertion`s are re-indexed from the origin chain, the tree is
+//! rebuilt leaf by leaf, and every stored `SignedCheckpointWithMessageId` in
+//! `audit.fromIndex..=audit.toIndex` is checked for the right root, message
+//! id, origin and signer. The report is written as JSON to `audit.output`, or
+//! to stdout.
+
+use std::{collections::BTreeMap, ops::RangeInclusive, sync::Arc};
+
+use eyre::{eyre, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use hyperlane_base::{
+    settings::IndexSettings, BaseAgent, CheckpointSyncer, CoreMetrics, LoadableFromSettings,
+    AUDIT_SUBCOMMAND,
+};
+use hyperlane_core::{
+    accumulator::incremental::IncrementalMerkle, HyperlaneChain, HyperlaneContract,
+    HyperlaneDomain, HyperlaneSigner, IndexMode, MerkleTreeInsertion, SequenceAwareIndexer,
+    SignedCheckpointWithMessageId, H160, H256,
+};
+use hyperlane_ethereum::Signers;
+
+use crate::{
+    settings::{OriginSettings, ValidatorSettings},
+    validator::Validator,
+};
+
+/// Whether the process was started with the `audit` subcommand
+pub fn is_audit_command() -> bool {
+    hyperlane_base::subcommand() == Some(AUDIT_SUBCOMMAND)
+}
+
+/// Audit the checkpoints of every origin in the config. Fails if the audit
+/// couldn't be completed, or if any origin has gaps or mismatches.
+pub async fn audit_main() -> Result<()> {
+    let settings = ValidatorSettings::load(Validator::AGENT_NAME)?;
+    let metrics = settings.metrics(Validator::AGENT_NAME)?;
+    let _tokio_server = settings.tracing.start_tracing(&metrics)?;
+
+    let mut reports = vec![];
+    for origin in &settings.origins {
+        info!(origin_chain = %origin.origin_chain, "Auditing checkpoints");
+        reports.push(audit_origin(&settings, origin, &metrics).await?);
+    }
+
+    let serialized = serde_json::to_string_pretty(&reports)?;
+    match &settings.audit.output {
+        Some(path) => std::fs::write(path, serialized)?,
+        None => println!("{serialized}"),
+    }
+
+    if reports.iter().any(|report| !report.is_clean()) {
+        return Err(eyre!("Audit found gaps or mismatches"));
+    }
+    Ok(())
+}
+
+async fn audit_origin(
+    settings: &ValidatorSettings,
+    origin: &OriginSettings,
+    metrics: &Arc<CoreMetrics>,
+) -> Result<AuditReport> {
+    let validator = origin.validator.build::<Signers>().await?.eth_address();
+    let checkpoint_syncer = origin.checkpoint_syncer.build_unvalidated().await?;
+    let chain_conf = settings.chain_setup(&origin.origin_chain)?;
+    let merkle_tree_hook = settings
+        .build_merkle_tree_hook(&origin.origin_chain, metrics)
+        .await?;
+    let indexer = chain_conf
+        .build_merkle_tree_hook_indexer(metrics, false)
+        .await?;
+
+    let to_index = match settings.audit.to_index {
+        Some(to_index) => to_index,
+        None => checkpoint_syncer
+            .latest_index()
+            .await?
+            .ok_or_else(|| eyre!("No checkpoint found in the checkpoint syncer"))?,
+    };
+    let range = settings.audit.from_index..=to_index;
+    if range.is_empty() {
+        return Err(eyre!("Invalid audit range {range:?}"));
+    }
+
+    let insertions =
+        index_insertions(indexer.as_ref(), &chain_conf.index_settings(), to_index).await?;
+
+    let auditor = CheckpointAuditor {
+        origin_chain: origin.origin_chain.clone(),
+        validator,
+        mailbox_domain: merkle_tree_hook.domain().id(),
+        merkle_tree_hook_address: merkle_tree_hook.address(),
+    };
+    Ok(auditor
+        .audit(&insertions, checkpoint_syncer.as_ref(), range)
+        .await)
+}
+
+/// Fetch every insertion up to `to_index`, keyed by leaf index
+async fn index_insertions(
+    indexer: &dyn SequenceAwareIndexer<MerkleTreeInsertion>,
+    index_settings: &IndexSettings,
+    to_index: u32,
+) -> Result<BTreeMap<u32, H256>> {
+    let range = match index_settings.mode {
+        IndexMode::Block => {
+            let (_, tip) = indexer.latest_sequence_count_and_tip().await?;
+            u32::try_from(index_settings.from).unwrap_or_default()..=tip
+        }
+        IndexMode::Sequence => 0..=to_index,
+    };
+    let chunk_size = index_settings.chunk_size.max(1);
+
+    let mut insertions = BTreeMap::new();
+    let mut from = *range.start();
+    while from <= *range.end() {
+        let to = from
+            .saturating_add(chunk_size.saturating_sub(1))
+            .min(*range.end());
+        for (insertion, _) in indexer.fetch_logs_in_range(from..=to).await? {
+            let insertion = insertion.inner();
+            insertions.insert(insertion.index(), insertion.message_id());
+        }
+        // Stop early once every leaf of the audited tree was found
+        if insertions.range(..=to_index).count() > usize::try_from(to_index)? {
+            break;
+        }
+        if to == *range.end() {
+            break;
+        }
+        from = to.saturating_add(1);
+    }
+    Ok(insertions)
+}
+
+/// Audit results of a single origin
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditReport {
+    pub origin_chain: String,
+    pub validator: H160,
+    pub mailbox_domain: u32,
+    pub merkle_tree_hook_address: H256,
+    pub from_index: u32,
+    pub to_index: u32,
+    /// Number of checkpoints that passed every check
+    pub verified: u32,
+    /// Leaf indices without an indexed insertion. Checkpoints after the
+    /// first gap can't be verified, as the tree can't be rebuilt past it.
+    pub insertion_gaps: Vec<u32>,
+    /// Indices without a checkpoint in the checkpoint syncer
+    pub missing_checkpoints: Vec<u32>,
+    /// Checkpoints that couldn't be fetched from the checkpoint syncer
+    pub unreadable_checkpoints: Vec<UnreadableCheckpoint>,
+    pub mismatches: Vec<CheckpointMismatch>,
+}
+
+impl AuditReport {
+    /// Whether every checkpoint in the range was found and verified
+    pub fn is_clean(&self) -> bool {
+        self.insertion_gaps.is_empty()
+            && self.missing_checkpoints.is_empty()
+            && self.unreadable_checkpoints.is_empty()
+            && self.mismatches.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnreadableCheckpoint {
+    pub index: u32,
+    pub error: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckpointMismatch {
+    pub index: u32,
+    #[serde(flatten)]
+    pub kind: MismatchKind,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum MismatchKind {
+    /// The checkpoint stored at `index` is for another index
+    #[serde(rename_all = "camelCase")]
+    Index { actual: u32 },
+    /// The checkpoint is for another mailbox domain or merkle tree hook
+    #[serde(rename_all = "camelCase")]
+    Origin {
+        mailbox_domain: u32,
+        merkle_tree_hook_address: H256,
+    },
+    #[serde(rename_all = "camelCase")]
+    Root { expected: H256, actual: H256 },
+    #[serde(rename_all = "camelCase")]
+    MessageId { expected: H256, actual: H256 },
+    #[serde(rename_all = "camelCase")]
+    Signer { expected: H160, actual: H160 },
+    #[serde(rename_all = "camelCase")]
+    InvalidSignature { error: String },
+}
+
+/// Verifies stored checkpoints against a tree rebuilt from insertions
+#[derive(Clone, Debug)]
+pub struct CheckpointAuditor {
+    pub origin_chain: HyperlaneDomain,
+    pub validator: H160,
+    pub mailbox_domain: u32,
+    pub merkle_tree_hook_address: H256,
+}
+
+impl CheckpointAuditor {
+    pub async fn audit(
+        &self,
+        insertions: &BTreeMap<u32, H256>,
+        checkpoint_syncer: &dyn CheckpointSyncer,
+        range: RangeInclusive<u32>,
+    ) -> AuditReport {
+        let mut report = AuditReport {
+            origin_chain: self.origin_chain.name().to_string(),
+            validator: self.validator,
+            mailbox_domain: self.mailbox_domain,
+            merkle_tree_hook_address: self.merkle_tree_hook_address,
+            from_index: *range.start(),
+            to_index: *range.end(),
+            verified: 0,
+            insertion_gaps: vec![],
+            missing_checkpoints: vec![],
+            unreadable_checkpoints: vec![],
+            mismatches: vec![],
+        };
+
+        report.insertion_gaps = (0..=*range.end())
+            .filter(|index| !insertions.contains_key(index))
+            .collect();
+        if let Some(first_gap) = report.insertion_gaps.first() {
+            warn!(
+                first_gap,
+                gaps = report.insertion_gaps.len(),
+                "Missing insertions, checkpoints after the first gap won't be verified"
+            );
+        }
+
+        let mut tree = IncrementalMerkle::default();
+        for (index, message_id) in insertions.range(..=*range.end()) {
+            // The tree can only be rebuilt from contiguous leaves
+            if usize::try_from(*index).ok() != Some(tree.count()) {
+                break;
+            }
+            tree.ingest(*message_id);
+            if !range.contains(index) {
+                continue;
+            }
+
+            match checkpoint_syncer.fetch_checkpoint(*index).await {
+                Ok(Some(signed)) => {
+                    let mismatches = self.verify(&tree, *message_id, &signed);
+                    if mismatches.is_empty() {
+                        report.verified = report.verified.saturating_add(1);
+                    }
+                    report.mismatches.extend(mismatches.into_iter().map(|kind| {
+                        CheckpointMismatch {
+                            index: *index,
+                            kind,
+                        }
+                    }));
+                }
+                Ok(None) => report.missing_checkpoints.push(*index),
+                Err(err) => report.unreadable_checkpoints.push(UnreadableCheckpoint {
+                    index: *index,
+                    error: err.to_string(),
+                }),
+            }
+        }
+        report
+    }
+
+    /// Check a checkpoint against the tree after ingesting its leaf
+    fn verify(
+        &self,
+        tree: &IncrementalMerkle,
+        message_id: H256,
+        signed: &SignedCheckpointWithMessageId,
+    ) -> Vec<MismatchKind> {
+        let checkpoint = &signed.value;
+        let mut mismatches = vec![];
+        if checkpoint.index != tree.index() {
+            mismatches.push(MismatchKind::Index {
+                actual: checkpoint.index,
+            });
+        }
+        if checkpoint.mailbox_domain != self.mailbox_domain
+            || checkpoint.merkle_tree_hook_address != self.merkle_tree_hook_address
+        {
+            mismatches.push(MismatchKind::Origin {
+                mailbox_domain: checkpoint.mailbox_domain,
+                merkle_tree_hook_address: checkpoint.merkle_tree_hook_address,
+            });
+        }
+        if checkpoint.root != tree.root() {
+            mismatches.push(MismatchKind::Root {
+                expected: tree.root(),
+                actual: checkpoint.root,
+            });
+        }
+        if checkpoint.message_id != message_id {
+            mismatches.push(MismatchKind::MessageId {
+                expected: message_id,
+                actual: checkpoint.message_id,
+            });
+        }
+        match signed.recover() {
+            Ok(signer) if signer != self.validator => mismatches.push(MismatchKind::Signer {
+                expected: self.validator,
+                actual: signer,
+            }),
+            Ok(_) => {}
+            Err(err) => mismatches.push(MismatchKind::InvalidSignature {
+                error: err.to_string(),
+            }),
+        }
+        mismatches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyperlane_base::LocalStorage;
+    use hyperlane_core::{
+        Checkpoint, CheckpointWithMessageId, HyperlaneSignerExt, KnownHyperlaneDomain,
+    };
+
+    use super::*;
+
+    const HOOK_ADDRESS: H256 = H256::repeat_byte(0x42);
+
+    fn signer(key: &str) -> Signers {
+        key.parse::<ethers::signers::LocalWallet>().unwrap().into()
+    }
+
+    fn insertions(count: u64) -> BTreeMap<u32, H256> {
+        (0..count)
+            .map(|index| {
+                (
+                    u32::try_from(index).unwrap(),
+                    H256::from_low_u64_be(index.saturating_add(100)),
+                )
+            })
+            .collect()
+    }
+
+    fn auditor(validator: H160) -> CheckpointAuditor {
+        let origin_chain = HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum);
+        CheckpointAuditor {
+            mailbox_domain: origin_chain.id(),
+            origin_chain,
+            validator,
+            merkle_tree_hook_address: HOOK_ADDRESS,
+        }
+    }
+
+    /// Sign a checkpoint for every insertion, as a validator would
+    async fn write_checkpoints(
+        storage: &LocalStorage,
+        signer: &Signers,
+        mailbox_domain: u32,
+        insertions: &BTreeMap<u32, H256>,
+    ) {
+        let mut tree = IncrementalMerkle::default();
+        for (index, message_id) in insertions {
+            tree.ingest(*message_id);
+            let checkpoint = CheckpointWithMessageId {
+                checkpoint: Checkpoint {
+                    merkle_tree_hook_address: HOOK_ADDRESS,
+                    mailbox_domain,
+                    root: tree.root(),
+                    index: *index,
+                },
+                message_id: *message_id,
+            };
+            let signed = signer.sign(checkpoint).await.unwrap();
+            storage.write_checkpoint(&signed).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_audit_valid_checkpoints() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = LocalStorage::new(temp_dir.path().to_path_buf(), None).unwrap();
+        let signer = signer("1111111111111111111111111111111111111111111111111111111111111111");
+        let auditor = auditor(signer.eth_address());
+        let insertions = insertions(5);
+        write_checkpoints(&storage, &signer, auditor.mailbox_domain, &insertions).await;
+
+        let report = auditor.audit(&insertions, &storage, 1..=4).await;
+
+        assert!(report.is_clean(), "{report:?}");
+        assert_eq!(report.verified, 4);
+    }
+
+    #[tokio::test]
+    async fn test_audit_reports_mismatches_and_gaps() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = LocalStorage::new(temp_dir.path().to_path_buf(), None).unwrap();
+        let signer = signer("1111111111111111111111111111111111111111111111111111111111111111");
+        let other_signer =
+            signer("2222222222222222222222222222222222222222222222222222222222222222");
+        let auditor = auditor(signer.eth_address());
+
+        // Checkpoints 0 to 2 are signed by the validator, 3 by another key,
+        // and the tree the validator signed has a different message at 1
+        let mut signed_insertions = insertions(3);
+        signed_insertions.insert(1, H256::repeat_byte(0xff));
+        write_checkpoints(
+            &storage,
+            &signer,
+            auditor.mailbox_domain,
+            &signed_insertions,
+        )
+        .await;
+        let mut tree = IncrementalMerkle::default();
+        for message_id in insertions(4).values() {
+            tree.ingest(*message_id);
+        }
+        let checkpoint = CheckpointWithMessageId {
+            checkpoint: Checkpoint {
+                merkle_tree_hook_address: HOOK_ADDRESS,
+                mailbox_domain: auditor.mailbox_domain,
+                root: tree.root(),
+                index: 3,
+            },
+            message_id: insertions(4)[&3],
+        };
+        let signed = other_signer.sign(checkpoint).await.unwrap();
+        storage.write_checkpoint(&signed).await.unwrap();
+
+        // Index 4 has no checkpoint, and leaf 5 was never indexed
+        let mut chain_insertions = insertions(7);
+        chain_insertions.remove(&5);
+
+        let report = auditor.audit(&chain_insertions, &storage, 0..=6).await;
+
+        assert!(!report.is_clean());
+        assert_eq!(report.verified, 1);
+        assert_eq!(report.insertion_gaps, vec![5]);
+        assert_eq!(report.missing_checkpoints, vec![4]);
+        let mismatched: Vec<(u32, &str)> = report
+            .mismatches
+            .iter()
+            .map(|mismatch| {
+                let kind = match mismatch.kind {
+                    MismatchKind::Root { .. } => "root",
+                    MismatchKind::MessageId { .. } => "messageId",
+                    MismatchKind::Signer { .. } => "signer",
+                    _ => "other",
+                };
+                (mismatch.index, kind)
+            })
+            .collect();
+        assert_eq!(
+            mismatched,
+            vec![(1, "root"), (1, "messageId"), (2, "root"), (3, "signer"),]
+        );
+    }
+}
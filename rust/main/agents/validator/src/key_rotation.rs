@@ -0,0 +1,281 @@
+//! Rotation of a validator key with a dual-signing period.
+//!
+//! While a rotation is in progress, every checkpoint is signed by both the
+//! current and the new key, each writing to its own checkpoint syncer, so
+//! relayers see no gap while they switch over to the new key. Once the
+//! window ends, only the new key keeps signing.
+
+use std::{
+    fmt::Debug,
+    future::Future,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use eyre::Result;
+use prometheus::IntGaugeVec;
+use serde::{Deserialize, Serialize};
+use tokio::time::sleep;
+use tracing::info;
+
+use hyperlane_base::{CheckpointSyncer, CoreMetrics};
+use hyperlane_core::{HyperlaneDomain, HyperlaneSigner, H160};
+use hyperlane_ethereum::{Signers, SingletonSigner, SingletonSignerHandle};
+
+use crate::settings::KeyRotationSettings;
+
+/// Metrics of key rotations, shared by all origins
+#[derive(Clone, Debug)]
+pub struct KeyRotationMetrics {
+    dual_signing: IntGaugeVec,
+    ends_at: IntGaugeVec,
+}
+
+impl KeyRotationMetrics {
+    pub fn new(metrics: &CoreMetrics) -> Result<Self> {
+        Ok(Self {
+            dual_signing: metrics.new_int_gauge(
+                "validator_key_rotation_dual_signing",
+                "Whether checkpoints are signed by both the current and the new validator key",
+                &["origin"],
+            )?,
+            ends_at: metrics.new_int_gauge(
+                "validator_key_rotation_ends_at",
+                "Unix timestamp at which the current validator key stops signing",
+                &["origin"],
+            )?,
+        })
+    }
+
+    fn update(&self, origin_chain: &HyperlaneDomain, rotation: &KeyRotationInfo) {
+        let origin = origin_chain.name();
+        let dual_signing = rotation.phase() == KeyRotationPhase::DualSigning;
+        self.dual_signing
+            .with_label_values(&[origin])
+            .set(dual_signing as i64);
+        self.ends_at
+            .with_label_values(&[origin])
+            .set(i64::try_from(rotation.ends_at).unwrap_or(i64::MAX));
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum KeyRotationPhase {
+    /// Both keys sign every checkpoint
+    DualSigning,
+    /// Only the new key signs checkpoints
+    Completed,
+}
+
+/// Public state of a key rotation, served over HTTP
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyRotationState {
+    pub previous_validator: H160,
+    pub validator: H160,
+    pub storage_location: String,
+    pub ends_at: u64,
+    pub phase: KeyRotationPhase,
+}
+
+/// What is known about a rotation without access to the signers
+#[derive(Clone, Debug)]
+pub struct KeyRotationInfo {
+    pub previous_validator: H160,
+    pub validator: H160,
+    pub storage_location: String,
+    pub ends_at: u64,
+}
+
+impl KeyRotationInfo {
+    pub fn phase(&self) -> KeyRotationPhase {
+        if now() < self.ends_at {
+            KeyRotationPhase::DualSigning
+        } else {
+            KeyRotationPhase::Completed
+        }
+    }
+
+    pub fn state(&self) -> KeyRotationState {
+        KeyRotationState {
+            previous_validator: self.previous_validator,
+            validator: self.validator,
+            storage_location: self.storage_location.clone(),
+            ends_at: self.ends_at,
+            phase: self.phase(),
+        }
+    }
+
+    /// Resolves once the dual-signing window is over
+    pub async fn wait_for_end(&self) {
+        let remaining = self.ends_at.saturating_sub(now());
+        sleep(Duration::from_secs(remaining)).await;
+    }
+}
+
+/// A validator key of an origin
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidatorKey {
+    /// The key configured as `validator`
+    Current,
+    /// The key being rotated to
+    Rotated,
+}
+
+/// The keys which sign the checkpoints of an origin and are announced, each
+/// with the rotation it signs until, if it is being rotated out
+pub fn signing_keys(
+    rotation: Option<&KeyRotationInfo>,
+) -> Vec<(ValidatorKey, Option<KeyRotationInfo>)> {
+    match rotation {
+        None => vec![(ValidatorKey::Current, None)],
+        Some(rotation) if rotation.phase() == KeyRotationPhase::DualSigning => vec![
+            (ValidatorKey::Current, Some(rotation.clone())),
+            (ValidatorKey::Rotated, None),
+        ],
+        Some(_) => vec![(ValidatorKey::Rotated, None)],
+    }
+}
+
+/// A rotation in progress on an origin, with the new key's signer and
+/// checkpoint syncer
+#[derive(Debug)]
+pub struct KeyRotation {
+    pub info: KeyRotationInfo,
+    pub signer: SingletonSignerHandle,
+    pub raw_signer: Signers,
+    // temporary holder until the origin runs
+    pub signer_instance: Option<Box<SingletonSigner>>,
+    pub checkpoint_syncer: Arc<dyn CheckpointSyncer>,
+    origin_chain: HyperlaneDomain,
+    metrics: KeyRotationMetrics,
+}
+
+impl KeyRotation {
+    pub async fn from_settings(
+        settings: &KeyRotationSettings,
+        previous_validator: H160,
+        origin_chain: &HyperlaneDomain,
+        metrics: KeyRotationMetrics,
+    ) -> Result<Self> {
+        let raw_signer: Signers = settings.validator.build().await?;
+        let (signer_instance, signer) = SingletonSigner::new(raw_signer.clone());
+        let checkpoint_syncer: Arc<dyn CheckpointSyncer> = settings
+            .checkpoint_syncer
+            .build_and_validate(None)
+            .await
+            .map_err(|err| eyre::eyre!("Failed to build rotation checkpoint syncer: {err:?}"))?
+            .into();
+
+        let info = KeyRotationInfo {
+            previous_validator,
+            validator: raw_signer.eth_address(),
+            storage_location: checkpoint_syncer.announcement_location(),
+            ends_at: settings.ends_at,
+        };
+        let rotation = Self {
+            info,
+            signer,
+            raw_signer,
+            signer_instance: Some(Box::new(signer_instance)),
+            checkpoint_syncer,
+            origin_chain: origin_chain.clone(),
+            metrics,
+        };
+        rotation
+            .metrics
+            .update(&rotation.origin_chain, &rotation.info);
+        Ok(rotation)
+    }
+
+    /// Resolves once the dual-signing window is over, after updating the
+    /// metrics. The previous key must stop signing by then.
+    pub fn watch_end(&self) -> impl Future<Output = ()> + Send + 'static {
+        let info = self.info.clone();
+        let origin_chain = self.origin_chain.clone();
+        let metrics = self.metrics.clone();
+        async move {
+            info.wait_for_end().await;
+            metrics.update(&origin_chain, &info);
+            info!(
+                previous_validator = ?info.previous_validator,
+                validator = ?info.validator,
+                "Key rotation window ended, only the new validator key signs checkpoints from now on"
+            );
+        }
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rotation_info(ends_at: u64) -> KeyRotationInfo {
+        KeyRotationInfo {
+            previous_validator: H160::repeat_byte(1),
+            validator: H160::repeat_byte(2),
+            storage_location: "file:///tmp/rotated".to_owned(),
+            ends_at,
+        }
+    }
+
+    #[test]
+    fn only_current_key_signs_without_rotation() {
+        assert_eq!(
+            signing_keys(None)
+                .into_iter()
+                .map(|(key, signs_until)| (key, signs_until.is_some()))
+                .collect::<Vec<_>>(),
+            vec![(ValidatorKey::Current, false)]
+        );
+    }
+
+    #[test]
+    fn both_keys_sign_during_rotation_window() {
+        let rotation = rotation_info(now().saturating_add(3600));
+        assert_eq!(rotation.phase(), KeyRotationPhase::DualSigning);
+
+        let keys = signing_keys(Some(&rotation));
+
+        assert_eq!(keys.len(), 2);
+        // the current key stops at the end of the window, the new one doesn't
+        assert_eq!(keys[0].0, ValidatorKey::Current);
+        assert_eq!(
+            keys[0].1.as_ref().map(|info| info.ends_at),
+            Some(rotation.ends_at)
+        );
+        assert_eq!(keys[1].0, ValidatorKey::Rotated);
+        assert!(keys[1].1.is_none());
+    }
+
+    #[test]
+    fn only_new_key_signs_after_rotation_window() {
+        let rotation = rotation_info(now().saturating_sub(1));
+        assert_eq!(rotation.phase(), KeyRotationPhase::Completed);
+        assert_eq!(rotation.state().phase, KeyRotationPhase::Completed);
+
+        let keys = signing_keys(Some(&rotation));
+
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].0, ValidatorKey::Rotated);
+    }
+
+    #[tokio::test]
+    async fn rotation_window_ends() {
+        let rotation = rotation_info(now().saturating_add(1));
+
+        tokio::time::timeout(Duration::from_secs(5), rotation.wait_for_end())
+            .await
+            .expect("rotation window should have ended");
+
+        assert_eq!(rotation.phase(), KeyRotationPhase::Completed);
+    }
+}
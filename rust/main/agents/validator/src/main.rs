@@ -11,14 +11,22 @@ use hyperlane_base::agent_main;
 
 use crate::validator::Validator;
 
+mod audit;
+mod key_rotation;
 mod reorg_reporter;
 mod server;
 mod settings;
+mod signing_journal;
+mod status;
 mod submit;
 mod validator;
 
 #[tokio::main(flavor = "multi_thread")]
 async fn main() -> Result<()> {
+    if audit::is_audit_command() {
+        return audit::audit_main().await;
+    }
+
     // Logging is not initialised at this point, so, using `println!`
     println!("Validator starting up...");
 
@@ -1,11 +1,13 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
 use ethers::utils::keccak256;
 use futures_util::future::join_all;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use tokio::time::timeout;
 use tracing::{info, warn};
 use url::Url;
 
@@ -17,10 +19,30 @@ use hyperlane_ethereum::RpcConnectionConf;
 
 use crate::settings::ValidatorSettings;
 
+/// Timeout of a single endpoint probe
+const ENDPOINT_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
 #[async_trait]
 pub trait ReorgReporter: Send + Sync + Debug {
     async fn report_at_block(&self, height: u64);
     async fn report_with_reorg_period(&self, reorg_period: &ReorgPeriod);
+
+    /// Query the latest checkpoint from each RPC endpoint once, for status
+    /// reporting
+    async fn probe_endpoints(&self, _reorg_period: &ReorgPeriod) -> Vec<EndpointProbe> {
+        vec![]
+    }
+}
+
+/// Latest checkpoint of a single RPC endpoint, and how long it took to get it
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointProbe {
+    pub host: Option<String>,
+    pub url_hash: H256,
+    pub latency_ms: u64,
+    pub latest_checkpoint_index: Option<u32>,
+    pub error: Option<String>,
 }
 
 #[derive(Debug)]
@@ -67,6 +89,10 @@ impl ReorgReporter for LatestCheckpointReorgReporter {
     async fn report_with_reorg_period(&self, reorg_period: &ReorgPeriod) {
         self.report_with_reorg_period(reorg_period).await;
     }
+
+    async fn probe_endpoints(&self, reorg_period: &ReorgPeriod) -> Vec<EndpointProbe> {
+        self.probe_endpoints(reorg_period).await
+    }
 }
 
 impl LatestCheckpointReorgReporter {
@@ -122,18 +148,45 @@ impl LatestCheckpointReorgReporter {
 
         join_all(futures).await
     }
+
+    async fn probe_endpoints(&self, reorg_period: &ReorgPeriod) -> Vec<EndpointProbe> {
+        let futures = self
+            .merkle_tree_hooks
+            .iter()
+            .map(|(url, merkle_tree_hook)| async {
+                let start = Instant::now();
+                let result = timeout(
+                    ENDPOINT_PROBE_TIMEOUT,
+                    merkle_tree_hook.latest_checkpoint(reorg_period),
+                )
+                .await;
+                let latency_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+                let (latest_checkpoint_index, error) = match result {
+                    Ok(Ok(checkpoint)) => (Some(checkpoint.checkpoint.index), None),
+                    Ok(Err(err)) => (None, Some(err.to_string())),
+                    Err(_) => (None, Some("Timed out".to_string())),
+                };
+                EndpointProbe {
+                    host: url.host_str().map(str::to_string),
+                    url_hash: H256::from_slice(&keccak256(url.as_str().as_bytes())),
+                    latency_ms,
+                    latest_checkpoint_index,
+                    error,
+                }
+            });
+        join_all(futures).await
+    }
 }
 
 impl LatestCheckpointReorgReporter {
     pub(crate) async fn from_settings(
         settings: &ValidatorSettings,
+        origin: &HyperlaneDomain,
         metrics: &CoreMetrics,
     ) -> eyre::Result<Self> {
-        let origin = &settings.origin_chain;
-
         let mut merkle_tree_hooks = HashMap::new();
         for (url, settings) in Self::settings_with_single_rpc(settings, origin) {
-            let chain_setup = settings.chain_setup(&settings.origin_chain)?;
+            let chain_setup = settings.chain_setup(origin)?;
             let merkle_tree_hook = chain_setup.build_merkle_tree_hook(metrics).await?;
 
             merkle_tree_hooks.insert(url, merkle_tree_hook.into());
@@ -151,7 +204,7 @@ impl LatestCheckpointReorgReporter {
         #[cfg(feature = "aleo")]
         use ChainConnectionConf::Aleo;
         use ChainConnectionConf::{
-            Cosmos, CosmosNative, Ethereum, Fuel, Radix, Sealevel, Starknet, Tron,
+            Aptos, Cosmos, CosmosNative, Ethereum, Fuel, Radix, Sealevel, Starknet, Tron,
         };
 
         let chain_conf = settings
@@ -166,7 +219,13 @@ impl LatestCheckpointReorgReporter {
                 updated_conn.rpc_connection = RpcConnectionConf::Http { url };
                 Ethereum(updated_conn)
             }),
-            Fuel(_) => todo!("Fuel connection not implemented"),
+            Fuel(conn) => {
+                Self::map_urls_to_connections(vec![conn.url.clone()], conn, |conn, url| {
+                    let mut updated_conn = conn.clone();
+                    updated_conn.url = url;
+                    Fuel(updated_conn)
+                })
+            }
             Sealevel(conn) => {
                 Self::map_urls_to_connections(conn.urls.clone(), conn, |conn, url| {
                     let mut updated_conn = conn.clone();
@@ -214,6 +273,11 @@ impl LatestCheckpointReorgReporter {
                     Tron(updated_conn)
                 })
             }
+            Aptos(conn) => Self::map_urls_to_connections(conn.urls.clone(), conn, |conn, url| {
+                let mut updated_conn = conn.clone();
+                updated_conn.urls = vec![url];
+                Aptos(updated_conn)
+            }),
         };
 
         chain_conn_confs
@@ -274,17 +338,24 @@ impl ReorgReporter for LatestCheckpointReorgReporterWithStorageWriter {
             .await;
         self.submit_to_storage_writer(&logs).await;
     }
+
+    async fn probe_endpoints(&self, reorg_period: &ReorgPeriod) -> Vec<EndpointProbe> {
+        self.latest_checkpoint_reorg_reporter
+            .probe_endpoints(reorg_period)
+            .await
+    }
 }
 
 impl LatestCheckpointReorgReporterWithStorageWriter {
     pub(crate) async fn from_settings_with_storage_writer(
         settings: &ValidatorSettings,
+        origin: &HyperlaneDomain,
         metrics: &CoreMetrics,
         storage_writer: Arc<dyn CheckpointSyncer>,
     ) -> eyre::Result<Self> {
         Ok(LatestCheckpointReorgReporterWithStorageWriter {
             latest_checkpoint_reorg_reporter: LatestCheckpointReorgReporter::from_settings(
-                settings, metrics,
+                settings, origin, metrics,
             )
             .await?,
             storage_writer,
@@ -0,0 +1,74 @@
+use axum::{extract::State, routing::get, Router};
+use derive_new::new;
+
+use hyperlane_base::server::utils::{ServerResult, ServerSuccessResponse};
+
+use crate::key_rotation::{KeyRotationInfo, KeyRotationState};
+
+#[derive(Clone, Debug, new)]
+pub struct ServerState {
+    pub rotation: KeyRotationInfo,
+}
+
+impl ServerState {
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/key_rotation", get(handler))
+            .with_state(self)
+    }
+}
+
+/// Get the state of the key rotation in progress
+pub async fn handler(
+    State(state): State<ServerState>,
+) -> ServerResult<ServerSuccessResponse<KeyRotationState>> {
+    Ok(ServerSuccessResponse::new(state.rotation.state()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use tower::ServiceExt;
+
+    use hyperlane_core::H160;
+
+    use crate::{key_rotation::KeyRotationPhase, test_utils::request::parse_body_to_json};
+
+    use super::*;
+
+    async fn get_state(rotation: KeyRotationInfo) -> KeyRotationState {
+        let app = ServerState::new(rotation).router();
+        let request = Request::builder()
+            .uri("/key_rotation")
+            .body(Body::empty())
+            .expect("Failed to build request");
+        let response = app.oneshot(request).await.expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::OK);
+        parse_body_to_json(response.into_body()).await
+    }
+
+    #[tokio::test]
+    async fn test_key_rotation_state() {
+        let rotation = KeyRotationInfo {
+            previous_validator: H160::from_low_u64_be(1),
+            validator: H160::from_low_u64_be(2),
+            storage_location: "file:///tmp/new".to_string(),
+            ends_at: u64::MAX,
+        };
+
+        let state = get_state(rotation.clone()).await;
+        assert_eq!(state.phase, KeyRotationPhase::DualSigning);
+        assert_eq!(state.validator, rotation.validator);
+        assert_eq!(state.previous_validator, rotation.previous_validator);
+
+        let state = get_state(KeyRotationInfo {
+            ends_at: 0,
+            ..rotation
+        })
+        .await;
+        assert_eq!(state.phase, KeyRotationPhase::Completed);
+    }
+}
@@ -1,5 +1,8 @@
 pub mod eigen_node;
+pub mod key_rotation;
 pub mod merkle_tree_insertions;
+pub mod signing_journal;
+pub mod status;
 
 pub use eigen_node::EigenNodeApi;
 
@@ -12,8 +15,8 @@ use hyperlane_core::HyperlaneDomain;
 
 /// Returns a vector of validator-specific endpoint routes to be served.
 /// Can be extended with additional routes and feature flags to enable/disable individually.
-pub fn router(origin_chain: HyperlaneDomain, metrics: Arc<CoreMetrics>) -> Router {
-    let eigen_node_api = EigenNodeApi::new(origin_chain, metrics);
+pub fn router(origin_chains: Vec<HyperlaneDomain>, metrics: Arc<CoreMetrics>) -> Router {
+    let eigen_node_api = EigenNodeApi::new(origin_chains, metrics);
 
     eigen_node_api.router()
 }
@@ -0,0 +1,101 @@
+use axum::{extract::State, http::StatusCode, routing::get, Router};
+use derive_new::new;
+
+use hyperlane_base::server::utils::{
+    ServerErrorBody, ServerErrorResponse, ServerResult, ServerSuccessResponse,
+};
+use hyperlane_core::{H160, H256};
+
+use crate::signing_journal::{SigningJournal, SigningJournalInterchange};
+
+#[derive(Clone, Debug, new)]
+pub struct ServerState {
+    pub journal: SigningJournal,
+    pub validator: H160,
+    pub mailbox_domain: u32,
+    pub merkle_tree_hook_address: H256,
+}
+
+impl ServerState {
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/signing_journal", get(handler))
+            .with_state(self)
+    }
+}
+
+/// Export the signing journal in the interchange format, to be imported
+/// on another host through `signingJournalImport`
+pub async fn handler(
+    State(state): State<ServerState>,
+) -> ServerResult<ServerSuccessResponse<SigningJournalInterchange>> {
+    let interchange = state
+        .journal
+        .export(
+            state.validator,
+            state.mailbox_domain,
+            state.merkle_tree_hook_address,
+        )
+        .map_err(|err| {
+            let error_msg = "Failed to export signing journal";
+            tracing::warn!(?err, "{error_msg}");
+            ServerErrorResponse::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ServerErrorBody {
+                    message: error_msg.to_string(),
+                },
+            )
+        })?;
+    Ok(ServerSuccessResponse::new(interchange))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    use hyperlane_base::db::{HyperlaneRocksDB, DB};
+    use hyperlane_core::{
+        Checkpoint, CheckpointWithMessageId, HyperlaneDomain, KnownHyperlaneDomain,
+    };
+
+    use crate::test_utils::request::parse_body_to_json;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_export_signing_journal() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = DB::from_path(temp_dir.path()).unwrap();
+        let domain = HyperlaneDomain::Known(KnownHyperlaneDomain::Arbitrum);
+        let journal = SigningJournal::new(Arc::new(HyperlaneRocksDB::new(&domain, db)));
+
+        let hook_address = H256::from_low_u64_be(0x42);
+        let checkpoint = CheckpointWithMessageId {
+            checkpoint: Checkpoint {
+                merkle_tree_hook_address: hook_address,
+                mailbox_domain: domain.id(),
+                root: H256::from_low_u64_be(1),
+                index: 0,
+            },
+            message_id: H256::from_low_u64_be(2),
+        };
+        journal.check_and_record(&checkpoint).await.unwrap();
+
+        let app = ServerState::new(journal, H160::zero(), domain.id(), hook_address).router();
+        let request = Request::builder()
+            .uri("/signing_journal")
+            .body(Body::empty())
+            .expect("Failed to build request");
+        let response = app.oneshot(request).await.expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let interchange: SigningJournalInterchange = parse_body_to_json(response.into_body()).await;
+        assert_eq!(interchange.metadata.mailbox_domain, domain.id());
+        assert_eq!(interchange.checkpoints.len(), 1);
+        assert_eq!(interchange.checkpoints[0].root, checkpoint.root);
+        assert_eq!(interchange.checkpoints[0].message_id, checkpoint.message_id);
+    }
+}
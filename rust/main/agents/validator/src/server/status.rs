@@ -0,0 +1,460 @@
+//! Validator status routes
+//!
+//! - /status - Status of every origin, see [`OriginStatusResponse`]. RPC
+//!   endpoints are probed at most once every `RPC_PROBES_TTL`
+//! - /status/live - Liveness probe, 503 once validation halted on every origin
+//! - /status/ready - Readiness probe, 503 until every origin is announced and
+//!   signing checkpoints within [`READY_MAX_CHECKPOINT_LAG`] of the tree
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use axum::{extract::State, http::StatusCode, routing::get, Json, Router};
+use futures_util::future::join_all;
+use serde::{Deserialize, Serialize};
+use tokio::{sync::Mutex, time::timeout};
+
+use hyperlane_base::{CheckpointSyncer, CoreMetrics};
+use hyperlane_core::{HyperlaneDomain, ReorgEvent, ReorgPeriod, H160};
+
+use crate::{
+    reorg_reporter::{EndpointProbe, ReorgReporter},
+    status::{OriginStatus, OriginStatusSnapshot},
+};
+
+/// Largest distance between the tree and the latest signed checkpoint for an
+/// origin to be ready, in checkpoints
+pub const READY_MAX_CHECKPOINT_LAG: i64 = 10;
+
+/// Timeout of the requests to a checkpoint syncer
+const CHECKPOINT_SYNCER_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long the RPC endpoint probes of an origin are reused for, so that
+/// polling `/status` doesn't query every RPC on each request
+const RPC_PROBES_TTL: Duration = Duration::from_secs(30);
+
+/// A validator key of an origin and where it writes checkpoints
+#[derive(Clone, Debug)]
+pub struct SignerSource {
+    pub validator: H160,
+    pub checkpoint_syncer: Arc<dyn CheckpointSyncer>,
+}
+
+/// Everything the status of an origin is built from
+#[derive(Clone, Debug)]
+pub struct OriginSource {
+    pub origin_chain: HyperlaneDomain,
+    pub reorg_period: ReorgPeriod,
+    pub status: OriginStatus,
+    pub signers: Vec<SignerSource>,
+    pub reorg_reporter: Arc<dyn ReorgReporter>,
+}
+
+/// Latest RPC endpoint probes of an origin
+#[derive(Debug, Default)]
+struct CachedProbes {
+    probed_at: Option<Instant>,
+    probes: Vec<EndpointProbe>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ServerState {
+    origins: Vec<OriginSource>,
+    core_metrics: Arc<CoreMetrics>,
+    rpc_probes: Arc<HashMap<HyperlaneDomain, Mutex<CachedProbes>>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckpointSyncerStatus {
+    pub validator: H160,
+    pub location: String,
+    pub latest_index: Option<u32>,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorgStatus {
+    pub detected: bool,
+    pub event: Option<ReorgEvent>,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OriginStatusResponse {
+    pub origin_chain: String,
+    /// Latest checkpoint index of the on-chain tree, at the reorg period
+    pub latest_tree_index: i64,
+    /// Latest checkpoint index signed by the validator
+    pub latest_signed_index: i64,
+    pub checkpoint_lag: i64,
+    pub checkpoint_syncers: Vec<CheckpointSyncerStatus>,
+    pub reorg: ReorgStatus,
+    pub rpc_endpoints: Vec<EndpointProbe>,
+    #[serde(flatten)]
+    pub status: OriginStatusSnapshot,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbeResponse {
+    pub ok: bool,
+    /// Origins failing the probe
+    pub failing: Vec<String>,
+}
+
+impl ServerState {
+    pub fn new(origins: Vec<OriginSource>, core_metrics: Arc<CoreMetrics>) -> Self {
+        let rpc_probes = origins
+            .iter()
+            .map(|origin| (origin.origin_chain.clone(), Mutex::default()))
+            .collect();
+        Self {
+            origins,
+            core_metrics,
+            rpc_probes: Arc::new(rpc_probes),
+        }
+    }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/status", get(status_handler))
+            .route("/status/live", get(live_handler))
+            .route("/status/ready", get(ready_handler))
+            .with_state(self)
+    }
+
+    fn latest_indices(&self, origin_chain: &HyperlaneDomain) -> (i64, i64) {
+        let latest_checkpoint = self.core_metrics.latest_checkpoint();
+        let tree_index = latest_checkpoint
+            .with_label_values(&["validator_observed", origin_chain.name()])
+            .get();
+        let signed_index = latest_checkpoint
+            .with_label_values(&["validator_processed", origin_chain.name()])
+            .get();
+        (tree_index, signed_index)
+    }
+
+    async fn origin_status(&self, origin: &OriginSource) -> OriginStatusResponse {
+        let (latest_tree_index, latest_signed_index) = self.latest_indices(&origin.origin_chain);
+        let checkpoint_syncers = join_all(origin.signers.iter().map(checkpoint_syncer_status));
+        let rpc_endpoints = self.rpc_probes(origin);
+        let (checkpoint_syncers, rpc_endpoints) = tokio::join!(checkpoint_syncers, rpc_endpoints);
+        let reorg = match origin.signers.first() {
+            Some(signer) => reorg_status(signer.checkpoint_syncer.as_ref()).await,
+            None => ReorgStatus {
+                detected: false,
+                event: None,
+                error: None,
+            },
+        };
+        OriginStatusResponse {
+            origin_chain: origin.origin_chain.name().to_string(),
+            latest_tree_index,
+            latest_signed_index,
+            checkpoint_lag: latest_tree_index.saturating_sub(latest_signed_index),
+            checkpoint_syncers,
+            reorg,
+            rpc_endpoints,
+            status: origin.status.snapshot().await,
+        }
+    }
+
+    /// Probes of the RPC endpoints of an origin, reused for [`RPC_PROBES_TTL`].
+    /// Concurrent requests wait for the same probes rather than sending their own.
+    async fn rpc_probes(&self, origin: &OriginSource) -> Vec<EndpointProbe> {
+        let Some(cached) = self.rpc_probes.get(&origin.origin_chain) else {
+            return origin
+                .reorg_reporter
+                .probe_endpoints(&origin.reorg_period)
+                .await;
+        };
+        let mut cached = cached.lock().await;
+        let fresh = cached
+            .probed_at
+            .is_some_and(|probed_at| probed_at.elapsed() < RPC_PROBES_TTL);
+        if !fresh {
+            cached.probes = origin
+                .reorg_reporter
+                .probe_endpoints(&origin.reorg_period)
+                .await;
+            cached.probed_at = Some(Instant::now());
+        }
+        cached.probes.clone()
+    }
+
+    async fn is_ready(&self, origin: &OriginSource) -> bool {
+        let (tree_index, signed_index) = self.latest_indices(&origin.origin_chain);
+        let status = origin.status.snapshot().await;
+        status.is_announced()
+            && status.submitting
+            && !status.halted
+            && tree_index.saturating_sub(signed_index) <= READY_MAX_CHECKPOINT_LAG
+    }
+}
+
+async fn checkpoint_syncer_status(signer: &SignerSource) -> CheckpointSyncerStatus {
+    let (latest_index, error) = match timeout(
+        CHECKPOINT_SYNCER_TIMEOUT,
+        signer.checkpoint_syncer.latest_index(),
+    )
+    .await
+    {
+        Ok(Ok(latest_index)) => (latest_index, None),
+        Ok(Err(err)) => (None, Some(err.to_string())),
+        Err(_) => (None, Some("Timed out".to_string())),
+    };
+    CheckpointSyncerStatus {
+        validator: signer.validator,
+        location: signer.checkpoint_syncer.announcement_location(),
+        latest_index,
+        error,
+    }
+}
+
+async fn reorg_status(checkpoint_syncer: &dyn CheckpointSyncer) -> ReorgStatus {
+    match timeout(CHECKPOINT_SYNCER_TIMEOUT, checkpoint_syncer.reorg_status()).await {
+        Ok(Ok(response)) => ReorgStatus {
+            detected: response.exists,
+            event: response.event,
+            error: None,
+        },
+        Ok(Err(err)) => ReorgStatus {
+            detected: false,
+            event: None,
+            error: Some(err.to_string()),
+        },
+        Err(_) => ReorgStatus {
+            detected: false,
+            event: None,
+            error: Some("Timed out".to_string()),
+        },
+    }
+}
+
+fn probe_response(failing: Vec<String>, ok: bool) -> (StatusCode, Json<ProbeResponse>) {
+    let code = if ok {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (code, Json(ProbeResponse { ok, failing }))
+}
+
+/// Status of every origin
+pub async fn status_handler(State(state): State<ServerState>) -> Json<Vec<OriginStatusResponse>> {
+    let statuses = join_all(
+        state
+            .origins
+            .iter()
+            .map(|origin| state.origin_status(origin)),
+    )
+    .await;
+    Json(statuses)
+}
+
+/// Live while validation is running on at least one origin
+pub async fn live_handler(State(state): State<ServerState>) -> (StatusCode, Json<ProbeResponse>) {
+    let mut failing = vec![];
+    for origin in &state.origins {
+        if origin.status.snapshot().await.halted {
+            failing.push(origin.origin_chain.name().to_string());
+        }
+    }
+    let ok = failing.len() < state.origins.len();
+    probe_response(failing, ok)
+}
+
+/// Ready once every origin is announced and keeping up with the tree
+pub async fn ready_handler(State(state): State<ServerState>) -> (StatusCode, Json<ProbeResponse>) {
+    let mut failing = vec![];
+    for origin in &state.origins {
+        if !state.is_ready(origin).await {
+            failing.push(origin.origin_chain.name().to_string());
+        }
+    }
+    let ok = failing.is_empty();
+    probe_response(failing, ok)
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use axum::{body::Body, http::Request};
+    use prometheus::Registry;
+    use tower::ServiceExt;
+
+    use hyperlane_base::LocalStorage;
+
+    use crate::{status::AnnounceStatus, test_utils::request::parse_body_to_json};
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct FixedReorgReporter {
+        probes: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ReorgReporter for FixedReorgReporter {
+        async fn report_at_block(&self, _height: u64) {}
+        async fn report_with_reorg_period(&self, _reorg_period: &ReorgPeriod) {}
+
+        async fn probe_endpoints(&self, _reorg_period: &ReorgPeriod) -> Vec<EndpointProbe> {
+            self.probes
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            vec![EndpointProbe {
+                host: Some("rpc.example.com".to_string()),
+                url_hash: Default::default(),
+                latency_ms: 12,
+                latest_checkpoint_index: Some(41),
+                error: None,
+            }]
+        }
+    }
+
+    struct TestSetup {
+        app: Router,
+        status: OriginStatus,
+        core_metrics: Arc<CoreMetrics>,
+        reorg_reporter: Arc<FixedReorgReporter>,
+        _temp_dir: tempfile::TempDir,
+    }
+
+    fn setup() -> TestSetup {
+        let core_metrics =
+            Arc::new(CoreMetrics::new("dummy_validator", 37582, Registry::new()).unwrap());
+        let temp_dir = tempfile::tempdir().unwrap();
+        let checkpoint_syncer = LocalStorage::new(temp_dir.path().to_path_buf(), None).unwrap();
+        let status = OriginStatus::default();
+        let reorg_reporter = Arc::new(FixedReorgReporter::default());
+        let origin = OriginSource {
+            origin_chain: HyperlaneDomain::new_test_domain("ethereum"),
+            reorg_period: ReorgPeriod::from_blocks(1),
+            status: status.clone(),
+            signers: vec![SignerSource {
+                validator: H160::from_low_u64_be(1),
+                checkpoint_syncer: Arc::new(checkpoint_syncer),
+            }],
+            reorg_reporter: reorg_reporter.clone(),
+        };
+        let app = ServerState::new(vec![origin], core_metrics.clone()).router();
+        TestSetup {
+            app,
+            status,
+            core_metrics,
+            reorg_reporter,
+            _temp_dir: temp_dir,
+        }
+    }
+
+    async fn get(app: &Router, uri: &str) -> axum::response::Response {
+        let request = Request::builder()
+            .uri(uri)
+            .body(Body::empty())
+            .expect("Failed to build request");
+        app.clone()
+            .oneshot(request)
+            .await
+            .expect("Failed to send request")
+    }
+
+    fn set_checkpoints(core_metrics: &CoreMetrics, observed: i64, processed: i64) {
+        core_metrics
+            .latest_checkpoint()
+            .with_label_values(&["validator_observed", "ethereum"])
+            .set(observed);
+        core_metrics
+            .latest_checkpoint()
+            .with_label_values(&["validator_processed", "ethereum"])
+            .set(processed);
+    }
+
+    #[tokio::test]
+    async fn test_status() {
+        let TestSetup {
+            app,
+            status,
+            core_metrics,
+            ..
+        } = setup();
+        set_checkpoints(&core_metrics, 42, 40);
+        status
+            .set_announce_status(H160::from_low_u64_be(1), AnnounceStatus::announced())
+            .await;
+
+        let response = get(&app, "/status").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let statuses: Vec<OriginStatusResponse> = parse_body_to_json(response.into_body()).await;
+
+        assert_eq!(statuses.len(), 1);
+        let origin = &statuses[0];
+        assert_eq!(origin.origin_chain, "ethereum");
+        assert_eq!(origin.checkpoint_lag, 2);
+        assert!(origin.status.is_announced());
+        assert!(!origin.reorg.detected);
+        assert_eq!(origin.checkpoint_syncers[0].latest_index, None);
+        assert_eq!(origin.rpc_endpoints[0].latest_checkpoint_index, Some(41));
+    }
+
+    #[tokio::test]
+    async fn test_probes() {
+        let TestSetup {
+            app,
+            status,
+            core_metrics,
+            ..
+        } = setup();
+        set_checkpoints(&core_metrics, 42, 42);
+
+        assert_eq!(get(&app, "/status/live").await.status(), StatusCode::OK);
+        assert_eq!(
+            get(&app, "/status/ready").await.status(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        status
+            .set_announce_status(H160::from_low_u64_be(1), AnnounceStatus::announced())
+            .await;
+        status.set_submitting().await;
+        assert_eq!(get(&app, "/status/ready").await.status(), StatusCode::OK);
+
+        // falling behind the tree
+        set_checkpoints(&core_metrics, 100, 42);
+        assert_eq!(
+            get(&app, "/status/ready").await.status(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        status.set_halted().await;
+        let response = get(&app, "/status/live").await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body: ProbeResponse = parse_body_to_json(response.into_body()).await;
+        assert_eq!(body.failing, vec!["ethereum".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_status_reuses_rpc_probes() {
+        let TestSetup {
+            app,
+            reorg_reporter,
+            ..
+        } = setup();
+
+        for _ in 0..3 {
+            assert_eq!(get(&app, "/status").await.status(), StatusCode::OK);
+        }
+
+        assert_eq!(
+            reorg_reporter
+                .probes
+                .load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+    }
+}
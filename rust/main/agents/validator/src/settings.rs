@@ -13,6 +13,10 @@ use hyperlane_base::{
     impl_loadable_from_settings,
     settings::{
         parser::{RawAgentConf, RawAgentSignerConf, ValueParser},
+        schema::{
+            boolean, checkpoint_syncer_schema, integer, object_properties, signer_schema,
+            RawConfSchema,
+        },
         CheckpointSyncerConf, Settings, SignerConf,
     },
 };
@@ -21,7 +25,7 @@ use hyperlane_core::{
 };
 use itertools::Itertools;
 use serde::Deserialize;
-use serde_json::Value;
+use serde_json::{json, Map, Value};
 
 /// Settings for RPCs
 #[derive(Debug, Clone)]
@@ -30,6 +34,51 @@ pub struct RpcConfig {
     pub public: bool,
 }
 
+/// Settings for validating a single origin chain
+#[derive(Debug, Clone)]
+pub struct OriginSettings {
+    /// Chain to validate messages on
+    pub origin_chain: HyperlaneDomain,
+    /// The validator attestation signer
+    pub validator: SignerConf,
+    /// The checkpoint syncer configuration
+    pub checkpoint_syncer: CheckpointSyncerConf,
+    /// The reorg configuration
+    pub reorg_period: ReorgPeriod,
+    /// A list of RPCs that the validator uses for this chain
+    pub rpcs: Vec<RpcConfig>,
+    /// Signing journal exported from another host, merged into the local
+    /// journal on startup
+    pub signing_journal_import: Option<PathBuf>,
+    /// Rotation to a new validator key, if one is in progress
+    pub rotation: Option<KeyRotationSettings>,
+}
+
+/// Settings for rotating the validator key of an origin. Until `ends_at`,
+/// every checkpoint is signed by both the current and the new key.
+#[derive(Debug, Clone)]
+pub struct KeyRotationSettings {
+    /// The new validator attestation signer
+    pub validator: SignerConf,
+    /// Where checkpoints signed by the new key are written, apart from the
+    /// ones signed by the current key
+    pub checkpoint_syncer: CheckpointSyncerConf,
+    /// Unix timestamp in seconds at which the current key stops signing
+    pub ends_at: u64,
+}
+
+/// Settings for the `audit` subcommand
+#[derive(Debug, Clone, Default)]
+pub struct AuditSettings {
+    /// First checkpoint index to verify
+    pub from_index: u32,
+    /// Last checkpoint index to verify, defaults to the latest index in the
+    /// checkpoint syncer
+    pub to_index: Option<u32>,
+    /// Where to write the report, defaults to stdout
+    pub output: Option<PathBuf>,
+}
+
 /// Settings for `Validator`
 #[derive(Debug, AsRef, AsMut, Deref, DerefMut, Clone)]
 pub struct ValidatorSettings {
@@ -39,24 +88,29 @@ pub struct ValidatorSettings {
     #[deref_mut]
     base: Settings,
 
-    /// Database path
+    /// Database path, shared by all origins
     pub db: PathBuf,
-    /// Chain to validate messages on
-    pub origin_chain: HyperlaneDomain,
-    /// The validator attestation signer
-    pub validator: SignerConf,
-    /// The checkpoint syncer configuration
-    pub checkpoint_syncer: CheckpointSyncerConf,
-    /// The reorg configuration
-    pub reorg_period: ReorgPeriod,
+    /// Path of the database recording every checkpoint the validator signed.
+    /// Kept apart from `db` so that wiping the indexing data keeps it intact.
+    pub signing_journal_db: PathBuf,
+    /// Chains to validate messages on, either the single `originChainName`
+    /// or every chain in `origins`
+    pub origins: Vec<OriginSettings>,
     /// How frequently to check for new checkpoints
     pub interval: Duration,
-    /// A list of RPCs that the validator uses
-    pub rpcs: Vec<RpcConfig>,
     /// If the validator oped into public RPCs
     pub allow_public_rpcs: bool,
     /// Max sign concurrency
     pub max_sign_concurrency: usize,
+    /// Settings for the `audit` subcommand
+    pub audit: AuditSettings,
+}
+
+impl ValidatorSettings {
+    /// All RPCs the validator uses, across origins
+    pub fn rpcs(&self) -> impl Iterator<Item = &RpcConfig> {
+        self.origins.iter().flat_map(|origin| origin.rpcs.iter())
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -65,6 +119,58 @@ struct RawValidatorSettings(Value);
 
 impl_loadable_from_settings!(Validator, RawValidatorSettings -> ValidatorSettings);
 
+/// Keys configuring a single origin, either at the top level of the config
+/// with `originChainName` or in each entry of `origins`
+fn origin_properties() -> Value {
+    json!({
+        "validator": signer_schema(),
+        "checkpointSyncer": checkpoint_syncer_schema(),
+        "reorgPeriod": { "type": ["integer", "string"] },
+        "signingJournalImport": { "type": "string" },
+        "rotation": {
+            "type": "object",
+            "properties": {
+                "validator": signer_schema(),
+                "checkpointSyncer": checkpoint_syncer_schema(),
+                "endsAt": integer(),
+            },
+            "required": ["validator", "checkpointSyncer", "endsAt"],
+        },
+    })
+}
+
+impl RawConfSchema for RawValidatorSettings {
+    fn agent_properties() -> Map<String, Value> {
+        let mut properties = object_properties(json!({
+            "originChainName": { "type": "string" },
+            "origins": {
+                "description": "Settings of each origin, by chain name",
+                "type": "object",
+                "additionalProperties": {
+                    "type": "object",
+                    "properties": origin_properties(),
+                    "required": ["checkpointSyncer"],
+                },
+            },
+            "allowPublicRpcs": boolean(),
+            "db": { "type": "string" },
+            "signingJournalDb": { "type": "string" },
+            "interval": integer(),
+            "maxSignConcurrency": integer(),
+            "audit": {
+                "type": "object",
+                "properties": {
+                    "fromIndex": integer(),
+                    "toIndex": integer(),
+                    "output": { "type": "string" },
+                },
+            },
+        }));
+        properties.extend(object_properties(origin_properties()));
+        properties
+    }
+}
+
 impl FromRawConf<RawValidatorSettings> for ValidatorSettings {
     fn from_config_filtered(
         raw: RawValidatorSettings,
@@ -84,58 +190,87 @@ impl FromRawConf<RawValidatorSettings> for ValidatorSettings {
 
         let origin_chain_name = p
             .chain(&mut err)
-            .get_key("originChainName")
+            .get_opt_key("originChainName")
             .parse_string()
             .end();
 
+        let origins_config = p
+            .chain(&mut err)
+            .get_opt_key("origins")
+            .into_obj_iter()
+            .map(|origins| origins.collect_vec());
+
+        // Each origin is configured by its own object in `origins`, or by the
+        // top level of the config when only `originChainName` is set.
+        let origin_parsers: Vec<(String, ValueParser)> = match (origin_chain_name, origins_config) {
+            (Some(name), None) => vec![(name.to_owned(), p.clone())],
+            (None, Some(origins)) if !origins.is_empty() => origins,
+            (Some(_), Some(_)) => {
+                err.push(
+                    cwp.add("origins"),
+                    eyre!("Expected only one of `originChainName` and `origins` to be defined"),
+                );
+                vec![]
+            }
+            _ => {
+                err.push(
+                    cwp.add("origin_chain_name"),
+                    eyre!("Expected `originChainName` or `origins` to be defined"),
+                );
+                vec![]
+            }
+        };
+        let multi_origin = origin_chain_name.is_none();
+
         let allow_public_rpcs = p
             .chain(&mut err)
             .get_opt_key("allowPublicRpcs")
             .parse_bool()
             .unwrap_or(false);
 
-        let origin_chain_name_set = origin_chain_name.map(|s| HashSet::from([s]));
+        let origin_chain_name_set: HashSet<&str> = origin_parsers
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
 
         let base: Option<Settings> = p
             .parse_from_raw_config::<Settings, RawAgentConf, Option<&HashSet<&str>>>(
-                origin_chain_name_set.as_ref(),
+                Some(&origin_chain_name_set),
                 "Expected valid base agent configuration",
                 agent_name.to_string(),
             )
             .take_config_err(&mut err);
 
-        let origin_chain = if let (Some(base), Some(origin_chain_name)) = (&base, origin_chain_name)
-        {
-            base.lookup_domain(origin_chain_name)
-                .context("Missing configuration for the origin chain")
-                .take_err(&mut err, || cwp.add("origin_chain_name"))
+        // With several origins, a top level signer is used by every origin
+        // that doesn't set its own
+        let default_validator = if multi_origin {
+            p.chain(&mut err)
+                .get_opt_key("validator")
+                .parse_from_raw_config::<SignerConf, RawAgentSignerConf, NoFilter>(
+                    (),
+                    "Expected valid validator configuration",
+                    agent_name.to_string(),
+                )
+                .end()
         } else {
             None
         };
 
-        let validator = p
-            .chain(&mut err)
-            .get_key("validator")
-            .parse_from_raw_config::<SignerConf, RawAgentSignerConf, NoFilter>(
-                (),
-                "Expected valid validator configuration",
-                agent_name.to_string(),
-            )
-            .end();
+        let db_suffix = origin_chain_name
+            .map(|name| format!("_{name}"))
+            .unwrap_or_default();
 
         let db = p
             .chain(&mut err)
             .get_opt_key("db")
             .parse_from_str("Expected db file path")
-            .unwrap_or_else(|| {
-                curr_dir.join(format!("validator_db_{}", origin_chain_name.unwrap_or("")))
-            });
+            .unwrap_or_else(|| curr_dir.join(format!("validator_db{db_suffix}")));
 
-        let checkpoint_syncer = p
+        let signing_journal_db = p
             .chain(&mut err)
-            .get_key("checkpointSyncer")
-            .and_then(parse_checkpoint_syncer)
-            .end();
+            .get_opt_key("signingJournalDb")
+            .parse_from_str("Expected signing journal db file path")
+            .unwrap_or_else(|| curr_dir.join(format!("validator_signing_journal{db_suffix}")));
 
         let interval = p
             .chain(&mut err)
@@ -144,27 +279,26 @@ impl FromRawConf<RawValidatorSettings> for ValidatorSettings {
             .map(Duration::from_secs)
             .unwrap_or(Duration::from_secs(2));
 
-        cfg_unwrap_all!(cwp, err: [origin_chain_name]);
-
-        let reorg_period = p
-            .chain(&mut err)
-            .get_key("chains")
-            .get_key(origin_chain_name)
-            .get_opt_key("blocks")
-            .get_opt_key("reorgPeriod")
-            .parse_value("Invalid reorgPeriod")
-            .unwrap_or(ReorgPeriod::from_blocks(1));
-
-        let chain = p
-            .chain(&mut err)
-            .get_key("chains")
-            .get_key(origin_chain_name)
-            .end()
-            .ok_or_else(|| {
-                let mut config_err = ConfigParsingError::default();
-                config_err.push(cwp.clone(), eyre::eyre!("chains missing".to_string()));
-                config_err
-            })?;
+        let audit = AuditSettings {
+            from_index: p
+                .chain(&mut err)
+                .get_opt_key("audit")
+                .get_opt_key("fromIndex")
+                .parse_u32()
+                .unwrap_or(0),
+            to_index: p
+                .chain(&mut err)
+                .get_opt_key("audit")
+                .get_opt_key("toIndex")
+                .parse_u32()
+                .end(),
+            output: p
+                .chain(&mut err)
+                .get_opt_key("audit")
+                .get_opt_key("output")
+                .parse_from_str("Expected audit report file path")
+                .end(),
+        };
 
         let max_sign_concurrency = p
             .chain(&mut err)
@@ -172,52 +306,169 @@ impl FromRawConf<RawValidatorSettings> for ValidatorSettings {
             .parse_u64()
             .unwrap_or(50) as usize;
 
-        let mut rpcs = get_rpc_urls(&chain, "rpcUrls", "customRpcUrls", &mut err);
-        // this is only relevant for cosmos
-        rpcs.extend(get_rpc_urls(&chain, "grpcUrls", "customGrpcUrls", &mut err));
-        // tron wallet urls
-        rpcs.extend(get_rpc_urls(
-            &chain,
-            "walletUrls",
-            "customWalletUrls",
-            &mut err,
-        ));
-        rpcs.extend(get_rpc_urls(
-            &chain,
-            "walletSolidityUrls",
-            "customWalletSolidityUrls",
-            &mut err,
-        ));
+        let mut origins = vec![];
+        for (origin_chain_name, origin_p) in &origin_parsers {
+            let origin_chain = base.as_ref().and_then(|base| {
+                base.lookup_domain(origin_chain_name)
+                    .context("Missing configuration for the origin chain")
+                    .take_err(&mut err, || {
+                        if multi_origin {
+                            origin_p.cwp.clone()
+                        } else {
+                            cwp.add("origin_chain_name")
+                        }
+                    })
+            });
+
+            let validator = origin_p
+                .chain(&mut err)
+                .get_opt_key("validator")
+                .parse_from_raw_config::<SignerConf, RawAgentSignerConf, NoFilter>(
+                    (),
+                    "Expected valid validator configuration",
+                    agent_name.to_string(),
+                )
+                .end()
+                .or_else(|| default_validator.clone());
+            if validator.is_none() {
+                err.push(
+                    origin_p.cwp.add("validator"),
+                    eyre!("Expected key `validator` to be defined"),
+                );
+            }
+
+            let checkpoint_syncer = origin_p
+                .chain(&mut err)
+                .get_key("checkpointSyncer")
+                .and_then(parse_checkpoint_syncer)
+                .end();
+
+            let signing_journal_import = origin_p
+                .chain(&mut err)
+                .get_opt_key("signingJournalImport")
+                .parse_from_str("Expected signing journal import file path")
+                .end();
 
-        cfg_unwrap_all!(cwp, err: [base, origin_chain, validator, checkpoint_syncer]);
+            let rotation = origin_p
+                .chain(&mut err)
+                .get_opt_key("rotation")
+                .end()
+                .and_then(|rotation| {
+                    let validator = rotation
+                        .chain(&mut err)
+                        .get_key("validator")
+                        .parse_from_raw_config::<SignerConf, RawAgentSignerConf, NoFilter>(
+                            (),
+                            "Expected valid rotation validator configuration",
+                            agent_name.to_string(),
+                        )
+                        .end();
+                    let checkpoint_syncer = rotation
+                        .chain(&mut err)
+                        .get_key("checkpointSyncer")
+                        .and_then(parse_checkpoint_syncer)
+                        .end();
+                    let ends_at = rotation.chain(&mut err).get_key("endsAt").parse_u64().end();
+                    Some(KeyRotationSettings {
+                        validator: validator?,
+                        checkpoint_syncer: checkpoint_syncer?,
+                        ends_at: ends_at?,
+                    })
+                });
+
+            let reorg_period_override = if multi_origin {
+                origin_p
+                    .chain(&mut err)
+                    .get_opt_key("reorgPeriod")
+                    .parse_value("Invalid reorgPeriod")
+                    .end()
+            } else {
+                None
+            };
+            let reorg_period = reorg_period_override.unwrap_or_else(|| {
+                p.chain(&mut err)
+                    .get_key("chains")
+                    .get_key(origin_chain_name)
+                    .get_opt_key("blocks")
+                    .get_opt_key("reorgPeriod")
+                    .parse_value("Invalid reorgPeriod")
+                    .unwrap_or(ReorgPeriod::from_blocks(1))
+            });
+
+            let rpcs = p
+                .chain(&mut err)
+                .get_key("chains")
+                .get_key(origin_chain_name)
+                .end()
+                .map(|chain| get_chain_rpc_urls(&chain, &mut err))
+                .unwrap_or_default();
+
+            if let (Some(origin_chain), Some(validator), Some(checkpoint_syncer)) =
+                (origin_chain, validator, checkpoint_syncer)
+            {
+                origins.push(OriginSettings {
+                    origin_chain,
+                    validator,
+                    checkpoint_syncer,
+                    reorg_period,
+                    rpcs,
+                    signing_journal_import,
+                    rotation,
+                });
+            }
+        }
+
+        cfg_unwrap_all!(cwp, err: [base]);
 
         let mut base: Settings = base;
-        // Tron and Ethereum both use secp256k1 keys, so the validator attestation
-        // signer can double as the origin chain signer (used for self-announce txs).
-        if matches!(
-            origin_chain.domain_protocol(),
-            HyperlaneDomainProtocol::Ethereum | HyperlaneDomainProtocol::Tron
-        ) {
-            if let Some(origin) = base.chains.get_mut(&origin_chain) {
-                origin.signer.get_or_insert_with(|| validator.clone());
+        for origin in &origins {
+            // Tron and Ethereum both use secp256k1 keys, so the validator attestation
+            // signer can double as the origin chain signer (used for self-announce txs).
+            // Aptos accounts are derived from the same 32 byte hex key used as an
+            // ed25519 seed, which rules out remote signers.
+            let shares_validator_key = match origin.origin_chain.domain_protocol() {
+                HyperlaneDomainProtocol::Ethereum | HyperlaneDomainProtocol::Tron => true,
+                HyperlaneDomainProtocol::Aptos => {
+                    matches!(origin.validator, SignerConf::HexKey { .. })
+                }
+                _ => false,
+            };
+            if shares_validator_key {
+                if let Some(chain) = base.chains.get_mut(&origin.origin_chain) {
+                    chain.signer.get_or_insert_with(|| origin.validator.clone());
+                }
             }
         }
 
         err.into_result(Self {
             base,
             db,
-            origin_chain,
-            validator,
-            checkpoint_syncer,
-            reorg_period,
+            signing_journal_db,
+            origins,
             interval,
-            rpcs,
             allow_public_rpcs,
             max_sign_concurrency,
+            audit,
         })
     }
 }
 
+/// Extracts the rpc urls of every kind a chain may define
+fn get_chain_rpc_urls(chain: &ValueParser, err: &mut ConfigParsingError) -> Vec<RpcConfig> {
+    let mut rpcs = get_rpc_urls(chain, "rpcUrls", "customRpcUrls", err);
+    // this is only relevant for cosmos
+    rpcs.extend(get_rpc_urls(chain, "grpcUrls", "customGrpcUrls", err));
+    // tron wallet urls
+    rpcs.extend(get_rpc_urls(chain, "walletUrls", "customWalletUrls", err));
+    rpcs.extend(get_rpc_urls(
+        chain,
+        "walletSolidityUrls",
+        "customWalletSolidityUrls",
+        err,
+    ));
+    rpcs
+}
+
 /// Extracts all of the rpc urls
 ///
 /// rpcKey is either grpcUrls or rpcUrls
@@ -359,6 +610,8 @@ fn parse_checkpoint_syncer(syncer: ValueParser) -> ConfigResult<CheckpointSyncer
 
 #[cfg(test)]
 mod test {
+    use hyperlane_core::H256;
+
     use super::*;
 
     #[test]
@@ -452,4 +705,239 @@ mod test {
         assert_eq!(parsed[1].url, "http://my-rpc-url-4.com");
         assert!(!parsed[1].public);
     }
+
+    const KEY_A: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+    const KEY_B: &str = "0x0000000000000000000000000000000000000000000000000000000000000002";
+
+    fn chain_config(name: &str, domain_id: u32) -> Value {
+        json!({
+            "name": name,
+            "domainid": domain_id,
+            "chainid": domain_id,
+            "protocol": "ethereum",
+            "rpcurls": [{ "http": format!("http://{name}.rpc") }],
+            "blocks": { "reorgperiod": 3 },
+            "mailbox": "0x0000000000000000000000000000000000000001",
+            "interchaingaspaymaster": "0x0000000000000000000000000000000000000002",
+            "validatorannounce": "0x0000000000000000000000000000000000000003",
+            "merkletreehook": "0x0000000000000000000000000000000000000004",
+        })
+    }
+
+    fn local_syncer(path: &str) -> Value {
+        json!({ "type": "localStorage", "path": path })
+    }
+
+    fn parse_settings(raw: Value) -> ConfigResult<ValidatorSettings> {
+        ValidatorSettings::from_config_filtered(
+            RawValidatorSettings(raw),
+            &ConfigPath::default(),
+            (),
+            "validator",
+        )
+    }
+
+    fn origin<'a>(settings: &'a ValidatorSettings, name: &str) -> &'a OriginSettings {
+        settings
+            .origins
+            .iter()
+            .find(|origin| origin.origin_chain.name() == name)
+            .unwrap()
+    }
+
+    fn hex_key(signer: &SignerConf) -> H256 {
+        match signer {
+            SignerConf::HexKey { key } => *key,
+            _ => panic!("expected a hex key signer"),
+        }
+    }
+
+    #[test]
+    fn test_parse_multiple_origins() {
+        let settings = parse_settings(json!({
+            "chains": {
+                "alpha": chain_config("alpha", 1000),
+                "beta": chain_config("beta", 2000),
+            },
+            "validator": { "type": "hexKey", "key": KEY_A },
+            "origins": {
+                "alpha": {
+                    "checkpointsyncer": local_syncer("/tmp/alpha"),
+                    "reorgperiod": 5,
+                },
+                "beta": {
+                    "checkpointsyncer": local_syncer("/tmp/beta"),
+                    "validator": { "type": "hexKey", "key": KEY_B },
+                },
+            },
+        }))
+        .expect("multiple origins should parse");
+
+        assert_eq!(settings.origins.len(), 2);
+        // every origin shares the databases
+        assert!(settings.db.ends_with("validator_db"));
+
+        let alpha = origin(&settings, "alpha");
+        assert_eq!(hex_key(&alpha.validator), KEY_A.parse().unwrap());
+        assert_eq!(alpha.reorg_period, ReorgPeriod::from_blocks(5));
+        assert!(matches!(
+            &alpha.checkpoint_syncer,
+            CheckpointSyncerConf::LocalStorage { path } if path == &PathBuf::from("/tmp/alpha")
+        ));
+        assert_eq!(alpha.rpcs.len(), 1);
+        assert_eq!(alpha.rpcs[0].url, "http://alpha.rpc");
+
+        let beta = origin(&settings, "beta");
+        assert_eq!(hex_key(&beta.validator), KEY_B.parse().unwrap());
+        // falls back to the reorg period of the chain
+        assert_eq!(beta.reorg_period, ReorgPeriod::from_blocks(3));
+        assert_eq!(beta.rpcs[0].url, "http://beta.rpc");
+
+        // the validator key doubles as the signer of each origin chain
+        for origin in &settings.origins {
+            let signer = settings.chains[&origin.origin_chain].signer.as_ref();
+            assert_eq!(hex_key(signer.unwrap()), hex_key(&origin.validator));
+        }
+    }
+
+    #[test]
+    fn test_aptos_origin_uses_the_validator_key_as_signer() {
+        let mut aptos = chain_config("aptos", 3000);
+        aptos["protocol"] = json!("aptos");
+        let settings = parse_settings(json!({
+            "chains": { "aptos": aptos },
+            "originchainname": "aptos",
+            "validator": { "type": "hexKey", "key": KEY_A },
+            "checkpointsyncer": local_syncer("/tmp/aptos"),
+        }))
+        .expect("aptos origin should parse");
+
+        let origin = origin(&settings, "aptos");
+        let signer = settings.chains[&origin.origin_chain].signer.as_ref();
+        assert_eq!(hex_key(signer.unwrap()), KEY_A.parse().unwrap());
+    }
+
+    #[test]
+    fn test_parse_single_origin() {
+        let settings = parse_settings(json!({
+            "chains": { "alpha": chain_config("alpha", 1000) },
+            "originchainname": "alpha",
+            "validator": { "type": "hexKey", "key": KEY_A },
+            "checkpointsyncer": local_syncer("/tmp/alpha"),
+        }))
+        .expect("single origin should parse");
+
+        assert_eq!(settings.origins.len(), 1);
+        assert!(settings.db.ends_with("validator_db_alpha"));
+        let alpha = origin(&settings, "alpha");
+        assert_eq!(alpha.reorg_period, ReorgPeriod::from_blocks(3));
+    }
+
+    #[test]
+    fn test_parse_origins_rejects_origin_chain_name() {
+        let settings = parse_settings(json!({
+            "chains": { "alpha": chain_config("alpha", 1000) },
+            "originchainname": "alpha",
+            "validator": { "type": "hexKey", "key": KEY_A },
+            "checkpointsyncer": local_syncer("/tmp/alpha"),
+            "origins": {
+                "alpha": { "checkpointsyncer": local_syncer("/tmp/alpha") },
+            },
+        }));
+
+        assert!(settings.is_err());
+    }
+
+    #[test]
+    fn test_parse_origins_requires_a_validator() {
+        let error = parse_settings(json!({
+            "chains": {
+                "alpha": chain_config("alpha", 1000),
+                "beta": chain_config("beta", 2000),
+            },
+            "origins": {
+                "alpha": {
+                    "checkpointsyncer": local_syncer("/tmp/alpha"),
+                    "validator": { "type": "hexKey", "key": KEY_A },
+                },
+                "beta": { "checkpointsyncer": local_syncer("/tmp/beta") },
+            },
+        }))
+        .expect_err("an origin without a validator must reject")
+        .to_string();
+
+        assert!(error.contains("validator"), "unexpected error: {error}");
+    }
+
+    #[test]
+    fn test_parse_origins_rejects_unknown_chain() {
+        let settings = parse_settings(json!({
+            "chains": { "alpha": chain_config("alpha", 1000) },
+            "validator": { "type": "hexKey", "key": KEY_A },
+            "origins": {
+                "alpha": { "checkpointsyncer": local_syncer("/tmp/alpha") },
+                "gamma": { "checkpointsyncer": local_syncer("/tmp/gamma") },
+            },
+        }));
+
+        assert!(settings.is_err());
+    }
+
+    #[test]
+    fn test_parse_key_rotation() {
+        let settings = parse_settings(json!({
+            "chains": { "alpha": chain_config("alpha", 1000) },
+            "originchainname": "alpha",
+            "validator": { "type": "hexKey", "key": KEY_A },
+            "checkpointsyncer": local_syncer("/tmp/alpha"),
+            "rotation": {
+                "validator": { "type": "hexKey", "key": KEY_B },
+                "checkpointsyncer": local_syncer("/tmp/alpha-rotated"),
+                "endsat": 1_700_000_000,
+            },
+        }))
+        .expect("key rotation should parse");
+
+        let alpha = origin(&settings, "alpha");
+        assert_eq!(hex_key(&alpha.validator), KEY_A.parse().unwrap());
+        let rotation = alpha.rotation.as_ref().expect("rotation not parsed");
+        assert_eq!(hex_key(&rotation.validator), KEY_B.parse().unwrap());
+        assert_eq!(rotation.ends_at, 1_700_000_000);
+        assert!(matches!(
+            &rotation.checkpoint_syncer,
+            CheckpointSyncerConf::LocalStorage { path }
+                if path == &PathBuf::from("/tmp/alpha-rotated")
+        ));
+    }
+
+    #[test]
+    fn test_parse_key_rotation_requires_an_end() {
+        let settings = parse_settings(json!({
+            "chains": { "alpha": chain_config("alpha", 1000) },
+            "originchainname": "alpha",
+            "validator": { "type": "hexKey", "key": KEY_A },
+            "checkpointsyncer": local_syncer("/tmp/alpha"),
+            "rotation": {
+                "validator": { "type": "hexKey", "key": KEY_B },
+                "checkpointsyncer": local_syncer("/tmp/alpha-rotated"),
+            },
+        }));
+
+        assert!(settings.is_err());
+    }
+
+    #[test]
+    fn test_config_schema_origin_keys() {
+        use hyperlane_base::LoadableFromSettings;
+
+        let schema = ValidatorSettings::config_schema("validator");
+        let properties = &schema["properties"];
+
+        // A single origin is configured at the top level, several in `origins`
+        let origin = &properties["origins"]["additionalProperties"]["properties"];
+        for key in ["validator", "checkpointSyncer", "rotation"] {
+            assert!(properties.get(key).is_some(), "missing `{key}`");
+            assert!(origin.get(key).is_some(), "missing `origins.*.{key}`");
+        }
+    }
 }
@@ -0,0 +1,399 @@
+//! Slashing protection for checkpoint signatures.
+//!
+//! Every checkpoint is recorded in the journal before it is signed, and the
+//! validator refuses to sign a checkpoint that conflicts with one it already
+//! signed at the same index. The journal can be exported and imported in an
+//! interchange format so that operators can move a validator between hosts
+//! without losing this protection: fetch `GET /signing_journal` from the old
+//! host right before stopping it, and point `signingJournalImport` at the
+//! saved file on the new one.
+
+use std::{path::Path, sync::Arc};
+
+use eyre::{bail, eyre, Context, Result};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use hyperlane_base::db::{DbError, HyperlaneDb};
+use hyperlane_core::{Checkpoint, CheckpointWithMessageId, H160, H256};
+
+/// Version of the interchange format written by `export`
+pub const INTERCHANGE_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SigningJournalError {
+    /// A different checkpoint was already signed at the same index
+    #[error("Conflicting checkpoint at index {}: already signed {signed:?}, refusing to sign {conflicting:?}", signed.index)]
+    Conflict {
+        signed: CheckpointWithMessageId,
+        conflicting: CheckpointWithMessageId,
+    },
+    #[error(transparent)]
+    Db(#[from] DbError),
+}
+
+/// Metadata identifying whose signatures an interchange file covers
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InterchangeMetadata {
+    pub interchange_format_version: u32,
+    pub validator: H160,
+    pub mailbox_domain: u32,
+    pub merkle_tree_hook_address: H256,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InterchangeEntry {
+    pub index: u32,
+    pub root: H256,
+    pub message_id: H256,
+}
+
+/// Portable representation of the signing journal
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SigningJournalInterchange {
+    pub metadata: InterchangeMetadata,
+    pub checkpoints: Vec<InterchangeEntry>,
+}
+
+impl SigningJournalInterchange {
+    /// Read an interchange file from disk
+    pub fn read_from_file(path: &Path) -> Result<Self> {
+        let contents = std::fs::read(path)
+            .with_context(|| format!("Failed to read signing journal from {path:?}"))?;
+        serde_json::from_slice(&contents)
+            .with_context(|| format!("Failed to parse signing journal from {path:?}"))
+    }
+}
+
+/// Persistent record of the checkpoints a validator has signed
+#[derive(Clone)]
+pub struct SigningJournal {
+    db: Arc<dyn HyperlaneDb>,
+    // Serializes check-and-record so concurrent signers can't race on an index
+    lock: Arc<Mutex<()>>,
+}
+
+impl std::fmt::Debug for SigningJournal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SigningJournal").finish_non_exhaustive()
+    }
+}
+
+impl SigningJournal {
+    pub fn new(db: Arc<dyn HyperlaneDb>) -> Self {
+        Self {
+            db,
+            lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// Records the checkpoint as signed, unless a conflicting checkpoint
+    /// was already signed at the same index.
+    pub async fn check_and_record(
+        &self,
+        checkpoint: &CheckpointWithMessageId,
+    ) -> Result<(), SigningJournalError> {
+        let _guard = self.lock.lock().await;
+        self.record(checkpoint)
+    }
+
+    fn record(&self, checkpoint: &CheckpointWithMessageId) -> Result<(), SigningJournalError> {
+        if let Some(signed) = self
+            .db
+            .retrieve_signed_checkpoint_by_index(&checkpoint.index)?
+        {
+            if signed != *checkpoint {
+                return Err(SigningJournalError::Conflict {
+                    signed,
+                    conflicting: *checkpoint,
+                });
+            }
+            return Ok(());
+        }
+
+        self.db
+            .store_signed_checkpoint_by_index(&checkpoint.index, checkpoint)?;
+        let highest = self.db.retrieve_highest_signed_checkpoint_index()?;
+        if highest.is_none_or(|highest| highest < checkpoint.index) {
+            self.db
+                .store_highest_signed_checkpoint_index(&checkpoint.index)?;
+        }
+        Ok(())
+    }
+
+    /// Exports every journaled checkpoint, ordered by index
+    pub fn export(
+        &self,
+        validator: H160,
+        mailbox_domain: u32,
+        merkle_tree_hook_address: H256,
+    ) -> Result<SigningJournalInterchange> {
+        let mut checkpoints = vec![];
+        if let Some(highest) = self.db.retrieve_highest_signed_checkpoint_index()? {
+            for index in 0..=highest {
+                let Some(signed) = self.db.retrieve_signed_checkpoint_by_index(&index)? else {
+                    continue;
+                };
+                if signed.mailbox_domain != mailbox_domain
+                    || signed.merkle_tree_hook_address != merkle_tree_hook_address
+                {
+                    bail!(
+                        "Signing journal contains checkpoint {signed:?} for a different merkle tree hook"
+                    );
+                }
+                checkpoints.push(InterchangeEntry {
+                    index: signed.index,
+                    root: signed.root,
+                    message_id: signed.message_id,
+                });
+            }
+        }
+
+        Ok(SigningJournalInterchange {
+            metadata: InterchangeMetadata {
+                interchange_format_version: INTERCHANGE_FORMAT_VERSION,
+                validator,
+                mailbox_domain,
+                merkle_tree_hook_address,
+            },
+            checkpoints,
+        })
+    }
+
+    /// Merges an exported journal into this one, returning the number of
+    /// checkpoints that were not journaled yet.
+    ///
+    /// Fails without importing anything if the interchange belongs to another
+    /// validator or merkle tree hook, or if any entry conflicts with a
+    /// checkpoint that is already journaled.
+    pub async fn import(
+        &self,
+        interchange: &SigningJournalInterchange,
+        validator: H160,
+        mailbox_domain: u32,
+        merkle_tree_hook_address: H256,
+    ) -> Result<usize> {
+        let metadata = &interchange.metadata;
+        if metadata.interchange_format_version != INTERCHANGE_FORMAT_VERSION {
+            bail!(
+                "Unsupported signing journal interchange format version {}",
+                metadata.interchange_format_version
+            );
+        }
+        if metadata.validator != validator {
+            bail!(
+                "Signing journal belongs to validator {:?}, not {validator:?}",
+                metadata.validator
+            );
+        }
+        if metadata.mailbox_domain != mailbox_domain
+            || metadata.merkle_tree_hook_address != merkle_tree_hook_address
+        {
+            bail!(
+                "Signing journal is for merkle tree hook {:?} on domain {}, not {merkle_tree_hook_address:?} on domain {mailbox_domain}",
+                metadata.merkle_tree_hook_address,
+                metadata.mailbox_domain
+            );
+        }
+
+        let checkpoints = interchange
+            .checkpoints
+            .iter()
+            .map(|entry| CheckpointWithMessageId {
+                checkpoint: Checkpoint {
+                    merkle_tree_hook_address,
+                    mailbox_domain,
+                    root: entry.root,
+                    index: entry.index,
+                },
+                message_id: entry.message_id,
+            })
+            .collect::<Vec<_>>();
+
+        let _guard = self.lock.lock().await;
+
+        // Check everything first so a conflicting file leaves the journal untouched
+        let mut new_checkpoints = vec![];
+        for checkpoint in &checkpoints {
+            match self
+                .db
+                .retrieve_signed_checkpoint_by_index(&checkpoint.index)?
+            {
+                Some(signed) if signed != *checkpoint => {
+                    return Err(eyre!(SigningJournalError::Conflict {
+                        signed,
+                        conflicting: *checkpoint,
+                    }));
+                }
+                Some(_) => {}
+                None => new_checkpoints.push(checkpoint),
+            }
+        }
+
+        for checkpoint in &new_checkpoints {
+            self.record(checkpoint)?;
+        }
+        Ok(new_checkpoints.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyperlane_base::db::{HyperlaneRocksDB, DB};
+    use hyperlane_core::{HyperlaneDomain, KnownHyperlaneDomain};
+
+    use super::*;
+
+    const MAILBOX_DOMAIN: u32 = 1;
+
+    fn hook_address() -> H256 {
+        H256::from_low_u64_be(0x42)
+    }
+
+    fn validator() -> H160 {
+        H160::from_low_u64_be(0x1)
+    }
+
+    fn journal() -> (SigningJournal, tempfile::TempDir) {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = DB::from_path(temp_dir.path()).unwrap();
+        let domain = HyperlaneDomain::Known(KnownHyperlaneDomain::Ethereum);
+        let db = HyperlaneRocksDB::new(&domain, db);
+        (SigningJournal::new(Arc::new(db)), temp_dir)
+    }
+
+    fn checkpoint(index: u32, root: H256) -> CheckpointWithMessageId {
+        CheckpointWithMessageId {
+            checkpoint: Checkpoint {
+                merkle_tree_hook_address: hook_address(),
+                mailbox_domain: MAILBOX_DOMAIN,
+                root,
+                index,
+            },
+            message_id: H256::from_low_u64_be(index.into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rejects_conflicting_checkpoint() {
+        let (journal, _dir) = journal();
+        let signed = checkpoint(3, H256::from_low_u64_be(1));
+
+        journal.check_and_record(&signed).await.unwrap();
+        // Re-signing the same checkpoint is fine
+        journal.check_and_record(&signed).await.unwrap();
+
+        let conflicting = checkpoint(3, H256::from_low_u64_be(2));
+        let err = journal.check_and_record(&conflicting).await.unwrap_err();
+        assert!(matches!(
+            err,
+            SigningJournalError::Conflict { signed: s, conflicting: c } if s == signed && c == conflicting
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_export_import_roundtrip() {
+        let (journal, _dir) = journal();
+        for index in [0, 1, 5] {
+            journal
+                .check_and_record(&checkpoint(index, H256::from_low_u64_be(index.into())))
+                .await
+                .unwrap();
+        }
+
+        let exported = journal
+            .export(validator(), MAILBOX_DOMAIN, hook_address())
+            .unwrap();
+        assert_eq!(
+            exported
+                .checkpoints
+                .iter()
+                .map(|e| e.index)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 5]
+        );
+
+        // The interchange format survives serialization
+        let serialized = serde_json::to_string(&exported).unwrap();
+        let parsed: SigningJournalInterchange = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(parsed, exported);
+
+        let (new_journal, _new_dir) = journal_with_entry(checkpoint(1, H256::from_low_u64_be(1)));
+        let imported = new_journal
+            .import(&parsed, validator(), MAILBOX_DOMAIN, hook_address())
+            .await
+            .unwrap();
+        assert_eq!(imported, 2);
+        assert_eq!(
+            new_journal
+                .export(validator(), MAILBOX_DOMAIN, hook_address())
+                .unwrap(),
+            exported
+        );
+
+        // Imported entries protect against conflicting signatures
+        let err = new_journal
+            .check_and_record(&checkpoint(5, H256::zero()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SigningJournalError::Conflict { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_import_rejects_conflicts_and_foreign_metadata() {
+        let (journal, _dir) = journal_with_entry(checkpoint(1, H256::from_low_u64_be(1)));
+
+        let interchange = SigningJournalInterchange {
+            metadata: InterchangeMetadata {
+                interchange_format_version: INTERCHANGE_FORMAT_VERSION,
+                validator: validator(),
+                mailbox_domain: MAILBOX_DOMAIN,
+                merkle_tree_hook_address: hook_address(),
+            },
+            checkpoints: vec![
+                InterchangeEntry {
+                    index: 0,
+                    root: H256::from_low_u64_be(7),
+                    message_id: H256::zero(),
+                },
+                InterchangeEntry {
+                    index: 1,
+                    root: H256::from_low_u64_be(2),
+                    message_id: H256::from_low_u64_be(1),
+                },
+            ],
+        };
+
+        assert!(journal
+            .import(&interchange, validator(), MAILBOX_DOMAIN, hook_address())
+            .await
+            .is_err());
+        // Nothing was imported, not even the non-conflicting entry
+        assert_eq!(
+            journal
+                .export(validator(), MAILBOX_DOMAIN, hook_address())
+                .unwrap()
+                .checkpoints
+                .len(),
+            1
+        );
+
+        assert!(journal
+            .import(
+                &interchange,
+                H160::from_low_u64_be(0x2),
+                MAILBOX_DOMAIN,
+                hook_address()
+            )
+            .await
+            .is_err());
+    }
+
+    fn journal_with_entry(entry: CheckpointWithMessageId) -> (SigningJournal, tempfile::TempDir) {
+        let (journal, dir) = journal();
+        journal.record(&entry).unwrap();
+        (journal, dir)
+    }
+}
@@ -0,0 +1,86 @@
+//! Runtime state of each origin that isn't available from metrics, served by
+//! the `/status` routes.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use hyperlane_core::H160;
+
+/// Outcome of the last attempt to announce a validator key
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum AnnounceStatus {
+    #[default]
+    Pending,
+    Announced {
+        /// Unix timestamp in seconds
+        at: i64,
+    },
+    Failed {
+        /// Unix timestamp in seconds
+        at: i64,
+        error: String,
+    },
+}
+
+impl AnnounceStatus {
+    pub fn announced() -> Self {
+        Self::Announced {
+            at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    pub fn failed(error: impl Into<String>) -> Self {
+        Self::Failed {
+            at: chrono::Utc::now().timestamp(),
+            error: error.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OriginStatusSnapshot {
+    /// Announcement status of each validator key of the origin
+    pub announcements: BTreeMap<H160, AnnounceStatus>,
+    /// Whether checkpoints are being signed
+    pub submitting: bool,
+    /// Whether validation of the origin stopped, e.g. after a reorg
+    pub halted: bool,
+}
+
+impl OriginStatusSnapshot {
+    pub fn is_announced(&self) -> bool {
+        !self.announcements.is_empty()
+            && self
+                .announcements
+                .values()
+                .all(|status| matches!(status, AnnounceStatus::Announced { .. }))
+    }
+}
+
+/// Shared handle to the status of an origin
+#[derive(Clone, Debug, Default)]
+pub struct OriginStatus(Arc<RwLock<OriginStatusSnapshot>>);
+
+impl OriginStatus {
+    pub async fn set_announce_status(&self, validator: H160, status: AnnounceStatus) {
+        self.0.write().await.announcements.insert(validator, status);
+    }
+
+    pub async fn set_submitting(&self) {
+        self.0.write().await.submitting = true;
+    }
+
+    pub async fn set_halted(&self) {
+        let mut status = self.0.write().await;
+        status.submitting = false;
+        status.halted = true;
+    }
+
+    pub async fn snapshot(&self) -> OriginStatusSnapshot {
+        self.0.read().await.clone()
+    }
+}
@@ -21,6 +21,10 @@ use hyperlane_core::{
 use hyperlane_ethereum::{Signers, SingletonSignerHandle};
 
 use crate::reorg_reporter::ReorgReporter;
+use crate::signing_journal::{SigningJournal, SigningJournalError};
+
+const REORG_HALT_MESSAGE: &str = "Incorrect tree root. Most likely a reorg has occurred. Please reach out for help, this is a potentially serious error impacting signed messages. Do NOT forcefully resume operation of this validator. Keep it crashlooping or shut down until you receive support.";
+const CONFLICTING_CHECKPOINT_HALT_MESSAGE: &str = "Refusing to sign a checkpoint that conflicts with one this validator already signed. The local merkle tree or RPC is likely inconsistent. Please reach out for help, this is a potentially serious error impacting signed messages. Do NOT forcefully resume operation of this validator or wipe its signing journal. Keep it crashlooping or shut down until you receive support.";
 
 #[derive(Clone)]
 pub(crate) struct ValidatorSubmitter {
@@ -35,6 +39,7 @@ pub(crate) struct ValidatorSubmitter {
     metrics: ValidatorSubmitterMetrics,
     max_sign_concurrency: usize,
     reorg_reporter: Arc<dyn ReorgReporter>,
+    signing_journal: SigningJournal,
 }
 
 impl ValidatorSubmitter {
@@ -50,6 +55,7 @@ impl ValidatorSubmitter {
         metrics: ValidatorSubmitterMetrics,
         max_sign_concurrency: usize,
         reorg_reporter: Arc<dyn ReorgReporter>,
+        signing_journal: SigningJournal,
     ) -> Self {
         Self {
             reorg_period,
@@ -62,6 +68,7 @@ impl ValidatorSubmitter {
             metrics,
             max_sign_concurrency,
             reorg_reporter,
+            signing_journal,
         }
     }
 
@@ -249,7 +256,7 @@ impl ValidatorSubmitter {
                 ?checkpoint,
                 ?correctness_checkpoint,
                 ?reorg_event,
-                "Incorrect tree root. Most likely a reorg has occurred. Please reach out for help, this is a potentially serious error impacting signed messages. Do NOT forcefully resume operation of this validator. Keep it crashlooping or shut down until you receive support."
+                "{REORG_HALT_MESSAGE}"
             );
 
             if let Some(height) = correctness_checkpoint.block_height {
@@ -261,16 +268,9 @@ impl ValidatorSubmitter {
                     .await;
             }
 
-            let mut panic_message = "Incorrect tree root. Most likely a reorg has occurred. Please reach out for help, this is a potentially serious error impacting signed messages. Do NOT forcefully resume operation of this validator. Keep it crashlooping or shut down until you receive support.".to_owned();
-            if let Err(e) = self
-                .checkpoint_syncer
-                .write_reorg_status(&reorg_event)
-                .await
-            {
-                panic_message.push_str(&format!(
-                    " Reorg troubleshooting details couldn't be written to checkpoint storage: {e}"
-                ));
-            }
+            let panic_message = self
+                .write_reorg_status(&reorg_event, REORG_HALT_MESSAGE)
+                .await;
             panic!("{panic_message}");
         }
 
@@ -295,10 +295,68 @@ impl ValidatorSubmitter {
         }
     }
 
+    /// Writes the reorg event to checkpoint storage, returning the message to halt with.
+    async fn write_reorg_status(&self, reorg_event: &ReorgEvent, message: &str) -> String {
+        let mut panic_message = message.to_owned();
+        if let Err(e) = self.checkpoint_syncer.write_reorg_status(reorg_event).await {
+            panic_message.push_str(&format!(
+                " Reorg troubleshooting details couldn't be written to checkpoint storage: {e}"
+            ));
+        }
+        panic_message
+    }
+
+    /// Reports a checkpoint that conflicts with one in the signing journal,
+    /// returning the message to halt with.
+    async fn report_conflicting_checkpoint(
+        &self,
+        signed: CheckpointWithMessageId,
+        conflicting: CheckpointWithMessageId,
+    ) -> String {
+        // The previously signed root stands in for the canonical one, since
+        // that's what other validators and relayers have already seen.
+        let reorg_event = ReorgEvent::new(
+            conflicting.root,
+            signed.root,
+            conflicting.index,
+            chrono::Utc::now().timestamp() as u64,
+            self.reorg_period.clone(),
+        );
+        error!(
+            ?signed,
+            ?conflicting,
+            ?reorg_event,
+            "{CONFLICTING_CHECKPOINT_HALT_MESSAGE}"
+        );
+
+        self.reorg_reporter
+            .report_with_reorg_period(&self.reorg_period)
+            .await;
+
+        self.write_reorg_status(&reorg_event, CONFLICTING_CHECKPOINT_HALT_MESSAGE)
+            .await
+    }
+
     async fn sign_checkpoint(
         &self,
         checkpoint: CheckpointWithMessageId,
     ) -> ChainResult<SignedType<CheckpointWithMessageId>> {
+        // Journal the checkpoint before signing it, so that a crash after
+        // signing can never leave a signature the journal doesn't know about.
+        match self.signing_journal.check_and_record(&checkpoint).await {
+            Ok(()) => {}
+            Err(SigningJournalError::Conflict {
+                signed,
+                conflicting,
+            }) => {
+                let panic_message = self
+                    .report_conflicting_checkpoint(signed, conflicting)
+                    .await;
+                panic!("{panic_message}");
+            }
+            Err(SigningJournalError::Db(err)) => return Err(err.into()),
+        }
+
         let signer_retries = 5;
 
         for i in 0..signer_retries {
@@ -5,7 +5,7 @@ use eyre::Result;
 use prometheus::Registry;
 use tokio::sync::mpsc;
 
-use hyperlane_base::tests::mock_hyperlane_db::MockHyperlaneDb as MockDb;
+use hyperlane_base::db::{HyperlaneDb, HyperlaneRocksDB, DB};
 use hyperlane_core::{
     test_utils::dummy_domain, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
     HyperlaneProvider, MerkleTreeHook, MerkleTreeInsertion, ReorgEvent, ReorgEventResponse,
@@ -88,6 +88,15 @@ fn dummy_singleton_handle() -> SingletonSignerHandle {
     SingletonSignerHandle::new(H160::from_low_u64_be(0), mpsc::unbounded_channel().0)
 }
 
+/// An empty database of the origin, kept in memory
+fn in_memory_db() -> HyperlaneRocksDB {
+    HyperlaneRocksDB::new(&dummy_domain(0, "dummy_domain"), DB::in_memory())
+}
+
+fn dummy_signing_journal() -> SigningJournal {
+    SigningJournal::new(Arc::new(in_memory_db()))
+}
+
 fn reorg_event_is_correct(
     reorg_event: &ReorgEvent,
     expected_local_merkle_tree: &IncrementalMerkle,
@@ -153,9 +162,10 @@ async fn reorg_is_detected_and_persisted_to_checkpoint_storage() {
     );
 
     // the db returns the pre-reorg merkle tree insertions
-    let mut db = MockDb::new();
-    db.expect_retrieve_merkle_tree_insertion_by_leaf_index()
-        .returning(move |sequence| Ok(Some(pre_reorg_merke_insertions[*sequence as usize])));
+    let db = in_memory_db();
+    for insertion in pre_reorg_merke_insertions.iter() {
+        db.process_tree_insertion(insertion, 0).unwrap();
+    }
 
     // boilerplate mocks
     let mut mock_merkle_tree_hook = MockMerkleTreeHook::new();
@@ -210,6 +220,7 @@ async fn reorg_is_detected_and_persisted_to_checkpoint_storage() {
         dummy_metrics(),
         50,
         Arc::new(mock_reorg_reporter),
+        dummy_signing_journal(),
     );
 
     // mock the correctness checkpoint response
@@ -267,9 +278,10 @@ async fn sign_and_submit_checkpoint_same_signature() {
     );
 
     // the db returns the pre-reorg merkle tree insertions
-    let mut db = MockDb::new();
-    db.expect_retrieve_merkle_tree_insertion_by_leaf_index()
-        .returning(move |sequence| Ok(Some(pre_reorg_merke_insertions[*sequence as usize])));
+    let db = in_memory_db();
+    for insertion in pre_reorg_merke_insertions.iter() {
+        db.process_tree_insertion(insertion, 0).unwrap();
+    }
 
     // boilerplate mocks
     let mut mock_merkle_tree_hook = MockMerkleTreeHook::new();
@@ -328,6 +340,7 @@ async fn sign_and_submit_checkpoint_same_signature() {
         dummy_metrics(),
         50,
         Arc::new(mock_reorg_reporter),
+        dummy_signing_journal(),
     );
 
     // Start the submitter with an empty merkle tree, so it gets rebuilt from the db.
@@ -372,9 +385,10 @@ async fn sign_and_submit_checkpoint_different_signature() {
     );
 
     // the db returns the pre-reorg merkle tree insertions
-    let mut db = MockDb::new();
-    db.expect_retrieve_merkle_tree_insertion_by_leaf_index()
-        .returning(move |sequence| Ok(Some(pre_reorg_merke_insertions[*sequence as usize])));
+    let db = in_memory_db();
+    for insertion in pre_reorg_merke_insertions.iter() {
+        db.process_tree_insertion(insertion, 0).unwrap();
+    }
 
     // boilerplate mocks
     let mut mock_merkle_tree_hook = MockMerkleTreeHook::new();
@@ -447,6 +461,7 @@ async fn sign_and_submit_checkpoint_different_signature() {
         dummy_metrics(),
         50,
         Arc::new(mock_reorg_reporter),
+        dummy_signing_journal(),
     );
 
     // Start the submitter with an empty merkle tree, so it gets rebuilt from the db.
@@ -457,3 +472,78 @@ async fn sign_and_submit_checkpoint_different_signature() {
 
     logs_contain("Checkpoint already submitted, but with different signature, overwriting");
 }
+
+#[tokio::test]
+#[should_panic(
+    expected = "Refusing to sign a checkpoint that conflicts with one this validator already signed."
+)]
+async fn conflicting_checkpoint_is_not_signed() {
+    let dummy_domain = dummy_domain(0, "dummy_domain");
+    let checkpoint = CheckpointWithMessageId {
+        checkpoint: Checkpoint {
+            root: H256::from_low_u64_be(1),
+            index: 2,
+            merkle_tree_hook_address: H256::from_low_u64_be(0),
+            mailbox_domain: dummy_domain.id(),
+        },
+        message_id: H256::from_low_u64_be(2),
+    };
+    // the journal already holds a different root for the same index,
+    // e.g. signed before the validator's db was wiped
+    let previously_signed = CheckpointWithMessageId {
+        checkpoint: Checkpoint {
+            root: H256::from_low_u64_be(3),
+            ..checkpoint.checkpoint
+        },
+        ..checkpoint
+    };
+
+    let journal_db = in_memory_db();
+    journal_db
+        .store_signed_checkpoint_by_index(&checkpoint.checkpoint.index, &previously_signed)
+        .unwrap();
+
+    let mut mock_checkpoint_syncer = MockCheckpointSyncer::new();
+    mock_checkpoint_syncer
+        .expect_fetch_checkpoint()
+        .returning(|_| Ok(None));
+    // the conflict is persisted like a reorg, and nothing is written
+    mock_checkpoint_syncer
+        .expect_write_reorg_status()
+        .once()
+        .returning(move |reorg_event| {
+            assert_eq!(reorg_event.local_merkle_root, H256::from_low_u64_be(1));
+            assert_eq!(reorg_event.canonical_merkle_root, H256::from_low_u64_be(3));
+            assert_eq!(reorg_event.checkpoint_index, 2);
+            Ok(())
+        });
+
+    let mut mock_reorg_reporter = MockReorgReporter::new();
+    mock_reorg_reporter
+        .expect_report_with_reorg_period()
+        .once()
+        .return_once(|_| {});
+
+    let signer: Signers = "1111111111111111111111111111111111111111111111111111111111111111"
+        .parse::<ethers::signers::LocalWallet>()
+        .unwrap()
+        .into();
+
+    let validator_submitter = ValidatorSubmitter::new(
+        Duration::from_secs(1),
+        ReorgPeriod::from_blocks(12),
+        Arc::new(MockMerkleTreeHook::new()),
+        dummy_singleton_handle(),
+        signer,
+        Arc::new(mock_checkpoint_syncer),
+        Arc::new(in_memory_db()),
+        dummy_metrics(),
+        50,
+        Arc::new(mock_reorg_reporter),
+        SigningJournal::new(Arc::new(journal_db)),
+    );
+
+    let _ = validator_submitter
+        .sign_and_submit_checkpoint(checkpoint)
+        .await;
+}
@@ -1,11 +1,11 @@
-use std::{fmt::Debug, sync::Arc, time::Duration};
+use std::{fmt::Debug, future::Future, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use axum::Router;
 use derive_more::AsRef;
 use ethers::utils::keccak256;
 use eyre::{eyre, Result};
-use futures_util::future::try_join_all;
+use futures_util::future::{join_all, try_join_all};
 use itertools::Itertools;
 use serde::Serialize;
 use tokio::{task::JoinHandle, time::sleep};
@@ -16,15 +16,19 @@ use hyperlane_base::{
     db::{HyperlaneDb, HyperlaneRocksDB, DB},
     git_sha,
     metrics::AgentMetrics,
-    settings::{ChainConf, CheckpointSyncerBuildError},
+    settings::{
+        check::{check_checkpoint_syncer, check_signer, ConfigCheck},
+        ChainConf, CheckpointSyncerBuildError,
+    },
     BaseAgent, ChainMetrics, ChainSpecificMetricsUpdater, CheckpointSyncer, ContractSyncMetrics,
     ContractSyncer, CoreMetrics, HyperlaneAgentCore, MetadataFromSettings, RuntimeMetrics,
     SequencedDataContractSync,
 };
 use hyperlane_core::{
     rpc_clients::RPC_RETRY_SLEEP_DURATION, Announcement, ChainResult, HyperlaneChain,
-    HyperlaneContract, HyperlaneDomain, HyperlaneSigner, HyperlaneSignerExt, Mailbox,
-    MerkleTreeHook, MerkleTreeInsertion, ReorgPeriod, TxOutcome, ValidatorAnnounce, H256, U256,
+    HyperlaneContract, HyperlaneDomain, HyperlaneSigner, HyperlaneSignerExt,
+    IncrementalMerkleAtBlock, Mailbox, MerkleTreeHook, MerkleTreeInsertion, ReorgPeriod, TxOutcome,
+    ValidatorAnnounce, H256, U256,
 };
 use hyperlane_ethereum::{Signers, SingletonSigner, SingletonSignerHandle};
 
@@ -33,7 +37,13 @@ use crate::reorg_reporter::{
 };
 use crate::server::{self as validator_server, merkle_tree_insertions};
 use crate::{
-    settings::ValidatorSettings,
+    key_rotation::{
+        signing_keys, KeyRotation, KeyRotationInfo, KeyRotationMetrics, KeyRotationPhase,
+        ValidatorKey,
+    },
+    settings::{OriginSettings, RpcConfig, ValidatorSettings},
+    signing_journal::{SigningJournal, SigningJournalInterchange},
+    status::{AnnounceStatus, OriginStatus},
     submit::{ValidatorSubmitter, ValidatorSubmitterMetrics},
 };
 
@@ -42,11 +52,20 @@ const CURSOR_INSTANTIATION_ATTEMPTS: usize = 10;
 /// A validator agent
 #[derive(Debug, AsRef)]
 pub struct Validator {
-    origin_chain: HyperlaneDomain,
-    origin_chain_conf: ChainConf,
     #[as_ref]
     core: HyperlaneAgentCore,
+    origins: Vec<OriginValidator>,
+    runtime_metrics: RuntimeMetrics,
+}
+
+/// Validates the checkpoints of a single origin chain. Origins share the
+/// agent's databases and metrics, but otherwise run independently.
+#[derive(Debug)]
+struct OriginValidator {
+    origin_chain: HyperlaneDomain,
+    origin_chain_conf: ChainConf,
     db: HyperlaneRocksDB,
+    signing_journal: SigningJournal,
     merkle_tree_hook_sync: Arc<SequencedDataContractSync<MerkleTreeInsertion>>,
     mailbox: Arc<dyn Mailbox>,
     merkle_tree_hook: Arc<dyn MerkleTreeHook>,
@@ -61,10 +80,11 @@ pub struct Validator {
     core_metrics: Arc<CoreMetrics>,
     agent_metrics: AgentMetrics,
     chain_metrics: ChainMetrics,
-    runtime_metrics: RuntimeMetrics,
     agent_metadata: ValidatorMetadata,
     max_sign_concurrency: usize,
     reorg_reporter: Arc<dyn ReorgReporter>,
+    rotation: Option<KeyRotation>,
+    status: OriginStatus,
 }
 
 /// Metadata for `validator`
@@ -80,13 +100,10 @@ pub struct ValidatorMetadataRpcEntry {
     host_hash: H256,
 }
 
-impl MetadataFromSettings<ValidatorSettings> for ValidatorMetadata {
-    /// Create a new instance of the agent metadata from the settings
-    fn build_metadata(settings: &ValidatorSettings) -> ValidatorMetadata {
+impl ValidatorMetadata {
+    fn new<'a>(rpcs: impl Iterator<Item = &'a RpcConfig>, allows_public_rpcs: bool) -> Self {
         // Hash all the RPCs for the metadata
-        let rpcs = settings
-            .rpcs
-            .iter()
+        let rpcs = rpcs
             .map(|rpc| ValidatorMetadataRpcEntry {
                 url_hash: H256::from_slice(&keccak256(&rpc.url)),
                 host_hash: H256::from_slice(&keccak256(
@@ -100,11 +117,27 @@ impl MetadataFromSettings<ValidatorSettings> for ValidatorMetadata {
         ValidatorMetadata {
             git_sha: git_sha(),
             rpcs,
-            allows_public_rpcs: settings.allow_public_rpcs,
+            allows_public_rpcs,
+        }
+    }
+
+    /// Metadata written to the checkpoint syncer of a single origin, which
+    /// only lists the RPCs of that origin
+    fn for_origin(&self, origin: &OriginSettings) -> Self {
+        Self {
+            git_sha: self.git_sha.clone(),
+            ..Self::new(origin.rpcs.iter(), self.allows_public_rpcs)
         }
     }
 }
 
+impl MetadataFromSettings<ValidatorSettings> for ValidatorMetadata {
+    /// Create a new instance of the agent metadata from the settings
+    fn build_metadata(settings: &ValidatorSettings) -> ValidatorMetadata {
+        ValidatorMetadata::new(settings.rpcs(), settings.allow_public_rpcs)
+    }
+}
+
 #[async_trait]
 impl BaseAgent for Validator {
     const AGENT_NAME: &'static str = "validator";
@@ -125,30 +158,215 @@ impl BaseAgent for Validator {
         Self: Sized,
     {
         // Check for public rpcs in the config
-        if settings.rpcs.iter().any(|x| x.public) && !settings.allow_public_rpcs {
+        if settings.rpcs().any(|x| x.public) && !settings.allow_public_rpcs {
             return Err(
                 eyre!(
                     "Public RPC endpoints detected: {}. Using public RPCs can compromise security and reliability. If you understand the risks and still want to proceed, set `--allowPublicRpcs true`. We strongly recommend using private RPC endpoints for production validators.",
-                    settings.rpcs.iter().filter_map(|x| if x.public { Some(x.url.clone()) } else { None }).join(", ")
+                    settings.rpcs().filter_map(|x| if x.public { Some(x.url.clone()) } else { None }).join(", ")
                 )
             );
         }
 
         let db = DB::from_path(&settings.db)?;
-        let msg_db = HyperlaneRocksDB::new(&settings.origin_chain, db);
+        let signing_journal_db = DB::from_path(&settings.signing_journal_db)?;
+
+        let core = settings.build_hyperlane_core(metrics.clone());
+
+        let contract_sync_metrics = Arc::new(ContractSyncMetrics::new(&metrics));
+        let key_rotation_metrics = KeyRotationMetrics::new(&metrics)?;
+
+        let mut origin_validators = vec![];
+        for origin in &settings.origins {
+            let origin_validator = OriginValidator::from_settings(
+                &settings,
+                origin,
+                agent_metadata.for_origin(origin),
+                &db,
+                &signing_journal_db,
+                &metrics,
+                &agent_metrics,
+                &chain_metrics,
+                &contract_sync_metrics,
+                &key_rotation_metrics,
+            )
+            .await;
+            origin_validators.push((origin.origin_chain.clone(), origin_validator));
+        }
+        let origins = started_origins(origin_validators, &chain_metrics)?;
+
+        Ok(Self {
+            core,
+            origins,
+            runtime_metrics,
+        })
+    }
+
+    #[allow(clippy::async_yields_async)]
+    async fn run(self) {
+        let mut tasks = vec![];
 
-        let raw_signer: Signers = settings.validator.build().await?;
+        // run server
+        let router = self.router();
+        let server = self
+            .core
+            .settings
+            .server(self.core.metrics.clone())
+            .expect("Failed to create server");
+        let server_task = tokio::spawn(
+            async move {
+                server.run_with_custom_router(router);
+            }
+            .instrument(info_span!("Validator server")),
+        );
+        tasks.push(server_task);
+
+        tasks.push(self.runtime_metrics.spawn());
+
+        let origin_tasks = self
+            .origins
+            .into_iter()
+            .map(|origin| {
+                let origin_chain = origin.origin_chain.clone();
+                let status = origin.status.clone();
+                supervise_origin(&origin_chain, status, origin.run())
+            })
+            .collect_vec();
+
+        tokio::select! {
+            // Note that this only returns an error if one of the tasks panics
+            result = try_join_all(tasks) => {
+                if let Err(err) = result {
+                    error!(?err, "One of the validator tasks returned an error");
+                }
+            }
+            _ = join_all(origin_tasks) => {
+                error!("Validation halted on every origin");
+            }
+        }
+    }
+
+    async fn check_settings(settings: &Self::Settings) -> Vec<ConfigCheck> {
+        let mut checks = vec![];
+        if settings.rpcs().any(|rpc| rpc.public) && !settings.allow_public_rpcs {
+            checks.push(ConfigCheck::failed(
+                "allowPublicRpcs",
+                "Public RPC endpoints are configured without `allowPublicRpcs`",
+            ));
+        }
+        for origin in &settings.origins {
+            let name = origin.origin_chain.name();
+            checks.push(
+                check_signer::<Signers>(&format!("origins.{name}.validator"), &origin.validator)
+                    .await,
+            );
+            checks.push(check_checkpoint_syncer(
+                format!("origins.{name}.checkpointSyncer"),
+                &origin.checkpoint_syncer,
+            ));
+            if let Some(rotation) = &origin.rotation {
+                checks.push(
+                    check_signer::<Signers>(
+                        &format!("origins.{name}.rotation.validator"),
+                        &rotation.validator,
+                    )
+                    .await,
+                );
+                checks.push(check_checkpoint_syncer(
+                    format!("origins.{name}.rotation.checkpointSyncer"),
+                    &rotation.checkpoint_syncer,
+                ));
+            }
+        }
+        checks
+    }
+}
+
+impl Validator {
+    /// Routes of every origin are served under `/origins/{origin_chain}`, and
+    /// without a prefix too when there is a single origin.
+    fn router(&self) -> Router {
+        let origin_chains = self
+            .origins
+            .iter()
+            .map(|origin| origin.origin_chain.clone())
+            .collect();
+        let origin_sources = self
+            .origins
+            .iter()
+            .map(OriginValidator::status_source)
+            .collect();
+        let mut router = Router::new()
+            .merge(validator_server::router(
+                origin_chains,
+                self.core.metrics.clone(),
+            ))
+            .merge(
+                validator_server::status::ServerState::new(
+                    origin_sources,
+                    self.core.metrics.clone(),
+                )
+                .router(),
+            );
+        for origin in &self.origins {
+            router = router.nest(
+                &format!("/origins/{}", origin.origin_chain.name()),
+                origin.router(),
+            );
+        }
+        if let [origin] = self.origins.as_slice() {
+            router = router.merge(origin.router());
+        }
+        router
+    }
+
+    fn aleo_announcement_location(announcement_location: String) -> Result<String> {
+        // Aleo announcement locations are fixed size C strings of 480 bytes (include nulls)
+        let mut bytes = announcement_location.into_bytes();
+        // Ensure it fits within 479 bytes (leaving room for null terminator)
+        if bytes.len() > 479 {
+            return Err(eyre!(
+                "Aleo announcement location too long: {} bytes (max 479)",
+                bytes.len()
+            ));
+        }
+        // Pad remaining bytes with nulls up to 480 total
+        bytes.resize(480, 0);
+        String::from_utf8(bytes).map_err(|e| {
+            eyre!(
+                "Failed to convert Aleo announcement location to string: {}",
+                e
+            )
+        })
+    }
+}
+
+impl OriginValidator {
+    #[allow(clippy::too_many_arguments)]
+    async fn from_settings(
+        settings: &ValidatorSettings,
+        origin: &OriginSettings,
+        agent_metadata: ValidatorMetadata,
+        db: &DB,
+        signing_journal_db: &DB,
+        metrics: &Arc<CoreMetrics>,
+        agent_metrics: &AgentMetrics,
+        chain_metrics: &ChainMetrics,
+        contract_sync_metrics: &Arc<ContractSyncMetrics>,
+        key_rotation_metrics: &KeyRotationMetrics,
+    ) -> Result<Self> {
+        let origin_chain = &origin.origin_chain;
+        let msg_db = HyperlaneRocksDB::new(origin_chain, db.clone());
+
+        let raw_signer: Signers = origin.validator.build().await?;
 
         // Intentionally using hyperlane_ethereum for the validator's signer
         let (signer_instance, signer) = SingletonSigner::new(raw_signer.clone());
 
-        let core = settings.build_hyperlane_core(metrics.clone());
-
         let reorg_reporter =
-            LatestCheckpointReorgReporter::from_settings(&settings, &metrics).await?;
+            LatestCheckpointReorgReporter::from_settings(settings, origin_chain, metrics).await?;
         let reorg_reporter = Arc::new(reorg_reporter) as Arc<dyn ReorgReporter>;
 
-        let checkpoint_syncer_result = settings.checkpoint_syncer.build_and_validate(None).await;
+        let checkpoint_syncer_result = origin.checkpoint_syncer.build_and_validate(None).await;
 
         Self::report_latest_checkpoints_from_each_endpoint(
             &reorg_reporter,
@@ -156,42 +374,76 @@ impl BaseAgent for Validator {
         )
         .await;
 
-        // Be extra sure to panic when checkpoint syncer fails, which indicates
-        // a fatal startup error.
+        // A checkpoint syncer that fails to build, e.g. because a reorg was
+        // flagged in its storage, is a fatal startup error for this origin.
         let checkpoint_syncer: Arc<dyn CheckpointSyncer> = checkpoint_syncer_result
-            .expect("Failed to build checkpoint syncer")
+            .map_err(|err| eyre!("Failed to build checkpoint syncer: {err:?}"))?
             .into();
 
         // If checkpoint syncer initialization was successful, use a reorg-reporter which
         // writes to the storage location in addition to the logs.
         let reorg_reporter_with_storage_writer =
             LatestCheckpointReorgReporterWithStorageWriter::from_settings_with_storage_writer(
-                &settings,
-                &metrics,
+                settings,
+                origin_chain,
+                metrics,
                 checkpoint_syncer.clone(),
             )
             .await?;
         let reorg_reporter = Arc::new(reorg_reporter_with_storage_writer) as Arc<dyn ReorgReporter>;
 
-        let origin_chain_conf = core.settings.chain_setup(&settings.origin_chain)?.clone();
+        let origin_chain_conf = settings.chain_setup(origin_chain)?.clone();
 
-        let mailbox = origin_chain_conf.build_mailbox(&metrics).await?;
+        let mailbox = origin_chain_conf.build_mailbox(metrics).await?;
 
         let merkle_tree_hook = settings
-            .build_merkle_tree_hook(&settings.origin_chain, &metrics)
+            .build_merkle_tree_hook(origin_chain, metrics)
             .await?;
 
         let validator_announce = settings
-            .build_validator_announce(&settings.origin_chain, &metrics)
+            .build_validator_announce(origin_chain, metrics)
             .await?;
 
-        let contract_sync_metrics = Arc::new(ContractSyncMetrics::new(&metrics));
+        let signing_journal = SigningJournal::new(Arc::new(HyperlaneRocksDB::new(
+            origin_chain,
+            signing_journal_db.clone(),
+        )));
+        if let Some(path) = &origin.signing_journal_import {
+            let interchange = SigningJournalInterchange::read_from_file(path)?;
+            let imported = signing_journal
+                .import(
+                    &interchange,
+                    raw_signer.eth_address(),
+                    merkle_tree_hook.domain().id(),
+                    merkle_tree_hook.address(),
+                )
+                .await?;
+            info!(
+                ?path,
+                imported,
+                total = interchange.checkpoints.len(),
+                "Imported signing journal"
+            );
+        }
+
+        let rotation = match &origin.rotation {
+            Some(rotation) => Some(
+                KeyRotation::from_settings(
+                    rotation,
+                    raw_signer.eth_address(),
+                    origin_chain,
+                    key_rotation_metrics.clone(),
+                )
+                .await?,
+            ),
+            None => None,
+        };
 
         let merkle_tree_hook_sync = settings
             .sequenced_contract_sync::<MerkleTreeInsertion, _>(
-                &settings.origin_chain,
-                &metrics,
-                &contract_sync_metrics,
+                origin_chain,
+                metrics,
+                contract_sync_metrics,
                 msg_db.clone().into(),
                 false,
                 false,
@@ -199,10 +451,10 @@ impl BaseAgent for Validator {
             .await?;
 
         Ok(Self {
-            origin_chain: settings.origin_chain,
+            origin_chain: origin_chain.clone(),
             origin_chain_conf,
-            core,
             db: msg_db,
+            signing_journal,
             mailbox: mailbox.into(),
             merkle_tree_hook: merkle_tree_hook.into(),
             merkle_tree_hook_sync,
@@ -210,48 +462,69 @@ impl BaseAgent for Validator {
             signer,
             raw_signer,
             signer_instance: Some(Box::new(signer_instance)),
-            reorg_period: settings.reorg_period,
+            reorg_period: origin.reorg_period.clone(),
             interval: settings.interval,
             checkpoint_syncer,
-            agent_metrics,
-            chain_metrics,
-            core_metrics: metrics,
-            runtime_metrics,
+            core_metrics: metrics.clone(),
+            agent_metrics: agent_metrics.clone(),
+            chain_metrics: chain_metrics.clone(),
             agent_metadata,
             max_sign_concurrency: settings.max_sign_concurrency,
             reorg_reporter,
+            rotation,
+            status: OriginStatus::default(),
         })
     }
 
-    #[allow(clippy::async_yields_async)]
-    async fn run(mut self) {
-        let mut tasks = vec![];
+    fn status_source(&self) -> validator_server::status::OriginSource {
+        let mut signers = vec![validator_server::status::SignerSource {
+            validator: self.signer.eth_address(),
+            checkpoint_syncer: self.checkpoint_syncer.clone(),
+        }];
+        if let Some(rotation) = &self.rotation {
+            signers.push(validator_server::status::SignerSource {
+                validator: rotation.info.validator,
+                checkpoint_syncer: rotation.checkpoint_syncer.clone(),
+            });
+        }
+        validator_server::status::OriginSource {
+            origin_chain: self.origin_chain.clone(),
+            reorg_period: self.reorg_period.clone(),
+            status: self.status.clone(),
+            signers,
+            reorg_reporter: self.reorg_reporter.clone(),
+        }
+    }
 
-        // run server
+    fn router(&self) -> Router {
         let router = Router::new()
-            .merge(validator_server::router(
-                self.origin_chain.clone(),
-                self.core.metrics.clone(),
-            ))
             .merge(
                 merkle_tree_insertions::list_merkle_tree_insertions::ServerState::new(
                     self.db.clone(),
                 )
                 .router(),
+            )
+            .merge(
+                validator_server::signing_journal::ServerState::new(
+                    self.signing_journal.clone(),
+                    self.signer.eth_address(),
+                    self.merkle_tree_hook.domain().id(),
+                    self.merkle_tree_hook.address(),
+                )
+                .router(),
             );
+        match &self.rotation {
+            Some(rotation) => router.merge(
+                validator_server::key_rotation::ServerState::new(rotation.info.clone()).router(),
+            ),
+            None => router,
+        }
+    }
 
-        let server = self
-            .core
-            .settings
-            .server(self.core_metrics.clone())
-            .expect("Failed to create server");
-        let server_task = tokio::spawn(
-            async move {
-                server.run_with_custom_router(router);
-            }
-            .instrument(info_span!("Validator server")),
-        );
-        tasks.push(server_task);
+    /// Runs until validation of this origin halts, e.g. because one of its
+    /// tasks panicked on a reorg. Other origins keep running.
+    async fn run(mut self) {
+        let mut tasks = vec![];
 
         if let Some(signer_instance) = self.signer_instance.take() {
             tasks.push(tokio::spawn(
@@ -261,13 +534,25 @@ impl BaseAgent for Validator {
                 .instrument(info_span!("SingletonSigner")),
             ));
         }
+        if let Some(signer_instance) = self
+            .rotation
+            .as_mut()
+            .and_then(|rotation| rotation.signer_instance.take())
+        {
+            tasks.push(tokio::spawn(
+                async move {
+                    signer_instance.run().await;
+                }
+                .instrument(info_span!("RotationSingletonSigner")),
+            ));
+        }
 
         let metrics_updater = match ChainSpecificMetricsUpdater::new(
             &self.origin_chain_conf,
             self.core_metrics.clone(),
             self.agent_metrics.clone(),
             self.chain_metrics.clone(),
-            Self::AGENT_NAME.to_string(),
+            Validator::AGENT_NAME.to_string(),
         )
         .await
         {
@@ -286,8 +571,22 @@ impl BaseAgent for Validator {
             .await
             .expect("Failed to report agent metadata");
 
-        // announce the validator after spawning the signer task
-        self.announce().await.expect("Failed to announce validator");
+        // announce the validator after spawning the signer task. During a
+        // rotation the new key is announced ahead of signing with it, so that
+        // relayers can pick up its checkpoints during the dual-signing window,
+        // and the previous key is no longer announced once the window ended.
+        for (key, signs_until) in self.signing_keys() {
+            let (signer, _, checkpoint_syncer) = self.validator_key(key);
+            until_rotation_ends(
+                async {
+                    self.announce(signer, checkpoint_syncer)
+                        .await
+                        .expect("Failed to announce validator");
+                },
+                signs_until,
+            )
+            .await;
+        }
 
         // Ensure that the merkle tree hook has count > 0 before we begin indexing
         // messages or submitting checkpoints.
@@ -321,17 +620,24 @@ impl BaseAgent for Validator {
         for checkpoint_sync_task in self.run_checkpoint_submitters().await {
             tasks.push(checkpoint_sync_task);
         }
+        self.status.set_submitting().await;
 
-        tasks.push(self.runtime_metrics.spawn());
-
+        let abort_handles = tasks.iter().map(JoinHandle::abort_handle).collect_vec();
         // Note that this only returns an error if one of the tasks panics
         if let Err(err) = try_join_all(tasks).await {
-            error!(?err, "One of the validator tasks returned an error");
+            error!(
+                ?err,
+                origin_chain = %self.origin_chain,
+                "One of the origin's validator tasks returned an error, halting validation of this origin"
+            );
+            self.chain_metrics
+                .set_critical_error(self.origin_chain.name(), true);
+            for handle in abort_handles {
+                handle.abort();
+            }
         }
     }
-}
 
-impl Validator {
     /// Try to create merkle tree hook contract sync attempts times before giving up.
     async fn try_n_times_to_run_merkle_tree_hook_sync(
         &self,
@@ -364,13 +670,7 @@ impl Validator {
     }
 
     async fn run_merkle_tree_hook_sync(&self) -> eyre::Result<JoinHandle<()>> {
-        let index_settings = self
-            .as_ref()
-            .settings
-            .chains
-            .get(&self.origin_chain)
-            .map(|chain| chain.index_settings())
-            .ok_or_else(|| eyre::eyre!("No index setting found"))?;
+        let index_settings = self.origin_chain_conf.index_settings();
         let contract_sync = self.merkle_tree_hook_sync.clone();
         let cursor = contract_sync.cursor(index_settings).await?;
         let origin = self.origin_chain.name().to_string();
@@ -387,19 +687,6 @@ impl Validator {
     }
 
     async fn run_checkpoint_submitters(&self) -> Vec<JoinHandle<()>> {
-        let submitter = ValidatorSubmitter::new(
-            self.interval,
-            self.reorg_period.clone(),
-            self.merkle_tree_hook.clone(),
-            self.signer.clone(),
-            self.raw_signer.clone(),
-            self.checkpoint_syncer.clone(),
-            Arc::new(self.db.clone()) as Arc<dyn HyperlaneDb>,
-            ValidatorSubmitterMetrics::new(&self.core.metrics, &self.origin_chain),
-            self.max_sign_concurrency,
-            self.reorg_reporter.clone(),
-        );
-
         let tip_tree = self
             .merkle_tree_hook
             .tree(&self.reorg_period)
@@ -410,29 +697,109 @@ impl Validator {
         // merkle tree hook has count > 0, but we assert to be extra sure this is
         // the case.
         assert!(tip_tree.count() > 0, "merkle tree is empty");
-        let backfill_target = submitter.checkpoint_at_block(&tip_tree);
+
+        let mut tasks = vec![];
+        // The previous key only signs until the end of the dual-signing window
+        for (key, signs_until) in self.signing_keys() {
+            let (signer, raw_signer, checkpoint_syncer) = self.validator_key(key);
+            tasks.extend(self.spawn_checkpoint_submitters(
+                signer.clone(),
+                raw_signer.clone(),
+                checkpoint_syncer.clone(),
+                &tip_tree,
+                signs_until,
+            ));
+        }
+        if let Some(rotation) = &self.rotation {
+            if rotation.info.phase() == KeyRotationPhase::DualSigning {
+                tasks.push(tokio::spawn(
+                    rotation
+                        .watch_end()
+                        .instrument(info_span!("KeyRotationWatcher")),
+                ));
+            }
+        }
+        tasks
+    }
+
+    fn signing_keys(&self) -> Vec<(ValidatorKey, Option<KeyRotationInfo>)> {
+        signing_keys(self.rotation.as_ref().map(|rotation| &rotation.info))
+    }
+
+    /// The signer and checkpoint syncer of a validator key of this origin
+    fn validator_key(
+        &self,
+        key: ValidatorKey,
+    ) -> (&SingletonSignerHandle, &Signers, &Arc<dyn CheckpointSyncer>) {
+        match (key, &self.rotation) {
+            (ValidatorKey::Rotated, Some(rotation)) => (
+                &rotation.signer,
+                &rotation.raw_signer,
+                &rotation.checkpoint_syncer,
+            ),
+            _ => (&self.signer, &self.raw_signer, &self.checkpoint_syncer),
+        }
+    }
+
+    /// Spawn the backfill and tip submitters of a validator key. With
+    /// `signs_until`, they stop once that key rotation window ends.
+    fn spawn_checkpoint_submitters(
+        &self,
+        signer: SingletonSignerHandle,
+        raw_signer: Signers,
+        checkpoint_syncer: Arc<dyn CheckpointSyncer>,
+        tip_tree: &IncrementalMerkleAtBlock,
+        signs_until: Option<KeyRotationInfo>,
+    ) -> Vec<JoinHandle<()>> {
+        let validator = signer.eth_address();
+        let submitter = ValidatorSubmitter::new(
+            self.interval,
+            self.reorg_period.clone(),
+            self.merkle_tree_hook.clone(),
+            signer,
+            raw_signer,
+            checkpoint_syncer,
+            Arc::new(self.db.clone()) as Arc<dyn HyperlaneDb>,
+            ValidatorSubmitterMetrics::new(&self.core_metrics, &self.origin_chain),
+            self.max_sign_concurrency,
+            self.reorg_reporter.clone(),
+            self.signing_journal.clone(),
+        );
+
+        let backfill_target = submitter.checkpoint_at_block(tip_tree);
 
         let backfill_submitter = submitter.clone();
+        let tree = tip_tree.tree.clone();
 
         let mut tasks = vec![];
         tasks.push(tokio::spawn(
-            async move {
-                backfill_submitter
-                    .backfill_checkpoint_submitter(backfill_target)
-                    .await
-            }
-            .instrument(info_span!("BackfillCheckpointSubmitter")),
+            until_rotation_ends(
+                async move {
+                    backfill_submitter
+                        .backfill_checkpoint_submitter(backfill_target)
+                        .await
+                },
+                signs_until.clone(),
+            )
+            .instrument(info_span!("BackfillCheckpointSubmitter", ?validator)),
         ));
 
         tasks.push(tokio::spawn(
-            async move { submitter.checkpoint_submitter(tip_tree.tree).await }
-                .instrument(info_span!("TipCheckpointSubmitter")),
+            until_rotation_ends(
+                async move { submitter.checkpoint_submitter(tree).await },
+                signs_until,
+            )
+            .instrument(info_span!("TipCheckpointSubmitter", ?validator)),
         ));
 
         tasks
     }
 
-    fn log_on_announce_failure(result: ChainResult<TxOutcome>, chain_signer: &String) {
+    /// Returns the reason the announcement failed, if it did
+    fn log_on_announce_failure(
+        result: ChainResult<TxOutcome>,
+        chain_signer: &String,
+    ) -> Option<String> {
         match result {
             Ok(outcome) => {
                 if outcome.executed {
@@ -441,6 +808,7 @@ impl Validator {
                         ?chain_signer,
                         "Successfully announced validator",
                     );
+                    None
                 } else {
                     error!(
                         txid=?outcome.transaction_id,
@@ -449,6 +817,10 @@ impl Validator {
                         ?chain_signer,
                         "Transaction attempting to announce validator reverted. Make sure you have enough funds in your account to pay for transaction fees."
                     );
+                    Some(format!(
+                        "Announcement transaction {:?} reverted",
+                        outcome.transaction_id
+                    ))
                 }
             }
             Err(err) => {
@@ -457,6 +829,7 @@ impl Validator {
                     ?chain_signer,
                     "Failed to announce validator. Make sure you have enough funds in your account to pay for gas."
                 );
+                Some(format!("Failed to announce validator: {err}"))
             }
         }
     }
@@ -465,22 +838,33 @@ impl Validator {
         let serialized_metadata = serde_json::to_string_pretty(&self.agent_metadata)?;
         self.checkpoint_syncer
             .write_metadata(&serialized_metadata)
-            .await
+            .await?;
+        if let Some(rotation) = &self.rotation {
+            rotation
+                .checkpoint_syncer
+                .write_metadata(&serialized_metadata)
+                .await?;
+        }
+        Ok(())
     }
 
-    async fn announce(&self) -> Result<()> {
-        let address = self.signer.eth_address();
-        let announcement_location = self.checkpoint_syncer.announcement_location();
+    async fn announce(
+        &self,
+        signer: &SingletonSignerHandle,
+        checkpoint_syncer: &Arc<dyn CheckpointSyncer>,
+    ) -> Result<()> {
+        let address = signer.eth_address();
+        let announcement_location = checkpoint_syncer.announcement_location();
 
         // Sign and post the validator announcement
         let announcement = Announcement {
             validator: address,
             mailbox_address: self.mailbox.address(),
             mailbox_domain: self.mailbox.domain().id(),
-            storage_location: self.announcement_location()?, // Use formatted location for the signed announcement
+            storage_location: self.announcement_location(checkpoint_syncer)?, // Use formatted location for the signed announcement
         };
-        let signed_announcement = self.signer.sign(announcement.clone()).await?;
-        self.checkpoint_syncer
+        let signed_announcement = signer.sign(announcement.clone()).await?;
+        checkpoint_syncer
             .write_announcement(&signed_announcement)
             .await?;
 
@@ -505,6 +889,9 @@ impl Validator {
                     );
 
                     self.core_metrics.set_announced(self.origin_chain.clone());
+                    self.status
+                        .set_announce_status(address, AnnounceStatus::announced())
+                        .await;
 
                     break;
                 }
@@ -513,10 +900,7 @@ impl Validator {
                     "Validator has not announced signature storage location"
                 );
 
-                if let Some(chain_signer) = self.core.settings.chains[&self.origin_chain]
-                    .chain_signer()
-                    .await?
-                {
+                if let Some(chain_signer) = self.origin_chain_conf.chain_signer().await? {
                     let chain_signer_string = chain_signer.address_string();
                     let chain_signer_h256 = chain_signer.address_h256();
                     info!(eth_validator_address=?announcement.validator, ?chain_signer_string, ?chain_signer_h256, "Attempting self announce");
@@ -534,15 +918,35 @@ impl Validator {
                             ?chain_signer_h256,
                             "Please send tokens to your chain signer address to announce",
                         );
+                        self.status
+                            .set_announce_status(
+                                address,
+                                AnnounceStatus::failed(format!(
+                                    "Chain signer {chain_signer_string} needs {balance_delta} more tokens to announce"
+                                )),
+                            )
+                            .await;
                     } else {
                         let result = self
                             .validator_announce
                             .announce(signed_announcement.clone())
                             .await;
-                        Self::log_on_announce_failure(result, &chain_signer_string);
+                        if let Some(error) =
+                            Self::log_on_announce_failure(result, &chain_signer_string)
+                        {
+                            self.status
+                                .set_announce_status(address, AnnounceStatus::failed(error))
+                                .await;
+                        }
                     }
                 } else {
                     warn!(origin_chain=%self.origin_chain, "Cannot announce validator without a signer; make sure a signer is set for the origin chain");
+                    self.status
+                        .set_announce_status(
+                            address,
+                            AnnounceStatus::failed("No signer set for the origin chain"),
+                        )
+                        .await;
                 }
 
                 sleep(self.interval).await;
@@ -576,33 +980,77 @@ impl Validator {
         }
     }
 
-    fn announcement_location(&self) -> Result<String> {
-        let location = self.checkpoint_syncer.announcement_location();
+    fn announcement_location(
+        &self,
+        checkpoint_syncer: &Arc<dyn CheckpointSyncer>,
+    ) -> Result<String> {
+        let location = checkpoint_syncer.announcement_location();
         if self.origin_chain.domain_protocol() == hyperlane_core::HyperlaneDomainProtocol::Aleo {
-            Self::aleo_announcement_location(location)
+            Validator::aleo_announcement_location(location)
         } else {
             Ok(location)
         }
     }
+}
 
-    fn aleo_announcement_location(announcement_location: String) -> Result<String> {
-        // Aleo announcement locations are fixed size C strings of 480 bytes (include nulls)
-        let mut bytes = announcement_location.into_bytes();
-        // Ensure it fits within 479 bytes (leaving room for null terminator)
-        if bytes.len() > 479 {
-            return Err(eyre!(
-                "Aleo announcement location too long: {} bytes (max 479)",
-                bytes.len()
-            ));
+/// Keeps the origins that started. With several origins, one failing to start
+/// is flagged as a critical error for its chain rather than stopping the
+/// others.
+fn started_origins<T>(
+    results: Vec<(HyperlaneDomain, Result<T>)>,
+    chain_metrics: &ChainMetrics,
+) -> Result<Vec<T>> {
+    let multi_origin = results.len() > 1;
+    let mut origins = vec![];
+    for (origin_chain, result) in results {
+        match result {
+            Ok(origin) => origins.push(origin),
+            Err(err) if multi_origin => {
+                error!(
+                    ?err,
+                    %origin_chain,
+                    "Failed to start validating origin, skipping it"
+                );
+                chain_metrics.set_critical_error(origin_chain.name(), true);
+            }
+            Err(err) => return Err(err),
         }
-        // Pad remaining bytes with nulls up to 480 total
-        bytes.resize(480, 0);
-        String::from_utf8(bytes).map_err(|e| {
-            eyre!(
-                "Failed to convert Aleo announcement location to string: {}",
-                e
-            )
-        })
+    }
+    if origins.is_empty() {
+        return Err(eyre!("Failed to start validating any origin"));
+    }
+    Ok(origins)
+}
+
+/// Runs the validation of an origin in its own task, so that it panicking
+/// only halts that origin. The returned future completes once it halted.
+fn supervise_origin(
+    origin_chain: &HyperlaneDomain,
+    status: OriginStatus,
+    run: impl Future<Output = ()> + Send + 'static,
+) -> impl Future<Output = ()> {
+    let span = info_span!("Origin", %origin_chain);
+    let task = tokio::spawn(run.instrument(span.clone()));
+    async move {
+        if let Err(err) = task.await {
+            error!(?err, "Origin validator task panicked");
+        }
+        status.set_halted().await;
+    }
+    .instrument(span)
+}
+
+/// Run a submitter task, or until the rotation window ends if it signs with
+/// the key being rotated out
+async fn until_rotation_ends(task: impl Future<Output = ()>, signs_until: Option<KeyRotationInfo>) {
+    match signs_until {
+        Some(rotation) => {
+            tokio::select! {
+                _ = task => {}
+                _ = rotation.wait_for_end() => {}
+            }
+        }
+        None => task.await,
     }
 }
 
@@ -670,4 +1118,69 @@ mod tests {
         assert!(out_bytes[input_bytes.len()..].iter().all(|&b| b == 0));
         Ok(())
     }
+
+    fn dummy_chain_metrics() -> ChainMetrics {
+        let core_metrics =
+            CoreMetrics::new("dummy_validator", 37582, prometheus::Registry::new()).unwrap();
+        ChainMetrics::new(&core_metrics).unwrap()
+    }
+
+    #[test]
+    fn origin_failing_to_start_is_skipped() {
+        let chain_metrics = dummy_chain_metrics();
+        let results = vec![
+            (HyperlaneDomain::new_test_domain("healthy"), Ok(1)),
+            (
+                HyperlaneDomain::new_test_domain("broken"),
+                Err(eyre!("Failed to build checkpoint syncer")),
+            ),
+        ];
+
+        let origins = started_origins(results, &chain_metrics).unwrap();
+
+        assert_eq!(origins, vec![1]);
+        let critical = |chain: &str| {
+            chain_metrics
+                .critical_error
+                .get_metric_with_label_values(&[chain])
+                .unwrap()
+                .get()
+        };
+        assert_eq!(critical("broken"), 1);
+        assert_eq!(critical("healthy"), 0);
+    }
+
+    #[test]
+    fn single_origin_failing_to_start_is_fatal() {
+        let results: Vec<(HyperlaneDomain, Result<()>)> = vec![(
+            HyperlaneDomain::new_test_domain("broken"),
+            Err(eyre!("Failed to build checkpoint syncer")),
+        )];
+
+        assert!(started_origins(results, &dummy_chain_metrics()).is_err());
+    }
+
+    #[tokio::test]
+    async fn halted_origin_does_not_stop_the_others() {
+        let halting_status = OriginStatus::default();
+        let running_status = OriginStatus::default();
+        let halting = supervise_origin(
+            &HyperlaneDomain::new_test_domain("halting"),
+            halting_status.clone(),
+            async { panic!("reorg detected") },
+        );
+        let running = supervise_origin(
+            &HyperlaneDomain::new_test_domain("running"),
+            running_status.clone(),
+            std::future::pending(),
+        );
+
+        halting.await;
+
+        assert!(halting_status.snapshot().await.halted);
+        assert!(!running_status.snapshot().await.halted);
+        assert!(tokio::time::timeout(Duration::from_millis(100), running)
+            .await
+            .is_err());
+    }
 }
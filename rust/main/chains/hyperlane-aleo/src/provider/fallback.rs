@@ -4,8 +4,9 @@ use hyperlane_core::{
     rpc_clients::{BlockNumberGetter, FallbackProvider},
     ChainResult,
 };
-use hyperlane_metric::prometheus_metric::{
-    ClientConnectionType, PrometheusClientMetrics, PrometheusConfig,
+use hyperlane_metric::{
+    prometheus_metric::{ClientConnectionType, PrometheusClientMetrics, PrometheusConfig},
+    utils::urls_to_provider_nodes,
 };
 use snarkvm_console_account::{DeserializeOwned, Itertools};
 use url::Url;
@@ -28,6 +29,7 @@ impl<C: AleoClient> FallbackHttpClient<C> {
         chain: Option<hyperlane_metric::prometheus_metric::ChainInfo>,
         network: u16,
     ) -> ChainResult<Self> {
+        let provider_nodes = urls_to_provider_nodes(&urls);
         let clients = urls
             .into_iter()
             .map(|url| {
@@ -39,7 +41,14 @@ impl<C: AleoClient> FallbackHttpClient<C> {
             .into_iter()
             .map(RpcClient::new)
             .collect_vec();
-        let fallback = FallbackProvider::new(clients);
+        let fallback = FallbackProvider::builder()
+            .add_providers(clients)
+            .with_score_metric(
+                metrics.provider_score.clone(),
+                PrometheusConfig::chain_name(&chain),
+                provider_nodes,
+            )
+            .build();
         Ok(Self { fallback })
     }
 }
@@ -0,0 +1,178 @@
+//! BCS encoding of the transactions the agents sign, so the signing message
+//! does not have to be trusted from the node.
+
+use sha3::{Digest, Sha3_256};
+
+use hyperlane_core::{ChainResult, H256};
+
+use crate::{
+    utils::parse_address, EntryFunctionArgument, EntryFunctionPayload, HyperlaneAptosError,
+    SubmitTransactionRequest,
+};
+
+/// Domain separator hashed into the prefix of transaction signing messages
+const RAW_TRANSACTION_SALT: &[u8] = b"APTOS::RawTransaction";
+/// Variant index of `TransactionPayload::EntryFunction`
+const ENTRY_FUNCTION_PAYLOAD: u32 = 2;
+
+/// Returns the message an account signs to authorize a transaction:
+/// `sha3-256("APTOS::RawTransaction")` followed by the BCS encoded
+/// `RawTransaction`
+pub(crate) fn signing_message(
+    request: &SubmitTransactionRequest,
+    chain_id: u8,
+) -> ChainResult<Vec<u8>> {
+    let mut message = Sha3_256::digest(RAW_TRANSACTION_SALT).to_vec();
+    write_address(&mut message, request.sender);
+    message.extend(request.sequence_number.to_le_bytes());
+    write_entry_function(&mut message, &request.payload)?;
+    message.extend(request.max_gas_amount.to_le_bytes());
+    message.extend(request.gas_unit_price.to_le_bytes());
+    message.extend(request.expiration_timestamp_secs.to_le_bytes());
+    message.push(chain_id);
+    Ok(message)
+}
+
+/// Writes a `TransactionPayload::EntryFunction`
+fn write_entry_function(out: &mut Vec<u8>, payload: &EntryFunctionPayload) -> ChainResult<()> {
+    let invalid_function =
+        || HyperlaneAptosError::Other(format!("Invalid entry function: {}", payload.function));
+    let mut parts = payload.function.splitn(3, "::");
+    let (Some(address), Some(module), Some(function)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid_function().into());
+    };
+    if function.contains("::") {
+        return Err(invalid_function().into());
+    }
+    // Encoding type tags isn't needed by any of the entry functions called
+    if !payload.type_arguments.is_empty() {
+        return Err(HyperlaneAptosError::Other(format!(
+            "Type arguments of entry function {} can't be encoded",
+            payload.function
+        ))
+        .into());
+    }
+
+    write_uleb128(out, ENTRY_FUNCTION_PAYLOAD);
+    write_address(out, parse_address(address)?);
+    write_bytes(out, module.as_bytes())?;
+    write_bytes(out, function.as_bytes())?;
+    write_length(out, 0)?;
+    write_length(out, payload.arguments.len())?;
+    for argument in &payload.arguments {
+        // Each argument is itself BCS encoded, then written as a `vector<u8>`
+        let mut encoded = vec![];
+        match argument {
+            EntryFunctionArgument::Bytes(bytes) => write_bytes(&mut encoded, bytes)?,
+            EntryFunctionArgument::String(string) => write_bytes(&mut encoded, string.as_bytes())?,
+        }
+        write_bytes(out, &encoded)?;
+    }
+    Ok(())
+}
+
+/// Writes an account address, which is encoded without a length prefix
+fn write_address(out: &mut Vec<u8>, address: H256) {
+    out.extend_from_slice(address.as_bytes());
+}
+
+/// Writes a `vector<u8>` or a string
+fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) -> ChainResult<()> {
+    write_length(out, bytes.len())?;
+    out.extend_from_slice(bytes);
+    Ok(())
+}
+
+/// Writes the length of a sequence, which BCS limits to `u32`
+fn write_length(out: &mut Vec<u8>, length: usize) -> ChainResult<()> {
+    let length = u32::try_from(length)
+        .map_err(|_| HyperlaneAptosError::Other(format!("Sequence too long: {length}")))?;
+    write_uleb128(out, length);
+    Ok(())
+}
+
+fn write_uleb128(out: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        out.push((value % 0x80) as u8 | 0x80);
+        value /= 0x80;
+    }
+    out.push(value as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(payload: EntryFunctionPayload) -> SubmitTransactionRequest {
+        SubmitTransactionRequest {
+            sender: H256::repeat_byte(0x01),
+            sequence_number: 7,
+            max_gas_amount: 1_000,
+            gas_unit_price: 100,
+            expiration_timestamp_secs: 1_700_000_000,
+            payload,
+            signature: None,
+        }
+    }
+
+    #[test]
+    fn test_uleb128() {
+        for (value, expected) in [
+            (0, vec![0x00]),
+            (0x7f, vec![0x7f]),
+            (0x80, vec![0x80, 0x01]),
+            (300, vec![0xac, 0x02]),
+            (u32::MAX, vec![0xff, 0xff, 0xff, 0xff, 0x0f]),
+        ] {
+            let mut out = vec![];
+            write_uleb128(&mut out, value);
+            assert_eq!(out, expected);
+        }
+    }
+
+    #[test]
+    fn test_signing_message() {
+        let payload = EntryFunctionPayload {
+            function: "0x1::mailbox::process".to_owned(),
+            type_arguments: vec![],
+            arguments: vec![
+                EntryFunctionArgument::Bytes(vec![0xab, 0xcd]),
+                EntryFunctionArgument::String("s3".to_owned()),
+            ],
+        };
+        let message = signing_message(&request(payload), 4).unwrap();
+
+        let mut expected = Sha3_256::digest(b"APTOS::RawTransaction").to_vec();
+        expected.extend([0x01; 32]);
+        expected.extend([7, 0, 0, 0, 0, 0, 0, 0]);
+        // Entry function variant, module address and name, function name
+        expected.push(2);
+        expected.extend(H256::from_low_u64_be(1).as_bytes());
+        expected.extend(b"\x07mailbox");
+        expected.extend(b"\x07process");
+        // No type arguments, then two arguments wrapped in `vector<u8>`
+        expected.extend([0, 2]);
+        expected.extend([3, 2, 0xab, 0xcd]);
+        expected.extend([3, 2, b's', b'3']);
+        expected.extend(1_000u64.to_le_bytes());
+        expected.extend(100u64.to_le_bytes());
+        expected.extend(1_700_000_000u64.to_le_bytes());
+        expected.push(4);
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn test_signing_message_rejects_unsupported_payloads() {
+        let mut payload = EntryFunctionPayload {
+            function: "0x1::coin::transfer".to_owned(),
+            type_arguments: vec!["0x1::aptos_coin::AptosCoin".to_owned()],
+            arguments: vec![],
+        };
+        assert!(signing_message(&request(payload.clone()), 4).is_err());
+
+        payload.type_arguments.clear();
+        payload.function = "0x1::coin".to_owned();
+        assert!(signing_message(&request(payload), 4).is_err());
+    }
+}
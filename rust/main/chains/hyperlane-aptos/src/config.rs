@@ -0,0 +1,25 @@
+use url::Url;
+
+/// Default multiplier applied to the simulated gas usage of a transaction to
+/// get its max gas amount
+pub const DEFAULT_GAS_LIMIT_MULTIPLIER: f64 = 1.5;
+
+/// Aptos connection configuration
+#[derive(Debug, Clone)]
+pub struct ConnectionConf {
+    /// Aptos REST API urls, including the `/v1` path
+    pub urls: Vec<Url>,
+    /// Multiplier applied to the simulated gas usage of a transaction to get
+    /// its max gas amount
+    pub gas_limit_multiplier: f64,
+}
+
+impl ConnectionConf {
+    /// New Aptos Connection Config
+    pub fn new(urls: Vec<Url>, gas_limit_multiplier: Option<f64>) -> Self {
+        Self {
+            urls,
+            gas_limit_multiplier: gas_limit_multiplier.unwrap_or(DEFAULT_GAS_LIMIT_MULTIPLIER),
+        }
+    }
+}
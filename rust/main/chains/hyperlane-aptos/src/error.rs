@@ -0,0 +1,55 @@
+use hyperlane_core::ChainCommunicationError;
+
+/// Errors from the crates specific to the hyperlane-aptos
+#[derive(Debug, thiserror::Error)]
+pub enum HyperlaneAptosError {
+    /// Reqwest Errors
+    #[error("{0}")]
+    ReqwestError(#[from] reqwest::Error),
+    /// Serde Errors
+    #[error("{0}")]
+    SerdeError(#[from] serde_json::Error),
+    /// Hex decoding errors
+    #[error("{0}")]
+    HexError(#[from] hex::FromHexError),
+    /// Error returned by the REST API
+    #[error("Aptos API error ({status}): {message}")]
+    ApiError {
+        /// HTTP status code
+        status: u16,
+        /// Error message returned by the node
+        message: String,
+    },
+    /// Signer missing
+    #[error("Signer missing")]
+    SignerMissing,
+    /// Invalid private key
+    #[error("Invalid private key: {0}")]
+    InvalidPrivateKey(String),
+    /// Unexpected return value of a view function
+    #[error("Unexpected return value of view function {function}: {value}")]
+    UnexpectedViewResult {
+        /// Fully qualified view function
+        function: String,
+        /// Value returned by the node
+        value: serde_json::Value,
+    },
+    /// Unknown ISM type
+    #[error("Unknown ISM type: {0}")]
+    UnknownIsmType(u8),
+    /// Transaction simulation failed
+    #[error("Transaction simulation failed: {0}")]
+    SimulationFailed(String),
+    /// Transaction was not committed in time
+    #[error("Transaction {0} was not committed in time")]
+    TransactionTimeout(String),
+    /// Other errors
+    #[error("{0}")]
+    Other(String),
+}
+
+impl From<HyperlaneAptosError> for ChainCommunicationError {
+    fn from(value: HyperlaneAptosError) -> Self {
+        ChainCommunicationError::from_other(value)
+    }
+}
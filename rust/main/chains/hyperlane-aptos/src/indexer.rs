@@ -0,0 +1,13 @@
+pub(crate) use traits::AptosIndexer;
+
+pub use delivery::AptosDeliveryIndexer;
+pub use dispatch::AptosDispatchIndexer;
+pub use interchain_gas::AptosInterchainGasIndexer;
+pub use merkle_tree_hook::AptosMerkleTreeHook;
+
+mod delivery;
+mod dispatch;
+mod interchain_gas;
+mod merkle_tree_hook;
+
+mod traits;
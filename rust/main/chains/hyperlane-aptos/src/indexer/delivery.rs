@@ -0,0 +1,103 @@
+use std::ops::RangeInclusive;
+
+use async_trait::async_trait;
+
+use hyperlane_core::{
+    ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
+    HyperlaneProvider, Indexed, Indexer, LogMeta, SequenceAwareIndexer, H256, H512,
+};
+
+use crate::{
+    indexer::AptosIndexer,
+    mailbox::MAILBOX_MODULE,
+    provider::{AptosClient, FallbackHttpClient},
+    AptosProvider, ProcessIdEvent,
+};
+
+/// Aptos Delivery Indexer
+#[derive(Debug, Clone)]
+pub struct AptosDeliveryIndexer<C: AptosClient = FallbackHttpClient> {
+    provider: AptosProvider<C>,
+    address: H256,
+    domain: HyperlaneDomain,
+}
+
+impl<C: AptosClient> AptosDeliveryIndexer<C> {
+    /// Creates a new Delivery Indexer
+    pub fn new(provider: AptosProvider<C>, locator: &ContractLocator) -> Self {
+        Self {
+            provider,
+            address: locator.address,
+            domain: locator.domain.clone(),
+        }
+    }
+}
+
+impl<C: AptosClient> HyperlaneChain for AptosDeliveryIndexer<C> {
+    /// Return the domain
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    /// A provider for the chain
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+impl<C: AptosClient> HyperlaneContract for AptosDeliveryIndexer<C> {
+    /// Address
+    fn address(&self) -> H256 {
+        self.address
+    }
+}
+
+impl<C: AptosClient> AptosIndexer for AptosDeliveryIndexer<C> {
+    const MODULE: &str = MAILBOX_MODULE;
+    const EVENT: &str = "ProcessIdEvent";
+
+    type AptosType = ProcessIdEvent;
+    type Type = H256;
+
+    fn get_provider(&self) -> &AptosProvider<impl AptosClient> {
+        &self.provider
+    }
+
+    fn get_package(&self) -> H256 {
+        self.address
+    }
+}
+
+#[async_trait]
+impl<C: AptosClient> Indexer<H256> for AptosDeliveryIndexer<C> {
+    /// Fetch list of logs between blocks `from` and `to`, inclusive.
+    async fn fetch_logs_in_range(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> ChainResult<Vec<(Indexed<H256>, LogMeta)>> {
+        AptosIndexer::fetch_logs_in_range(self, range).await
+    }
+
+    /// Get the chain's latest block number that has reached finality
+    async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+        AptosIndexer::get_finalized_block_number(self).await
+    }
+
+    /// Fetch list of logs emitted in a transaction with the given hash.
+    async fn fetch_logs_by_tx_hash(
+        &self,
+        tx_hash: H512,
+    ) -> ChainResult<Vec<(Indexed<H256>, LogMeta)>> {
+        AptosIndexer::fetch_logs_by_tx_hash(self, tx_hash).await
+    }
+}
+
+#[async_trait]
+impl<C: AptosClient> SequenceAwareIndexer<H256> for AptosDeliveryIndexer<C> {
+    /// Return the latest finalized sequence (if any) and block number
+    async fn latest_sequence_count_and_tip(&self) -> ChainResult<(Option<u32>, u32)> {
+        // Deliveries are not sequenced
+        let tip = AptosIndexer::get_finalized_block_number(self).await?;
+        Ok((None, tip))
+    }
+}
@@ -0,0 +1,113 @@
+use std::ops::RangeInclusive;
+
+use async_trait::async_trait;
+
+use hyperlane_core::{
+    ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
+    HyperlaneMessage, HyperlaneProvider, Indexed, Indexer, LogMeta, SequenceAwareIndexer, H256,
+    H512,
+};
+
+use crate::{
+    indexer::AptosIndexer,
+    mailbox::MAILBOX_MODULE,
+    provider::{AptosClient, FallbackHttpClient},
+    AptosProvider, DispatchEvent,
+};
+
+/// Aptos Dispatch Indexer
+#[derive(Debug, Clone)]
+pub struct AptosDispatchIndexer<C: AptosClient = FallbackHttpClient> {
+    provider: AptosProvider<C>,
+    address: H256,
+    domain: HyperlaneDomain,
+}
+
+impl<C: AptosClient> AptosDispatchIndexer<C> {
+    /// Creates a new Dispatch Indexer
+    pub fn new(provider: AptosProvider<C>, locator: &ContractLocator) -> Self {
+        Self {
+            provider,
+            address: locator.address,
+            domain: locator.domain.clone(),
+        }
+    }
+}
+
+impl<C: AptosClient> HyperlaneChain for AptosDispatchIndexer<C> {
+    /// Return the domain
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    /// A provider for the chain
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+impl<C: AptosClient> HyperlaneContract for AptosDispatchIndexer<C> {
+    /// Address
+    fn address(&self) -> H256 {
+        self.address
+    }
+}
+
+impl<C: AptosClient> AptosIndexer for AptosDispatchIndexer<C> {
+    const MODULE: &str = MAILBOX_MODULE;
+    const EVENT: &str = "DispatchEvent";
+
+    type AptosType = DispatchEvent;
+    type Type = HyperlaneMessage;
+
+    fn get_provider(&self) -> &AptosProvider<impl AptosClient> {
+        &self.provider
+    }
+
+    fn get_package(&self) -> H256 {
+        self.address
+    }
+}
+
+#[async_trait]
+impl<C: AptosClient> Indexer<HyperlaneMessage> for AptosDispatchIndexer<C> {
+    /// Fetch list of logs between blocks `from` and `to`, inclusive.
+    async fn fetch_logs_in_range(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> ChainResult<Vec<(Indexed<HyperlaneMessage>, LogMeta)>> {
+        AptosIndexer::fetch_logs_in_range(self, range).await
+    }
+
+    /// Get the chain's latest block number that has reached finality
+    async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+        AptosIndexer::get_finalized_block_number(self).await
+    }
+
+    /// Fetch list of logs emitted in a transaction with the given hash.
+    async fn fetch_logs_by_tx_hash(
+        &self,
+        tx_hash: H512,
+    ) -> ChainResult<Vec<(Indexed<HyperlaneMessage>, LogMeta)>> {
+        AptosIndexer::fetch_logs_by_tx_hash(self, tx_hash).await
+    }
+}
+
+#[async_trait]
+impl<C: AptosClient> SequenceAwareIndexer<HyperlaneMessage> for AptosDispatchIndexer<C> {
+    /// Return the latest finalized sequence (if any) and block number
+    async fn latest_sequence_count_and_tip(&self) -> ChainResult<(Option<u32>, u32)> {
+        let (tip, ledger_version) = self.provider.get_tip().await?;
+        let (nonce,): (u32,) = self
+            .provider
+            .view(
+                self.address,
+                MAILBOX_MODULE,
+                "nonce",
+                vec![],
+                Some(ledger_version),
+            )
+            .await?;
+        Ok((Some(nonce), tip))
+    }
+}
@@ -0,0 +1,105 @@
+use std::ops::RangeInclusive;
+
+use async_trait::async_trait;
+
+use hyperlane_core::{
+    ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
+    HyperlaneProvider, Indexed, Indexer, InterchainGasPaymaster, InterchainGasPayment, LogMeta,
+    SequenceAwareIndexer, H256, H512,
+};
+
+use crate::{
+    indexer::AptosIndexer,
+    provider::{AptosClient, FallbackHttpClient},
+    AptosProvider, GasPaymentEvent,
+};
+
+/// Aptos InterchainGas Indexer
+#[derive(Debug, Clone)]
+pub struct AptosInterchainGasIndexer<C: AptosClient = FallbackHttpClient> {
+    provider: AptosProvider<C>,
+    address: H256,
+    domain: HyperlaneDomain,
+}
+
+impl<C: AptosClient> AptosInterchainGasIndexer<C> {
+    /// Creates a new IGP Indexer
+    pub fn new(provider: AptosProvider<C>, locator: &ContractLocator) -> Self {
+        Self {
+            provider,
+            address: locator.address,
+            domain: locator.domain.clone(),
+        }
+    }
+}
+
+impl<C: AptosClient> HyperlaneChain for AptosInterchainGasIndexer<C> {
+    /// Return the domain
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    /// A provider for the chain
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+impl<C: AptosClient> HyperlaneContract for AptosInterchainGasIndexer<C> {
+    /// Address
+    fn address(&self) -> H256 {
+        self.address
+    }
+}
+
+impl<C: AptosClient> InterchainGasPaymaster for AptosInterchainGasIndexer<C> {}
+
+impl<C: AptosClient> AptosIndexer for AptosInterchainGasIndexer<C> {
+    const MODULE: &str = "igp";
+    const EVENT: &str = "GasPaymentEvent";
+
+    type AptosType = GasPaymentEvent;
+    type Type = InterchainGasPayment;
+
+    fn get_provider(&self) -> &AptosProvider<impl AptosClient> {
+        &self.provider
+    }
+
+    fn get_package(&self) -> H256 {
+        self.address
+    }
+}
+
+#[async_trait]
+impl<C: AptosClient> Indexer<InterchainGasPayment> for AptosInterchainGasIndexer<C> {
+    /// Fetch list of logs between blocks `from` and `to`, inclusive.
+    async fn fetch_logs_in_range(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> ChainResult<Vec<(Indexed<InterchainGasPayment>, LogMeta)>> {
+        AptosIndexer::fetch_logs_in_range(self, range).await
+    }
+
+    /// Get the chain's latest block number that has reached finality
+    async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+        AptosIndexer::get_finalized_block_number(self).await
+    }
+
+    /// Fetch list of logs emitted in a transaction with the given hash.
+    async fn fetch_logs_by_tx_hash(
+        &self,
+        tx_hash: H512,
+    ) -> ChainResult<Vec<(Indexed<InterchainGasPayment>, LogMeta)>> {
+        AptosIndexer::fetch_logs_by_tx_hash(self, tx_hash).await
+    }
+}
+
+#[async_trait]
+impl<C: AptosClient> SequenceAwareIndexer<InterchainGasPayment> for AptosInterchainGasIndexer<C> {
+    /// Return the latest finalized sequence (if any) and block number
+    async fn latest_sequence_count_and_tip(&self) -> ChainResult<(Option<u32>, u32)> {
+        // Gas payments are not sequenced
+        let tip = AptosIndexer::get_finalized_block_number(self).await?;
+        Ok((None, tip))
+    }
+}
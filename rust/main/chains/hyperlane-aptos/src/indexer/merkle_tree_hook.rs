@@ -0,0 +1,210 @@
+use std::ops::RangeInclusive;
+
+use async_trait::async_trait;
+
+use hyperlane_core::{
+    accumulator::{incremental::IncrementalMerkle, TREE_DEPTH},
+    ChainResult, Checkpoint, CheckpointAtBlock, ContractLocator, HyperlaneChain, HyperlaneContract,
+    HyperlaneDomain, HyperlaneProvider, IncrementalMerkleAtBlock, Indexed, Indexer, LogMeta,
+    MerkleTreeHook, MerkleTreeInsertion, ReorgPeriod, SequenceAwareIndexer, H256, H512,
+};
+
+use crate::{
+    indexer::AptosIndexer,
+    provider::{AptosClient, FallbackHttpClient},
+    utils::parse_hash,
+    AptosProvider, HyperlaneAptosError, InsertedIntoTreeEvent,
+};
+
+/// Name of the merkle tree hook module
+const MERKLE_TREE_HOOK_MODULE: &str = "merkle_tree_hook";
+
+/// Aptos Merkle Tree Hook and its Indexer
+#[derive(Debug, Clone)]
+pub struct AptosMerkleTreeHook<C: AptosClient = FallbackHttpClient> {
+    provider: AptosProvider<C>,
+    address: H256,
+    domain: HyperlaneDomain,
+}
+
+impl<C: AptosClient> AptosMerkleTreeHook<C> {
+    /// Creates a new Merkle Tree Hook
+    pub fn new(provider: AptosProvider<C>, locator: &ContractLocator) -> Self {
+        Self {
+            provider,
+            address: locator.address,
+            domain: locator.domain.clone(),
+        }
+    }
+
+    async fn count_at(&self, ledger_version: u64) -> ChainResult<u32> {
+        let (count,): (u32,) = self
+            .provider
+            .view(
+                self.address,
+                MERKLE_TREE_HOOK_MODULE,
+                "count",
+                vec![],
+                Some(ledger_version),
+            )
+            .await?;
+        Ok(count)
+    }
+
+    async fn checkpoint_at(
+        &self,
+        height: u64,
+        ledger_version: u64,
+    ) -> ChainResult<CheckpointAtBlock> {
+        let (root, index): (String, u32) = self
+            .provider
+            .view(
+                self.address,
+                MERKLE_TREE_HOOK_MODULE,
+                "latest_checkpoint",
+                vec![],
+                Some(ledger_version),
+            )
+            .await?;
+        Ok(CheckpointAtBlock {
+            checkpoint: Checkpoint {
+                merkle_tree_hook_address: self.address,
+                mailbox_domain: self.domain.id(),
+                root: parse_hash(&root)?,
+                index,
+            },
+            block_height: Some(height),
+        })
+    }
+}
+
+impl<C: AptosClient> HyperlaneChain for AptosMerkleTreeHook<C> {
+    /// Return the domain
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    /// A provider for the chain
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+impl<C: AptosClient> HyperlaneContract for AptosMerkleTreeHook<C> {
+    /// Address
+    fn address(&self) -> H256 {
+        self.address
+    }
+}
+
+impl<C: AptosClient> AptosIndexer for AptosMerkleTreeHook<C> {
+    const MODULE: &str = MERKLE_TREE_HOOK_MODULE;
+    const EVENT: &str = "InsertedIntoTreeEvent";
+
+    type AptosType = InsertedIntoTreeEvent;
+    type Type = MerkleTreeInsertion;
+
+    fn get_provider(&self) -> &AptosProvider<impl AptosClient> {
+        &self.provider
+    }
+
+    fn get_package(&self) -> H256 {
+        self.address
+    }
+}
+
+#[async_trait]
+impl<C: AptosClient> Indexer<MerkleTreeInsertion> for AptosMerkleTreeHook<C> {
+    /// Fetch list of logs between blocks `from` and `to`, inclusive.
+    async fn fetch_logs_in_range(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> ChainResult<Vec<(Indexed<MerkleTreeInsertion>, LogMeta)>> {
+        AptosIndexer::fetch_logs_in_range(self, range).await
+    }
+
+    /// Get the chain's latest block number that has reached finality
+    async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+        AptosIndexer::get_finalized_block_number(self).await
+    }
+
+    /// Fetch list of logs emitted in a transaction with the given hash.
+    async fn fetch_logs_by_tx_hash(
+        &self,
+        tx_hash: H512,
+    ) -> ChainResult<Vec<(Indexed<MerkleTreeInsertion>, LogMeta)>> {
+        AptosIndexer::fetch_logs_by_tx_hash(self, tx_hash).await
+    }
+}
+
+#[async_trait]
+impl<C: AptosClient> SequenceAwareIndexer<MerkleTreeInsertion> for AptosMerkleTreeHook<C> {
+    /// Return the latest finalized sequence (if any) and block number
+    async fn latest_sequence_count_and_tip(&self) -> ChainResult<(Option<u32>, u32)> {
+        let (tip, ledger_version) = self.provider.get_tip().await?;
+        let count = self.count_at(ledger_version).await?;
+        Ok((Some(count), tip))
+    }
+}
+
+#[async_trait]
+impl<C: AptosClient> MerkleTreeHook for AptosMerkleTreeHook<C> {
+    /// Return the incremental merkle tree in storage
+    ///
+    /// - `reorg_period` is how far behind the current block to query, if not specified
+    ///   it will query at the latest block.
+    async fn tree(&self, reorg_period: &ReorgPeriod) -> ChainResult<IncrementalMerkleAtBlock> {
+        let (height, ledger_version) = self.provider.ledger_state_at(reorg_period).await?;
+        let (branch, count): (Vec<String>, u32) = self
+            .provider
+            .view(
+                self.address,
+                MERKLE_TREE_HOOK_MODULE,
+                "tree",
+                vec![],
+                Some(ledger_version),
+            )
+            .await?;
+        let branch = branch
+            .iter()
+            .map(|node| parse_hash(node))
+            .collect::<ChainResult<Vec<_>>>()?;
+        let branch: [H256; TREE_DEPTH] = branch.try_into().map_err(|branch: Vec<H256>| {
+            HyperlaneAptosError::Other(format!(
+                "Expected {TREE_DEPTH} branch nodes, got {}",
+                branch.len()
+            ))
+        })?;
+        Ok(IncrementalMerkleAtBlock {
+            tree: IncrementalMerkle::new(branch, count as usize),
+            block_height: Some(height),
+        })
+    }
+
+    /// Gets the current leaf count of the merkle tree
+    ///
+    /// - `reorg_period` is how far behind the current block to query, if not specified
+    ///   it will query at the latest block.
+    async fn count(&self, reorg_period: &ReorgPeriod) -> ChainResult<u32> {
+        let (_, ledger_version) = self.provider.ledger_state_at(reorg_period).await?;
+        self.count_at(ledger_version).await
+    }
+
+    /// Get the latest checkpoint.
+    ///
+    /// - `reorg_period` is how far behind the current block to query, if not specified
+    ///   it will query at the latest block.
+    async fn latest_checkpoint(
+        &self,
+        reorg_period: &ReorgPeriod,
+    ) -> ChainResult<CheckpointAtBlock> {
+        let (height, ledger_version) = self.provider.ledger_state_at(reorg_period).await?;
+        self.checkpoint_at(height, ledger_version).await
+    }
+
+    /// Get the latest checkpoint at a specific block height.
+    async fn latest_checkpoint_at_block(&self, height: u64) -> ChainResult<CheckpointAtBlock> {
+        let (height, ledger_version) = self.provider.ledger_state_at_height(height).await?;
+        self.checkpoint_at(height, ledger_version).await
+    }
+}
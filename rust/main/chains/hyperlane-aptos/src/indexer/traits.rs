@@ -0,0 +1,80 @@
+use std::ops::RangeInclusive;
+
+use serde::de::DeserializeOwned;
+
+use hyperlane_core::{ChainResult, Indexed, LogMeta, H256, H512};
+
+use crate::provider::AptosClient;
+use crate::utils::is_move_type;
+use crate::{AptosProvider, Event, HyperlaneAptosError};
+
+pub(crate) trait AptosIndexer {
+    /// Module emitting the event
+    const MODULE: &str;
+    /// Struct name of the event
+    const EVENT: &str;
+    type AptosType: DeserializeOwned + Into<Self::Type>;
+    type Type: Into<Indexed<Self::Type>>;
+
+    fn get_provider(&self) -> &AptosProvider<impl AptosClient>;
+
+    /// Address of the package emitting the event
+    fn get_package(&self) -> H256;
+
+    async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+        self.get_provider().get_finalized_block_number().await
+    }
+
+    /// Returns whether an event is the indexed event of the package
+    fn is_indexed_event(&self, event: &Event) -> bool {
+        is_move_type(
+            &event.event_type,
+            self.get_package(),
+            Self::MODULE,
+            Self::EVENT,
+        )
+    }
+
+    async fn fetch_logs_in_range(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> ChainResult<Vec<(Indexed<Self::Type>, LogMeta)>> {
+        let events = self
+            .get_provider()
+            .get_events_in_range(range, self.get_package(), |event| {
+                self.is_indexed_event(event)
+            })
+            .await?;
+        events
+            .into_iter()
+            .map(|(event, meta)| Ok((Self::parse_event(event)?, meta)))
+            .collect()
+    }
+
+    /// Fetch list of logs from a tx hash
+    async fn fetch_logs_by_tx_hash(
+        &self,
+        tx_hash: H512,
+    ) -> ChainResult<Vec<(Indexed<Self::Type>, LogMeta)>> {
+        if tx_hash.is_zero() {
+            return Ok(vec![]);
+        }
+        let events = self
+            .get_provider()
+            .get_events_by_hash(H256::from(tx_hash), self.get_package(), |event| {
+                self.is_indexed_event(event)
+            })
+            .await?;
+        events
+            .into_iter()
+            .map(|(event, meta)| Ok((Self::parse_event(event)?, meta)))
+            .collect()
+    }
+
+    fn parse_event(event: Event) -> ChainResult<Indexed<Self::Type>> {
+        let data: Self::AptosType =
+            serde_json::from_value(event.data).map_err(HyperlaneAptosError::from)?;
+        let value: Self::Type = data.into();
+        Ok(value.into())
+    }
+}
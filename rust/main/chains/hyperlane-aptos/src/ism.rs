@@ -0,0 +1,139 @@
+use async_trait::async_trait;
+use num_traits::cast::FromPrimitive;
+use serde_json::Value;
+
+use hyperlane_core::{
+    ChainResult, ContractLocator, Encode, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
+    HyperlaneMessage, HyperlaneProvider, InterchainSecurityModule, Metadata, ModuleType,
+    MultisigIsm, RoutingIsm, H256, U256,
+};
+
+use crate::{
+    utils::{encode_bytes, parse_address, parse_bytes},
+    AptosProvider, HyperlaneAptosError,
+};
+
+/// Name of the module every ISM package exposes
+const ISM_MODULE: &str = "ism";
+
+/// Aptos ISM
+#[derive(Debug, Clone)]
+pub struct AptosIsm {
+    provider: AptosProvider,
+    address: H256,
+}
+
+impl AptosIsm {
+    /// New ISM instance
+    pub fn new(provider: AptosProvider, locator: &ContractLocator) -> Self {
+        Self {
+            provider,
+            address: locator.address,
+        }
+    }
+
+    async fn view<T: serde::de::DeserializeOwned>(
+        &self,
+        function: &str,
+        arguments: Vec<Value>,
+    ) -> ChainResult<T> {
+        self.provider
+            .view(self.address, ISM_MODULE, function, arguments, None)
+            .await
+    }
+}
+
+impl HyperlaneContract for AptosIsm {
+    fn address(&self) -> H256 {
+        self.address
+    }
+}
+
+impl HyperlaneChain for AptosIsm {
+    fn domain(&self) -> &HyperlaneDomain {
+        self.provider.domain()
+    }
+
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+#[async_trait]
+impl InterchainSecurityModule for AptosIsm {
+    /// Returns the module type of the ISM compliant with the corresponding
+    /// metadata offchain fetching and onchain formatting standard.
+    async fn module_type(&self) -> ChainResult<ModuleType> {
+        let (module_type,): (u8,) = self.view("module_type", vec![]).await?;
+        Ok(ModuleType::from_u8(module_type)
+            .ok_or(HyperlaneAptosError::UnknownIsmType(module_type))?)
+    }
+
+    /// Dry runs the `verify()` ISM call and returns `Some(gas_estimate)` if the call
+    /// succeeds.
+    async fn dry_run_verify(
+        &self,
+        message: &HyperlaneMessage,
+        metadata: &Metadata,
+    ) -> ChainResult<Option<U256>> {
+        // View functions don't report gas usage, verification is all we can check
+        let (verified,): (bool,) = self
+            .view(
+                "verify",
+                vec![
+                    Value::String(encode_bytes(metadata)),
+                    Value::String(encode_bytes(&message.to_vec())),
+                ],
+            )
+            .await?;
+        Ok(verified.then(U256::one))
+    }
+}
+
+#[async_trait]
+impl MultisigIsm for AptosIsm {
+    /// Returns the validator and threshold needed to verify message
+    async fn validators_and_threshold(
+        &self,
+        message: &HyperlaneMessage,
+    ) -> ChainResult<(Vec<H256>, u8)> {
+        let (validators, threshold): (Vec<String>, u8) = self
+            .view(
+                "validators_and_threshold",
+                vec![Value::String(encode_bytes(&message.to_vec()))],
+            )
+            .await?;
+        // Validators are 20 byte Ethereum addresses
+        let validators = validators
+            .iter()
+            .map(|validator| {
+                let bytes = parse_bytes(validator)?;
+                if bytes.len() > 32 {
+                    return Err(HyperlaneAptosError::Other(format!(
+                        "Invalid validator: {validator}"
+                    ))
+                    .into());
+                }
+                let mut address = H256::zero();
+                let offset = 32usize.saturating_sub(bytes.len());
+                address.as_bytes_mut()[offset..].copy_from_slice(&bytes);
+                Ok(address)
+            })
+            .collect::<ChainResult<Vec<_>>>()?;
+        Ok((validators, threshold))
+    }
+}
+
+#[async_trait]
+impl RoutingIsm for AptosIsm {
+    /// Returns the ISM needed to verify message
+    async fn route(&self, message: &HyperlaneMessage) -> ChainResult<H256> {
+        let (route,): (String,) = self
+            .view(
+                "route",
+                vec![Value::String(encode_bytes(&message.to_vec()))],
+            )
+            .await?;
+        parse_address(&route)
+    }
+}
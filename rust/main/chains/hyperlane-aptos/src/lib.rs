@@ -0,0 +1,46 @@
+//! Implementation of hyperlane for Aptos and other Move-based chains exposing
+//! the Aptos REST API.
+//!
+//! Hyperlane packages are addressed by the account they are published under, and
+//! every contract address in the chain config is such a package address. The
+//! agents call view functions and entry functions on the following modules:
+//!
+//! - `mailbox`: `nonce`, `delivered`, `default_ism`, `recipient_ism`, `process`,
+//!   emitting `DispatchEvent` and `ProcessIdEvent`
+//! - `merkle_tree_hook`: `count`, `tree`, `latest_checkpoint`, emitting
+//!   `InsertedIntoTreeEvent`
+//! - `igp`: emitting `GasPaymentEvent`
+//! - `validator_announce`: `get_announced_storage_locations`, `announce`
+//! - `ism`: `module_type`, `verify`, `validators_and_threshold`, `route`
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+#![deny(clippy::unwrap_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+
+/// Hyperlane Application specific functionality
+pub mod application;
+mod bcs;
+mod config;
+mod error;
+mod indexer;
+mod ism;
+mod mailbox;
+mod provider;
+mod signer;
+mod types;
+mod utils;
+mod validator_announce;
+
+pub(crate) use types::*;
+
+pub use config::*;
+pub use error::*;
+pub use indexer::{
+    AptosDeliveryIndexer, AptosDispatchIndexer, AptosInterchainGasIndexer, AptosMerkleTreeHook,
+};
+pub use ism::AptosIsm;
+pub use mailbox::AptosMailbox;
+pub use provider::{AptosProvider, BaseHttpClient, FallbackHttpClient, HttpClient};
+pub use signer::AptosSigner;
+pub use validator_announce::AptosValidatorAnnounce;
@@ -0,0 +1,177 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use hyperlane_core::{
+    ChainCommunicationError, ChainResult, ContractLocator, Encode, FixedPointNumber,
+    HyperlaneChain, HyperlaneContract, HyperlaneDomain, HyperlaneMessage, HyperlaneProvider,
+    Mailbox, Metadata, ReorgPeriod, TxCostEstimate, TxOutcome, H256, U256,
+};
+
+use crate::{
+    utils::{encode_address, encode_bytes, parse_address},
+    AptosProvider, EntryFunctionArgument, EntryFunctionPayload, HyperlaneAptosError, ViewRequest,
+};
+
+/// Name of the mailbox module
+pub(crate) const MAILBOX_MODULE: &str = "mailbox";
+
+/// Aptos mailbox
+#[derive(Debug, Clone)]
+pub struct AptosMailbox {
+    provider: AptosProvider,
+    address: H256,
+}
+
+impl AptosMailbox {
+    /// New mailbox instance
+    pub fn new(provider: AptosProvider, locator: &ContractLocator) -> Self {
+        Self {
+            provider,
+            address: locator.address,
+        }
+    }
+
+    fn process_payload(&self, message: &HyperlaneMessage, metadata: &[u8]) -> EntryFunctionPayload {
+        EntryFunctionPayload {
+            function: format!(
+                "{}::{MAILBOX_MODULE}::process",
+                encode_address(self.address)
+            ),
+            type_arguments: vec![],
+            arguments: vec![
+                EntryFunctionArgument::Bytes(metadata.to_vec()),
+                EntryFunctionArgument::Bytes(message.to_vec()),
+            ],
+        }
+    }
+
+    async fn view_address(&self, function: &str, arguments: Vec<Value>) -> ChainResult<H256> {
+        let (address,): (String,) = self
+            .provider
+            .view(self.address, MAILBOX_MODULE, function, arguments, None)
+            .await?;
+        parse_address(&address)
+    }
+}
+
+impl HyperlaneContract for AptosMailbox {
+    fn address(&self) -> H256 {
+        self.address
+    }
+}
+
+impl HyperlaneChain for AptosMailbox {
+    fn domain(&self) -> &HyperlaneDomain {
+        self.provider.domain()
+    }
+
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+#[async_trait]
+impl Mailbox for AptosMailbox {
+    /// Gets the current number of dispatched messages
+    ///
+    /// - `reorg_period` is how far behind the current block to query, if not specified
+    ///   it will query at the latest block.
+    async fn count(&self, reorg_period: &ReorgPeriod) -> ChainResult<u32> {
+        let (_, ledger_version) = self.provider.ledger_state_at(reorg_period).await?;
+        let (nonce,): (u32,) = self
+            .provider
+            .view(
+                self.address,
+                MAILBOX_MODULE,
+                "nonce",
+                vec![],
+                Some(ledger_version),
+            )
+            .await?;
+        Ok(nonce)
+    }
+
+    /// Fetch the status of a message
+    async fn delivered(&self, id: H256) -> ChainResult<bool> {
+        let (delivered,): (bool,) = self
+            .provider
+            .view(
+                self.address,
+                MAILBOX_MODULE,
+                "delivered",
+                vec![Value::String(encode_bytes(id.as_bytes()))],
+                None,
+            )
+            .await?;
+        Ok(delivered)
+    }
+
+    /// Fetch the current default interchain security module value
+    async fn default_ism(&self) -> ChainResult<H256> {
+        self.view_address("default_ism", vec![]).await
+    }
+
+    /// Get the recipient ism address
+    async fn recipient_ism(&self, recipient: H256) -> ChainResult<H256> {
+        self.view_address(
+            "recipient_ism",
+            vec![Value::String(encode_address(recipient))],
+        )
+        .await
+    }
+
+    /// Process a message with a proof against the provided signed checkpoint
+    async fn process(
+        &self,
+        message: &HyperlaneMessage,
+        metadata: &Metadata,
+        tx_gas_limit: Option<U256>,
+    ) -> ChainResult<TxOutcome> {
+        let payload = self.process_payload(message, metadata);
+        self.provider.send_transaction(payload, tx_gas_limit).await
+    }
+
+    /// Estimate transaction costs to process a message.
+    async fn process_estimate_costs(
+        &self,
+        message: &HyperlaneMessage,
+        metadata: &Metadata,
+    ) -> ChainResult<TxCostEstimate> {
+        let payload = self.process_payload(message, metadata);
+        let simulation = self.provider.simulate(payload).await?;
+        if !simulation.success {
+            return Err(HyperlaneAptosError::SimulationFailed(simulation.vm_status).into());
+        }
+        Ok(TxCostEstimate {
+            gas_limit: simulation.gas_used.into(),
+            gas_price: FixedPointNumber::from(simulation.gas_unit_price),
+            l2_gas_limit: None,
+        })
+    }
+
+    /// Get the calldata for a transaction to process a message with a proof
+    /// against the provided signed checkpoint
+    async fn process_calldata(
+        &self,
+        message: &HyperlaneMessage,
+        metadata: &Metadata,
+    ) -> ChainResult<Vec<u8>> {
+        let payload = self.process_payload(message, metadata);
+        serde_json::to_vec(&payload).map_err(ChainCommunicationError::JsonParseError)
+    }
+
+    /// View request which allows to check if a message was delivered
+    fn delivered_calldata(&self, message_id: H256) -> ChainResult<Option<Vec<u8>>> {
+        let request = ViewRequest {
+            function: format!(
+                "{}::{MAILBOX_MODULE}::delivered",
+                encode_address(self.address)
+            ),
+            type_arguments: vec![],
+            arguments: vec![Value::String(encode_bytes(message_id.as_bytes()))],
+        };
+        let calldata =
+            serde_json::to_vec(&request).map_err(ChainCommunicationError::JsonParseError)?;
+        Ok(Some(calldata))
+    }
+}
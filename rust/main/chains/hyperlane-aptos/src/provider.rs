@@ -0,0 +1,10 @@
+pub use aptos::*;
+pub use base::*;
+pub use fallback::FallbackHttpClient;
+pub use traits::*;
+
+mod aptos;
+mod base;
+mod fallback;
+mod metric;
+mod traits;
@@ -0,0 +1,483 @@
+use std::{
+    ops::{Deref, RangeInclusive},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use futures::{stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tracing::{debug, instrument};
+
+use hyperlane_core::{
+    BlockInfo, ChainCommunicationError, ChainInfo, ChainResult, FixedPointNumber, HyperlaneChain,
+    HyperlaneDomain, HyperlaneProvider, LogMeta, ReorgPeriod, TxOutcome, TxnInfo, TxnReceiptInfo,
+    H256, H512, U256,
+};
+use hyperlane_metric::prometheus_metric::PrometheusClientMetrics;
+
+use crate::{
+    bcs::signing_message,
+    provider::{AptosClient, BaseHttpClient, FallbackHttpClient, RpcClient},
+    utils::{encode_address, encode_bytes},
+    AptosSigner, Block, ConnectionConf, EntryFunctionPayload, Event, HyperlaneAptosError,
+    SubmitTransactionRequest, Transaction, TransactionSignature, UserTransaction, ViewRequest,
+};
+
+/// Seconds after which a submitted transaction expires
+const TRANSACTION_EXPIRATION: Duration = Duration::from_secs(60);
+/// Interval between polls for a submitted transaction
+const TRANSACTION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Max transactions the node returns per page
+const TRANSACTIONS_PAGE_SIZE: u16 = 100;
+/// Max blocks fetched concurrently while indexing a range
+const BLOCK_FETCH_CONCURRENCY: usize = 10;
+
+/// Aptos Rest Client. Generic over an underlying HttpClient to allow injection of a mock for testing.
+#[derive(Clone)]
+pub struct AptosProvider<C: AptosClient = FallbackHttpClient> {
+    client: RpcClient<C>,
+    domain: HyperlaneDomain,
+    signer: Option<AptosSigner>,
+    gas_limit_multiplier: f64,
+}
+
+impl<C: AptosClient> std::fmt::Debug for AptosProvider<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AptosProvider")
+            .field("domain", &self.domain)
+            .field(
+                "signer",
+                &self.signer.as_ref().map(|signer| signer.address()),
+            )
+            .field("gas_limit_multiplier", &self.gas_limit_multiplier)
+            .finish()
+    }
+}
+
+impl AptosProvider<FallbackHttpClient> {
+    /// Creates a new production AptosProvider
+    pub fn new(
+        conf: &ConnectionConf,
+        domain: HyperlaneDomain,
+        signer: Option<AptosSigner>,
+        metrics: PrometheusClientMetrics,
+        chain: Option<hyperlane_metric::prometheus_metric::ChainInfo>,
+    ) -> ChainResult<Self> {
+        let client = FallbackHttpClient::new::<BaseHttpClient>(conf.urls.clone(), metrics, chain)?;
+        Ok(Self::with_client(
+            client,
+            domain,
+            signer,
+            conf.gas_limit_multiplier,
+        ))
+    }
+}
+
+impl<C: AptosClient> AptosProvider<C> {
+    /// Generic constructor allowing a pre-built client
+    pub fn with_client(
+        client: C,
+        domain: HyperlaneDomain,
+        signer: Option<AptosSigner>,
+        gas_limit_multiplier: f64,
+    ) -> Self {
+        Self {
+            client: RpcClient::new(client),
+            domain,
+            signer,
+            gas_limit_multiplier,
+        }
+    }
+
+    /// Get the Aptos Signer
+    pub fn get_signer(&self) -> ChainResult<&AptosSigner> {
+        let signer = self
+            .signer
+            .as_ref()
+            .ok_or(HyperlaneAptosError::SignerMissing)?;
+        Ok(signer)
+    }
+
+    /// Returns the block height and the ledger version to read state at for a
+    /// reorg period
+    pub async fn ledger_state_at(&self, reorg_period: &ReorgPeriod) -> ChainResult<(u64, u64)> {
+        match reorg_period {
+            ReorgPeriod::None => {
+                let ledger_info = self.get_ledger_info().await?;
+                Ok((ledger_info.block_height, ledger_info.ledger_version))
+            }
+            ReorgPeriod::Blocks(blocks) => {
+                let tip = self.get_ledger_info().await?.block_height;
+                let height = tip.saturating_sub(blocks.get().into());
+                self.ledger_state_at_height(height).await
+            }
+            ReorgPeriod::Tag(_) => Err(ChainCommunicationError::InvalidReorgPeriod(
+                reorg_period.clone(),
+            )),
+        }
+    }
+
+    /// Returns the block height and the ledger version at the end of a block
+    pub async fn ledger_state_at_height(&self, height: u64) -> ChainResult<(u64, u64)> {
+        let block = self.client.get_block_by_height(height, false).await?;
+        Ok((block.block_height, block.last_version))
+    }
+
+    /// Calls a view function and deserializes its return values, e.g. into a
+    /// tuple
+    pub async fn view<T: DeserializeOwned>(
+        &self,
+        package: H256,
+        module: &str,
+        function: &str,
+        arguments: Vec<Value>,
+        ledger_version: Option<u64>,
+    ) -> ChainResult<T> {
+        let request = ViewRequest {
+            function: format!("{}::{module}::{function}", encode_address(package)),
+            type_arguments: vec![],
+            arguments,
+        };
+        let values = self.view_raw(&request, ledger_version).await?;
+        let value = Value::Array(values);
+        serde_json::from_value(value.clone()).map_err(|_| {
+            HyperlaneAptosError::UnexpectedViewResult {
+                function: request.function,
+                value,
+            }
+            .into()
+        })
+    }
+
+    /// Builds an unsigned transaction calling an entry function
+    async fn build_transaction(
+        &self,
+        payload: EntryFunctionPayload,
+        max_gas_amount: u64,
+        gas_unit_price: u64,
+    ) -> ChainResult<SubmitTransactionRequest> {
+        let signer = self.get_signer()?;
+        let account = self.get_account(signer.address_h256()).await?;
+        let expiration = SystemTime::now()
+            .checked_add(TRANSACTION_EXPIRATION)
+            .and_then(|expiration| expiration.duration_since(UNIX_EPOCH).ok())
+            .ok_or_else(|| HyperlaneAptosError::Other("Invalid system time".to_owned()))?;
+        Ok(SubmitTransactionRequest {
+            sender: signer.address_h256(),
+            sequence_number: account.sequence_number,
+            max_gas_amount,
+            gas_unit_price,
+            expiration_timestamp_secs: expiration.as_secs(),
+            payload,
+            signature: None,
+        })
+    }
+
+    /// Simulates a transaction calling an entry function
+    #[instrument(skip(self))]
+    pub async fn simulate(&self, payload: EntryFunctionPayload) -> ChainResult<UserTransaction> {
+        let signer = self.get_signer()?;
+        // The node estimates the gas amount and price, the values set here are ignored
+        let mut request = self.build_transaction(payload, 0, 0).await?;
+        // Simulations must not carry a valid signature
+        request.signature = Some(TransactionSignature {
+            public_key: encode_bytes(&signer.public_key()),
+            signature: encode_bytes(&[0; 64]),
+        });
+        self.simulate_transaction(&request)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                HyperlaneAptosError::SimulationFailed("empty response".to_owned()).into()
+            })
+    }
+
+    /// Signs and submits a transaction calling an entry function and waits
+    /// for it to be committed. Without a gas limit, the max gas amount is
+    /// derived from a simulation.
+    #[instrument(skip(self))]
+    pub async fn send_transaction(
+        &self,
+        payload: EntryFunctionPayload,
+        gas_limit: Option<U256>,
+    ) -> ChainResult<TxOutcome> {
+        let signer = self.get_signer()?;
+        let max_gas_amount = match gas_limit {
+            Some(gas_limit) => gas_limit.min(U256::from(u64::MAX)).as_u64(),
+            None => {
+                let simulation = self.simulate(payload.clone()).await?;
+                if !simulation.success {
+                    return Err(HyperlaneAptosError::SimulationFailed(simulation.vm_status).into());
+                }
+                (simulation.gas_used as f64 * self.gas_limit_multiplier).ceil() as u64
+            }
+        };
+        let gas_unit_price = self.estimate_gas_price().await?.gas_estimate;
+
+        let mut request = self
+            .build_transaction(payload, max_gas_amount, gas_unit_price)
+            .await?;
+        // The signing message is built locally rather than requested from
+        // the node, which could otherwise get any transaction signed
+        let chain_id = self.get_ledger_info().await?.chain_id;
+        let signing_message = signing_message(&request, chain_id)?;
+        request.signature = Some(TransactionSignature {
+            public_key: encode_bytes(&signer.public_key()),
+            signature: encode_bytes(&signer.sign(&signing_message)),
+        });
+        let pending = self.submit_transaction(&request).await?;
+        debug!(hash = ?pending.hash, "Submitted transaction");
+
+        let transaction = self.wait_for_transaction(pending.hash).await?;
+        Ok(TxOutcome {
+            transaction_id: H512::from(transaction.hash),
+            executed: transaction.success,
+            gas_used: transaction.gas_used.into(),
+            gas_price: FixedPointNumber::from(transaction.gas_unit_price),
+        })
+    }
+
+    /// Polls a submitted transaction until it is committed
+    async fn wait_for_transaction(&self, hash: H256) -> ChainResult<UserTransaction> {
+        let start = Instant::now();
+        while start.elapsed() < TRANSACTION_EXPIRATION {
+            match self.get_transaction_by_hash(hash).await? {
+                Transaction::UserTransaction(transaction) => return Ok(transaction),
+                Transaction::PendingTransaction(_) | Transaction::Other => {
+                    tokio::time::sleep(TRANSACTION_POLL_INTERVAL).await;
+                }
+            }
+        }
+        Err(HyperlaneAptosError::TransactionTimeout(encode_address(hash)).into())
+    }
+
+    /// Gets a block by height including all of its transactions, paging
+    /// through them when the node truncated the block
+    async fn get_full_block(&self, height: u64) -> ChainResult<Block> {
+        let mut block = self.client.get_block_by_height(height, true).await?;
+        loop {
+            let fetched = block.transactions.len() as u64;
+            let next = block.first_version.saturating_add(fetched);
+            if next > block.last_version {
+                return Ok(block);
+            }
+            let page = self.get_transactions(next, TRANSACTIONS_PAGE_SIZE).await?;
+            if page.is_empty() {
+                return Err(HyperlaneAptosError::Other(format!(
+                    "Missing transactions of block {height} from version {next}"
+                ))
+                .into());
+            }
+            let remaining = block.last_version.saturating_sub(next).saturating_add(1);
+            block
+                .transactions
+                .extend(page.into_iter().take(remaining as usize));
+        }
+    }
+
+    /// Returns the events of successful transactions in a range of blocks
+    /// that match a filter, along with their log metadata
+    pub async fn get_events_in_range(
+        &self,
+        range: RangeInclusive<u32>,
+        address: H256,
+        filter: impl Fn(&Event) -> bool,
+    ) -> ChainResult<Vec<(Event, LogMeta)>> {
+        let blocks: Vec<Block> = stream::iter(range)
+            .map(|height| self.get_full_block(height.into()))
+            .buffered(BLOCK_FETCH_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let mut events = vec![];
+        for block in blocks {
+            for transaction in block.transactions {
+                let Transaction::UserTransaction(transaction) = transaction else {
+                    continue;
+                };
+                events.extend(filter_events(
+                    block.block_height,
+                    block.block_hash,
+                    transaction,
+                    address,
+                    &filter,
+                ));
+            }
+        }
+        Ok(events)
+    }
+
+    /// Returns the events of a transaction that match a filter, along with
+    /// their log metadata
+    pub async fn get_events_by_hash(
+        &self,
+        hash: H256,
+        address: H256,
+        filter: impl Fn(&Event) -> bool,
+    ) -> ChainResult<Vec<(Event, LogMeta)>> {
+        let Transaction::UserTransaction(transaction) = self.get_transaction_by_hash(hash).await?
+        else {
+            return Ok(vec![]);
+        };
+        let block = self.get_block_by_version(transaction.version).await?;
+        Ok(filter_events(
+            block.block_height,
+            block.block_hash,
+            transaction,
+            address,
+            &filter,
+        ))
+    }
+
+    /// Returns the latest block height along with the latest ledger version,
+    /// which is part of that block
+    pub async fn get_tip(&self) -> ChainResult<(u32, u64)> {
+        // Aptos has BFT finality, every committed block is final
+        let ledger_info = self.get_ledger_info().await?;
+        let height = ledger_info.block_height.try_into().map_err(|_| {
+            HyperlaneAptosError::Other(format!(
+                "Block height {} too large",
+                ledger_info.block_height
+            ))
+        })?;
+        Ok((height, ledger_info.ledger_version))
+    }
+
+    /// Returns the latest block height
+    pub async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+        Ok(self.get_tip().await?.0)
+    }
+}
+
+/// Returns the events of a successful transaction that match a filter, along
+/// with their log metadata
+fn filter_events(
+    block_number: u64,
+    block_hash: H256,
+    transaction: UserTransaction,
+    address: H256,
+    filter: impl Fn(&Event) -> bool,
+) -> Vec<(Event, LogMeta)> {
+    if !transaction.success {
+        return vec![];
+    }
+    transaction
+        .events
+        .into_iter()
+        .enumerate()
+        .filter(|(_, event)| filter(event))
+        .map(|(index, event)| {
+            let meta = LogMeta {
+                address,
+                block_number,
+                block_hash,
+                transaction_id: H512::from(transaction.hash),
+                transaction_index: transaction.version,
+                log_index: U256::from(index),
+            };
+            (event, meta)
+        })
+        .collect()
+}
+
+impl<C: AptosClient> Deref for AptosProvider<C> {
+    type Target = RpcClient<C>;
+    fn deref(&self) -> &Self::Target {
+        &self.client
+    }
+}
+
+impl<C: AptosClient> HyperlaneChain for AptosProvider<C> {
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.clone())
+    }
+}
+
+#[async_trait]
+impl<C: AptosClient> HyperlaneProvider for AptosProvider<C> {
+    /// Get block info for a given block height
+    async fn get_block_by_height(&self, height: u64) -> ChainResult<BlockInfo> {
+        let block = self.client.get_block_by_height(height, false).await?;
+        Ok(BlockInfo {
+            hash: block.block_hash,
+            // Block timestamps are in microseconds
+            timestamp: block.block_timestamp / 1_000_000,
+            number: block.block_height,
+        })
+    }
+
+    /// Get txn info for a given txn hash
+    async fn get_txn_by_hash(&self, hash: &H512) -> ChainResult<TxnInfo> {
+        let hash = H256::from(*hash);
+        let transaction = match self.get_transaction_by_hash(hash).await? {
+            Transaction::UserTransaction(transaction) => transaction,
+            Transaction::PendingTransaction(_) | Transaction::Other => {
+                return Err(HyperlaneAptosError::Other(format!(
+                    "Transaction {} is not a committed user transaction",
+                    encode_address(hash)
+                ))
+                .into())
+            }
+        };
+        let recipient = transaction.called_package();
+        let gas_price = U256::from(transaction.gas_unit_price);
+
+        Ok(TxnInfo {
+            hash: H512::from(transaction.hash),
+            gas_limit: transaction.max_gas_amount.into(),
+            max_priority_fee_per_gas: None,
+            max_fee_per_gas: None,
+            gas_price: Some(gas_price),
+            nonce: transaction.sequence_number,
+            sender: transaction.sender,
+            recipient,
+            receipt: Some(TxnReceiptInfo {
+                gas_used: transaction.gas_used.into(),
+                cumulative_gas_used: transaction.gas_used.into(),
+                effective_gas_price: Some(gas_price),
+            }),
+            raw_input_data: None,
+        })
+    }
+
+    /// Returns whether a package is published at the provided address
+    async fn is_contract(&self, address: &H256) -> ChainResult<bool> {
+        self.has_modules(*address).await
+    }
+
+    /// Fetch the APT balance of an account
+    async fn get_balance(&self, address: String) -> ChainResult<U256> {
+        let request = ViewRequest {
+            function: "0x1::coin::balance".to_owned(),
+            type_arguments: vec!["0x1::aptos_coin::AptosCoin".to_owned()],
+            arguments: vec![Value::String(address)],
+        };
+        let values = self.view_raw(&request, None).await?;
+        let balance = values
+            .first()
+            .and_then(Value::as_str)
+            .and_then(|balance| balance.parse::<u64>().ok())
+            .ok_or_else(|| HyperlaneAptosError::UnexpectedViewResult {
+                function: request.function,
+                value: Value::Array(values.clone()),
+            })?;
+        Ok(U256::from(balance))
+    }
+
+    /// Fetch metrics related to this chain
+    async fn get_chain_metrics(&self) -> ChainResult<Option<ChainInfo>> {
+        let height = self.get_ledger_info().await?.block_height;
+        let latest_block = HyperlaneProvider::get_block_by_height(self, height).await?;
+        let min_gas_price = self.estimate_gas_price().await?.gas_estimate;
+        Ok(Some(ChainInfo {
+            latest_block,
+            min_gas_price: Some(U256::from(min_gas_price)),
+        }))
+    }
+}
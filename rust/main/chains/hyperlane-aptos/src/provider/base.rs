@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client as ReqwestClient, Response};
+use reqwest_utils::parse_custom_rpc_headers;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use hyperlane_core::{ChainCommunicationError, ChainResult};
+
+use crate::provider::{HttpClient, HttpClientBuilder};
+use crate::HyperlaneAptosError;
+
+// Default timeouts
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Error body returned by the REST API
+#[derive(serde::Deserialize)]
+struct ApiErrorBody {
+    message: String,
+}
+
+/// Base Http client that performs REST-ful queries
+#[derive(Clone, Debug)]
+pub struct BaseHttpClient {
+    client: ReqwestClient,
+    base_url: String,
+}
+
+impl BaseHttpClient {
+    /// Creates a new Http client
+    pub fn new(base_url: Url) -> ChainResult<Self> {
+        let (headers, url) =
+            parse_custom_rpc_headers(&base_url).map_err(ChainCommunicationError::from_other)?;
+        let client = ReqwestClient::builder()
+            .connect_timeout(DEFAULT_CONNECT_TIMEOUT)
+            .timeout(DEFAULT_REQUEST_TIMEOUT)
+            .default_headers(headers)
+            .build()
+            .map_err(HyperlaneAptosError::from)?;
+        Ok(Self {
+            client,
+            base_url: url.to_string().trim_end_matches('/').to_string(),
+        })
+    }
+
+    fn url(&self, path: &str) -> String {
+        if path.is_empty() {
+            self.base_url.clone()
+        } else {
+            format!("{}/{}", self.base_url, path)
+        }
+    }
+
+    /// Parses the response, turning error statuses into the message of the node
+    async fn parse_response<T: DeserializeOwned>(response: Response) -> ChainResult<T> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response.json().await.map_err(HyperlaneAptosError::from)?);
+        }
+        let body = response.text().await.map_err(HyperlaneAptosError::from)?;
+        let message = serde_json::from_str::<ApiErrorBody>(&body)
+            .map(|error| error.message)
+            .unwrap_or(body);
+        Err(HyperlaneAptosError::ApiError {
+            status: status.as_u16(),
+            message,
+        }
+        .into())
+    }
+}
+
+#[async_trait]
+impl HttpClient for BaseHttpClient {
+    /// Makes a GET request to the API
+    async fn request<T: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        query: impl Into<Option<serde_json::Value>> + Send,
+    ) -> ChainResult<T> {
+        let query: serde_json::Value = query.into().unwrap_or_default();
+        let response = self
+            .client
+            .get(self.url(path))
+            .query(&query)
+            .send()
+            .await
+            .map_err(HyperlaneAptosError::from)?;
+        Self::parse_response(response).await
+    }
+
+    /// Makes a POST request to the API
+    async fn request_post<T: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> ChainResult<T> {
+        let response = self
+            .client
+            .post(self.url(path))
+            .json(body)
+            .send()
+            .await
+            .map_err(HyperlaneAptosError::from)?;
+        Self::parse_response(response).await
+    }
+}
+
+impl HttpClientBuilder for BaseHttpClient {
+    type Client = BaseHttpClient;
+
+    fn build(url: Url) -> ChainResult<Self::Client> {
+        BaseHttpClient::new(url)
+    }
+}
@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use itertools::Itertools;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use hyperlane_core::{
+    rpc_clients::{BlockNumberGetter, FallbackProvider},
+    ChainResult,
+};
+use hyperlane_metric::{
+    prometheus_metric::{
+        ChainInfo, ClientConnectionType, PrometheusClientMetrics, PrometheusConfig,
+    },
+    utils::urls_to_provider_nodes,
+};
+
+use crate::provider::{
+    metric::MetricHttpClient, AptosClient, BaseHttpClient, HttpClient, HttpClientBuilder, RpcClient,
+};
+
+/// Fallback Http Client that tries multiple RpcClients in order
+#[derive(Clone, Debug)]
+pub struct FallbackHttpClient<C: AptosClient = BaseHttpClient> {
+    fallback: FallbackProvider<RpcClient<MetricHttpClient<C>>, RpcClient<MetricHttpClient<C>>>,
+}
+
+impl<C: AptosClient> FallbackHttpClient<C> {
+    /// Creates a new FallbackHttpClient from a list of base urls
+    pub fn new<Builder: HttpClientBuilder<Client = C>>(
+        urls: Vec<Url>,
+        metrics: PrometheusClientMetrics,
+        chain: Option<ChainInfo>,
+    ) -> ChainResult<Self> {
+        let provider_nodes = urls_to_provider_nodes(&urls);
+        let clients = urls
+            .into_iter()
+            .map(|url| {
+                let metrics_config =
+                    PrometheusConfig::from_url(&url, ClientConnectionType::Rpc, chain.clone());
+                MetricHttpClient::new::<Builder>(url, metrics.clone(), metrics_config)
+            })
+            .collect::<ChainResult<Vec<_>>>()?
+            .into_iter()
+            .map(RpcClient::new)
+            .collect_vec();
+        let fallback = FallbackProvider::builder()
+            .add_providers(clients)
+            .with_score_metric(
+                metrics.provider_score.clone(),
+                PrometheusConfig::chain_name(&chain),
+                provider_nodes,
+            )
+            .build();
+        Ok(Self { fallback })
+    }
+}
+
+#[async_trait]
+impl<C: HttpClient + std::fmt::Debug + Send + Sync> BlockNumberGetter for RpcClient<C> {
+    async fn get_block_number(&self) -> ChainResult<u64> {
+        let ledger_info = self.get_ledger_info().await?;
+        Ok(ledger_info.block_height)
+    }
+}
+
+#[async_trait]
+impl<C: AptosClient> HttpClient for FallbackHttpClient<C> {
+    /// Makes a GET request to the API
+    async fn request<T: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        query: impl Into<Option<serde_json::Value>> + Send,
+    ) -> ChainResult<T> {
+        let query = query.into();
+        self.fallback
+            .call(|inner| {
+                let path = path.to_string();
+                let query = query.clone();
+                let future = async move { inner.request(&path, query).await };
+                Box::pin(future)
+            })
+            .await
+    }
+
+    /// Makes a POST request to the API
+    async fn request_post<T: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> ChainResult<T> {
+        self.fallback
+            .call(|inner| {
+                let path = path.to_string();
+                let body = body.clone();
+                let future = async move { inner.request_post(&path, &body).await };
+                Box::pin(future)
+            })
+            .await
+    }
+}
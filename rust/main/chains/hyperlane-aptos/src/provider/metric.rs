@@ -0,0 +1,110 @@
+use std::{ops::Deref, time::Instant};
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use hyperlane_core::ChainResult;
+use hyperlane_metric::prometheus_metric::{PrometheusClientMetrics, PrometheusConfig};
+
+use crate::provider::{AptosClient, BaseHttpClient, HttpClient, HttpClientBuilder, RpcClient};
+
+/// Http Client that records metrics of every request
+#[derive(Debug)]
+pub struct MetricHttpClient<C: AptosClient = BaseHttpClient> {
+    inner: RpcClient<C>,
+    metrics: PrometheusClientMetrics,
+    metrics_config: PrometheusConfig,
+}
+
+impl<C: AptosClient> Deref for MetricHttpClient<C> {
+    type Target = RpcClient<C>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<C: AptosClient> Drop for MetricHttpClient<C> {
+    fn drop(&mut self) {
+        // decrement provider metric count
+        let chain_name = PrometheusConfig::chain_name(&self.metrics_config.chain);
+        self.metrics.decrement_provider_instance(chain_name);
+    }
+}
+
+impl<C: AptosClient> Clone for MetricHttpClient<C> {
+    fn clone(&self) -> Self {
+        // increment provider metric count
+        let chain_name = PrometheusConfig::chain_name(&self.metrics_config.chain);
+        self.metrics.increment_provider_instance(chain_name);
+
+        Self {
+            inner: self.inner.clone(),
+            metrics: self.metrics.clone(),
+            metrics_config: self.metrics_config.clone(),
+        }
+    }
+}
+
+impl<C: AptosClient> MetricHttpClient<C> {
+    /// Creates a new MetricHttpClient
+    pub fn new<Builder: HttpClientBuilder<Client = C>>(
+        url: Url,
+        metrics: PrometheusClientMetrics,
+        metrics_config: PrometheusConfig,
+    ) -> ChainResult<Self> {
+        // increment provider metric count
+        let chain_name = PrometheusConfig::chain_name(&metrics_config.chain);
+        metrics.increment_provider_instance(chain_name);
+
+        let base_client = Builder::build(url)?;
+        Ok(Self {
+            inner: RpcClient::new(base_client),
+            metrics,
+            metrics_config,
+        })
+    }
+
+    /// Helper function to track metrics for RPC calls
+    async fn track_request<T, F, Fut>(&self, path: &str, operation: F) -> ChainResult<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = ChainResult<T>>,
+    {
+        let start = Instant::now();
+        let res = operation().await;
+        // Label by the first path segment so ids and query strings don't create new series
+        let method = path
+            .split(['/', '?'])
+            .next()
+            .filter(|method| !method.is_empty())
+            .unwrap_or("ledger_info");
+        self.metrics
+            .increment_metrics(&self.metrics_config, method, start, res.is_ok());
+        res
+    }
+}
+
+#[async_trait]
+impl<C: AptosClient> HttpClient for MetricHttpClient<C> {
+    /// Makes a GET request to the API
+    async fn request<T: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        query: impl Into<Option<serde_json::Value>> + Send,
+    ) -> ChainResult<T> {
+        self.track_request(path, || self.inner.request(path, query))
+            .await
+    }
+
+    /// Makes a POST request to the API
+    async fn request_post<T: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> ChainResult<T> {
+        self.track_request(path, || self.inner.request_post(path, body))
+            .await
+    }
+}
@@ -0,0 +1,160 @@
+use std::ops::Deref;
+
+use async_trait::async_trait;
+use derive_new::new;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use hyperlane_core::{ChainResult, H256};
+
+use crate::utils::encode_address;
+use crate::{
+    AccountData, Block, GasEstimation, HyperlaneAptosError, LedgerInfo, PendingTransaction,
+    SubmitTransactionRequest, Transaction, UserTransaction, ViewRequest,
+};
+
+/// Aptos Http Client trait alias
+pub trait AptosClient: HttpClient + Clone + std::fmt::Debug + Send + Sync + 'static {}
+impl<T> AptosClient for T where T: HttpClient + Clone + std::fmt::Debug + Send + Sync + 'static {}
+
+/// Builds a raw HttpClient for a single REST API url
+pub trait HttpClientBuilder {
+    /// Client that is built
+    type Client: HttpClient;
+
+    /// Builds the client
+    fn build(url: Url) -> ChainResult<Self::Client>;
+}
+
+#[async_trait]
+/// HttpClient trait defines the base layer that Aptos provider will use
+pub trait HttpClient {
+    /// Makes a GET request to the API
+    async fn request<T: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        query: impl Into<Option<serde_json::Value>> + Send,
+    ) -> ChainResult<T>;
+
+    /// Makes a POST request to the API
+    async fn request_post<T: DeserializeOwned + Send>(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> ChainResult<T>;
+}
+
+/// Implements high level Aptos REST requests based on a raw HttpClient
+#[derive(Debug, Clone, new)]
+pub struct RpcClient<Client: HttpClient>(Client);
+
+impl<T: HttpClient> Deref for RpcClient<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Client: HttpClient> RpcClient<Client> {
+    /// Gets the latest ledger information
+    pub async fn get_ledger_info(&self) -> ChainResult<LedgerInfo> {
+        self.request("", None).await
+    }
+
+    /// Gets a block by height, optionally including its transactions
+    pub async fn get_block_by_height(
+        &self,
+        height: u64,
+        with_transactions: bool,
+    ) -> ChainResult<Block> {
+        self.request(
+            &format!("blocks/by_height/{height}"),
+            Some(serde_json::json!({ "with_transactions": with_transactions })),
+        )
+        .await
+    }
+
+    /// Gets the block containing a ledger version
+    pub async fn get_block_by_version(&self, version: u64) -> ChainResult<Block> {
+        self.request(&format!("blocks/by_version/{version}"), None)
+            .await
+    }
+
+    /// Gets committed transactions, starting at a ledger version
+    pub async fn get_transactions(&self, start: u64, limit: u16) -> ChainResult<Vec<Transaction>> {
+        self.request(
+            "transactions",
+            Some(serde_json::json!({ "start": start.to_string(), "limit": limit })),
+        )
+        .await
+    }
+
+    /// Gets a pending or committed transaction by hash
+    pub async fn get_transaction_by_hash(&self, hash: H256) -> ChainResult<Transaction> {
+        self.request(
+            &format!("transactions/by_hash/{}", encode_address(hash)),
+            None,
+        )
+        .await
+    }
+
+    /// Gets the account resource of an address
+    pub async fn get_account(&self, address: H256) -> ChainResult<AccountData> {
+        self.request(&format!("accounts/{}", encode_address(address)), None)
+            .await
+    }
+
+    /// Returns whether any module is published under an address
+    pub async fn has_modules(&self, address: H256) -> ChainResult<bool> {
+        let modules: Vec<serde_json::Value> = self
+            .request(
+                &format!("accounts/{}/modules", encode_address(address)),
+                Some(serde_json::json!({ "limit": 1 })),
+            )
+            .await?;
+        Ok(!modules.is_empty())
+    }
+
+    /// Gets the gas unit price estimation of the node
+    pub async fn estimate_gas_price(&self) -> ChainResult<GasEstimation> {
+        self.request("estimate_gas_price", None).await
+    }
+
+    /// Calls a view function, optionally at a past ledger version
+    pub async fn view_raw(
+        &self,
+        request: &ViewRequest,
+        ledger_version: Option<u64>,
+    ) -> ChainResult<Vec<serde_json::Value>> {
+        let path = match ledger_version {
+            Some(version) => format!("view?ledger_version={version}"),
+            None => "view".to_owned(),
+        };
+        let body = serde_json::to_value(request).map_err(HyperlaneAptosError::from)?;
+        self.request_post(&path, &body).await
+    }
+
+    /// Simulates a transaction, letting the node estimate the max gas amount
+    /// and gas unit price
+    pub async fn simulate_transaction(
+        &self,
+        request: &SubmitTransactionRequest,
+    ) -> ChainResult<Vec<UserTransaction>> {
+        let body = serde_json::to_value(request).map_err(HyperlaneAptosError::from)?;
+        self.request_post(
+            "transactions/simulate?estimate_max_gas_amount=true&estimate_gas_unit_price=true",
+            &body,
+        )
+        .await
+    }
+
+    /// Submits a signed transaction to the mempool
+    pub async fn submit_transaction(
+        &self,
+        request: &SubmitTransactionRequest,
+    ) -> ChainResult<PendingTransaction> {
+        let body = serde_json::to_value(request).map_err(HyperlaneAptosError::from)?;
+        self.request_post("transactions", &body).await
+    }
+}
@@ -0,0 +1,95 @@
+use ed25519_dalek::{Signer, SigningKey};
+use sha3::{Digest, Sha3_256};
+
+use hyperlane_core::{ChainResult, H256};
+
+use crate::{utils::encode_address, HyperlaneAptosError};
+
+/// Scheme identifier appended to the public key when deriving the
+/// authentication key of a single Ed25519 key account
+const ED25519_SCHEME: u8 = 0;
+
+/// Signer for Aptos chains, using a single Ed25519 key account
+#[derive(Clone, Debug)]
+pub struct AptosSigner {
+    signing_key: SigningKey,
+    address: H256,
+}
+
+impl AptosSigner {
+    /// Creates a new signer from an Ed25519 private key
+    pub fn new(private_key: &[u8]) -> ChainResult<Self> {
+        let private_key: [u8; 32] = private_key
+            .try_into()
+            .map_err(|_| HyperlaneAptosError::InvalidPrivateKey("expected 32 bytes".to_owned()))?;
+        let signing_key = SigningKey::from_bytes(&private_key);
+
+        // The account address of a fresh account is its authentication key
+        let mut hasher = Sha3_256::new();
+        hasher.update(signing_key.verifying_key().as_bytes());
+        hasher.update([ED25519_SCHEME]);
+        let address = H256::from_slice(&hasher.finalize());
+
+        Ok(Self {
+            signing_key,
+            address,
+        })
+    }
+
+    /// Returns the account address of the signer
+    pub fn address_h256(&self) -> H256 {
+        self.address
+    }
+
+    /// Returns the account address of the signer as a hex string
+    pub fn address(&self) -> String {
+        encode_address(self.address)
+    }
+
+    /// Returns the public key of the signer
+    pub fn public_key(&self) -> [u8; 32] {
+        self.signing_key.verifying_key().to_bytes()
+    }
+
+    /// Signs a message, e.g. the signing message of a transaction
+    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+        self.signing_key.sign(message).to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+    use super::*;
+
+    #[test]
+    fn test_address_derivation() {
+        let signer = AptosSigner::new(&[1; 32]).unwrap();
+
+        // sha3-256(public key | single Ed25519 scheme)
+        let mut hasher = Sha3_256::new();
+        hasher.update(signer.public_key());
+        hasher.update([0]);
+        assert_eq!(
+            signer.address_h256().as_bytes(),
+            hasher.finalize().as_slice()
+        );
+        assert_eq!(signer.address().len(), 66);
+        assert!(signer.address().starts_with("0x"));
+    }
+
+    #[test]
+    fn test_sign() {
+        let signer = AptosSigner::new(&[7; 32]).unwrap();
+        let signature = Signature::from_bytes(&signer.sign(b"message"));
+
+        let verifying_key = VerifyingKey::from_bytes(&signer.public_key()).unwrap();
+        assert!(verifying_key.verify(b"message", &signature).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_key_length() {
+        assert!(AptosSigner::new(&[1; 31]).is_err());
+    }
+}
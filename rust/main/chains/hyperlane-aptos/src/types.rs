@@ -0,0 +1,284 @@
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
+
+use hyperlane_core::{HyperlaneMessage, InterchainGasPayment, MerkleTreeInsertion, H256, U256};
+
+use crate::utils::{
+    deserialize_bytes, deserialize_h256, deserialize_u64, encode_bytes, serialize_address,
+    serialize_u64,
+};
+
+/// Ledger information returned by the index endpoint
+#[derive(Debug, Clone, Deserialize)]
+pub struct LedgerInfo {
+    /// Chain id used in transactions
+    pub chain_id: u8,
+    /// Latest ledger version
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub ledger_version: u64,
+    /// Latest block height
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub block_height: u64,
+}
+
+/// A block
+#[derive(Debug, Clone, Deserialize)]
+pub struct Block {
+    /// Height of the block
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub block_height: u64,
+    /// Hash of the block
+    #[serde(deserialize_with = "deserialize_h256")]
+    pub block_hash: H256,
+    /// Timestamp of the block in microseconds
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub block_timestamp: u64,
+    /// Version of the first transaction in the block
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub first_version: u64,
+    /// Version of the last transaction in the block
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub last_version: u64,
+    /// Transactions of the block, which may be truncated by the node
+    #[serde(default)]
+    pub transactions: Vec<Transaction>,
+}
+
+/// A transaction, either pending or committed
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Transaction {
+    /// Transaction in the mempool
+    PendingTransaction(PendingTransaction),
+    /// Committed transaction sent by an account
+    UserTransaction(UserTransaction),
+    /// Any transaction type emitted by the chain itself
+    #[serde(other)]
+    Other,
+}
+
+/// Transaction in the mempool
+#[derive(Debug, Clone, Deserialize)]
+pub struct PendingTransaction {
+    /// Hash of the transaction
+    #[serde(deserialize_with = "deserialize_h256")]
+    pub hash: H256,
+}
+
+/// Committed transaction sent by an account
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserTransaction {
+    /// Hash of the transaction
+    #[serde(deserialize_with = "deserialize_h256")]
+    pub hash: H256,
+    /// Ledger version of the transaction
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub version: u64,
+    /// Whether the transaction was executed successfully
+    pub success: bool,
+    /// Execution status
+    pub vm_status: String,
+    /// Sender of the transaction
+    #[serde(deserialize_with = "deserialize_h256")]
+    pub sender: H256,
+    /// Sequence number of the sender
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub sequence_number: u64,
+    /// Max gas units the transaction could use
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub max_gas_amount: u64,
+    /// Gas units used by the transaction
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub gas_used: u64,
+    /// Price of a gas unit in octas
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub gas_unit_price: u64,
+    /// Payload of the transaction
+    pub payload: Value,
+    /// Events emitted by the transaction
+    #[serde(default)]
+    pub events: Vec<Event>,
+}
+
+impl UserTransaction {
+    /// Returns the package called by an entry function payload
+    pub fn called_package(&self) -> Option<H256> {
+        let function = self.payload.get("function")?.as_str()?;
+        let address = function.split("::").next()?;
+        crate::utils::parse_address(address).ok()
+    }
+}
+
+/// An event emitted by a transaction
+#[derive(Debug, Clone, Deserialize)]
+pub struct Event {
+    /// Fully qualified Move type of the event
+    #[serde(rename = "type")]
+    pub event_type: String,
+    /// Event fields
+    pub data: Value,
+}
+
+/// Account resource of an address
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountData {
+    /// Next sequence number of the account
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub sequence_number: u64,
+}
+
+/// Gas price estimation
+#[derive(Debug, Clone, Deserialize)]
+pub struct GasEstimation {
+    /// Gas unit price that is likely to get a transaction included
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub gas_estimate: u64,
+}
+
+/// Request to call a view function
+#[derive(Debug, Clone, Serialize)]
+pub struct ViewRequest {
+    /// Fully qualified function, e.g. `0x1::coin::balance`
+    pub function: String,
+    /// Type arguments of the function
+    pub type_arguments: Vec<String>,
+    /// Arguments of the function, encoded as JSON
+    pub arguments: Vec<Value>,
+}
+
+/// Payload calling an entry function
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename = "entry_function_payload")]
+pub struct EntryFunctionPayload {
+    /// Fully qualified function, e.g. `0x1::aptos_account::transfer`
+    pub function: String,
+    /// Type arguments of the function
+    pub type_arguments: Vec<String>,
+    /// Arguments of the function
+    pub arguments: Vec<EntryFunctionArgument>,
+}
+
+/// Argument of an entry function. Arguments are typed so that the signing
+/// message of a transaction can be BCS encoded locally.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryFunctionArgument {
+    /// A `vector<u8>`
+    Bytes(Vec<u8>),
+    /// A `0x1::string::String`
+    String(String),
+}
+
+impl Serialize for EntryFunctionArgument {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Bytes(bytes) => serializer.serialize_str(&encode_bytes(bytes)),
+            Self::String(string) => serializer.serialize_str(string),
+        }
+    }
+}
+
+/// Request to submit or simulate a transaction
+#[derive(Debug, Clone, Serialize)]
+pub struct SubmitTransactionRequest {
+    /// Sender of the transaction
+    #[serde(serialize_with = "serialize_address")]
+    pub sender: H256,
+    /// Sequence number of the sender
+    #[serde(serialize_with = "serialize_u64")]
+    pub sequence_number: u64,
+    /// Max gas units the transaction can use
+    #[serde(serialize_with = "serialize_u64")]
+    pub max_gas_amount: u64,
+    /// Price of a gas unit in octas
+    #[serde(serialize_with = "serialize_u64")]
+    pub gas_unit_price: u64,
+    /// Unix timestamp after which the transaction is discarded
+    #[serde(serialize_with = "serialize_u64")]
+    pub expiration_timestamp_secs: u64,
+    /// Payload of the transaction
+    pub payload: EntryFunctionPayload,
+    /// Signature, omitted until the transaction is signed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<TransactionSignature>,
+}
+
+/// Signature of a single Ed25519 key account
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename = "ed25519_signature")]
+pub struct TransactionSignature {
+    /// Hex encoded public key
+    pub public_key: String,
+    /// Hex encoded signature
+    pub signature: String,
+}
+
+/// `DispatchEvent` of the mailbox
+#[derive(Debug, Clone, Deserialize)]
+pub struct DispatchEvent {
+    /// Encoded Hyperlane message
+    #[serde(deserialize_with = "deserialize_bytes")]
+    pub message: Vec<u8>,
+}
+
+impl From<DispatchEvent> for HyperlaneMessage {
+    fn from(event: DispatchEvent) -> Self {
+        HyperlaneMessage::from(event.message)
+    }
+}
+
+/// `ProcessIdEvent` of the mailbox
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProcessIdEvent {
+    /// Id of the delivered message
+    #[serde(deserialize_with = "deserialize_h256")]
+    pub message_id: H256,
+}
+
+impl From<ProcessIdEvent> for H256 {
+    fn from(event: ProcessIdEvent) -> Self {
+        event.message_id
+    }
+}
+
+/// `InsertedIntoTreeEvent` of the merkle tree hook
+#[derive(Debug, Clone, Deserialize)]
+pub struct InsertedIntoTreeEvent {
+    /// Id of the inserted message
+    #[serde(deserialize_with = "deserialize_h256")]
+    pub message_id: H256,
+    /// Index of the leaf
+    pub index: u32,
+}
+
+impl From<InsertedIntoTreeEvent> for MerkleTreeInsertion {
+    fn from(event: InsertedIntoTreeEvent) -> Self {
+        MerkleTreeInsertion::new(event.index, event.message_id)
+    }
+}
+
+/// `GasPaymentEvent` of the IGP
+#[derive(Debug, Clone, Deserialize)]
+pub struct GasPaymentEvent {
+    /// Id of the message paid for
+    #[serde(deserialize_with = "deserialize_h256")]
+    pub message_id: H256,
+    /// Destination domain of the message
+    pub destination_domain: u32,
+    /// Gas amount paid for
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub gas_amount: u64,
+    /// Payment in octas
+    #[serde(deserialize_with = "deserialize_u64")]
+    pub payment: u64,
+}
+
+impl From<GasPaymentEvent> for InterchainGasPayment {
+    fn from(event: GasPaymentEvent) -> Self {
+        InterchainGasPayment {
+            message_id: event.message_id,
+            destination: event.destination_domain,
+            payment: U256::from(event.payment),
+            gas_amount: U256::from(event.gas_amount),
+        }
+    }
+}
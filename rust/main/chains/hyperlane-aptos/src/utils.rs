@@ -0,0 +1,152 @@
+use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+use hyperlane_core::{ChainResult, H256};
+
+use crate::HyperlaneAptosError;
+
+/// Encodes an account address in its long hex form
+pub(crate) fn encode_address(address: H256) -> String {
+    format!("0x{}", hex::encode(address.as_bytes()))
+}
+
+/// Parses an account address, accepting the short form of special addresses
+/// like `0x1`
+pub(crate) fn parse_address(address: &str) -> ChainResult<H256> {
+    let address = address.strip_prefix("0x").unwrap_or(address);
+    if address.is_empty() || address.len() > 64 {
+        return Err(HyperlaneAptosError::Other(format!("Invalid address: 0x{address}")).into());
+    }
+    let padded = format!("{address:0>64}");
+    let bytes = hex::decode(padded).map_err(HyperlaneAptosError::from)?;
+    Ok(H256::from_slice(&bytes))
+}
+
+/// Encodes a `vector<u8>` the way the REST API expects it
+pub(crate) fn encode_bytes(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+/// Parses a `vector<u8>` returned by the REST API
+pub(crate) fn parse_bytes(bytes: &str) -> ChainResult<Vec<u8>> {
+    let bytes = bytes.strip_prefix("0x").unwrap_or(bytes);
+    Ok(hex::decode(bytes).map_err(HyperlaneAptosError::from)?)
+}
+
+/// Parses a transaction hash
+pub(crate) fn parse_hash(hash: &str) -> ChainResult<H256> {
+    let bytes = parse_bytes(hash)?;
+    if bytes.len() != 32 {
+        return Err(HyperlaneAptosError::Other(format!("Invalid hash: {hash}")).into());
+    }
+    Ok(H256::from_slice(&bytes))
+}
+
+/// Returns whether a fully qualified Move type, e.g. `0x1::coin::CoinStore<..>`,
+/// is the struct `name` of `module` published at `address`
+pub(crate) fn is_move_type(move_type: &str, address: H256, module: &str, name: &str) -> bool {
+    let mut parts = move_type.splitn(3, "::");
+    let (Some(type_address), Some(type_module), Some(type_name)) =
+        (parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+    // Ignore generic type parameters
+    let type_name = type_name.split('<').next().unwrap_or_default();
+    type_module == module
+        && type_name == name
+        && parse_address(type_address).is_ok_and(|type_address| type_address == address)
+}
+
+/// Deserializes a `vector<u8>` encoded as a hex string
+pub(crate) fn deserialize_bytes<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<u8>, D::Error> {
+    let bytes = String::deserialize(deserializer)?;
+    parse_bytes(&bytes).map_err(D::Error::custom)
+}
+
+/// Deserializes a 32 byte `vector<u8>` or address encoded as a hex string
+pub(crate) fn deserialize_h256<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<H256, D::Error> {
+    let address = String::deserialize(deserializer)?;
+    parse_address(&address).map_err(D::Error::custom)
+}
+
+/// Deserializes an integer, which the REST API encodes as a string for `u64`
+/// and wider types and as a number for narrower types
+pub(crate) fn deserialize_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(u64),
+    }
+
+    match StringOrNumber::deserialize(deserializer)? {
+        StringOrNumber::String(value) => value.parse().map_err(D::Error::custom),
+        StringOrNumber::Number(value) => Ok(value),
+    }
+}
+
+/// Serializes an account address in its long hex form
+pub(crate) fn serialize_address<S: Serializer>(
+    address: &H256,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&encode_address(*address))
+}
+
+/// Serializes a `u64` as a string, as the REST API expects it
+pub(crate) fn serialize_u64<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_address() {
+        assert_eq!(parse_address("0x1").unwrap(), H256::from_low_u64_be(1));
+        assert_eq!(
+            parse_address(&encode_address(H256::repeat_byte(0xab))).unwrap(),
+            H256::repeat_byte(0xab)
+        );
+        assert!(parse_address("0x").is_err());
+        assert!(parse_address(&format!("0x{}", "1".repeat(65))).is_err());
+    }
+
+    #[test]
+    fn test_is_move_type() {
+        let address = H256::repeat_byte(0x11);
+        let event_type = format!("{}::mailbox::DispatchEvent", encode_address(address));
+
+        assert!(is_move_type(
+            &event_type,
+            address,
+            "mailbox",
+            "DispatchEvent"
+        ));
+        assert!(!is_move_type(
+            &event_type,
+            address,
+            "mailbox",
+            "ProcessIdEvent"
+        ));
+        assert!(!is_move_type(&event_type, address, "igp", "DispatchEvent"));
+        assert!(!is_move_type(
+            &event_type,
+            H256::repeat_byte(0x22),
+            "mailbox",
+            "DispatchEvent"
+        ));
+        assert!(is_move_type(
+            "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>",
+            H256::from_low_u64_be(1),
+            "coin",
+            "CoinStore"
+        ));
+        assert!(!is_move_type("u64", address, "mailbox", "DispatchEvent"));
+    }
+}
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use hyperlane_core::{
+    Announcement, ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
+    HyperlaneProvider, SignedType, TxOutcome, ValidatorAnnounce, H160, H256, U256,
+};
+
+use crate::{
+    utils::{encode_address, encode_bytes},
+    AptosProvider, EntryFunctionArgument, EntryFunctionPayload,
+};
+
+/// Name of the validator announce module
+const VALIDATOR_ANNOUNCE_MODULE: &str = "validator_announce";
+
+/// Aptos validator announce
+#[derive(Debug, Clone)]
+pub struct AptosValidatorAnnounce {
+    provider: AptosProvider,
+    address: H256,
+}
+
+impl AptosValidatorAnnounce {
+    /// New validator announce instance
+    pub fn new(provider: AptosProvider, locator: &ContractLocator) -> Self {
+        Self {
+            provider,
+            address: locator.address,
+        }
+    }
+
+    fn announce_payload(&self, announcement: &SignedType<Announcement>) -> EntryFunctionPayload {
+        let validator = announcement.value.validator;
+        EntryFunctionPayload {
+            function: format!(
+                "{}::{VALIDATOR_ANNOUNCE_MODULE}::announce",
+                encode_address(self.address)
+            ),
+            type_arguments: vec![],
+            arguments: vec![
+                EntryFunctionArgument::Bytes(validator.as_bytes().to_vec()),
+                EntryFunctionArgument::Bytes(announcement.signature.to_vec()),
+                EntryFunctionArgument::String(announcement.value.storage_location.clone()),
+            ],
+        }
+    }
+}
+
+impl HyperlaneContract for AptosValidatorAnnounce {
+    fn address(&self) -> H256 {
+        self.address
+    }
+}
+
+impl HyperlaneChain for AptosValidatorAnnounce {
+    fn domain(&self) -> &HyperlaneDomain {
+        self.provider.domain()
+    }
+
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+#[async_trait]
+impl ValidatorAnnounce for AptosValidatorAnnounce {
+    async fn get_announced_storage_locations(
+        &self,
+        validators: &[H256],
+    ) -> ChainResult<Vec<Vec<String>>> {
+        // Validators are stored as 20 byte Ethereum addresses
+        let validators = validators
+            .iter()
+            .map(|validator| Value::String(encode_bytes(H160::from(*validator).as_bytes())))
+            .collect();
+        let (storage_locations,): (Vec<Vec<String>>,) = self
+            .provider
+            .view(
+                self.address,
+                VALIDATOR_ANNOUNCE_MODULE,
+                "get_announced_storage_locations",
+                vec![Value::Array(validators)],
+                None,
+            )
+            .await?;
+        Ok(storage_locations)
+    }
+
+    async fn announce(&self, announcement: SignedType<Announcement>) -> ChainResult<TxOutcome> {
+        let payload = self.announce_payload(&announcement);
+        self.provider.send_transaction(payload, None).await
+    }
+
+    async fn announce_tokens_needed(
+        &self,
+        announcement: SignedType<Announcement>,
+        chain_signer: H256,
+    ) -> Option<U256> {
+        let payload = self.announce_payload(&announcement);
+        let simulation = self.provider.simulate(payload).await.ok()?;
+        let cost =
+            U256::from(simulation.gas_used).saturating_mul(U256::from(simulation.gas_unit_price));
+        let balance = self
+            .provider
+            .get_balance(encode_address(chain_signer))
+            .await
+            .ok()?;
+        Some(cost.saturating_sub(balance))
+    }
+}
@@ -0,0 +1,582 @@
+//! Runs the Aptos contracts against a mock node serving the subset of the
+//! REST API the agents use.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::{
+    extract::{Path, Query, State},
+    routing::{get, post},
+    Json, Router,
+};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde_json::{json, Value};
+use sha3::{Digest, Sha3_256};
+use url::Url;
+
+use hyperlane_aptos::{
+    AptosDispatchIndexer, AptosIsm, AptosMailbox, AptosMerkleTreeHook, AptosProvider, AptosSigner,
+    ConnectionConf,
+};
+use hyperlane_core::{
+    accumulator::TREE_DEPTH, ContractLocator, Encode, HyperlaneDomain, HyperlaneDomainProtocol,
+    HyperlaneDomainTechnicalStack, HyperlaneDomainType, HyperlaneMessage, Indexer,
+    InterchainSecurityModule, Mailbox, MerkleTreeHook, Metadata, ModuleType, MultisigIsm,
+    ReorgPeriod, SequenceAwareIndexer, H160, H256,
+};
+use hyperlane_metric::prometheus_metric::PrometheusClientMetrics;
+
+const PACKAGE: H256 = H256::repeat_byte(0xaa);
+/// Signing message a malicious node hands out for any transaction
+const FORGED_SIGNING_MESSAGE: &[u8] = b"forged signing message";
+
+#[derive(Default)]
+struct MockNode {
+    block_height: u64,
+    ledger_version: u64,
+    /// (first version, last version) by height
+    blocks: Vec<(u64, u64)>,
+    /// Committed transactions by version
+    transactions: Vec<Value>,
+    /// Max transactions returned with a block, to force pagination
+    max_block_transactions: usize,
+    /// Return values by view function name
+    views: HashMap<String, Value>,
+    /// Ledger versions requested by view calls
+    view_versions: Vec<Option<u64>>,
+    simulated: Vec<Value>,
+    submitted: Vec<Value>,
+    /// Polls of a submitted transaction left before it is committed
+    pending_polls: usize,
+    /// Block requests currently being served
+    blocks_in_flight: usize,
+    /// Most block requests served at once
+    max_blocks_in_flight: usize,
+}
+
+type Node = Arc<Mutex<MockNode>>;
+
+fn address(address: H256) -> String {
+    format!("0x{}", hex::encode(address.as_bytes()))
+}
+
+fn block_json(node: &MockNode, height: u64, with_transactions: bool) -> Value {
+    let (first, last) = node.blocks[height as usize];
+    let transactions: Vec<Value> = if with_transactions {
+        node.transactions[first as usize..=last as usize]
+            .iter()
+            .take(node.max_block_transactions)
+            .cloned()
+            .collect()
+    } else {
+        vec![]
+    };
+    json!({
+        "block_height": height.to_string(),
+        "block_hash": address(H256::from_low_u64_be(height + 1000)),
+        "block_timestamp": (height * 1_000_000).to_string(),
+        "first_version": first.to_string(),
+        "last_version": last.to_string(),
+        "transactions": transactions,
+    })
+}
+
+fn user_transaction(version: u64, hash: H256, events: Vec<Value>) -> Value {
+    json!({
+        "type": "user_transaction",
+        "version": version.to_string(),
+        "hash": address(hash),
+        "success": true,
+        "vm_status": "Executed successfully",
+        "sender": address(H256::repeat_byte(0x01)),
+        "sequence_number": "0",
+        "max_gas_amount": "1000",
+        "gas_used": "100",
+        "gas_unit_price": "100",
+        "payload": {
+            "type": "entry_function_payload",
+            "function": format!("{}::mailbox::process", address(PACKAGE)),
+            "type_arguments": [],
+            "arguments": [],
+        },
+        "events": events,
+    })
+}
+
+fn metadata_transaction(version: u64) -> Value {
+    json!({ "type": "block_metadata_transaction", "version": version.to_string() })
+}
+
+fn dispatch_event(package: H256, message: &HyperlaneMessage) -> Value {
+    json!({
+        "type": format!("{}::mailbox::DispatchEvent", address(package)),
+        "data": { "message": format!("0x{}", hex::encode(message.to_vec())) },
+    })
+}
+
+async fn ledger_info(State(node): State<Node>) -> Json<Value> {
+    let node = node.lock().unwrap();
+    Json(json!({
+        "chain_id": 4,
+        "ledger_version": node.ledger_version.to_string(),
+        "block_height": node.block_height.to_string(),
+    }))
+}
+
+async fn block_by_height(
+    State(node): State<Node>,
+    Path(height): Path<u64>,
+    Query(query): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    {
+        let mut node = node.lock().unwrap();
+        node.blocks_in_flight += 1;
+        node.max_blocks_in_flight = node.max_blocks_in_flight.max(node.blocks_in_flight);
+    }
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    let mut node = node.lock().unwrap();
+    node.blocks_in_flight -= 1;
+    let with_transactions = query.get("with_transactions").map(String::as_str) == Some("true");
+    Json(block_json(&node, height, with_transactions))
+}
+
+async fn block_by_version(State(node): State<Node>, Path(version): Path<u64>) -> Json<Value> {
+    let node = node.lock().unwrap();
+    let height = node
+        .blocks
+        .iter()
+        .position(|(first, last)| (*first..=*last).contains(&version))
+        .unwrap();
+    Json(block_json(&node, height as u64, false))
+}
+
+async fn transactions(
+    State(node): State<Node>,
+    Query(query): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    let node = node.lock().unwrap();
+    let start: usize = query["start"].parse().unwrap();
+    let limit: usize = query["limit"].parse().unwrap();
+    let page: Vec<Value> = node
+        .transactions
+        .iter()
+        .skip(start)
+        .take(limit)
+        .cloned()
+        .collect();
+    Json(Value::Array(page))
+}
+
+async fn transaction_by_hash(State(node): State<Node>, Path(hash): Path<String>) -> Json<Value> {
+    let mut node = node.lock().unwrap();
+    if let Some(transaction) = node
+        .transactions
+        .iter()
+        .find(|transaction| transaction["hash"] == hash.as_str())
+    {
+        return Json(transaction.clone());
+    }
+    if node.pending_polls > 0 {
+        node.pending_polls -= 1;
+        return Json(json!({ "type": "pending_transaction", "hash": hash }));
+    }
+    let mut committed = user_transaction(99, H256::zero(), vec![]);
+    committed["hash"] = json!(hash);
+    committed["gas_used"] = json!("90");
+    committed["gas_unit_price"] = json!("150");
+    Json(committed)
+}
+
+async fn account() -> Json<Value> {
+    Json(json!({ "sequence_number": "7", "authentication_key": address(H256::zero()) }))
+}
+
+async fn estimate_gas_price() -> Json<Value> {
+    Json(json!({ "gas_estimate": 150, "deprioritized_gas_estimate": 100 }))
+}
+
+async fn view(
+    State(node): State<Node>,
+    Query(query): Query<HashMap<String, String>>,
+    Json(request): Json<Value>,
+) -> Json<Value> {
+    let mut node = node.lock().unwrap();
+    node.view_versions
+        .push(query.get("ledger_version").map(|v| v.parse().unwrap()));
+    let function = request["function"].as_str().unwrap();
+    let name = function.rsplit("::").next().unwrap();
+    Json(node.views[name].clone())
+}
+
+async fn simulate(State(node): State<Node>, Json(request): Json<Value>) -> Json<Value> {
+    node.lock().unwrap().simulated.push(request);
+    Json(json!([user_transaction(0, H256::zero(), vec![])]))
+}
+
+async fn encode_submission() -> Json<Value> {
+    Json(json!(format!("0x{}", hex::encode(FORGED_SIGNING_MESSAGE))))
+}
+
+async fn submit(State(node): State<Node>, Json(request): Json<Value>) -> Json<Value> {
+    node.lock().unwrap().submitted.push(request);
+    Json(json!({ "hash": address(H256::repeat_byte(0x77)) }))
+}
+
+async fn start_node(node: MockNode) -> (Node, Url) {
+    let node = Arc::new(Mutex::new(node));
+    let router = Router::new()
+        .route("/v1", get(ledger_info))
+        .route("/v1/blocks/by_height/{height}", get(block_by_height))
+        .route("/v1/blocks/by_version/{version}", get(block_by_version))
+        .route("/v1/transactions", get(transactions).post(submit))
+        .route("/v1/transactions/by_hash/{hash}", get(transaction_by_hash))
+        .route("/v1/transactions/simulate", post(simulate))
+        .route(
+            "/v1/transactions/encode_submission",
+            post(encode_submission),
+        )
+        .route("/v1/accounts/{address}", get(account))
+        .route("/v1/estimate_gas_price", get(estimate_gas_price))
+        .route("/v1/view", post(view))
+        .with_state(node.clone());
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/v1", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+    (node, Url::parse(&url).unwrap())
+}
+
+fn domain() -> HyperlaneDomain {
+    HyperlaneDomain::Unknown {
+        domain_id: 14402,
+        domain_name: "aptostest".to_owned(),
+        domain_type: HyperlaneDomainType::LocalTestChain,
+        domain_protocol: HyperlaneDomainProtocol::Aptos,
+        domain_technical_stack: HyperlaneDomainTechnicalStack::Other,
+    }
+}
+
+fn provider(url: Url, signer: Option<AptosSigner>) -> AptosProvider {
+    AptosProvider::new(
+        &ConnectionConf::new(vec![url], None),
+        domain(),
+        signer,
+        PrometheusClientMetrics::default(),
+        None,
+    )
+    .unwrap()
+}
+
+fn message(nonce: u32) -> HyperlaneMessage {
+    HyperlaneMessage {
+        nonce,
+        origin: 14402,
+        destination: 1,
+        body: vec![nonce as u8; 4],
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_mailbox_views() {
+    let mut node = MockNode {
+        block_height: 10,
+        ledger_version: 500,
+        blocks: (0..=10)
+            .map(|height| (height * 50, height * 50 + 49))
+            .collect(),
+        ..Default::default()
+    };
+    node.views.insert("nonce".to_owned(), json!([42]));
+    node.views.insert("delivered".to_owned(), json!([true]));
+    node.views.insert("default_ism".to_owned(), json!(["0xbb"]));
+    let (node, url) = start_node(node).await;
+
+    let domain = domain();
+    let locator = ContractLocator {
+        domain: &domain,
+        address: PACKAGE,
+    };
+    let mailbox = AptosMailbox::new(provider(url, None), &locator);
+
+    assert_eq!(mailbox.count(&ReorgPeriod::None).await.unwrap(), 42);
+    assert_eq!(
+        mailbox.count(&ReorgPeriod::from_blocks(3)).await.unwrap(),
+        42
+    );
+    assert!(mailbox.delivered(H256::random()).await.unwrap());
+    assert_eq!(
+        mailbox.default_ism().await.unwrap(),
+        H256::from_low_u64_be(0xbb)
+    );
+    assert!(mailbox
+        .count(&ReorgPeriod::Tag("finalized".to_owned()))
+        .await
+        .is_err());
+
+    // Reads at the latest version, then at the last version of block 10 - 3
+    let view_versions = node.lock().unwrap().view_versions.clone();
+    assert_eq!(&view_versions[..2], &[Some(500), Some(7 * 50 + 49)]);
+}
+
+#[tokio::test]
+async fn test_dispatch_indexing() {
+    let other_package = H256::repeat_byte(0xcc);
+    let transactions = vec![
+        metadata_transaction(0),
+        metadata_transaction(1),
+        user_transaction(
+            2,
+            H256::repeat_byte(0x02),
+            vec![
+                dispatch_event(other_package, &message(100)),
+                dispatch_event(PACKAGE, &message(0)),
+            ],
+        ),
+        user_transaction(
+            3,
+            H256::repeat_byte(0x03),
+            vec![dispatch_event(PACKAGE, &message(1))],
+        ),
+    ];
+    let mut node = MockNode {
+        block_height: 1,
+        ledger_version: 3,
+        blocks: vec![(0, 0), (1, 3)],
+        transactions,
+        max_block_transactions: 1,
+        ..Default::default()
+    };
+    node.views.insert("nonce".to_owned(), json!([2]));
+    let (_, url) = start_node(node).await;
+
+    let domain = domain();
+    let locator = ContractLocator {
+        domain: &domain,
+        address: PACKAGE,
+    };
+    let indexer = AptosDispatchIndexer::new(provider(url, None), &locator);
+
+    let logs = Indexer::fetch_logs_in_range(&indexer, 0..=1).await.unwrap();
+    assert_eq!(logs.len(), 2);
+    let (first, first_meta) = &logs[0];
+    assert_eq!(*first.inner(), message(0));
+    assert_eq!(first.sequence, Some(0));
+    assert_eq!(first_meta.address, PACKAGE);
+    assert_eq!(first_meta.block_number, 1);
+    assert_eq!(first_meta.block_hash, H256::from_low_u64_be(1001));
+    assert_eq!(first_meta.transaction_id, H256::repeat_byte(0x02).into());
+    assert_eq!(first_meta.transaction_index, 2);
+    assert_eq!(first_meta.log_index, 1.into());
+    assert_eq!(*logs[1].0.inner(), message(1));
+
+    let logs = Indexer::fetch_logs_by_tx_hash(&indexer, H256::repeat_byte(0x03).into())
+        .await
+        .unwrap();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].1.block_number, 1);
+
+    assert_eq!(
+        indexer.latest_sequence_count_and_tip().await.unwrap(),
+        (Some(2), 1)
+    );
+}
+
+#[tokio::test]
+async fn test_indexing_bounds_block_requests() {
+    let node = MockNode {
+        block_height: 99,
+        ledger_version: 99,
+        blocks: (0..100).map(|height| (height, height)).collect(),
+        transactions: (0..100).map(metadata_transaction).collect(),
+        max_block_transactions: 1,
+        ..Default::default()
+    };
+    let (node, url) = start_node(node).await;
+
+    let domain = domain();
+    let locator = ContractLocator {
+        domain: &domain,
+        address: PACKAGE,
+    };
+    let indexer = AptosDispatchIndexer::new(provider(url, None), &locator);
+
+    let logs = Indexer::fetch_logs_in_range(&indexer, 0..=99)
+        .await
+        .unwrap();
+    assert!(logs.is_empty());
+    let max_blocks_in_flight = node.lock().unwrap().max_blocks_in_flight;
+    assert!(max_blocks_in_flight > 1);
+    assert!(max_blocks_in_flight <= 10);
+}
+
+#[tokio::test]
+async fn test_merkle_tree_hook() {
+    let branch: Vec<String> = (0..TREE_DEPTH)
+        .map(|i| address(H256::from_low_u64_be(i as u64)))
+        .collect();
+    let root = H256::repeat_byte(0x55);
+    let mut node = MockNode {
+        block_height: 5,
+        ledger_version: 60,
+        blocks: (0..=5)
+            .map(|height| (height * 10, height * 10 + 9))
+            .collect(),
+        ..Default::default()
+    };
+    node.views.insert("tree".to_owned(), json!([branch, 3]));
+    node.views.insert("count".to_owned(), json!([3]));
+    node.views
+        .insert("latest_checkpoint".to_owned(), json!([address(root), 2]));
+    let (_, url) = start_node(node).await;
+
+    let domain = domain();
+    let locator = ContractLocator {
+        domain: &domain,
+        address: PACKAGE,
+    };
+    let hook = AptosMerkleTreeHook::new(provider(url, None), &locator);
+
+    let tree = hook.tree(&ReorgPeriod::from_blocks(1)).await.unwrap();
+    assert_eq!(tree.count, 3);
+    assert_eq!(tree.branch[31], H256::from_low_u64_be(31));
+    assert_eq!(tree.block_height, Some(4));
+
+    let checkpoint = hook.latest_checkpoint(&ReorgPeriod::None).await.unwrap();
+    assert_eq!(checkpoint.root, root);
+    assert_eq!(checkpoint.index, 2);
+    assert_eq!(checkpoint.mailbox_domain, 14402);
+    assert_eq!(checkpoint.block_height, Some(5));
+
+    let checkpoint = hook.latest_checkpoint_at_block(2).await.unwrap();
+    assert_eq!(checkpoint.block_height, Some(2));
+}
+
+#[tokio::test]
+async fn test_ism() {
+    let validator = H160::repeat_byte(0x99);
+    let mut node = MockNode::default();
+    node.views.insert("module_type".to_owned(), json!([5]));
+    node.views.insert(
+        "validators_and_threshold".to_owned(),
+        json!([[format!("0x{}", hex::encode(validator.as_bytes()))], 1]),
+    );
+    node.views.insert("verify".to_owned(), json!([false]));
+    let (_, url) = start_node(node).await;
+
+    let domain = domain();
+    let locator = ContractLocator {
+        domain: &domain,
+        address: PACKAGE,
+    };
+    let ism = AptosIsm::new(provider(url, None), &locator);
+
+    assert_eq!(
+        ism.module_type().await.unwrap(),
+        ModuleType::MessageIdMultisig
+    );
+    assert_eq!(
+        ism.validators_and_threshold(&message(0)).await.unwrap(),
+        (vec![validator.into()], 1)
+    );
+    assert_eq!(
+        ism.dry_run_verify(&message(0), &Metadata::new(vec![]))
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_process_submission() {
+    let node = MockNode {
+        pending_polls: 1,
+        ..Default::default()
+    };
+    let (node, url) = start_node(node).await;
+
+    let signer = AptosSigner::new(&[3; 32]).unwrap();
+    let domain = domain();
+    let locator = ContractLocator {
+        domain: &domain,
+        address: PACKAGE,
+    };
+    let mailbox = AptosMailbox::new(provider(url, Some(signer.clone())), &locator);
+
+    let message = message(0);
+    let metadata = Metadata::new(vec![1, 2, 3]);
+    let outcome = mailbox.process(&message, &metadata, None).await.unwrap();
+    assert!(outcome.executed);
+    assert_eq!(outcome.transaction_id, H256::repeat_byte(0x77).into());
+    assert_eq!(outcome.gas_used, 90.into());
+
+    let node = node.lock().unwrap();
+    let simulated = &node.simulated[0];
+    assert_eq!(simulated["sender"], signer.address());
+    assert_eq!(simulated["sequence_number"], "7");
+    assert_eq!(
+        simulated["signature"]["signature"],
+        format!("0x{}", "00".repeat(64))
+    );
+
+    let submitted = &node.submitted[0];
+    assert_eq!(
+        submitted["payload"]["function"],
+        format!("{}::mailbox::process", address(PACKAGE))
+    );
+    assert_eq!(submitted["payload"]["arguments"][0], "0x010203");
+    assert_eq!(
+        submitted["payload"]["arguments"][1],
+        format!("0x{}", hex::encode(message.to_vec()))
+    );
+    // Simulated gas usage times the default multiplier
+    assert_eq!(submitted["max_gas_amount"], "150");
+    assert_eq!(submitted["gas_unit_price"], "150");
+
+    let public_key = hex::decode(
+        submitted["signature"]["public_key"]
+            .as_str()
+            .unwrap()
+            .trim_start_matches("0x"),
+    )
+    .unwrap();
+    let signature = hex::decode(
+        submitted["signature"]["signature"]
+            .as_str()
+            .unwrap()
+            .trim_start_matches("0x"),
+    )
+    .unwrap();
+    let verifying_key = VerifyingKey::from_bytes(&public_key.try_into().unwrap()).unwrap();
+    let signature = Signature::from_bytes(&signature.try_into().unwrap());
+    assert!(verifying_key
+        .verify(FORGED_SIGNING_MESSAGE, &signature)
+        .is_err());
+
+    // The signed message is the BCS encoded transaction built by the agent
+    let expiration: u64 = submitted["expiration_timestamp_secs"]
+        .as_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    let mut signing_message = Sha3_256::digest(b"APTOS::RawTransaction").to_vec();
+    signing_message.extend(signer.address_h256().as_bytes());
+    signing_message.extend(7u64.to_le_bytes());
+    signing_message.push(2);
+    signing_message.extend(PACKAGE.as_bytes());
+    signing_message.extend(b"\x07mailbox\x07process");
+    signing_message.extend([0, 2]);
+    for argument in [vec![1, 2, 3], message.to_vec()] {
+        let length = u8::try_from(argument.len()).unwrap();
+        assert!(length < 0x7f);
+        signing_message.extend([length + 1, length]);
+        signing_message.extend(argument);
+    }
+    signing_message.extend(150u64.to_le_bytes());
+    signing_message.extend(150u64.to_le_bytes());
+    signing_message.extend(expiration.to_le_bytes());
+    signing_message.push(4);
+    assert!(verifying_key.verify(&signing_message, &signature).is_ok());
+}
@@ -7,8 +7,11 @@ use tonic::transport::{Channel, Endpoint};
 
 use hyperlane_core::rpc_clients::{BlockNumberGetter, FallbackProvider};
 use hyperlane_core::{ChainCommunicationError, ChainResult, ReorgPeriod};
-use hyperlane_metric::prometheus_metric::{
-    ChainInfo, ClientConnectionType, PrometheusClientMetrics, PrometheusConfig,
+use hyperlane_metric::{
+    prometheus_metric::{
+        ChainInfo, ClientConnectionType, PrometheusClientMetrics, PrometheusConfig,
+    },
+    utils::urls_to_provider_nodes,
 };
 
 use cosmrs::proto::cosmos::base::tendermint::v1beta1::service_client::ServiceClient;
@@ -67,8 +70,9 @@ impl GrpcProvider {
         metrics: PrometheusClientMetrics,
         chain: Option<ChainInfo>,
     ) -> ChainResult<Self> {
-        let clients = conf
-            .get_grpc_urls()
+        let urls = conf.get_grpc_urls();
+        let provider_nodes = urls_to_provider_nodes(&urls);
+        let clients = urls
             .into_iter()
             .map(|url| {
                 let metrics_config =
@@ -82,7 +86,14 @@ impl GrpcProvider {
             })
             .collect::<Result<Vec<GrpcChannel>, _>>()?;
 
-        let fallback = FallbackProvider::new(clients);
+        let fallback = FallbackProvider::builder()
+            .add_providers(clients)
+            .with_score_metric(
+                metrics.provider_score.clone(),
+                PrometheusConfig::chain_name(&chain),
+                provider_nodes,
+            )
+            .build();
         Ok(Self { fallback })
     }
 
@@ -32,8 +32,9 @@ use hyperlane_core::{
     rpc_clients::{BlockNumberGetter, FallbackProvider},
     ChainCommunicationError, ChainResult, FixedPointNumber, H256, H512, U256,
 };
-use hyperlane_metric::prometheus_metric::{
-    ClientConnectionType, PrometheusClientMetrics, PrometheusConfig,
+use hyperlane_metric::{
+    prometheus_metric::{ClientConnectionType, PrometheusClientMetrics, PrometheusConfig},
+    utils::urls_to_provider_nodes,
 };
 
 use crate::{ConnectionConf, CosmosAmount, HyperlaneCosmosError, Signer};
@@ -153,7 +154,14 @@ impl RpcProvider {
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        let provider = FallbackProvider::new(clients);
+        let provider = FallbackProvider::builder()
+            .add_providers(clients)
+            .with_score_metric(
+                metrics.provider_score.clone(),
+                PrometheusConfig::chain_name(&chain),
+                urls_to_provider_nodes(&conf.get_rpc_urls()),
+            )
+            .build();
         let gas_price = CosmosAmount::try_from(conf.get_minimum_gas_price().clone())?;
 
         Ok(RpcProvider {
@@ -1,14 +1,23 @@
+use std::{collections::HashMap, time::Duration};
+
 use ethers::providers::Middleware;
 use ethers_core::types::{BlockId, BlockNumber};
 use url::Url;
 
 use hyperlane_core::{
-    config::OpSubmissionConfig, utils::hex_or_base58_or_bech32_to_h256, ChainCommunicationError,
-    ChainResult, ReorgPeriod, H256, U256,
+    config::OpSubmissionConfig,
+    rpc_clients::{FallbackProviderConf, RpcRateLimitConf},
+    utils::hex_or_base58_or_bech32_to_h256,
+    ChainCommunicationError, ChainResult, ReorgPeriod, H256, U256,
 };
 
 static BATCH_CONTRACT_ADDRESS_DEFAULT: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
 
+/// Default number of blocks past the current head a private submission targets
+pub const DEFAULT_PRIVATE_RELAY_TARGET_BLOCKS: u64 = 10;
+/// Default time after which a transaction falls back to the public mempool
+pub const DEFAULT_PRIVATE_RELAY_FALLBACK_TIMEOUT: Duration = Duration::from_secs(180);
+
 /// Ethereum RPC connection configuration
 #[derive(Debug, Clone)]
 pub enum RpcConnectionConf {
@@ -53,6 +62,14 @@ pub struct ConnectionConf {
     /// we will try other providers and see if another provider returns something
     /// non-null
     pub consider_null_transaction_receipt: bool,
+    /// If set, transactions are first submitted to a private relay rather than
+    /// to the public mempool
+    pub private_relay: Option<PrivateRelayConf>,
+    /// Self-imposed request budgets of the RPC urls. Urls without an entry
+    /// aren't limited.
+    pub rpc_rate_limits: HashMap<Url, RpcRateLimitConf>,
+    /// How the urls of a fallback connection are scored and hedged
+    pub rpc_fallback: FallbackProviderConf,
 }
 
 impl ConnectionConf {
@@ -115,6 +132,31 @@ pub struct TransactionOverrides {
     pub gas_limit_cap: Option<U256>,
 }
 
+/// Private relay configuration, used to keep transactions out of the public
+/// mempool until they are included in a block.
+#[derive(Debug, Clone)]
+pub struct PrivateRelayConf {
+    /// Url of the relay endpoint
+    pub url: Url,
+    /// JSON-RPC method used to hand transactions over to the relay
+    pub method: PrivateRelayMethod,
+    /// Number of blocks past the current head a submission is valid for
+    pub target_blocks: u64,
+    /// Time since the transaction was created after which it is submitted
+    /// to the public mempool instead
+    pub fallback_timeout: Duration,
+}
+
+/// JSON-RPC method supported by a private relay
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PrivateRelayMethod {
+    /// `eth_sendPrivateTransaction`, valid up to a maximum block number
+    #[default]
+    PrivateTransaction,
+    /// `eth_sendBundle`, with a single-transaction bundle sent for each target block
+    Bundle,
+}
+
 /// Ethereum reorg period
 #[derive(Copy, Clone, Debug)]
 pub enum EthereumReorgPeriod {
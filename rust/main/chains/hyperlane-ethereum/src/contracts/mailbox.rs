@@ -876,6 +876,9 @@ mod test {
             transaction_overrides: Default::default(),
             op_submission_config: Default::default(),
             consider_null_transaction_receipt: false,
+            private_relay: None,
+            rpc_rate_limits: Default::default(),
+            rpc_fallback: Default::default(),
         };
 
         let mailbox = EthereumMailbox::new(
@@ -6,6 +6,7 @@ mod tests;
 use std::fmt::{Debug, Formatter};
 use std::future::Future;
 use std::ops::Deref;
+use std::pin::Pin;
 use std::time::Duration;
 
 use async_trait::async_trait;
@@ -19,7 +20,7 @@ use tokio::time::sleep;
 use tracing::{instrument, warn};
 
 use ethers_prometheus::json_rpc_client::JsonRpcBlockGetter;
-use hyperlane_core::rpc_clients::{BlockNumberGetter, FallbackProvider};
+use hyperlane_core::rpc_clients::{BlockNumberGetter, FallbackProvider, RpcBudgetExhausted};
 use hyperlane_metric::prometheus_metric::PrometheusConfigExt;
 
 use crate::rpc_clients::{categorize_client_response, CategorizedResponse};
@@ -91,6 +92,9 @@ pub enum FallbackError {
     /// All providers failed
     #[error("All providers failed. (Errors: {0:?})")]
     AllProvidersFailed(Vec<ProviderError>),
+    /// The daily budget of every provider is exhausted
+    #[error(transparent)]
+    BudgetExhausted(#[from] RpcBudgetExhausted),
 }
 
 impl From<FallbackError> for ProviderError {
@@ -106,12 +110,12 @@ where
     C: JsonRpcClient<Error = HttpClientError>
         + Into<JsonRpcBlockGetter<C>>
         + PrometheusConfigExt
-        + Clone,
+        + Clone
+        + 'static,
     JsonRpcBlockGetter<C>: BlockNumberGetter,
 {
     type Error = ProviderError;
 
-    // TODO: Refactor to use `FallbackProvider::call`
     #[instrument(skip(self, params))]
     async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
     where
@@ -134,7 +138,8 @@ where
     C: JsonRpcClient<Error = HttpClientError>
         + Into<JsonRpcBlockGetter<C>>
         + PrometheusConfigExt
-        + Clone,
+        + Clone
+        + 'static,
     JsonRpcBlockGetter<C>: BlockNumberGetter,
 {
     async fn multicast<T, R>(&self, method: &str, params: T) -> Result<R, ProviderError>
@@ -162,7 +167,15 @@ where
             // future which visits all providers as they fulfill their requests
             let mut unordered = self.populate_unordered_future(method, &params);
 
-            while let Some((provider_host, resp)) = unordered.next().await {
+            while let Some(resp) = unordered.next().await {
+                let (provider_host, resp) = match resp {
+                    Ok(resp) => resp,
+                    // the budget only resets the next day
+                    Err(e) => {
+                        non_retryable_errors.push(FallbackError::from(e).into());
+                        continue;
+                    }
+                };
                 let value = match categorize_client_response(provider_host.as_str(), method, resp) {
                     IsOk(v) => serde_json::from_value(v)?,
                     RetryableErr(e) | RateLimitErr(e) => {
@@ -211,6 +224,7 @@ where
         use CategorizedResponse::*;
 
         let params = serde_json::to_value(params).expect("valid");
+        let mut request = Self::request_fn(method, &params);
 
         let mut errors: Vec<ProviderError> = vec![];
         // make sure we do at least 4 total retries.
@@ -219,41 +233,64 @@ where
                 sleep(Duration::from_millis(100)).await;
             }
             let priorities_snapshot = self.take_priorities_snapshot().await;
+            // Index of the provider which was already called to hedge the previous one
+            let mut hedged = None;
+            // Set if no provider responded because every budget is exhausted
+            let mut budget_exhausted = None;
+            let mut responded = false;
             for (idx, priority) in priorities_snapshot.iter().enumerate() {
-                let provider = &self.inner.providers[priority.index];
-                let fut = Self::provider_request(provider, method, &params);
-                let (provider_host, resp) = fut.await;
-                self.handle_stalled_provider(priority, provider).await;
-                if resp.is_err() {
-                    self.handle_failed_provider(priority).await;
+                if hedged == Some(priority.index) {
+                    continue;
                 }
-                tracing::debug!(
-                    fallback_count = idx,
-                    provider_index = priority.index,
-                    provider_host = provider_host.as_str(),
-                    method,
-                    "fallback_request"
-                );
-
-                match categorize_client_response(provider_host.as_str(), method, resp) {
-                    IsOk(v) => {
-                        // Add log to identify content of v when no tx receipt is found
-                        if v.is_null() {
-                            tracing::debug!(
-                                fallback_count = idx,
-                                provider_index = priority.index,
-                                provider_host = provider_host.as_str(),
-                                method,
-                                ?v,
-                                "fallback_request: value is null"
-                            );
+                let next = priorities_snapshot.get(idx.saturating_add(1));
+                let responses = match self
+                    .call_hedged(Some(method), priority, next, &mut request, Result::is_ok)
+                    .await
+                {
+                    Ok(responses) => responses,
+                    Err(e) => {
+                        warn!(provider_index = priority.index, error = %e, method, "Skipping provider");
+                        budget_exhausted = Some(e);
+                        continue;
+                    }
+                };
+                responded = true;
+                for (provider_index, resp) in responses {
+                    if provider_index != priority.index {
+                        hedged = Some(provider_index);
+                    }
+                    let provider_host = self.inner.providers[provider_index].node_host();
+                    tracing::debug!(
+                        fallback_count = idx,
+                        provider_index,
+                        provider_host,
+                        method,
+                        "fallback_request"
+                    );
+
+                    match categorize_client_response(provider_host, method, resp) {
+                        IsOk(v) => {
+                            // Add log to identify content of v when no tx receipt is found
+                            if v.is_null() {
+                                tracing::debug!(
+                                    fallback_count = idx,
+                                    provider_index,
+                                    provider_host,
+                                    method,
+                                    ?v,
+                                    "fallback_request: value is null"
+                                );
+                            }
+                            return Ok(serde_json::from_value(v)?);
                         }
-                        return Ok(serde_json::from_value(v)?);
+                        RetryableErr(e) | RateLimitErr(e) => errors.push(e.into()),
+                        NonRetryableErr(e) => return Err(e.into()),
                     }
-                    RetryableErr(e) | RateLimitErr(e) => errors.push(e.into()),
-                    NonRetryableErr(e) => return Err(e.into()),
                 }
             }
+            if let Some(e) = budget_exhausted.filter(|_| !responded) {
+                return Err(FallbackError::from(e).into());
+            }
         }
         Err(FallbackError::AllProvidersFailed(errors).into())
     }
@@ -270,6 +307,11 @@ where
         use CategorizedResponse::*;
 
         let params = serde_json::to_value(params).expect("valid");
+        let mut request = Self::request_fn(method, &params);
+        // A null receipt from the primary provider is not worth cancelling the
+        // hedged request for
+        let is_receipt =
+            |resp: &Result<Value, HttpClientError>| matches!(resp, Ok(v) if !v.is_null());
 
         let mut priorities = self.take_priorities_snapshot().await;
         let mut errors: Vec<ProviderError> = vec![];
@@ -281,47 +323,97 @@ where
             }
 
             let mut retry_priorities = Vec::with_capacity(priorities.len());
-            for (idx, priority) in priorities.into_iter().enumerate() {
-                let provider = &self.inner.providers[priority.index];
-                let fut = Self::provider_request(provider, method, &params);
-                let (provider_host, resp) = fut.await;
-                self.handle_stalled_provider(&priority, provider).await;
-                if resp.is_err() {
-                    self.handle_failed_provider(&priority).await;
+            // Index of the provider which was already called to hedge the previous one
+            let mut hedged = None;
+            // Set if no provider responded because every budget is exhausted
+            let mut budget_exhausted = None;
+            let mut responded = false;
+            for (idx, priority) in priorities.iter().enumerate() {
+                if hedged == Some(priority.index) {
+                    continue;
                 }
-                tracing::debug!(
-                    fallback_count = idx,
-                    provider_index = priority.index,
-                    provider_host = provider_host.as_str(),
-                    method,
-                    ?resp,
-                    "fallback_transaction_receipt"
-                );
-
-                match categorize_client_response(provider_host.as_str(), method, resp) {
-                    NonRetryableErr(e) => return Err(e.into()),
-                    RetryableErr(e) | RateLimitErr(e) => {
-                        errors.push(e.into());
-                        // if it is a retryable error, then we want to
-                        // retry this provider
-                        retry_priorities.push(priority);
+                let next = priorities.get(idx.saturating_add(1));
+                // A provider without budget isn't retried
+                let responses = match self
+                    .call_hedged(Some(method), priority, next, &mut request, is_receipt)
+                    .await
+                {
+                    Ok(responses) => responses,
+                    Err(e) => {
+                        warn!(provider_index = priority.index, error = %e, method, "Skipping provider");
+                        budget_exhausted = Some(e);
+                        continue;
                     }
-                    IsOk(v) => {
-                        // If we received null for transaction receipt
-                        // we don't want to retry this provider
-                        if v.is_null() {
-                            continue;
+                };
+                responded = true;
+                for (provider_index, resp) in responses {
+                    if provider_index != priority.index {
+                        hedged = Some(provider_index);
+                    }
+                    let provider_host = self.inner.providers[provider_index].node_host();
+                    tracing::debug!(
+                        fallback_count = idx,
+                        provider_index,
+                        provider_host,
+                        method,
+                        ?resp,
+                        "fallback_transaction_receipt"
+                    );
+
+                    match categorize_client_response(provider_host, method, resp) {
+                        NonRetryableErr(e) => return Err(e.into()),
+                        RetryableErr(e) | RateLimitErr(e) => {
+                            errors.push(e.into());
+                            // if it is a retryable error, then we want to
+                            // retry this provider
+                            retry_priorities.extend(
+                                std::iter::once(priority)
+                                    .chain(next)
+                                    .find(|p| p.index == provider_index)
+                                    .copied(),
+                            );
+                        }
+                        IsOk(v) => {
+                            // If we received null for transaction receipt
+                            // we don't want to retry this provider
+                            if v.is_null() {
+                                continue;
+                            }
+                            return Ok(serde_json::from_value(v)?);
                         }
-                        return Ok(serde_json::from_value(v)?);
                     }
                 }
             }
+            if let Some(e) = budget_exhausted.filter(|_| !responded) {
+                return Err(FallbackError::from(e).into());
+            }
             priorities = retry_priorities;
         }
 
         Err(FallbackError::AllProvidersFailed(errors).into())
     }
 
+    /// Request `method` with `params` from whichever provider is passed to the
+    /// returned closure, for use with `FallbackProvider::call_hedged`
+    #[allow(clippy::type_complexity)]
+    fn request_fn(
+        method: &str,
+        params: &Value,
+    ) -> impl FnMut(C) -> Pin<Box<dyn Future<Output = Result<Value, HttpClientError>> + Send>> {
+        let method = method.to_owned();
+        let params = params.clone();
+        move |provider: C| {
+            let method = method.clone();
+            let params = params.clone();
+            Box::pin(async move {
+                match params {
+                    Value::Null => provider.request(&method, ()).await,
+                    _ => provider.request(&method, params).await,
+                }
+            })
+        }
+    }
+
     async fn provider_request<'a>(
         provider: &'a C,
         method: &'a str,
@@ -336,17 +428,27 @@ where
         (provider_host, result)
     }
 
+    #[allow(clippy::type_complexity)]
     fn populate_unordered_future<'a>(
         &'a self,
         method: &'a str,
         params: &'a Value,
-    ) -> FuturesUnordered<impl Future<Output = (String, Result<Value, HttpClientError>)> + Sized + 'a>
-    {
+    ) -> FuturesUnordered<
+        impl Future<Output = Result<(String, Result<Value, HttpClientError>), RpcBudgetExhausted>>
+            + Sized
+            + 'a,
+    > {
         let unordered = FuturesUnordered::new();
         self.inner
             .providers
             .iter()
-            .for_each(|p| unordered.push(Self::provider_request(p, method, params)));
+            .enumerate()
+            .for_each(|(index, p)| {
+                unordered.push(async move {
+                    self.acquire(index, Some(method)).await?;
+                    Ok(Self::provider_request(p, method, params).await)
+                })
+            });
         unordered
     }
 }
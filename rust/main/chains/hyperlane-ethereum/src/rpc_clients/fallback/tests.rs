@@ -11,7 +11,8 @@ where
     C: JsonRpcClient<Error = HttpClientError>
         + PrometheusConfigExt
         + Into<JsonRpcBlockGetter<C>>
-        + Clone,
+        + Clone
+        + 'static,
     JsonRpcBlockGetter<C>: BlockNumberGetter,
 {
     async fn fallback_test_call(&self) -> u64 {
@@ -375,6 +376,51 @@ async fn test_fallback_one_stalled_provider() {
     assert_eq!(provider_call_count, vec![0, 0, 2]);
 }
 
+#[tracing_test::traced_test]
+#[tokio::test]
+async fn test_fallback_slow_provider_is_hedged() {
+    let fallback_provider_builder = FallbackProviderBuilder::default();
+    let providers = vec![
+        EthereumProviderMock::new(Some(Duration::from_millis(200))),
+        EthereumProviderMock::new(None),
+        EthereumProviderMock::new(None),
+    ];
+    providers[0]
+        .responses
+        .get_block_number
+        .lock()
+        .unwrap()
+        .push_back(Some(1));
+    providers[1]
+        .responses
+        .get_block_number
+        .lock()
+        .unwrap()
+        .push_back(Some(2));
+
+    let fallback_provider = fallback_provider_builder
+        .add_providers(providers)
+        .with_hedge_after(Duration::from_millis(20))
+        .build();
+    let ethereum_fallback_provider = EthereumFallbackProvider::new(fallback_provider, false);
+    let provider_id = ethereum_fallback_provider.fallback_test_call().await;
+    let provider_call_count: Vec<_> = ethereum_fallback_provider
+        .inner
+        .providers
+        .iter()
+        .map(|provider| provider.requests().len())
+        .collect();
+    assert_eq!(provider_id, 2);
+    assert_eq!(provider_call_count, vec![1, 1, 0]);
+    // The slow provider is no longer tried first
+    let priorities: Vec<_> = ProviderMock::get_priorities(&ethereum_fallback_provider)
+        .await
+        .iter()
+        .map(|p| p.index)
+        .collect();
+    assert_ne!(priorities[0], 0);
+}
+
 // TODO: make `categorize_client_response` generic over `ProviderError` to allow testing
 // two stalled providers (so that the for loop in `request` doesn't stop after the first provider)
 
@@ -1,11 +1,12 @@
 use ethers::providers::HttpClientError;
 use tracing::{error, info, trace, warn};
 
-pub use self::{fallback::*, provider::*, retrying::*, trait_builder::*};
+pub use self::{fallback::*, private_relay::*, provider::*, retrying::*, trait_builder::*};
 pub use error::decode_revert_reason;
 
 mod error;
 mod fallback;
+mod private_relay;
 mod provider;
 mod retrying;
 mod trait_builder;
@@ -0,0 +1,224 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use ethers::types::Bytes;
+use ethers::utils::keccak256;
+use ethers_signers::Signer;
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tracing::{debug, instrument};
+
+use hyperlane_core::{ChainCommunicationError, ChainResult, H256};
+
+use crate::{PrivateRelayConf, Signers};
+
+const METHOD_SEND_PRIVATE_TRANSACTION: &str = "eth_sendPrivateTransaction";
+const METHOD_SEND_BUNDLE: &str = "eth_sendBundle";
+
+/// Header used by Flashbots-style relays to authenticate the searcher / relayer
+const FLASHBOTS_SIGNATURE_HEADER: &str = "X-Flashbots-Signature";
+
+/// A relay which accepts signed transactions without broadcasting them
+/// to the public mempool.
+#[async_trait]
+pub trait PrivateRelay: Send + Sync {
+    /// Submit a signed transaction with `eth_sendPrivateTransaction`.
+    /// The relay stops trying to include it after `max_block_number`.
+    async fn send_private_transaction(
+        &self,
+        raw_tx: &Bytes,
+        max_block_number: u64,
+    ) -> ChainResult<H256>;
+
+    /// Submit a bundle of signed transactions with `eth_sendBundle`,
+    /// targeting inclusion in `block_number` only. Returns the bundle hash.
+    async fn send_bundle(&self, raw_txs: &[Bytes], block_number: u64) -> ChainResult<H256>;
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcResponse<T> {
+    result: Option<T>,
+    error: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SendBundleResult {
+    bundle_hash: H256,
+}
+
+/// HTTP client for a Flashbots-style private relay
+pub struct PrivateRelayClient {
+    conf: PrivateRelayConf,
+    client: Client,
+    /// Key used to sign the request body, if the relay requires authentication
+    signer: Option<Signers>,
+}
+
+impl Debug for PrivateRelayClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrivateRelayClient")
+            .field("url", &self.conf.url.as_str())
+            .field("method", &self.conf.method)
+            .field("signer", &self.signer.as_ref().map(|s| s.address()))
+            .finish()
+    }
+}
+
+impl PrivateRelayClient {
+    /// Create a new private relay client
+    pub fn new(conf: PrivateRelayConf, signer: Option<Signers>) -> Self {
+        Self {
+            conf,
+            client: Client::new(),
+            signer,
+        }
+    }
+
+    async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> ChainResult<T> {
+        let body = build_request_body(method, params);
+
+        let mut request = self
+            .client
+            .post(self.conf.url.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        if let Some(signer) = &self.signer {
+            let header = flashbots_signature_header(signer, &body).await?;
+            request = request.header(FLASHBOTS_SIGNATURE_HEADER, header);
+        }
+
+        let response = request
+            .body(body)
+            .send()
+            .await
+            .map_err(ChainCommunicationError::from_other)?
+            .json::<JsonRpcResponse<T>>()
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+
+        match (response.result, response.error) {
+            (_, Some(error)) => Err(ChainCommunicationError::CustomError(format!(
+                "Private relay returned an error for {method}: {error}"
+            ))),
+            (Some(result), None) => Ok(result),
+            (None, None) => Err(ChainCommunicationError::CustomError(format!(
+                "Private relay returned an empty response for {method}"
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl PrivateRelay for PrivateRelayClient {
+    #[instrument(skip(self, raw_tx), fields(url = %self.conf.url))]
+    async fn send_private_transaction(
+        &self,
+        raw_tx: &Bytes,
+        max_block_number: u64,
+    ) -> ChainResult<H256> {
+        let params = json!([{
+            "tx": raw_tx,
+            "maxBlockNumber": format!("{max_block_number:#x}"),
+        }]);
+        let hash: H256 = self
+            .request(METHOD_SEND_PRIVATE_TRANSACTION, params)
+            .await?;
+        debug!(?hash, "Sent private transaction to relay");
+        Ok(hash)
+    }
+
+    #[instrument(skip(self, raw_txs), fields(url = %self.conf.url))]
+    async fn send_bundle(&self, raw_txs: &[Bytes], block_number: u64) -> ChainResult<H256> {
+        let params = json!([{
+            "txs": raw_txs,
+            "blockNumber": format!("{block_number:#x}"),
+        }]);
+        let result: SendBundleResult = self.request(METHOD_SEND_BUNDLE, params).await?;
+        debug!(bundle_hash = ?result.bundle_hash, "Sent bundle to relay");
+        Ok(result.bundle_hash)
+    }
+}
+
+fn build_request_body(method: &str, params: Value) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params,
+    })
+    .to_string()
+}
+
+/// Flashbots authenticates requests by an EIP-191 signature of the
+/// hex-encoded keccak256 hash of the request body, in the form `address:signature`.
+async fn flashbots_signature_header(signer: &Signers, body: &str) -> ChainResult<String> {
+    let digest = format!("0x{}", hex::encode(keccak256(body.as_bytes())));
+    let signature = signer
+        .sign_message(digest)
+        .await
+        .map_err(ChainCommunicationError::from_other)?;
+    Ok(format!(
+        "{:?}:0x{}",
+        signer.address(),
+        hex::encode(signature.to_vec())
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::signers::LocalWallet;
+    use ethers::types::Signature;
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_build_request_body() {
+        let body = build_request_body(METHOD_SEND_BUNDLE, json!([{ "blockNumber": "0x10" }]));
+        let value: Value = serde_json::from_str(&body).unwrap();
+
+        assert_eq!(value["jsonrpc"], "2.0");
+        assert_eq!(value["method"], METHOD_SEND_BUNDLE);
+        assert_eq!(value["params"][0]["blockNumber"], "0x10");
+    }
+
+    #[tokio::test]
+    async fn test_flashbots_signature_header_is_verifiable() {
+        let wallet = LocalWallet::from_str(
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        )
+        .unwrap();
+        let address = wallet.address();
+        let signer = Signers::from(wallet);
+        let body = build_request_body(METHOD_SEND_PRIVATE_TRANSACTION, json!([]));
+
+        let header = flashbots_signature_header(&signer, &body).await.unwrap();
+
+        let (header_address, header_signature) = header.split_once(':').unwrap();
+        assert_eq!(header_address, format!("{address:?}"));
+
+        let signature = Signature::from_str(header_signature.trim_start_matches("0x")).unwrap();
+        let digest = format!("0x{}", hex::encode(keccak256(body.as_bytes())));
+        signature.verify(digest, address).unwrap();
+    }
+
+    #[test]
+    fn test_parse_bundle_response() {
+        let response: JsonRpcResponse<SendBundleResult> = serde_json::from_str(
+            r#"{"jsonrpc":"2.0","id":1,"result":{"bundleHash":"0x2228f5d8954ce31dc1601a8ba264dbd401bf1428388ce88238932815c5d6f23f"}}"#,
+        )
+        .unwrap();
+
+        assert!(response.error.is_none());
+        assert_eq!(
+            response.result.unwrap().bundle_hash,
+            H256::from_str("0x2228f5d8954ce31dc1601a8ba264dbd401bf1428388ce88238932815c5d6f23f")
+                .unwrap()
+        );
+    }
+}
@@ -7,7 +7,9 @@ use std::time::Duration;
 use async_trait::async_trait;
 use derive_new::new;
 use ethers::prelude::Middleware;
-use ethers::types::{Block, TransactionReceipt, H160, H256 as EthersH256};
+use ethers::types::{
+    Block, Bytes, TransactionReceipt, TransactionRequest, H160, H256 as EthersH256,
+};
 use ethers_contract::{builders::ContractCall, Multicall, MulticallResult};
 use ethers_core::abi::{Address, Function};
 use ethers_core::types::transaction::eip2718::TypedTransaction;
@@ -23,6 +25,7 @@ use hyperlane_core::{
 };
 
 use crate::contracts::multicall::BatchCache;
+use crate::tx::{track_pending_tx, PENDING_TRANSACTION_POLLING_INTERVAL};
 use crate::{
     get_finalized_block_number, multicall, BuildableWithProvider, ConnectionConf,
     EthereumReorgPeriod,
@@ -120,6 +123,10 @@ pub trait EvmProviderForLander: Send + Sync {
     /// Send transaction into blockchain
     async fn send(&self, tx: &TypedTransaction, function: &Function) -> ChainResult<H256>;
 
+    /// Sign transaction without sending it, returning its hash and
+    /// RLP-encoded signed bytes
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> ChainResult<(H256, Bytes)>;
+
     /// Read-only call into blockchain which returns a boolean
     async fn check(&self, tx: &TypedTransaction, function: &Function) -> ChainResult<bool>;
 
@@ -130,6 +137,9 @@ pub trait EvmProviderForLander: Send + Sync {
         reorg_period: &EthereumReorgPeriod,
     ) -> ChainResult<U256>;
 
+    /// Get the native token balance of a given address at the latest block
+    async fn get_native_balance(&self, address: &Address) -> ChainResult<U256>;
+
     /// Get the fee history
     async fn fee_history(
         &self,
@@ -247,6 +257,26 @@ where
         Ok(pending.tx_hash().into())
     }
 
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> ChainResult<(H256, Bytes)> {
+        let mut tx = tx.clone();
+        let from = tx
+            .from()
+            .copied()
+            .or_else(|| self.provider.default_sender())
+            .ok_or(ChainCommunicationError::SignerUnavailable)?;
+        self.provider
+            .fill_transaction(&mut tx, None)
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+        let signature = self
+            .provider
+            .sign_transaction(&tx, from)
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+
+        Ok((tx.hash(&signature).into(), tx.rlp_signed(&signature)))
+    }
+
     async fn check(&self, tx: &TypedTransaction, function: &Function) -> ChainResult<bool> {
         let contract_call = self.build_contract_call::<bool>(tx.clone(), function.clone());
         let success = contract_call
@@ -275,6 +305,14 @@ where
             .map(Into::into)
     }
 
+    async fn get_native_balance(&self, address: &Address) -> ChainResult<U256> {
+        self.provider
+            .get_balance(*address, None)
+            .await
+            .map_err(ChainCommunicationError::from_other)
+            .map(Into::into)
+    }
+
     #[instrument(skip(self), ret)]
     async fn fee_history(
         &self,
@@ -531,6 +569,61 @@ impl BuildableWithProvider for LanderProviderBuilder {
     }
 }
 
+/// Transfers of the native token from the signer of the provider
+#[async_trait]
+pub trait NativeTransfer: Send + Sync {
+    /// Transfer `amount` of the native token to `recipient` and wait until the
+    /// transaction is included. Returns the transaction hash.
+    async fn transfer_native(&self, recipient: H160, amount: U256) -> ChainResult<H512>;
+}
+
+#[async_trait]
+impl<M> NativeTransfer for EthereumProvider<M>
+where
+    M: Middleware + 'static,
+{
+    #[instrument(skip(self))]
+    async fn transfer_native(&self, recipient: H160, amount: U256) -> ChainResult<H512> {
+        let tx = TransactionRequest::new()
+            .to(recipient)
+            .value(EthersU256::from(amount));
+        let pending = self
+            .provider
+            .send_transaction(tx, None)
+            .await
+            .map_err(ChainCommunicationError::from_other)?
+            .interval(PENDING_TRANSACTION_POLLING_INTERVAL);
+        let receipt = track_pending_tx(pending).await?;
+        if receipt.status != Some(1.into()) {
+            return Err(ChainCommunicationError::from_other_str(
+                "native transfer transaction reverted",
+            ));
+        }
+        Ok(H256::from(receipt.transaction_hash).into())
+    }
+}
+
+/// Builder for native token transfers, signed by the signer passed to the builder
+pub struct NativeTransferBuilder {}
+
+#[async_trait]
+impl BuildableWithProvider for NativeTransferBuilder {
+    type Output = Arc<dyn NativeTransfer>;
+    const NEEDS_SIGNER: bool = true;
+
+    async fn build_with_provider<M: Middleware + 'static>(
+        &self,
+        provider: M,
+        _conn: &ConnectionConf,
+        locator: &ContractLocator,
+    ) -> Self::Output {
+        Arc::new(EthereumProvider::new(
+            Arc::new(provider),
+            locator.domain.clone(),
+        ))
+    }
+}
+
 /// Builder for hyperlane providers.
 pub struct HyperlaneProviderBuilder {}
 
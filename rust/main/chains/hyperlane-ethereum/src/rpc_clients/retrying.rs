@@ -1,9 +1,10 @@
-use std::{fmt::Debug, str::FromStr, time::Duration};
+use std::{fmt::Debug, str::FromStr, sync::Arc, time::Duration};
 
 use crate::rpc_clients::{categorize_client_response, CategorizedResponse};
 use async_trait::async_trait;
 use ethers::providers::{Http, JsonRpcClient, ProviderError};
 use ethers_prometheus::json_rpc_client::PrometheusJsonRpcClient;
+use hyperlane_core::rpc_clients::{RpcBudgetExhausted, RpcRateLimiter};
 use hyperlane_metric::prometheus_metric::PrometheusConfigExt;
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
@@ -16,6 +17,7 @@ use tracing::{error, instrument, trace, warn, warn_span};
 pub struct RetryingProvider<P> {
     max_requests: u32,
     base_retry_ms: u64,
+    rate_limiter: Option<Arc<RpcRateLimiter>>,
     inner: P,
 }
 
@@ -26,9 +28,16 @@ impl<P> RetryingProvider<P> {
             inner,
             max_requests: max_requests.unwrap_or(6),
             base_retry_ms: base_retry_ms.unwrap_or(50),
+            rate_limiter: None,
         }
     }
 
+    /// Wait for `rate_limiter` before each request, including retries. The
+    /// wait isn't part of the latency measured by the inner provider.
+    pub fn set_rate_limiter(&mut self, rate_limiter: Arc<RpcRateLimiter>) {
+        self.rate_limiter = Some(rate_limiter);
+    }
+
     /// Set the max_requests (and by extension the total time a request can
     /// take).
     pub fn set_max_requests(&mut self, max_requests: u32) {
@@ -101,6 +110,10 @@ where
             }
             trace!(attempt = i, params = %serde_json::to_string(&params).unwrap_or_default(), "Dispatching request");
 
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire(method).await?;
+            }
+
             let fut = match params {
                 Value::Null => self.inner.request(method, ()),
                 _ => self.inner.request(method, &params),
@@ -155,6 +168,10 @@ where
     /// Hit max requests
     #[error("Hit max requests")]
     MaxRequests(Option<P::Error>),
+    /// The daily budget of the provider is exhausted, so the request wasn't
+    /// sent
+    #[error(transparent)]
+    BudgetExhausted(#[from] RpcBudgetExhausted),
 }
 
 impl<P> From<RetryingProviderError<P>> for ProviderError
@@ -14,8 +14,8 @@ use ethers::prelude::{
 };
 use ethers::types::Address;
 use ethers_signers::Signer;
-use hyperlane_core::rpc_clients::FallbackProvider;
-use hyperlane_metric::utils::url_to_host_info;
+use hyperlane_core::rpc_clients::{FallbackProvider, RpcRateLimiter, RpcRateLimiterMetrics};
+use hyperlane_metric::utils::{url_to_host_info, urls_to_provider_nodes};
 use reqwest::{Client, Url};
 use reqwest_utils::parse_custom_rpc_headers;
 use thiserror::Error;
@@ -102,8 +102,13 @@ pub trait BuildableWithProvider {
                         &client_metrics,
                         &middleware_metrics,
                     );
-                    let retrying_provider =
+                    let mut retrying_provider =
                         RetryingProvider::new(metrics_provider, Some(5), Some(1000));
+                    if let Some(rate_limiter) =
+                        build_rate_limiter(url, conn, &client_metrics, &middleware_metrics)
+                    {
+                        retrying_provider.set_rate_limiter(rate_limiter);
+                    }
                     let weighted_provider = WeightedProvider::new(retrying_provider);
                     builder = builder.add_provider(weighted_provider);
                 }
@@ -111,7 +116,18 @@ pub trait BuildableWithProvider {
                 self.build(quorum_provider, conn, locator, signer).await?
             }
             RpcConnectionConf::HttpFallback { urls } => {
-                let mut builder = FallbackProvider::builder();
+                let chain = middleware_metrics
+                    .as_ref()
+                    .and_then(|(_, conf)| conf.chain.clone());
+                let mut builder = FallbackProvider::builder()
+                    .with_conf(&conn.rpc_fallback)
+                    .with_score_metric(
+                        client_metrics
+                            .as_ref()
+                            .and_then(|metrics| metrics.provider_score.clone()),
+                        PrometheusConfig::chain_name(&chain),
+                        urls_to_provider_nodes(urls),
+                    );
                 for url in urls {
                     let http_provider = build_http_provider(url.clone())?;
                     let metrics_provider = self.wrap_rpc_with_metrics(
@@ -120,7 +136,11 @@ pub trait BuildableWithProvider {
                         &client_metrics,
                         &middleware_metrics,
                     );
-                    builder = builder.add_provider(metrics_provider);
+                    // The fallback provider waits for the rate limit before it
+                    // times the request
+                    let rate_limiter =
+                        build_rate_limiter(url, conn, &client_metrics, &middleware_metrics);
+                    builder = builder.add_rate_limited_provider(metrics_provider, rate_limiter);
                 }
                 let fallback_provider = builder.build();
                 let ethereum_fallback_provider = EthereumFallbackProvider::<
@@ -141,7 +161,13 @@ pub trait BuildableWithProvider {
                     &client_metrics,
                     &middleware_metrics,
                 );
-                let retrying_http_provider = RetryingProvider::new(metrics_provider, None, None);
+                let mut retrying_http_provider =
+                    RetryingProvider::new(metrics_provider, None, None);
+                if let Some(rate_limiter) =
+                    build_rate_limiter(url, conn, &client_metrics, &middleware_metrics)
+                {
+                    retrying_http_provider.set_rate_limiter(rate_limiter);
+                }
                 self.build(retrying_http_provider, conn, locator, signer)
                     .await?
             }
@@ -324,6 +350,35 @@ fn build_http_provider(url: Url) -> ChainResult<Http> {
     Ok(Http::new_with_client(url, client))
 }
 
+/// Gets the rate limiter shared by all providers of the URL, if it has a rate
+/// limit.
+fn build_rate_limiter(
+    url: &Url,
+    conn: &ConnectionConf,
+    client_metrics: &Option<PrometheusClientMetrics>,
+    middleware_metrics: &Option<(MiddlewareMetrics, PrometheusMiddlewareConf)>,
+) -> Option<Arc<RpcRateLimiter>> {
+    let rate_limit = conn
+        .rpc_rate_limits
+        .get(url)
+        .filter(|rate_limit| rate_limit.is_limited())?;
+    Some(RpcRateLimiter::shared(url.as_str(), rate_limit, || {
+        let chain = middleware_metrics
+            .as_ref()
+            .and_then(|(_, conf)| conf.chain.clone());
+        let labels = [
+            url_to_host_info(url).unwrap_or_else(|| "unknown".to_owned()),
+            PrometheusConfig::chain_name(&chain).to_owned(),
+        ];
+        let metrics = client_metrics.as_ref();
+        RpcRateLimiterMetrics::with_labels(
+            metrics.and_then(|m| m.rpc_remaining_daily_credits.as_ref()),
+            metrics.and_then(|m| m.rpc_throttled_seconds.as_ref()),
+            &labels.each_ref().map(String::as_str),
+        )
+    }))
+}
+
 /// Gets a cached reqwest client for the given URL, or builds a new one if it doesn't exist.
 fn get_reqwest_client(url: &Url) -> ChainResult<Client> {
     let client_cache = get_reqwest_client_cache();
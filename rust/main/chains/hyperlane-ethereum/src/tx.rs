@@ -65,7 +65,7 @@ pub fn apply_gas_estimate_buffer(gas: U256, domain: &HyperlaneDomain) -> ChainRe
     Ok(gas.saturating_add(GAS_LIMIT_BUFFER.into()))
 }
 
-const PENDING_TRANSACTION_POLLING_INTERVAL: Duration = Duration::from_secs(2);
+pub(crate) const PENDING_TRANSACTION_POLLING_INTERVAL: Duration = Duration::from_secs(2);
 const EVM_RELAYER_ADDRESS: &str = "0x74cae0ecc47b02ed9b9d32e000fd70b9417970c5";
 
 /// Dispatches a transaction, logs the tx id, and returns the result
@@ -0,0 +1,131 @@
+use std::io::Cursor;
+
+use async_trait::async_trait;
+use derive_new::new;
+use tracing::trace;
+
+use hyperlane_core::{Decode, HyperlaneMessage, U256};
+use hyperlane_operation_verifier::{
+    ApplicationOperationVerifier, ApplicationOperationVerifierReport,
+};
+use hyperlane_warp_route::TokenMessage;
+
+const WARP_ROUTE_MARKER: &str = "/";
+
+/// Application operation verifier for Fuel
+#[derive(new)]
+pub struct FuelApplicationOperationVerifier {}
+
+#[async_trait]
+impl ApplicationOperationVerifier for FuelApplicationOperationVerifier {
+    async fn verify(
+        &self,
+        app_context: &Option<String>,
+        message: &HyperlaneMessage,
+    ) -> Option<ApplicationOperationVerifierReport> {
+        trace!(
+            ?app_context,
+            ?message,
+            "Fuel application operation verifier",
+        );
+
+        Self::verify_message(app_context, message)
+    }
+}
+
+impl FuelApplicationOperationVerifier {
+    fn verify_message(
+        app_context: &Option<String>,
+        message: &HyperlaneMessage,
+    ) -> Option<ApplicationOperationVerifierReport> {
+        use ApplicationOperationVerifierReport::MalformedMessage;
+
+        let context = match app_context {
+            Some(c) => c,
+            None => return None,
+        };
+
+        if !context.contains(WARP_ROUTE_MARKER) {
+            return None;
+        }
+
+        // Starting from this point we assume that we are in a warp route context
+
+        let mut reader = Cursor::new(message.body.as_slice());
+        let token_message = match TokenMessage::read_from(&mut reader) {
+            Ok(m) => m,
+            Err(_) => return Some(MalformedMessage(message.clone())),
+        };
+
+        // Native asset amounts on Fuel are u64, larger amounts can never be delivered
+        if token_message.amount() > U256::from(u64::MAX) {
+            return Some(MalformedMessage(message.clone()));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyperlane_core::{Encode, H256};
+
+    use super::*;
+
+    fn message_with_amount(amount: U256) -> HyperlaneMessage {
+        let token_message = TokenMessage::new(H256::zero(), amount, vec![]);
+        HyperlaneMessage {
+            body: token_message.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_non_warp_route_context() {
+        let message = HyperlaneMessage::default();
+        let result = FuelApplicationOperationVerifier::verify_message(
+            &Some("some_context".to_string()),
+            &message,
+        );
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_valid_amount() {
+        let message = message_with_amount(U256::from(u64::MAX));
+        let result = FuelApplicationOperationVerifier::verify_message(
+            &Some("warp/route".to_string()),
+            &message,
+        );
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_amount_exceeds_u64() {
+        let message = message_with_amount(U256::from(u64::MAX).saturating_add(U256::one()));
+        let result = FuelApplicationOperationVerifier::verify_message(
+            &Some("warp/route".to_string()),
+            &message,
+        );
+        assert!(matches!(
+            result,
+            Some(ApplicationOperationVerifierReport::MalformedMessage(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_token_message() {
+        let message = HyperlaneMessage {
+            body: vec![0u8; 16],
+            ..Default::default()
+        };
+        let result = FuelApplicationOperationVerifier::verify_message(
+            &Some("warp/route".to_string()),
+            &message,
+        );
+        assert!(matches!(
+            result,
+            Some(ApplicationOperationVerifierReport::MalformedMessage(_))
+        ));
+    }
+}
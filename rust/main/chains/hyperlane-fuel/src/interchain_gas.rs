@@ -1,29 +1,63 @@
 use std::ops::RangeInclusive;
 
 use async_trait::async_trait;
+use fuels::prelude::{Bech32ContractId, WalletUnlocked};
 
 use hyperlane_core::{
-    ChainResult, HyperlaneChain, HyperlaneContract, Indexed, Indexer, InterchainGasPaymaster,
+    ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
+    HyperlaneProvider, Indexed, Indexer, InterchainGasPaymaster, InterchainGasPayment, LogMeta,
+    SequenceAwareIndexer, H256, U256,
+};
+
+use crate::{
+    contracts::interchain_gas_paymaster::{
+        GasPaymentEvent, InterchainGasPaymaster as FuelInterchainGasPaymasterInner,
+    },
+    conversions::*,
+    ConnectionConf, FuelProvider,
 };
-use hyperlane_core::{HyperlaneDomain, HyperlaneProvider, InterchainGasPayment, LogMeta, H256};
 
 /// A reference to an IGP contract on some Fuel chain
 #[derive(Debug)]
-pub struct FuelInterchainGasPaymaster {}
+pub struct FuelInterchainGasPaymaster {
+    contract: FuelInterchainGasPaymasterInner<WalletUnlocked>,
+    domain: HyperlaneDomain,
+    provider: FuelProvider,
+}
+
+impl FuelInterchainGasPaymaster {
+    /// Create a new fuel IGP
+    pub async fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator<'_>,
+        mut wallet: WalletUnlocked,
+    ) -> ChainResult<Self> {
+        let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
+
+        wallet.set_provider(fuel_provider.provider().clone());
+        let address = Bech32ContractId::from_h256(&locator.address);
+
+        Ok(FuelInterchainGasPaymaster {
+            contract: FuelInterchainGasPaymasterInner::new(address, wallet),
+            domain: locator.domain.clone(),
+            provider: fuel_provider,
+        })
+    }
+}
 
 impl HyperlaneContract for FuelInterchainGasPaymaster {
     fn address(&self) -> H256 {
-        todo!()
+        self.contract.contract_id().into_h256()
     }
 }
 
 impl HyperlaneChain for FuelInterchainGasPaymaster {
     fn domain(&self) -> &HyperlaneDomain {
-        todo!()
+        &self.domain
     }
 
     fn provider(&self) -> Box<dyn HyperlaneProvider> {
-        todo!()
+        Box::new(self.provider.clone())
     }
 }
 
@@ -31,7 +65,41 @@ impl InterchainGasPaymaster for FuelInterchainGasPaymaster {}
 
 /// Struct that retrieves event data for a Fuel IGP contract
 #[derive(Debug)]
-pub struct FuelInterchainGasPaymasterIndexer {}
+pub struct FuelInterchainGasPaymasterIndexer {
+    contract: FuelInterchainGasPaymasterInner<WalletUnlocked>,
+    provider: FuelProvider,
+}
+
+impl FuelInterchainGasPaymasterIndexer {
+    /// Create a new fuel IGP indexer
+    pub async fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator<'_>,
+        mut wallet: WalletUnlocked,
+    ) -> ChainResult<Self> {
+        let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
+
+        wallet.set_provider(fuel_provider.provider().clone());
+        let address = Bech32ContractId::from_h256(&locator.address);
+        let contract = FuelInterchainGasPaymasterInner::new(address, wallet);
+
+        Ok(FuelInterchainGasPaymasterIndexer {
+            contract,
+            provider: fuel_provider,
+        })
+    }
+}
+
+impl From<GasPaymentEvent> for InterchainGasPayment {
+    fn from(event: GasPaymentEvent) -> Self {
+        InterchainGasPayment {
+            message_id: event.message_id.into_h256(),
+            destination: event.destination_domain,
+            payment: U256::from(event.payment),
+            gas_amount: U256::from(event.gas_amount),
+        }
+    }
+}
 
 #[async_trait]
 impl Indexer<InterchainGasPayment> for FuelInterchainGasPaymasterIndexer {
@@ -39,10 +107,96 @@ impl Indexer<InterchainGasPayment> for FuelInterchainGasPaymasterIndexer {
         &self,
         range: RangeInclusive<u32>,
     ) -> ChainResult<Vec<(Indexed<InterchainGasPayment>, LogMeta)>> {
-        todo!()
+        let logs = self
+            .provider
+            .index_contract_logs_in_range::<GasPaymentEvent>(
+                range,
+                self.contract.contract_id(),
+                &self.contract.log_decoder(),
+            )
+            .await?;
+
+        Ok(logs
+            .into_iter()
+            .map(|(event, log_meta)| (InterchainGasPayment::from(event).into(), log_meta))
+            .collect())
     }
 
     async fn get_finalized_block_number(&self) -> ChainResult<u32> {
-        todo!()
+        self.provider.get_finalized_block_number().await
+    }
+}
+
+#[async_trait]
+impl SequenceAwareIndexer<InterchainGasPayment> for FuelInterchainGasPaymasterIndexer {
+    async fn latest_sequence_count_and_tip(&self) -> ChainResult<(Option<u32>, u32)> {
+        let tip = self.get_finalized_block_number().await?;
+
+        // No sequence for gas payments.
+        Ok((None, tip))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels::{
+        tx::Receipt,
+        types::{Bits256, ContractId},
+    };
+
+    use crate::{
+        provider::decode_contract_logs,
+        test_utils::{abi_log_ids, log_data_receipt},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_decode_gas_payments() {
+        let contract_id = ContractId::new([1; 32]);
+        let contract = FuelInterchainGasPaymasterInner::new(
+            Bech32ContractId::from(contract_id),
+            WalletUnlocked::new_random(None),
+        );
+        // The ABI isn't compiler output yet, see `update_abis.sh`, so this
+        // only checks decoding, not the log id of the deployed contract
+        let log_id = abi_log_ids(include_str!("../abis/InterchainGasPaymaster.abi.json"))
+            ["struct GasPaymentEvent"];
+        let event = GasPaymentEvent {
+            message_id: Bits256([3; 32]),
+            destination_domain: 1234,
+            gas_amount: 200_000,
+            payment: 42,
+        };
+        let receipts = vec![
+            Receipt::Return {
+                id: contract_id,
+                val: 0,
+                pc: 0,
+                is: 0,
+            },
+            // Same event logged by another contract
+            log_data_receipt(ContractId::new([2; 32]), log_id, event.clone()),
+            log_data_receipt(contract_id, log_id, event),
+        ];
+
+        let logs = decode_contract_logs::<GasPaymentEvent>(
+            &receipts,
+            &contract_id,
+            &contract.log_decoder(),
+        );
+
+        assert_eq!(logs.len(), 1);
+        let (log_index, log) = logs.into_iter().next().unwrap();
+        assert_eq!(log_index, 2);
+        assert_eq!(
+            InterchainGasPayment::from(log),
+            InterchainGasPayment {
+                message_id: H256([3; 32]),
+                destination: 1234,
+                payment: U256::from(42),
+                gas_amount: U256::from(200_000),
+            }
+        );
     }
 }
@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use fuels::{
+    prelude::{Bech32ContractId, WalletUnlocked},
+    types::Bytes,
+};
+
+use hyperlane_core::{
+    ChainCommunicationError, ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract,
+    HyperlaneDomain, HyperlaneMessage, HyperlaneProvider, InterchainSecurityModule, Metadata,
+    ModuleType, RawHyperlaneMessage, H256, U256,
+};
+
+use crate::{
+    contracts::interchain_security_module::{
+        InterchainSecurityModule as FuelInterchainSecurityModuleInner, ModuleType as FuelModuleType,
+    },
+    conversions::*,
+    ConnectionConf, FuelProvider,
+};
+
+/// A reference to any ISM contract on some Fuel chain
+#[derive(Debug)]
+pub struct FuelInterchainSecurityModule {
+    contract: FuelInterchainSecurityModuleInner<WalletUnlocked>,
+    domain: HyperlaneDomain,
+    provider: FuelProvider,
+}
+
+impl FuelInterchainSecurityModule {
+    /// Create a new fuel ISM
+    pub async fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator<'_>,
+        mut wallet: WalletUnlocked,
+    ) -> ChainResult<Self> {
+        let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
+
+        wallet.set_provider(fuel_provider.provider().clone());
+        let address = Bech32ContractId::from_h256(&locator.address);
+
+        Ok(FuelInterchainSecurityModule {
+            contract: FuelInterchainSecurityModuleInner::new(address, wallet),
+            domain: locator.domain.clone(),
+            provider: fuel_provider,
+        })
+    }
+}
+
+impl HyperlaneContract for FuelInterchainSecurityModule {
+    fn address(&self) -> H256 {
+        self.contract.contract_id().into_h256()
+    }
+}
+
+impl HyperlaneChain for FuelInterchainSecurityModule {
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+impl From<FuelModuleType> for ModuleType {
+    fn from(module_type: FuelModuleType) -> Self {
+        match module_type {
+            FuelModuleType::UNUSED => ModuleType::Unused,
+            FuelModuleType::ROUTING => ModuleType::Routing,
+            FuelModuleType::AGGREGATION => ModuleType::Aggregation,
+            FuelModuleType::LEGACY_MULTISIG => ModuleType::LegacyMultisig,
+            FuelModuleType::MERKLE_ROOT_MULTISIG => ModuleType::MerkleRootMultisig,
+            FuelModuleType::MESSAGE_ID_MULTISIG => ModuleType::MessageIdMultisig,
+            FuelModuleType::NULL => ModuleType::Null,
+            FuelModuleType::CCIP_READ => ModuleType::CcipRead,
+        }
+    }
+}
+
+#[async_trait]
+impl InterchainSecurityModule for FuelInterchainSecurityModule {
+    async fn module_type(&self) -> ChainResult<ModuleType> {
+        self.contract
+            .methods()
+            .module_type()
+            .simulate()
+            .await
+            .map(|r| r.value.into())
+            .map_err(ChainCommunicationError::from_other)
+    }
+
+    async fn dry_run_verify(
+        &self,
+        message: &HyperlaneMessage,
+        metadata: &Metadata,
+    ) -> ChainResult<Option<U256>> {
+        let call_res = self
+            .contract
+            .methods()
+            .verify(
+                Bytes(metadata.to_vec()),
+                Bytes(RawHyperlaneMessage::from(message)),
+            )
+            .simulate()
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+
+        Ok(call_res.value.then(|| call_res.gas_used.into()))
+    }
+}
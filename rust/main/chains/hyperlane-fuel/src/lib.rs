@@ -7,19 +7,24 @@
 #![deny(clippy::arithmetic_side_effects)]
 
 pub use self::{
-    interchain_gas::*, mailbox::*, multisig_ism::*, provider::*, routing_ism::*, trait_builder::*,
-    validator_announce::*,
+    interchain_gas::*, interchain_security_module::*, mailbox::*, merkle_tree_hook::*,
+    multisig_ism::*, provider::*, routing_ism::*, trait_builder::*,
 };
 
+/// Hyperlane Application specific functionality
+pub mod application;
 mod contracts;
 mod conversions;
 mod interchain_gas;
+mod interchain_security_module;
 mod mailbox;
+mod merkle_tree_hook;
 mod multisig_ism;
 mod provider;
 mod routing_ism;
+#[cfg(test)]
+mod test_utils;
 mod trait_builder;
-mod validator_announce;
 
 /// Safe default imports of commonly used traits/types.
 pub mod prelude {
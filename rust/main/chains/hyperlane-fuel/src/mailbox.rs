@@ -1,8 +1,11 @@
 use crate::{
-    contracts::mailbox::Mailbox as FuelMailboxInner, conversions::*, ConnectionConf, FuelProvider,
+    contracts::mailbox::{Mailbox as FuelMailboxInner, ProcessEvent},
+    conversions::*,
+    ConnectionConf, FuelProvider,
 };
 use async_trait::async_trait;
 use fuels::{
+    core::codec::encode_fn_selector,
     prelude::{Bech32ContractId, WalletUnlocked},
     tx::{Receipt, ScriptExecutionResult},
     types::{transaction::TxPolicies, Bytes},
@@ -25,17 +28,21 @@ pub struct FuelMailbox {
     contract: FuelMailboxInner<WalletUnlocked>,
     provider: FuelProvider,
     domain: HyperlaneDomain,
+    has_signer: bool,
 }
 
 impl FuelMailbox {
-    /// Create a new fuel mailbox
+    /// Create a new fuel mailbox. Without a signer it can only be read from,
+    /// and processing messages fails.
     pub async fn new(
         conf: &ConnectionConf,
         locator: ContractLocator<'_>,
-        mut wallet: WalletUnlocked,
+        signer: Option<WalletUnlocked>,
     ) -> ChainResult<Self> {
         let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
 
+        let has_signer = signer.is_some();
+        let mut wallet = signer.unwrap_or_else(|| WalletUnlocked::new_random(None));
         wallet.set_provider(fuel_provider.provider().clone());
         let address = Bech32ContractId::from_h256(&locator.address);
 
@@ -43,6 +50,7 @@ impl FuelMailbox {
             contract: FuelMailboxInner::new(address, wallet),
             domain: locator.domain.clone(),
             provider: fuel_provider,
+            has_signer,
         })
     }
 }
@@ -131,6 +139,10 @@ impl Mailbox for FuelMailbox {
         metadata: &Metadata,
         tx_gas_limit: Option<U256>,
     ) -> ChainResult<TxOutcome> {
+        if !self.has_signer {
+            return Err(ChainCommunicationError::SignerUnavailable);
+        }
+
         // The max gas limit per transaction is 30000000 so it should always be safe to convert to u64
         let tx_policies = match tx_gas_limit {
             Some(gas_limit) if gas_limit <= U256::from(u64::MAX) => {
@@ -215,14 +227,17 @@ impl Mailbox for FuelMailbox {
     async fn process_calldata(
         &self,
         _message: &HyperlaneMessage,
-        metadata: &Metadata,
+        _metadata: &Metadata,
     ) -> ChainResult<Vec<u8>> {
-        // Seems like this is not needed for Fuel, as it's only used in mocks
-        todo!()
+        // Only used to batch `process` calls through a multicall contract, which Fuel doesn't have
+        Err(ChainCommunicationError::from_other_str(
+            "Fuel does not support batching process calls",
+        ))
     }
 
-    fn delivered_calldata(&self, message_id: H256) -> ChainResult<Option<Vec<u8>>> {
-        todo!()
+    fn delivered_calldata(&self, _message_id: H256) -> ChainResult<Option<Vec<u8>>> {
+        // No multicall contract to batch `delivered` queries through
+        Ok(None)
     }
 }
 
@@ -238,10 +253,11 @@ impl FuelMailboxIndexer {
     pub async fn new(
         conf: &ConnectionConf,
         locator: ContractLocator<'_>,
-        wallet: WalletUnlocked,
+        mut wallet: WalletUnlocked,
     ) -> ChainResult<Self> {
         let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
 
+        wallet.set_provider(fuel_provider.provider().clone());
         let address = Bech32ContractId::from_h256(&locator.address);
         let contract = FuelMailboxInner::new(address, wallet);
 
@@ -275,7 +291,19 @@ impl Indexer<H256> for FuelMailboxIndexer {
         &self,
         range: RangeInclusive<u32>,
     ) -> ChainResult<Vec<(Indexed<H256>, LogMeta)>> {
-        todo!() // Needed for scraper
+        let logs = self
+            .provider
+            .index_contract_logs_in_range::<ProcessEvent>(
+                range,
+                self.contract.contract_id(),
+                &self.contract.log_decoder(),
+            )
+            .await?;
+
+        Ok(logs
+            .into_iter()
+            .map(|(event, log_meta)| (event.message_id.into_h256().into(), log_meta))
+            .collect())
     }
 
     async fn get_finalized_block_number(&self) -> ChainResult<u32> {
@@ -310,14 +338,98 @@ impl SequenceAwareIndexer<HyperlaneMessage> for FuelMailboxIndexer {
     }
 }
 
-#[allow(dead_code)] // TODO: Remove this once the FuelMailboxAbi is implemented
-struct FuelMailboxAbi;
+/// ABI information for the Fuel Mailbox contract
+pub struct FuelMailboxAbi;
+
+impl FuelMailboxAbi {
+    /// Names of the functions exposed by the Mailbox contract
+    const FUNCTION_NAMES: [&'static str; 23] = [
+        "default_hook",
+        "default_ism",
+        "delivered",
+        "dispatch",
+        "initialize",
+        "latest_dispatched_id",
+        "local_domain",
+        "nonce",
+        "process",
+        "quote_dispatch",
+        "recipient_ism",
+        "required_hook",
+        "set_default_hook",
+        "set_default_ism",
+        "set_required_hook",
+        "is_paused",
+        "pause",
+        "unpause",
+        "initialize_ownership",
+        "only_owner",
+        "owner",
+        "renounce_ownership",
+        "transfer_ownership",
+    ];
+}
 
 impl HyperlaneAbi for FuelMailboxAbi {
     const SELECTOR_SIZE_BYTES: usize = 8;
 
+    /// Fuel selectors are the length-prefixed function name rather than a
+    /// fixed size hash; `SELECTOR_SIZE_BYTES` is the size of the prefix.
     fn fn_map() -> HashMap<Vec<u8>, &'static str> {
-        // Can't support this without Fuels exporting it in the generated code
-        todo!()
+        Self::FUNCTION_NAMES
+            .into_iter()
+            .map(|name| (encode_fn_selector(name), name))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels::types::{Bits256, ContractId};
+
+    use crate::{
+        provider::decode_contract_logs,
+        test_utils::{log_data_receipt, sway_log_id},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_decode_process_events() {
+        let contract_id = ContractId::new([1; 32]);
+        let contract = FuelMailboxInner::new(
+            Bech32ContractId::from(contract_id),
+            WalletUnlocked::new_random(None),
+        );
+        let log_id = sway_log_id("struct interfaces::events::ProcessEvent");
+        let event = ProcessEvent {
+            message_id: Bits256([5; 32]),
+            origin: 1234,
+            sender: Bits256([6; 32]),
+            recipient: Bits256([7; 32]),
+        };
+        let receipts = vec![log_data_receipt(contract_id, log_id, event)];
+
+        let delivered =
+            decode_contract_logs::<ProcessEvent>(&receipts, &contract_id, &contract.log_decoder())
+                .into_iter()
+                .map(|(_, event)| event.message_id.into_h256())
+                .collect::<Vec<_>>();
+
+        assert_eq!(delivered, vec![H256([5; 32])]);
+    }
+
+    #[test]
+    fn test_fn_map_covers_abi() {
+        let abi: serde_json::Value =
+            serde_json::from_str(include_str!("../abis/Mailbox.abi.json")).unwrap();
+        let fn_map = FuelMailboxAbi::fn_map();
+
+        let functions = abi["functions"].as_array().unwrap();
+        assert_eq!(fn_map.len(), functions.len());
+        for function in functions {
+            let name = function["name"].as_str().unwrap();
+            assert_eq!(fn_map.get(&encode_fn_selector(name)), Some(&name));
+        }
     }
 }
@@ -0,0 +1,306 @@
+use std::ops::RangeInclusive;
+
+use async_trait::async_trait;
+use fuels::prelude::{Bech32ContractId, WalletUnlocked};
+
+use hyperlane_core::{
+    accumulator::incremental::IncrementalMerkle, ChainCommunicationError, ChainResult, Checkpoint,
+    CheckpointAtBlock, ContractLocator, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
+    HyperlaneProvider, IncrementalMerkleAtBlock, Indexed, Indexer, LogMeta, MerkleTreeHook,
+    MerkleTreeInsertion, ReorgPeriod, SequenceAwareIndexer, H256,
+};
+use tracing::instrument;
+
+use crate::{
+    contracts::merkle_tree_hook::{
+        InsertedIntoTreeEvent, MerkleTree, MerkleTreeHook as FuelMerkleTreeHookInner,
+    },
+    conversions::*,
+    ConnectionConf, FuelProvider,
+};
+
+/// A reference to a MerkleTreeHook contract on some Fuel chain
+#[derive(Debug)]
+pub struct FuelMerkleTreeHook {
+    contract: FuelMerkleTreeHookInner<WalletUnlocked>,
+    domain: HyperlaneDomain,
+    provider: FuelProvider,
+}
+
+impl FuelMerkleTreeHook {
+    /// Create a new fuel merkle tree hook
+    pub async fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator<'_>,
+        mut wallet: WalletUnlocked,
+    ) -> ChainResult<Self> {
+        let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
+
+        wallet.set_provider(fuel_provider.provider().clone());
+        let address = Bech32ContractId::from_h256(&locator.address);
+
+        Ok(FuelMerkleTreeHook {
+            contract: FuelMerkleTreeHookInner::new(address, wallet),
+            domain: locator.domain.clone(),
+            provider: fuel_provider,
+        })
+    }
+}
+
+impl HyperlaneContract for FuelMerkleTreeHook {
+    fn address(&self) -> H256 {
+        self.contract.contract_id().into_h256()
+    }
+}
+
+impl HyperlaneChain for FuelMerkleTreeHook {
+    fn domain(&self) -> &HyperlaneDomain {
+        &self.domain
+    }
+
+    fn provider(&self) -> Box<dyn HyperlaneProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+/// Fuel contracts can only be simulated against the latest state, so any
+/// reorg period other than none is rejected instead of being ignored.
+fn ensure_latest(reorg_period: &ReorgPeriod) -> ChainResult<()> {
+    if reorg_period.is_none() {
+        Ok(())
+    } else {
+        Err(ChainCommunicationError::InvalidReorgPeriod(
+            reorg_period.clone(),
+        ))
+    }
+}
+
+impl TryFrom<MerkleTree> for IncrementalMerkle {
+    type Error = ChainCommunicationError;
+
+    fn try_from(tree: MerkleTree) -> Result<Self, Self::Error> {
+        Ok(IncrementalMerkle {
+            branch: tree.branch.map(|node| node.into_h256()),
+            count: usize::try_from(tree.count).map_err(ChainCommunicationError::from_other)?,
+        })
+    }
+}
+
+#[async_trait]
+impl MerkleTreeHook for FuelMerkleTreeHook {
+    #[instrument(level = "debug", err, ret, skip(self))]
+    #[allow(clippy::blocks_in_conditions)] // TODO: `rustc` 1.80.1 clippy issue
+    async fn tree(&self, reorg_period: &ReorgPeriod) -> ChainResult<IncrementalMerkleAtBlock> {
+        ensure_latest(reorg_period)?;
+        let tree = self
+            .contract
+            .methods()
+            .tree()
+            .simulate()
+            .await
+            .map_err(ChainCommunicationError::from_other)?
+            .value;
+
+        Ok(IncrementalMerkleAtBlock {
+            tree: tree.try_into()?,
+            block_height: None,
+        })
+    }
+
+    #[instrument(level = "debug", err, ret, skip(self))]
+    #[allow(clippy::blocks_in_conditions)] // TODO: `rustc` 1.80.1 clippy issue
+    async fn count(&self, reorg_period: &ReorgPeriod) -> ChainResult<u32> {
+        ensure_latest(reorg_period)?;
+        self.contract
+            .methods()
+            .count()
+            .simulate()
+            .await
+            .map(|r| r.value)
+            .map_err(ChainCommunicationError::from_other)
+    }
+
+    #[instrument(level = "debug", err, ret, skip(self))]
+    #[allow(clippy::blocks_in_conditions)] // TODO: `rustc` 1.80.1 clippy issue
+    async fn latest_checkpoint(
+        &self,
+        reorg_period: &ReorgPeriod,
+    ) -> ChainResult<CheckpointAtBlock> {
+        ensure_latest(reorg_period)?;
+        let (root, index) = self
+            .contract
+            .methods()
+            .latest_checkpoint()
+            .simulate()
+            .await
+            .map_err(ChainCommunicationError::from_other)?
+            .value;
+
+        Ok(CheckpointAtBlock {
+            checkpoint: Checkpoint {
+                merkle_tree_hook_address: self.address(),
+                mailbox_domain: self.domain.id(),
+                root: root.into_h256(),
+                index,
+            },
+            block_height: None,
+        })
+    }
+
+    async fn latest_checkpoint_at_block(&self, _height: u64) -> ChainResult<CheckpointAtBlock> {
+        Err(ChainCommunicationError::from_other_str(
+            "Fuel does not support querying point-in-time",
+        ))
+    }
+}
+
+/// Struct that retrieves event data for a Fuel MerkleTreeHook contract
+#[derive(Debug)]
+pub struct FuelMerkleTreeHookIndexer {
+    contract: FuelMerkleTreeHookInner<WalletUnlocked>,
+    provider: FuelProvider,
+}
+
+impl FuelMerkleTreeHookIndexer {
+    /// Create a new fuel merkle tree hook indexer
+    pub async fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator<'_>,
+        mut wallet: WalletUnlocked,
+    ) -> ChainResult<Self> {
+        let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
+
+        wallet.set_provider(fuel_provider.provider().clone());
+        let address = Bech32ContractId::from_h256(&locator.address);
+
+        Ok(FuelMerkleTreeHookIndexer {
+            contract: FuelMerkleTreeHookInner::new(address, wallet),
+            provider: fuel_provider,
+        })
+    }
+}
+
+impl From<InsertedIntoTreeEvent> for MerkleTreeInsertion {
+    fn from(event: InsertedIntoTreeEvent) -> Self {
+        MerkleTreeInsertion::new(event.index, event.message_id.into_h256())
+    }
+}
+
+#[async_trait]
+impl Indexer<MerkleTreeInsertion> for FuelMerkleTreeHookIndexer {
+    async fn fetch_logs_in_range(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> ChainResult<Vec<(Indexed<MerkleTreeInsertion>, LogMeta)>> {
+        let logs = self
+            .provider
+            .index_contract_logs_in_range::<InsertedIntoTreeEvent>(
+                range,
+                self.contract.contract_id(),
+                &self.contract.log_decoder(),
+            )
+            .await?;
+
+        Ok(logs
+            .into_iter()
+            .map(|(event, log_meta)| {
+                let insertion = MerkleTreeInsertion::from(event);
+                let sequence = insertion.index();
+                (Indexed::new(insertion).with_sequence(sequence), log_meta)
+            })
+            .collect())
+    }
+
+    async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+        self.provider.get_finalized_block_number().await
+    }
+}
+
+#[async_trait]
+impl SequenceAwareIndexer<MerkleTreeInsertion> for FuelMerkleTreeHookIndexer {
+    async fn latest_sequence_count_and_tip(&self) -> ChainResult<(Option<u32>, u32)> {
+        let tip = self.get_finalized_block_number().await?;
+
+        self.contract
+            .methods()
+            .count()
+            .simulate()
+            .await
+            .map(|r| (Some(r.value), tip))
+            .map_err(ChainCommunicationError::from_other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels::types::{Bits256, ContractId};
+
+    use crate::{
+        provider::decode_contract_logs,
+        test_utils::{abi_log_ids, log_data_receipt},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_decode_insertions() {
+        let contract_id = ContractId::new([1; 32]);
+        let contract = FuelMerkleTreeHookInner::new(
+            Bech32ContractId::from(contract_id),
+            WalletUnlocked::new_random(None),
+        );
+        // The ABI isn't compiler output yet, see `update_abis.sh`, so this
+        // only checks decoding, not the log id of the deployed contract
+        let log_id = abi_log_ids(include_str!("../abis/MerkleTreeHook.abi.json"))
+            ["struct InsertedIntoTreeEvent"];
+        let receipts = (0..3)
+            .map(|index| {
+                let event = InsertedIntoTreeEvent {
+                    message_id: Bits256([index as u8; 32]),
+                    index,
+                };
+                log_data_receipt(contract_id, log_id, event)
+            })
+            .collect::<Vec<_>>();
+
+        let insertions = decode_contract_logs::<InsertedIntoTreeEvent>(
+            &receipts,
+            &contract_id,
+            &contract.log_decoder(),
+        )
+        .into_iter()
+        .map(|(_, event)| MerkleTreeInsertion::from(event))
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            insertions,
+            vec![
+                MerkleTreeInsertion::new(0, H256([0; 32])),
+                MerkleTreeInsertion::new(1, H256([1; 32])),
+                MerkleTreeInsertion::new(2, H256([2; 32])),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reorg_period_is_rejected() {
+        assert!(ensure_latest(&ReorgPeriod::None).is_ok());
+        assert!(matches!(
+            ensure_latest(&ReorgPeriod::from_blocks(5)),
+            Err(ChainCommunicationError::InvalidReorgPeriod(_))
+        ));
+    }
+
+    #[test]
+    fn test_tree_conversion() {
+        let tree = MerkleTree {
+            branch: [Bits256([7; 32]); 32],
+            count: 12,
+        };
+
+        let tree = IncrementalMerkle::try_from(tree).unwrap();
+
+        assert_eq!(tree.count, 12);
+        assert!(tree.branch.iter().all(|node| *node == H256([7; 32])));
+    }
+}
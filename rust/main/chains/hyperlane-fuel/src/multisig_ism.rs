@@ -1,27 +1,60 @@
 use async_trait::async_trait;
+use fuels::{
+    prelude::{Bech32ContractId, WalletUnlocked},
+    types::Bytes,
+};
 
 use hyperlane_core::{
-    ChainResult, HyperlaneChain, HyperlaneContract, HyperlaneDomain, HyperlaneMessage,
-    HyperlaneProvider, MultisigIsm, H256,
+    ChainCommunicationError, ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract,
+    HyperlaneDomain, HyperlaneMessage, HyperlaneProvider, MultisigIsm, RawHyperlaneMessage, H256,
+};
+
+use crate::{
+    contracts::message_id_multisig_ism::MessageIdMultisigIsm as FuelMessageIdMultisigIsmInner,
+    conversions::*, ConnectionConf, FuelProvider,
 };
 
 /// A reference to a MultisigIsm contract on some Fuel chain
 #[derive(Debug)]
-pub struct FuelMultisigIsm {}
+pub struct FuelMultisigIsm {
+    contract: FuelMessageIdMultisigIsmInner<WalletUnlocked>,
+    domain: HyperlaneDomain,
+    provider: FuelProvider,
+}
+
+impl FuelMultisigIsm {
+    /// Create a new fuel multisig ISM
+    pub async fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator<'_>,
+        mut wallet: WalletUnlocked,
+    ) -> ChainResult<Self> {
+        let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
+
+        wallet.set_provider(fuel_provider.provider().clone());
+        let address = Bech32ContractId::from_h256(&locator.address);
+
+        Ok(FuelMultisigIsm {
+            contract: FuelMessageIdMultisigIsmInner::new(address, wallet),
+            domain: locator.domain.clone(),
+            provider: fuel_provider,
+        })
+    }
+}
 
 impl HyperlaneContract for FuelMultisigIsm {
     fn address(&self) -> H256 {
-        todo!()
+        self.contract.contract_id().into_h256()
     }
 }
 
 impl HyperlaneChain for FuelMultisigIsm {
     fn domain(&self) -> &HyperlaneDomain {
-        todo!()
+        &self.domain
     }
 
     fn provider(&self) -> Box<dyn HyperlaneProvider> {
-        todo!()
+        Box::new(self.provider.clone())
     }
 }
 
@@ -32,6 +65,20 @@ impl MultisigIsm for FuelMultisigIsm {
         &self,
         message: &HyperlaneMessage,
     ) -> ChainResult<(Vec<H256>, u8)> {
-        todo!()
+        let (validators, threshold) = self
+            .contract
+            .methods()
+            .validators_and_threshold(Bytes(RawHyperlaneMessage::from(message)))
+            .simulate()
+            .await
+            .map_err(ChainCommunicationError::from_other)?
+            .value;
+
+        // Validators are EVM addresses, left-padded to 32 bytes like on other chains
+        let validators = validators
+            .into_iter()
+            .map(|validator| validator.value().into_h256())
+            .collect();
+        Ok((validators, threshold))
     }
 }
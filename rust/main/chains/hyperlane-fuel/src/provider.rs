@@ -1,8 +1,15 @@
-use std::{collections::HashMap, ops::Deref};
+use std::{
+    collections::HashMap,
+    ops::{Deref, RangeInclusive},
+};
 
 use async_trait::async_trait;
 use fuels::{
     client::{FuelClient, PageDirection, PaginationRequest},
+    core::{
+        codec::LogDecoder,
+        traits::{Parameterize, Tokenizable},
+    },
     prelude::Provider,
     tx::Receipt,
     types::{
@@ -15,7 +22,7 @@ use fuels::{
         Address, BlockHeight, Bytes32, ContractId,
     },
 };
-use futures::future::join_all;
+use futures::{future::join_all, stream, StreamExt};
 use hyperlane_core::{
     h512_to_bytes, BlockInfo, ChainCommunicationError, ChainInfo, ChainResult, HyperlaneChain,
     HyperlaneDomain, HyperlaneMessage, HyperlaneProvider, HyperlaneProviderError, Indexed, LogMeta,
@@ -24,6 +31,9 @@ use hyperlane_core::{
 
 use crate::{make_client, make_provider, prelude::FuelIntoH256, ConnectionConf};
 
+/// The maximum number of transactions fetched concurrently while indexing a block
+const TRANSACTION_FETCH_CONCURRENCY: usize = 20;
+
 /// A wrapper around a fuel provider to get generic blockchain information.
 #[derive(Debug, Clone)]
 pub struct FuelProvider {
@@ -158,6 +168,61 @@ impl FuelProvider {
             .map_err(ChainCommunicationError::from_other)
     }
 
+    /// Index the logs of type `T` emitted by a contract in a range of blocks.
+    /// Logs are matched on the emitting contract and the log id of `T`, so this
+    /// works regardless of which contract was called by the transaction.
+    pub async fn index_contract_logs_in_range<T>(
+        &self,
+        range: RangeInclusive<u32>,
+        contract: &Bech32ContractId,
+        log_decoder: &LogDecoder,
+    ) -> ChainResult<Vec<(T, LogMeta)>>
+    where
+        T: Tokenizable + Parameterize + 'static,
+    {
+        let (blocks, _) = self.get_block_data(range).await?;
+        let contract_id = ContractId::from(contract);
+        let address = contract.clone().into_h256();
+
+        let mut logs = vec![];
+        for block in blocks {
+            let responses = stream::iter(
+                block
+                    .transactions
+                    .iter()
+                    .map(|tx_id| self.provider.get_transaction_by_id(tx_id)),
+            )
+            .buffered(TRANSACTION_FETCH_CONCURRENCY)
+            .collect::<Vec<_>>()
+            .await;
+
+            for (transaction_index, (tx_id, response)) in
+                block.transactions.iter().zip(responses).enumerate()
+            {
+                let Some(response) = response.map_err(ChainCommunicationError::from_other)? else {
+                    continue;
+                };
+                let TxStatus::Success { receipts } = &response.status else {
+                    continue;
+                };
+                for (log_index, log) in
+                    decode_contract_logs::<T>(receipts, &contract_id, log_decoder)
+                {
+                    let log_meta = LogMeta {
+                        address,
+                        block_number: block.header.height.into(),
+                        block_hash: block.id.into_h256(),
+                        transaction_id: H512::from(tx_id.into_h256()),
+                        transaction_index: transaction_index as u64,
+                        log_index: U256::from(log_index),
+                    };
+                    logs.push((log, log_meta));
+                }
+            }
+        }
+        Ok(logs)
+    }
+
     /// index logs in a range
     #[allow(clippy::clone_on_copy)] // TODO: `rustc` 1.80.1 clippy issue
     #[allow(clippy::manual_map)] // TODO: `rustc` 1.80.1 clippy issue
@@ -271,6 +336,34 @@ impl FuelProvider {
     }
 }
 
+/// Decode the logs of type `T` emitted by `contract_id`, along with the index of
+/// their receipt
+pub fn decode_contract_logs<T>(
+    receipts: &[Receipt],
+    contract_id: &ContractId,
+    log_decoder: &LogDecoder,
+) -> Vec<(usize, T)>
+where
+    T: Tokenizable + Parameterize + 'static,
+{
+    receipts
+        .iter()
+        .enumerate()
+        .filter(|(_, receipt)| {
+            matches!(receipt, Receipt::LogData { id, .. } | Receipt::Log { id, .. } if id == contract_id)
+        })
+        .filter_map(|(index, receipt)| {
+            // Fails if the receipt isn't a log of type `T`
+            let log = log_decoder
+                .decode_logs_with_type::<T>(std::slice::from_ref(receipt))
+                .ok()?
+                .into_iter()
+                .next()?;
+            Some((index, log))
+        })
+        .collect()
+}
+
 impl HyperlaneChain for FuelProvider {
     fn domain(&self) -> &HyperlaneDomain {
         &self.domain
@@ -1,27 +1,60 @@
 use async_trait::async_trait;
+use fuels::{
+    prelude::{Bech32ContractId, WalletUnlocked},
+    types::Bytes,
+};
 
 use hyperlane_core::{
-    ChainResult, HyperlaneChain, HyperlaneContract, HyperlaneDomain, HyperlaneMessage,
-    HyperlaneProvider, RoutingIsm, H256,
+    ChainCommunicationError, ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract,
+    HyperlaneDomain, HyperlaneMessage, HyperlaneProvider, RawHyperlaneMessage, RoutingIsm, H256,
+};
+
+use crate::{
+    contracts::domain_routing_ism::DomainRoutingIsm as FuelDomainRoutingIsmInner, conversions::*,
+    ConnectionConf, FuelProvider,
 };
 
 /// A reference to a RoutingIsm contract on some Fuel chain
 #[derive(Debug)]
-pub struct FuelRoutingIsm {}
+pub struct FuelRoutingIsm {
+    contract: FuelDomainRoutingIsmInner<WalletUnlocked>,
+    domain: HyperlaneDomain,
+    provider: FuelProvider,
+}
+
+impl FuelRoutingIsm {
+    /// Create a new fuel routing ISM
+    pub async fn new(
+        conf: &ConnectionConf,
+        locator: ContractLocator<'_>,
+        mut wallet: WalletUnlocked,
+    ) -> ChainResult<Self> {
+        let fuel_provider = FuelProvider::new(locator.domain.clone(), conf).await;
+
+        wallet.set_provider(fuel_provider.provider().clone());
+        let address = Bech32ContractId::from_h256(&locator.address);
+
+        Ok(FuelRoutingIsm {
+            contract: FuelDomainRoutingIsmInner::new(address, wallet),
+            domain: locator.domain.clone(),
+            provider: fuel_provider,
+        })
+    }
+}
 
 impl HyperlaneContract for FuelRoutingIsm {
     fn address(&self) -> H256 {
-        todo!()
+        self.contract.contract_id().into_h256()
     }
 }
 
 impl HyperlaneChain for FuelRoutingIsm {
     fn domain(&self) -> &HyperlaneDomain {
-        todo!()
+        &self.domain
     }
 
     fn provider(&self) -> Box<dyn HyperlaneProvider> {
-        todo!()
+        Box::new(self.provider.clone())
     }
 }
 
@@ -29,6 +62,12 @@ impl HyperlaneChain for FuelRoutingIsm {
 impl RoutingIsm for FuelRoutingIsm {
     /// Returns the ism needed to verify message
     async fn route(&self, message: &HyperlaneMessage) -> ChainResult<H256> {
-        todo!()
+        self.contract
+            .methods()
+            .route(Bytes(RawHyperlaneMessage::from(message)))
+            .simulate()
+            .await
+            .map(|r| r.value.into_h256())
+            .map_err(ChainCommunicationError::from_other)
     }
 }
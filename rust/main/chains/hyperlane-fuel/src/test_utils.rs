@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use fuels::{
+    core::{codec::ABIEncoder, traits::Tokenizable},
+    tx::Receipt,
+    types::ContractId,
+};
+use sha2::{Digest, Sha256};
+
+/// The log id the Sway compiler gives a logged type: the first 8 bytes of the
+/// sha256 of its declaration, e.g. `struct interfaces::events::ProcessEvent`.
+/// Derived independently of the checked-in ABIs, so tests using it catch ABIs
+/// which don't match the compiled contracts.
+pub fn sway_log_id(type_declaration: &str) -> u64 {
+    let digest = Sha256::digest(type_declaration.as_bytes());
+    u64::from_be_bytes(digest[..8].try_into().unwrap())
+}
+
+/// The log ids of the types logged by a contract, by type, read from its ABI
+pub fn abi_log_ids(abi: &str) -> HashMap<String, u64> {
+    let abi: serde_json::Value = serde_json::from_str(abi).unwrap();
+    let types = abi["types"].as_array().unwrap();
+    abi["loggedTypes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|logged| {
+            let type_id = &logged["loggedType"]["type"];
+            let declaration = types.iter().find(|t| &t["typeId"] == type_id).unwrap()["type"]
+                .as_str()
+                .unwrap()
+                .to_owned();
+            let log_id = logged["logId"].as_str().unwrap().parse().unwrap();
+            (declaration, log_id)
+        })
+        .collect()
+}
+
+/// A receipt as reported by fuel-core for a value logged by a contract
+pub fn log_data_receipt<T: Tokenizable>(contract_id: ContractId, log_id: u64, value: T) -> Receipt {
+    let data = ABIEncoder::default().encode(&[value.into_token()]).unwrap();
+    Receipt::LogData {
+        id: contract_id,
+        ra: 0,
+        rb: log_id,
+        ptr: 0,
+        len: data.len() as u64,
+        digest: Default::default(),
+        pc: 0,
+        is: 0,
+        data: Some(data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The checked-in Mailbox ABI is the output of `forc build`, so the log
+    /// ids derived by `sway_log_id` must match it
+    #[test]
+    fn log_ids_match_the_compiled_mailbox() {
+        let log_ids = abi_log_ids(include_str!("../abis/Mailbox.abi.json"));
+        for (declaration, path) in [
+            (
+                "struct ProcessEvent",
+                "struct interfaces::events::ProcessEvent",
+            ),
+            (
+                "struct DispatchEvent",
+                "struct interfaces::events::DispatchEvent",
+            ),
+            ("enum MailboxError", "enum MailboxError"),
+            (
+                "enum PauseError",
+                "enum sway_libs::pausable::errors::PauseError",
+            ),
+            ("enum AccessError", "enum standards::src5::AccessError"),
+        ] {
+            assert_eq!(log_ids[declaration], sway_log_id(path), "{path}");
+        }
+    }
+}
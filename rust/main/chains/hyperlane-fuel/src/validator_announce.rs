@@ -1,48 +0,0 @@
-use async_trait::async_trait;
-
-use hyperlane_core::{
-    Announcement, ChainResult, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
-    HyperlaneProvider, SignedType, TxOutcome, ValidatorAnnounce, H256, U256,
-};
-
-/// A reference to a ValidatorAnnounce contract on some Fuel chain
-#[derive(Debug)]
-pub struct FuelValidatorAnnounce {}
-
-impl HyperlaneContract for FuelValidatorAnnounce {
-    fn address(&self) -> H256 {
-        todo!()
-    }
-}
-
-impl HyperlaneChain for FuelValidatorAnnounce {
-    fn domain(&self) -> &HyperlaneDomain {
-        todo!()
-    }
-
-    fn provider(&self) -> Box<dyn HyperlaneProvider> {
-        todo!()
-    }
-}
-
-#[async_trait]
-impl ValidatorAnnounce for FuelValidatorAnnounce {
-    async fn get_announced_storage_locations(
-        &self,
-        validators: &[H256],
-    ) -> ChainResult<Vec<Vec<String>>> {
-        todo!()
-    }
-
-    async fn announce(&self, announcement: SignedType<Announcement>) -> ChainResult<TxOutcome> {
-        todo!()
-    }
-
-    async fn announce_tokens_needed(
-        &self,
-        announcement: SignedType<Announcement>,
-        _chain_signer: H256,
-    ) -> Option<U256> {
-        todo!()
-    }
-}
@@ -0,0 +1,33 @@
+#!/bin/sh
+
+# Regenerates the checked-in ABIs from the compiled Sway contracts.
+# Usage: ./update_abis.sh <path to the fuel contracts workspace>
+# The workspace must have been built with `forc build --release`.
+
+set -e
+
+CONTRACTS_DIR="$1"
+ABIS_DIR="$(dirname "$0")/abis"
+
+if [ -z "$CONTRACTS_DIR" ]; then
+    echo "Usage: $0 <path to the fuel contracts workspace>"
+    exit 1
+fi
+
+copy() {
+    # $1 is the forc project name, $2 the ABI file name used by the bindings
+    ABI=$(find "$CONTRACTS_DIR" -path "*/out/release/$1-abi.json" | head -n 1)
+    if [ -z "$ABI" ]; then
+        echo "Could not find the compiled ABI of $1"
+        exit 1
+    fi
+    jq . < "$ABI" > "$ABIS_DIR/$2.abi.json"
+}
+
+copy mailbox Mailbox
+copy interchain-gas-paymaster InterchainGasPaymaster
+copy merkle-tree-hook MerkleTreeHook
+copy message-id-multisig-ism MessageIdMultisigIsm
+copy domain-routing-ism DomainRoutingIsm
+# Any ISM implements the generic interface, only `module_type` and `verify` are used
+copy message-id-multisig-ism InterchainSecurityModule
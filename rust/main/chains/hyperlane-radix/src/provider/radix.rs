@@ -23,7 +23,10 @@ use gateway_api_client::{
         TransactionStatusResponse,
     },
 };
-use hyperlane_metric::prometheus_metric::{ChainInfo, PrometheusClientMetrics};
+use hyperlane_metric::{
+    prometheus_metric::{ChainInfo, PrometheusClientMetrics, PrometheusConfig},
+    utils::urls_to_provider_nodes,
+};
 use radix_common::traits::ScryptoEvent;
 use radix_transactions::{
     builder::{
@@ -130,10 +133,24 @@ impl RadixProvider {
                 RadixMetricCoreProvider::new(provider, &url, metrics.clone(), chain.clone());
             core_provider.push(provider);
         }
-        Ok(RadixFallbackProvider::new(
-            FallbackProvider::new(core_provider),
-            FallbackProvider::new(gateway_provider),
-        ))
+        let chain_name = PrometheusConfig::chain_name(&chain);
+        let core = FallbackProvider::builder()
+            .add_providers(core_provider)
+            .with_score_metric(
+                metrics.provider_score.clone(),
+                chain_name,
+                urls_to_provider_nodes(&conf.core),
+            )
+            .build();
+        let gateway = FallbackProvider::builder()
+            .add_providers(gateway_provider)
+            .with_score_metric(
+                metrics.provider_score.clone(),
+                chain_name,
+                urls_to_provider_nodes(&conf.gateway),
+            )
+            .build();
+        Ok(RadixFallbackProvider::new(core, gateway))
     }
 
     /// Create a new Radix Provider
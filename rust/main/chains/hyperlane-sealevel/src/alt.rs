@@ -16,6 +16,7 @@
 
 use hyperlane_core::{ChainCommunicationError, ChainResult};
 use solana_address_lookup_table_interface::state::AddressLookupTable;
+use solana_sdk::account::Account;
 use solana_sdk::message::AddressLookupTableAccount;
 use solana_sdk::pubkey::Pubkey;
 
@@ -32,6 +33,14 @@ pub async fn fetch_alt(
     let account = rpc
         .get_account_with_finalized_commitment(alt_address)
         .await?;
+    alt_from_account(alt_address, &account)
+}
+
+/// Deserialize the data of an ALT account into the Solana SDK's native type.
+pub fn alt_from_account(
+    alt_address: Pubkey,
+    account: &Account,
+) -> ChainResult<AddressLookupTableAccount> {
     let alt = AddressLookupTable::deserialize(&account.data)
         .map_err(ChainCommunicationError::from_other)?;
     Ok(AddressLookupTableAccount {
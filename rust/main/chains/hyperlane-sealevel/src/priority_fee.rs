@@ -1,12 +1,20 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use derive_new::new;
 use hyperlane_core::{ChainCommunicationError, ChainResult};
 use reqwest::Client;
 use serde::Deserialize;
-use solana_sdk::bs58;
+use solana_sdk::{bs58, message::AddressLookupTableAccount, pubkey::Pubkey};
+use tokio::sync::RwLock;
 
+use crate::alt::alt_from_account;
+use crate::client::SealevelRpcClient;
 use crate::tx_type::SealevelTxType;
-use crate::{HeliusPriorityFeeLevel, HeliusPriorityFeeOracleConfig};
+use crate::{
+    HeliusPriorityFeeLevel, HeliusPriorityFeeOracleConfig, RecentPrioritizationFeesOracleConfig,
+};
 
 /// A trait for fetching the priority fee for a transaction.
 #[async_trait]
@@ -107,6 +115,119 @@ impl PriorityFeeOracle for HeliusPriorityFeeOracle {
     }
 }
 
+/// A priority fee oracle using the `getRecentPrioritizationFees` RPC method,
+/// supported by any Solana RPC.
+/// https://solana.com/docs/rpc/http/getrecentprioritizationfees
+///
+/// Fees are scoped to the writable accounts of the transaction, including the
+/// ones loaded from address lookup tables, so that the fee reflects the
+/// contention on e.g. the mailbox and the recipient PDAs.
+#[derive(Debug, Clone)]
+pub struct RecentPrioritizationFeesOracle {
+    client: SealevelRpcClient,
+    config: RecentPrioritizationFeesOracleConfig,
+    /// Fetched ALTs, assumed static like in the provider
+    alt_cache: Arc<RwLock<HashMap<Pubkey, AddressLookupTableAccount>>>,
+}
+
+impl RecentPrioritizationFeesOracle {
+    /// The RPC method accepts at most this many accounts
+    const MAX_ACCOUNTS: usize = 128;
+
+    pub fn new(config: RecentPrioritizationFeesOracleConfig) -> Self {
+        Self {
+            client: SealevelRpcClient::new(config.url.to_string()),
+            config,
+            alt_cache: Default::default(),
+        }
+    }
+
+    /// The address lookup tables the transaction loads accounts from. Tables
+    /// which can't be fetched are left out, so the fee is only scoped to the
+    /// accounts which are known.
+    async fn lookup_tables(&self, transaction: &SealevelTxType) -> Vec<AddressLookupTableAccount> {
+        let mut lookup_tables = vec![];
+        for lookup in transaction.address_table_lookups() {
+            let alt_address = lookup.account_key;
+            if let Some(table) = self.alt_cache.read().await.get(&alt_address) {
+                lookup_tables.push(table.clone());
+                continue;
+            }
+            let table = match self
+                .client
+                .get_account_with_finalized_commitment(&alt_address)
+                .await
+                .and_then(|account| alt_from_account(alt_address, &account))
+            {
+                Ok(table) => table,
+                Err(err) => {
+                    tracing::warn!(
+                        %alt_address,
+                        ?err,
+                        "Failed to fetch ALT, leaving its accounts out of the priority fee"
+                    );
+                    continue;
+                }
+            };
+            self.alt_cache
+                .write()
+                .await
+                .insert(alt_address, table.clone());
+            lookup_tables.push(table);
+        }
+        lookup_tables
+    }
+
+    /// Picks the configured percentile of the fees and applies the floor and ceiling
+    fn fee_from_recent_fees(&self, mut fees: Vec<u64>) -> u64 {
+        fees.sort_unstable();
+        let fee = percentile(&fees, self.config.percentile)
+            .unwrap_or_default()
+            .max(self.config.min_fee);
+        match self.config.max_fee {
+            Some(max_fee) => fee.min(max_fee),
+            None => fee,
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted values
+fn percentile(sorted: &[u64], percentile: u8) -> Option<u64> {
+    let percentile = usize::from(percentile.min(100));
+    let rank = percentile
+        .saturating_mul(sorted.len())
+        .div_ceil(100)
+        .saturating_sub(1);
+    sorted.get(rank).copied()
+}
+
+#[async_trait]
+impl PriorityFeeOracle for RecentPrioritizationFeesOracle {
+    async fn get_priority_fee(&self, transaction: &SealevelTxType) -> ChainResult<u64> {
+        let lookup_tables = self.lookup_tables(transaction).await;
+        let mut accounts = transaction.writable_non_signer_accounts(&lookup_tables);
+        accounts.truncate(Self::MAX_ACCOUNTS);
+
+        let fees = self
+            .client
+            .get_recent_prioritization_fees(&accounts)
+            .await?
+            .into_iter()
+            .map(|fee| fee.prioritization_fee)
+            .collect::<Vec<_>>();
+
+        let fee = self.fee_from_recent_fees(fees);
+        tracing::debug!(
+            fee,
+            accounts = accounts.len(),
+            percentile = self.config.percentile,
+            "Fetched priority fee from recent prioritization fees"
+        );
+
+        Ok(fee)
+    }
+}
+
 /// The result of a JSON-RPC request to the Helius API.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -133,6 +254,25 @@ mod test {
         priority_fee::{HeliusPriorityFeeOracle, PriorityFeeOracle},
         tx_type::SealevelTxType,
         HeliusPriorityFeeLevel, HeliusPriorityFeeOracleConfig,
+        RecentPrioritizationFeesOracleConfig,
+    };
+
+    use super::{percentile, RecentPrioritizationFeesOracle};
+
+    use std::sync::{Arc, Mutex};
+
+    use axum::{extract::State, routing::post, Json, Router};
+    use base64::{engine::general_purpose::STANDARD as Base64, Engine};
+    use serde_json::{json, Value};
+    use solana_address_lookup_table_interface::state::LOOKUP_TABLE_META_SIZE;
+    use solana_sdk::{
+        hash::Hash,
+        instruction::{AccountMeta, Instruction},
+        message::{v0, AddressLookupTableAccount, VersionedMessage},
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::VersionedTransaction,
     };
 
     use super::{GetPriorityFeeEstimateResult, JsonRpcResult};
@@ -224,4 +364,151 @@ mod test {
         };
         assert_eq!(response.result, expected);
     }
+
+    #[test]
+    fn test_percentile() {
+        let fees = [0, 0, 10, 20, 30, 40, 50, 60, 70, 100];
+        assert_eq!(percentile(&fees, 0), Some(0));
+        assert_eq!(percentile(&fees, 50), Some(30));
+        assert_eq!(percentile(&fees, 75), Some(60));
+        assert_eq!(percentile(&fees, 90), Some(70));
+        assert_eq!(percentile(&fees, 100), Some(100));
+        assert_eq!(percentile(&fees, 255), Some(100));
+        assert_eq!(percentile(&[], 50), None);
+    }
+
+    #[test]
+    fn test_recent_prioritization_fees_floor_and_ceiling() {
+        let get_oracle = |min_fee, max_fee| {
+            RecentPrioritizationFeesOracle::new(RecentPrioritizationFeesOracleConfig {
+                url: url::Url::parse("http://localhost:8899").unwrap(),
+                percentile: 50,
+                min_fee,
+                max_fee,
+            })
+        };
+        let fees = vec![500, 100, 300, 200, 400];
+
+        assert_eq!(get_oracle(0, None).fee_from_recent_fees(fees.clone()), 300);
+        assert_eq!(
+            get_oracle(1000, None).fee_from_recent_fees(fees.clone()),
+            1000
+        );
+        assert_eq!(get_oracle(0, Some(250)).fee_from_recent_fees(fees), 250);
+        // No recent fees, fall back to the floor
+        assert_eq!(get_oracle(10, Some(250)).fee_from_recent_fees(vec![]), 10);
+    }
+
+    /// A mock RPC serving an ALT account and recording the accounts the
+    /// recent prioritization fees are requested for
+    #[derive(Clone)]
+    struct MockRpc {
+        alt_data: Vec<u8>,
+        fee_accounts: Arc<Mutex<Vec<Value>>>,
+    }
+
+    async fn rpc_handler(State(rpc): State<MockRpc>, Json(body): Json<Value>) -> Json<Value> {
+        let result = match body["method"].as_str() {
+            Some("getAccountInfo") => json!({
+                "context": { "slot": 1 },
+                "value": {
+                    "data": [Base64.encode(&rpc.alt_data), "base64"],
+                    "executable": false,
+                    "lamports": 1_000_000,
+                    "owner": solana_sdk_ids::address_lookup_table::id().to_string(),
+                    "rentEpoch": 0,
+                    "space": rpc.alt_data.len(),
+                },
+            }),
+            Some("getRecentPrioritizationFees") => {
+                rpc.fee_accounts
+                    .lock()
+                    .unwrap()
+                    .push(body["params"][0].clone());
+                json!([
+                    { "slot": 1, "prioritizationFee": 100 },
+                    { "slot": 2, "prioritizationFee": 300 },
+                    { "slot": 3, "prioritizationFee": 200 },
+                ])
+            }
+            method => panic!("unexpected method {method:?}"),
+        };
+        Json(json!({ "jsonrpc": "2.0", "id": body["id"], "result": result }))
+    }
+
+    /// Data of an active lookup table account without authority
+    fn alt_account_data(addresses: &[Pubkey]) -> Vec<u8> {
+        let mut data = vec![0; LOOKUP_TABLE_META_SIZE];
+        // `ProgramState::LookupTable`
+        data[0] = 1;
+        // deactivation slot, never deactivated
+        data[4..12].copy_from_slice(&u64::MAX.to_le_bytes());
+        for address in addresses {
+            data.extend_from_slice(address.as_ref());
+        }
+        data
+    }
+
+    #[tokio::test]
+    async fn test_recent_prioritization_fees_include_lookup_table_accounts() {
+        let payer = Keypair::new();
+        let program = Pubkey::new_unique();
+        let static_writable = Pubkey::new_unique();
+        let alt_writable = Pubkey::new_unique();
+        let alt_readonly = Pubkey::new_unique();
+        let alt = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: vec![alt_readonly, alt_writable],
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            program,
+            &[],
+            vec![
+                AccountMeta::new(static_writable, false),
+                AccountMeta::new(alt_writable, false),
+                AccountMeta::new_readonly(alt_readonly, false),
+            ],
+        );
+        let message = v0::Message::try_compile(
+            &payer.pubkey(),
+            &[instruction],
+            std::slice::from_ref(&alt),
+            Hash::default(),
+        )
+        .unwrap();
+        let transaction = SealevelTxType::Versioned(
+            VersionedTransaction::try_new(VersionedMessage::V0(message), &[&payer]).unwrap(),
+        );
+
+        let rpc = MockRpc {
+            alt_data: alt_account_data(&alt.addresses),
+            fee_accounts: Default::default(),
+        };
+        let app = Router::new()
+            .route("/", post(rpc_handler))
+            .with_state(rpc.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let oracle = RecentPrioritizationFeesOracle::new(RecentPrioritizationFeesOracleConfig {
+            url: url::Url::parse(&format!("http://{addr}/")).unwrap(),
+            percentile: 50,
+            min_fee: 0,
+            max_fee: None,
+        });
+
+        assert_eq!(oracle.get_priority_fee(&transaction).await.unwrap(), 200);
+        // the fetched ALT is reused
+        assert_eq!(oracle.get_priority_fee(&transaction).await.unwrap(), 200);
+
+        let fee_accounts = rpc.fee_accounts.lock().unwrap().clone();
+        assert_eq!(fee_accounts.len(), 2);
+        assert_eq!(
+            fee_accounts[0],
+            json!([static_writable.to_string(), alt_writable.to_string()])
+        );
+        assert_eq!(oracle.alt_cache.read().await.len(), 1);
+    }
 }
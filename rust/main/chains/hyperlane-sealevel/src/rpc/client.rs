@@ -7,7 +7,7 @@ use solana_client::{
         RpcBlockConfig, RpcProgramAccountsConfig, RpcSendTransactionConfig,
         RpcSimulateTransactionConfig, RpcTransactionConfig,
     },
-    rpc_response::{Response, RpcSimulateTransactionResult},
+    rpc_response::{Response, RpcPrioritizationFee, RpcSimulateTransactionResult},
 };
 use solana_commitment_config::CommitmentConfig;
 use solana_program::clock::Slot;
@@ -194,6 +194,18 @@ impl SealevelRpcClient {
             .map_err(ChainCommunicationError::from_other)
     }
 
+    /// get the prioritization fees paid in recent slots by transactions
+    /// locking all the given accounts as writable
+    pub async fn get_recent_prioritization_fees(
+        &self,
+        addresses: &[Pubkey],
+    ) -> ChainResult<Vec<RpcPrioritizationFee>> {
+        self.0
+            .get_recent_prioritization_fees(addresses)
+            .await
+            .map_err(ChainCommunicationError::from_other)
+    }
+
     /// get statuses based on signatures
     pub async fn get_signature_statuses(
         &self,
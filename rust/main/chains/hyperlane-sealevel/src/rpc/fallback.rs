@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
 
 use async_trait::async_trait;
@@ -15,8 +16,14 @@ use solana_transaction_status::{
 };
 use url::Url;
 
-use hyperlane_core::{rpc_clients::FallbackProvider, ChainResult, U256};
-use hyperlane_metric::prometheus_metric::PrometheusClientMetrics;
+use hyperlane_core::{
+    rpc_clients::{FallbackProvider, RpcRateLimitConf, RpcRateLimiter, RpcRateLimiterMetrics},
+    ChainResult, U256,
+};
+use hyperlane_metric::{
+    prometheus_metric::{PrometheusClientMetrics, PrometheusConfig},
+    utils::{url_to_host_info, urls_to_provider_nodes},
+};
 
 use crate::client::SealevelRpcClient;
 use crate::client_builder::SealevelRpcClientBuilder;
@@ -140,22 +147,46 @@ impl SubmitSealevelRpc for SealevelFallbackRpcClient {
 }
 
 impl SealevelFallbackRpcClient {
-    /// Create a SealevelFallbackProvider from a list of urls
+    /// Create a SealevelFallbackProvider from a list of urls, limiting the
+    /// requests to the urls with an entry in `rate_limits`
     pub fn from_urls(
         chain: Option<hyperlane_metric::prometheus_metric::ChainInfo>,
         urls: Vec<Url>,
+        rate_limits: &HashMap<Url, RpcRateLimitConf>,
         metrics: PrometheusClientMetrics,
     ) -> Self {
-        let clients: Vec<_> = urls
+        let provider_nodes = urls_to_provider_nodes(&urls);
+        let chain_name = PrometheusConfig::chain_name(&chain).to_owned();
+        let builder = urls
             .into_iter()
-            .map(|rpc_url| {
-                SealevelRpcClientBuilder::new(rpc_url)
+            .fold(FallbackProvider::builder(), |builder, rpc_url| {
+                let rate_limiter = rate_limits
+                    .get(&rpc_url)
+                    .filter(|rate_limit| rate_limit.is_limited())
+                    .map(|rate_limit| {
+                        RpcRateLimiter::shared(rpc_url.as_str(), rate_limit, || {
+                            let host =
+                                url_to_host_info(&rpc_url).unwrap_or_else(|| "unknown".to_owned());
+                            RpcRateLimiterMetrics::with_labels(
+                                metrics.rpc_remaining_daily_credits.as_ref(),
+                                metrics.rpc_throttled_seconds.as_ref(),
+                                &[host.as_str(), chain_name.as_str()],
+                            )
+                        })
+                    });
+                let client = SealevelRpcClientBuilder::new(rpc_url)
                     .with_prometheus_metrics(metrics.clone(), chain.clone())
-                    .build()
-            })
-            .collect();
-
-        let fallback = FallbackProvider::new(clients);
+                    .build();
+                builder.add_rate_limited_provider(client, rate_limiter)
+            });
+
+        let fallback = builder
+            .with_score_metric(
+                metrics.provider_score.clone(),
+                PrometheusConfig::chain_name(&chain),
+                provider_nodes,
+            )
+            .build();
         SealevelFallbackRpcClient::new(fallback)
     }
 
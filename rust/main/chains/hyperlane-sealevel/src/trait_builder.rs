@@ -1,14 +1,18 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use hyperlane_core::{
-    config::OpSubmissionConfig, matching_list::MatchingList, ChainCommunicationError, NativeToken,
+    config::OpSubmissionConfig, matching_list::MatchingList, rpc_clients::RpcRateLimitConf,
+    ChainCommunicationError, NativeToken,
 };
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
 use url::Url;
 
 use crate::{
-    priority_fee::{ConstantPriorityFeeOracle, HeliusPriorityFeeOracle, PriorityFeeOracle},
+    priority_fee::{
+        ConstantPriorityFeeOracle, HeliusPriorityFeeOracle, PriorityFeeOracle,
+        RecentPrioritizationFeesOracle,
+    },
     tx_submitter::config::TransactionSubmitterConfig,
 };
 
@@ -41,6 +45,9 @@ pub struct ConnectionConf {
     /// Per-message ALT overrides. First matching entry wins.
     /// Falls back to `mailbox_process_alt` if no match.
     pub process_alt_overrides: Vec<ProcessAltOverride>,
+    /// Self-imposed request budgets of the RPC urls. Urls without an entry
+    /// aren't limited.
+    pub rpc_rate_limits: HashMap<Url, RpcRateLimitConf>,
 }
 
 /// An error type when parsing a connection configuration.
@@ -61,6 +68,8 @@ pub enum PriorityFeeOracleConfig {
     Constant(u64),
     /// A Helius priority fee oracle
     Helius(HeliusPriorityFeeOracleConfig),
+    /// An oracle using the standard `getRecentPrioritizationFees` RPC method
+    RecentPrioritizationFees(RecentPrioritizationFeesOracleConfig),
 }
 
 impl Default for PriorityFeeOracleConfig {
@@ -79,6 +88,9 @@ impl PriorityFeeOracleConfig {
             PriorityFeeOracleConfig::Helius(config) => {
                 Arc::new(HeliusPriorityFeeOracle::new(config.clone()))
             }
+            PriorityFeeOracleConfig::RecentPrioritizationFees(config) => {
+                Arc::new(RecentPrioritizationFeesOracle::new(config.clone()))
+            }
         }
     }
 }
@@ -113,6 +125,24 @@ pub enum HeliusPriorityFeeLevel {
     UnsafeMax,
 }
 
+/// Configuration for the `getRecentPrioritizationFees` priority fee oracle
+#[derive(Debug, Clone)]
+pub struct RecentPrioritizationFeesOracleConfig {
+    /// The RPC URL to query
+    pub url: Url,
+    /// The percentile of the recent fees to use, between 0 and 100
+    pub percentile: u8,
+    /// The lowest fee to use, in micro lamports
+    pub min_fee: u64,
+    /// The highest fee to use, in micro lamports
+    pub max_fee: Option<u64>,
+}
+
+impl RecentPrioritizationFeesOracleConfig {
+    /// The percentile used when none is configured
+    pub const DEFAULT_PERCENTILE: u8 = 75;
+}
+
 #[derive(thiserror::Error, Debug)]
 #[error(transparent)]
 struct SealevelNewConnectionError(#[from] anyhow::Error);
@@ -1,5 +1,8 @@
 /// Transaction Submitter config
 pub mod config;
+mod jito_bundle;
+
+pub use jito_bundle::{BundleStatus, JitoBundleClient, JitoBundleTransactionSubmitter};
 
 use std::sync::Arc;
 
@@ -107,12 +110,9 @@ impl JitoTransactionSubmitter {
     /// The minimum tip to include in a transaction.
     /// From https://docs.jito.wtf/lowlatencytxnsend/#sendtransaction
     const MINIMUM_TIP_LAMPORTS: u64 = 1000;
-}
 
-#[async_trait]
-impl TransactionSubmitter for JitoTransactionSubmitter {
-    fn get_priority_fee_instruction(
-        &self,
+    /// Get the instruction tipping Jito for the given compute unit price
+    pub(crate) fn tip_instruction(
         compute_unit_price_micro_lamports: u64,
         compute_units: u64,
         payer: &Pubkey,
@@ -133,6 +133,18 @@ impl TransactionSubmitter for JitoTransactionSubmitter {
             tip_lamports,
         )
     }
+}
+
+#[async_trait]
+impl TransactionSubmitter for JitoTransactionSubmitter {
+    fn get_priority_fee_instruction(
+        &self,
+        compute_unit_price_micro_lamports: u64,
+        compute_units: u64,
+        payer: &Pubkey,
+    ) -> Instruction {
+        Self::tip_instruction(compute_unit_price_micro_lamports, compute_units, payer)
+    }
 
     async fn send_transaction(
         &self,
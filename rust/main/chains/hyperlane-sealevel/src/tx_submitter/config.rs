@@ -9,7 +9,7 @@ use crate::fallback::SealevelFallbackRpcClient;
 use crate::tx_submitter::TransactionSubmitter;
 use crate::{ConnectionConf, SealevelProvider};
 
-use super::{JitoTransactionSubmitter, RpcTransactionSubmitter};
+use super::{JitoBundleTransactionSubmitter, JitoTransactionSubmitter, RpcTransactionSubmitter};
 
 /// Configuration for the transaction submitter
 #[derive(Debug, Clone)]
@@ -24,6 +24,13 @@ pub enum TransactionSubmitterConfig {
         /// The URL to use. If not provided, a default Jito URL will be used
         urls: Vec<String>,
     },
+    /// Submit transactions as Jito bundles, falling back to the RPC
+    /// if a bundle doesn't land
+    JitoBundle {
+        /// The block-engine base URLs to use, tried in order. If not provided,
+        /// the mainnet block engine will be used
+        urls: Vec<Url>,
+    },
 }
 
 impl Default for TransactionSubmitterConfig {
@@ -53,7 +60,12 @@ impl TransactionSubmitterConfig {
             TransactionSubmitterConfig::Rpc { urls } => {
                 let urls: Vec<_> = urls.iter().filter_map(|url| Url::parse(url).ok()).collect();
 
-                let rpc_client = SealevelFallbackRpcClient::from_urls(chain, urls, metrics);
+                let rpc_client = SealevelFallbackRpcClient::from_urls(
+                    chain,
+                    urls,
+                    &conf.rpc_rate_limits,
+                    metrics,
+                );
                 let provider = SealevelProvider::new(rpc_client, domain, &[], conf);
                 Arc::new(RpcTransactionSubmitter::new(Arc::new(provider)))
             }
@@ -70,13 +82,28 @@ impl TransactionSubmitterConfig {
 
                 let urls: Vec<_> = urls.iter().filter_map(|url| Url::parse(url).ok()).collect();
 
-                let rpc_client = SealevelFallbackRpcClient::from_urls(chain, urls, metrics);
+                let rpc_client = SealevelFallbackRpcClient::from_urls(
+                    chain,
+                    urls,
+                    &conf.rpc_rate_limits,
+                    metrics,
+                );
                 let submit_provider = SealevelProvider::new(rpc_client, domain, &[], conf);
                 Arc::new(JitoTransactionSubmitter::new(
                     provider.clone(),
                     Arc::new(submit_provider),
                 ))
             }
+            TransactionSubmitterConfig::JitoBundle { urls } => {
+                let urls = if urls.is_empty() {
+                    vec![Url::parse("https://mainnet.block-engine.jito.wtf")
+                        .expect("the mainnet block engine url is valid")]
+                } else {
+                    urls.clone()
+                };
+
+                Arc::new(JitoBundleTransactionSubmitter::new(provider.clone(), urls))
+            }
         }
     }
 }
@@ -0,0 +1,459 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as Base64, Engine};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Signature};
+use tokio::time::{sleep, Instant};
+use tracing::{debug, warn};
+use url::Url;
+
+use hyperlane_core::{ChainCommunicationError, ChainResult};
+
+use crate::tx_type::SealevelTxType;
+use crate::{SealevelProvider, SealevelProviderForLander};
+
+use super::{JitoTransactionSubmitter, TransactionSubmitter};
+
+/// Status of a bundle in the Jito block engine.
+/// https://docs.jito.wtf/lowlatencytxnsend/#getinflightbundlestatuses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BundleStatus {
+    /// The bundle isn't known to the block engine, e.g. because it was dropped
+    Invalid,
+    /// The bundle hasn't landed nor failed yet
+    Pending,
+    /// All regions marked the bundle as failed and it wasn't forwarded
+    Failed,
+    /// The bundle landed on-chain
+    Landed,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonRpcRequest<'a, T> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'a str,
+    params: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonRpcResponse<T> {
+    result: Option<T>,
+    error: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InflightBundleStatuses {
+    value: Vec<InflightBundleStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InflightBundleStatus {
+    bundle_id: String,
+    status: BundleStatus,
+}
+
+/// A client of the Jito block-engine bundle API
+#[derive(Debug, Clone)]
+pub struct JitoBundleClient {
+    client: Client,
+    /// Block-engine base URLs, tried in order
+    urls: Vec<Url>,
+    /// How often the status of a submitted bundle is polled
+    status_poll_interval: Duration,
+    /// How long a bundle is tracked for before giving up on it
+    status_timeout: Duration,
+}
+
+impl JitoBundleClient {
+    const SEND_BUNDLE_PATH: &'static str = "api/v1/bundles";
+    const INFLIGHT_BUNDLE_STATUSES_PATH: &'static str = "api/v1/getInflightBundleStatuses";
+    const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+    const STATUS_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Create a client of the given block-engine URLs
+    pub fn new(urls: Vec<Url>) -> Self {
+        Self {
+            client: Client::new(),
+            urls,
+            status_poll_interval: Self::STATUS_POLL_INTERVAL,
+            status_timeout: Self::STATUS_TIMEOUT,
+        }
+    }
+
+    /// Submits a bundle of a single transaction and tracks it until it lands,
+    /// fails, or tracking times out. Returns the last known status.
+    pub async fn submit(&self, transaction: &SealevelTxType) -> ChainResult<BundleStatus> {
+        let (url, bundle_id) = self.send_bundle(transaction).await?;
+        debug!(?bundle_id, %url, "Submitted Jito bundle");
+
+        let started = Instant::now();
+        loop {
+            let status = match self.get_inflight_bundle_status(&url, &bundle_id).await {
+                Ok(status) => status,
+                Err(err) => {
+                    warn!(?err, ?bundle_id, "Failed to get Jito bundle status");
+                    BundleStatus::Pending
+                }
+            };
+            // `Invalid` is also reported until the block engine picks the bundle up,
+            // so only `Landed` and `Failed` are final
+            if matches!(status, BundleStatus::Landed | BundleStatus::Failed)
+                || started.elapsed() >= self.status_timeout
+            {
+                debug!(?bundle_id, ?status, "Finished tracking Jito bundle");
+                return Ok(status);
+            }
+            sleep(self.status_poll_interval).await;
+        }
+    }
+
+    /// Sends the bundle to the first block engine accepting it
+    async fn send_bundle(&self, transaction: &SealevelTxType) -> ChainResult<(Url, String)> {
+        let tx_bytes = match transaction {
+            SealevelTxType::Legacy(tx) => bincode::serialize(tx),
+            SealevelTxType::Versioned(tx) => bincode::serialize(tx),
+        }
+        .map_err(ChainCommunicationError::from_other)?;
+        let params = serde_json::json!([[Base64.encode(tx_bytes)], { "encoding": "base64" }]);
+
+        let mut last_err = None;
+        for url in &self.urls {
+            match self
+                .request::<_, String>(url, Self::SEND_BUNDLE_PATH, "sendBundle", &params)
+                .await
+            {
+                Ok(bundle_id) => return Ok((url.clone(), bundle_id)),
+                Err(err) => {
+                    warn!(?err, %url, "Failed to send Jito bundle");
+                    last_err = Some(err);
+                }
+            }
+        }
+        Err(last_err.unwrap_or_else(|| {
+            ChainCommunicationError::from_other_str("No Jito block-engine URL configured")
+        }))
+    }
+
+    async fn get_inflight_bundle_status(
+        &self,
+        url: &Url,
+        bundle_id: &str,
+    ) -> ChainResult<BundleStatus> {
+        let statuses: InflightBundleStatuses = self
+            .request(
+                url,
+                Self::INFLIGHT_BUNDLE_STATUSES_PATH,
+                "getInflightBundleStatuses",
+                &[[bundle_id]],
+            )
+            .await?;
+        let status = statuses
+            .value
+            .into_iter()
+            .find(|status| status.bundle_id == bundle_id)
+            .map(|status| status.status)
+            .unwrap_or(BundleStatus::Invalid);
+        Ok(status)
+    }
+
+    async fn request<P: Serialize, R: for<'de> Deserialize<'de>>(
+        &self,
+        url: &Url,
+        path: &str,
+        method: &str,
+        params: P,
+    ) -> ChainResult<R> {
+        let url = url
+            .join(path)
+            .map_err(ChainCommunicationError::from_other)?;
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0",
+            id: 1,
+            method,
+            params,
+        };
+        let response: JsonRpcResponse<R> = self
+            .client
+            .post(url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(ChainCommunicationError::from_other)?
+            .json()
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+        match (response.result, response.error) {
+            (Some(result), None) => Ok(result),
+            (_, error) => Err(ChainCommunicationError::from_other_str(&format!(
+                "Jito {method} request failed: {error:?}"
+            ))),
+        }
+    }
+}
+
+/// A transaction submitter that sends each transaction as a Jito bundle and
+/// falls back to RPC submission if the bundle doesn't land.
+///
+/// Resending through RPC is safe: a transaction can only be included once.
+#[derive(Debug)]
+pub struct JitoBundleTransactionSubmitter {
+    /// Used for the RPC fallback and other operations
+    default_provider: Arc<SealevelProvider>,
+    bundle_client: JitoBundleClient,
+}
+
+impl JitoBundleTransactionSubmitter {
+    /// Create a submitter sending bundles through the given block-engine URLs
+    pub fn new(default_provider: Arc<SealevelProvider>, urls: Vec<Url>) -> Self {
+        Self {
+            default_provider,
+            bundle_client: JitoBundleClient::new(urls),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionSubmitter for JitoBundleTransactionSubmitter {
+    fn get_priority_fee_instruction(
+        &self,
+        compute_unit_price_micro_lamports: u64,
+        compute_units: u64,
+        payer: &Pubkey,
+    ) -> Instruction {
+        // Bundles must pay a tip, the same way as bundle-only transactions
+        JitoTransactionSubmitter::tip_instruction(
+            compute_unit_price_micro_lamports,
+            compute_units,
+            payer,
+        )
+    }
+
+    async fn send_transaction(
+        &self,
+        transaction: &SealevelTxType,
+        skip_preflight: bool,
+    ) -> ChainResult<Signature> {
+        match self.bundle_client.submit(transaction).await {
+            Ok(BundleStatus::Landed) => {
+                return transaction.signature().copied().ok_or_else(|| {
+                    ChainCommunicationError::from_other_str("No signature in transaction")
+                });
+            }
+            Ok(status) => {
+                warn!(?status, "Jito bundle did not land, falling back to RPC");
+            }
+            Err(err) => {
+                warn!(?err, "Failed to submit Jito bundle, falling back to RPC");
+            }
+        }
+        self.default_provider
+            .rpc_client()
+            .send_sealevel_tx(transaction, skip_preflight)
+            .await
+    }
+
+    async fn wait_for_transaction_confirmation(
+        &self,
+        transaction: &SealevelTxType,
+    ) -> ChainResult<()> {
+        self.default_provider
+            .wait_for_transaction_confirmation(transaction)
+            .await
+    }
+
+    async fn confirm_transaction(
+        &self,
+        signature: Signature,
+        commitment: CommitmentConfig,
+    ) -> ChainResult<bool> {
+        self.default_provider
+            .confirm_transaction(signature, commitment)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use axum::{extract::State, routing::post, Json, Router};
+    use serde_json::{json, Value};
+    use solana_sdk::{hash::Hash, signature::Keypair, signer::Signer, transaction::Transaction};
+
+    use super::*;
+
+    /// A mock block engine, landing bundles after `pending_polls` status requests
+    #[derive(Clone)]
+    struct MockBlockEngine {
+        final_status: BundleStatus,
+        pending_polls: Arc<Mutex<usize>>,
+        bundles: Arc<Mutex<Vec<Value>>>,
+    }
+
+    async fn send_bundle(
+        State(engine): State<MockBlockEngine>,
+        Json(body): Json<Value>,
+    ) -> Json<Value> {
+        assert_eq!(body["method"], "sendBundle");
+        assert_eq!(body["params"][1]["encoding"], "base64");
+        engine
+            .bundles
+            .lock()
+            .unwrap()
+            .push(body["params"][0].clone());
+        Json(json!({ "jsonrpc": "2.0", "id": 1, "result": "bundle-id" }))
+    }
+
+    async fn bundle_statuses(
+        State(engine): State<MockBlockEngine>,
+        Json(body): Json<Value>,
+    ) -> Json<Value> {
+        assert_eq!(body["method"], "getInflightBundleStatuses");
+        let status = {
+            let mut pending_polls = engine.pending_polls.lock().unwrap();
+            if *pending_polls > 0 {
+                *pending_polls = pending_polls.saturating_sub(1);
+                BundleStatus::Pending
+            } else {
+                engine.final_status
+            }
+        };
+        Json(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "context": { "slot": 1 },
+                "value": [{ "bundle_id": "bundle-id", "status": status, "landed_slot": null }],
+            },
+        }))
+    }
+
+    async fn spawn_block_engine(
+        final_status: BundleStatus,
+        pending_polls: usize,
+    ) -> (Url, MockBlockEngine) {
+        let engine = MockBlockEngine {
+            final_status,
+            pending_polls: Arc::new(Mutex::new(pending_polls)),
+            bundles: Default::default(),
+        };
+        let app = Router::new()
+            .route("/api/v1/bundles", post(send_bundle))
+            .route("/api/v1/getInflightBundleStatuses", post(bundle_statuses))
+            .with_state(engine.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        let url = Url::parse(&format!("http://{addr}/")).unwrap();
+        (url, engine)
+    }
+
+    fn client(urls: Vec<Url>) -> JitoBundleClient {
+        JitoBundleClient {
+            status_poll_interval: Duration::from_millis(10),
+            status_timeout: Duration::from_millis(200),
+            ..JitoBundleClient::new(urls)
+        }
+    }
+
+    fn legacy_transaction() -> Transaction {
+        let payer = Keypair::new();
+        let instruction = solana_system_interface::instruction::transfer(
+            &payer.pubkey(),
+            &Pubkey::new_unique(),
+            1,
+        );
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[&payer],
+            Hash::default(),
+        )
+    }
+
+    fn transaction() -> SealevelTxType {
+        SealevelTxType::Legacy(legacy_transaction())
+    }
+
+    #[tokio::test]
+    async fn test_submit_bundle_lands() {
+        let (url, engine) = spawn_block_engine(BundleStatus::Landed, 2).await;
+        let transaction = legacy_transaction();
+
+        let status = client(vec![url])
+            .submit(&SealevelTxType::Legacy(transaction.clone()))
+            .await
+            .unwrap();
+
+        assert_eq!(status, BundleStatus::Landed);
+        let bundles = engine.bundles.lock().unwrap();
+        assert_eq!(bundles.len(), 1);
+        let expected = Base64.encode(bincode::serialize(&transaction).unwrap());
+        assert_eq!(bundles[0], json!([expected]));
+    }
+
+    #[tokio::test]
+    async fn test_submit_bundle_fails() {
+        let (url, _) = spawn_block_engine(BundleStatus::Failed, 0).await;
+
+        let status = client(vec![url]).submit(&transaction()).await.unwrap();
+
+        assert_eq!(status, BundleStatus::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_submit_bundle_times_out_while_pending() {
+        let (url, _) = spawn_block_engine(BundleStatus::Landed, usize::MAX).await;
+
+        let status = client(vec![url]).submit(&transaction()).await.unwrap();
+
+        assert_eq!(status, BundleStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_submit_bundle_tries_next_url() {
+        let unreachable = Url::parse("http://127.0.0.1:1/").unwrap();
+        let (url, engine) = spawn_block_engine(BundleStatus::Landed, 0).await;
+
+        let status = client(vec![unreachable, url])
+            .submit(&transaction())
+            .await
+            .unwrap();
+
+        assert_eq!(status, BundleStatus::Landed);
+        assert_eq!(engine.bundles.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_submit_bundle_without_urls() {
+        assert!(client(vec![]).submit(&transaction()).await.is_err());
+    }
+
+    #[test]
+    fn test_bundle_tip_instruction() {
+        let payer = Pubkey::new_unique();
+        let tip_account = solana_sdk::pubkey!("DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh");
+        let tip = |compute_unit_price_micro_lamports| {
+            JitoTransactionSubmitter::tip_instruction(
+                compute_unit_price_micro_lamports,
+                200_000,
+                &payer,
+            )
+        };
+
+        // 200_000 compute units at 50_000 microlamports each
+        assert_eq!(
+            tip(50_000),
+            solana_system_interface::instruction::transfer(&payer, &tip_account, 10_000)
+        );
+        // below the minimum tip
+        assert_eq!(
+            tip(1),
+            solana_system_interface::instruction::transfer(&payer, &tip_account, 1_000)
+        );
+    }
+}
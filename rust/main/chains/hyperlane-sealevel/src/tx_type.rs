@@ -6,7 +6,10 @@
 use serde::{Deserialize, Serialize};
 use solana_sdk::{
     hash::Hash,
-    message::VersionedMessage,
+    message::{
+        v0::MessageAddressTableLookup, AddressLookupTableAccount, MessageHeader, VersionedMessage,
+    },
+    pubkey::Pubkey,
     signature::Signature,
     transaction::{Transaction, VersionedTransaction},
 };
@@ -43,6 +46,50 @@ impl SealevelTxType {
         }
     }
 
+    /// Get the accounts the transaction locks as writable, excluding signers
+    /// such as the fee payer.
+    /// Writable accounts loaded from address lookup tables are resolved with
+    /// `lookup_tables`, the ones of a table missing from it are left out.
+    pub fn writable_non_signer_accounts(
+        &self,
+        lookup_tables: &[AddressLookupTableAccount],
+    ) -> Vec<Pubkey> {
+        let (header, account_keys) = match self {
+            SealevelTxType::Legacy(tx) => (&tx.message.header, &tx.message.account_keys),
+            SealevelTxType::Versioned(tx) => match &tx.message {
+                VersionedMessage::Legacy(msg) => (&msg.header, &msg.account_keys),
+                VersionedMessage::V0(msg) => (&msg.header, &msg.account_keys),
+            },
+        };
+        let mut accounts = writable_non_signer_accounts(header, account_keys);
+        for lookup in self.address_table_lookups() {
+            let Some(table) = lookup_tables
+                .iter()
+                .find(|table| table.key == lookup.account_key)
+            else {
+                continue;
+            };
+            accounts.extend(
+                lookup
+                    .writable_indexes
+                    .iter()
+                    .filter_map(|index| table.addresses.get(usize::from(*index))),
+            );
+        }
+        accounts
+    }
+
+    /// Get the address lookup tables the transaction loads accounts from.
+    pub fn address_table_lookups(&self) -> &[MessageAddressTableLookup] {
+        match self {
+            SealevelTxType::Versioned(VersionedTransaction {
+                message: VersionedMessage::V0(msg),
+                ..
+            }) => &msg.address_table_lookups,
+            _ => &[],
+        }
+    }
+
     /// Check if this is a legacy transaction.
     pub fn is_legacy(&self) -> bool {
         matches!(self, SealevelTxType::Legacy(_))
@@ -54,6 +101,17 @@ impl SealevelTxType {
     }
 }
 
+/// Unsigned accounts come after the signers, with the read-only ones last.
+fn writable_non_signer_accounts(header: &MessageHeader, account_keys: &[Pubkey]) -> Vec<Pubkey> {
+    let signers = usize::from(header.num_required_signatures);
+    let readonly_unsigned = usize::from(header.num_readonly_unsigned_accounts);
+    let writable_end = account_keys.len().saturating_sub(readonly_unsigned);
+    account_keys
+        .get(signers..writable_end)
+        .unwrap_or_default()
+        .to_vec()
+}
+
 impl From<Transaction> for SealevelTxType {
     fn from(tx: Transaction) -> Self {
         SealevelTxType::Legacy(tx)
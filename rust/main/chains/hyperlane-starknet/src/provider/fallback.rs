@@ -1,4 +1,7 @@
-use std::{ops::Deref, time::Duration};
+use std::{
+    ops::Deref,
+    time::{Duration, Instant},
+};
 
 /// Fallback HttpTransport
 /// the HttpTransport abstraction is the lowest level of abstraction on the starknet json provider
@@ -7,8 +10,11 @@ use std::{ops::Deref, time::Duration};
 /// https://github.com/xJonathanLEI/starknet-rs/blob/master/starknet-providers/src/jsonrpc/transports/http.rs
 use async_trait::async_trait;
 use hyperlane_core::{rpc_clients::FallbackProvider, ChainCommunicationError, ChainResult};
-use hyperlane_metric::prometheus_metric::{
-    ChainInfo, ClientConnectionType, PrometheusClientMetrics, PrometheusConfig,
+use hyperlane_metric::{
+    prometheus_metric::{
+        ChainInfo, ClientConnectionType, PrometheusClientMetrics, PrometheusConfig,
+    },
+    utils::urls_to_provider_nodes,
 };
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -57,6 +63,8 @@ impl FallbackHttpTransport {
         metrics: PrometheusClientMetrics,
         chain: Option<ChainInfo>,
     ) -> ChainResult<Self> {
+        let urls = urls.into_iter().collect::<Vec<_>>();
+        let provider_nodes = urls_to_provider_nodes(&urls);
         let providers = urls
             .into_iter()
             .map(|url| {
@@ -66,9 +74,15 @@ impl FallbackHttpTransport {
                 MetricProvider::new(url, metrics.clone(), metrics_config.clone())
             })
             .collect::<ChainResult<Vec<_>>>()?;
-        Ok(Self {
-            fallback: FallbackProvider::new(providers),
-        })
+        let fallback = FallbackProvider::builder()
+            .add_providers(providers)
+            .with_score_metric(
+                metrics.provider_score.clone(),
+                PrometheusConfig::chain_name(&chain),
+                provider_nodes,
+            )
+            .build();
+        Ok(Self { fallback })
     }
 }
 
@@ -113,7 +127,9 @@ impl JsonRpcTransport for FallbackHttpTransport {
                 // we have to do this, because the result does not implement the `Send` trait
                 let _ = self.handle_stalled_provider(priority, provider).await;
 
+                let start = Instant::now();
                 let result = provider.send_request(method, params_json.clone()).await;
+                self.record_response(priority.index, start.elapsed(), result.is_ok());
 
                 match result {
                     Ok(resp) => return Ok(resp),
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use derive_new::new;
 use url::Url;
 
@@ -14,4 +16,10 @@ pub struct ConnectionConf {
     pub wallet_solidity_urls: Vec<Url>,
     /// Optional Energy multiplier
     pub energy_multiplier: Option<f64>,
+    /// Optional cap, in sun, on the TRX a single transaction may burn for
+    /// energy and bandwidth not covered by staked resources
+    pub max_fee_limit: Option<u64>,
+    /// How long a transaction may be held back waiting for staked energy to
+    /// regenerate before it is submitted anyway. Disabled if not set.
+    pub max_energy_wait: Option<Duration>,
 }
@@ -1,4 +1,6 @@
-use hyperlane_core::ChainCommunicationError;
+use std::any::Any;
+
+use hyperlane_core::{ChainCommunicationError, HyperlaneCustomError};
 
 /// Errors from the crates specific to the hyperlane-tron
 /// implementation.
@@ -35,6 +37,14 @@ pub enum HyperlaneTronError {
     /// Missing Chain Parameter
     #[error("Missing chain parameter: {0}")]
     MissingChainParameter(String),
+    /// The transaction would burn more TRX than allowed
+    #[error("Estimated TRX burn of {estimated} sun exceeds the max fee limit of {max} sun")]
+    FeeLimitExceeded {
+        /// Estimated TRX burn, in sun
+        estimated: u64,
+        /// Configured max fee limit, in sun
+        max: u64,
+    },
     /// Broadcast transaction error
     #[error("Broadcast transaction error: {0}")]
     BroadcastTransactionError(String),
@@ -54,3 +64,14 @@ impl From<HyperlaneTronError> for ChainCommunicationError {
         ChainCommunicationError::from_other(value)
     }
 }
+
+impl HyperlaneTronError {
+    /// Get the Tron error wrapped by a chain communication error, if any
+    pub fn from_chain_error(err: &ChainCommunicationError) -> Option<&Self> {
+        let ChainCommunicationError::Other(wrapped) = err else {
+            return None;
+        };
+        let err: &dyn HyperlaneCustomError = wrapped.as_ref();
+        (err as &dyn Any).downcast_ref()
+    }
+}
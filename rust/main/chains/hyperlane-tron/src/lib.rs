@@ -13,11 +13,13 @@ pub use {
     contracts::TronMerkleTreeHook,
     contracts::TronMerkleTreeHookIndexer,
     contracts::TronValidatorAnnounce,
+    error::HyperlaneTronError,
     ism::TronAggregationIsm,
     ism::TronCcipReadIsm,
     ism::TronInterchainSecurityModule,
     ism::TronMultisigIsm,
     ism::TronRoutingIsm,
+    provider::AccountResources,
     provider::TronProvider,
     provider::TronProviderForLander,
     signer::{TronSigners, TronSignersError},
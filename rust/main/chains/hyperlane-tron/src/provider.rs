@@ -6,4 +6,5 @@ mod types;
 pub(crate) use http_client::TronHttpProvider;
 
 pub use lander::TronProviderForLander;
+pub use types::AccountResources;
 pub use tron::TronProvider;
@@ -9,14 +9,17 @@ use url::Url;
 
 use hyperlane_core::rpc_clients::{BlockNumberGetter, FallbackProvider};
 use hyperlane_core::{ChainCommunicationError, ChainResult};
-use hyperlane_metric::prometheus_metric::{
-    ChainInfo, ClientConnectionType, PrometheusClientMetrics, PrometheusConfig,
+use hyperlane_metric::{
+    prometheus_metric::{
+        ChainInfo, ClientConnectionType, PrometheusClientMetrics, PrometheusConfig,
+    },
+    utils::urls_to_provider_nodes,
 };
 use reqwest_utils::parse_custom_rpc_headers;
 
 use super::types::{
-    BlockResponse, BroadcastResponse, EstimateEnergyResponse, TriggerConstantResponse,
-    TriggerContractRequest,
+    AccountResourceResponse, BlockResponse, BroadcastResponse, ChainParametersResponse,
+    TriggerConstantResponse, TriggerContractRequest,
 };
 use crate::HyperlaneTronError;
 
@@ -160,7 +163,14 @@ impl TronHttpProvider {
             .iter()
             .map(|url| TronHttpChannel::new(url, metrics.clone(), chain.clone()))
             .collect::<Result<Vec<_>, _>>()?;
-        let fallback = FallbackProvider::new(channels);
+        let fallback = FallbackProvider::builder()
+            .add_providers(channels)
+            .with_score_metric(
+                metrics.provider_score.clone(),
+                PrometheusConfig::chain_name(&chain),
+                urls_to_provider_nodes(&urls),
+            )
+            .build();
         Ok(Self { fallback })
     }
 
@@ -216,18 +226,37 @@ impl TronHttpProvider {
             .await
     }
 
-    /// Estimate energy for a transaction
-    pub async fn estimate_energy(
+    /// Get the staked energy and bandwidth of an account
+    pub async fn get_account_resource(
         &self,
-        req: TriggerContractRequest,
-    ) -> ChainResult<EstimateEnergyResponse> {
+        address: String,
+    ) -> ChainResult<AccountResourceResponse> {
+        self.fallback
+            .call(|channel| {
+                let address = address.clone();
+                let future = async move {
+                    channel
+                        .track_metric_call("get_account_resource", || {
+                            channel.post_json(
+                                "/getaccountresource",
+                                serde_json::json!({"address": address, "visible": false}),
+                            )
+                        })
+                        .await
+                };
+                Box::pin(future)
+            })
+            .await
+    }
+
+    /// Get the chain parameters, which include the energy and bandwidth prices
+    pub async fn get_chain_parameters(&self) -> ChainResult<ChainParametersResponse> {
         self.fallback
             .call(|channel| {
-                let req = req.clone();
                 let future = async move {
                     channel
-                        .track_metric_call("estimate_energy", || {
-                            channel.post_json("/estimateenergy", req.clone())
+                        .track_metric_call("get_chain_parameters", || {
+                            channel.post_json("/getchainparameters", serde_json::json!({}))
                         })
                         .await
                 };
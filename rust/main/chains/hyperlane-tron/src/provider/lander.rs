@@ -8,7 +8,7 @@ use ethers_contract::decode_function_data;
 
 use hyperlane_core::{ChainCommunicationError, ChainResult, H256, H512};
 
-use crate::TronProvider;
+use crate::{AccountResources, TronProvider};
 
 /// Trait defining the necessary methods for a Tron provider to be used by the lander.
 #[async_trait]
@@ -34,6 +34,9 @@ pub trait TronProviderForLander: Send + Sync {
         tx: &TypedTransaction,
         function: &Function,
     ) -> ChainResult<T>;
+
+    /// Gets the staked energy and bandwidth currently available to the signer.
+    async fn get_account_resources(&self) -> ChainResult<AccountResources>;
 }
 
 #[async_trait]
@@ -67,4 +70,8 @@ impl TronProviderForLander for TronProvider {
             .map_err(ChainCommunicationError::from_other)?;
         Ok(success)
     }
+
+    async fn get_account_resources(&self) -> ChainResult<AccountResources> {
+        TronProvider::get_account_resources(self).await
+    }
 }
@@ -1,5 +1,5 @@
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
 use ethers::abi::Address;
@@ -13,6 +13,7 @@ use num::ToPrimitive;
 use prost::Message;
 use prost_types::Any;
 use time::OffsetDateTime;
+use tokio::sync::Mutex;
 use tracing::{debug, instrument};
 use tron_rs::tron::protocol::{
     transaction::{self, contract::ContractType, Contract},
@@ -26,7 +27,7 @@ use hyperlane_core::{
 };
 use hyperlane_metric::prometheus_metric::{self, PrometheusClientMetrics};
 
-use super::types::{BlockResponse, TriggerContractRequest, TronApiError};
+use super::types::{AccountResources, BlockResponse, TriggerContractRequest, TronApiError};
 use crate::{
     build_fallback_provider, calculate_ref_block_bytes, calculate_ref_block_hash, calculate_txid,
     ConnectionConf, HyperlaneTronError, JsonProvider, TronHttpProvider, TronSigner,
@@ -45,6 +46,45 @@ fn decode_tron_error(result: &TronApiError) -> String {
     format!("code={:?}, message={:?}", result.code, decoded)
 }
 
+/// NOTE: Tron addresses need to be prefixed with a byte 0x41
+const ADDRESS_PREFIX: u8 = 0x41;
+
+/// Chain parameter holding the price of a byte of bandwidth, in sun
+const BANDWIDTH_PRICE_PARAMETER: &str = "getTransactionFee";
+
+/// Bandwidth charged on top of the serialized transaction for its result
+const TX_RESULT_BANDWIDTH: u64 = 64;
+
+/// How long the bandwidth price is reused before querying the chain parameters again
+const BANDWIDTH_PRICE_TTL: Duration = Duration::from_secs(600);
+
+/// Hex encoding of an address as expected by the wallet HTTP API
+fn tron_hex_address(address: H160) -> String {
+    hex::encode([&[ADDRESS_PREFIX], address.as_bytes()].concat())
+}
+
+/// Estimate the TRX, in sun, burnt by a transaction for the energy and
+/// bandwidth not covered by the account's staked resources.
+/// Tron burns bandwidth for the whole transaction if the staked bandwidth
+/// does not cover it.
+fn estimate_trx_burn(
+    energy: u64,
+    energy_price: u64,
+    tx_size: u64,
+    bandwidth_price: u64,
+    resources: &AccountResources,
+) -> u64 {
+    let energy_burn = energy
+        .saturating_sub(resources.available_energy)
+        .saturating_mul(energy_price);
+    let bandwidth_burn = if resources.available_bandwidth >= tx_size {
+        0
+    } else {
+        tx_size.saturating_mul(bandwidth_price)
+    };
+    energy_burn.saturating_add(bandwidth_burn)
+}
+
 /// Tron Provider
 #[derive(Clone, Debug)]
 pub struct TronProvider {
@@ -54,6 +94,8 @@ pub struct TronProvider {
     domain: HyperlaneDomain,
     signer: Option<TronSigner>,
     energy_multiplier: f64,
+    max_fee_limit: Option<u64>,
+    bandwidth_price: Arc<Mutex<Option<(Instant, u64)>>>,
 }
 
 impl TronProvider {
@@ -81,6 +123,8 @@ impl TronProvider {
             domain: locator.domain.clone(),
             signer,
             energy_multiplier: conf.energy_multiplier.unwrap_or(DEFAULT_ENERGY_MULTIPLIER),
+            max_fee_limit: conf.max_fee_limit,
+            bandwidth_price: Default::default(),
         })
     }
 
@@ -205,11 +249,9 @@ impl TronProvider {
             ));
         }
 
-        // NOTE: Tron addresses need to be prefixed with a byte 0x41
-        const ADDRESS_PREFIX: u8 = 0x41;
         Ok(TriggerContractRequest {
-            owner_address: hex::encode([&[ADDRESS_PREFIX], owner.as_bytes()].concat()),
-            contract_address: hex::encode([&[ADDRESS_PREFIX], to.as_bytes()].concat()),
+            owner_address: tron_hex_address(owner),
+            contract_address: tron_hex_address(to),
             data: hex::encode(data.to_vec()),
             call_value: value.as_u64() as i64,
             visible: false,
@@ -232,6 +274,82 @@ impl TronProvider {
         })
     }
 
+    /// Get the staked energy and bandwidth currently available to the signer
+    pub async fn get_account_resources(&self) -> ChainResult<AccountResources> {
+        let address = tron_hex_address(self.get_signer()?.address());
+        let resources = self.wallet.get_account_resource(address).await?;
+        Ok(resources.into())
+    }
+
+    /// Get the price of a byte of bandwidth, in sun.
+    /// The price is a chain parameter which rarely changes, so it is cached.
+    async fn get_bandwidth_price(&self) -> ChainResult<u64> {
+        let mut cached = self.bandwidth_price.lock().await;
+        if let Some((fetched_at, price)) = *cached {
+            if fetched_at.elapsed() < BANDWIDTH_PRICE_TTL {
+                return Ok(price);
+            }
+        }
+        let price = self.fetch_bandwidth_price().await?;
+        *cached = Some((Instant::now(), price));
+        Ok(price)
+    }
+
+    async fn fetch_bandwidth_price(&self) -> ChainResult<u64> {
+        let params = self.wallet.get_chain_parameters().await?;
+        let param = params
+            .chain_parameter
+            .into_iter()
+            .find(|param| param.key == BANDWIDTH_PRICE_PARAMETER)
+            .ok_or_else(|| {
+                HyperlaneTronError::MissingChainParameter(BANDWIDTH_PRICE_PARAMETER.to_owned())
+            })?;
+        Ok(param.value.unwrap_or_default().max(0) as u64)
+    }
+
+    /// Reject the transaction if it would burn more TRX than `max_fee_limit`.
+    /// The staked resources of the signer are only queried when the
+    /// transaction could exceed the limit without them.
+    async fn check_trx_burn(
+        &self,
+        energy: u64,
+        energy_price: u64,
+        tx_size: u64,
+        max_fee_limit: u64,
+    ) -> ChainResult<()> {
+        let bandwidth_price = self.get_bandwidth_price().await?;
+        let worst_case = estimate_trx_burn(
+            energy,
+            energy_price,
+            tx_size,
+            bandwidth_price,
+            &AccountResources::default(),
+        );
+        if worst_case <= max_fee_limit {
+            return Ok(());
+        }
+
+        let resources = self.get_account_resources().await?;
+        let burn = estimate_trx_burn(energy, energy_price, tx_size, bandwidth_price, &resources);
+        debug!(
+            energy,
+            energy_price,
+            tx_size,
+            bandwidth_price,
+            ?resources,
+            burn,
+            "Estimated TRX burn for transaction"
+        );
+        if burn > max_fee_limit {
+            return Err(HyperlaneTronError::FeeLimitExceeded {
+                estimated: burn,
+                max: max_fee_limit,
+            }
+            .into());
+        }
+        Ok(())
+    }
+
     /// Get the current block
     async fn get_current_block(&self) -> ChainResult<BlockResponse> {
         self.wallet_solidity.get_now_block().await
@@ -285,10 +403,18 @@ impl TronProvider {
             Some(gas) => gas,
             None => &self.estimate_gas(tx, None).await?,
         };
-        let fee_limit = energy_estimate.saturating_mul(energy_price).as_u64();
+        let u64_max = ethers::types::U256::from(u64::MAX);
+        let energy_price = energy_price.min(u64_max).as_u64();
+        let energy_estimate = (*energy_estimate).min(u64_max).as_u64();
+        let fee_limit = energy_estimate.saturating_mul(energy_price);
         let fee_limit = (fee_limit as f64 * self.energy_multiplier)
             .to_u64()
             .unwrap_or(u64::MAX);
+        // The fee limit only bounds the TRX burnt for energy, staked energy is used first
+        let fee_limit = match self.max_fee_limit {
+            Some(max_fee_limit) => fee_limit.min(max_fee_limit),
+            None => fee_limit,
+        };
 
         let block = self.get_current_block().await?;
         let tron_call = self.parse_tx(tx)?;
@@ -304,6 +430,12 @@ impl TronProvider {
         // Set the signature
         tx.signature = vec![signature.to_vec()];
 
+        if let Some(max_fee_limit) = self.max_fee_limit {
+            let tx_size = (tx.encoded_len() as u64).saturating_add(TX_RESULT_BANDWIDTH);
+            self.check_trx_burn(energy_estimate, energy_price, tx_size, max_fee_limit)
+                .await?;
+        }
+
         self.broadcast_transaction(tx).await?;
 
         Ok(hash)
@@ -406,9 +538,10 @@ impl Middleware for TronProvider {
             .parse_tx(tx)
             .map_err(|e| ProviderError::CustomError(e.to_string()))?;
 
+        // `/estimateenergy` is disabled on most nodes, so we simulate the call instead
         let estimate = self
             .wallet
-            .estimate_energy(call)
+            .trigger_constant_contract(call)
             .await
             .map_err(|e| ProviderError::CustomError(e.to_string()))?;
 
@@ -422,14 +555,14 @@ impl Middleware for TronProvider {
             }
         }
 
-        if estimate.energy_required <= 0 {
+        if estimate.energy_used <= 0 {
             return Err(ProviderError::CustomError(format!(
                 "Energy estimation returned invalid value: {}",
-                estimate.energy_required
+                estimate.energy_used
             )));
         }
 
-        Ok(ethers::types::U256::from(estimate.energy_required as u64))
+        Ok(ethers::types::U256::from(estimate.energy_used as u64))
     }
 }
 
@@ -524,3 +657,45 @@ impl HyperlaneProvider for TronProvider {
         Ok(Some(chain_metrics))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::types::AccountResourceResponse;
+
+    #[test]
+    fn test_account_resources_from_response() {
+        let resp: AccountResourceResponse = serde_json::from_str(
+            r#"{"freeNetLimit":1500,"freeNetUsed":300,"NetLimit":1000,"NetUsed":1200,"EnergyLimit":50000,"EnergyUsed":20000}"#,
+        )
+        .unwrap();
+        let resources = AccountResources::from(resp);
+        assert_eq!(resources.available_energy, 30_000);
+        // over-used staked bandwidth does not eat into the free bandwidth
+        assert_eq!(resources.available_bandwidth, 1_200);
+
+        let empty: AccountResourceResponse = serde_json::from_str("{}").unwrap();
+        assert_eq!(AccountResources::from(empty), AccountResources::default());
+    }
+
+    #[test]
+    fn test_estimate_trx_burn() {
+        let resources = AccountResources {
+            available_energy: 30_000,
+            available_bandwidth: 400,
+        };
+
+        // fully covered by staked resources
+        assert_eq!(estimate_trx_burn(30_000, 100, 400, 1_000, &resources), 0);
+        // energy shortfall is burnt at the energy price
+        assert_eq!(
+            estimate_trx_burn(35_000, 100, 400, 1_000, &resources),
+            500_000
+        );
+        // bandwidth is burnt for the whole transaction when not covered
+        assert_eq!(
+            estimate_trx_burn(30_000, 100, 401, 1_000, &resources),
+            401_000
+        );
+    }
+}
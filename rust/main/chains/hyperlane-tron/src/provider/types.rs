@@ -32,18 +32,54 @@ pub struct TriggerConstantResponse {
     /// Hex-encoded return values
     #[serde(default)]
     pub constant_result: Vec<String>,
+    /// Energy consumed by the call
+    #[serde(default)]
+    pub energy_used: i64,
     /// Result of the call (contains error info on failure)
     pub result: Option<TronApiError>,
 }
 
-/// Response from `/wallet/estimateenergy`
+/// Response from `/wallet/getaccountresource`
+///
+/// The node omits fields which are zero.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct AccountResourceResponse {
+    /// Daily free bandwidth granted to every account
+    #[serde(rename = "freeNetLimit")]
+    pub free_net_limit: i64,
+    /// Free bandwidth used
+    #[serde(rename = "freeNetUsed")]
+    pub free_net_used: i64,
+    /// Bandwidth obtained by staking TRX
+    #[serde(rename = "NetLimit")]
+    pub net_limit: i64,
+    /// Staked bandwidth used
+    #[serde(rename = "NetUsed")]
+    pub net_used: i64,
+    /// Energy obtained by staking TRX
+    #[serde(rename = "EnergyLimit")]
+    pub energy_limit: i64,
+    /// Staked energy used
+    #[serde(rename = "EnergyUsed")]
+    pub energy_used: i64,
+}
+
+/// Response from `/wallet/getchainparameters`
 #[derive(Debug, Deserialize)]
-pub struct EstimateEnergyResponse {
-    /// Energy required (may be absent on estimation failure)
-    #[serde(default)]
-    pub energy_required: i64,
-    /// Result of the estimation
-    pub result: Option<TronApiError>,
+pub struct ChainParametersResponse {
+    /// Chain parameters
+    #[serde(rename = "chainParameter", default)]
+    pub chain_parameter: Vec<ChainParameter>,
+}
+
+/// A single chain parameter
+#[derive(Debug, Deserialize)]
+pub struct ChainParameter {
+    /// Parameter name, e.g. `getEnergyFee`
+    pub key: String,
+    /// Parameter value (absent when zero)
+    pub value: Option<i64>,
 }
 
 /// Error details from Tron API responses (shared across estimate, trigger, and broadcast)
@@ -66,7 +102,7 @@ pub struct BroadcastResponse {
     pub message: Option<String>,
 }
 
-/// Request body for `/triggerconstantcontract` and `/estimateenergy`
+/// Request body for `/triggerconstantcontract`
 #[derive(Debug, Clone, Serialize)]
 pub struct TriggerContractRequest {
     /// Owner address (hex with 41 prefix)
@@ -80,3 +116,23 @@ pub struct TriggerContractRequest {
     /// Always false (hex addresses)
     pub visible: bool,
 }
+
+/// Staked resources currently available to an account
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AccountResources {
+    /// Energy which can be spent without burning TRX
+    pub available_energy: u64,
+    /// Bandwidth which can be spent without burning TRX
+    pub available_bandwidth: u64,
+}
+
+impl From<AccountResourceResponse> for AccountResources {
+    fn from(resp: AccountResourceResponse) -> Self {
+        let remaining = |limit: i64, used: i64| limit.saturating_sub(used).max(0) as u64;
+        Self {
+            available_energy: remaining(resp.energy_limit, resp.energy_used),
+            available_bandwidth: remaining(resp.free_net_limit, resp.free_net_used)
+                .saturating_add(remaining(resp.net_limit, resp.net_used)),
+        }
+    }
+}
@@ -10,8 +10,9 @@ use url::Url;
 use ethers_prometheus::json_rpc_client::{JsonRpcBlockGetter, PrometheusJsonRpcClient};
 use hyperlane_core::{rpc_clients::FallbackProvider, ChainCommunicationError, ChainResult, H256};
 use hyperlane_ethereum::EthereumFallbackProvider;
-use hyperlane_metric::prometheus_metric::{
-    self, ClientConnectionType, PrometheusClientMetrics, PrometheusConfig,
+use hyperlane_metric::{
+    prometheus_metric::{self, ClientConnectionType, PrometheusClientMetrics, PrometheusConfig},
+    utils::urls_to_provider_nodes,
 };
 use reqwest_utils::parse_custom_rpc_headers;
 
@@ -46,7 +47,11 @@ pub(crate) fn build_fallback_provider(
     metrics: PrometheusClientMetrics,
     chain: Option<prometheus_metric::ChainInfo>,
 ) -> ChainResult<JsonProvider> {
-    let mut builder = FallbackProvider::builder();
+    let mut builder = FallbackProvider::builder().with_score_metric(
+        metrics.provider_score.clone(),
+        PrometheusConfig::chain_name(&chain),
+        urls_to_provider_nodes(rpcs),
+    );
     for url in rpcs {
         let http_provider = build_http_provider(url.clone())?;
         let metrics_provider =
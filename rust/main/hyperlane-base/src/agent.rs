@@ -10,10 +10,38 @@ use tracing::info;
 
 use crate::{
     metrics::{AgentMetrics, CoreMetrics, RuntimeMetrics},
-    settings::Settings,
+    settings::{
+        check::{check_chains, ConfigCheck, ConfigCheckReport},
+        Settings,
+    },
     ChainMetrics,
 };
 
+/// Command line flag which makes an agent check its config and exit instead
+/// of running
+pub const CHECK_CONFIG_FLAG: &str = "check-config";
+
+/// Command line flag which makes an agent print the JSON Schema of its config
+/// and exit instead of running
+pub const PRINT_CONFIG_SCHEMA_FLAG: &str = "print-config-schema";
+
+/// Subcommand which runs the validator audit instead of the validator
+pub const AUDIT_SUBCOMMAND: &str = "audit";
+
+/// Subcommand doing the same as `--check-config`
+pub const CHECK_CONFIG_SUBCOMMAND: &str = "check";
+
+/// Subcommand doing the same as `--print-config-schema`
+pub const PRINT_CONFIG_SCHEMA_SUBCOMMAND: &str = "schema";
+
+/// Subcommands an agent binary may be started with, e.g. `validator audit`.
+/// They select what runs, so they are not parsed as config.
+pub const AGENT_SUBCOMMANDS: &[&str] = &[
+    AUDIT_SUBCOMMAND,
+    CHECK_CONFIG_SUBCOMMAND,
+    PRINT_CONFIG_SCHEMA_SUBCOMMAND,
+];
+
 /// Properties shared across all hyperlane agents
 #[derive(Debug)]
 pub struct HyperlaneAgentCore {
@@ -28,6 +56,9 @@ pub trait LoadableFromSettings: AsRef<Settings> + Sized {
     /// Create a new instance of these settings by reading the configs and env
     /// vars.
     fn load(agent_name: &str) -> ConfigResult<Self>;
+
+    /// JSON Schema of the config these settings are loaded from.
+    fn config_schema(agent_name: &str) -> serde_json::Value;
 }
 
 /// Metadata of an agent defined from configuration
@@ -44,7 +75,7 @@ pub trait BaseAgent: Send + Sync + Debug {
     const AGENT_NAME: &'static str;
 
     /// The settings object for this agent
-    type Settings: LoadableFromSettings;
+    type Settings: LoadableFromSettings + Sync;
 
     /// The agents metadata type
     type Metadata: MetadataFromSettings<Self::Settings>;
@@ -65,6 +96,12 @@ pub trait BaseAgent: Send + Sync + Debug {
     /// Start running this agent.
     #[allow(clippy::async_yields_async)]
     async fn run(self);
+
+    /// Checks of the agent specific settings, run by `--check-config` on top
+    /// of the checks of every chain. Must not need network access.
+    async fn check_settings(_settings: &Self::Settings) -> Vec<ConfigCheck> {
+        vec![]
+    }
 }
 
 /// Call this from `main` to fully initialize and run the agent for its entire
@@ -86,6 +123,19 @@ pub async fn agent_main<A: BaseAgent>() -> Result<()> {
         color_eyre::install()?;
     }
 
+    if has_flag(PRINT_CONFIG_SCHEMA_FLAG) || subcommand() == Some(PRINT_CONFIG_SCHEMA_SUBCOMMAND) {
+        let schema = A::Settings::config_schema(A::AGENT_NAME);
+        println!("{}", serde_json::to_string_pretty(&schema)?);
+        return Ok(());
+    }
+    if has_flag(CHECK_CONFIG_FLAG) || subcommand() == Some(CHECK_CONFIG_SUBCOMMAND) {
+        return check_config::<A>().await;
+    }
+    // binaries with an audit handle it before running the agent
+    if subcommand() == Some(AUDIT_SUBCOMMAND) {
+        eyre::bail!("The {} agent has no `audit` subcommand", A::AGENT_NAME);
+    }
+
     // Logging is not initialised at this point, so, using `println!`
     println!(
         "Agent {} starting up with version {}",
@@ -117,5 +167,48 @@ pub async fn agent_main<A: BaseAgent>() -> Result<()> {
     // This await will only end if a panic happens. We won't crash, but instead gracefully shut down
     agent.run().await;
     info!(agent = A::AGENT_NAME, "Shutting down agent...");
+    crate::settings::shutdown_tracing().await;
     Ok(())
 }
+
+/// Loads and checks the settings of the agent without running it, and prints
+/// the report. Fails if the config is not valid.
+async fn check_config<A: BaseAgent>() -> Result<()> {
+    let report = match A::Settings::load(A::AGENT_NAME) {
+        Ok(settings) => {
+            let mut checks = check_chains(settings.as_ref()).await;
+            checks.extend(A::check_settings(&settings).await);
+            ConfigCheckReport::from_checks(A::AGENT_NAME, checks)
+        }
+        Err(err) => ConfigCheckReport::from_parsing_error(A::AGENT_NAME, &err),
+    };
+
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    if report.valid {
+        Ok(())
+    } else {
+        Err(eyre::eyre!("Config of the {} is not valid", A::AGENT_NAME))
+    }
+}
+
+/// The subcommand the agent was started with, if any
+pub fn subcommand() -> Option<&'static str> {
+    subcommand_of(env::args_os().nth(1).as_deref())
+}
+
+/// The known subcommand `arg` is, if any. Any other argument is left to the
+/// config parser, which rejects the ones that are not a config key.
+pub(crate) fn subcommand_of(arg: Option<&std::ffi::OsStr>) -> Option<&'static str> {
+    let arg = arg?.to_str()?;
+    AGENT_SUBCOMMANDS
+        .iter()
+        .find(|subcommand| **subcommand == arg)
+        .copied()
+}
+
+/// Whether the agent was started with the `--<flag>` command line flag
+fn has_flag(flag: &str) -> bool {
+    env::args()
+        .skip(1)
+        .any(|arg| arg.strip_prefix("--") == Some(flag))
+}
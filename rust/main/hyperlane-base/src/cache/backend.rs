@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use url::Url;
+
+use crate::db::{CALL_CACHE_CF, DB};
+
+use super::{CacheResult, DbCache, ExpirationType, FunctionCallCache, LocalCache, RedisCache};
+
+/// Where a function call cache keeps its entries
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum CacheBackendConf {
+    /// In memory of the agent, lost on restart
+    #[default]
+    Local,
+    /// In a column family of the agent database, kept across restarts
+    Db,
+    /// In a Redis server, shared by the agents using it
+    Redis {
+        /// `redis://` or, with TLS, `rediss://` url of the server
+        url: Url,
+    },
+}
+
+impl CacheBackendConf {
+    /// Column families of the agent database the backend keeps its entries
+    /// in, which have to be created when opening the database
+    pub fn column_families(&self) -> &'static [&'static str] {
+        match self {
+            Self::Db => &[CALL_CACHE_CF],
+            Self::Local | Self::Redis { .. } => &[],
+        }
+    }
+}
+
+/// A function call cache using the backend chosen in the agent config
+#[derive(Debug, Clone)]
+pub enum CacheBackend {
+    /// See [`LocalCache`]
+    Local(LocalCache),
+    /// See [`DbCache`]
+    Db(DbCache),
+    /// See [`RedisCache`]
+    Redis(RedisCache),
+}
+
+impl CacheBackend {
+    /// Create the cache `name` with the configured backend, storing its
+    /// entries in `db` if configured so
+    pub fn new(conf: &CacheBackendConf, name: &str, db: &DB) -> CacheResult<Self> {
+        Ok(match conf {
+            CacheBackendConf::Local => Self::Local(LocalCache::new(name)),
+            CacheBackendConf::Db => Self::Db(DbCache::new(db.clone())),
+            CacheBackendConf::Redis { url } => Self::Redis(RedisCache::new(url.clone(), name)?),
+        })
+    }
+}
+
+impl From<LocalCache> for CacheBackend {
+    fn from(cache: LocalCache) -> Self {
+        Self::Local(cache)
+    }
+}
+
+#[async_trait]
+impl FunctionCallCache for CacheBackend {
+    async fn cache_call_result(
+        &self,
+        domain_name: &str,
+        fn_key: &str,
+        fn_params: &(impl Serialize + Send + Sync),
+        result: &(impl Serialize + Send + Sync),
+    ) -> CacheResult<()> {
+        match self {
+            Self::Local(cache) => {
+                cache
+                    .cache_call_result(domain_name, fn_key, fn_params, result)
+                    .await
+            }
+            Self::Db(cache) => {
+                cache
+                    .cache_call_result(domain_name, fn_key, fn_params, result)
+                    .await
+            }
+            Self::Redis(cache) => {
+                cache
+                    .cache_call_result(domain_name, fn_key, fn_params, result)
+                    .await
+            }
+        }
+    }
+
+    async fn cache_call_result_with_expiration(
+        &self,
+        domain_name: &str,
+        fn_key: &str,
+        fn_params: &(impl Serialize + Send + Sync),
+        result: &(impl Serialize + Send + Sync),
+        expiration: ExpirationType,
+    ) -> CacheResult<()> {
+        match self {
+            Self::Local(cache) => {
+                cache
+                    .cache_call_result_with_expiration(
+                        domain_name,
+                        fn_key,
+                        fn_params,
+                        result,
+                        expiration,
+                    )
+                    .await
+            }
+            Self::Db(cache) => {
+                cache
+                    .cache_call_result_with_expiration(
+                        domain_name,
+                        fn_key,
+                        fn_params,
+                        result,
+                        expiration,
+                    )
+                    .await
+            }
+            Self::Redis(cache) => {
+                cache
+                    .cache_call_result_with_expiration(
+                        domain_name,
+                        fn_key,
+                        fn_params,
+                        result,
+                        expiration,
+                    )
+                    .await
+            }
+        }
+    }
+
+    async fn get_cached_call_result<T>(
+        &self,
+        domain_name: &str,
+        method: &str,
+        fn_params: &(impl Serialize + Send + Sync),
+    ) -> CacheResult<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        match self {
+            Self::Local(cache) => {
+                cache
+                    .get_cached_call_result::<T>(domain_name, method, fn_params)
+                    .await
+            }
+            Self::Db(cache) => {
+                cache
+                    .get_cached_call_result::<T>(domain_name, method, fn_params)
+                    .await
+            }
+            Self::Redis(cache) => {
+                cache
+                    .get_cached_call_result::<T>(domain_name, method, fn_params)
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use tracing::{debug, info_span, warn, Instrument};
+
+use crate::db::{CALL_CACHE_CF, DB};
+
+use super::{
+    stored_call::{call_key, StoredCall},
+    CacheError, CacheResult, ExpirationType, FunctionCallCache,
+};
+
+/// Time between removals of the expired entries
+const SWEEP_INTERVAL: Duration = Duration::from_secs(3_600);
+
+/// Cache for storing function calls in a column family of the agent database,
+/// so that they survive restarts. Expired entries are removed when read and
+/// by a periodic sweep, which catches the ones never read again.
+///
+/// The database has to be opened with the [`CALL_CACHE_CF`] column family.
+#[derive(Debug, Clone)]
+pub struct DbCache {
+    db: DB,
+}
+
+impl DbCache {
+    /// Create a new cache in the given database
+    pub fn new(db: DB) -> Self {
+        Self { db }
+    }
+
+    /// Spawns a tokio task removing the expired entries on an interval
+    pub fn spawn_sweeper(self) -> JoinHandle<()> {
+        tokio::task::Builder::new()
+            .name("cache::sweeper")
+            .spawn(
+                async move {
+                    self.run_sweeper().await;
+                }
+                .instrument(info_span!("DbCacheSweeper")),
+            )
+            .expect("spawning tokio task from Builder is infallible")
+    }
+
+    async fn run_sweeper(self) {
+        let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            let cache = self.clone();
+            match tokio::task::spawn_blocking(move || cache.remove_expired()).await {
+                Ok(Ok(removed)) => debug!(removed, "Removed expired cache entries"),
+                Ok(Err(err)) => warn!(?err, "Failed to remove expired cache entries"),
+                Err(err) => warn!(?err, "Cache sweeping task failed"),
+            }
+        }
+    }
+
+    /// Remove the expired entries, returning how many were removed
+    pub fn remove_expired(&self) -> CacheResult<usize> {
+        let mut removed: usize = 0;
+        for entry in self.db.entries_cf(CALL_CACHE_CF) {
+            let (key, value) = entry.map_err(CacheError::DbError)?;
+            // entries which can't be decoded are overwritten when cached again
+            let Ok(stored) = StoredCall::decode(&value) else {
+                continue;
+            };
+            if stored.expiration().is_expired() {
+                self.db
+                    .delete_cf(CALL_CACHE_CF, &key)
+                    .map_err(CacheError::DbError)?;
+                removed = removed.saturating_add(1);
+            }
+        }
+        Ok(removed)
+    }
+}
+
+#[async_trait]
+impl FunctionCallCache for DbCache {
+    async fn cache_call_result(
+        &self,
+        domain_name: &str,
+        method: &str,
+        fn_params: &(impl Serialize + Send + Sync),
+        result: &(impl Serialize + Send + Sync),
+    ) -> CacheResult<()> {
+        self.cache_call_result_with_expiration(
+            domain_name,
+            method,
+            fn_params,
+            result,
+            ExpirationType::Default,
+        )
+        .await
+    }
+
+    async fn cache_call_result_with_expiration(
+        &self,
+        domain_name: &str,
+        method: &str,
+        fn_params: &(impl Serialize + Send + Sync),
+        result: &(impl Serialize + Send + Sync),
+        expiration: ExpirationType,
+    ) -> CacheResult<()> {
+        let stored = StoredCall::new(result, expiration)?;
+        if stored.expiration().is_expired() {
+            return Ok(());
+        }
+        let key = call_key(domain_name, method, fn_params)?;
+        self.db
+            .store_cf(CALL_CACHE_CF, key.as_bytes(), &stored.encode()?)
+            .map_err(CacheError::DbError)
+    }
+
+    async fn get_cached_call_result<T>(
+        &self,
+        domain_name: &str,
+        method: &str,
+        fn_params: &(impl Serialize + Send + Sync),
+    ) -> CacheResult<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let key = call_key(domain_name, method, fn_params)?;
+        let Some(bytes) = self
+            .db
+            .retrieve_cf(CALL_CACHE_CF, key.as_bytes())
+            .map_err(CacheError::DbError)?
+        else {
+            return Ok(None);
+        };
+        let stored = StoredCall::decode(&bytes)?;
+        if stored.expiration().is_expired() {
+            self.db
+                .delete_cf(CALL_CACHE_CF, key.as_bytes())
+                .map_err(CacheError::DbError)?;
+            return Ok(None);
+        }
+        stored.into_value().map(Some)
+    }
+}
+
+#[cfg(all(test, feature = "rocksdb"))]
+mod test {
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    use crate::cache::NoParams;
+
+    use super::*;
+
+    fn cache_db(dir: &TempDir) -> DB {
+        DB::from_path_with_column_families(dir.path(), &[CALL_CACHE_CF]).unwrap()
+    }
+
+    fn past() -> u64 {
+        (Utc::now().timestamp() - 1) as u64
+    }
+
+    #[tokio::test]
+    async fn entries_expire() {
+        let dir = TempDir::new().unwrap();
+        let cache = DbCache::new(cache_db(&dir));
+        let cases = [
+            ("never", ExpirationType::Never, true),
+            ("default", ExpirationType::Default, true),
+            (
+                "duration",
+                ExpirationType::AfterDuration(Duration::from_secs(60)),
+                true,
+            ),
+            ("past", ExpirationType::AfterTimestamp(past()), false),
+        ];
+        for (method, expiration, _) in cases {
+            cache
+                .cache_call_result_with_expiration("test", method, &1, &42u32, expiration)
+                .await
+                .unwrap();
+        }
+        for (method, _, cached) in cases {
+            let value = cache
+                .get_cached_call_result::<u32>("test", method, &1)
+                .await
+                .unwrap();
+            assert_eq!(value, cached.then_some(42), "{method}");
+        }
+    }
+
+    #[tokio::test]
+    async fn expired_entries_are_swept() {
+        let dir = TempDir::new().unwrap();
+        let db = cache_db(&dir);
+        let cache = DbCache::new(db.clone());
+        cache
+            .cache_call_result_with_expiration("test", "fresh", &1, &42u32, ExpirationType::Never)
+            .await
+            .unwrap();
+        // entries expire after being cached, and may never be read again
+        let expired = StoredCall::new(&42u32, ExpirationType::AfterTimestamp(past())).unwrap();
+        let expired_key = call_key("test", "expired", &1).unwrap();
+        db.store_cf(
+            CALL_CACHE_CF,
+            expired_key.as_bytes(),
+            &expired.encode().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(cache.remove_expired().unwrap(), 1);
+        assert_eq!(cache.remove_expired().unwrap(), 0);
+        assert_eq!(
+            db.retrieve_cf(CALL_CACHE_CF, expired_key.as_bytes())
+                .unwrap(),
+            None
+        );
+        let fresh = cache
+            .get_cached_call_result::<u32>("test", "fresh", &1)
+            .await
+            .unwrap();
+        assert_eq!(fresh, Some(42));
+    }
+
+    #[tokio::test]
+    async fn entries_survive_reopening() {
+        let dir = TempDir::new().unwrap();
+        // the cache is written by one process and read by the next one
+        {
+            let cache = DbCache::new(cache_db(&dir));
+            cache
+                .cache_call_result("test", "validators", &NoParams, &vec!["a", "b"])
+                .await
+                .unwrap();
+        }
+        let cache = DbCache::new(cache_db(&dir));
+        let value = cache
+            .get_cached_call_result::<Vec<String>>("test", "validators", &NoParams)
+            .await
+            .unwrap();
+        assert_eq!(value, Some(vec!["a".to_owned(), "b".to_owned()]));
+    }
+
+    #[test]
+    fn column_family_is_only_created_for_the_cache() {
+        let dir = TempDir::new().unwrap();
+        {
+            let db = DB::from_path(dir.path()).unwrap();
+            assert!(db.store_cf(CALL_CACHE_CF, b"key", b"value").is_err());
+        }
+        cache_db(&dir)
+            .store_cf(CALL_CACHE_CF, b"key", b"value")
+            .unwrap();
+        // once created, it is opened without the cache being configured
+        let db = DB::from_path(dir.path()).unwrap();
+        assert_eq!(
+            db.retrieve_cf(CALL_CACHE_CF, b"key").unwrap(),
+            Some(b"value".to_vec())
+        );
+    }
+}
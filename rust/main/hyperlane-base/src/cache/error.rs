@@ -10,4 +10,12 @@ pub enum CacheError {
     /// expected vs actual type of the entity.
     #[error("Failed to deserialize output: {0}")]
     FailedToDeserializeOutput(#[source] serde_json::Error),
+
+    /// Error when reading from or writing to the database cache.
+    #[error("Database cache error: {0}")]
+    DbError(#[source] crate::db::DbError),
+
+    /// Error when talking to the Redis cache.
+    #[error("Redis cache error: {0}")]
+    RedisError(String),
 }
@@ -1,11 +1,17 @@
+mod backend;
+mod db_cache;
 mod error;
 mod metered_cache;
 mod moka;
 mod optional_cache;
+mod redis_cache;
+mod stored_call;
 
 use async_trait::async_trait;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
+pub use backend::{CacheBackend, CacheBackendConf};
+pub use db_cache::DbCache;
 pub use error::CacheError;
 pub use metered_cache::{
     MeteredCache, MeteredCacheConfig, MeteredCacheMetrics, MeteredCacheMetricsBuilder,
@@ -13,6 +19,7 @@ pub use metered_cache::{
 };
 pub use moka::{CacheResult, Expiration, ExpirationType, LocalCache};
 pub use optional_cache::OptionalCache;
+pub use redis_cache::RedisCache;
 
 /// Should be used as the `fn_params` when the function has no parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,6 +69,23 @@ impl Expiration {
         }
     }
 
+    /// Whether the entry expired. Used by the caches which don't evict expired
+    /// entries themselves.
+    pub fn is_expired(&self) -> bool {
+        let now = Utc::now().timestamp().max(0) as u64;
+        let expires_at = match self.variant {
+            ExpirationType::Never => return false,
+            ExpirationType::AfterTimestamp(timestamp) => timestamp,
+            ExpirationType::AfterDuration(duration) => {
+                self.created_at.saturating_add(duration.as_secs())
+            }
+            ExpirationType::Default => self
+                .created_at
+                .saturating_add(default_expiration().as_secs()),
+        };
+        now >= expires_at
+    }
+
     /// Calculate the time to live for the entry
     /// Returns None if the entry should never expire or if the expiration time is in the past
     pub fn time_to_live(&self) -> Option<Duration> {
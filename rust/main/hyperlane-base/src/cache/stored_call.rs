@@ -0,0 +1,50 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{CacheError, CacheResult, Expiration, ExpirationType};
+
+/// A call result as stored by the caches outside of the process, together
+/// with its expiration so that it's honoured when read back
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct StoredCall {
+    value: Value,
+    expiration: Expiration,
+}
+
+impl StoredCall {
+    pub(crate) fn new(
+        result: &(impl Serialize + Send + Sync),
+        expiration: ExpirationType,
+    ) -> CacheResult<Self> {
+        Ok(Self {
+            value: serde_json::to_value(result).map_err(CacheError::FailedToSerializeInput)?,
+            expiration: expiration.into(),
+        })
+    }
+
+    pub(crate) fn expiration(&self) -> &Expiration {
+        &self.expiration
+    }
+
+    pub(crate) fn encode(&self) -> CacheResult<Vec<u8>> {
+        serde_json::to_vec(self).map_err(CacheError::FailedToSerializeInput)
+    }
+
+    pub(crate) fn decode(bytes: &[u8]) -> CacheResult<Self> {
+        serde_json::from_slice(bytes).map_err(CacheError::FailedToDeserializeOutput)
+    }
+
+    pub(crate) fn into_value<T: DeserializeOwned>(self) -> CacheResult<T> {
+        serde_json::from_value(self.value).map_err(CacheError::FailedToDeserializeOutput)
+    }
+}
+
+/// Key of a call, the same as the one of the local cache
+pub(crate) fn call_key(
+    domain_name: &str,
+    method: &str,
+    fn_params: &(impl Serialize + Send + Sync),
+) -> CacheResult<String> {
+    serde_json::to_string(&(domain_name, method, fn_params))
+        .map_err(CacheError::FailedToSerializeInput)
+}
@@ -39,7 +39,7 @@ impl Indexable for HyperlaneMessage {
     fn indexing_cursor(domain: HyperlaneDomainProtocol) -> CursorType {
         match domain {
             HyperlaneDomainProtocol::Ethereum => CursorType::SequenceAware,
-            HyperlaneDomainProtocol::Fuel => todo!(),
+            HyperlaneDomainProtocol::Fuel => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Sealevel => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Cosmos => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Starknet => CursorType::SequenceAware,
@@ -47,6 +47,7 @@ impl Indexable for HyperlaneMessage {
             HyperlaneDomainProtocol::Radix => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Aleo => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Tron => CursorType::SequenceAware,
+            HyperlaneDomainProtocol::Aptos => CursorType::SequenceAware,
         }
     }
 
@@ -64,7 +65,7 @@ impl Indexable for InterchainGasPayment {
     fn indexing_cursor(domain: HyperlaneDomainProtocol) -> CursorType {
         match domain {
             HyperlaneDomainProtocol::Ethereum => CursorType::RateLimited,
-            HyperlaneDomainProtocol::Fuel => todo!(),
+            HyperlaneDomainProtocol::Fuel => CursorType::RateLimited,
             HyperlaneDomainProtocol::Sealevel => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Cosmos => CursorType::RateLimited,
             HyperlaneDomainProtocol::Starknet => CursorType::RateLimited,
@@ -72,6 +73,7 @@ impl Indexable for InterchainGasPayment {
             HyperlaneDomainProtocol::Radix => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Aleo => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Tron => CursorType::RateLimited,
+            HyperlaneDomainProtocol::Aptos => CursorType::RateLimited,
         }
     }
 
@@ -84,7 +86,7 @@ impl Indexable for MerkleTreeInsertion {
     fn indexing_cursor(domain: HyperlaneDomainProtocol) -> CursorType {
         match domain {
             HyperlaneDomainProtocol::Ethereum => CursorType::SequenceAware,
-            HyperlaneDomainProtocol::Fuel => todo!(),
+            HyperlaneDomainProtocol::Fuel => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Sealevel => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Cosmos => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Starknet => CursorType::SequenceAware,
@@ -92,6 +94,7 @@ impl Indexable for MerkleTreeInsertion {
             HyperlaneDomainProtocol::Radix => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Aleo => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Tron => CursorType::SequenceAware,
+            HyperlaneDomainProtocol::Aptos => CursorType::SequenceAware,
         }
     }
 
@@ -104,7 +107,7 @@ impl Indexable for Delivery {
     fn indexing_cursor(domain: HyperlaneDomainProtocol) -> CursorType {
         match domain {
             HyperlaneDomainProtocol::Ethereum => CursorType::RateLimited,
-            HyperlaneDomainProtocol::Fuel => todo!(),
+            HyperlaneDomainProtocol::Fuel => CursorType::RateLimited,
             HyperlaneDomainProtocol::Sealevel => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Cosmos => CursorType::RateLimited,
             HyperlaneDomainProtocol::Starknet => CursorType::RateLimited,
@@ -112,6 +115,7 @@ impl Indexable for Delivery {
             HyperlaneDomainProtocol::Radix => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Aleo => CursorType::SequenceAware,
             HyperlaneDomainProtocol::Tron => CursorType::RateLimited,
+            HyperlaneDomainProtocol::Aptos => CursorType::RateLimited,
         }
     }
 
@@ -0,0 +1,9 @@
+/// Where an agent keeps its database
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DbBackendConf {
+    /// In a RocksDB database at the configured path, kept across restarts
+    #[default]
+    RocksDb,
+    /// In memory of the agent, lost on restart
+    Memory,
+}
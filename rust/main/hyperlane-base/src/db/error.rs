@@ -6,8 +6,10 @@ use hyperlane_core::{ChainCommunicationError, HyperlaneProtocolError};
 #[derive(thiserror::Error, Debug)]
 pub enum DbError {
     /// Rocks DB Error
+    #[cfg(feature = "rocksdb")]
     #[error("{0}")]
     RockError(#[from] rocksdb::Error),
+    #[cfg(feature = "rocksdb")]
     #[error("Failed to open {path}, canonicalized as {canonicalized}: {source}")]
     /// Error opening the database
     OpeningError {
@@ -0,0 +1,221 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{PoisonError, RwLock},
+};
+
+use super::{DbError, DbResult, KeyValueStore, StoreEntries};
+
+type Entries = BTreeMap<Vec<u8>, Vec<u8>>;
+
+/// A [`KeyValueStore`] keeping its entries in memory, lost when dropped.
+///
+/// It behaves like the RocksDB store, so agent logic can be tested with it,
+/// and it lets the agent code run where RocksDB isn't available.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    entries: RwLock<Entries>,
+    column_families: RwLock<HashMap<String, Entries>>,
+}
+
+impl MemoryStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty store with the given column families. As with RocksDB,
+    /// using any other column family fails.
+    pub fn with_column_families(column_families: &[&str]) -> Self {
+        let column_families = column_families
+            .iter()
+            .map(|cf| ((*cf).to_owned(), Entries::default()))
+            .collect();
+        Self {
+            entries: Default::default(),
+            column_families: RwLock::new(column_families),
+        }
+    }
+}
+
+fn missing_cf(cf: &str) -> DbError {
+    DbError::Other(format!("Missing column family {cf}"))
+}
+
+impl KeyValueStore for MemoryStore {
+    fn store(&self, key: &[u8], value: &[u8]) -> DbResult<()> {
+        self.entries
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key.to_vec(), value.to_vec());
+        Ok(())
+    }
+
+    fn retrieve(&self, key: &[u8]) -> DbResult<Option<Vec<u8>>> {
+        Ok(self
+            .entries
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .cloned())
+    }
+
+    fn delete(&self, key: &[u8]) -> DbResult<()> {
+        self.entries
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(key);
+        Ok(())
+    }
+
+    fn prefix_entries(&self, prefix: &[u8]) -> StoreEntries<'_> {
+        // the entries are copied so that writes aren't blocked while iterating,
+        // like RocksDB iterators which don't hold a lock either
+        let entries = self
+            .entries
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .range(prefix.to_vec()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| Ok((key.clone().into(), value.clone().into())))
+            .collect::<Vec<_>>();
+        Box::new(entries.into_iter())
+    }
+
+    fn store_cf(&self, cf: &str, key: &[u8], value: &[u8]) -> DbResult<()> {
+        self.column_families
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(cf)
+            .ok_or_else(|| missing_cf(cf))?
+            .insert(key.to_vec(), value.to_vec());
+        Ok(())
+    }
+
+    fn retrieve_cf(&self, cf: &str, key: &[u8]) -> DbResult<Option<Vec<u8>>> {
+        Ok(self
+            .column_families
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(cf)
+            .ok_or_else(|| missing_cf(cf))?
+            .get(key)
+            .cloned())
+    }
+
+    fn delete_cf(&self, cf: &str, key: &[u8]) -> DbResult<()> {
+        self.column_families
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(cf)
+            .ok_or_else(|| missing_cf(cf))?
+            .remove(key);
+        Ok(())
+    }
+
+    fn entries_cf(&self, cf: &str) -> StoreEntries<'_> {
+        let column_families = self
+            .column_families
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        let Some(entries) = column_families.get(cf) else {
+            return Box::new(std::iter::once(Err(missing_cf(cf))));
+        };
+        let entries = entries
+            .iter()
+            .map(|(key, value)| Ok((key.clone().into(), value.clone().into())))
+            .collect::<Vec<_>>();
+        Box::new(entries.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use hyperlane_core::{HyperlaneDomain, HyperlaneMessage, MerkleTreeInsertion};
+
+    use crate::db::{HyperlaneDb, HyperlaneRocksDB, CALL_CACHE_CF, DB};
+
+    use super::*;
+
+    #[test]
+    fn prefix_entries_are_in_key_order() {
+        let store = MemoryStore::new();
+        for key in [&b"b2"[..], b"a", b"b1", b"c", b"b"] {
+            store.store(key, key).unwrap();
+        }
+        store.delete(b"b1").unwrap();
+
+        let keys = store
+            .prefix_entries(b"b")
+            .map(|entry| entry.unwrap().0.to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec![b"b".to_vec(), b"b2".to_vec()]);
+    }
+
+    #[test]
+    fn column_families_are_separate_keyspaces() {
+        let store = MemoryStore::with_column_families(&[CALL_CACHE_CF]);
+        store.store(b"key", b"default").unwrap();
+        store.store_cf(CALL_CACHE_CF, b"key", b"cache").unwrap();
+
+        assert_eq!(store.retrieve(b"key").unwrap(), Some(b"default".to_vec()));
+        assert_eq!(
+            store.retrieve_cf(CALL_CACHE_CF, b"key").unwrap(),
+            Some(b"cache".to_vec())
+        );
+        store.delete_cf(CALL_CACHE_CF, b"key").unwrap();
+        assert_eq!(store.retrieve_cf(CALL_CACHE_CF, b"key").unwrap(), None);
+        assert_eq!(store.retrieve(b"key").unwrap(), Some(b"default".to_vec()));
+    }
+
+    #[test]
+    fn unknown_column_families_fail_like_rocksdb() {
+        let store = MemoryStore::new();
+        assert!(store.store_cf("missing", b"key", b"value").is_err());
+        assert!(store.retrieve_cf("missing", b"key").is_err());
+        assert!(store.delete_cf("missing", b"key").is_err());
+        assert!(store.entries_cf("missing").next().unwrap().is_err());
+        // the default keyspace is unaffected
+        store.store(b"key", b"default").unwrap();
+        assert_eq!(store.retrieve(b"key").unwrap(), Some(b"default".to_vec()));
+    }
+
+    #[test]
+    fn hyperlane_db_runs_in_memory() {
+        let db = HyperlaneRocksDB::new(
+            &HyperlaneDomain::new_test_domain("hyperlane_db_runs_in_memory"),
+            DB::in_memory(),
+        )
+        .with_pruning();
+        let message = HyperlaneMessage {
+            nonce: 3,
+            body: vec![1, 2, 3],
+            ..Default::default()
+        };
+        db.store_message(&message, 10).unwrap();
+        db.process_tree_insertion(&MerkleTreeInsertion::new(0, message.id()), 11)
+            .unwrap();
+        db.store_processed_by_nonce(&3, &true).unwrap();
+
+        assert_eq!(
+            db.retrieve_message_by_nonce(3).unwrap(),
+            Some(message.clone())
+        );
+        assert_eq!(db.retrieve_highest_seen_message_nonce().unwrap(), Some(3));
+        assert_eq!(
+            db.retrieve_merkle_leaf_index_by_message_id(&message.id())
+                .unwrap(),
+            Some(0)
+        );
+        assert_eq!(db.retrieve_processed_by_nonce(&3).unwrap(), Some(true));
+        // delivered messages are pruned as with RocksDB
+        assert_eq!(db.prune_delivered_messages(u64::MAX, 10).unwrap(), 1);
+        assert_eq!(db.retrieve_message_pruned_by_nonce(&3).unwrap(), Some(true));
+        assert_eq!(
+            db.retrieve_message_by_nonce(3).unwrap(),
+            Some(HyperlaneMessage {
+                body: vec![],
+                ..message
+            })
+        );
+    }
+}
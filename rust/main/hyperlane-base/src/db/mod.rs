@@ -1,16 +1,22 @@
 pub use self::storage_types::{InterchainGasExpenditureData, InterchainGasPaymentData};
+pub use backend::DbBackendConf;
 pub use error::*;
+pub use memory::MemoryStore;
 pub use rocks::*;
+pub use store::{KeyValueStore, StoreEntries};
 
 use hyperlane_core::{
-    identifiers::UniqueIdentifier, GasPaymentKey, HyperlaneDomain, HyperlaneMessage,
-    InterchainGasPayment, InterchainGasPaymentMeta, MerkleTreeInsertion, PendingOperationStatus,
-    H256, H512,
+    identifiers::UniqueIdentifier, CheckpointWithMessageId, GasPaymentKey, HyperlaneDomain,
+    HyperlaneMessage, InterchainGasPayment, InterchainGasPaymentMeta, MerkleTreeInsertion,
+    PendingOperationSnapshot, PendingOperationStatus, H256, H512,
 };
 
+mod backend;
 mod error;
+mod memory;
 mod rocks;
 pub(crate) mod storage_types;
+mod store;
 
 #[allow(missing_docs)]
 /// Hyperlane database interface
@@ -81,6 +87,22 @@ pub trait HyperlaneDb: Send + Sync {
         message_id: &H256,
     ) -> DbResult<Option<PendingOperationStatus>>;
 
+    /// Store a snapshot of the processing state of an operation by its message id
+    fn store_operation_snapshot_by_message_id(
+        &self,
+        message_id: &H256,
+        snapshot: &PendingOperationSnapshot,
+    ) -> DbResult<()>;
+
+    /// Retrieve the snapshot of the processing state of an operation by its message id
+    fn retrieve_operation_snapshot_by_message_id(
+        &self,
+        message_id: &H256,
+    ) -> DbResult<Option<PendingOperationSnapshot>>;
+
+    /// Delete the snapshot of the processing state of an operation by its message id
+    fn delete_operation_snapshot_by_message_id(&self, message_id: &H256) -> DbResult<()>;
+
     fn store_interchain_gas_payment_data_by_gas_payment_key(
         &self,
         key: &GasPaymentKey,
@@ -161,6 +183,25 @@ pub trait HyperlaneDb: Send + Sync {
     /// Retrieve the nonce of the highest processed message we're aware of
     fn retrieve_highest_seen_message_nonce_number(&self) -> DbResult<Option<u32>>;
 
+    /// Store the checkpoint a validator signed at the given index
+    fn store_signed_checkpoint_by_index(
+        &self,
+        index: &u32,
+        checkpoint: &CheckpointWithMessageId,
+    ) -> DbResult<()>;
+
+    /// Retrieve the checkpoint a validator signed at the given index
+    fn retrieve_signed_checkpoint_by_index(
+        &self,
+        index: &u32,
+    ) -> DbResult<Option<CheckpointWithMessageId>>;
+
+    /// Store the highest checkpoint index a validator signed
+    fn store_highest_signed_checkpoint_index(&self, index: &u32) -> DbResult<()>;
+
+    /// Retrieve the highest checkpoint index a validator signed
+    fn retrieve_highest_signed_checkpoint_index(&self) -> DbResult<Option<u32>>;
+
     /// Store the origin transaction hash for a dispatched message by its message id
     fn store_dispatched_tx_hash_by_message_id(
         &self,
@@ -1,14 +1,15 @@
-use std::ops::Add;
+use std::{collections::HashMap, ops::Add};
 
 use async_trait::async_trait;
 use eyre::{bail, Result};
 use tracing::{debug, instrument, trace};
 
 use hyperlane_core::{
-    identifiers::UniqueIdentifier, Decode, Encode, GasPaymentKey, HyperlaneDomain,
-    HyperlaneLogStore, HyperlaneMessage, HyperlaneSequenceAwareIndexerStoreReader,
+    identifiers::UniqueIdentifier, CheckpointWithMessageId, Decode, Encode, GasPaymentKey,
+    HyperlaneDomain, HyperlaneLogStore, HyperlaneMessage, HyperlaneSequenceAwareIndexerStoreReader,
     HyperlaneWatermarkedLogStore, Indexed, InterchainGasExpenditure, InterchainGasPayment,
-    InterchainGasPaymentMeta, LogMeta, MerkleTreeInsertion, PendingOperationStatus, H256, H512,
+    InterchainGasPaymentMeta, LogMeta, MerkleTreeInsertion, PendingOperationSnapshot,
+    PendingOperationStatus, H256, H512,
 };
 
 use crate::db::{
@@ -16,7 +17,7 @@ use crate::db::{
     HyperlaneDb,
 };
 
-use super::{DbError, TypedDB, DB};
+use super::{pruning::unix_timestamp, DbError, TypedDB, DB};
 
 // these keys MUST not be given multiple uses in case multiple agents are
 // started with the same database and domain.
@@ -32,6 +33,7 @@ const GAS_PAYMENT_FOR_MESSAGE_ID: &str = "gas_payment_sequence_for_message_id_v2
 const GAS_PAYMENT_META_PROCESSED: &str = "gas_payment_meta_processed_v3_";
 const GAS_EXPENDITURE_FOR_MESSAGE_ID: &str = "gas_expenditure_for_message_id_v2_";
 const STATUS_BY_MESSAGE_ID: &str = "status_by_message_id_";
+const OPERATION_SNAPSHOT_BY_MESSAGE_ID: &str = "operation_snapshot_by_message_id_";
 const PENDING_MESSAGE_RETRY_COUNT_FOR_MESSAGE_ID: &str =
     "pending_message_retry_count_for_message_id_";
 const MERKLE_TREE_INSERTION: &str = "merkle_tree_insertion_";
@@ -41,13 +43,56 @@ const MERKLE_TREE_INSERTION_BLOCK_NUMBER_BY_LEAF_INDEX: &str =
 const LATEST_INDEXED_GAS_PAYMENT_BLOCK: &str = "latest_indexed_gas_payment_block";
 const PAYLOAD_UUIDS_BY_MESSAGE_ID: &str = "payload_uuids_by_message_id_";
 const MESSAGE_DISPATCHED_TX_HASH_BY_MESSAGE_ID: &str = "message_dispatched_tx_hash_by_message_id_";
+const SIGNED_CHECKPOINT_BY_INDEX: &str = "signed_checkpoint_by_index_";
+const HIGHEST_SIGNED_CHECKPOINT_INDEX: &str = "highest_signed_checkpoint_index_";
+const MESSAGE_DELIVERED_AT_BY_NONCE: &str = "message_delivered_at_by_nonce_";
+const NONCE_BY_DELIVERY_TIME: &str = "nonce_by_delivery_time_";
+const DELIVERY_BACKFILL_NONCE: &str = "delivery_backfill_nonce_";
+const MESSAGE_PRUNED_BY_NONCE: &str = "message_pruned_by_nonce_";
+const GAS_PAYMENT_META_BY_MESSAGE_ID: &str = "gas_payment_meta_by_message_id_";
+const GAS_PAYMENT_SEQUENCE_BY_MESSAGE_ID: &str = "gas_payment_sequence_by_message_id_";
+const PRUNED_GAS_PAYMENT_BY_SEQUENCE: &str = "pruned_gas_payment_by_sequence_";
+
+/// Every key prefix, used to break down the size of the database
+const KEY_PREFIXES: &[&str] = &[
+    MESSAGE_ID,
+    MESSAGE_DISPATCHED_BLOCK_NUMBER,
+    MESSAGE,
+    NONCE_PROCESSED,
+    GAS_PAYMENT_BY_SEQUENCE,
+    GAS_PAYMENT_BLOCK_BY_SEQUENCE,
+    HIGHEST_SEEN_MESSAGE_NONCE,
+    GAS_PAYMENT_FOR_MESSAGE_ID,
+    GAS_PAYMENT_META_PROCESSED,
+    GAS_EXPENDITURE_FOR_MESSAGE_ID,
+    STATUS_BY_MESSAGE_ID,
+    OPERATION_SNAPSHOT_BY_MESSAGE_ID,
+    PENDING_MESSAGE_RETRY_COUNT_FOR_MESSAGE_ID,
+    MERKLE_TREE_INSERTION,
+    MERKLE_LEAF_INDEX_BY_MESSAGE_ID,
+    MERKLE_TREE_INSERTION_BLOCK_NUMBER_BY_LEAF_INDEX,
+    LATEST_INDEXED_GAS_PAYMENT_BLOCK,
+    PAYLOAD_UUIDS_BY_MESSAGE_ID,
+    MESSAGE_DISPATCHED_TX_HASH_BY_MESSAGE_ID,
+    SIGNED_CHECKPOINT_BY_INDEX,
+    HIGHEST_SIGNED_CHECKPOINT_INDEX,
+    MESSAGE_DELIVERED_AT_BY_NONCE,
+    NONCE_BY_DELIVERY_TIME,
+    DELIVERY_BACKFILL_NONCE,
+    MESSAGE_PRUNED_BY_NONCE,
+    GAS_PAYMENT_META_BY_MESSAGE_ID,
+    GAS_PAYMENT_SEQUENCE_BY_MESSAGE_ID,
+    PRUNED_GAS_PAYMENT_BY_SEQUENCE,
+];
 
 /// Rocks DB result type
 pub type DbResult<T> = std::result::Result<T, DbError>;
 
 /// DB handle for storing data tied to a specific Mailbox.
+///
+/// The flag is set when the data is pruned, see [`HyperlaneRocksDB::with_pruning`].
 #[derive(Debug, Clone)]
-pub struct HyperlaneRocksDB(HyperlaneDomain, TypedDB);
+pub struct HyperlaneRocksDB(HyperlaneDomain, TypedDB, bool);
 
 impl std::ops::Deref for HyperlaneRocksDB {
     type Target = TypedDB;
@@ -72,7 +117,15 @@ impl AsRef<DB> for HyperlaneRocksDB {
 impl HyperlaneRocksDB {
     /// Instantiated new `HyperlaneRocksDB`
     pub fn new(domain: &HyperlaneDomain, db: DB) -> Self {
-        Self(domain.clone(), TypedDB::new(domain, db))
+        Self(domain.clone(), TypedDB::new(domain, db), false)
+    }
+
+    /// Record what pruning needs: when messages were delivered and which gas
+    /// payments were made for them. These records cost writes for every
+    /// message, so they are only kept when the database is pruned.
+    pub fn with_pruning(mut self) -> Self {
+        self.2 = true;
+        self
     }
 
     /// Get the domain this database is scoped to
@@ -111,7 +164,17 @@ impl HyperlaneRocksDB {
         message: &HyperlaneMessage,
         dispatched_block_number: u64,
     ) -> DbResult<()> {
-        let id = message.id();
+        self.upsert_message_with_id(message.id(), message, dispatched_block_number)
+    }
+
+    /// Store a raw committed message under a known id. A pruned message has
+    /// no body anymore, so its id can't be computed from it.
+    pub fn upsert_message_with_id(
+        &self,
+        id: H256,
+        message: &HyperlaneMessage,
+        dispatched_block_number: u64,
+    ) -> DbResult<()> {
         debug!(hyp_message=?message,  "Storing new message in db",);
 
         // - `id` --> `message`
@@ -125,7 +188,8 @@ impl HyperlaneRocksDB {
         Ok(())
     }
 
-    /// Retrieve a message by its nonce
+    /// Retrieve a message by its nonce. A pruned message is returned without
+    /// its body.
     pub fn retrieve_message_by_nonce(&self, nonce: u32) -> DbResult<Option<HyperlaneMessage>> {
         let id = self.retrieve_message_id_by_nonce(&nonce)?;
         match id {
@@ -154,6 +218,18 @@ impl HyperlaneRocksDB {
         log_meta: &LogMeta,
     ) -> DbResult<bool> {
         let payment = *(indexed_payment.inner());
+        // the payments of pruned messages aren't counted again if fetched again
+        if let Some(sequence) = indexed_payment.sequence {
+            if self
+                .retrieve_value_by_key::<_, GasPaymentKey>(
+                    PRUNED_GAS_PAYMENT_BY_SEQUENCE,
+                    &sequence,
+                )?
+                .is_some()
+            {
+                return Ok(false);
+            }
+        }
         let gas_processing_successful = self.process_gas_payment(payment, log_meta)?;
 
         // only store the payment and return early if there's no sequence
@@ -173,10 +249,32 @@ impl HyperlaneRocksDB {
 
         self.store_gas_payment_by_sequence(&gas_payment_sequence, indexed_payment.inner())?;
         self.store_gas_payment_block_by_sequence(&gas_payment_sequence, &log_meta.block_number)?;
+        if self.2 {
+            self.store_encodable(
+                GAS_PAYMENT_SEQUENCE_BY_MESSAGE_ID,
+                [payment.message_id.to_vec(), gas_payment_sequence.to_vec()].concat(),
+                &true,
+            )?;
+        }
 
         Ok(gas_processing_successful)
     }
 
+    /// Retrieve the gas payment indexed with `sequence`. The payments of pruned
+    /// messages are returned without their amounts, since their sequence
+    /// stays indexed.
+    fn retrieve_indexed_gas_payment_by_sequence(
+        &self,
+        sequence: u32,
+    ) -> DbResult<Option<InterchainGasPayment>> {
+        if let Some(payment) = self.retrieve_gas_payment_by_sequence(&sequence)? {
+            return Ok(Some(payment));
+        }
+        Ok(self
+            .retrieve_value_by_key::<_, GasPaymentKey>(PRUNED_GAS_PAYMENT_BY_SEQUENCE, &sequence)?
+            .map(InterchainGasPayment::from_gas_payment_key))
+    }
+
     /// If the provided gas payment, identified by its metadata, has not been
     /// processed, processes the gas payment and records it as processed.
     /// Returns whether the gas payment was processed for the first time.
@@ -185,7 +283,7 @@ impl HyperlaneRocksDB {
         payment: InterchainGasPayment,
         log_meta: &LogMeta,
     ) -> DbResult<bool> {
-        let payment_meta = log_meta.into();
+        let payment_meta: InterchainGasPaymentMeta = log_meta.into();
         // If the gas payment has already been processed, do nothing
         if self
             .retrieve_processed_by_gas_payment_meta(&payment_meta)?
@@ -201,6 +299,13 @@ impl HyperlaneRocksDB {
         }
         // Set the gas payment as processed
         self.store_processed_by_gas_payment_meta(&payment_meta, &true)?;
+        if self.2 {
+            self.store_encodable(
+                GAS_PAYMENT_META_BY_MESSAGE_ID,
+                [payment.message_id.to_vec(), payment_meta.to_vec()].concat(),
+                &true,
+            )?;
+        }
 
         // Update the total gas payment for the message to include the payment
         self.update_gas_payment_by_gas_payment_key(payment)?;
@@ -298,6 +403,168 @@ impl HyperlaneRocksDB {
             }))
     }
 
+    /// Record when the message with `nonce` was delivered, unless it's already
+    /// recorded. Messages are pruned in the order they were delivered.
+    pub fn record_message_delivery(&self, nonce: u32, delivered_at: u64) -> DbResult<()> {
+        if self
+            .retrieve_value_by_key::<_, u64>(MESSAGE_DELIVERED_AT_BY_NONCE, &nonce)?
+            .is_some()
+        {
+            return Ok(());
+        }
+        self.store_value_by_key(MESSAGE_DELIVERED_AT_BY_NONCE, &nonce, &delivered_at)?;
+        self.store_encodable(
+            NONCE_BY_DELIVERY_TIME,
+            [delivered_at.to_vec(), nonce.to_vec()].concat(),
+            &nonce,
+        )
+    }
+
+    /// Record the delivery of messages which were processed before deliveries
+    /// were recorded, as if they were delivered at `delivered_at`. Checks at
+    /// most `limit` nonces, continuing where the previous call stopped, and
+    /// returns how many were checked.
+    pub fn backfill_message_deliveries(&self, delivered_at: u64, limit: u32) -> DbResult<u32> {
+        let Some(highest_nonce) = self.retrieve_highest_seen_message_nonce()? else {
+            return Ok(0);
+        };
+        let start: u32 = self
+            .retrieve_value_by_key(DELIVERY_BACKFILL_NONCE, &bool::default())?
+            .unwrap_or_default();
+        let end = start
+            .saturating_add(limit)
+            .min(highest_nonce.saturating_add(1));
+        for nonce in start..end {
+            if self.retrieve_processed_by_nonce(&nonce)?.unwrap_or(false) {
+                self.record_message_delivery(nonce, delivered_at)?;
+            }
+        }
+        self.store_value_by_key(DELIVERY_BACKFILL_NONCE, &bool::default(), &end)?;
+        Ok(end.saturating_sub(start))
+    }
+
+    /// Prune the messages delivered before `delivered_before`, oldest first and
+    /// at most `limit` of them. Returns how many were pruned.
+    ///
+    /// The body, gas payments, gas expenditure, processing state and origin
+    /// transaction of a pruned message are dropped, and the message is
+    /// recorded as pruned. The rest of the message is kept under its stored
+    /// id, along with its nonce and tree insertion, so that indexers don't
+    /// fetch it again and merkle proofs can still be built. The id of a
+    /// pruned message must be read with `retrieve_message_id_by_nonce`, it
+    /// can't be computed from the message without its body.
+    pub fn prune_delivered_messages(&self, delivered_before: u64, limit: u32) -> DbResult<u32> {
+        let mut due = vec![];
+        for entry in self
+            .prefix_iterator(NONCE_BY_DELIVERY_TIME.as_bytes())
+            .take(limit as usize)
+        {
+            let (key, nonce) = entry?;
+            let mut delivered_at = key.get(NONCE_BY_DELIVERY_TIME.len()..).unwrap_or_default();
+            if u64::read_from(&mut delivered_at)? >= delivered_before {
+                break;
+            }
+            due.push((key, u32::read_from(&mut &nonce[..])?));
+        }
+        for (key, nonce) in &due {
+            self.prune_message(*nonce)?;
+            self.delete("", key)?;
+        }
+        Ok(due.len() as u32)
+    }
+
+    fn prune_message(&self, nonce: u32) -> DbResult<()> {
+        let Some(id) = self.retrieve_message_id_by_nonce(&nonce)? else {
+            return Ok(());
+        };
+        if let Some(message) = self.retrieve_message_by_id(&id)? {
+            let gas_payment_key = GasPaymentKey {
+                message_id: id,
+                destination: message.destination,
+            };
+            self.delete(GAS_PAYMENT_FOR_MESSAGE_ID, gas_payment_key.to_vec())?;
+            let message = HyperlaneMessage {
+                body: vec![],
+                ..message
+            };
+            self.store_message_by_id(&id, &message)?;
+        }
+        for (key, sequence) in
+            self.gas_payment_links::<u32>(GAS_PAYMENT_SEQUENCE_BY_MESSAGE_ID, id)?
+        {
+            if let Some(payment) = self.retrieve_gas_payment_by_sequence(&sequence)? {
+                // payments may be made for another destination than the message's
+                let gas_payment_key = GasPaymentKey::from(payment);
+                self.delete(GAS_PAYMENT_FOR_MESSAGE_ID, gas_payment_key.to_vec())?;
+                self.delete(GAS_PAYMENT_BY_SEQUENCE, sequence.to_vec())?;
+                // the sequence stays indexed, so that it isn't fetched again
+                self.store_value_by_key(
+                    PRUNED_GAS_PAYMENT_BY_SEQUENCE,
+                    &sequence,
+                    &gas_payment_key,
+                )?;
+            }
+            self.delete("", key)?;
+        }
+        for (key, meta) in
+            self.gas_payment_links::<InterchainGasPaymentMeta>(GAS_PAYMENT_META_BY_MESSAGE_ID, id)?
+        {
+            self.delete(GAS_PAYMENT_META_PROCESSED, meta.to_vec())?;
+            self.delete("", key)?;
+        }
+        for prefix in [
+            GAS_EXPENDITURE_FOR_MESSAGE_ID,
+            STATUS_BY_MESSAGE_ID,
+            OPERATION_SNAPSHOT_BY_MESSAGE_ID,
+            PENDING_MESSAGE_RETRY_COUNT_FOR_MESSAGE_ID,
+            PAYLOAD_UUIDS_BY_MESSAGE_ID,
+            MESSAGE_DISPATCHED_TX_HASH_BY_MESSAGE_ID,
+        ] {
+            self.delete(prefix, id.to_vec())?;
+        }
+        self.store_message_pruned_by_nonce(&nonce, &true)
+    }
+
+    /// The gas payments recorded for the message `id` under `prefix`, with
+    /// the keys of the records
+    fn gas_payment_links<T: Decode>(&self, prefix: &str, id: H256) -> DbResult<Vec<(Vec<u8>, T)>> {
+        let prefix = [prefix.as_bytes(), id.as_bytes()].concat();
+        self.prefix_iterator(&prefix)
+            .map(|entry| {
+                let (key, _) = entry?;
+                let payment = T::read_from(&mut key.get(prefix.len()..).unwrap_or_default())?;
+                Ok((key, payment))
+            })
+            .collect()
+    }
+
+    /// Store whether the data of a delivered message was pruned
+    pub fn store_message_pruned_by_nonce(&self, nonce: &u32, pruned: &bool) -> DbResult<()> {
+        self.store_value_by_key(MESSAGE_PRUNED_BY_NONCE, nonce, pruned)
+    }
+
+    /// Retrieve whether the data of a delivered message was pruned
+    pub fn retrieve_message_pruned_by_nonce(&self, nonce: &u32) -> DbResult<Option<bool>> {
+        self.retrieve_value_by_key(MESSAGE_PRUNED_BY_NONCE, nonce)
+    }
+
+    /// Size in bytes of the entries of the domain by key prefix, without its
+    /// trailing underscore. Reads every entry of the domain.
+    pub fn size_by_key_prefix(&self) -> DbResult<HashMap<&'static str, u64>> {
+        let mut sizes = HashMap::new();
+        for entry in self.prefix_iterator(&[]) {
+            let (key, value) = entry?;
+            let prefix = KEY_PREFIXES
+                .iter()
+                .filter(|prefix| key.starts_with(prefix.as_bytes()))
+                .max_by_key(|prefix| prefix.len())
+                .map_or("other", |prefix| prefix.trim_end_matches('_'));
+            let size: &mut u64 = sizes.entry(prefix).or_default();
+            *size = size.saturating_add(key.len().saturating_add(value.len()) as u64);
+        }
+        Ok(sizes)
+    }
+
     /// Retrieve the total gas payment for a message
     pub fn retrieve_gas_expenditure_by_message_id(
         &self,
@@ -419,7 +686,7 @@ impl HyperlaneSequenceAwareIndexerStoreReader<MerkleTreeInsertion> for Hyperlane
 impl HyperlaneSequenceAwareIndexerStoreReader<InterchainGasPayment> for HyperlaneRocksDB {
     /// Gets data by its sequence.
     async fn retrieve_by_sequence(&self, sequence: u32) -> Result<Option<InterchainGasPayment>> {
-        Ok(self.retrieve_gas_payment_by_sequence(&sequence)?)
+        Ok(self.retrieve_indexed_gas_payment_by_sequence(sequence)?)
     }
 
     /// Gets the block number at which the log occurred.
@@ -512,9 +779,14 @@ impl HyperlaneDb for HyperlaneRocksDB {
         self.retrieve_value_by_key(MESSAGE_DISPATCHED_BLOCK_NUMBER, nonce)
     }
 
-    /// Store whether a message was processed by its nonce
+    /// Store whether a message was processed by its nonce, recording when it
+    /// was delivered if the database is pruned
     fn store_processed_by_nonce(&self, nonce: &u32, processed: &bool) -> DbResult<()> {
-        self.store_value_by_key(NONCE_PROCESSED, nonce, processed)
+        self.store_value_by_key(NONCE_PROCESSED, nonce, processed)?;
+        if *processed && self.2 {
+            self.record_message_delivery(*nonce, unix_timestamp())?;
+        }
+        Ok(())
     }
 
     fn retrieve_processed_by_nonce(&self, nonce: &u32) -> DbResult<Option<bool>> {
@@ -568,6 +840,28 @@ impl HyperlaneDb for HyperlaneRocksDB {
         self.retrieve_value_by_key(STATUS_BY_MESSAGE_ID, message_id)
     }
 
+    /// Store a snapshot of the processing state of an operation by its message id
+    fn store_operation_snapshot_by_message_id(
+        &self,
+        message_id: &H256,
+        snapshot: &PendingOperationSnapshot,
+    ) -> DbResult<()> {
+        self.store_value_by_key(OPERATION_SNAPSHOT_BY_MESSAGE_ID, message_id, snapshot)
+    }
+
+    /// Retrieve the snapshot of the processing state of an operation by its message id
+    fn retrieve_operation_snapshot_by_message_id(
+        &self,
+        message_id: &H256,
+    ) -> DbResult<Option<PendingOperationSnapshot>> {
+        self.retrieve_value_by_key(OPERATION_SNAPSHOT_BY_MESSAGE_ID, message_id)
+    }
+
+    /// Delete the snapshot of the processing state of an operation by its message id
+    fn delete_operation_snapshot_by_message_id(&self, message_id: &H256) -> DbResult<()> {
+        self.delete(OPERATION_SNAPSHOT_BY_MESSAGE_ID, message_id.to_vec())
+    }
+
     fn store_interchain_gas_payment_data_by_gas_payment_key(
         &self,
         key: &GasPaymentKey,
@@ -690,6 +984,29 @@ impl HyperlaneDb for HyperlaneRocksDB {
         self.retrieve_value_by_key(HIGHEST_SEEN_MESSAGE_NONCE, &bool::default())
     }
 
+    fn store_signed_checkpoint_by_index(
+        &self,
+        index: &u32,
+        checkpoint: &CheckpointWithMessageId,
+    ) -> DbResult<()> {
+        self.store_value_by_key(SIGNED_CHECKPOINT_BY_INDEX, index, checkpoint)
+    }
+
+    fn retrieve_signed_checkpoint_by_index(
+        &self,
+        index: &u32,
+    ) -> DbResult<Option<CheckpointWithMessageId>> {
+        self.retrieve_value_by_key(SIGNED_CHECKPOINT_BY_INDEX, index)
+    }
+
+    fn store_highest_signed_checkpoint_index(&self, index: &u32) -> DbResult<()> {
+        self.store_value_by_key(HIGHEST_SIGNED_CHECKPOINT_INDEX, &bool::default(), index)
+    }
+
+    fn retrieve_highest_signed_checkpoint_index(&self) -> DbResult<Option<u32>> {
+        self.retrieve_value_by_key(HIGHEST_SIGNED_CHECKPOINT_INDEX, &bool::default())
+    }
+
     fn store_payload_uuids_by_message_id(
         &self,
         message_id: &H256,
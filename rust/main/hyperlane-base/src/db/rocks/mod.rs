@@ -1,28 +1,43 @@
 use std::{path::Path, sync::Arc};
 
-use super::error::DbError;
-use rocksdb::{Options, DB as Rocks};
+use super::{error::DbError, DbBackendConf, KeyValueStore, MemoryStore, StoreEntries};
+#[cfg(feature = "rocksdb")]
+use rocksdb::{ColumnFamily, Direction, IteratorMode, Options, DB as Rocks};
+#[cfg(feature = "rocksdb")]
 use tracing::info;
 
 pub use hyperlane_db::*;
+pub use pruning::{
+    DbPruner, DbPruningConf, DbPruningMetrics, DEFAULT_PRUNING_BATCH_SIZE, DEFAULT_PRUNING_INTERVAL,
+};
 pub use typed_db::*;
 
 /// Shared functionality surrounding use of rocksdb
+#[cfg(feature = "rocksdb")]
 pub mod iterator;
 
 /// DB operations tied to specific Mailbox
 mod hyperlane_db;
+
+/// Pruning of delivered messages
+mod pruning;
+
 /// Type-specific db operations
 mod typed_db;
 
 /// Database test utilities.
-#[cfg(any(test, feature = "test-utils"))]
+#[cfg(all(any(test, feature = "test-utils"), feature = "rocksdb"))]
 pub mod test_utils;
 
+/// Column family of the persistent contract call cache, only created when the
+/// cache is kept in the database
+pub const CALL_CACHE_CF: &str = "call_cache";
+
 #[derive(Debug, Clone)]
-/// A KV Store
-pub struct DB(Arc<Rocks>);
+/// A KV Store, backed by RocksDB or by memory
+pub struct DB(Arc<dyn KeyValueStore>);
 
+#[cfg(feature = "rocksdb")]
 impl From<Rocks> for DB {
     fn from(rocks: Rocks) -> Self {
         Self(Arc::new(rocks))
@@ -32,9 +47,37 @@ impl From<Rocks> for DB {
 type Result<T> = std::result::Result<T, DbError>;
 
 impl DB {
+    /// Opens the db of the configured backend, with the `column_families` the
+    /// agent uses. The path is only used by RocksDB.
+    pub fn open(backend: DbBackendConf, db_path: &Path, column_families: &[&str]) -> Result<DB> {
+        match backend {
+            #[cfg(feature = "rocksdb")]
+            DbBackendConf::RocksDb => {
+                Self::from_path_with_column_families(db_path, column_families)
+            }
+            #[cfg(not(feature = "rocksdb"))]
+            DbBackendConf::RocksDb => Err(DbError::Other(format!(
+                "Cannot open {}, built without the `rocksdb` feature",
+                db_path.display()
+            ))),
+            DbBackendConf::Memory => Ok(Self::in_memory_with_column_families(column_families)),
+        }
+    }
+
     /// Opens db at `db_path` and creates if missing
-    #[tracing::instrument(err)]
+    #[cfg(feature = "rocksdb")]
     pub fn from_path(db_path: &Path) -> Result<DB> {
+        Self::from_path_with_column_families(db_path, &[])
+    }
+
+    /// Opens db at `db_path` and creates if missing, along with the
+    /// `column_families` it doesn't have yet.
+    ///
+    /// Once created, a column family has to be opened by every version of the
+    /// agents using the db, so they are only created where needed.
+    #[cfg(feature = "rocksdb")]
+    #[tracing::instrument(err)]
+    pub fn from_path_with_column_families(db_path: &Path, column_families: &[&str]) -> Result<DB> {
         let path = {
             let mut path = db_path
                 .parent()
@@ -56,7 +99,7 @@ impl DB {
         let mut opts = Options::default();
         opts.create_if_missing(true);
 
-        Rocks::open(&opts, &path)
+        open_with_column_families(&mut opts, &path, column_families)
             .map_err(|e| DbError::OpeningError {
                 source: Box::new(e),
                 path: db_path.into(),
@@ -65,13 +108,164 @@ impl DB {
             .map(Into::into)
     }
 
+    /// Opens the existing db at `db_path` for reading only, with all of its
+    /// column families. Writes to it fail, and it can be opened while an agent
+    /// is using it.
+    #[cfg(feature = "rocksdb")]
+    pub fn from_path_read_only(db_path: &Path) -> Result<DB> {
+        let opts = Options::default();
+        Rocks::list_cf(&opts, db_path)
+            .and_then(|column_families| {
+                Rocks::open_cf_for_read_only(&opts, db_path, column_families, false)
+            })
+            .map_err(|e| DbError::OpeningError {
+                source: Box::new(e),
+                path: db_path.into(),
+                canonicalized: db_path.into(),
+            })
+            .map(Into::into)
+    }
+
+    /// Creates an empty db kept in memory, lost when the last handle to it
+    /// is dropped
+    pub fn in_memory() -> DB {
+        Self::from_store(MemoryStore::new())
+    }
+
+    /// Creates an empty db kept in memory, with the given column families
+    pub fn in_memory_with_column_families(column_families: &[&str]) -> DB {
+        Self::from_store(MemoryStore::with_column_families(column_families))
+    }
+
+    /// Creates a db backed by any key-value store
+    pub fn from_store(store: impl KeyValueStore + 'static) -> DB {
+        Self(Arc::new(store))
+    }
+
     /// Store a value in the DB
     pub fn store(&self, key: &[u8], value: &[u8]) -> Result<()> {
-        Ok(self.0.put(key, value)?)
+        self.0.store(key, value)
     }
 
     /// Retrieve a value from the DB
     pub fn retrieve(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
-        Ok(self.0.get(key)?)
+        self.0.retrieve(key)
+    }
+
+    /// Delete a value from the DB
+    pub fn delete(&self, key: &[u8]) -> Result<()> {
+        self.0.delete(key)
+    }
+
+    /// Iterate over the entries whose key starts with `prefix`, in key order
+    pub fn prefix_iterator(&self, prefix: &[u8]) -> StoreEntries<'_> {
+        self.0.prefix_entries(prefix)
+    }
+
+    /// Compact the whole DB, reclaiming the space of deleted entries
+    pub fn compact(&self) {
+        self.0.compact()
+    }
+
+    /// Store a value in a column family of the DB
+    pub fn store_cf(&self, cf: &str, key: &[u8], value: &[u8]) -> Result<()> {
+        self.0.store_cf(cf, key, value)
+    }
+
+    /// Retrieve a value from a column family of the DB
+    pub fn retrieve_cf(&self, cf: &str, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.0.retrieve_cf(cf, key)
+    }
+
+    /// Delete a value from a column family of the DB
+    pub fn delete_cf(&self, cf: &str, key: &[u8]) -> Result<()> {
+        self.0.delete_cf(cf, key)
+    }
+
+    /// Iterate over the entries of a column family of the DB, in key order
+    pub fn entries_cf(&self, cf: &str) -> StoreEntries<'_> {
+        self.0.entries_cf(cf)
+    }
+}
+
+#[cfg(feature = "rocksdb")]
+impl KeyValueStore for Rocks {
+    fn store(&self, key: &[u8], value: &[u8]) -> Result<()> {
+        Ok(self.put(key, value)?)
+    }
+
+    fn retrieve(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self.get(key)?)
+    }
+
+    fn delete(&self, key: &[u8]) -> Result<()> {
+        Ok(Rocks::delete(self, key)?)
+    }
+
+    fn prefix_entries(&self, prefix: &[u8]) -> StoreEntries<'_> {
+        let prefix = prefix.to_vec();
+        let entries = self
+            .iterator(IteratorMode::From(&prefix, Direction::Forward))
+            .map(|entry| entry.map_err(DbError::from))
+            .take_while(move |entry| {
+                // errors are passed on to the caller
+                entry
+                    .as_ref()
+                    .ok()
+                    .is_none_or(|(key, _)| key.starts_with(&prefix))
+            });
+        Box::new(entries)
+    }
+
+    fn store_cf(&self, cf: &str, key: &[u8], value: &[u8]) -> Result<()> {
+        Ok(self.put_cf(cf_handle(self, cf)?, key, value)?)
+    }
+
+    fn retrieve_cf(&self, cf: &str, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self.get_cf(cf_handle(self, cf)?, key)?)
+    }
+
+    fn delete_cf(&self, cf: &str, key: &[u8]) -> Result<()> {
+        Ok(Rocks::delete_cf(self, cf_handle(self, cf)?, key)?)
+    }
+
+    fn entries_cf(&self, cf: &str) -> StoreEntries<'_> {
+        match cf_handle(self, cf) {
+            Ok(handle) => Box::new(
+                self.iterator_cf(handle, IteratorMode::Start)
+                    .map(|entry| entry.map_err(DbError::from)),
+            ),
+            Err(err) => Box::new(std::iter::once(Err(err))),
+        }
+    }
+
+    fn compact(&self) {
+        self.compact_range::<&[u8], &[u8]>(None, None)
+    }
+}
+
+#[cfg(feature = "rocksdb")]
+fn cf_handle<'a>(rocks: &'a Rocks, cf: &str) -> Result<&'a ColumnFamily> {
+    rocks
+        .cf_handle(cf)
+        .ok_or_else(|| DbError::Other(format!("Missing column family {cf}")))
+}
+
+/// Opens the db with all of its existing column families and the given ones,
+/// creating those which are missing
+#[cfg(feature = "rocksdb")]
+pub(crate) fn open_with_column_families(
+    opts: &mut Options,
+    path: &Path,
+    required: &[&str],
+) -> std::result::Result<Rocks, rocksdb::Error> {
+    opts.create_missing_column_families(true);
+    // listing fails if the db doesn't exist yet
+    let mut column_families = Rocks::list_cf(opts, path).unwrap_or_default();
+    for cf in required {
+        if !column_families.iter().any(|existing| existing == cf) {
+            column_families.push((*cf).to_owned());
+        }
     }
+    Rocks::open_cf(opts, path, column_families)
 }
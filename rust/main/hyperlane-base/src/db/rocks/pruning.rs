@@ -0,0 +1,438 @@
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use eyre::Result;
+use prometheus::{IntCounterVec, IntGaugeVec};
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use tracing::{info, info_span, warn, Instrument};
+
+use crate::CoreMetrics;
+
+use super::{DbResult, HyperlaneRocksDB, DB};
+
+/// Default time between pruning runs
+pub const DEFAULT_PRUNING_INTERVAL: Duration = Duration::from_secs(3_600);
+/// Default maximum number of messages pruned per chain and run
+pub const DEFAULT_PRUNING_BATCH_SIZE: u32 = 10_000;
+
+const DB_SIZE_BYTES_HELP: &str =
+    "Size of the entries of the agent database, by chain and key prefix";
+const DB_PRUNED_MESSAGES_HELP: &str =
+    "Number of delivered messages whose data was pruned from the agent database";
+
+/// Retention policy of the messages in an agent database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DbPruningConf {
+    /// The data of messages delivered longer ago than this is pruned
+    pub retention: Duration,
+    /// Time between pruning runs
+    pub interval: Duration,
+    /// Maximum number of messages pruned per chain and run
+    pub batch_size: u32,
+}
+
+/// Metrics of the [`DbPruner`]
+#[derive(Debug, Clone)]
+pub struct DbPruningMetrics {
+    size_bytes: IntGaugeVec,
+    pruned_messages: IntCounterVec,
+}
+
+impl DbPruningMetrics {
+    /// Create and register the metrics
+    pub fn new(metrics: &CoreMetrics) -> Result<Self> {
+        Ok(Self {
+            size_bytes: metrics.new_int_gauge(
+                "db_size_bytes",
+                DB_SIZE_BYTES_HELP,
+                &["chain", "prefix"],
+            )?,
+            pruned_messages: metrics.new_int_counter(
+                "db_pruned_messages",
+                DB_PRUNED_MESSAGES_HELP,
+                &["chain"],
+            )?,
+        })
+    }
+}
+
+/// Periodically prunes the data of delivered messages from an agent database,
+/// compacts it and reports its size
+#[derive(Debug)]
+pub struct DbPruner {
+    db: DB,
+    chain_dbs: Vec<HyperlaneRocksDB>,
+    conf: DbPruningConf,
+    metrics: DbPruningMetrics,
+}
+
+impl DbPruner {
+    /// Create a pruner of the chains `chain_dbs`, which are stored in `db`
+    pub fn new(
+        db: DB,
+        chain_dbs: Vec<HyperlaneRocksDB>,
+        conf: DbPruningConf,
+        metrics: DbPruningMetrics,
+    ) -> Self {
+        Self {
+            db,
+            chain_dbs,
+            conf,
+            metrics,
+        }
+    }
+
+    /// Spawns a tokio task pruning the database on an interval
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::task::Builder::new()
+            .name("db::pruner")
+            .spawn(
+                async move {
+                    self.run().await;
+                }
+                .instrument(info_span!("DbPruner")),
+            )
+            .expect("spawning tokio task from Builder is infallible")
+    }
+
+    async fn run(self) {
+        let pruner = Arc::new(self);
+        let mut interval = tokio::time::interval(pruner.conf.interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            // reading and compacting the database blocks for a while
+            let run = pruner.clone();
+            match tokio::task::spawn_blocking(move || run.prune()).await {
+                Ok(Ok(())) => {}
+                Ok(Err(err)) => warn!(?err, "Failed to prune database"),
+                Err(err) => warn!(?err, "Database pruning task failed"),
+            }
+        }
+    }
+
+    /// Prune the messages delivered before the retention period of every
+    /// chain, compact the database if anything was pruned and update the size
+    /// metrics
+    pub fn prune(&self) -> DbResult<()> {
+        let now = unix_timestamp();
+        let delivered_before = now.saturating_sub(self.conf.retention.as_secs());
+        let mut total_pruned: u32 = 0;
+        for db in &self.chain_dbs {
+            // messages delivered before deliveries were recorded are kept for
+            // the retention period from now on
+            db.backfill_message_deliveries(now, self.conf.batch_size)?;
+            let pruned = db.prune_delivered_messages(delivered_before, self.conf.batch_size)?;
+            self.metrics
+                .pruned_messages
+                .with_label_values(&[db.domain().name()])
+                .inc_by(pruned.into());
+            total_pruned = total_pruned.saturating_add(pruned);
+        }
+        if total_pruned > 0 {
+            info!(
+                pruned_messages = total_pruned,
+                "Pruned delivered messages, compacting database"
+            );
+            self.db.compact();
+        }
+        for db in &self.chain_dbs {
+            for (prefix, size) in db.size_by_key_prefix()? {
+                self.metrics
+                    .size_bytes
+                    .with_label_values(&[db.domain().name(), prefix])
+                    .set(i64::try_from(size).unwrap_or(i64::MAX));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Seconds since the unix epoch
+pub(crate) fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(all(test, feature = "rocksdb"))]
+mod test {
+    use hyperlane_core::{
+        accumulator::{incremental::IncrementalMerkle, merkle::MerkleTree, TREE_DEPTH},
+        GasPaymentKey, HyperlaneDomain, HyperlaneLogStore, HyperlaneMessage,
+        HyperlaneSequenceAwareIndexerStoreReader, Indexed, InterchainGasPayment,
+        InterchainGasPaymentMeta, LogMeta, MerkleTreeInsertion, PendingOperationStatus, H256, H512,
+        U256,
+    };
+    use prometheus::Registry;
+
+    use crate::db::{test_utils::run_test_db, HyperlaneDb};
+
+    use super::*;
+
+    fn log_meta(block_number: u64) -> LogMeta {
+        LogMeta {
+            address: H256::from_low_u64_be(1),
+            block_number,
+            block_hash: H256::from_low_u64_be(block_number),
+            transaction_id: H512::from_low_u64_be(block_number),
+            transaction_index: 0,
+            log_index: U256::from(0),
+        }
+    }
+
+    /// Index `count` messages with a body, their tree insertions and gas
+    /// payments, the payment of each message having its nonce as sequence
+    async fn index_messages(db: &HyperlaneRocksDB, count: u32) -> Vec<HyperlaneMessage> {
+        let messages = (0..count)
+            .map(|nonce| HyperlaneMessage {
+                nonce,
+                version: 3,
+                origin: 10,
+                sender: H256::from_low_u64_be(4),
+                destination: 12,
+                recipient: H256::from_low_u64_be(5),
+                body: vec![1, 2, 3],
+            })
+            .collect::<Vec<_>>();
+        for message in &messages {
+            let meta = log_meta(message.nonce.into());
+            db.store_logs(&[(Indexed::new(message.clone()), meta.clone())])
+                .await
+                .unwrap();
+            let insertion = MerkleTreeInsertion::new(message.nonce, message.id());
+            db.process_tree_insertion(&insertion, meta.block_number)
+                .unwrap();
+            let payment = InterchainGasPayment {
+                message_id: message.id(),
+                destination: message.destination,
+                payment: U256::from(100),
+                gas_amount: U256::from(10),
+            };
+            let payment = Indexed::new(payment).with_sequence(message.nonce);
+            db.process_indexed_gas_payment(payment, &meta).unwrap();
+        }
+        messages
+    }
+
+    fn gas_payment(db: &HyperlaneRocksDB, message: &HyperlaneMessage) -> Option<U256> {
+        db.retrieve_gas_payment_by_gas_payment_key(GasPaymentKey {
+            message_id: message.id(),
+            destination: message.destination,
+        })
+        .unwrap()
+        .map(|payment| payment.payment)
+    }
+
+    async fn indexed_gas_payment(db: &HyperlaneRocksDB, sequence: u32) -> bool {
+        HyperlaneSequenceAwareIndexerStoreReader::<InterchainGasPayment>::retrieve_by_sequence(
+            db, sequence,
+        )
+        .await
+        .unwrap()
+        .is_some()
+    }
+
+    #[tokio::test]
+    async fn pruning_keeps_what_relaying_and_proving_need() {
+        run_test_db(|db| async move {
+            let db = HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("pruning"), db)
+                .with_pruning();
+            let messages = index_messages(&db, 3).await;
+            db.store_status_by_message_id(
+                &messages[0].id(),
+                &PendingOperationStatus::ReadyToSubmit,
+            )
+            .unwrap();
+            // the first message was delivered long ago, the second recently
+            // and the last one isn't delivered yet
+            db.record_message_delivery(0, 100).unwrap();
+            db.record_message_delivery(1, 200).unwrap();
+            db.store_processed_by_nonce(&0, &true).unwrap();
+            db.store_processed_by_nonce(&1, &true).unwrap();
+
+            assert_eq!(db.prune_delivered_messages(150, 10).unwrap(), 1);
+            assert_eq!(db.prune_delivered_messages(150, 10).unwrap(), 0);
+
+            // the pruned message is kept without its body under its stored id
+            // and recorded as pruned, its payments and processing state are
+            // dropped
+            assert_eq!(
+                db.retrieve_message_by_nonce(0).unwrap(),
+                Some(HyperlaneMessage {
+                    body: vec![],
+                    ..messages[0].clone()
+                })
+            );
+            assert_eq!(
+                db.retrieve_message_id_by_nonce(&0).unwrap(),
+                Some(messages[0].id())
+            );
+            assert_eq!(db.retrieve_message_pruned_by_nonce(&0).unwrap(), Some(true));
+            assert_eq!(db.retrieve_message_pruned_by_nonce(&1).unwrap(), None);
+            assert_eq!(gas_payment(&db, &messages[0]), None);
+            assert_eq!(db.retrieve_gas_payment_by_sequence(&0).unwrap(), None);
+            let payment_meta = InterchainGasPaymentMeta::from(&log_meta(0));
+            assert_eq!(
+                db.retrieve_processed_by_gas_payment_meta(&payment_meta)
+                    .unwrap(),
+                None
+            );
+            assert_eq!(
+                db.retrieve_status_by_message_id(&messages[0].id()).unwrap(),
+                None
+            );
+            assert_eq!(
+                db.retrieve_dispatched_tx_hash_by_message_id(&messages[0].id())
+                    .unwrap(),
+                None
+            );
+            assert_eq!(
+                db.retrieve_message_by_nonce(1).unwrap(),
+                Some(messages[1].clone())
+            );
+            assert_eq!(gas_payment(&db, &messages[1]), Some(U256::from(100)));
+            assert!(db.retrieve_gas_payment_by_sequence(&1).unwrap().is_some());
+
+            // the gas payment of the pruned message isn't indexed again
+            assert!(indexed_gas_payment(&db, 0).await);
+            let payment = Indexed::new(InterchainGasPayment {
+                message_id: messages[0].id(),
+                destination: messages[0].destination,
+                payment: U256::from(100),
+                gas_amount: U256::from(10),
+            })
+            .with_sequence(0);
+            assert!(!db
+                .process_indexed_gas_payment(payment, &log_meta(0))
+                .unwrap());
+            assert_eq!(db.retrieve_gas_payment_by_sequence(&0).unwrap(), None);
+            assert_eq!(gas_payment(&db, &messages[0]), None);
+
+            // the indexer doesn't fetch the pruned message again and the
+            // relayer doesn't deliver it again
+            let indexed =
+                HyperlaneSequenceAwareIndexerStoreReader::<HyperlaneMessage>::retrieve_by_sequence(
+                    &db, 0,
+                )
+                .await
+                .unwrap();
+            assert!(indexed.is_some());
+            assert!(!db.store_message(&messages[0], 0).unwrap());
+            assert_eq!(db.retrieve_processed_by_nonce(&0).unwrap(), Some(true));
+            assert_eq!(db.retrieve_processed_by_nonce(&2).unwrap(), None);
+            assert_eq!(
+                db.retrieve_message_by_nonce(2).unwrap(),
+                Some(messages[2].clone())
+            );
+
+            // a proof of the pruned message can still be built
+            let leaves = (0..3)
+                .map(|index| {
+                    db.retrieve_merkle_tree_insertion_by_leaf_index(&index)
+                        .unwrap()
+                        .unwrap()
+                        .message_id()
+                })
+                .collect::<Vec<_>>();
+            let pruned_id = db.retrieve_message_id_by_nonce(&0).unwrap().unwrap();
+            let leaf_index = db
+                .retrieve_merkle_leaf_index_by_message_id(&pruned_id)
+                .unwrap()
+                .unwrap();
+            assert_eq!(leaves[leaf_index as usize], pruned_id);
+            let mut incremental = IncrementalMerkle::default();
+            leaves.iter().for_each(|leaf| incremental.ingest(*leaf));
+            let proof =
+                MerkleTree::create(&leaves, TREE_DEPTH).prove_against_current(leaf_index as usize);
+            assert_eq!(proof.leaf, pruned_id);
+            assert!(incremental.verify(&proof));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn deliveries_processed_before_recording_are_backfilled() {
+        run_test_db(|db| async move {
+            let db = HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("backfill"), db)
+                .with_pruning();
+            index_messages(&db, 3).await;
+            // processed as before deliveries were recorded
+            db.store_value_by_key("nonce_processed_", &1u32, &true)
+                .unwrap();
+
+            assert_eq!(db.backfill_message_deliveries(100, 2).unwrap(), 2);
+            assert_eq!(db.backfill_message_deliveries(100, 2).unwrap(), 1);
+            assert_eq!(db.backfill_message_deliveries(100, 2).unwrap(), 0);
+
+            assert_eq!(db.prune_delivered_messages(101, 10).unwrap(), 1);
+            assert_eq!(db.retrieve_message_pruned_by_nonce(&1).unwrap(), Some(true));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn deliveries_are_only_recorded_when_pruning() {
+        run_test_db(|db| async move {
+            let domain = HyperlaneDomain::new_test_domain("recording");
+            let unpruned = HyperlaneRocksDB::new(&domain, db.clone());
+            index_messages(&unpruned, 2).await;
+            unpruned.store_processed_by_nonce(&0, &true).unwrap();
+            assert_eq!(unpruned.prune_delivered_messages(u64::MAX, 10).unwrap(), 0);
+
+            let pruned = HyperlaneRocksDB::new(&domain, db).with_pruning();
+            pruned.store_processed_by_nonce(&1, &true).unwrap();
+            assert_eq!(pruned.prune_delivered_messages(u64::MAX, 10).unwrap(), 1);
+            assert_eq!(pruned.retrieve_message_pruned_by_nonce(&0).unwrap(), None);
+            assert_eq!(
+                pruned.retrieve_message_pruned_by_nonce(&1).unwrap(),
+                Some(true)
+            );
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn pruner_reports_pruned_messages_and_sizes() {
+        run_test_db(|db| async move {
+            let chain_db =
+                HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("pruner"), db.clone())
+                    .with_pruning();
+            index_messages(&chain_db, 2).await;
+            chain_db.record_message_delivery(0, 100).unwrap();
+            chain_db.store_processed_by_nonce(&0, &true).unwrap();
+            chain_db.store_processed_by_nonce(&1, &true).unwrap();
+
+            let core_metrics = CoreMetrics::new("test", 0, Registry::new()).unwrap();
+            let metrics = DbPruningMetrics::new(&core_metrics).unwrap();
+            let conf = DbPruningConf {
+                retention: Duration::from_secs(3_600),
+                interval: DEFAULT_PRUNING_INTERVAL,
+                batch_size: DEFAULT_PRUNING_BATCH_SIZE,
+            };
+            let pruner = DbPruner::new(db, vec![chain_db], conf, metrics.clone());
+            pruner.prune().unwrap();
+
+            let chain = "pruner";
+            assert_eq!(metrics.pruned_messages.with_label_values(&[chain]).get(), 1);
+            assert!(
+                metrics
+                    .size_bytes
+                    .with_label_values(&[chain, "message"])
+                    .get()
+                    > 0
+            );
+            assert!(
+                metrics
+                    .size_bytes
+                    .with_label_values(&[chain, "merkle_tree_insertion"])
+                    .get()
+                    > 0
+            );
+        })
+        .await;
+    }
+}
@@ -4,11 +4,13 @@ use tempfile::TempDir;
 
 use crate::db::DB;
 
+use super::open_with_column_families;
+
 /// Create a database from a path.
 pub fn setup_db(db_path: String) -> DB {
     let mut opts = Options::default();
     opts.create_if_missing(true);
-    rocksdb::DB::open(&opts, db_path)
+    open_with_column_families(&mut opts, db_path.as_ref(), &[])
         .expect("Failed to open db path")
         .into()
 }
@@ -123,4 +125,16 @@ mod test {
         })
         .await;
     }
+
+    #[test]
+    fn read_only_db_reads_without_writing() {
+        let dir = TempDir::new().unwrap();
+        let db = DB::from_path(dir.path()).unwrap();
+        db.store(b"key", b"value").unwrap();
+
+        let read_only = DB::from_path_read_only(dir.path()).unwrap();
+        assert_eq!(read_only.retrieve(b"key").unwrap(), Some(b"value".to_vec()));
+        assert!(read_only.store(b"key", b"other").is_err());
+        assert!(DB::from_path_read_only(&dir.path().join("missing")).is_err());
+    }
 }
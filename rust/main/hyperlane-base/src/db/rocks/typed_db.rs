@@ -4,7 +4,9 @@ use crate::db::{error::DbError, DB};
 
 type Result<T> = std::result::Result<T, DbError>;
 
-/// DB handle for storing data tied to a specific type/entity.
+/// DB handle for storing data tied to a specific type/entity. The data is
+/// kept in whichever [`KeyValueStore`](crate::db::KeyValueStore) backs the
+/// [`DB`].
 ///
 /// Key structure: ```<domain_prefix>_<additional_prefix(es)>_<key>```
 #[derive(Debug, Clone)]
@@ -67,6 +69,29 @@ impl TypedDB {
             .map_err(Into::into)
     }
 
+    /// Delete a value
+    pub fn delete(&self, prefix: impl AsRef<[u8]>, key: impl AsRef<[u8]>) -> Result<()> {
+        self.db
+            .delete(&self.prefixed_key(prefix.as_ref(), key.as_ref()))
+    }
+
+    /// Iterate over the entries of the domain whose key starts with `prefix`,
+    /// in key order. The keys are returned without the domain prefix.
+    pub fn prefix_iterator(
+        &self,
+        prefix: &[u8],
+    ) -> impl Iterator<Item = Result<(Vec<u8>, Box<[u8]>)>> + '_ {
+        let domain_prefix_len = self.domain_prefix.len();
+        self.db
+            .prefix_iterator(&self.prefixed_key(prefix, &[]))
+            .map(move |entry| {
+                entry.map(|(key, value)| {
+                    let key = key.get(domain_prefix_len..).unwrap_or_default().to_vec();
+                    (key, value)
+                })
+            })
+    }
+
     /// Store encodable kv pair
     pub fn store_keyed_encodable<K: Encode, V: Encode>(
         &self,
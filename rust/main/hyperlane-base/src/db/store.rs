@@ -0,0 +1,39 @@
+use std::fmt::Debug;
+
+use super::DbResult;
+
+/// Entries of a [`KeyValueStore`], in key order
+pub type StoreEntries<'a> = Box<dyn Iterator<Item = DbResult<(Box<[u8]>, Box<[u8]>)>> + 'a>;
+
+/// A key-value store backing a [`DB`](super::DB).
+///
+/// Besides the default keyspace, entries can be kept in named column families,
+/// each being a keyspace of its own.
+pub trait KeyValueStore: Debug + Send + Sync {
+    /// Store a value
+    fn store(&self, key: &[u8], value: &[u8]) -> DbResult<()>;
+
+    /// Retrieve a value
+    fn retrieve(&self, key: &[u8]) -> DbResult<Option<Vec<u8>>>;
+
+    /// Delete a value
+    fn delete(&self, key: &[u8]) -> DbResult<()>;
+
+    /// Iterate over the entries whose key starts with `prefix`, in key order
+    fn prefix_entries(&self, prefix: &[u8]) -> StoreEntries<'_>;
+
+    /// Store a value in a column family
+    fn store_cf(&self, cf: &str, key: &[u8], value: &[u8]) -> DbResult<()>;
+
+    /// Retrieve a value from a column family
+    fn retrieve_cf(&self, cf: &str, key: &[u8]) -> DbResult<Option<Vec<u8>>>;
+
+    /// Delete a value from a column family
+    fn delete_cf(&self, cf: &str, key: &[u8]) -> DbResult<()>;
+
+    /// Iterate over the entries of a column family, in key order
+    fn entries_cf(&self, cf: &str) -> StoreEntries<'_>;
+
+    /// Reclaim the space of deleted entries, if the store needs to
+    fn compact(&self) {}
+}
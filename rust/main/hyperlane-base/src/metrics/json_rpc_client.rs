@@ -2,8 +2,10 @@ use eyre::Result;
 use hyperlane_metric::prometheus_metric::{
     PrometheusClientMetrics, PrometheusClientMetricsBuilder, PROVIDER_CREATE_COUNT_HELP,
     PROVIDER_CREATE_COUNT_LABELS, PROVIDER_DROP_COUNT_HELP, PROVIDER_DROP_COUNT_LABELS,
-    REQUEST_COUNT_HELP, REQUEST_COUNT_LABELS, REQUEST_DURATION_SECONDS_HELP,
-    REQUEST_DURATION_SECONDS_LABELS,
+    PROVIDER_SCORE_HELP, PROVIDER_SCORE_LABELS, REQUEST_COUNT_HELP, REQUEST_COUNT_LABELS,
+    REQUEST_DURATION_SECONDS_HELP, REQUEST_DURATION_SECONDS_LABELS,
+    RPC_REMAINING_DAILY_CREDITS_HELP, RPC_REMAINING_DAILY_CREDITS_LABELS,
+    RPC_THROTTLED_SECONDS_HELP, RPC_THROTTLED_SECONDS_LABELS,
 };
 
 use crate::CoreMetrics;
@@ -32,5 +34,20 @@ pub(crate) fn create_json_rpc_client_metrics(
             PROVIDER_DROP_COUNT_HELP,
             PROVIDER_DROP_COUNT_LABELS,
         )?)
+        .provider_score(metrics.new_gauge(
+            "provider_score",
+            PROVIDER_SCORE_HELP,
+            PROVIDER_SCORE_LABELS,
+        )?)
+        .rpc_remaining_daily_credits(metrics.new_gauge(
+            "rpc_remaining_daily_credits",
+            RPC_REMAINING_DAILY_CREDITS_HELP,
+            RPC_REMAINING_DAILY_CREDITS_LABELS,
+        )?)
+        .rpc_throttled_seconds(metrics.new_counter(
+            "rpc_throttled_seconds",
+            RPC_THROTTLED_SECONDS_HELP,
+            RPC_THROTTLED_SECONDS_LABELS,
+        )?)
         .build()?)
 }
@@ -268,30 +268,131 @@ impl Settings {
             + HyperlaneWatermarkedLogStore<T>
             + 'static,
     {
-        let sync = match T::indexing_cursor(domain.domain_protocol()) {
-            CursorType::SequenceAware => self
-                .sequenced_contract_sync(
-                    domain,
-                    metrics,
-                    sync_metrics,
-                    store,
-                    advanced_log_meta,
-                    broadcast_sender_enabled,
-                )
-                .await
-                .map(|r| r as Arc<dyn ContractSyncer<T>>)?,
-            CursorType::RateLimited => self
-                .watermark_contract_sync(
-                    domain,
-                    metrics,
-                    sync_metrics,
-                    store,
-                    advanced_log_meta,
-                    broadcast_sender_enabled,
-                )
-                .await
-                .map(|r| r as Arc<dyn ContractSyncer<T>>)?,
+        let setup = self.chain_setup(domain)?;
+        let indexer =
+            SequenceIndexer::<T>::try_from_with_metrics(setup, metrics, advanced_log_meta).await?;
+        Ok(contract_syncer(
+            domain,
+            indexer,
+            store,
+            sync_metrics,
+            broadcast_sender_enabled,
+        ))
+    }
+}
+
+/// Wrap `indexer` in the contract sync of the cursor type `T` is indexed
+/// with on `domain`
+fn contract_syncer<T, S>(
+    domain: &HyperlaneDomain,
+    indexer: SequenceIndexer<T>,
+    store: Arc<S>,
+    sync_metrics: &ContractSyncMetrics,
+    broadcast_sender_enabled: bool,
+) -> Arc<dyn ContractSyncer<T>>
+where
+    T: Indexable + Debug + Send + Sync + Clone + Eq + Hash + 'static,
+    S: HyperlaneLogStore<T>
+        + HyperlaneSequenceAwareIndexerStoreReader<T>
+        + HyperlaneWatermarkedLogStore<T>
+        + 'static,
+{
+    match T::indexing_cursor(domain.domain_protocol()) {
+        CursorType::SequenceAware => Arc::new(SequencedDataContractSync::<T>::new(
+            domain.clone(),
+            store as SequenceAwareLogStore<_>,
+            indexer,
+            sync_metrics.clone(),
+            broadcast_sender_enabled,
+        )),
+        CursorType::RateLimited => Arc::new(WatermarkContractSync::<T>::new(
+            domain.clone(),
+            store as WatermarkLogStore<_>,
+            indexer,
+            sync_metrics.clone(),
+            broadcast_sender_enabled,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeInclusive;
+
+    use async_trait::async_trait;
+    use prometheus::Registry;
+
+    use hyperlane_core::{
+        ChainResult, HyperlaneMessage, IndexMode, Indexed, Indexer, InterchainGasPayment,
+        KnownHyperlaneDomain, LogMeta, MerkleTreeInsertion,
+    };
+
+    use crate::{
+        db::{HyperlaneRocksDB, DB},
+        settings::IndexSettings,
+    };
+
+    use super::*;
+
+    /// An indexer of a chain without any event yet
+    #[derive(Debug)]
+    struct EmptyIndexer;
+
+    #[async_trait]
+    impl<T: Send + Sync + Debug + 'static> Indexer<T> for EmptyIndexer {
+        async fn fetch_logs_in_range(
+            &self,
+            _range: RangeInclusive<u32>,
+        ) -> ChainResult<Vec<(Indexed<T>, LogMeta)>> {
+            Ok(vec![])
+        }
+
+        async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+            Ok(100)
+        }
+    }
+
+    #[async_trait]
+    impl<T: Send + Sync + Debug + 'static> SequenceAwareIndexer<T> for EmptyIndexer {
+        async fn latest_sequence_count_and_tip(&self) -> ChainResult<(Option<u32>, u32)> {
+            Ok((Some(0), 100))
+        }
+    }
+
+    async fn assert_syncs<T>(domain: &HyperlaneDomain, db: &Arc<HyperlaneRocksDB>)
+    where
+        T: Indexable + Debug + Send + Sync + Clone + Eq + Hash + 'static,
+        HyperlaneRocksDB: HyperlaneLogStore<T>
+            + HyperlaneSequenceAwareIndexerStoreReader<T>
+            + HyperlaneWatermarkedLogStore<T>,
+    {
+        let core_metrics = CoreMetrics::new("test", 0, Registry::new()).unwrap();
+        let sync = contract_syncer::<T, _>(
+            domain,
+            Arc::new(EmptyIndexer),
+            db.clone(),
+            &ContractSyncMetrics::new(&core_metrics),
+            false,
+        );
+        let index_settings = IndexSettings {
+            from: 0,
+            chunk_size: 10,
+            mode: IndexMode::Sequence,
         };
-        Ok(sync)
+
+        assert_eq!(sync.domain(), domain);
+        sync.cursor(index_settings)
+            .await
+            .unwrap_or_else(|err| panic!("no {} cursor on {domain}: {err}", T::name()));
+    }
+
+    #[tokio::test]
+    async fn fuel_contract_syncs_build() {
+        let domain = HyperlaneDomain::Known(KnownHyperlaneDomain::FuelTest1);
+        let db = Arc::new(HyperlaneRocksDB::new(&domain, DB::in_memory()));
+
+        assert_syncs::<HyperlaneMessage>(&domain, &db).await;
+        assert_syncs::<InterchainGasPayment>(&domain, &db).await;
+        assert_syncs::<MerkleTreeInsertion>(&domain, &db).await;
     }
 }
@@ -22,6 +22,7 @@ use hyperlane_operation_verifier::ApplicationOperationVerifier;
 
 #[cfg(feature = "aleo")]
 use hyperlane_aleo::{self as h_aleo, AleoProvider};
+use hyperlane_aptos::{self as h_aptos, AptosProvider};
 use hyperlane_cosmos::{
     self as h_cosmos, cw::CwQueryClient, native::ModuleQueryClient, CosmosProvider,
 };
@@ -76,6 +77,9 @@ pub struct ChainConf {
     pub domain: HyperlaneDomain,
     /// Signer configuration for this chain
     pub signer: Option<SignerConf>,
+    /// Additional signers which submit transactions alongside `signer`,
+    /// each with its own nonce lane. Only supported by Lander on EVM chains.
+    pub additional_signers: Vec<SignerConf>,
     /// Submitter type for this chain
     pub submitter: SubmitterType,
     /// The estimated block time, i.e. the average time the next block is added to the chain
@@ -192,6 +196,8 @@ pub enum ChainConnectionConf {
     Aleo(h_aleo::ConnectionConf),
     /// Tron configuration
     Tron(h_tron::ConnectionConf),
+    /// Aptos configuration
+    Aptos(h_aptos::ConnectionConf),
 }
 
 impl ChainConnectionConf {
@@ -206,6 +212,7 @@ impl ChainConnectionConf {
             Self::CosmosNative(_) => HyperlaneDomainProtocol::CosmosNative,
             Self::Radix(_) => HyperlaneDomainProtocol::Radix,
             Self::Tron(_) => HyperlaneDomainProtocol::Tron,
+            Self::Aptos(_) => HyperlaneDomainProtocol::Aptos,
             #[cfg(feature = "aleo")]
             Self::Aleo(_) => HyperlaneDomainProtocol::Aleo,
         }
@@ -270,7 +277,14 @@ impl ChainConf {
                 h_eth::application::EthereumApplicationOperationVerifier::new(),
             )
                 as Box<dyn ApplicationOperationVerifier>),
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Aptos(_) => Ok(Box::new(
+                h_aptos::application::AptosApplicationOperationVerifier::new(),
+            )
+                as Box<dyn ApplicationOperationVerifier>),
+            ChainConnectionConf::Fuel(_) => Ok(Box::new(
+                h_fuel::application::FuelApplicationOperationVerifier::new(),
+            )
+                as Box<dyn ApplicationOperationVerifier>),
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
                     Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
@@ -316,7 +330,10 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::HyperlaneProviderBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let provider = h_fuel::FuelProvider::new(locator.domain.clone(), conf).await;
+                Ok(Box::new(provider) as Box<dyn HyperlaneProvider>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider = build_sealevel_provider(
                     self,
@@ -350,6 +367,10 @@ impl ChainConf {
                 let provider = build_tron_provider(self, conf, metrics, &locator, None)?;
                 Ok(Box::new(provider) as Box<dyn HyperlaneProvider>)
             }
+            ChainConnectionConf::Aptos(conf) => {
+                let provider = build_aptos_provider(self, conf, metrics, &locator, None)?;
+                Ok(Box::new(provider) as Box<dyn HyperlaneProvider>)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(conf) => {
                 let provider = build_aleo_provider(self, conf, metrics, &locator, None)?;
@@ -370,8 +391,8 @@ impl ChainConf {
                     .await
             }
             ChainConnectionConf::Fuel(conf) => {
-                let wallet = self.fuel_signer().await.context(ctx)?;
-                hyperlane_fuel::FuelMailbox::new(conf, locator, wallet)
+                let signer = self.fuel_signer().await.context(ctx)?;
+                hyperlane_fuel::FuelMailbox::new(conf, locator, signer)
                     .await
                     .map(|m| Box::new(m) as Box<dyn Mailbox>)
                     .map_err(Into::into)
@@ -429,6 +450,12 @@ impl ChainConf {
                 let mailbox = h_tron::TronMailbox::new(provider, &locator);
                 Ok(Box::new(mailbox) as Box<dyn Mailbox>)
             }
+            ChainConnectionConf::Aptos(conf) => {
+                let signer = self.aptos_signer().await.context(ctx)?;
+                let provider = build_aptos_provider(self, conf, metrics, &locator, signer)?;
+                let mailbox = h_aptos::AptosMailbox::new(provider, &locator);
+                Ok(Box::new(mailbox) as Box<dyn Mailbox>)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(conf) => {
                 let signer = self.aleo_signer().await?;
@@ -453,8 +480,10 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::MerkleTreeHookBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_conf) => {
-                todo!("Fuel does not support merkle tree hooks yet")
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_read_only_wallet().await.context(ctx)?;
+                let hook = h_fuel::FuelMerkleTreeHook::new(conf, locator, wallet).await?;
+                Ok(Box::new(hook) as Box<dyn MerkleTreeHook>)
             }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
@@ -497,6 +526,11 @@ impl ChainConf {
 
                 Ok(Box::new(hook) as Box<dyn MerkleTreeHook>)
             }
+            ChainConnectionConf::Aptos(conf) => {
+                let provider = build_aptos_provider(self, conf, metrics, &locator, None)?;
+                let hook = h_aptos::AptosMerkleTreeHook::new(provider, &locator);
+                Ok(Box::new(hook) as Box<dyn MerkleTreeHook>)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(conf) => {
                 let provider = build_aleo_provider(self, conf, metrics, &locator, None)?;
@@ -529,7 +563,11 @@ impl ChainConf {
                 )
                 .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_read_only_wallet().await.context(ctx)?;
+                let indexer = h_fuel::FuelMailboxIndexer::new(conf, locator, wallet).await?;
+                Ok(Box::new(indexer) as Box<dyn SequenceAwareIndexer<HyperlaneMessage>>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
                     Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
@@ -586,6 +624,12 @@ impl ChainConf {
 
                 Ok(Box::new(indexer) as Box<dyn SequenceAwareIndexer<HyperlaneMessage>>)
             }
+            ChainConnectionConf::Aptos(conf) => {
+                let provider = build_aptos_provider(self, conf, metrics, &locator, None)?;
+                let indexer = h_aptos::AptosDispatchIndexer::new(provider, &locator);
+
+                Ok(Box::new(indexer) as Box<dyn SequenceAwareIndexer<HyperlaneMessage>>)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(conf) => {
                 let provider = build_aleo_provider(self, conf, metrics, &locator, None)?;
@@ -618,7 +662,11 @@ impl ChainConf {
                 )
                 .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_read_only_wallet().await.context(ctx)?;
+                let indexer = h_fuel::FuelMailboxIndexer::new(conf, locator, wallet).await?;
+                Ok(Box::new(indexer) as Box<dyn SequenceAwareIndexer<H256>>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
                     Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
@@ -671,6 +719,12 @@ impl ChainConf {
 
                 Ok(Box::new(indexer) as Box<dyn SequenceAwareIndexer<H256>>)
             }
+            ChainConnectionConf::Aptos(conf) => {
+                let provider = build_aptos_provider(self, conf, metrics, &locator, None)?;
+                let indexer = h_aptos::AptosDeliveryIndexer::new(provider, &locator);
+
+                Ok(Box::new(indexer) as Box<dyn SequenceAwareIndexer<H256>>)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(conf) => {
                 let provider = build_aleo_provider(self, conf, metrics, &locator, None)?;
@@ -701,7 +755,12 @@ impl ChainConf {
                 )
                 .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_read_only_wallet().await.context(ctx)?;
+                let paymaster =
+                    h_fuel::FuelInterchainGasPaymaster::new(conf, locator, wallet).await?;
+                Ok(Box::new(paymaster) as Box<dyn InterchainGasPaymaster>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
                     Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
@@ -748,6 +807,11 @@ impl ChainConf {
                     Box::new(h_tron::TronInterchainGasPaymaster::new(provider, &locator));
                 Ok(paymaster as Box<dyn InterchainGasPaymaster>)
             }
+            ChainConnectionConf::Aptos(conf) => {
+                let provider = build_aptos_provider(self, conf, metrics, &locator, None)?;
+                let igp = h_aptos::AptosInterchainGasIndexer::new(provider, &locator);
+                Ok(Box::new(igp) as Box<dyn InterchainGasPaymaster>)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(conf) => {
                 let provider = build_aleo_provider(self, conf, metrics, &locator, None)?;
@@ -783,7 +847,12 @@ impl ChainConf {
                 )
                 .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_read_only_wallet().await.context(ctx)?;
+                let indexer =
+                    h_fuel::FuelInterchainGasPaymasterIndexer::new(conf, locator, wallet).await?;
+                Ok(Box::new(indexer) as Box<dyn SequenceAwareIndexer<InterchainGasPayment>>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
                     Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
@@ -828,6 +897,11 @@ impl ChainConf {
                 let indexer = Box::new(h_tron::TronInterchainGasPaymaster::new(provider, &locator));
                 Ok(indexer as Box<dyn SequenceAwareIndexer<InterchainGasPayment>>)
             }
+            ChainConnectionConf::Aptos(conf) => {
+                let provider = build_aptos_provider(self, conf, metrics, &locator, None)?;
+                let indexer = h_aptos::AptosInterchainGasIndexer::new(provider, &locator);
+                Ok(Box::new(indexer) as Box<dyn SequenceAwareIndexer<InterchainGasPayment>>)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(conf) => {
                 let provider = build_aleo_provider(self, conf, metrics, &locator, None)?;
@@ -901,7 +975,11 @@ impl ChainConf {
                 )
                 .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_read_only_wallet().await.context(ctx)?;
+                let indexer = h_fuel::FuelMerkleTreeHookIndexer::new(conf, locator, wallet).await?;
+                Ok(Box::new(indexer) as Box<dyn SequenceAwareIndexer<MerkleTreeInsertion>>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let provider =
                     Arc::new(build_sealevel_provider(self, &locator, &[], conf, metrics));
@@ -957,6 +1035,11 @@ impl ChainConf {
                 let indexer = Box::new(h_tron::TronMerkleTreeHookIndexer::new(provider, &locator));
                 Ok(indexer as Box<dyn SequenceAwareIndexer<MerkleTreeInsertion>>)
             }
+            ChainConnectionConf::Aptos(conf) => {
+                let provider = build_aptos_provider(self, conf, metrics, &locator, None)?;
+                let indexer = h_aptos::AptosMerkleTreeHook::new(provider, &locator);
+                Ok(Box::new(indexer) as Box<dyn SequenceAwareIndexer<MerkleTreeInsertion>>)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(conf) => {
                 let provider = build_aleo_provider(self, conf, metrics, &locator, None)?;
@@ -980,7 +1063,9 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::ValidatorAnnounceBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(_) => {
+                Err(eyre!("Fuel does not support validator announce yet")).context(ctx)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let signer = self.sealevel_signer().await.context(ctx)?;
                 let provider =
@@ -1044,6 +1129,12 @@ impl ChainConf {
                 let validator_announce = h_tron::TronValidatorAnnounce::new(provider, &locator);
                 Ok(Box::new(validator_announce) as Box<dyn ValidatorAnnounce>)
             }
+            ChainConnectionConf::Aptos(conf) => {
+                let signer = self.aptos_signer().await.context(ctx)?;
+                let provider = build_aptos_provider(self, conf, metrics, &locator, signer)?;
+                let validator_announce = h_aptos::AptosValidatorAnnounce::new(provider, &locator);
+                Ok(Box::new(validator_announce) as Box<dyn ValidatorAnnounce>)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(conf) => {
                 let signer = self.aleo_signer().await?;
@@ -1076,7 +1167,11 @@ impl ChainConf {
                 )
                 .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_read_only_wallet().await.context(ctx)?;
+                let ism = h_fuel::FuelInterchainSecurityModule::new(conf, locator, wallet).await?;
+                Ok(Box::new(ism) as Box<dyn InterchainSecurityModule>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let keypair = self.sealevel_signer().await.context(ctx)?;
                 let provider =
@@ -1120,6 +1215,11 @@ impl ChainConf {
                 let ism = h_tron::TronInterchainSecurityModule::new(provider, &locator);
                 Ok(Box::new(ism) as Box<dyn InterchainSecurityModule>)
             }
+            ChainConnectionConf::Aptos(conf) => {
+                let provider = build_aptos_provider(self, conf, metrics, &locator, None)?;
+                let ism = h_aptos::AptosIsm::new(provider, &locator);
+                Ok(Box::new(ism) as Box<dyn InterchainSecurityModule>)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(conf) => {
                 let provider = build_aleo_provider(self, conf, metrics, &locator, None)?;
@@ -1144,7 +1244,11 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::MultisigIsmBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_read_only_wallet().await.context(ctx)?;
+                let ism = h_fuel::FuelMultisigIsm::new(conf, locator, wallet).await?;
+                Ok(Box::new(ism) as Box<dyn MultisigIsm>)
+            }
             ChainConnectionConf::Sealevel(conf) => {
                 let keypair = self.sealevel_signer().await.context(ctx)?;
                 let provider =
@@ -1185,6 +1289,11 @@ impl ChainConf {
                 let ism = h_tron::TronMultisigIsm::new(provider, &locator);
                 Ok(Box::new(ism) as Box<dyn MultisigIsm>)
             }
+            ChainConnectionConf::Aptos(conf) => {
+                let provider = build_aptos_provider(self, conf, metrics, &locator, None)?;
+                let ism = h_aptos::AptosIsm::new(provider, &locator);
+                Ok(Box::new(ism) as Box<dyn MultisigIsm>)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(conf) => {
                 let provider = build_aleo_provider(self, conf, metrics, &locator, None)?;
@@ -1212,7 +1321,11 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::RoutingIsmBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(conf) => {
+                let wallet = self.fuel_read_only_wallet().await.context(ctx)?;
+                let ism = h_fuel::FuelRoutingIsm::new(conf, locator, wallet).await?;
+                Ok(Box::new(ism) as Box<dyn RoutingIsm>)
+            }
             ChainConnectionConf::Sealevel(_) => {
                 Err(eyre!("Sealevel does not support routing ISM yet")).context(ctx)
             }
@@ -1245,6 +1358,11 @@ impl ChainConf {
                 let ism = h_tron::TronRoutingIsm::new(provider, &locator);
                 Ok(Box::new(ism) as Box<dyn RoutingIsm>)
             }
+            ChainConnectionConf::Aptos(conf) => {
+                let provider = build_aptos_provider(self, conf, metrics, &locator, None)?;
+                let ism = h_aptos::AptosIsm::new(provider, &locator);
+                Ok(Box::new(ism) as Box<dyn RoutingIsm>)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(conf) => {
                 let provider = build_aleo_provider(self, conf, metrics, &locator, None)?;
@@ -1272,7 +1390,9 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::AggregationIsmBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(_) => {
+                Err(eyre!("Fuel does not support aggregation ISM yet")).context(ctx)
+            }
             ChainConnectionConf::Sealevel(_) => {
                 Err(eyre!("Sealevel does not support aggregation ISM yet")).context(ctx)
             }
@@ -1305,6 +1425,9 @@ impl ChainConf {
                 let ism = h_tron::TronAggregationIsm::new(provider, &locator);
                 Ok(Box::new(ism) as Box<dyn AggregationIsm>)
             }
+            ChainConnectionConf::Aptos(_) => {
+                Err(eyre!("Aptos does not support aggregation ISM yet")).context(ctx)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(_) => Err(eyre!("Aleo support missing")).context(ctx),
         }
@@ -1328,7 +1451,9 @@ impl ChainConf {
                 self.build_ethereum(conf, &locator, metrics, h_eth::CcipReadIsmBuilder {})
                     .await
             }
-            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Fuel(_) => {
+                Err(eyre!("Fuel does not support CCIP read ISM yet")).context(ctx)
+            }
             ChainConnectionConf::Sealevel(_) => {
                 Err(eyre!("Sealevel does not support CCIP read ISM yet")).context(ctx)
             }
@@ -1349,6 +1474,9 @@ impl ChainConf {
                 let ism = h_tron::TronCcipReadIsm::new(provider, &locator);
                 Ok(Box::new(ism) as Box<dyn CcipReadIsm>)
             }
+            ChainConnectionConf::Aptos(_) => {
+                Err(eyre!("Aptos does not support CCIP read ISM yet")).context(ctx)
+            }
             #[cfg(feature = "aleo")]
             ChainConnectionConf::Aleo(_) => Err(eyre!("Aleo support missing")).context(ctx),
         }
@@ -1385,6 +1513,9 @@ impl ChainConf {
                     Box::new(conf.build::<h_radix::RadixSigner>().await?)
                 }
                 ChainConnectionConf::Tron(_) => Box::new(conf.build::<h_tron::TronSigner>().await?),
+                ChainConnectionConf::Aptos(_) => {
+                    Box::new(conf.build::<h_aptos::AptosSigner>().await?)
+                }
                 #[cfg(feature = "aleo")]
                 ChainConnectionConf::Aleo(_) => Box::new(conf.build::<h_aleo::AleoSigner>().await?),
             };
@@ -1399,10 +1530,17 @@ impl ChainConf {
         self.signer().await
     }
 
-    async fn fuel_signer(&self) -> Result<fuels::prelude::WalletUnlocked> {
-        self.signer().await.and_then(|opt| {
-            opt.ok_or_else(|| eyre!("Fuel requires a signer to construct contract instances"))
-        })
+    async fn fuel_signer(&self) -> Result<Option<fuels::prelude::WalletUnlocked>> {
+        self.signer().await
+    }
+
+    /// The Fuel signer if there is one, or else a throwaway wallet. Enough for
+    /// contracts which only simulate calls and index events.
+    async fn fuel_read_only_wallet(&self) -> Result<fuels::prelude::WalletUnlocked> {
+        Ok(self
+            .fuel_signer()
+            .await?
+            .unwrap_or_else(|| fuels::prelude::WalletUnlocked::new_random(None)))
     }
 
     async fn sealevel_signer(&self) -> Result<Option<h_sealevel::Keypair>> {
@@ -1430,6 +1568,10 @@ impl ChainConf {
         self.signer().await
     }
 
+    async fn aptos_signer(&self) -> Result<Option<h_aptos::AptosSigner>> {
+        self.signer().await
+    }
+
     /// Try to build an agent metrics configuration from the chain config
     pub async fn agent_metrics_conf(&self, agent_name: String) -> Result<AgentMetricsConf> {
         let chain_signer_address = self.chain_signer().await?.map(|s| s.address_string());
@@ -1514,6 +1656,23 @@ impl ChainConf {
         if B::NEEDS_SIGNER {
             signer = self.ethereum_signer().await?;
         }
+        self.build_ethereum_with_signer(conf, locator, metrics, builder, signer)
+            .await
+    }
+
+    /// Build an ethereum object using the given signer instead of the one
+    /// configured for the chain
+    pub async fn build_ethereum_with_signer<B>(
+        &self,
+        conf: &h_eth::ConnectionConf,
+        locator: &ContractLocator<'_>,
+        metrics: &CoreMetrics,
+        builder: B,
+        signer: Option<h_eth::Signers>,
+    ) -> Result<B::Output>
+    where
+        B: BuildableWithProvider + Sync,
+    {
         let metrics_conf = self.metrics_conf();
         let client_metrics = metrics.client_metrics();
 
@@ -1540,7 +1699,8 @@ fn build_sealevel_provider(
 
     let chain = middleware_metrics.chain.clone();
     let urls = conf.urls.clone();
-    let rpc_client = SealevelFallbackRpcClient::from_urls(chain, urls, client_metrics);
+    let rpc_client =
+        SealevelFallbackRpcClient::from_urls(chain, urls, &conf.rpc_rate_limits, client_metrics);
     SealevelProvider::new(rpc_client, locator.domain.clone(), contract_addresses, conf)
 }
 
@@ -1669,3 +1829,21 @@ fn build_tron_provider(
         middleware_metrics.chain.clone(),
     )
 }
+
+fn build_aptos_provider(
+    chain_conf: &ChainConf,
+    connection_conf: &h_aptos::ConnectionConf,
+    metrics: &CoreMetrics,
+    locator: &ContractLocator,
+    signer: Option<h_aptos::AptosSigner>,
+) -> ChainResult<AptosProvider> {
+    let middleware_metrics = chain_conf.metrics_conf();
+    let metrics = metrics.client_metrics();
+    AptosProvider::new(
+        connection_conf,
+        locator.domain.clone(),
+        signer,
+        metrics,
+        middleware_metrics.chain,
+    )
+}
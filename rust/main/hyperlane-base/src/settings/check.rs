@@ -0,0 +1,278 @@
+//! Offline checks of the agent config, run instead of the agent with `--check-config`.
+//!
+//! Loading the settings already parses every chain connection. On top of that, signers and
+//! checkpoint syncers are built where this doesn't need network access, e.g. local keys are
+//! turned into addresses while AWS KMS signers are skipped.
+
+use hyperlane_core::config::ConfigParsingError;
+use itertools::Itertools;
+use serde::Serialize;
+
+use crate::settings::{
+    BuildableWithSignerConf, ChainSigner, CheckpointSyncerConf, Settings, SignerConf,
+};
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CheckStatus {
+    /// The check passed
+    Ok,
+    /// The check needs network access and was not run
+    Skipped,
+    /// The check failed
+    Failed,
+}
+
+/// A single check of the config
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigCheck {
+    /// What was checked, e.g. `chains.ethereum.signer`
+    pub subject: String,
+    /// Outcome of the check
+    pub status: CheckStatus,
+    /// Details of the outcome, e.g. the address of a signer or why it failed
+    pub detail: String,
+}
+
+impl ConfigCheck {
+    /// A passed check
+    pub fn ok(subject: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self::new(subject, CheckStatus::Ok, detail)
+    }
+
+    /// A check which was not run
+    pub fn skipped(subject: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self::new(subject, CheckStatus::Skipped, detail)
+    }
+
+    /// A failed check
+    pub fn failed(subject: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self::new(subject, CheckStatus::Failed, detail)
+    }
+
+    fn new(subject: impl Into<String>, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            subject: subject.into(),
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// An error of parsing the config, with all the ways to set the faulty key
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigErrorReport {
+    /// Path of the key in the config files
+    pub config_path: String,
+    /// Name of the env var setting the key
+    pub env_name: String,
+    /// Name of the command line argument setting the key
+    pub arg_name: String,
+    /// What is wrong with the key
+    pub error: String,
+}
+
+/// Report of `--check-config`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigCheckReport {
+    /// Name of the agent whose config was checked
+    pub agent: String,
+    /// Whether the config parsed and all checks which were run passed
+    pub valid: bool,
+    /// Errors of parsing the config, the checks are not run if there are any
+    pub errors: Vec<ConfigErrorReport>,
+    /// Checks run on the parsed config
+    pub checks: Vec<ConfigCheck>,
+}
+
+impl ConfigCheckReport {
+    /// Report of a config which failed to parse
+    pub fn from_parsing_error(agent: &str, err: &ConfigParsingError) -> Self {
+        let errors = err
+            .iter()
+            .map(|(path, report)| ConfigErrorReport {
+                config_path: path.to_string(),
+                env_name: path.env_name(),
+                arg_name: path.arg_name(),
+                error: format!("{report:#}"),
+            })
+            .collect();
+        Self {
+            agent: agent.to_owned(),
+            valid: false,
+            errors,
+            checks: vec![],
+        }
+    }
+
+    /// Report of a config which parsed, with the checks run on it
+    pub fn from_checks(agent: &str, checks: Vec<ConfigCheck>) -> Self {
+        let valid = checks.iter().all(|c| c.status != CheckStatus::Failed);
+        Self {
+            agent: agent.to_owned(),
+            valid,
+            errors: vec![],
+            checks,
+        }
+    }
+}
+
+/// Checks the connection and signer of every chain in the settings
+pub async fn check_chains(settings: &Settings) -> Vec<ConfigCheck> {
+    let mut checks = vec![];
+    for (name, chain) in settings
+        .chains
+        .iter()
+        .map(|(domain, chain)| (domain.name(), chain))
+        .sorted_by_key(|(name, _)| *name)
+    {
+        checks.push(ConfigCheck::ok(
+            format!("chains.{name}.connection"),
+            format!(
+                "{} chain with domain id {}",
+                chain.connection.protocol(),
+                chain.domain.id()
+            ),
+        ));
+
+        let Some(signer) = &chain.signer else {
+            continue;
+        };
+        let subject = format!("chains.{name}.signer");
+        let check = match unbuilt_signer_check(&subject, signer) {
+            Some(check) => check,
+            None => match chain.chain_signer().await {
+                Ok(Some(chain_signer)) => ConfigCheck::ok(subject, chain_signer.address_string()),
+                Ok(None) => ConfigCheck::ok(subject, "No signer"),
+                Err(err) => ConfigCheck::failed(subject, format!("{err:#}")),
+            },
+        };
+        checks.push(check);
+    }
+    checks
+}
+
+/// Builds a signer of type `S`, e.g. a validator attestation signer
+pub async fn check_signer<S: BuildableWithSignerConf>(
+    subject: &str,
+    conf: &SignerConf,
+) -> ConfigCheck {
+    if let Some(check) = unbuilt_signer_check(subject, conf) {
+        return check;
+    }
+    match conf.build::<S>().await {
+        Ok(signer) => ConfigCheck::ok(subject, signer.address_string()),
+        Err(err) => ConfigCheck::failed(subject, format!("{err:#}")),
+    }
+}
+
+/// Checks where a checkpoint syncer writes to. Only local storage can be
+/// checked offline, remote storage clients are not built.
+pub fn check_checkpoint_syncer(
+    subject: impl Into<String>,
+    conf: &CheckpointSyncerConf,
+) -> ConfigCheck {
+    match conf {
+        CheckpointSyncerConf::LocalStorage { path } if path.is_file() => ConfigCheck::failed(
+            subject,
+            format!("Local storage path {} is a file", path.display()),
+        ),
+        CheckpointSyncerConf::LocalStorage { path } => {
+            ConfigCheck::ok(subject, format!("Local storage at {}", path.display()))
+        }
+        CheckpointSyncerConf::S3 {
+            bucket,
+            folder,
+            region,
+        } => ConfigCheck::skipped(
+            subject,
+            format!(
+                "S3 bucket s3://{bucket}/{} in {region} is not checked offline",
+                folder.as_deref().unwrap_or_default()
+            ),
+        ),
+        CheckpointSyncerConf::Gcs { bucket, folder, .. } => ConfigCheck::skipped(
+            subject,
+            format!(
+                "GCS bucket gs://{bucket}/{} is not checked offline",
+                folder.as_deref().unwrap_or_default()
+            ),
+        ),
+    }
+}
+
+/// Check of a signer which is not built, either because it needs network
+/// access or because there is nothing to build
+fn unbuilt_signer_check(subject: &str, conf: &SignerConf) -> Option<ConfigCheck> {
+    match conf {
+        SignerConf::Aws { .. } => Some(ConfigCheck::skipped(
+            subject,
+            "AWS KMS signers are built with network access",
+        )),
+        SignerConf::Node => Some(ConfigCheck::ok(
+            subject,
+            "Transactions are signed by the node",
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use hyperlane_core::H256;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_check_hex_key_signer() {
+        let key =
+            H256::from_str("0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80")
+                .unwrap();
+        let check =
+            check_signer::<hyperlane_ethereum::Signers>("validator", &SignerConf::HexKey { key })
+                .await;
+        assert_eq!(check.status, CheckStatus::Ok);
+        assert!(check
+            .detail
+            .to_lowercase()
+            .ends_with("f39fd6e51aad88f6f4ce6ab8827279cfffb92266"));
+    }
+
+    #[tokio::test]
+    async fn test_check_invalid_hex_key_signer() {
+        let conf = SignerConf::HexKey { key: H256::zero() };
+        let check = check_signer::<hyperlane_ethereum::Signers>("validator", &conf).await;
+        assert_eq!(check.status, CheckStatus::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_check_aws_signer_is_skipped() {
+        let conf = SignerConf::Aws {
+            id: "alias/validator".to_owned(),
+            region: "us-east-1".to_owned(),
+        };
+        let check = check_signer::<hyperlane_ethereum::Signers>("validator", &conf).await;
+        assert_eq!(check.status, CheckStatus::Skipped);
+    }
+
+    #[test]
+    fn test_report_validity() {
+        let report = ConfigCheckReport::from_checks(
+            "relayer",
+            vec![ConfigCheck::ok("a", ""), ConfigCheck::skipped("b", "")],
+        );
+        assert!(report.valid);
+
+        let report = ConfigCheckReport::from_checks(
+            "relayer",
+            vec![ConfigCheck::ok("a", ""), ConfigCheck::failed("b", "")],
+        );
+        assert!(!report.valid);
+    }
+}
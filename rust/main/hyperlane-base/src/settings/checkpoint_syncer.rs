@@ -140,7 +140,14 @@ impl CheckpointSyncerConf {
         Ok(syncer)
     }
 
-    // keep this private to force all initializations to perform the reorg check via `build_and_validate`
+    /// Turn conf into a Checkpoint Syncer without checking for a reorg flag.
+    /// Only meant for tools that read checkpoints, e.g. to audit a validator
+    /// that flagged a reorg. Validators must use `build_and_validate`.
+    pub async fn build_unvalidated(&self) -> Result<Box<dyn CheckpointSyncer>, Report> {
+        self.build(None).await
+    }
+
+    // keep this private to force all validator initializations to perform the reorg check via `build_and_validate`
     async fn build(
         &self,
         latest_index_gauge: Option<IntGauge>,
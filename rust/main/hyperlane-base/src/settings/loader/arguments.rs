@@ -4,6 +4,11 @@ use config::{ConfigError, Map, Source, Value, ValueKind};
 use hyperlane_core::unwrap_or_none_result;
 use itertools::Itertools;
 
+use crate::{agent::subcommand_of, CHECK_CONFIG_FLAG, PRINT_CONFIG_SCHEMA_FLAG};
+
+/// Flags which select what the agent does rather than configure it
+const AGENT_MODE_FLAGS: &[&str] = &[CHECK_CONFIG_FLAG, PRINT_CONFIG_SCHEMA_FLAG];
+
 /// A source for loading configuration from command line arguments.
 ///
 /// * `--key=value`
@@ -27,6 +32,10 @@ pub struct CommandLineArguments {
     /// test your own code using this source, without the need to change the
     /// actual system environment variables.
     source: Option<Vec<OsString>>,
+
+    /// Alternate arguments of the process, including the executable path and
+    /// subcommand, parsed like the actual ones.
+    process_args: Option<Vec<OsString>>,
 }
 
 #[allow(unused)]
@@ -49,6 +58,15 @@ impl CommandLineArguments {
         self.source = Some(source.into_iter().map(|s| s.as_ref().to_owned()).collect());
         self
     }
+
+    pub fn process_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.process_args = Some(args.into_iter().map(|s| s.as_ref().to_owned()).collect());
+        self
+    }
 }
 
 impl Source for CommandLineArguments {
@@ -64,6 +82,8 @@ impl Source for CommandLineArguments {
 
         let mut args = if let Some(source) = &self.source {
             ArgumentParser::from_vec(source.clone())
+        } else if let Some(process_args) = &self.process_args {
+            ArgumentParser::from_args(process_args.clone())
         } else {
             ArgumentParser::from_env()
         };
@@ -76,6 +96,9 @@ impl Source for CommandLineArguments {
             if self.ignore_empty && value.is_empty() {
                 continue;
             }
+            if AGENT_MODE_FLAGS.contains(&key.as_str()) {
+                continue;
+            }
 
             let key = key.split(separator).join(".");
 
@@ -109,12 +132,21 @@ impl ArgumentParser {
 
     /// Creates a parser from [`env::args_os`].
     ///
-    /// The executable path will be removed.
-    ///
     /// [`env::args_os`]: https://doc.rust-lang.org/stable/std/env/fn.args_os.html
     fn from_env() -> Self {
-        let mut args: Vec<_> = std::env::args_os().collect();
-        args.remove(0);
+        Self::from_args(std::env::args_os())
+    }
+
+    /// Creates a parser from the arguments of a process.
+    ///
+    /// The executable path will be removed, as well as a leading subcommand
+    /// such as `validator audit`. Only the known subcommands are removed, any
+    /// other leading argument is left for the parser to reject.
+    fn from_args(args: impl IntoIterator<Item = OsString>) -> Self {
+        let mut args: Vec<_> = args.into_iter().skip(1).collect();
+        if subcommand_of(args.first().map(OsString::as_os_str)).is_some() {
+            args.remove(0);
+        }
         ArgumentParser(args)
     }
 
@@ -266,7 +298,10 @@ enum PairKind {
 
 #[cfg(test)]
 mod test {
+    use convert_case::Case;
+
     use super::*;
+    use crate::settings::loader::case_adapter::CaseAdapter;
 
     macro_rules! assert_arg {
         ($config:expr, $key:literal, $value:literal) => {
@@ -332,4 +367,59 @@ mod test {
 
         assert!(config.is_empty());
     }
+
+    fn parse_process_args(args: &[&str]) -> Result<Map<String, Value>, ConfigError> {
+        CommandLineArguments::default()
+            .separator(".")
+            .process_args(args)
+            .collect()
+    }
+
+    #[test]
+    fn subcommand_is_not_parsed_as_config() {
+        for subcommand in ["audit", "check", "schema"] {
+            let mut config =
+                parse_process_args(&["validator", subcommand, "--audit.fromIndex", "3"]).unwrap();
+
+            assert_arg!(config, "audit.fromIndex", "3");
+            assert!(config.is_empty());
+        }
+    }
+
+    #[test]
+    fn validator_audit_arguments_are_loaded() {
+        // the command line source as it is added by `load_settings`
+        let loaded = config::Config::builder()
+            .add_source(CaseAdapter::new(
+                CommandLineArguments::default()
+                    .separator(".")
+                    .process_args(["validator", "audit", "--audit.fromIndex", "3"]),
+                Case::Flat,
+            ))
+            .build()
+            .unwrap()
+            .try_deserialize::<serde_json::Value>()
+            .unwrap();
+
+        assert_eq!(loaded, serde_json::json!({ "audit": { "fromindex": "3" } }));
+    }
+
+    #[test]
+    fn unknown_subcommand_is_rejected() {
+        assert!(parse_process_args(&["validator", "audits", "--key-a", "value-a"]).is_err());
+        assert!(parse_process_args(&["validator", "--key-a", "value-a", "audit"]).is_err());
+    }
+
+    #[test]
+    fn agent_mode_flags() {
+        let mut config = CommandLineArguments::default()
+            .separator(".")
+            .source(["--check-config", "--key-a", "value-a"])
+            .collect()
+            .unwrap();
+
+        assert_arg!(config, "key-a", "value-a");
+
+        assert!(config.is_empty());
+    }
 }
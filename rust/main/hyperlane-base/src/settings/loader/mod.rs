@@ -8,13 +8,18 @@ use eyre::{eyre, Context};
 use hyperlane_core::config::*;
 use serde::de::DeserializeOwned;
 
-use crate::settings::loader::{
-    arguments::CommandLineArguments, case_adapter::CaseAdapter, environment::Environment,
+use crate::settings::{
+    loader::{
+        arguments::CommandLineArguments, case_adapter::CaseAdapter, environment::Environment,
+        registry::RegistrySource,
+    },
+    secrets::SecretResolver,
 };
 
 mod arguments;
 mod case_adapter;
 mod environment;
+mod registry;
 
 /// Deserialize a settings object from the configs.
 pub fn load_settings<T, R>(agent_name: &str) -> ConfigResult<R>
@@ -61,6 +66,20 @@ where
         }
     }
 
+    // Load the chains of a local registry, on top of the default config files and
+    // below any user specified config
+    if let Ok(registry_path) = env::var("REGISTRY_PATH") {
+        let p = PathBuf::from(registry_path);
+        if !p.is_dir() {
+            return Err(eyre!(
+                "Provided registry path via REGISTRY_PATH is not a directory ({p:?})"
+            ))
+            .into_config_result(|| root_path.clone());
+        }
+        base_config_sources.push(format!("{p:?}"));
+        builder = builder.add_source(CaseAdapter::new(RegistrySource::new(p), Case::Flat));
+    }
+
     // Load a set of additional user specified config files
     let config_file_paths: Vec<String> = env::var("CONFIG_FILES")
         .map(|s| s.split(',').map(|s| s.to_owned()).collect())
@@ -119,7 +138,7 @@ where
         }
     };
 
-    let raw_config = Config::try_deserialize::<T>(config_deserializer)
+    let mut raw_config = Config::try_deserialize::<serde_json::Value>(config_deserializer)
         .or_else(|err| {
             let mut err = if let Some(source_err) = err.source() {
                 let source = format!("Config error source: {source_err}");
@@ -136,6 +155,17 @@ where
         })
         .into_config_result(|| root_path.clone())?;
 
+    // Resolve secret references before the config is parsed, so parsers only
+    // ever see plain values
+    let secret_resolver = SecretResolver::from_config(&mut raw_config, &root_path)?;
+    let mut secrets_err = ConfigParsingError::default();
+    secret_resolver.resolve(&mut raw_config, &root_path, &mut secrets_err);
+    let raw_config = secrets_err.into_result(raw_config)?;
+
+    let raw_config = serde_json::from_value::<T>(raw_config)
+        .context("Config deserialization error")
+        .into_config_result(|| root_path.clone())?;
+
     let res = raw_config.parse_config(&root_path, agent_name);
     if res.is_err() {
         eprintln!("Loaded config for debugging: {formatted_config}");
@@ -0,0 +1,203 @@
+//! Chain metadata from a local Hyperlane registry directory.
+//!
+//! A registry holds the metadata and the core contract addresses of each chain in
+//! `chains/<name>/metadata.yaml` and `chains/<name>/addresses.yaml`. Together they are the
+//! `chains.<name>` config of the agents, so both files are merged into it as is.
+
+use std::path::{Path, PathBuf};
+
+use config::{ConfigError, File, FileFormat, Map, Source, Value, ValueKind};
+
+const CHAINS_DIR: &str = "chains";
+const METADATA_FILE: &str = "metadata.yaml";
+const ADDRESSES_FILE: &str = "addresses.yaml";
+
+/// A config source of the chains in a local registry
+#[derive(Clone, Debug)]
+pub struct RegistrySource {
+    path: PathBuf,
+}
+
+impl RegistrySource {
+    /// Create a source of the chains in the registry at `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// The directories of all chains with metadata, sorted by name
+    fn chain_dirs(&self) -> Result<Vec<PathBuf>, ConfigError> {
+        let chains_dir = self.path.join(CHAINS_DIR);
+        let entries = chains_dir.read_dir().map_err(|err| {
+            ConfigError::Message(format!(
+                "Failed to read registry chains at {}: {err}",
+                chains_dir.display()
+            ))
+        })?;
+        let mut dirs = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.join(METADATA_FILE).is_file())
+            .collect::<Vec<_>>();
+        dirs.sort();
+        Ok(dirs)
+    }
+}
+
+impl Source for RegistrySource {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        let origin = self.path.display().to_string();
+        let mut chains = Map::new();
+        for dir in self.chain_dirs()? {
+            let Some(name) = dir.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            let mut chain = read_yaml(&dir.join(METADATA_FILE))?;
+            // Chains which are not deployed yet have no addresses
+            let addresses = dir.join(ADDRESSES_FILE);
+            if addresses.is_file() {
+                chain.extend(read_yaml(&addresses)?);
+            }
+            chains.insert(
+                name.to_owned(),
+                Value::new(Some(&origin), ValueKind::Table(chain)),
+            );
+        }
+
+        let mut root = Map::new();
+        root.insert(
+            CHAINS_DIR.to_owned(),
+            Value::new(Some(&origin), ValueKind::Table(chains)),
+        );
+        Ok(root)
+    }
+}
+
+fn read_yaml(path: &Path) -> Result<Map<String, Value>, ConfigError> {
+    File::from(path).format(FileFormat::Yaml).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, str::FromStr, time::Duration};
+
+    use config::Config;
+    use convert_case::Case;
+    use hyperlane_core::{config::*, ReorgPeriod, H160, H256};
+
+    use super::*;
+    use crate::settings::{loader::case_adapter::CaseAdapter, parser::RawAgentConf, Settings};
+
+    fn fixture_registry() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/registry")
+    }
+
+    fn load_settings(overrides: &str) -> Settings {
+        let raw = Config::builder()
+            .add_source(CaseAdapter::new(
+                RegistrySource::new(fixture_registry()),
+                Case::Flat,
+            ))
+            .add_source(CaseAdapter::new(
+                File::from_str(overrides, FileFormat::Json),
+                Case::Flat,
+            ))
+            .build()
+            .unwrap()
+            .try_deserialize::<RawAgentConf>()
+            .unwrap();
+        // `newchain` has no addresses yet, so it can't be parsed
+        let chains = HashSet::from(["ethereum", "arbitrum"]);
+        Settings::from_config_filtered(raw, &ConfigPath::default(), Some(&chains), "relayer")
+            .unwrap()
+    }
+
+    fn address(address: &str) -> H256 {
+        H160::from_str(address).unwrap().into()
+    }
+
+    #[test]
+    fn test_collect_registry_chains() {
+        let config = Config::builder()
+            .add_source(RegistrySource::new(fixture_registry()))
+            .build()
+            .unwrap()
+            .try_deserialize::<serde_json::Value>()
+            .unwrap();
+        let chains = config["chains"].as_object().unwrap();
+
+        assert_eq!(
+            chains.keys().collect::<Vec<_>>(),
+            vec!["arbitrum", "ethereum", "newchain"]
+        );
+        assert!(chains["newchain"].get("mailbox").is_none());
+    }
+
+    #[test]
+    fn test_registry_chain_settings() {
+        let settings = load_settings("{}");
+
+        let ethereum = settings
+            .chain_setup(&settings.lookup_domain("ethereum").unwrap())
+            .unwrap();
+        assert_eq!(ethereum.domain.id(), 1);
+        assert_eq!(ethereum.estimated_block_time, Duration::from_secs(13));
+        assert_eq!(ethereum.reorg_period, ReorgPeriod::from_blocks(15));
+        assert_eq!(
+            ethereum.addresses.mailbox,
+            address("0xc005dc82818d67AF737725bD4bf75435d1036E3F")
+        );
+        assert_eq!(
+            ethereum.addresses.merkle_tree_hook,
+            address("0x48e6c30B97748d1e2e03bf3e9FbE3890ca5f8CCA")
+        );
+
+        let arbitrum = settings
+            .chain_setup(&settings.lookup_domain("arbitrum").unwrap())
+            .unwrap();
+        assert_eq!(arbitrum.domain.id(), 42161);
+        assert_eq!(arbitrum.estimated_block_time, Duration::from_secs_f64(0.3));
+        assert_eq!(
+            arbitrum.addresses.validator_announce,
+            address("0x1df063280C4166AF9a725e3828b4dAC6c7113B08")
+        );
+    }
+
+    #[test]
+    fn test_overrides_layer_on_registry() {
+        let settings = load_settings(
+            r#"{
+                "chains": {
+                    "ethereum": {
+                        "blocks": { "reorgPeriod": 20 },
+                        "mailbox": "0x0000000000000000000000000000000000000001"
+                    }
+                }
+            }"#,
+        );
+
+        let ethereum = settings
+            .chain_setup(&settings.lookup_domain("ethereum").unwrap())
+            .unwrap();
+        assert_eq!(ethereum.reorg_period, ReorgPeriod::from_blocks(20));
+        assert_eq!(
+            ethereum.addresses.mailbox,
+            address("0x0000000000000000000000000000000000000001")
+        );
+        // Keys which are not overridden are kept from the registry
+        assert_eq!(ethereum.estimated_block_time, Duration::from_secs(13));
+        assert_eq!(
+            ethereum.addresses.interchain_gas_paymaster,
+            address("0x9e6B1022bE9BBF5aFd152483DAD9b88911bC8611")
+        );
+    }
+
+    #[test]
+    fn test_missing_chains_dir_is_error() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(RegistrySource::new(dir.path()).collect().is_err());
+    }
+}
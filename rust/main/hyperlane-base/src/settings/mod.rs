@@ -86,10 +86,17 @@ mod signers;
 /// Tracing subscriber management
 mod trace;
 
+/// Offline checks of the agent config
+pub mod check;
 mod checkpoint_syncer;
 pub mod parser;
+/// JSON Schema of the agent config
+pub mod schema;
+/// Secret references in the agent config
+pub mod secrets;
 
-/// Declare that an agent can be constructed from settings.
+/// Declare that an agent can be constructed from settings. The raw settings
+/// must implement [`schema::RawConfSchema`].
 ///
 /// E.g.
 /// ```ignore
@@ -102,6 +109,10 @@ macro_rules! impl_loadable_from_settings {
             fn load(agent: &str) -> hyperlane_core::config::ConfigResult<Self> {
                 hyperlane_base::settings::loader::load_settings::<$settingsparser, Self>(agent)
             }
+
+            fn config_schema(agent: &str) -> serde_json::Value {
+                hyperlane_base::settings::schema::agent_conf_schema::<$settingsparser>(agent)
+            }
         }
     };
 }
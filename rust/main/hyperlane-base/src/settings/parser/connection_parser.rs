@@ -1,14 +1,17 @@
-use std::{ops::Add, str::FromStr};
+use std::{ops::Add, str::FromStr, time::Duration};
 
 use eyre::eyre;
 use hyperlane_sealevel::{
     HeliusPriorityFeeLevel, HeliusPriorityFeeOracleConfig, PriorityFeeOracleConfig,
-    ProcessAltOverride,
+    ProcessAltOverride, RecentPrioritizationFeesOracleConfig,
 };
 use solana_sdk::pubkey::Pubkey;
 use url::Url;
 
-use h_eth::TransactionOverrides;
+use h_eth::{
+    PrivateRelayConf, PrivateRelayMethod, TransactionOverrides,
+    DEFAULT_PRIVATE_RELAY_FALLBACK_TIMEOUT, DEFAULT_PRIVATE_RELAY_TARGET_BLOCKS,
+};
 
 use hyperlane_core::config::{ConfigErrResultExt, OpSubmissionConfig};
 use hyperlane_core::{config::ConfigParsingError, HyperlaneDomainProtocol, NativeToken};
@@ -20,7 +23,7 @@ use crate::settings::ChainConnectionConf;
 
 use super::{
     parse_base_and_override_urls, parse_cosmos_gas_price, parse_json_array, parse_matching_list,
-    ValueParser,
+    parse_rpc_fallback, parse_rpc_rate_limits, ValueParser,
 };
 
 #[allow(clippy::question_mark)] // TODO: `rustc` 1.80.1 clippy issue
@@ -129,14 +132,74 @@ pub fn build_ethereum_connection_conf(
         .parse_bool()
         .unwrap_or(false);
 
+    let private_relay = parse_ethereum_private_relay(chain, err);
+
+    let rpc_rate_limits =
+        parse_rpc_rate_limits(chain, "rpcUrls", "rpcRateLimit", "http", rpcs, err);
+    let rpc_fallback = parse_rpc_fallback(chain, err);
+
     Some(ChainConnectionConf::Ethereum(h_eth::ConnectionConf {
         rpc_connection: rpc_connection_conf?,
         transaction_overrides,
         op_submission_config: operation_batch,
         consider_null_transaction_receipt,
+        private_relay,
+        rpc_rate_limits,
+        rpc_fallback,
     }))
 }
 
+fn parse_ethereum_private_relay(
+    chain: &ValueParser,
+    err: &mut ConfigParsingError,
+) -> Option<PrivateRelayConf> {
+    let value_parser = chain.chain(err).get_opt_key("privateRelay").end()?;
+
+    let url: Option<Url> = value_parser
+        .chain(err)
+        .get_key("url")
+        .parse_from_str("Invalid url")
+        .end();
+
+    let method = value_parser
+        .chain(err)
+        .get_opt_key("method")
+        .parse_string()
+        .end();
+    let method = match method {
+        None | Some("privateTransaction") => Some(PrivateRelayMethod::PrivateTransaction),
+        Some("bundle") => Some(PrivateRelayMethod::Bundle),
+        Some(method) => {
+            err.push(
+                (&value_parser.cwp).add("method"),
+                eyre!("Unknown private relay method `{method}`"),
+            );
+            None
+        }
+    };
+
+    let target_blocks = value_parser
+        .chain(err)
+        .get_opt_key("targetBlocks")
+        .parse_u64()
+        .unwrap_or(DEFAULT_PRIVATE_RELAY_TARGET_BLOCKS);
+
+    // measured in seconds
+    let fallback_timeout = value_parser
+        .chain(err)
+        .get_opt_key("fallbackTimeout")
+        .parse_u64()
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_PRIVATE_RELAY_FALLBACK_TIMEOUT);
+
+    Some(PrivateRelayConf {
+        url: url?,
+        method: method?,
+        target_blocks,
+        fallback_timeout,
+    })
+}
+
 pub fn build_cosmos_connection_conf(
     rpcs: &[Url],
     chain: &ValueParser,
@@ -292,10 +355,19 @@ fn build_sealevel_connection_conf(
     let mut local_err = ConfigParsingError::default();
 
     let native_token = parse_native_token(chain, err, 9);
-    let priority_fee_oracle = parse_sealevel_priority_fee_oracle_config(chain, &mut local_err);
+    let priority_fee_oracle =
+        parse_sealevel_priority_fee_oracle_config(urls, chain, &mut local_err);
     let transaction_submitter = parse_transaction_submitter_config(chain, &mut local_err);
     let mailbox_process_alt = parse_sealevel_mailbox_process_alt(chain, &mut local_err);
     let process_alt_overrides = parse_sealevel_process_alt_overrides(chain, &mut local_err);
+    let rpc_rate_limits = parse_rpc_rate_limits(
+        chain,
+        "rpcUrls",
+        "rpcRateLimit",
+        "http",
+        urls,
+        &mut local_err,
+    );
 
     if !local_err.is_ok() {
         err.merge(local_err);
@@ -312,6 +384,7 @@ fn build_sealevel_connection_conf(
         transaction_submitter,
         mailbox_process_alt,
         process_alt_overrides,
+        rpc_rate_limits,
     }))
 }
 
@@ -431,6 +504,7 @@ fn parse_native_token(
 }
 
 fn parse_sealevel_priority_fee_oracle_config(
+    urls: &[Url],
     chain: &ValueParser,
     err: &mut ConfigParsingError,
 ) -> Option<PriorityFeeOracleConfig> {
@@ -475,6 +549,10 @@ fn parse_sealevel_priority_fee_oracle_config(
                 let config = HeliusPriorityFeeOracleConfig { url, fee_level };
                 Some(PriorityFeeOracleConfig::Helius(config))
             }
+            "recentPrioritizationFees" => {
+                parse_recent_prioritization_fees_oracle_config(urls, &value_parser, err)
+                    .map(PriorityFeeOracleConfig::RecentPrioritizationFees)
+            }
             _ => {
                 err.push(
                     (&value_parser.cwp).add("type"),
@@ -491,6 +569,68 @@ fn parse_sealevel_priority_fee_oracle_config(
     priority_fee_oracle
 }
 
+fn parse_recent_prioritization_fees_oracle_config(
+    urls: &[Url],
+    value_parser: &ValueParser,
+    err: &mut ConfigParsingError,
+) -> Option<RecentPrioritizationFeesOracleConfig> {
+    // Defaults to the chain's first RPC, as the method is supported by any Solana RPC
+    let url = value_parser
+        .chain(err)
+        .get_opt_key("url")
+        .parse_from_str("Invalid url")
+        .end()
+        .or_else(|| urls.first().cloned());
+    let percentile = value_parser
+        .chain(err)
+        .get_opt_key("percentile")
+        .parse_u32()
+        .end()
+        .unwrap_or(u32::from(
+            RecentPrioritizationFeesOracleConfig::DEFAULT_PERCENTILE,
+        ));
+    let min_fee = value_parser
+        .chain(err)
+        .get_opt_key("minFee")
+        .parse_u64()
+        .end()
+        .unwrap_or(0);
+    let max_fee = value_parser
+        .chain(err)
+        .get_opt_key("maxFee")
+        .parse_u64()
+        .end();
+
+    let Some(url) = url else {
+        err.push(
+            (&value_parser.cwp).add("url"),
+            eyre!("Missing url for recent prioritization fees oracle"),
+        );
+        return None;
+    };
+    let Some(percentile) = u8::try_from(percentile).ok().filter(|p| *p <= 100) else {
+        err.push(
+            (&value_parser.cwp).add("percentile"),
+            eyre!("Percentile must be between 0 and 100"),
+        );
+        return None;
+    };
+    if max_fee.is_some_and(|max_fee| max_fee < min_fee) {
+        err.push(
+            (&value_parser.cwp).add("maxFee"),
+            eyre!("maxFee must not be lower than minFee"),
+        );
+        return None;
+    }
+
+    Some(RecentPrioritizationFeesOracleConfig {
+        url,
+        percentile,
+        min_fee,
+        max_fee,
+    })
+}
+
 fn parse_helius_priority_fee_level(
     value_parser: &ValueParser,
     err: &mut ConfigParsingError,
@@ -557,6 +697,28 @@ fn parse_transaction_submitter_config(
                     .unwrap_or_default();
                 Some(h_sealevel::config::TransactionSubmitterConfig::Jito { urls })
             }
+            "jitobundle" => {
+                let urls: Option<&str> = chain
+                    .chain(err)
+                    .get_opt_key("transactionSubmitter")
+                    .get_opt_key("urls")
+                    .parse_string()
+                    .end();
+                let urls = urls
+                    .map(|urls| {
+                        urls.split(',')
+                            .filter_map(|url| {
+                                Url::parse(url)
+                                    .map_err(|e| eyre!("Invalid block engine url `{url}`: {e}"))
+                                    .take_err(err, || {
+                                        (&chain.cwp).add("transaction_submitter.urls")
+                                    })
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                Some(h_sealevel::config::TransactionSubmitterConfig::JitoBundle { urls })
+            }
             _ => {
                 err.push(
                     (&chain.cwp).add("transaction_submitter.type"),
@@ -601,6 +763,21 @@ pub fn build_tron_connection_conf(
         .parse_f64()
         .end();
 
+    // measured in sun
+    let max_fee_limit = chain
+        .chain(err)
+        .get_opt_key("maxFeeLimit")
+        .parse_u64()
+        .end();
+
+    // measured in seconds
+    let max_energy_wait = chain
+        .chain(err)
+        .get_opt_key("maxEnergyWait")
+        .parse_u64()
+        .end()
+        .map(Duration::from_secs);
+
     if !local_err.is_ok() {
         err.merge(local_err);
         return None;
@@ -612,10 +789,37 @@ pub fn build_tron_connection_conf(
             wallet_urls,
             wallet_solidity_urls,
             fee_multiplier,
+            max_fee_limit,
+            max_energy_wait,
         ),
     ))
 }
 
+pub fn build_aptos_connection_conf(
+    rpcs: &[Url],
+    chain: &ValueParser,
+    err: &mut ConfigParsingError,
+    _operation_batch: OpSubmissionConfig,
+) -> Option<ChainConnectionConf> {
+    if rpcs.is_empty() {
+        err.push(
+            (&chain.cwp).add("rpc_urls"),
+            eyre!("Missing base rpc urls for Aptos chain"),
+        );
+        return None;
+    }
+
+    let gas_limit_multiplier = chain
+        .chain(err)
+        .get_opt_key("gasLimitMultiplier")
+        .parse_f64()
+        .end();
+
+    Some(ChainConnectionConf::Aptos(
+        hyperlane_aptos::ConnectionConf::new(rpcs.to_vec(), gas_limit_multiplier),
+    ))
+}
+
 pub fn build_radix_connection_conf(
     rpcs: &[Url],
     chain: &ValueParser,
@@ -813,6 +1017,9 @@ pub fn build_connection_conf(
         HyperlaneDomainProtocol::Tron => {
             build_tron_connection_conf(rpcs, chain, err, operation_batch)
         }
+        HyperlaneDomainProtocol::Aptos => {
+            build_aptos_connection_conf(rpcs, chain, err, operation_batch)
+        }
         #[cfg(feature = "aleo")]
         HyperlaneDomainProtocol::Aleo => {
             build_aleo_connection_conf(rpcs, chain, err, operation_batch)
@@ -827,8 +1034,62 @@ pub fn build_connection_conf(
 pub fn is_protocol_supported(protocol: HyperlaneDomainProtocol) -> bool {
     use HyperlaneDomainProtocol::*;
     match protocol {
-        Ethereum | Fuel | Sealevel | Cosmos | CosmosNative | Starknet | Radix | Tron => true,
+        Ethereum | Fuel | Sealevel | Cosmos | CosmosNative | Starknet | Radix | Tron | Aptos => {
+            true
+        }
         // Aleo is feature-gated - only supported when the "aleo" feature is enabled
         Aleo => cfg!(feature = "aleo"),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use hyperlane_core::config::ConfigPath;
+
+    use super::*;
+
+    fn parse_submitter(
+        submitter: serde_json::Value,
+    ) -> (
+        Option<h_sealevel::config::TransactionSubmitterConfig>,
+        ConfigParsingError,
+    ) {
+        let val = serde_json::json!({ "transactionsubmitter": submitter });
+        let chain = ValueParser::new(ConfigPath::default(), &val);
+        let mut err = ConfigParsingError::default();
+        let config = parse_transaction_submitter_config(&chain, &mut err);
+        (config, err)
+    }
+
+    #[test]
+    fn parses_jito_bundle_urls() {
+        let (config, err) = parse_submitter(serde_json::json!({
+            "type": "jitoBundle",
+            "urls": "https://a.block-engine.test,https://b.block-engine.test",
+        }));
+
+        assert!(err.is_ok());
+        let Some(h_sealevel::config::TransactionSubmitterConfig::JitoBundle { urls }) = config
+        else {
+            panic!("expected a jito bundle submitter");
+        };
+        assert_eq!(
+            urls,
+            vec![
+                Url::parse("https://a.block-engine.test").unwrap(),
+                Url::parse("https://b.block-engine.test").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_jito_bundle_url() {
+        let (_, err) = parse_submitter(serde_json::json!({
+            "type": "jitoBundle",
+            "urls": "https://a.block-engine.test,not a url",
+        }));
+
+        assert!(!err.is_ok());
+        assert!(err.to_string().contains("Invalid block engine url"));
+    }
+}
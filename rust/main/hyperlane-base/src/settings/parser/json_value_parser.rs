@@ -1,4 +1,4 @@
-use std::{fmt::Debug, ops::Add, str::FromStr};
+use std::{cell::RefCell, fmt::Debug, ops::Add, str::FromStr};
 
 use convert_case::{Case, Casing};
 use derive_new::new;
@@ -11,6 +11,31 @@ use serde_json::Value;
 #[allow(unused_imports)] // TODO: `rustc` 1.80.1 clippy issue
 pub use super::super::envs::*;
 
+thread_local! {
+    /// Keys looked up while [`record_parsed_keys`] runs on this thread
+    static PARSED_KEYS: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Run `f` and collect the JSON path of every key it looked up, whether the
+/// key was set or not. Used to check that the config schema describes every
+/// key the parsers read.
+pub fn record_parsed_keys<T>(f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    PARSED_KEYS.with(|keys| *keys.borrow_mut() = Some(vec![]));
+    let result = f();
+    let keys = PARSED_KEYS
+        .with(|keys| keys.borrow_mut().take())
+        .unwrap_or_default();
+    (result, keys)
+}
+
+fn record_parsed_key(cwp: &ConfigPath) {
+    PARSED_KEYS.with(|keys| {
+        if let Some(keys) = keys.borrow_mut().as_mut() {
+            keys.push(cwp.json_name());
+        }
+    });
+}
+
 /// A serde-json value config parsing utility.
 #[derive(Debug, Clone, new)]
 pub struct ValueParser<'v> {
@@ -36,6 +61,7 @@ impl<'v> ValueParser<'v> {
     /// Get a value at the given key allowing for it to not be set.
     pub fn get_opt_key(&self, key: &str) -> ConfigResult<Option<ValueParser<'v>>> {
         let cwp = (&self.cwp).add(key.to_case(Case::Snake));
+        record_parsed_key(&cwp);
         match self.val {
             Value::Object(obj) => Ok(obj.get(&key.to_case(Case::Flat)).map(|val| Self {
                 val,
@@ -18,7 +18,10 @@ use serde::Deserialize;
 use serde_json::Value;
 use url::Url;
 
-use hyperlane_core::matching_list::MatchingList;
+use hyperlane_core::{
+    matching_list::MatchingList,
+    rpc_clients::{FallbackProviderConf, RpcRateLimitConf, ScoringConfig},
+};
 
 use h_cosmos::RawCosmosAmount;
 use hyperlane_core::{
@@ -29,13 +32,13 @@ use hyperlane_core::{
 use crate::settings::{
     chains::IndexSettings,
     parser::connection_parser::{build_connection_conf, is_protocol_supported},
-    trace::TracingConfig,
+    trace::{otlp::OtlpConfig, TracingConfig},
     ChainConf, CoreContractAddresses, Settings, SignerConf,
 };
 
 pub use super::envs::*;
 
-pub use self::json_value_parser::ValueParser;
+pub use self::json_value_parser::{record_parsed_keys, ValueParser};
 
 mod connection_parser;
 mod json_value_parser;
@@ -98,6 +101,13 @@ impl FromRawConf<RawAgentConf, Option<&HashSet<&str>>> for Settings {
             .parse_value("Invalid log level")
             .unwrap_or_default();
 
+        let otlp = p
+            .chain(&mut err)
+            .get_opt_key("log")
+            .get_opt_key("otlp")
+            .and_then(parse_otlp)
+            .end();
+
         let raw_chains: Vec<(String, ValueParser)> = if let Some(filter) = filter {
             p.chain(&mut err)
                 .get_opt_key("chains")
@@ -151,7 +161,7 @@ impl FromRawConf<RawAgentConf, Option<&HashSet<&str>>> for Settings {
             domains,
             chains,
             metrics_port,
-            tracing: TracingConfig { fmt, level },
+            tracing: TracingConfig { fmt, level, otlp },
         })
     }
 }
@@ -170,6 +180,16 @@ fn parse_chain(
         .get_opt_key("signer")
         .and_then(parse_signer)
         .end();
+    let additional_signers = chain
+        .chain(&mut err)
+        .get_opt_key("additionalSigners")
+        .into_array_iter()
+        .map(|signers| {
+            signers
+                .filter_map(|signer| parse_signer(signer).take_config_err(&mut err))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
 
     // measured in seconds (with fractions)
     let estimated_block_time = chain
@@ -348,6 +368,7 @@ fn parse_chain(
     err.into_result(ChainConf {
         domain,
         signer,
+        additional_signers,
         submitter,
         estimated_block_time,
         reorg_period,
@@ -423,8 +444,56 @@ fn parse_domain(chain: ValueParser, name: &str) -> ConfigResult<HyperlaneDomain>
     err.into_result(domain)
 }
 
+/// Parse the export to an OpenTelemetry collector, configured in `log.otlp`
+fn parse_otlp(otlp: ValueParser) -> ConfigResult<OtlpConfig> {
+    let mut err = ConfigParsingError::default();
+
+    let endpoint = otlp
+        .chain(&mut err)
+        .get_key("endpoint")
+        .parse_from_str::<Url>("Invalid OTLP endpoint")
+        .end();
+    let service_name = otlp
+        .chain(&mut err)
+        .get_opt_key("serviceName")
+        .parse_string()
+        .map(str::to_owned)
+        .end();
+    let sample_ratio = otlp
+        .chain(&mut err)
+        .get_opt_key("sampleRatio")
+        .parse_f64()
+        .unwrap_or(1.0);
+    if !(0.0..=1.0).contains(&sample_ratio) {
+        err.push(
+            &otlp.cwp + "sample_ratio",
+            eyre!("OTLP sample ratio must be between 0 and 1"),
+        );
+    }
+    let export_metrics = otlp
+        .chain(&mut err)
+        .get_opt_key("exportMetrics")
+        .parse_bool()
+        .unwrap_or(true);
+    let metrics_interval = otlp
+        .chain(&mut err)
+        .get_opt_key("metricsInterval")
+        .parse_u64()
+        .map(Duration::from_secs)
+        .unwrap_or(OtlpConfig::DEFAULT_METRICS_INTERVAL);
+
+    cfg_unwrap_all!(&otlp.cwp, err: [endpoint]);
+    err.into_result(OtlpConfig {
+        endpoint,
+        service_name,
+        sample_ratio,
+        export_metrics,
+        metrics_interval,
+    })
+}
+
 /// Expects AgentSigner.
-fn parse_signer(signer: ValueParser) -> ConfigResult<SignerConf> {
+pub fn parse_signer(signer: ValueParser) -> ConfigResult<SignerConf> {
     let mut err = ConfigParsingError::default();
 
     let signer_type = signer
@@ -664,6 +733,169 @@ fn parse_urls(
         .unwrap_or_default()
 }
 
+/// Parse the self-imposed request budgets of `urls`. A url listed in
+/// `base_key` may have its own `rateLimit`, every other url gets the one of
+/// the chain in `default_key`, which also covers urls overridden by the custom
+/// urls.
+fn parse_rpc_rate_limits(
+    chain: &ValueParser,
+    base_key: &str,
+    default_key: &str,
+    protocol: &str,
+    urls: &[Url],
+    err: &mut ConfigParsingError,
+) -> HashMap<Url, RpcRateLimitConf> {
+    let mut rate_limits = chain
+        .chain(err)
+        .get_opt_key(base_key)
+        .into_array_iter()
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let url = entry
+                        .chain(err)
+                        .get_key(protocol)
+                        .parse_from_str::<Url>("Invalid url")
+                        .end()?;
+                    let rate_limit = entry
+                        .chain(err)
+                        .get_opt_key("rateLimit")
+                        .and_then(parse_rpc_rate_limit)
+                        .end()?;
+                    Some((url, rate_limit))
+                })
+                .collect::<HashMap<_, _>>()
+        })
+        .unwrap_or_default();
+
+    let default = chain
+        .chain(err)
+        .get_opt_key(default_key)
+        .and_then(parse_rpc_rate_limit)
+        .end();
+    if let Some(default) = default {
+        for url in urls {
+            rate_limits
+                .entry(url.clone())
+                .or_insert_with(|| default.clone());
+        }
+    }
+    rate_limits.retain(|url, _| urls.contains(url));
+    rate_limits
+}
+
+fn parse_rpc_rate_limit(rate_limit: ValueParser) -> ConfigResult<RpcRateLimitConf> {
+    let mut err = ConfigParsingError::default();
+
+    let credits_per_second = rate_limit
+        .chain(&mut err)
+        .get_opt_key("creditsPerSecond")
+        .parse_f64()
+        .end();
+    let burst = rate_limit
+        .chain(&mut err)
+        .get_opt_key("burst")
+        .parse_f64()
+        .end();
+    for (key, value) in [("credits_per_second", credits_per_second), ("burst", burst)] {
+        if value.is_some_and(|value| value <= 0.0) {
+            err.push(
+                &rate_limit.cwp + key,
+                eyre!("Expected a positive number of credits"),
+            );
+        }
+    }
+    let daily_credits = rate_limit
+        .chain(&mut err)
+        .get_opt_key("dailyCredits")
+        .parse_u64()
+        .end();
+    let default_cost = rate_limit
+        .chain(&mut err)
+        .get_opt_key("defaultCost")
+        .parse_u64()
+        .end();
+    let method_costs = rate_limit
+        .chain(&mut err)
+        .get_opt_key("methodCosts")
+        .into_obj_iter()
+        .map(|costs| {
+            costs
+                .filter_map(|(method, cost)| {
+                    let cost = cost.chain(&mut err).parse_u64().end()?;
+                    Some((method, cost))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    err.into_result(RpcRateLimitConf {
+        credits_per_second,
+        burst,
+        daily_credits,
+        default_cost,
+        method_costs,
+    })
+}
+
+/// Parse how the urls of a fallback connection are scored and hedged. Unset
+/// values keep their defaults.
+fn parse_rpc_fallback(chain: &ValueParser, err: &mut ConfigParsingError) -> FallbackProviderConf {
+    let Some(fallback) = chain.chain(err).get_opt_key("rpcFallback").end() else {
+        return FallbackProviderConf::default();
+    };
+    let millis = |key: &str, err: &mut ConfigParsingError| {
+        fallback
+            .chain(err)
+            .get_opt_key(key)
+            .parse_u64()
+            .map(Duration::from_millis)
+            .end()
+    };
+
+    let hedge_after = millis("hedgeAfterMs", err);
+    let default = ScoringConfig::default();
+    let window = fallback
+        .chain(err)
+        .get_opt_key("scoringWindow")
+        .parse_u64()
+        .end()
+        .map(|window| window as usize)
+        .unwrap_or(default.window);
+    if window == 0 {
+        err.push(
+            &fallback.cwp + "scoring_window",
+            eyre!("Expected a positive number of requests"),
+        );
+    }
+    let latency_percentile = fallback
+        .chain(err)
+        .get_opt_key("latencyPercentile")
+        .parse_f64()
+        .end()
+        .unwrap_or(default.latency_percentile);
+    if !(0.0..=1.0).contains(&latency_percentile) {
+        err.push(
+            &fallback.cwp + "latency_percentile",
+            eyre!("Expected a percentile between 0 and 1"),
+        );
+    }
+    let scoring = ScoringConfig {
+        window,
+        latency_percentile,
+        error_penalty: millis("errorPenaltyMs", err).unwrap_or(default.error_penalty),
+        block_lag_penalty: millis("blockLagPenaltyMs", err).unwrap_or(default.block_lag_penalty),
+        stalled_penalty: millis("stalledPenaltyMs", err).unwrap_or(default.stalled_penalty),
+        deprioritized_penalty: millis("deprioritizedPenaltyMs", err)
+            .unwrap_or(default.deprioritized_penalty),
+    };
+
+    FallbackProviderConf {
+        hedge_after,
+        scoring,
+    }
+}
+
 fn parse_custom_urls(
     chain: &ValueParser,
     key: &str,
@@ -725,4 +957,94 @@ mod test {
         let value_parser = ValueParser::new(Default::default(), &val);
         parse_matching_list(value_parser).unwrap();
     }
+
+    #[test]
+    fn parses_rpc_rate_limits() {
+        let val = serde_json::json!({
+            "rpcurls": [
+                {
+                    "http": "http://a.test",
+                    "ratelimit": {
+                        "creditspersecond": 25,
+                        "dailycredits": 1000,
+                        "methodcosts": { "ethgetlogs": 75 }
+                    }
+                },
+                { "http": "http://b.test" }
+            ],
+            "rpcratelimit": { "creditspersecond": 5 }
+        });
+        let chain = ValueParser::new(Default::default(), &val);
+        let urls: Vec<Url> = vec![
+            "http://a.test".parse().unwrap(),
+            "http://b.test".parse().unwrap(),
+            "http://custom.test".parse().unwrap(),
+        ];
+        let mut err = ConfigParsingError::default();
+        let rate_limits =
+            parse_rpc_rate_limits(&chain, "rpcUrls", "rpcRateLimit", "http", &urls, &mut err);
+        assert!(err.is_ok());
+
+        let a = &rate_limits[&urls[0]];
+        assert_eq!(a.credits_per_second, Some(25.0));
+        assert_eq!(a.daily_credits, Some(1000));
+        assert_eq!(a.cost("eth_getLogs"), 75);
+        assert_eq!(a.cost("eth_call"), 1);
+        // urls without their own limit get the one of the chain
+        for url in &urls[1..] {
+            assert_eq!(rate_limits[url].credits_per_second, Some(5.0));
+            assert_eq!(rate_limits[url].daily_credits, None);
+        }
+
+        let val = serde_json::json!({ "creditspersecond": 0 });
+        assert!(parse_rpc_rate_limit(ValueParser::new(Default::default(), &val)).is_err());
+    }
+
+    #[test]
+    fn parses_rpc_fallback() {
+        let val = serde_json::json!({
+            "rpcfallback": {
+                "hedgeafterms": 500,
+                "scoringwindow": 20,
+                "stalledpenaltyms": 1000
+            }
+        });
+        let mut err = ConfigParsingError::default();
+        let conf = parse_rpc_fallback(&ValueParser::new(Default::default(), &val), &mut err);
+        assert!(err.is_ok());
+        assert_eq!(conf.hedge_after, Some(Duration::from_millis(500)));
+        assert_eq!(conf.scoring.window, 20);
+        assert_eq!(conf.scoring.stalled_penalty, Duration::from_secs(1));
+        assert_eq!(
+            conf.scoring.error_penalty,
+            ScoringConfig::default().error_penalty
+        );
+
+        let val = serde_json::json!({});
+        let conf = parse_rpc_fallback(&ValueParser::new(Default::default(), &val), &mut err);
+        assert!(err.is_ok());
+        assert_eq!(conf, FallbackProviderConf::default());
+
+        let val = serde_json::json!({ "rpcfallback": { "latencypercentile": 1.5 } });
+        parse_rpc_fallback(&ValueParser::new(Default::default(), &val), &mut err);
+        assert!(!err.is_ok());
+    }
+
+    #[test]
+    fn parses_otlp_config() {
+        let val = serde_json::json!({
+            "endpoint": "http://localhost:4317",
+            "sampleratio": 0.25,
+            "exportmetrics": false
+        });
+        let otlp = parse_otlp(ValueParser::new(Default::default(), &val)).unwrap();
+        assert_eq!(otlp.endpoint.as_str(), "http://localhost:4317/");
+        assert_eq!(otlp.service_name, None);
+        assert_eq!(otlp.sample_ratio, 0.25);
+        assert!(!otlp.export_metrics);
+        assert_eq!(otlp.metrics_interval, OtlpConfig::DEFAULT_METRICS_INTERVAL);
+
+        let val = serde_json::json!({ "endpoint": "http://localhost:4317", "sampleratio": 2 });
+        assert!(parse_otlp(ValueParser::new(Default::default(), &val)).is_err());
+    }
 }
@@ -0,0 +1,918 @@
+//! JSON Schema of the agent config.
+//!
+//! The agent config is parsed from an untyped JSON value (see [`RawAgentConf`]), so its shape
+//! can't be derived from the parsers and is described here instead. The schema documents the
+//! camelCase keys used by the TypeScript SDK metadata, the loader itself matches keys
+//! case-insensitively. The tests check it against the configs shipped with the agents and
+//! against every key the parsers read, see [`schema_violations`] and [`describes_key`].
+//! ANY CHANGES HERE NEED TO BE REFLECTED IN THE TYPESCRIPT SDK.
+
+use serde_json::{json, Map, Value};
+use strum::IntoEnumIterator;
+
+use hyperlane_core::{HyperlaneDomainProtocol, SubmitterType};
+
+use crate::settings::{
+    parser::RawAgentConf,
+    secrets::{SECRET_ENV_KEY, SECRET_FIELD_KEY, SECRET_FILE_KEY, SECRET_STORE_KEY},
+};
+
+/// The JSON Schema dialect of the generated schemas
+pub const JSON_SCHEMA_DIALECT: &str = "http://json-schema.org/draft-07/schema#";
+
+/// Raw agent settings which can describe their shape as a JSON Schema
+pub trait RawConfSchema {
+    /// Schema of the top level keys specific to the agent, on top of the keys
+    /// shared by all agents
+    fn agent_properties() -> Map<String, Value>;
+
+    /// Top level keys the agent can't be configured without
+    fn agent_required() -> Vec<&'static str> {
+        vec![]
+    }
+}
+
+impl RawConfSchema for RawAgentConf {
+    fn agent_properties() -> Map<String, Value> {
+        Map::new()
+    }
+}
+
+/// JSON Schema of the full config of an agent
+pub fn agent_conf_schema<T: RawConfSchema>(agent_name: &str) -> Value {
+    let mut properties = base_properties();
+    properties.extend(T::agent_properties());
+
+    json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "title": format!("Hyperlane {agent_name} agent config"),
+        "type": "object",
+        "properties": properties,
+        "required": T::agent_required(),
+    })
+}
+
+/// Keys shared by the config of all agents
+fn base_properties() -> Map<String, Value> {
+    let mut properties = Map::new();
+    properties.insert(
+        "chains".to_owned(),
+        json!({
+            "description": "Chain metadata and core contract addresses, by chain name",
+            "type": "object",
+            "additionalProperties": chain_schema(),
+        }),
+    );
+    properties.insert(
+        "defaultSigner".to_owned(),
+        with_description(
+            signer_schema(),
+            "Signer used on every chain which doesn't configure its own",
+        ),
+    );
+    properties.insert(
+        "metricsPort".to_owned(),
+        with_description(port(), "Port of the prometheus metrics server"),
+    );
+    properties.insert(
+        "secretStore".to_owned(),
+        json!({
+            "description": "HTTP secret store resolving `$secretStore` references, e.g. HashiCorp Vault",
+            "type": "object",
+            "properties": {
+                "url": { "type": "string", "format": "uri" },
+                "token": secret_string(),
+                "authHeader": { "type": "string" },
+            },
+            "required": ["url", "token"],
+        }),
+    );
+    properties.insert(
+        "log".to_owned(),
+        json!({
+            "type": "object",
+            "properties": {
+                "format": { "enum": ["json", "compact", "full", "pretty"] },
+                "level": {
+                    "enum": ["off", "error", "warn", "info", "debug", "trace", "dependencyTrace"]
+                },
+                "otlp": {
+                    "description": "Export of spans and metrics to an OpenTelemetry collector",
+                    "type": "object",
+                    "properties": {
+                        "endpoint": { "type": "string", "format": "uri" },
+                        "serviceName": { "type": "string" },
+                        "sampleRatio": number(),
+                        "exportMetrics": boolean(),
+                        "metricsInterval": integer(),
+                    },
+                    "required": ["endpoint"],
+                },
+            },
+        }),
+    );
+    properties
+}
+
+/// Schema of a single chain in `chains`, with the keys shared by all protocols
+/// and the connection keys of each protocol. Keys the agents don't read, e.g.
+/// the other registry metadata, are allowed as is.
+fn chain_schema() -> Value {
+    let protocols = HyperlaneDomainProtocol::iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<_>>();
+    let submitters = SubmitterType::iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>();
+
+    let mut properties = object_properties(json!({
+        "name": { "type": "string" },
+        "domainId": integer(),
+        "chainId": { "type": ["integer", "string"] },
+        "protocol": { "enum": protocols },
+        "technicalStack": { "type": "string" },
+        "signer": signer_schema(),
+        "additionalSigners": { "type": "array", "items": signer_schema() },
+        "submitter": { "enum": submitters },
+        "rpcUrls": url_list("http"),
+        "rpcRateLimit": rpc_rate_limit_schema(),
+        "customRpcUrls": { "anyOf": [comma_separated_list(), secret_reference()] },
+        "rpcConsensusType": { "enum": ["single", "fallback", "quorum"] },
+    }));
+    properties.extend(object_properties(json!({
+        "blocks": {
+            "type": "object",
+            "properties": {
+                "estimateBlockTime": number(),
+                "reorgPeriod": { "type": ["integer", "string"] },
+                "confirmations": integer(),
+            },
+            "required": ["estimateBlockTime", "reorgPeriod"],
+        },
+        "index": {
+            "type": "object",
+            "properties": {
+                "from": integer(),
+                "chunk": integer(),
+                "mode": { "enum": ["block", "sequence"] },
+            },
+        },
+        "nativeToken": {
+            "type": "object",
+            "properties": {
+                "decimals": integer(),
+                "name": { "type": "string" },
+                "symbol": { "type": "string" },
+                "denom": { "type": "string" },
+            },
+        },
+        "ignoreReorgReports": boolean(),
+    })));
+    properties.extend(object_properties(json!({
+        "mailbox": address(),
+        "interchainGasPaymaster": address(),
+        "validatorAnnounce": address(),
+        "merkleTreeHook": address(),
+        "batchContractAddress": address(),
+        "maxBatchSize": integer(),
+        "bypassBatchSimulation": boolean(),
+        "maxSubmitQueueLength": integer(),
+    })));
+    properties.extend(ethereum_properties());
+    properties.extend(cosmos_properties());
+    properties.extend(sealevel_properties());
+    properties.extend(object_properties(json!({
+        // tron
+        "walletUrls": url_list("http"),
+        "customWalletUrls": comma_separated_list(),
+        "walletSolidityUrls": url_list("http"),
+        "customWalletSolidityUrls": comma_separated_list(),
+        "feeMultiplier": number(),
+        "maxFeeLimit": integer(),
+        "maxEnergyWait": integer(),
+        // aptos
+        "gasLimitMultiplier": number(),
+        // radix
+        "gatewayUrls": url_list("http"),
+        "customGatewayUrls": comma_separated_list(),
+        "networkName": { "type": "string" },
+        // aleo
+        "mailboxProgram": { "type": "string" },
+        "hookManagerProgram": { "type": "string" },
+        "ismManagerProgram": { "type": "string" },
+        "validatorAnnounceProgram": { "type": "string" },
+        "consensusHeights": { "type": "array", "items": integer() },
+        "priorityFeeMultiplier": number(),
+        "provingServiceUrls": url_list("http"),
+        "customProvingServiceUrls": comma_separated_list(),
+    })));
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": [
+            "name",
+            "protocol",
+            "mailbox",
+            "interchainGasPaymaster",
+            "validatorAnnounce",
+            "merkleTreeHook",
+        ],
+        "anyOf": [
+            { "required": ["domainId"] },
+            { "required": ["chainId"] },
+        ],
+    })
+}
+
+/// Connection keys of EVM chains
+fn ethereum_properties() -> Map<String, Value> {
+    object_properties(json!({
+        "transactionOverrides": {
+            "type": "object",
+            "properties": {
+                "gasPrice": integer(),
+                "gasLimit": integer(),
+                "maxFeePerGas": integer(),
+                "maxPriorityFeePerGas": integer(),
+                "minGasPrice": integer(),
+                "minFeePerGas": integer(),
+                "minPriorityFeePerGas": integer(),
+                "gasPriceMultiplierDenominator": integer(),
+                "gasPriceMultiplierNumerator": integer(),
+                "gasPriceCapMultiplier": integer(),
+                "gasPriceCap": integer(),
+                "gasLimitCap": integer(),
+            },
+        },
+        "considerNullTransactionReceipt": boolean(),
+        "privateRelay": {
+            "description": "Relay submitting transactions privately instead of to the public mempool",
+            "type": "object",
+            "properties": {
+                "url": { "type": "string", "format": "uri" },
+                "method": { "type": "string" },
+                "targetBlocks": integer(),
+                "fallbackTimeout": integer(),
+            },
+            "required": ["url"],
+        },
+        "rpcFallback": {
+            "description": "How the urls of a fallback connection are scored and hedged",
+            "type": "object",
+            "properties": {
+                "hedgeAfterMs": integer(),
+                "scoringWindow": { "type": ["integer", "string"], "minimum": 1 },
+                "latencyPercentile": {
+                    "type": ["number", "string"],
+                    "minimum": 0,
+                    "maximum": 1,
+                },
+                "errorPenaltyMs": integer(),
+                "blockLagPenaltyMs": integer(),
+                "stalledPenaltyMs": integer(),
+                "deprioritizedPenaltyMs": integer(),
+            },
+        },
+    }))
+}
+
+/// Connection keys of Cosmos and Cosmos native chains
+fn cosmos_properties() -> Map<String, Value> {
+    object_properties(json!({
+        "grpcUrls": url_list("http"),
+        "customGrpcUrls": comma_separated_list(),
+        "bech32Prefix": { "type": "string" },
+        "gasPrice": {
+            "type": "object",
+            "properties": {
+                "amount": number(),
+                "denom": { "type": "string" },
+            },
+            "required": ["amount", "denom"],
+        },
+        "contractAddressBytes": integer(),
+        "gasMultiplier": number(),
+        "compatMode": { "type": "string" },
+        "canonicalAsset": { "type": "string" },
+    }))
+}
+
+/// Connection keys of Sealevel chains
+fn sealevel_properties() -> Map<String, Value> {
+    object_properties(json!({
+        "mailboxProcessAlt": address(),
+        "processAltOverrides": {
+            "description": "Address lookup tables of the messages matching a list, as JSON or a JSON encoded string",
+            "type": ["array", "string"],
+            "items": {
+                "type": "object",
+                "properties": {
+                    "matchingList": matching_list_schema(),
+                    "addressLookupTable": address(),
+                },
+                "required": ["addressLookupTable"],
+            },
+        },
+        "priorityFeeOracle": {
+            "type": "object",
+            "properties": {
+                "type": { "enum": ["constant", "helius", "recentPrioritizationFees"] },
+                "fee": integer(),
+                "url": { "type": "string", "format": "uri" },
+                "feeLevel": { "type": "string" },
+                "percentile": integer(),
+                "minFee": integer(),
+                "maxFee": integer(),
+            },
+            "required": ["type"],
+        },
+        "transactionSubmitter": {
+            "type": "object",
+            "properties": {
+                "type": {
+                    "description": "One of `rpc`, `jito` or `jitoBundle`, case-insensitive",
+                    "type": "string",
+                },
+                "urls": comma_separated_list(),
+            },
+        },
+    }))
+}
+
+/// Schema of a signer, e.g. `defaultSigner` or a chain `signer`
+pub fn signer_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "type": { "enum": ["hexKey", "aws", "cosmosKey", "starkKey", "radixKey"] },
+            "key": secret_string(),
+            "id": { "type": "string" },
+            "region": { "type": "string" },
+            "prefix": { "type": "string" },
+            "accountAddressType": { "type": "string" },
+            "address": { "type": "string" },
+            "legacy": boolean(),
+            "suffix": { "type": "string" },
+        },
+    })
+}
+
+/// Schema of a matching list, either as JSON or as a JSON encoded string
+pub fn matching_list_schema() -> Value {
+    json!({
+        "type": ["array", "object", "string"],
+    })
+}
+
+/// Schema of a checkpoint syncer, see [`crate::settings::CheckpointSyncerConf`]
+pub fn checkpoint_syncer_schema() -> Value {
+    json!({
+        "type": "object",
+        "oneOf": [
+            {
+                "properties": {
+                    "type": { "const": "localStorage" },
+                    "path": { "type": "string" },
+                },
+                "required": ["type", "path"],
+            },
+            {
+                "properties": {
+                    "type": { "const": "s3" },
+                    "bucket": { "type": "string" },
+                    "region": { "type": "string" },
+                    "folder": { "type": "string" },
+                },
+                "required": ["type", "bucket", "region"],
+            },
+            {
+                "properties": {
+                    "type": { "const": "gcs" },
+                    "bucket": { "type": "string" },
+                    "folder": { "type": "string" },
+                    "service_account_key": { "type": "string" },
+                    "user_secrets": { "type": "string" },
+                },
+                "required": ["type", "bucket"],
+            },
+        ],
+    })
+}
+
+/// An integer, which may also be given as a string as env vars and command
+/// line arguments always are
+pub fn integer() -> Value {
+    json!({ "type": ["integer", "string"] })
+}
+
+/// A number, which may also be given as a string
+pub fn number() -> Value {
+    json!({ "type": ["number", "string"] })
+}
+
+/// A boolean, which may also be given as a string
+pub fn boolean() -> Value {
+    json!({ "type": ["boolean", "string"] })
+}
+
+/// A network port
+pub fn port() -> Value {
+    json!({
+        "type": ["integer", "string"],
+        "minimum": 0,
+        "maximum": 65535,
+    })
+}
+
+/// A contract or account address
+pub fn address() -> Value {
+    json!({ "type": "string" })
+}
+
+/// A list of values in a single comma separated string, e.g. chain names
+pub fn comma_separated_list() -> Value {
+    json!({ "type": "string" })
+}
+
+/// A list of urls, each one in an object under the `protocol` key
+fn url_list(protocol: &str) -> Value {
+    json!({
+        "type": "array",
+        "items": {
+            "type": "object",
+            "properties": {
+                protocol: { "type": "string", "format": "uri" },
+                "rateLimit": rpc_rate_limit_schema(),
+            },
+            "required": [protocol],
+        },
+    })
+}
+
+/// A self-imposed request budget of an RPC url, in credits
+fn rpc_rate_limit_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "creditsPerSecond": number(),
+            "burst": number(),
+            "dailyCredits": integer(),
+            "defaultCost": integer(),
+            "methodCosts": { "type": "object", "additionalProperties": integer() },
+        },
+    })
+}
+
+/// A reference to a secret resolved when the config is loaded, see
+/// [`crate::settings::secrets`]
+pub fn secret_reference() -> Value {
+    json!({
+        "oneOf": [
+            {
+                "type": "object",
+                "properties": { SECRET_FILE_KEY: { "type": "string" } },
+                "required": [SECRET_FILE_KEY],
+                "additionalProperties": false,
+            },
+            {
+                "type": "object",
+                "properties": { SECRET_ENV_KEY: { "type": "string" } },
+                "required": [SECRET_ENV_KEY],
+                "additionalProperties": false,
+            },
+            {
+                "type": "object",
+                "properties": {
+                    SECRET_STORE_KEY: { "type": "string" },
+                    SECRET_FIELD_KEY: { "type": "string" },
+                },
+                "required": [SECRET_STORE_KEY],
+                "additionalProperties": false,
+            },
+        ],
+    })
+}
+
+/// A string which may be given as a secret reference, e.g. a private key
+pub fn secret_string() -> Value {
+    json!({ "anyOf": [{ "type": "string" }, secret_reference()] })
+}
+
+/// The properties of a JSON object, empty if the value is not an object
+pub fn object_properties(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(properties) => properties,
+        _ => Map::new(),
+    }
+}
+
+/// Adds a description to a schema object
+pub fn with_description(mut schema: Value, description: &str) -> Value {
+    if let Some(obj) = schema.as_object_mut() {
+        obj.insert("description".to_owned(), description.into());
+    }
+    schema
+}
+
+/// Checks a value against a schema, returning a message for every violation.
+///
+/// Only the subset of JSON Schema used in this module is supported: `type`, `enum`,
+/// `const`, `minimum`, `maximum`, `properties`, `required`, `additionalProperties`,
+/// `items`, `anyOf` and `oneOf`.
+pub fn schema_violations(schema: &Value, value: &Value) -> Vec<String> {
+    let mut violations = vec![];
+    collect_violations(schema, value, "", &mut violations);
+    violations
+}
+
+fn collect_violations(schema: &Value, value: &Value, path: &str, violations: &mut Vec<String>) {
+    let schema = match schema {
+        Value::Object(schema) => schema,
+        Value::Bool(false) => {
+            violations.push(format!("`{path}` is not allowed"));
+            return;
+        }
+        _ => return,
+    };
+
+    if let Some(types) = schema.get("type") {
+        if !schema_types(types).any(|ty| has_type(value, ty)) {
+            violations.push(format!("`{path}` is not of type {types}"));
+        }
+    }
+    if let Some(Value::Array(allowed)) = schema.get("enum") {
+        if !allowed.contains(value) {
+            violations.push(format!(
+                "`{path}` is not one of {}",
+                Value::from(allowed.clone())
+            ));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            violations.push(format!("`{path}` is not {expected}"));
+        }
+    }
+    if let Some(n) = value.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+            if n < min {
+                violations.push(format!("`{path}` is lower than {min}"));
+            }
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+            if n > max {
+                violations.push(format!("`{path}` is greater than {max}"));
+            }
+        }
+    }
+
+    match value {
+        Value::Object(obj) => {
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for key in required.iter().filter_map(Value::as_str) {
+                    if !obj.contains_key(key) {
+                        violations.push(format!("`{}` is missing", join_path(path, key)));
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (key, value) in obj {
+                let key_path = join_path(path, key);
+                match properties.and_then(|p| p.get(key)) {
+                    Some(property) => collect_violations(property, value, &key_path, violations),
+                    None => {
+                        if let Some(additional) = schema.get("additionalProperties") {
+                            collect_violations(additional, value, &key_path, violations);
+                        }
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    let item_path = join_path(path, &i.to_string());
+                    collect_violations(item_schema, item, &item_path, violations);
+                }
+            }
+        }
+        _ => {}
+    }
+
+    let matching = |alternatives: &[Value]| {
+        alternatives
+            .iter()
+            .filter(|alternative| schema_violations(alternative, value).is_empty())
+            .count()
+    };
+    if let Some(Value::Array(alternatives)) = schema.get("anyOf") {
+        if matching(alternatives) == 0 {
+            violations.push(format!("`{path}` matches none of the `anyOf` schemas"));
+        }
+    }
+    if let Some(Value::Array(alternatives)) = schema.get("oneOf") {
+        let count = matching(alternatives);
+        if count != 1 {
+            violations.push(format!(
+                "`{path}` matches {count} of the `oneOf` schemas instead of one"
+            ));
+        }
+    }
+}
+
+fn schema_types(types: &Value) -> impl Iterator<Item = &str> {
+    let types = match types {
+        Value::String(ty) => vec![ty.as_str()],
+        Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+        _ => vec![],
+    };
+    types.into_iter()
+}
+
+fn has_type(value: &Value, ty: &str) -> bool {
+    match ty {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        _ => false,
+    }
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+/// Whether the schema describes the key at the given dot separated JSON path,
+/// e.g. `chains.ethereum.rpcUrls.0.http`. Keys are matched case-insensitively
+/// and ignoring underscores, like the config loader does. Values without any
+/// structure in the schema, e.g. a matching list, may contain any key.
+pub fn describes_key(schema: &Value, json_path: &str) -> bool {
+    let segments = json_path.split('.').collect::<Vec<_>>();
+    describes_segments(schema, &segments)
+}
+
+fn describes_segments(schema: &Value, segments: &[&str]) -> bool {
+    let Some((segment, rest)) = segments.split_first() else {
+        return true;
+    };
+    let Some(obj) = schema.as_object() else {
+        return schema == &Value::Bool(true);
+    };
+    if is_free_form(obj) {
+        return true;
+    }
+
+    let property = obj
+        .get("properties")
+        .and_then(Value::as_object)
+        .and_then(|properties| {
+            properties
+                .iter()
+                .find(|(key, _)| flat_key(key) == flat_key(segment))
+        });
+    if let Some((_, property)) = property {
+        if describes_segments(property, rest) {
+            return true;
+        }
+    }
+    if let Some(additional) = obj.get("additionalProperties").filter(|a| a.is_object()) {
+        if property.is_none() && describes_segments(additional, rest) {
+            return true;
+        }
+    }
+    if let Some(items) = obj.get("items") {
+        if segment.parse::<usize>().is_ok() && describes_segments(items, rest) {
+            return true;
+        }
+    }
+    ["anyOf", "oneOf"]
+        .iter()
+        .filter_map(|combinator| obj.get(*combinator).and_then(Value::as_array))
+        .flatten()
+        // alternatives which only add constraints, e.g. `required`, don't describe keys
+        .filter(|alternative| alternative.as_object().is_some_and(|a| !is_free_form(a)))
+        .any(|alternative| describes_segments(alternative, segments))
+}
+
+/// Whether a schema leaves the shape of an object or array value open
+fn is_free_form(schema: &Map<String, Value>) -> bool {
+    const STRUCTURE_KEYWORDS: [&str; 7] = [
+        "properties",
+        "additionalProperties",
+        "items",
+        "anyOf",
+        "oneOf",
+        "enum",
+        "const",
+    ];
+    if STRUCTURE_KEYWORDS.iter().any(|k| schema.contains_key(*k)) {
+        return false;
+    }
+    match schema.get("type") {
+        Some(types) => schema_types(types).any(|ty| ty == "object" || ty == "array"),
+        None => true,
+    }
+}
+
+fn flat_key(key: &str) -> String {
+    key.replace('_', "").to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader, path::PathBuf};
+
+    use convert_case::Case;
+    use hyperlane_core::config::{ConfigPath, FromRawConf};
+
+    use super::*;
+    use crate::settings::{
+        parser::{recase_json_value, record_parsed_keys},
+        Settings,
+    };
+
+    fn config_dir() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../config")
+    }
+
+    fn load_config(name: &str) -> Value {
+        let path = config_dir().join(name);
+        serde_json::from_reader(BufReader::new(File::open(path).unwrap())).unwrap()
+    }
+
+    /// Names of all the configs shipped with the agents
+    fn shipped_configs() -> Vec<String> {
+        let mut names = std::fs::read_dir(config_dir())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .filter(|name| name.ends_with(".json"))
+            .collect::<Vec<_>>();
+        names.sort();
+        assert!(!names.is_empty());
+        names
+    }
+
+    fn required_keys(schema: &Value) -> Vec<&str> {
+        schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_base_schema_has_shared_keys() {
+        let schema = agent_conf_schema::<RawAgentConf>("test");
+        let properties = schema["properties"].as_object().unwrap();
+        for key in [
+            "chains",
+            "defaultSigner",
+            "metricsPort",
+            "secretStore",
+            "log",
+        ] {
+            assert!(properties.contains_key(key), "missing `{key}`");
+        }
+        assert!(required_keys(&schema).is_empty());
+    }
+
+    #[test]
+    fn test_chain_schema_lists_all_protocols() {
+        let protocols = chain_schema()["properties"]["protocol"]["enum"].clone();
+        let protocols = protocols.as_array().unwrap();
+        assert_eq!(protocols.len(), HyperlaneDomainProtocol::iter().count());
+        assert!(protocols.contains(&json!("ethereum")));
+        assert!(protocols.contains(&json!("sealevel")));
+    }
+
+    /// The required chain keys must be present in every chain of the configs
+    /// shipped with the agents, otherwise the schema is stricter than the parser.
+    #[test]
+    fn test_shipped_configs_have_required_chain_keys() {
+        let schema = chain_schema();
+        let required = required_keys(&schema);
+        let protocols = schema["properties"]["protocol"]["enum"].clone();
+        let protocols = protocols.as_array().unwrap();
+
+        for config in ["mainnet_config.json", "testnet_config.json"] {
+            let config = load_config(config);
+            for (name, chain) in config["chains"].as_object().unwrap() {
+                for key in &required {
+                    assert!(
+                        chain.get(*key).is_some(),
+                        "chain `{name}` is missing `{key}`"
+                    );
+                }
+                assert!(
+                    protocols.contains(&chain["protocol"]),
+                    "chain `{name}` has an unknown protocol"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_shipped_configs_match_schema() {
+        let schema = agent_conf_schema::<RawAgentConf>("test");
+        for name in shipped_configs() {
+            let violations = schema_violations(&schema, &load_config(&name));
+            assert!(violations.is_empty(), "`{name}`: {violations:#?}");
+        }
+    }
+
+    /// Every key the base parser reads from the shipped configs must be
+    /// described by the schema, otherwise the schema is missing keys.
+    #[test]
+    fn test_schema_describes_parsed_keys() {
+        let schema = agent_conf_schema::<RawAgentConf>("test");
+        for name in shipped_configs() {
+            let config = recase_json_value(load_config(&name), Case::Flat);
+            let raw: RawAgentConf = serde_json::from_value(config).unwrap();
+            // only the keys matter, the configs don't have to be complete
+            let (_, keys) = record_parsed_keys(|| {
+                Settings::from_config_filtered(raw, &ConfigPath::default(), None, "relayer")
+            });
+            assert!(!keys.is_empty());
+            for key in keys {
+                assert!(
+                    describes_key(&schema, &key),
+                    "`{name}`: `{key}` is not in the schema"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_schema_violations() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "port": port(),
+                "mode": { "enum": ["a", "b"] },
+                "list": { "type": "array", "items": integer() },
+                "secret": secret_string(),
+            },
+            "required": ["port"],
+        });
+        let violations = |value: Value| schema_violations(&schema, &value);
+
+        assert!(violations(json!({ "port": 80, "mode": "a", "list": [1, "2"] })).is_empty());
+        assert!(violations(json!({ "port": "80", "secret": { "$secretEnv": "KEY" } })).is_empty());
+        assert_eq!(violations(json!({})), vec!["`port` is missing"]);
+        assert_eq!(
+            violations(json!({ "port": 70000 })),
+            vec!["`port` is greater than 65535"]
+        );
+        assert_eq!(violations(json!({ "port": 80, "mode": "c" })).len(), 1);
+        assert_eq!(violations(json!({ "port": 80, "list": [1.5] })).len(), 1);
+        assert_eq!(
+            violations(
+                json!({ "port": 80, "secret": { "$secretEnv": "KEY", "$secretFile": "key" } })
+            )
+            .len(),
+            1
+        );
+        assert_eq!(violations(json!([])).len(), 1);
+    }
+
+    #[test]
+    fn test_describes_key() {
+        let schema = agent_conf_schema::<RawAgentConf>("test");
+        for key in [
+            "metricsPort",
+            "log.otlp.endpoint",
+            "chains.ethereum.rpcUrls.0.http",
+            "chains.ethereum.rpcurls.0.rateLimit.methodCosts.eth_getLogs",
+            "chains.ethereum.transactionOverrides.maxFeePerGas",
+            "chains.solana.processAltOverrides.0.matchingList.0.originDomain",
+            "chains.cosmoshub.gasPrice.denom",
+            "chains.ethereum.signer.type",
+        ] {
+            assert!(describes_key(&schema, key), "`{key}` should be described");
+        }
+        for key in [
+            "metricPort",
+            "chains.ethereum.rpcUrls.http",
+            "chains.ethereum.transactionOverrides.gasPrices",
+            "chains.ethereum.mailbox.address",
+            "chains.ethereum.blocks.reorg",
+        ] {
+            assert!(
+                !describes_key(&schema, key),
+                "`{key}` should not be described"
+            );
+        }
+    }
+}
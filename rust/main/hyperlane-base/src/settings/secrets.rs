@@ -0,0 +1,563 @@
+//! Secret references in the agent config.
+//!
+//! Any value of the config can be replaced by a reference to a secret, which is resolved when
+//! the config is loaded:
+//!
+//! * `{"$secretFile": "/run/secrets/key"}` - the contents of a file, without trailing whitespace
+//! * `{"$secretEnv": "RELAYER_KEY"}` - the value of another env var
+//! * `{"$secretStore": "secret/data/relayer", "$secretField": "key"}` - a secret fetched from the
+//!   secret store configured in the top level `secretStore` key. `$secretField` is optional and
+//!   selects a single value of a secret holding several.
+//!
+//! The secret store is an HTTP server authenticated with a token, such as the KV engine of
+//! HashiCorp Vault:
+//!
+//! ```json
+//! {
+//!   "secretStore": {
+//!     "url": "https://vault.example.com/v1/",
+//!     "token": { "$secretFile": "/run/secrets/vault-token" },
+//!     "authHeader": "X-Vault-Token"
+//!   }
+//! }
+//! ```
+//!
+//! Resolved secrets are recorded so they can be [`redact`]ed from the agent logs. Secrets holding
+//! urls are also recorded in the normalized form the parsed urls are logged in.
+
+use std::{
+    borrow::Cow,
+    fmt::{Debug, Formatter},
+    path::Path,
+    sync::RwLock,
+    time::Duration,
+};
+
+use convert_case::{Case, Casing};
+use eyre::{eyre, Context, Result};
+use hyperlane_core::config::{ConfigErrResultExt, ConfigParsingError, ConfigPath, ConfigResult};
+use serde_json::Value;
+use url::Url;
+
+/// Key of a reference to a secret file
+pub const SECRET_FILE_KEY: &str = "$secretFile";
+/// Key of a reference to a secret env var
+pub const SECRET_ENV_KEY: &str = "$secretEnv";
+/// Key of a reference to a secret in the secret store
+pub const SECRET_STORE_KEY: &str = "$secretStore";
+/// Key selecting a single value of a secret in the secret store
+pub const SECRET_FIELD_KEY: &str = "$secretField";
+
+/// Top level config key of the secret store
+const SECRET_STORE_CONFIG_KEY: &str = "secretStore";
+
+/// Header carrying the token of the secret store, unless configured otherwise.
+/// The token is sent as a bearer token in this header.
+const DEFAULT_AUTH_HEADER: &str = "Authorization";
+
+/// Timeout of a request to the secret store
+const SECRET_STORE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Secrets shorter than this are not redacted, as they would match too much
+/// unrelated log output
+const MIN_REDACTED_SECRET_LEN: usize = 8;
+
+/// Text secrets are replaced with in the logs
+pub const REDACTED: &str = "<redacted>";
+
+static RESOLVED_SECRETS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// Replaces every secret resolved from a secret reference in `text`
+pub fn redact(text: &str) -> Cow<'_, str> {
+    let Ok(secrets) = RESOLVED_SECRETS.read() else {
+        return Cow::Borrowed(text);
+    };
+    let mut text = Cow::Borrowed(text);
+    for secret in secrets.iter() {
+        if text.contains(secret.as_str()) {
+            text = Cow::Owned(text.replace(secret.as_str(), REDACTED));
+        }
+    }
+    text
+}
+
+fn record_secret(secret: &str) {
+    let Ok(mut secrets) = RESOLVED_SECRETS.write() else {
+        return;
+    };
+    for form in secret_forms(secret) {
+        if form.len() >= MIN_REDACTED_SECRET_LEN && !secrets.contains(&form) {
+            secrets.push(form);
+        }
+    }
+    // Longer forms first, so a secret is never partially redacted by a shorter
+    // form it contains, e.g. a url without its trailing slash
+    secrets.sort_by_key(|s| std::cmp::Reverse(s.len()));
+}
+
+/// The forms a secret may be logged in. Secrets are often urls, or comma
+/// separated lists of urls, which are logged after being parsed into a [`Url`].
+/// Parsing normalizes them, e.g. lowercases the host and drops default ports.
+fn secret_forms(secret: &str) -> Vec<String> {
+    let mut forms = vec![secret.to_owned()];
+    for part in secret.split(',').map(str::trim) {
+        forms.push(part.to_owned());
+        if let Ok(url) = Url::parse(part) {
+            forms.push(url.as_str().trim_end_matches('/').to_owned());
+            forms.push(url.to_string());
+        }
+    }
+    forms.dedup();
+    forms
+}
+
+/// A store of the secrets referenced with `$secretStore`
+pub trait SecretStore: Send + Sync + Debug {
+    /// Fetch the secret at `path`, or only its `field` if the secret holds
+    /// several values
+    fn get_secret(&self, path: &str, field: Option<&str>) -> Result<String>;
+}
+
+/// A secret store served over HTTP and authenticated with a token, e.g. the
+/// KV engine of HashiCorp Vault. A secret is fetched with a `GET` of its path
+/// relative to the store url.
+pub struct HttpSecretStore {
+    url: Url,
+    token: String,
+    auth_header: String,
+}
+
+impl HttpSecretStore {
+    /// Create a new store. The token is sent in `auth_header`, as a bearer token
+    /// if that is the `Authorization` header.
+    pub fn new(url: Url, token: String, auth_header: Option<String>) -> Self {
+        Self {
+            url,
+            token,
+            auth_header: auth_header.unwrap_or_else(|| DEFAULT_AUTH_HEADER.to_owned()),
+        }
+    }
+
+    fn fetch(&self, url: Url) -> Result<String> {
+        let token = if self.auth_header.eq_ignore_ascii_case(DEFAULT_AUTH_HEADER) {
+            format!("Bearer {}", self.token)
+        } else {
+            self.token.clone()
+        };
+        let client = reqwest::blocking::Client::builder()
+            .timeout(SECRET_STORE_TIMEOUT)
+            .build()?;
+        let response = client
+            .get(url)
+            .header(self.auth_header.as_str(), token)
+            .send()?
+            .error_for_status()?;
+        Ok(response.text()?)
+    }
+}
+
+impl Debug for HttpSecretStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpSecretStore")
+            .field("url", &self.url.as_str())
+            .field("token", &REDACTED)
+            .field("auth_header", &self.auth_header)
+            .finish()
+    }
+}
+
+impl SecretStore for HttpSecretStore {
+    fn get_secret(&self, path: &str, field: Option<&str>) -> Result<String> {
+        let url = self
+            .url
+            .join(path.trim_start_matches('/'))
+            .context("Invalid secret path")?;
+
+        // The config is loaded from within the tokio runtime, which the blocking
+        // client must not run on
+        let body = std::thread::scope(|s| s.spawn(|| self.fetch(url)).join())
+            .map_err(|_| eyre!("Secret store request panicked"))?
+            .context("Failed to fetch secret from the secret store")?;
+
+        extract_secret(&body, field)
+    }
+}
+
+/// Extracts a secret from the body of a secret store response. With a field,
+/// the body is expected to be a JSON object holding it, either at the top
+/// level or in the `data` of the Vault KV v1 and v2 engines.
+fn extract_secret(body: &str, field: Option<&str>) -> Result<String> {
+    let Some(field) = field else {
+        return Ok(match serde_json::from_str::<Value>(body) {
+            Ok(Value::String(secret)) => secret,
+            _ => body.trim_end().to_owned(),
+        });
+    };
+
+    let body: Value = serde_json::from_str(body).context("Expected a JSON secret")?;
+    [
+        &body["data"]["data"][field],
+        &body["data"][field],
+        &body[field],
+    ]
+    .into_iter()
+    .find_map(|value| match value {
+        Value::String(secret) => Some(secret.clone()),
+        Value::Number(secret) => Some(secret.to_string()),
+        _ => None,
+    })
+    .ok_or_else(|| eyre!("Secret has no field `{field}`"))
+}
+
+/// Resolves the secret references of a config
+#[derive(Debug, Default)]
+pub struct SecretResolver {
+    store: Option<Box<dyn SecretStore>>,
+}
+
+impl SecretResolver {
+    /// Create a resolver using `store` for `$secretStore` references
+    pub fn new(store: Option<Box<dyn SecretStore>>) -> Self {
+        Self { store }
+    }
+
+    /// Create a resolver using the secret store configured in the `secretStore`
+    /// key of `config`, if any. That key may only hold file and env var
+    /// references itself.
+    pub fn from_config(config: &mut Value, cwp: &ConfigPath) -> ConfigResult<Self> {
+        let key = SECRET_STORE_CONFIG_KEY.to_case(Case::Flat);
+        let cwp = cwp.join(SECRET_STORE_CONFIG_KEY.to_case(Case::Snake));
+        let Some(store_conf) = config.get_mut(key.as_str()) else {
+            return Ok(Self::default());
+        };
+
+        let mut err = ConfigParsingError::default();
+        Self::default().resolve(store_conf, &cwp, &mut err);
+        err.into_result(())?;
+
+        let get_string = |key: &str| {
+            store_conf
+                .get(key.to_case(Case::Flat))
+                .or_else(|| store_conf.get(key))
+                .and_then(Value::as_str)
+                .map(str::to_owned)
+        };
+        let url = get_string("url")
+            .ok_or_else(|| eyre!("Expected `url` of the secret store"))
+            .and_then(|url| {
+                // Secret paths are relative to the url, which must end with a slash
+                let url = if url.ends_with('/') {
+                    url
+                } else {
+                    format!("{url}/")
+                };
+                Url::parse(&url).context("Invalid secret store url")
+            })
+            .into_config_result(|| cwp.join("url"))?;
+        let token = get_string("token")
+            .ok_or_else(|| eyre!("Expected `token` of the secret store"))
+            .into_config_result(|| cwp.join("token"))?;
+        let auth_header = get_string("authHeader");
+
+        Ok(Self::new(Some(Box::new(HttpSecretStore::new(
+            url,
+            token,
+            auth_header,
+        )))))
+    }
+
+    /// Replace every secret reference in `value` with the secret. Errors never
+    /// contain the value of a secret.
+    pub fn resolve(&self, value: &mut Value, cwp: &ConfigPath, err: &mut ConfigParsingError) {
+        match value {
+            Value::Object(obj) => match SecretReference::from_object(obj) {
+                Ok(Some(reference)) => match self.resolve_reference(&reference) {
+                    Ok(secret) => {
+                        record_secret(&secret);
+                        *value = Value::String(secret);
+                    }
+                    Err(e) => err.push(cwp.clone(), e),
+                },
+                Ok(None) => {
+                    for (key, value) in obj.iter_mut() {
+                        self.resolve(value, &cwp.join(key.to_case(Case::Snake)), err);
+                    }
+                }
+                Err(e) => err.push(cwp.clone(), e),
+            },
+            Value::Array(arr) => {
+                for (i, value) in arr.iter_mut().enumerate() {
+                    self.resolve(value, &cwp.join(i.to_string()), err);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn resolve_reference(&self, reference: &SecretReference) -> Result<String> {
+        match reference {
+            SecretReference::File(path) => read_secret_file(Path::new(path)),
+            SecretReference::Env(name) => {
+                std::env::var(name).with_context(|| format!("Secret env var `{name}` is not set"))
+            }
+            SecretReference::Store { path, field } => self
+                .store
+                .as_ref()
+                .ok_or_else(|| eyre!("No `secretStore` is configured"))?
+                .get_secret(path, field.as_deref())
+                .with_context(|| format!("Failed to resolve secret `{path}`")),
+        }
+    }
+}
+
+fn read_secret_file(path: &Path) -> Result<String> {
+    let secret = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read secret file {}", path.display()))?;
+    Ok(secret.trim_end().to_owned())
+}
+
+/// A reference to a secret, in place of a config value
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SecretReference {
+    File(String),
+    Env(String),
+    Store { path: String, field: Option<String> },
+}
+
+impl SecretReference {
+    /// Parses a config object into a reference, if it is one. Keys are
+    /// compared in flat case as the config loader re-cases them.
+    fn from_object(obj: &serde_json::Map<String, Value>) -> Result<Option<Self>> {
+        let is_key = |key: &str, reference_key: &str| {
+            key.to_case(Case::Flat) == reference_key.to_case(Case::Flat)
+        };
+        let get = |reference_key: &str| {
+            obj.iter()
+                .find(|(key, _)| is_key(key, reference_key))
+                .map(|(_, value)| {
+                    value
+                        .as_str()
+                        .map(str::to_owned)
+                        .ok_or_else(|| eyre!("Expected `{reference_key}` to be a string"))
+                })
+                .transpose()
+        };
+
+        let reference = if let Some(path) = get(SECRET_FILE_KEY)? {
+            Self::File(path)
+        } else if let Some(name) = get(SECRET_ENV_KEY)? {
+            Self::Env(name)
+        } else if let Some(path) = get(SECRET_STORE_KEY)? {
+            Self::Store {
+                path,
+                field: get(SECRET_FIELD_KEY)?,
+            }
+        } else {
+            return Ok(None);
+        };
+
+        let expected_keys = match reference {
+            Self::Store { .. } => 2,
+            _ => 1,
+        };
+        let has_other_keys = obj.keys().any(|key| {
+            ![
+                SECRET_FILE_KEY,
+                SECRET_ENV_KEY,
+                SECRET_STORE_KEY,
+                SECRET_FIELD_KEY,
+            ]
+            .iter()
+            .any(|reference_key| is_key(key, reference_key))
+        });
+        if has_other_keys || obj.len() > expected_keys {
+            return Err(eyre!("A secret reference can't have other keys"));
+        }
+        Ok(Some(reference))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::JoinHandle,
+    };
+
+    use serde_json::json;
+
+    use super::*;
+
+    const TOKEN: &str = "test-token";
+
+    /// A secret store answering a single request, with the secret if the
+    /// request has the right token
+    fn mock_secret_store(auth_header: &'static str, body: &'static str) -> (Url, JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}/v1/", listener.local_addr().unwrap())).unwrap();
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut authorized = false;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.trim().split_once(':') {
+                    authorized |= name.eq_ignore_ascii_case(auth_header)
+                        && value.trim().trim_start_matches("Bearer ") == TOKEN;
+                }
+            }
+            let found = request_line.starts_with("GET /v1/secret/data/relayer ");
+            let (status, body) = match (authorized, found) {
+                (true, true) => ("200 OK", body),
+                (false, _) => ("403 Forbidden", ""),
+                (true, false) => ("404 Not Found", ""),
+            };
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        });
+        (url, handle)
+    }
+
+    fn resolve(resolver: &SecretResolver, mut value: Value) -> ConfigResult<Value> {
+        let mut err = ConfigParsingError::default();
+        resolver.resolve(&mut value, &ConfigPath::default(), &mut err);
+        err.into_result(value)
+    }
+
+    #[test]
+    fn test_resolve_secret_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("key");
+        std::fs::write(&path, "0x1234567890abcdef\n").unwrap();
+
+        let config = json!({
+            "chains": {
+                "test1": {
+                    "signer": { "key": { "$secretFile": path.to_str().unwrap() } },
+                },
+            },
+        });
+        let resolved = resolve(&SecretResolver::default(), config).unwrap();
+
+        assert_eq!(
+            resolved["chains"]["test1"]["signer"]["key"],
+            "0x1234567890abcdef"
+        );
+        assert_eq!(redact("key=0x1234567890abcdef"), format!("key={REDACTED}"));
+    }
+
+    #[test]
+    fn test_redact_normalized_urls() {
+        record_secret(
+            "https://RPC.example.com:443/redact-key-1, https://b.example.com?key=redact-key-2",
+        );
+
+        for logged in [
+            "https://RPC.example.com:443/redact-key-1",
+            "https://rpc.example.com/redact-key-1",
+            "https://b.example.com/?key=redact-key-2",
+        ] {
+            let redacted = redact(logged);
+            assert!(
+                !redacted.contains("redact-key"),
+                "`{logged}` -> `{redacted}`"
+            );
+        }
+        assert_eq!(
+            redact("url=https://b.example.com/?key=redact-key-2 and more"),
+            format!("url={REDACTED} and more")
+        );
+    }
+
+    #[test]
+    fn test_resolve_secret_env() {
+        std::env::set_var("TEST_SECRET_ENV_RPC", "https://rpc.example.com/api-key");
+        // Keys are re-cased to flat case by the config loader
+        let config = json!({ "customrpcurls": { "$secretenv": "TEST_SECRET_ENV_RPC" } });
+        let resolved = resolve(&SecretResolver::default(), config).unwrap();
+        assert_eq!(resolved["customrpcurls"], "https://rpc.example.com/api-key");
+    }
+
+    #[test]
+    fn test_missing_secrets_are_errors() {
+        let config = json!({
+            "a": { "$secretFile": "/does/not/exist" },
+            "b": [{ "$secretEnv": "TEST_SECRET_ENV_NOT_SET" }],
+            "c": { "$secretStore": "secret/data/relayer" },
+            "d": { "$secretFile": "/run/secrets/key", "other": 1 },
+        });
+        let err = resolve(&SecretResolver::default(), config).unwrap_err();
+        let paths = err
+            .iter()
+            .map(|(path, _)| path.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec!["a", "b.0", "c", "d"]);
+    }
+
+    #[test]
+    fn test_resolve_secret_store_field() {
+        let (url, server) = mock_secret_store(
+            "X-Vault-Token",
+            r#"{"data":{"data":{"key":"0xstore-secret-key"}}}"#,
+        );
+        let mut config = json!({
+            "secretstore": {
+                "url": url.as_str(),
+                "token": TOKEN,
+                "authheader": "X-Vault-Token",
+            },
+            "defaultsigner": {
+                "key": { "$secretStore": "secret/data/relayer", "$secretField": "key" },
+            },
+        });
+
+        let resolver = SecretResolver::from_config(&mut config, &ConfigPath::default()).unwrap();
+        let resolved = resolve(&resolver, config).unwrap();
+        server.join().unwrap();
+
+        assert_eq!(resolved["defaultsigner"]["key"], "0xstore-secret-key");
+        assert!(!format!("{resolver:?}").contains(TOKEN));
+    }
+
+    #[test]
+    fn test_secret_store_rejects_wrong_token() {
+        let (url, server) = mock_secret_store("Authorization", "plain-text-secret");
+        let store = HttpSecretStore::new(url, "wrong-token".to_owned(), None);
+        assert!(store.get_secret("secret/data/relayer", None).is_err());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_secret_store_bearer_token() {
+        let (url, server) = mock_secret_store("Authorization", "plain-text-secret\n");
+        let store = HttpSecretStore::new(url, TOKEN.to_owned(), None);
+        let secret = store.get_secret("/secret/data/relayer", None).unwrap();
+        server.join().unwrap();
+        assert_eq!(secret, "plain-text-secret");
+    }
+
+    #[test]
+    fn test_extract_secret() {
+        assert_eq!(extract_secret("\"secret\"", None).unwrap(), "secret");
+        assert_eq!(
+            extract_secret(r#"{"data":{"key":"v1"}}"#, Some("key")).unwrap(),
+            "v1"
+        );
+        assert_eq!(
+            extract_secret(r#"{"key":"top"}"#, Some("key")).unwrap(),
+            "top"
+        );
+        assert!(extract_secret(r#"{"data":{}}"#, Some("key")).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use std::fmt::{Debug, Formatter};
 use std::time::Duration;
 
 use async_trait::async_trait;
@@ -13,6 +14,7 @@ use tracing::instrument;
 use hyperlane_core::{AccountAddressType, H256};
 
 use super::aws_credentials::AwsChainCredentialsProvider;
+use super::secrets::REDACTED;
 use crate::types::utils;
 
 const AWS_SIGNER_TIMEOUT: Duration = Duration::from_secs(30);
@@ -28,7 +30,7 @@ fn resolve_kms_region(region: &str) -> Region {
 }
 
 /// Signer types
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Clone)]
 pub enum SignerConf {
     /// A local hex key
     HexKey {
@@ -73,6 +75,44 @@ pub enum SignerConf {
     Node,
 }
 
+/// Private keys are redacted, as signer configs end up in the logs
+impl Debug for SignerConf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HexKey { .. } => f.debug_struct("HexKey").field("key", &REDACTED).finish(),
+            Self::Aws { id, region } => f
+                .debug_struct("Aws")
+                .field("id", id)
+                .field("region", region)
+                .finish(),
+            Self::CosmosKey {
+                prefix,
+                account_address_type,
+                ..
+            } => f
+                .debug_struct("CosmosKey")
+                .field("key", &REDACTED)
+                .field("prefix", prefix)
+                .field("account_address_type", account_address_type)
+                .finish(),
+            Self::RadixKey { suffix, .. } => f
+                .debug_struct("RadixKey")
+                .field("key", &REDACTED)
+                .field("suffix", suffix)
+                .finish(),
+            Self::StarkKey {
+                address, is_legacy, ..
+            } => f
+                .debug_struct("StarkKey")
+                .field("key", &REDACTED)
+                .field("address", address)
+                .field("is_legacy", is_legacy)
+                .finish(),
+            Self::Node => write!(f, "Node"),
+        }
+    }
+}
+
 impl SignerConf {
     /// Try to convert the ethereum signer to a local wallet
     #[instrument(err)]
@@ -307,6 +347,27 @@ impl ChainSigner for hyperlane_radix::RadixSigner {
     }
 }
 
+#[async_trait]
+impl BuildableWithSignerConf for hyperlane_aptos::AptosSigner {
+    async fn build(conf: &SignerConf) -> Result<Self, Report> {
+        if let SignerConf::HexKey { key } = conf {
+            Ok(hyperlane_aptos::AptosSigner::new(key.as_bytes())?)
+        } else {
+            bail!(format!("{conf:?} key is not supported by aptos"));
+        }
+    }
+}
+
+impl ChainSigner for hyperlane_aptos::AptosSigner {
+    fn address_string(&self) -> String {
+        self.address()
+    }
+
+    fn address_h256(&self) -> H256 {
+        self.address_h256()
+    }
+}
+
 #[cfg(feature = "aleo")]
 #[async_trait]
 impl BuildableWithSignerConf for hyperlane_aleo::AleoSigner {
@@ -474,4 +535,30 @@ mod tests {
             tron_signer.address_string()
         );
     }
+
+    #[test]
+    fn debug_redacts_private_keys() {
+        let key = H256::repeat_byte(0xab);
+        let confs = [
+            SignerConf::HexKey { key },
+            SignerConf::CosmosKey {
+                key,
+                prefix: "neutron".to_owned(),
+                account_address_type: AccountAddressType::Bitcoin,
+            },
+            SignerConf::RadixKey {
+                key,
+                suffix: "rdx".to_owned(),
+            },
+            SignerConf::StarkKey {
+                key,
+                address: H256::zero(),
+                is_legacy: false,
+            },
+        ];
+        for conf in confs {
+            let debug = format!("{conf:?}");
+            assert!(!debug.contains("abab"), "{debug}");
+        }
+    }
 }
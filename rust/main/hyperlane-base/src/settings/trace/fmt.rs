@@ -1,15 +1,18 @@
-use std::io::Stdout;
+use std::io::{self, Stdout, Write};
 
 use tracing::{span, Subscriber};
 use tracing_subscriber::{
     fmt::{
         self,
         format::{Compact, DefaultFields, Format, Full, Json, JsonFields, Pretty},
+        MakeWriter,
     },
     registry::LookupSpan,
     Layer,
 };
 
+use crate::settings::secrets::redact;
+
 /// Basic tracing configuration
 #[derive(Default, Debug, Clone, Copy, serde::Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -40,7 +43,7 @@ pub enum Style {
 /// this to be configurable and the code to be legible, we do a little
 /// unification here :)
 #[derive(Debug)]
-pub enum LogOutputLayer<S, N = DefaultFields, W = fn() -> Stdout> {
+pub enum LogOutputLayer<S, N = DefaultFields, W = RedactingStdout> {
     /// Full log output (default mode)
     Full(fmt::Layer<S, N, Format<Full>, W>),
     /// Pretty log output
@@ -53,18 +56,51 @@ pub enum LogOutputLayer<S, N = DefaultFields, W = fn() -> Stdout> {
 
 impl<S> Default for LogOutputLayer<S> {
     fn default() -> Self {
-        Self::Full(Default::default())
+        Style::Full.into()
     }
 }
 
 impl<S> From<Style> for LogOutputLayer<S> {
     fn from(style: Style) -> Self {
+        let layer = fmt::layer().with_writer(RedactingStdout);
         match style {
-            Style::Full => Self::Full(fmt::layer()),
-            Style::Pretty => Self::Pretty(fmt::layer().pretty()),
-            Style::Compact => Self::Compact(fmt::layer().compact()),
-            Style::Json => Self::Json(fmt::layer().json()),
+            Style::Full => Self::Full(layer),
+            Style::Pretty => Self::Pretty(layer.pretty()),
+            Style::Compact => Self::Compact(layer.compact()),
+            Style::Json => Self::Json(layer.json()),
+        }
+    }
+}
+
+/// Writes log output to stdout, with the secrets resolved from the config
+/// redacted
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RedactingStdout;
+
+impl<'a> MakeWriter<'a> for RedactingStdout {
+    type Writer = RedactingWriter<Stdout>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter(io::stdout())
+    }
+}
+
+/// Writer redacting resolved secrets, see [`RedactingStdout`]
+#[derive(Debug)]
+pub struct RedactingWriter<W>(W);
+
+impl<W: Write> Write for RedactingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Each log line is written at once, so secrets are never split between writes
+        match std::str::from_utf8(buf) {
+            Ok(text) => self.0.write_all(redact(text).as_bytes())?,
+            Err(_) => self.0.write_all(buf)?,
         }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
     }
 }
 
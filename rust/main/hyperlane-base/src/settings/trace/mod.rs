@@ -5,11 +5,13 @@ use tracing_subscriber::{
     prelude::*,
 };
 
-use self::fmt::LogOutputLayer;
+use self::{fmt::LogOutputLayer, otlp::OtlpConfig};
 use crate::{settings::trace::fmt::Style, CoreMetrics};
 
 /// Configure a `tracing_subscriber::fmt` Layer outputting to stdout
 pub mod fmt;
+/// Export of spans and metrics to an OpenTelemetry collector
+pub mod otlp;
 
 mod span_metrics;
 
@@ -55,6 +57,8 @@ pub struct TracingConfig {
     pub(crate) fmt: Style,
     #[serde(default)]
     pub(crate) level: Level,
+    #[serde(skip)]
+    pub(crate) otlp: Option<OtlpConfig>,
 }
 
 impl TracingConfig {
@@ -93,6 +97,12 @@ impl TracingConfig {
         }
         let fmt_layer: LogOutputLayer<_> = self.fmt.into();
         let err_layer = tracing_error::ErrorLayer::default();
+        let otlp_provider = self
+            .otlp
+            .as_ref()
+            .map(|otlp| otlp.tracer_provider(metrics))
+            .transpose()?;
+        let otlp_layer = otlp_provider.as_ref().map(otlp::tracing_layer);
 
         let (tokio_layer, tokio_server) = console_subscriber::ConsoleLayer::new();
         let subscriber = tracing_subscriber::Registry::default()
@@ -100,9 +110,19 @@ impl TracingConfig {
             .with(target_layer)
             .with(TimeSpanLifetime::new(metrics))
             .with(fmt_layer)
-            .with(err_layer);
+            .with(err_layer)
+            .with(otlp_layer);
 
         subscriber.try_init()?;
+        if let (Some(otlp), Some(provider)) = (&self.otlp, otlp_provider) {
+            otlp.start_export(provider, metrics)?;
+        }
         Ok(tokio_server)
     }
 }
+
+/// Flush the spans which were not exported to the OpenTelemetry collector yet
+pub async fn shutdown_tracing() {
+    // Flushing blocks until the spans are exported
+    let _ = tokio::task::spawn_blocking(opentelemetry::global::shutdown_tracer_provider).await;
+}
@@ -0,0 +1,268 @@
+//! Export of the prometheus metrics of the agent over OTLP.
+//!
+//! The metrics are gathered from the prometheus registry and converted as is: counters become
+//! monotonic cumulative sums, gauges stay gauges and histograms become cumulative histograms
+//! with the same buckets. Summaries are not used by the agents and are skipped.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use eyre::Result;
+use opentelemetry_proto::tonic::{
+    collector::metrics::v1::{
+        metrics_service_client::MetricsServiceClient, ExportMetricsServiceRequest,
+    },
+    common::v1::{any_value, AnyValue, InstrumentationScope, KeyValue},
+    metrics::v1::{
+        metric::Data, number_data_point, AggregationTemporality, Gauge, Histogram,
+        HistogramDataPoint, Metric, NumberDataPoint, ResourceMetrics, ScopeMetrics, Sum,
+    },
+    resource::v1::Resource,
+};
+use prometheus::{
+    proto::{Metric as PromMetric, MetricFamily, MetricType},
+    Registry,
+};
+use tokio::task::JoinHandle;
+use tonic::transport::Endpoint;
+use tracing::warn;
+use url::Url;
+
+use super::{EXPORT_TIMEOUT, TRACER_NAME};
+
+/// Periodically export the metrics of `registry` to the collector at
+/// `endpoint`
+pub(crate) fn spawn_metrics_export(
+    endpoint: &Url,
+    interval: Duration,
+    service_name: &str,
+    registry: Registry,
+) -> Result<JoinHandle<()>> {
+    let channel = Endpoint::from_shared(endpoint.to_string())?
+        .timeout(EXPORT_TIMEOUT)
+        .connect_lazy();
+    let mut client = MetricsServiceClient::new(channel);
+    let service_name = service_name.to_owned();
+    let start_time = SystemTime::now();
+
+    Ok(tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            let request = export_request(
+                &registry.gather(),
+                &service_name,
+                start_time,
+                SystemTime::now(),
+            );
+            if let Err(err) = client.export(request).await {
+                warn!(
+                    ?err,
+                    "Failed to export metrics to the OpenTelemetry collector"
+                );
+            }
+        }
+    }))
+}
+
+/// Convert prometheus metrics into an OTLP export request. Cumulative
+/// metrics are reported as accumulated since `start_time`.
+pub fn export_request(
+    families: &[MetricFamily],
+    service_name: &str,
+    start_time: SystemTime,
+    time: SystemTime,
+) -> ExportMetricsServiceRequest {
+    let start_time = unix_nanos(start_time);
+    let time = unix_nanos(time);
+    let metrics = families
+        .iter()
+        .filter_map(|family| convert_family(family, start_time, time))
+        .collect();
+
+    ExportMetricsServiceRequest {
+        resource_metrics: vec![ResourceMetrics {
+            resource: Some(Resource {
+                attributes: vec![string_attribute("service.name", service_name)],
+                ..Default::default()
+            }),
+            scope_metrics: vec![ScopeMetrics {
+                scope: Some(InstrumentationScope {
+                    name: TRACER_NAME.to_owned(),
+                    ..Default::default()
+                }),
+                metrics,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+    }
+}
+
+fn convert_family(family: &MetricFamily, start_time: u64, time: u64) -> Option<Metric> {
+    let metrics = family.get_metric();
+    let number_points = |value: fn(&PromMetric) -> f64| {
+        metrics
+            .iter()
+            .map(|metric| NumberDataPoint {
+                attributes: attributes(metric),
+                start_time_unix_nano: start_time,
+                time_unix_nano: time,
+                value: Some(number_data_point::Value::AsDouble(value(metric))),
+                ..Default::default()
+            })
+            .collect()
+    };
+
+    let data = match family.get_field_type() {
+        MetricType::COUNTER => Data::Sum(Sum {
+            data_points: number_points(|metric| metric.get_counter().get_value()),
+            aggregation_temporality: AggregationTemporality::Cumulative as i32,
+            is_monotonic: true,
+        }),
+        MetricType::GAUGE => Data::Gauge(Gauge {
+            data_points: number_points(|metric| metric.get_gauge().get_value()),
+        }),
+        MetricType::HISTOGRAM => Data::Histogram(Histogram {
+            data_points: metrics
+                .iter()
+                .map(|metric| histogram_point(metric, start_time, time))
+                .collect(),
+            aggregation_temporality: AggregationTemporality::Cumulative as i32,
+        }),
+        MetricType::SUMMARY | MetricType::UNTYPED => return None,
+    };
+
+    Some(Metric {
+        name: family.get_name().to_owned(),
+        description: family.get_help().to_owned(),
+        data: Some(data),
+        ..Default::default()
+    })
+}
+
+/// Prometheus buckets count all samples up to their bound, OTLP buckets only
+/// the samples since the previous bound
+fn histogram_point(metric: &PromMetric, start_time: u64, time: u64) -> HistogramDataPoint {
+    let histogram = metric.get_histogram();
+    let mut explicit_bounds = vec![];
+    let mut bucket_counts = vec![];
+    let mut below = 0u64;
+    for bucket in histogram.get_bucket() {
+        // The +Inf bucket is the implicit last bucket of OTLP
+        if bucket.get_upper_bound().is_infinite() {
+            continue;
+        }
+        explicit_bounds.push(bucket.get_upper_bound());
+        bucket_counts.push(bucket.get_cumulative_count().saturating_sub(below));
+        below = bucket.get_cumulative_count();
+    }
+    bucket_counts.push(histogram.get_sample_count().saturating_sub(below));
+
+    HistogramDataPoint {
+        attributes: attributes(metric),
+        start_time_unix_nano: start_time,
+        time_unix_nano: time,
+        count: histogram.get_sample_count(),
+        sum: Some(histogram.get_sample_sum()),
+        bucket_counts,
+        explicit_bounds,
+        ..Default::default()
+    }
+}
+
+fn attributes(metric: &PromMetric) -> Vec<KeyValue> {
+    metric
+        .get_label()
+        .iter()
+        .map(|label| string_attribute(label.get_name(), label.get_value()))
+        .collect()
+}
+
+fn string_attribute(key: &str, value: &str) -> KeyValue {
+    KeyValue {
+        key: key.to_owned(),
+        value: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(value.to_owned())),
+        }),
+    }
+}
+
+fn unix_nanos(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|since_epoch| u64::try_from(since_epoch.as_nanos()).unwrap_or(u64::MAX))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use prometheus::{histogram_opts, opts, GaugeVec, HistogramVec, IntCounterVec};
+
+    use super::*;
+
+    fn string_value(kv: &KeyValue) -> &str {
+        match kv.value.as_ref().and_then(|v| v.value.as_ref()) {
+            Some(any_value::Value::StringValue(value)) => value,
+            _ => panic!("not a string attribute"),
+        }
+    }
+
+    #[test]
+    fn test_export_request_converts_metrics() {
+        let registry = Registry::new();
+        let counter = IntCounterVec::new(opts!("messages", "Messages"), &["remote"]).unwrap();
+        let gauge = GaugeVec::new(opts!("balance", "Balance"), &["chain"]).unwrap();
+        let histogram = HistogramVec::new(
+            histogram_opts!("latency", "Latency", vec![1.0, 5.0]),
+            &["stage"],
+        )
+        .unwrap();
+        registry.register(Box::new(counter.clone())).unwrap();
+        registry.register(Box::new(gauge.clone())).unwrap();
+        registry.register(Box::new(histogram.clone())).unwrap();
+
+        counter.with_label_values(&["ethereum"]).inc_by(3);
+        gauge.with_label_values(&["arbitrum"]).set(1.5);
+        for value in [0.5, 2.0, 10.0] {
+            histogram.with_label_values(&["prepare"]).observe(value);
+        }
+
+        let start = UNIX_EPOCH + Duration::from_secs(10);
+        let now = UNIX_EPOCH + Duration::from_secs(20);
+        let request = export_request(&registry.gather(), "relayer", start, now);
+
+        let resource_metrics = &request.resource_metrics[0];
+        let resource = resource_metrics.resource.as_ref().unwrap();
+        assert_eq!(string_value(&resource.attributes[0]), "relayer");
+
+        let metrics = &resource_metrics.scope_metrics[0].metrics;
+        let metric = |name: &str| metrics.iter().find(|m| m.name == name).unwrap();
+
+        let Some(Data::Sum(sum)) = &metric("messages").data else {
+            panic!("counter is not a sum");
+        };
+        assert!(sum.is_monotonic);
+        let point = &sum.data_points[0];
+        assert_eq!(point.value, Some(number_data_point::Value::AsDouble(3.0)));
+        assert_eq!(point.attributes[0].key, "remote");
+        assert_eq!(string_value(&point.attributes[0]), "ethereum");
+        assert_eq!(point.start_time_unix_nano, 10_000_000_000);
+        assert_eq!(point.time_unix_nano, 20_000_000_000);
+
+        let Some(Data::Gauge(gauge)) = &metric("balance").data else {
+            panic!("gauge is not a gauge");
+        };
+        assert_eq!(
+            gauge.data_points[0].value,
+            Some(number_data_point::Value::AsDouble(1.5))
+        );
+
+        let Some(Data::Histogram(histogram)) = &metric("latency").data else {
+            panic!("histogram is not a histogram");
+        };
+        let point = &histogram.data_points[0];
+        assert_eq!(point.count, 3);
+        assert_eq!(point.sum, Some(12.5));
+        assert_eq!(point.explicit_bounds, vec![1.0, 5.0]);
+        assert_eq!(point.bucket_counts, vec![1, 1, 1]);
+    }
+}
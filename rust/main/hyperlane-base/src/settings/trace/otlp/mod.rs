@@ -0,0 +1,169 @@
+//! Export of spans and metrics to an OpenTelemetry collector over OTLP/gRPC.
+//!
+//! Spans are exported through `tracing-opentelemetry`, with the span fields as attributes. The
+//! prometheus metrics of the agent are exported periodically next to the prometheus endpoint,
+//! see [`metrics`].
+//!
+//! The stages an operation goes through, e.g. a message being prepared by the relayer and its
+//! payload being submitted by Lander, hand it over through queues and the database, so their spans
+//! are not nested. A stage calls [`record_handoff`] when it hands an operation over and the next
+//! stage calls [`link_handoff`] when it picks it up, which links the spans of both stages. This
+//! allows following a single message across stages in the tracing backend. The innermost span
+//! enabled by the log level is linked, e.g. the relayer `prepare` span only at `debug`.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{LazyLock, Mutex},
+    time::Duration,
+};
+
+use eyre::Result;
+use opentelemetry::{
+    trace::{SpanContext, TraceContextExt, TracerProvider as _},
+    KeyValue,
+};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{
+    runtime,
+    trace::{Sampler, Tracer, TracerProvider},
+    Resource,
+};
+use tracing::Subscriber;
+use tracing_opentelemetry::{OpenTelemetryLayer, OpenTelemetrySpanExt};
+use tracing_subscriber::registry::LookupSpan;
+use url::Url;
+
+use crate::CoreMetrics;
+
+pub mod metrics;
+
+/// Name of the tracer the spans are reported by
+const TRACER_NAME: &str = "hyperlane-agent";
+
+/// Timeout of a single export to the collector
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Number of handed over operations whose span is remembered. Operations
+/// which are picked up after this many others were handed over are not linked.
+const MAX_HANDOFFS: usize = 10_000;
+
+/// Configuration of the export to an OpenTelemetry collector
+#[derive(Debug, Clone, PartialEq)]
+pub struct OtlpConfig {
+    /// gRPC endpoint of the collector, e.g. `http://localhost:4317`
+    pub endpoint: Url,
+    /// Service the telemetry is reported for, the agent name by default
+    pub service_name: Option<String>,
+    /// Share of the traces which are exported, between 0 and 1
+    pub sample_ratio: f64,
+    /// Whether the prometheus metrics are exported as well
+    pub export_metrics: bool,
+    /// Interval between two exports of the metrics
+    pub metrics_interval: Duration,
+}
+
+impl OtlpConfig {
+    /// Default interval between two exports of the metrics
+    pub const DEFAULT_METRICS_INTERVAL: Duration = Duration::from_secs(30);
+
+    fn service_name<'a>(&'a self, metrics: &'a CoreMetrics) -> &'a str {
+        self.service_name
+            .as_deref()
+            .unwrap_or_else(|| metrics.agent_name())
+    }
+
+    /// Build the provider of the tracer exporting spans to the collector
+    pub(crate) fn tracer_provider(&self, metrics: &CoreMetrics) -> Result<TracerProvider> {
+        let exporter = SpanExporter::builder()
+            .with_tonic()
+            .with_endpoint(self.endpoint.as_str())
+            .with_timeout(EXPORT_TIMEOUT)
+            .build()?;
+        let sampler = Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(self.sample_ratio)));
+        Ok(TracerProvider::builder()
+            .with_batch_exporter(exporter, runtime::Tokio)
+            .with_sampler(sampler)
+            .with_resource(resource(self.service_name(metrics)))
+            .build())
+    }
+
+    /// Start exporting to the collector once the tracing subscriber is
+    /// installed
+    pub(crate) fn start_export(
+        &self,
+        provider: TracerProvider,
+        metrics: &CoreMetrics,
+    ) -> Result<()> {
+        opentelemetry::global::set_tracer_provider(provider);
+        if self.export_metrics {
+            metrics::spawn_metrics_export(
+                &self.endpoint,
+                self.metrics_interval,
+                self.service_name(metrics),
+                metrics.registry(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Layer exporting the spans of the agent with the tracer of `provider`
+pub(crate) fn tracing_layer<S>(provider: &TracerProvider) -> OpenTelemetryLayer<S, Tracer>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    tracing_opentelemetry::layer().with_tracer(provider.tracer(TRACER_NAME))
+}
+
+fn resource(service_name: &str) -> Resource {
+    Resource::new([KeyValue::new("service.name", service_name.to_owned())])
+}
+
+/// Span contexts of handed over operations by key, evicted oldest first
+#[derive(Default)]
+struct Handoffs {
+    contexts: HashMap<String, SpanContext>,
+    order: VecDeque<String>,
+}
+
+static HANDOFFS: LazyLock<Mutex<Handoffs>> = LazyLock::new(Default::default);
+
+/// Remember the current span as the one handing over the operation
+/// identified by `key`, e.g. the hex message id or a payload uuid
+pub fn record_handoff(key: &str) {
+    let context = tracing::Span::current()
+        .context()
+        .span()
+        .span_context()
+        .clone();
+    // Spans are only valid if they are exported
+    if !context.is_valid() {
+        return;
+    }
+    let Ok(mut handoffs) = HANDOFFS.lock() else {
+        return;
+    };
+    if handoffs.contexts.insert(key.to_owned(), context).is_none() {
+        handoffs.order.push_back(key.to_owned());
+    }
+    while handoffs.order.len() > MAX_HANDOFFS {
+        if let Some(oldest) = handoffs.order.pop_front() {
+            handoffs.contexts.remove(&oldest);
+        }
+    }
+}
+
+/// Link the current span to the span which handed over the operation
+/// identified by `key`, if it was recorded
+pub fn link_handoff(key: &str) {
+    let context = HANDOFFS
+        .lock()
+        .ok()
+        .and_then(|handoffs| handoffs.contexts.get(key).cloned());
+    if let Some(context) = context {
+        tracing::Span::current().add_link(context);
+    }
+}
+
+#[cfg(test)]
+mod tests;
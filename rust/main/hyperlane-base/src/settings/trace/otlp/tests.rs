@@ -0,0 +1,161 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use opentelemetry_proto::tonic::{
+    collector::{
+        metrics::v1::{
+            metrics_service_server::{MetricsService, MetricsServiceServer},
+            ExportMetricsServiceRequest, ExportMetricsServiceResponse,
+        },
+        trace::v1::{
+            trace_service_server::{TraceService, TraceServiceServer},
+            ExportTraceServiceRequest, ExportTraceServiceResponse,
+        },
+    },
+    common::v1::any_value,
+    trace::v1::Span,
+};
+use prometheus::{IntCounter, Registry};
+use tonic::{Request, Response, Status};
+use tracing_subscriber::prelude::*;
+
+use super::*;
+
+/// In-process OTLP collector keeping everything it receives
+#[derive(Clone, Default)]
+struct CollectorStub {
+    spans: Arc<Mutex<Vec<ExportTraceServiceRequest>>>,
+    metrics: Arc<Mutex<Vec<ExportMetricsServiceRequest>>>,
+}
+
+#[tonic::async_trait]
+impl TraceService for CollectorStub {
+    async fn export(
+        &self,
+        request: Request<ExportTraceServiceRequest>,
+    ) -> Result<Response<ExportTraceServiceResponse>, Status> {
+        self.spans.lock().unwrap().push(request.into_inner());
+        Ok(Response::new(ExportTraceServiceResponse::default()))
+    }
+}
+
+#[tonic::async_trait]
+impl MetricsService for CollectorStub {
+    async fn export(
+        &self,
+        request: Request<ExportMetricsServiceRequest>,
+    ) -> Result<Response<ExportMetricsServiceResponse>, Status> {
+        self.metrics.lock().unwrap().push(request.into_inner());
+        Ok(Response::new(ExportMetricsServiceResponse::default()))
+    }
+}
+
+impl CollectorStub {
+    async fn start() -> (Self, Url) {
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let stub = Self::default();
+        let server = tonic::transport::Server::builder()
+            .add_service(TraceServiceServer::new(stub.clone()))
+            .add_service(MetricsServiceServer::new(stub.clone()))
+            .serve(addr);
+        tokio::spawn(server);
+        // Give the server time to bind
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        (stub, Url::parse(&format!("http://{addr}")).unwrap())
+    }
+
+    fn spans(&self) -> Vec<Span> {
+        self.spans
+            .lock()
+            .unwrap()
+            .iter()
+            .flat_map(|request| &request.resource_spans)
+            .flat_map(|resource_spans| &resource_spans.scope_spans)
+            .flat_map(|scope_spans| scope_spans.spans.clone())
+            .collect()
+    }
+}
+
+fn otlp_config(endpoint: Url) -> OtlpConfig {
+    OtlpConfig {
+        endpoint,
+        service_name: None,
+        sample_ratio: 1.0,
+        export_metrics: true,
+        metrics_interval: Duration::from_millis(50),
+    }
+}
+
+fn string_attribute<'a>(span: &'a Span, key: &str) -> Option<&'a str> {
+    span.attributes
+        .iter()
+        .find(|kv| kv.key == key)
+        .and_then(|kv| match kv.value.as_ref()?.value.as_ref()? {
+            any_value::Value::StringValue(value) => Some(value.as_str()),
+            _ => None,
+        })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spans_are_exported_with_handoff_links() {
+    let (collector, endpoint) = CollectorStub::start().await;
+    let metrics = CoreMetrics::new("relayer", 9090, Registry::new()).unwrap();
+    let provider = otlp_config(endpoint).tracer_provider(&metrics).unwrap();
+    let subscriber = tracing_subscriber::registry().with(tracing_layer(&provider));
+
+    let message_id = "0x6bc56d1e1e7a34a1c4a2a9a2f1e3e4a7b8f0e9d8c7b6a5f4e3d2c1b0a9f8e7d6";
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info_span!("prepare", message_id).in_scope(|| record_handoff(message_id));
+        tracing::info_span!("submit").in_scope(|| link_handoff(message_id));
+        tracing::info_span!("unrelated").in_scope(|| link_handoff("0x00"));
+    });
+    tokio::task::spawn_blocking(move || provider.force_flush())
+        .await
+        .unwrap();
+
+    let spans = collector.spans();
+    let span = |name: &str| spans.iter().find(|span| span.name == name).unwrap();
+    let prepare = span("prepare");
+    assert_eq!(string_attribute(prepare, "message_id"), Some(message_id));
+
+    let submit = span("submit");
+    assert_eq!(submit.links.len(), 1);
+    assert_eq!(submit.links[0].span_id, prepare.span_id);
+    assert_eq!(submit.links[0].trace_id, prepare.trace_id);
+    assert!(span("unrelated").links.is_empty());
+}
+
+#[tokio::test]
+async fn test_metrics_are_exported() {
+    let (collector, endpoint) = CollectorStub::start().await;
+    let registry = Registry::new();
+    let counter = IntCounter::new("messages_processed", "Processed messages").unwrap();
+    registry.register(Box::new(counter.clone())).unwrap();
+    counter.inc();
+
+    let export =
+        metrics::spawn_metrics_export(&endpoint, Duration::from_millis(50), "relayer", registry)
+            .unwrap();
+    for _ in 0..50 {
+        if !collector.metrics.lock().unwrap().is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    export.abort();
+
+    let requests = collector.metrics.lock().unwrap();
+    let names = requests
+        .iter()
+        .flat_map(|request| &request.resource_metrics)
+        .flat_map(|resource_metrics| &resource_metrics.scope_metrics)
+        .flat_map(|scope_metrics| &scope_metrics.metrics)
+        .map(|metric| metric.name.as_str())
+        .collect::<Vec<_>>();
+    assert!(names.contains(&"messages_processed"), "{names:?}");
+}
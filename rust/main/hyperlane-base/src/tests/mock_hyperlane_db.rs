@@ -2,9 +2,9 @@ use std::fmt::Debug;
 
 use crate::db::{DbResult, HyperlaneDb, InterchainGasExpenditureData, InterchainGasPaymentData};
 use hyperlane_core::{
-    identifiers::UniqueIdentifier, GasPaymentKey, HyperlaneDomain, HyperlaneMessage,
-    HyperlaneProvider, InterchainGasPayment, InterchainGasPaymentMeta, MerkleTreeInsertion,
-    PendingOperationStatus, H256, H512,
+    identifiers::UniqueIdentifier, CheckpointWithMessageId, GasPaymentKey, HyperlaneDomain,
+    HyperlaneMessage, HyperlaneProvider, InterchainGasPayment, InterchainGasPaymentMeta,
+    MerkleTreeInsertion, PendingOperationSnapshot, PendingOperationStatus, H256, H512,
 };
 
 mockall::mock! {
@@ -62,6 +62,16 @@ mockall::mock! {
             &self,
             message_id: &H256,
         ) -> DbResult<Option<PendingOperationStatus>>;
+        fn store_operation_snapshot_by_message_id(
+            &self,
+            message_id: &H256,
+            snapshot: &PendingOperationSnapshot,
+        ) -> DbResult<()>;
+        fn retrieve_operation_snapshot_by_message_id(
+            &self,
+            message_id: &H256,
+        ) -> DbResult<Option<PendingOperationSnapshot>>;
+        fn delete_operation_snapshot_by_message_id(&self, message_id: &H256) -> DbResult<()>;
         fn store_interchain_gas_payment_data_by_gas_payment_key(
             &self,
             key: &GasPaymentKey,
@@ -121,6 +131,17 @@ mockall::mock! {
         ) -> DbResult<Option<u64>>;
         fn store_highest_seen_message_nonce_number(&self, nonce: &u32) -> DbResult<()>;
         fn retrieve_highest_seen_message_nonce_number(&self) -> DbResult<Option<u32>>;
+        fn store_signed_checkpoint_by_index(
+            &self,
+            index: &u32,
+            checkpoint: &CheckpointWithMessageId,
+        ) -> DbResult<()>;
+        fn retrieve_signed_checkpoint_by_index(
+            &self,
+            index: &u32,
+        ) -> DbResult<Option<CheckpointWithMessageId>>;
+        fn store_highest_signed_checkpoint_index(&self, index: &u32) -> DbResult<()>;
+        fn retrieve_highest_signed_checkpoint_index(&self) -> DbResult<Option<u32>>;
         fn store_payload_uuids_by_message_id(&self, message_id: &H256, payload_uuids: Vec<UniqueIdentifier>) -> DbResult<()>;
         fn retrieve_payload_uuids_by_message_id(&self, message_id: &H256) -> DbResult<Option<Vec<UniqueIdentifier>>>;
         fn store_dispatched_tx_hash_by_message_id(&self, message_id: &H256, tx_hash: &H512) -> DbResult<()>;
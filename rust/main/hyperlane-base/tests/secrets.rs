@@ -0,0 +1,98 @@
+//! Secret references, from the config files to the redacted logs.
+
+use hyperlane_base::settings::{
+    loader::load_settings, parser::RawAgentConf, secrets::redact, ChainConnectionConf, Settings,
+    SignerConf,
+};
+use hyperlane_core::config::{ConfigPath, ConfigResult, FromRawConf};
+use hyperlane_ethereum::RpcConnectionConf;
+use serde_json::json;
+
+const RPC_URLS_ENV: &str = "TEST_LOAD_SETTINGS_RPC_URLS";
+const KEY: &str = "0x2bdd21761a483f71054e14f5b827213567971c676928d9a1808cbfa4b7501200";
+
+/// The base settings of an agent, loadable on their own
+#[derive(Debug)]
+struct TestSettings(Settings);
+
+impl FromRawConf<RawAgentConf> for TestSettings {
+    fn from_config_filtered(
+        raw: RawAgentConf,
+        cwp: &ConfigPath,
+        _filter: (),
+        agent_name: &str,
+    ) -> ConfigResult<Self> {
+        Settings::from_config_filtered(raw, cwp, None, agent_name).map(Self)
+    }
+}
+
+#[test]
+fn test_load_settings_resolves_and_redacts_secrets() {
+    // `load_settings` reads the default configs from `./config`, which is left
+    // empty so only the config below is loaded
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("config")).unwrap();
+    let key_path = dir.path().join("key");
+    std::fs::write(&key_path, format!("{KEY}\n")).unwrap();
+
+    let config_path = dir.path().join("agent.json");
+    let config = json!({
+        "chains": {
+            "test1": {
+                "name": "test1",
+                "domainId": 9913371,
+                "chainId": 9913371,
+                "protocol": "ethereum",
+                "rpcUrls": [{ "http": "http://localhost:8545" }],
+                "customRpcUrls": { "$secretEnv": RPC_URLS_ENV },
+                "blocks": { "estimateBlockTime": 1, "reorgPeriod": 0 },
+                "mailbox": "0x0000000000000000000000000000000000000001",
+                "interchainGasPaymaster": "0x0000000000000000000000000000000000000002",
+                "validatorAnnounce": "0x0000000000000000000000000000000000000003",
+                "merkleTreeHook": "0x0000000000000000000000000000000000000004",
+                "signer": {
+                    "type": "hexKey",
+                    "key": { "$secretFile": key_path.to_str().unwrap() },
+                },
+            },
+        },
+    });
+    std::fs::write(&config_path, config.to_string()).unwrap();
+
+    std::env::set_var(
+        RPC_URLS_ENV,
+        "https://RPC.example.com:443/api-key-1,https://other.example.com?key=api-key-2",
+    );
+    std::env::set_var("CONFIG_FILES", config_path.to_str().unwrap());
+    std::env::set_current_dir(dir.path()).unwrap();
+
+    let TestSettings(settings) = load_settings::<RawAgentConf, TestSettings>("relayer").unwrap();
+    let chain = settings
+        .chain_setup(&settings.lookup_domain("test1").unwrap())
+        .unwrap();
+
+    assert!(matches!(&chain.signer, Some(SignerConf::HexKey { .. })));
+    assert_eq!(
+        redact(&format!("signing with {KEY}")),
+        "signing with <redacted>"
+    );
+
+    let ChainConnectionConf::Ethereum(conn) = &chain.connection else {
+        panic!("expected an ethereum connection");
+    };
+    let RpcConnectionConf::HttpFallback { urls } = &conn.rpc_connection else {
+        panic!("expected a fallback connection");
+    };
+    // The urls are normalized when parsed, and logged in that form
+    assert_eq!(
+        urls.iter().map(|url| url.as_str()).collect::<Vec<_>>(),
+        vec![
+            "https://rpc.example.com/api-key-1",
+            "https://other.example.com/?key=api-key-2",
+        ]
+    );
+    for url in urls {
+        let logged = redact(&format!("connecting to {url}"));
+        assert_eq!(logged, "connecting to <redacted>");
+    }
+}
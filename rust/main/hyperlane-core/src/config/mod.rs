@@ -131,6 +131,11 @@ impl ConfigParsingError {
     pub fn is_ok(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Iterate over the individual errors and the config path of each.
+    pub fn iter(&self) -> impl Iterator<Item = (&ConfigPath, &Report)> {
+        self.0.iter().map(|(path, report)| (path, report))
+    }
 }
 
 impl FromIterator<ConfigParsingError> for ConfigParsingError {
@@ -2,10 +2,15 @@ use thiserror::Error;
 
 use crate::ChainCommunicationError;
 
+use super::RpcBudgetExhausted;
+
 /// Errors specific to fallback provider.
 #[derive(Error, Debug)]
 pub enum RpcClientError {
     /// Fallback providers failed
     #[error("All fallback providers failed. (Errors: {0:?})")]
     FallbackProvidersFailed(Vec<ChainCommunicationError>),
+    /// The daily budget of every provider is exhausted
+    #[error(transparent)]
+    BudgetExhausted(#[from] RpcBudgetExhausted),
 }
@@ -3,20 +3,25 @@ use std::{
     future::Future,
     marker::PhantomData,
     pin::Pin,
-    sync::Arc,
+    sync::{Arc, Mutex, PoisonError},
     time::{Duration, Instant},
 };
 
 use async_rwlock::RwLock;
 use async_trait::async_trait;
 use derive_new::new;
+use futures::future::{select, Either};
 use itertools::Itertools;
+use prometheus::GaugeVec;
 use tokio;
-use tracing::{info, warn, warn_span};
+use tracing::{debug, info, warn, warn_span, Instrument};
 
 use crate::ChainResult;
 
-use super::RpcClientError;
+use super::{
+    score_providers, ProviderStats, RpcBudgetExhausted, RpcClientError, RpcRateLimiter,
+    ScoringConfig,
+};
 
 /// Read the current block number from a chain.
 #[async_trait]
@@ -64,11 +69,57 @@ pub struct PrioritizedProviders<T> {
     pub providers: Vec<T>,
     /// Sorted list of providers this provider calls, in descending order or reliability
     pub priorities: RwLock<Vec<PrioritizedProviderInner>>,
+    /// Statistics of the recent requests to each provider, by index into `providers`
+    stats: Mutex<Vec<ProviderStats>>,
+    /// Self-imposed rate limit of each provider, by index into `providers`
+    rate_limiters: Vec<Option<Arc<RpcRateLimiter>>>,
+}
+
+/// Gauge of the score of each provider of a `FallbackProvider`, labelled by
+/// `provider_node` and `chain`
+#[derive(Clone)]
+struct ScoreMetric {
+    gauge: GaugeVec,
+    chain: String,
+    provider_nodes: Vec<String>,
+}
+
+impl ScoreMetric {
+    fn update(&self, scores: &[f64]) {
+        for (index, score) in scores.iter().enumerate() {
+            let node = self
+                .provider_nodes
+                .get(index)
+                .cloned()
+                .unwrap_or_else(|| index.to_string());
+            self.gauge
+                .with_label_values(&[node.as_str(), self.chain.as_str()])
+                .set(*score);
+        }
+    }
+}
+
+/// Just so we can derive Debug for the builder
+impl Debug for ScoreMetric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScoreMetric")
+            .field("chain", &self.chain)
+            .field("provider_nodes", &self.provider_nodes)
+            .finish()
+    }
 }
 
 /// A provider that bundles multiple providers and attempts to call the first,
 /// then the second, and so on until a response is received.
 ///
+/// Providers are ordered by their score, see `ScoringConfig`, which is updated with the latency
+/// and the outcome of every request. Deprioritizing a provider, because of too many errors or a
+/// stalled block height, penalizes its score, so the order by score keeps it deprioritized. If a
+/// hedging delay is set, a provider which didn't respond within it is raced against the next one.
+///
+/// A provider with a rate limit waits for it before its request is timed. A provider whose daily
+/// budget is exhausted is skipped without counting as a failure.
+///
 /// Although no trait bounds are used in the struct definition, the intended purpose of `B`
 /// is to be bound by `BlockNumberGetter` and have `T` be convertible to `B`. That is,
 /// inner providers should be able to get the current block number, or be convertible into
@@ -77,6 +128,9 @@ pub struct FallbackProvider<T, B> {
     /// The sub-providers called by this provider
     pub inner: Arc<PrioritizedProviders<T>>,
     max_block_time: Duration,
+    scoring: ScoringConfig,
+    hedge_after: Option<Duration>,
+    score_metric: Option<ScoreMetric>,
     _phantom: PhantomData<B>,
 }
 
@@ -97,6 +151,9 @@ impl<T, B> Clone for FallbackProvider<T, B> {
         Self {
             inner: self.inner.clone(),
             max_block_time: self.max_block_time,
+            scoring: self.scoring.clone(),
+            hedge_after: self.hedge_after,
+            score_metric: self.score_metric.clone(),
             _phantom: PhantomData,
         }
     }
@@ -178,6 +235,7 @@ where
             .await
             .unwrap_or(priority.last_block_height.0);
         if current_block_height <= priority.last_block_height.0 {
+            self.update_stats(priority.index, ProviderStats::record_stalled);
             let new_priority = priority.reset_failed_count();
 
             // The `max_block_time` elapsed but the block number returned by the provider has not increased
@@ -189,9 +247,13 @@ where
                 "Deprioritizing an inner provider in FallbackProvider",
             );
         } else {
+            self.update_stats(priority.index, |stats| {
+                stats.record_block_height(current_block_height)
+            });
             self.update_last_seen_block(priority.index, current_block_height)
                 .await;
         }
+        self.rescore();
     }
 
     /// De-prioritize a provider that has returned a bad response
@@ -200,6 +262,7 @@ where
 
         if priority.last_failed_count.saturating_add(1) >= FAILED_REQUEST_THRESHOLD {
             let new_priority = priority.reset_failed_count();
+            self.update_stats(priority.index, ProviderStats::record_deprioritized);
             self.deprioritize_provider(new_priority).await;
             info!(
                 provider_index=%new_priority.index,
@@ -207,6 +270,60 @@ where
                 reason="Too many errors",
                 "Deprioritizing an inner provider in FallbackProvider",
             );
+            self.rescore();
+        }
+    }
+
+    /// Record the latency and the outcome of a request to the provider at
+    /// `index`, and reorder the providers by their updated scores
+    pub fn record_response(&self, index: usize, latency: Duration, success: bool) {
+        let window = self.scoring.window;
+        self.update_stats(index, |stats| stats.record(latency, success, window));
+        self.rescore();
+    }
+
+    /// Record a request to the provider at `index` which was cancelled after
+    /// `latency`, e.g. because a hedged request responded first
+    pub fn record_cancelled(&self, index: usize, latency: Duration) {
+        let window = self.scoring.window;
+        self.update_stats(index, |stats| stats.record_cancelled(latency, window));
+        self.rescore();
+    }
+
+    /// Current score of each provider, by index into `providers`. Lower is
+    /// better.
+    pub fn scores(&self) -> Vec<f64> {
+        let stats = self
+            .inner
+            .stats
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        score_providers(&stats, &self.scoring)
+    }
+
+    fn update_stats(&self, index: usize, update: impl FnOnce(&mut ProviderStats)) {
+        let mut stats = self
+            .inner
+            .stats
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(stats) = stats.get_mut(index) {
+            update(stats);
+        }
+    }
+
+    /// Sort the providers by score, which includes the penalties of
+    /// deprioritized providers. The sort is stable, so providers with the same
+    /// score keep their order. If the priorities are locked, they are sorted on
+    /// the next update instead.
+    fn rescore(&self) {
+        let scores = self.scores();
+        if let Some(metric) = &self.score_metric {
+            metric.update(&scores);
+        }
+        let score = |p: &PrioritizedProviderInner| scores.get(p.index).copied().unwrap_or_default();
+        if let Some(mut priorities) = self.inner.priorities.try_write() {
+            priorities.sort_by(|a, b| score(a).total_cmp(&score(b)));
         }
     }
 
@@ -231,37 +348,201 @@ where
                 tokio::time::sleep(Duration::from_millis(100)).await;
             }
             let priorities_snapshot = self.take_priorities_snapshot().await;
+            // Index of the provider which was already called to hedge the previous one
+            let mut hedged = None;
+            // Set if no provider responded because every budget is exhausted
+            let mut budget_exhausted = None;
+            let mut responded = false;
             for (idx, priority) in priorities_snapshot.iter().enumerate() {
-                let provider = &self.inner.providers[priority.index];
-                let resp = f(provider.clone()).await;
-                self.handle_stalled_provider(priority, provider).await;
-                if resp.is_err() {
-                    self.handle_failed_provider(priority).await;
+                if hedged == Some(priority.index) {
+                    continue;
                 }
-                let _span =
-                    warn_span!("FallbackProvider::call", fallback_count=%idx, provider_index=%priority.index, ?provider).entered();
-                match resp {
-                    Ok(v) => return Ok(v),
+                let next = priorities_snapshot.get(idx.saturating_add(1));
+                let provider = &self.inner.providers[priority.index];
+                let span = warn_span!("FallbackProvider::call", fallback_count=%idx, provider_index=%priority.index, ?provider);
+                let responses = self
+                    .call_hedged(None, priority, next, &mut f, Result::is_ok)
+                    .instrument(span.clone())
+                    .await;
+                let _span = span.entered();
+                let responses = match responses {
+                    Ok(responses) => responses,
                     Err(e) => {
-                        warn!(
-                            error=?e,
-                            "Got error from inner fallback provider",
-                        );
-                        errors.push(e);
+                        warn!(error=%e, "Skipping inner fallback provider");
+                        budget_exhausted = Some(e);
+                        continue;
+                    }
+                };
+                responded = true;
+                for (index, resp) in responses {
+                    if index != priority.index {
+                        hedged = Some(index);
+                    }
+                    match resp {
+                        Ok(v) => return Ok(v),
+                        Err(e) => {
+                            warn!(
+                                error=?e,
+                                provider_index=%index,
+                                "Got error from inner fallback provider",
+                            );
+                            errors.push(e);
+                        }
                     }
                 }
             }
+            // Retrying is pointless if no provider has any budget left
+            if let Some(e) = budget_exhausted.filter(|_| !responded) {
+                return Err(RpcClientError::BudgetExhausted(e).into());
+            }
         }
 
         Err(RpcClientError::FallbackProvidersFailed(errors).into())
     }
+
+    /// Call the provider of `priority`. If hedging is enabled and it didn't
+    /// respond within the hedging delay, the provider of `next` is called as
+    /// well. Requests first spend the rate limit budget of their provider for
+    /// `method`, or the default cost if it's unnamed.
+    ///
+    /// Returns the responses with the index of their provider, in the order
+    /// they arrived, up to the first one which is `accept`ed. The request still
+    /// in flight then is cancelled. A response which is not accepted is
+    /// followed by the response of the other request, if there is one. Fails
+    /// without a request if the daily budget of the provider of `priority` is
+    /// exhausted, while the provider of `next` just isn't hedged with then.
+    pub async fn call_hedged<V, E>(
+        &self,
+        method: Option<&str>,
+        priority: &PrioritizedProviderInner,
+        next: Option<&PrioritizedProviderInner>,
+        f: &mut impl FnMut(T) -> Pin<Box<dyn Future<Output = Result<V, E>> + Send>>,
+        accept: impl Fn(&Result<V, E>) -> bool,
+    ) -> Result<Vec<(usize, Result<V, E>)>, RpcBudgetExhausted> {
+        let responses = self.race_hedge(method, priority, next, f, accept).await?;
+        // Only providers which responded are checked, not the one cancelled
+        for (index, resp) in &responses {
+            let Some(priority) = std::iter::once(priority)
+                .chain(next)
+                .find(|p| p.index == *index)
+            else {
+                continue;
+            };
+            self.handle_stalled_provider(priority, &self.inner.providers[priority.index])
+                .await;
+            if resp.is_err() {
+                self.handle_failed_provider(priority).await;
+            }
+        }
+        Ok(responses)
+    }
+
+    async fn race_hedge<V, E>(
+        &self,
+        method: Option<&str>,
+        priority: &PrioritizedProviderInner,
+        next: Option<&PrioritizedProviderInner>,
+        f: &mut impl FnMut(T) -> Pin<Box<dyn Future<Output = Result<V, E>> + Send>>,
+        accept: impl Fn(&Result<V, E>) -> bool,
+    ) -> Result<Vec<(usize, Result<V, E>)>, RpcBudgetExhausted> {
+        // Waiting for the rate limit is neither part of the latency nor of the
+        // hedging delay
+        self.acquire(priority.index, method).await?;
+        let start = Instant::now();
+        let mut primary = Box::pin(self.timed_call(priority.index, f));
+        let (Some(hedge_after), Some(next)) = (self.hedge_after, next) else {
+            return Ok(vec![(priority.index, primary.await)]);
+        };
+        if let Ok(resp) = tokio::time::timeout(hedge_after, &mut primary).await {
+            return Ok(vec![(priority.index, resp)]);
+        }
+
+        debug!(
+            provider_index = priority.index,
+            hedge_provider_index = next.index,
+            "Hedging slow request to inner fallback provider"
+        );
+        let request = self.timed_call(next.index, f);
+        let secondary = Box::pin(async move {
+            self.acquire(next.index, method).await?;
+            Ok(request.await)
+        });
+        Ok(match select(primary, secondary).await {
+            Either::Left((resp, secondary)) => {
+                if accept(&resp) {
+                    // The hedge was only started after the hedging delay, so its
+                    // latency so far says nothing about the provider
+                    return Ok(vec![(priority.index, resp)]);
+                }
+                match secondary.await {
+                    Ok(hedged) => vec![(priority.index, resp), (next.index, hedged)],
+                    Err(_) => vec![(priority.index, resp)],
+                }
+            }
+            Either::Right((Err(e), primary)) => {
+                debug!(hedge_provider_index = next.index, error = %e, "Not hedging request");
+                vec![(priority.index, primary.await)]
+            }
+            Either::Right((Ok(resp), primary)) => {
+                if accept(&resp) {
+                    // The slow request is dropped, its latency so far is a lower bound
+                    self.record_cancelled(priority.index, start.elapsed());
+                    return Ok(vec![(next.index, resp)]);
+                }
+                vec![(next.index, resp), (priority.index, primary.await)]
+            }
+        })
+    }
+
+    /// Spend the rate limit budget of a request of `method` to the provider at
+    /// `index`, waiting until its rate limit allows it
+    pub async fn acquire(
+        &self,
+        index: usize,
+        method: Option<&str>,
+    ) -> Result<(), RpcBudgetExhausted> {
+        match self.inner.rate_limiters.get(index).and_then(Option::as_ref) {
+            Some(rate_limiter) => rate_limiter.acquire(method.unwrap_or_default()).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Call the provider at `index`, recording the latency and the outcome of
+    /// the request as soon as it completes
+    fn timed_call<V, E>(
+        &self,
+        index: usize,
+        f: &mut impl FnMut(T) -> Pin<Box<dyn Future<Output = Result<V, E>> + Send>>,
+    ) -> impl Future<Output = Result<V, E>> + '_ {
+        let request = f(self.inner.providers[index].clone());
+        async move {
+            let start = Instant::now();
+            let resp = request.await;
+            self.record_response(index, start.elapsed(), resp.is_ok());
+            resp
+        }
+    }
+}
+
+/// Configuration of how a `FallbackProvider` orders and hedges its providers
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FallbackProviderConf {
+    /// Delay after which a provider which didn't respond is raced against the
+    /// next one. Requests are not hedged if unset.
+    pub hedge_after: Option<Duration>,
+    /// How the providers are scored
+    pub scoring: ScoringConfig,
 }
 
 /// Builder to create a new fallback provider.
 #[derive(Debug, Clone)]
 pub struct FallbackProviderBuilder<T, B> {
     providers: Vec<T>,
+    rate_limiters: Vec<Option<Arc<RpcRateLimiter>>>,
     max_block_time: Duration,
+    scoring: ScoringConfig,
+    hedge_after: Option<Duration>,
+    score_metric: Option<ScoreMetric>,
     _phantom: PhantomData<B>,
 }
 
@@ -269,7 +550,11 @@ impl<T, B> Default for FallbackProviderBuilder<T, B> {
     fn default() -> Self {
         Self {
             providers: Vec::new(),
+            rate_limiters: Vec::new(),
             max_block_time: MAX_BLOCK_TIME,
+            scoring: ScoringConfig::default(),
+            hedge_after: None,
+            score_metric: None,
             _phantom: PhantomData,
         }
     }
@@ -278,15 +563,27 @@ impl<T, B> Default for FallbackProviderBuilder<T, B> {
 impl<T, B> FallbackProviderBuilder<T, B> {
     /// Add a new provider to the set. Each new provider will be a lower
     /// priority than the previous.
-    pub fn add_provider(mut self, provider: T) -> Self {
+    pub fn add_provider(self, provider: T) -> Self {
+        self.add_rate_limited_provider(provider, None)
+    }
+
+    /// Add a new provider which has to wait for `rate_limiter` before each
+    /// request, if set
+    pub fn add_rate_limited_provider(
+        mut self,
+        provider: T,
+        rate_limiter: Option<Arc<RpcRateLimiter>>,
+    ) -> Self {
         self.providers.push(provider);
+        self.rate_limiters.push(rate_limiter);
         self
     }
 
     /// Add many providers sorted by highest priority to lowest.
-    pub fn add_providers(mut self, providers: impl IntoIterator<Item = T>) -> Self {
-        self.providers.extend(providers);
-        self
+    pub fn add_providers(self, providers: impl IntoIterator<Item = T>) -> Self {
+        providers
+            .into_iter()
+            .fold(self, |builder, provider| builder.add_provider(provider))
     }
 
     /// Only used for testing purposes.
@@ -296,6 +593,45 @@ impl<T, B> FallbackProviderBuilder<T, B> {
         self
     }
 
+    /// Set how the providers are scored
+    pub fn with_scoring(mut self, scoring: ScoringConfig) -> Self {
+        self.scoring = scoring;
+        self
+    }
+
+    /// Call the next provider as well if a provider didn't respond within
+    /// `hedge_after`, and use the first successful response
+    pub fn with_hedge_after(mut self, hedge_after: Duration) -> Self {
+        self.hedge_after = Some(hedge_after);
+        self
+    }
+
+    /// Apply the scoring and the hedging delay of `conf`
+    pub fn with_conf(self, conf: &FallbackProviderConf) -> Self {
+        let builder = self.with_scoring(conf.scoring.clone());
+        match conf.hedge_after {
+            Some(hedge_after) => builder.with_hedge_after(hedge_after),
+            None => builder,
+        }
+    }
+
+    /// Export the score of each provider to `gauge`, which is labelled by
+    /// `provider_node` and `chain`. `provider_nodes` names the providers in
+    /// the order they were added.
+    pub fn with_score_metric(
+        mut self,
+        gauge: Option<GaugeVec>,
+        chain: &str,
+        provider_nodes: Vec<String>,
+    ) -> Self {
+        self.score_metric = gauge.map(|gauge| ScoreMetric {
+            gauge,
+            chain: chain.to_owned(),
+            provider_nodes,
+        });
+        self
+    }
+
     /// Create a fallback provider.
     pub fn build(self) -> FallbackProvider<T, B> {
         let provider_count = self.providers.len();
@@ -307,10 +643,15 @@ impl<T, B> FallbackProviderBuilder<T, B> {
                     .map(|i| PrioritizedProviderInner::new(i, 0))
                     .collect(),
             ),
+            stats: Mutex::new(vec![ProviderStats::default(); provider_count]),
+            rate_limiters: self.rate_limiters,
         };
         FallbackProvider {
             inner: Arc::new(prioritized_providers),
             max_block_time: self.max_block_time,
+            scoring: self.scoring,
+            hedge_after: self.hedge_after,
+            score_metric: self.score_metric,
             _phantom: PhantomData,
         }
     }
@@ -444,4 +785,193 @@ pub mod test {
             .collect();
         assert_eq!(expected, actual);
     }
+
+    #[tokio::test]
+    async fn test_slow_provider_is_hedged_and_deprioritized() {
+        let slow = ProviderMock::new(Some(Duration::from_millis(200)));
+        let fast = ProviderMock::new(None);
+        let gauge = GaugeVec::new(
+            prometheus::opts!("provider_score", "Score"),
+            &["provider_node", "chain"],
+        )
+        .unwrap();
+        let fallback_provider: FallbackProvider<ProviderMock, ProviderMock> =
+            FallbackProvider::builder()
+                .add_providers(vec![slow, fast])
+                .with_hedge_after(Duration::from_millis(20))
+                .with_score_metric(
+                    Some(gauge.clone()),
+                    "test",
+                    vec!["slow".to_owned(), "fast".to_owned()],
+                )
+                .build();
+
+        let call = || {
+            fallback_provider.call(|provider: ProviderMock| {
+                let future = async move {
+                    provider.push("call", ());
+                    if let Some(sleep) = provider.request_sleep() {
+                        tokio::time::sleep(sleep).await;
+                    }
+                    // whether the fast provider responded
+                    Ok(provider.request_sleep().is_none())
+                };
+                Box::pin(future)
+            })
+        };
+
+        // the slow provider is called first, the fast one responds first
+        assert!(call().await.unwrap());
+        let priorities: Vec<_> = ProviderMock::get_priorities(&fallback_provider)
+            .await
+            .iter()
+            .map(|p| p.index)
+            .collect();
+        assert_eq!(priorities, vec![1, 0]);
+        let score = |node| gauge.with_label_values(&[node, "test"]).get();
+        assert!(score("slow") >= 0.02);
+        assert!(score("fast") < score("slow"));
+
+        // the fast provider is called first now
+        assert!(call().await.unwrap());
+        let call_counts = ProviderMock::get_call_counts(&fallback_provider).await;
+        assert_eq!(call_counts, vec![2, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_hedged_out_provider_is_not_recorded_as_success() {
+        let slow = ProviderMock::new(Some(Duration::from_millis(200)));
+        let fast = ProviderMock::new(None);
+        let fallback_provider: FallbackProvider<ProviderMock, ProviderMock> =
+            FallbackProvider::builder()
+                .add_providers(vec![slow, fast])
+                .with_conf(&FallbackProviderConf {
+                    hedge_after: Some(Duration::from_millis(20)),
+                    scoring: ScoringConfig::default(),
+                })
+                .build();
+        // a success would lift the penalty
+        fallback_provider.update_stats(0, ProviderStats::record_deprioritized);
+
+        let resp = fallback_provider
+            .call(|provider: ProviderMock| {
+                let future = async move {
+                    if let Some(sleep) = provider.request_sleep() {
+                        tokio::time::sleep(sleep).await;
+                    }
+                    Ok(provider.request_sleep().is_none())
+                };
+                Box::pin(future)
+            })
+            .await;
+        assert!(resp.unwrap());
+
+        let stats = fallback_provider.inner.stats.lock().unwrap()[0].clone();
+        assert_eq!(stats.error_rate(), 0.0);
+        assert!(stats.latency_percentile(1.0) >= Duration::from_millis(20));
+        assert!(fallback_provider.scores()[0] >= 30.0);
+    }
+
+    #[tokio::test]
+    async fn test_rescoring_keeps_deprioritized_provider_last() {
+        let fallback_provider: FallbackProvider<ProviderMock, ProviderMock> =
+            FallbackProvider::new(vec![ProviderMock::new(None), ProviderMock::new(None)]);
+        fallback_provider.record_response(0, Duration::from_millis(10), true);
+        fallback_provider.record_response(1, Duration::from_secs(1), true);
+
+        for _ in 0..FAILED_REQUEST_THRESHOLD {
+            let priority = fallback_provider
+                .take_priorities_snapshot()
+                .await
+                .into_iter()
+                .find(|p| p.index == 0)
+                .unwrap();
+            fallback_provider.handle_failed_provider(&priority).await;
+        }
+        // provider 0 is still faster, but stays deprioritized when the
+        // providers are scored again
+        fallback_provider.record_response(1, Duration::from_secs(1), true);
+
+        let priorities: Vec<_> = ProviderMock::get_priorities(&fallback_provider)
+            .await
+            .iter()
+            .map(|p| p.index)
+            .collect();
+        assert_eq!(priorities, vec![1, 0]);
+    }
+
+    fn push_request(
+        provider: ProviderMock,
+    ) -> Pin<Box<dyn Future<Output = ChainResult<()>> + Send>> {
+        Box::pin(async move {
+            provider.push("request", ());
+            Ok(())
+        })
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_wait_is_not_latency() {
+        let rate_limiter = RpcRateLimiter::new(
+            crate::rpc_clients::RpcRateLimitConf {
+                credits_per_second: Some(10.0),
+                burst: Some(1.0),
+                ..Default::default()
+            },
+            Default::default(),
+        );
+        let fallback_provider: FallbackProvider<ProviderMock, ProviderMock> =
+            FallbackProvider::builder()
+                .add_rate_limited_provider(ProviderMock::new(None), Some(Arc::new(rate_limiter)))
+                .build();
+
+        let start = Instant::now();
+        for _ in 0..2 {
+            fallback_provider.call(push_request).await.unwrap();
+        }
+        // the second request waited for the rate limit
+        assert!(start.elapsed() >= Duration::from_millis(90));
+        let stats = fallback_provider.inner.stats.lock().unwrap()[0].clone();
+        assert!(stats.latency_percentile(1.0) < Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_provider_without_budget_is_skipped() {
+        let exhausted = || {
+            let conf = crate::rpc_clients::RpcRateLimitConf {
+                daily_credits: Some(0),
+                ..Default::default()
+            };
+            Some(Arc::new(RpcRateLimiter::new(conf, Default::default())))
+        };
+        let fallback_provider: FallbackProvider<ProviderMock, ProviderMock> =
+            FallbackProvider::builder()
+                .add_rate_limited_provider(ProviderMock::new(None), exhausted())
+                .add_provider(ProviderMock::new(None))
+                .build();
+
+        fallback_provider.call(push_request).await.unwrap();
+        let requests: Vec<_> = fallback_provider
+            .inner
+            .providers
+            .iter()
+            .map(|provider| provider.requests().len())
+            .collect();
+        assert_eq!(requests, vec![0, 1]);
+        // skipping a provider isn't a failure of it
+        let stats = fallback_provider.inner.stats.lock().unwrap()[0].clone();
+        assert_eq!(stats.error_rate(), 0.0);
+
+        // without any budget left the request fails right away
+        let fallback_provider: FallbackProvider<ProviderMock, ProviderMock> =
+            FallbackProvider::builder()
+                .add_rate_limited_provider(ProviderMock::new(None), exhausted())
+                .build();
+        let resp = fallback_provider.call(push_request).await;
+        assert!(matches!(
+            resp,
+            Err(crate::ChainCommunicationError::RpcClientError(
+                RpcClientError::BudgetExhausted(_)
+            ))
+        ));
+    }
 }
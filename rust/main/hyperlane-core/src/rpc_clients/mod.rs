@@ -3,12 +3,24 @@ pub use self::error::*;
 #[cfg(feature = "async")]
 pub use self::fallback::*;
 
+#[cfg(feature = "async")]
+pub use self::rate_limit::*;
+
 #[cfg(feature = "async")]
 pub use self::retry::*;
 
+#[cfg(feature = "async")]
+pub use self::scoring::*;
+
 mod error;
 #[cfg(feature = "async")]
 mod fallback;
 
+#[cfg(feature = "async")]
+mod rate_limit;
+
 #[cfg(feature = "async")]
 mod retry;
+
+#[cfg(feature = "async")]
+mod scoring;
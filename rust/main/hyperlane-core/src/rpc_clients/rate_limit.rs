@@ -0,0 +1,315 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use prometheus::{Counter, CounterVec, Gauge, GaugeVec};
+use tracing::debug;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Self-imposed request budget of an RPC endpoint, measured in credits. A
+/// request costs `default_cost` credits unless its method has its own cost in
+/// `method_costs`, e.g. to match the compute units of a paid RPC plan.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RpcRateLimitConf {
+    /// Credits which may be spent per second. Requests beyond it wait until
+    /// enough credits are available again rather than failing.
+    pub credits_per_second: Option<f64>,
+    /// Credits which may be spent at once after a quiet period. Defaults to
+    /// `credits_per_second`.
+    pub burst: Option<f64>,
+    /// Credits which may be spent per UTC day. Requests beyond it fail, so a
+    /// fallback provider moves on to its other endpoints.
+    pub daily_credits: Option<u64>,
+    /// Cost of a request whose method isn't in `method_costs`, defaults to 1
+    pub default_cost: Option<u64>,
+    /// Cost of a request by method name. Names are matched ignoring case and
+    /// underscores, as keys of the agent config are.
+    pub method_costs: HashMap<String, u64>,
+}
+
+impl RpcRateLimitConf {
+    /// Whether any limit is configured
+    pub fn is_limited(&self) -> bool {
+        self.credits_per_second.is_some() || self.daily_credits.is_some()
+    }
+
+    /// Credits a request of `method` costs
+    pub fn cost(&self, method: &str) -> u64 {
+        self.method_costs
+            .iter()
+            .find(|(name, _)| same_method(name, method))
+            .map(|(_, cost)| *cost)
+            .or(self.default_cost)
+            .unwrap_or(1)
+    }
+}
+
+/// Error returned when the daily budget of an endpoint is exhausted. Unlike a
+/// rate limit error of the endpoint itself, it's not worth retrying until the
+/// budget resets.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error("Daily budget of {daily_credits} credits exhausted, resets in {resets_in:?}")]
+pub struct RpcBudgetExhausted {
+    /// Credits which may be spent per day
+    pub daily_credits: u64,
+    /// Time until the budget resets
+    pub resets_in: Duration,
+}
+
+/// Metrics of a [`RpcRateLimiter`]
+#[derive(Clone, Debug, Default)]
+pub struct RpcRateLimiterMetrics {
+    /// Credits left of the daily budget
+    pub remaining_daily_credits: Option<Gauge>,
+    /// Seconds requests waited for the rate limit
+    pub throttled_seconds: Option<Counter>,
+}
+
+impl RpcRateLimiterMetrics {
+    /// The metrics of the endpoint with `labels`, e.g. its `provider_node`
+    /// and `chain`, from the vectors of all endpoints
+    pub fn with_labels(
+        remaining_daily_credits: Option<&GaugeVec>,
+        throttled_seconds: Option<&CounterVec>,
+        labels: &[&str],
+    ) -> Self {
+        Self {
+            remaining_daily_credits: remaining_daily_credits
+                .map(|gauge| gauge.with_label_values(labels)),
+            throttled_seconds: throttled_seconds.map(|counter| counter.with_label_values(labels)),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RateLimiterState {
+    /// Credits currently available, negative if requests reserved credits
+    /// which aren't refilled yet
+    tokens: f64,
+    refilled_at: Instant,
+    /// Day since the unix epoch `credits_used` counts for
+    day: u64,
+    credits_used: u64,
+}
+
+/// Enforces a [`RpcRateLimitConf`] on the requests to an RPC endpoint. Use
+/// [`RpcRateLimiter::shared`] so that every client of the endpoint in the
+/// process spends the same budget.
+#[derive(Debug)]
+pub struct RpcRateLimiter {
+    conf: RpcRateLimitConf,
+    state: Mutex<RateLimiterState>,
+    metrics: RpcRateLimiterMetrics,
+}
+
+/// Rate limiters by endpoint, shared by all contracts and indexers
+static RATE_LIMITERS: OnceLock<Mutex<HashMap<String, Arc<RpcRateLimiter>>>> = OnceLock::new();
+
+impl RpcRateLimiter {
+    /// Create a rate limiter which isn't shared with other clients
+    pub fn new(conf: RpcRateLimitConf, metrics: RpcRateLimiterMetrics) -> Self {
+        let tokens = conf.burst.or(conf.credits_per_second).unwrap_or_default();
+        let limiter = Self {
+            conf,
+            state: Mutex::new(RateLimiterState {
+                tokens,
+                refilled_at: Instant::now(),
+                day: current_day(),
+                credits_used: 0,
+            }),
+            metrics,
+        };
+        limiter.update_remaining_credits(0);
+        limiter
+    }
+
+    /// The rate limiter of the endpoint `key`, e.g. its url. It's created
+    /// with `conf` and `metrics` by the first caller, later callers share it
+    /// and their arguments are ignored.
+    pub fn shared(
+        key: &str,
+        conf: &RpcRateLimitConf,
+        metrics: impl FnOnce() -> RpcRateLimiterMetrics,
+    ) -> Arc<Self> {
+        let mut limiters = RATE_LIMITERS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        limiters
+            .entry(key.to_owned())
+            .or_insert_with(|| Arc::new(Self::new(conf.clone(), metrics())))
+            .clone()
+    }
+
+    /// The configuration which is enforced
+    pub fn conf(&self) -> &RpcRateLimitConf {
+        &self.conf
+    }
+
+    /// Spend the credits of a request of `method`, waiting until the rate
+    /// limit allows it. Fails without waiting if the daily budget is
+    /// exhausted.
+    pub async fn acquire(&self, method: &str) -> Result<(), RpcBudgetExhausted> {
+        let wait = self.reserve(self.conf.cost(method))?;
+        if !wait.is_zero() {
+            debug!(method, ?wait, "Waiting for self-imposed RPC rate limit");
+            if let Some(counter) = &self.metrics.throttled_seconds {
+                counter.inc_by(wait.as_secs_f64());
+            }
+            tokio::time::sleep(wait).await;
+        }
+        Ok(())
+    }
+
+    /// Spend `cost` credits, returning how long to wait until the rate limit
+    /// allows it
+    fn reserve(&self, cost: u64) -> Result<Duration, RpcBudgetExhausted> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let day = current_day();
+        if day != state.day {
+            state.day = day;
+            state.credits_used = 0;
+        }
+        let credits_used = state.credits_used.saturating_add(cost);
+        if let Some(daily_credits) = self.conf.daily_credits {
+            if credits_used > daily_credits {
+                return Err(RpcBudgetExhausted {
+                    daily_credits,
+                    resets_in: until_next_day(),
+                });
+            }
+        }
+        state.credits_used = credits_used;
+        self.update_remaining_credits(credits_used);
+
+        let Some(rate) = self.conf.credits_per_second.filter(|rate| *rate > 0.0) else {
+            return Ok(Duration::ZERO);
+        };
+        let burst = self.conf.burst.unwrap_or(rate);
+        let now = Instant::now();
+        let refill = now.duration_since(state.refilled_at).as_secs_f64() * rate;
+        state.tokens = (state.tokens + refill).min(burst) - cost as f64;
+        state.refilled_at = now;
+        if state.tokens >= 0.0 {
+            return Ok(Duration::ZERO);
+        }
+        Ok(Duration::try_from_secs_f64(-state.tokens / rate).unwrap_or(Duration::MAX))
+    }
+
+    fn update_remaining_credits(&self, credits_used: u64) {
+        if let (Some(gauge), Some(daily_credits)) = (
+            &self.metrics.remaining_daily_credits,
+            self.conf.daily_credits,
+        ) {
+            gauge.set(daily_credits.saturating_sub(credits_used) as f64);
+        }
+    }
+}
+
+fn same_method(a: &str, b: &str) -> bool {
+    let normalize = |s: &str| {
+        s.chars()
+            .filter(|c| *c != '_')
+            .map(|c| c.to_ascii_lowercase())
+            .collect::<String>()
+    };
+    normalize(a) == normalize(b)
+}
+
+fn since_epoch() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+fn current_day() -> u64 {
+    since_epoch().as_secs().saturating_div(SECONDS_PER_DAY)
+}
+
+fn until_next_day() -> Duration {
+    let next_day = current_day()
+        .saturating_add(1)
+        .saturating_mul(SECONDS_PER_DAY);
+    Duration::from_secs(next_day).saturating_sub(since_epoch())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(conf: RpcRateLimitConf) -> RpcRateLimiter {
+        RpcRateLimiter::new(conf, Default::default())
+    }
+
+    #[test]
+    fn test_method_costs() {
+        let conf = RpcRateLimitConf {
+            default_cost: Some(2),
+            method_costs: [("eth_getLogs".to_owned(), 75)].into(),
+            ..Default::default()
+        };
+        assert_eq!(conf.cost("eth_getLogs"), 75);
+        assert_eq!(conf.cost("eth_getlogs"), 75);
+        assert_eq!(conf.cost("eth_call"), 2);
+        assert_eq!(RpcRateLimitConf::default().cost("eth_call"), 1);
+    }
+
+    #[test]
+    fn test_requests_beyond_burst_wait() {
+        let limiter = limiter(RpcRateLimitConf {
+            credits_per_second: Some(10.0),
+            burst: Some(2.0),
+            ..Default::default()
+        });
+        assert_eq!(limiter.reserve(1).unwrap(), Duration::ZERO);
+        assert_eq!(limiter.reserve(1).unwrap(), Duration::ZERO);
+        // the bucket is empty, the next credits are refilled at 10 per second
+        let wait = limiter.reserve(1).unwrap();
+        assert!(wait > Duration::from_millis(90) && wait <= Duration::from_millis(100));
+        let wait = limiter.reserve(1).unwrap();
+        assert!(wait > Duration::from_millis(190) && wait <= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_daily_budget_is_enforced() {
+        let remaining = Gauge::new("remaining", "remaining").unwrap();
+        let limiter = RpcRateLimiter::new(
+            RpcRateLimitConf {
+                daily_credits: Some(10),
+                method_costs: [("eth_getLogs".to_owned(), 4)].into(),
+                ..Default::default()
+            },
+            RpcRateLimiterMetrics {
+                remaining_daily_credits: Some(remaining.clone()),
+                throttled_seconds: None,
+            },
+        );
+        assert_eq!(remaining.get(), 10.0);
+        assert!(limiter.reserve(4).is_ok());
+        assert!(limiter.reserve(4).is_ok());
+        assert_eq!(remaining.get(), 2.0);
+        // a request which doesn't fit doesn't spend the budget
+        assert!(limiter.reserve(4).is_err());
+        assert!(limiter.reserve(2).is_ok());
+        assert_eq!(remaining.get(), 0.0);
+        assert!(limiter.reserve(1).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_shared_limiter_is_reused() {
+        let conf = RpcRateLimitConf {
+            daily_credits: Some(1),
+            ..Default::default()
+        };
+        let first = RpcRateLimiter::shared("http://shared.test", &conf, Default::default);
+        let second =
+            RpcRateLimiter::shared("http://shared.test", &Default::default(), Default::default);
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(second.acquire("eth_call").await.is_ok());
+        assert!(first.acquire("eth_call").await.is_err());
+    }
+}
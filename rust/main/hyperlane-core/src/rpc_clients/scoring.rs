@@ -0,0 +1,313 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// Configuration of how the providers of a `FallbackProvider` are scored.
+///
+/// The score of a provider is an estimate of how long a request to it takes, in seconds: the
+/// latency percentile of its recent requests, plus penalties for its recent error rate, for
+/// lagging behind the other providers and for being deprioritized after too many consecutive
+/// errors or a stalled block height. Providers are called in ascending order of their score.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoringConfig {
+    /// Number of recent requests the latency and the error rate are computed over
+    pub window: usize,
+    /// Latency percentile of the recent requests, between 0 and 1
+    pub latency_percentile: f64,
+    /// Penalty of a provider of which every recent request failed. Scaled by
+    /// the error rate.
+    pub error_penalty: Duration,
+    /// Penalty for each block the provider is behind the highest block
+    /// reported by another provider before it
+    pub block_lag_penalty: Duration,
+    /// Penalty of a provider whose block height stopped increasing
+    pub stalled_penalty: Duration,
+    /// Penalty of a provider deprioritized after too many consecutive errors,
+    /// until one of its requests succeeds
+    pub deprioritized_penalty: Duration,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            window: 100,
+            latency_percentile: 0.9,
+            error_penalty: Duration::from_secs(2),
+            block_lag_penalty: Duration::from_millis(100),
+            stalled_penalty: Duration::from_secs(60),
+            deprioritized_penalty: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Outcome of a request to a provider
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Outcome {
+    Success,
+    Failure,
+    /// The request was dropped before it completed, e.g. because a hedged
+    /// request to another provider responded first. Its latency is a lower
+    /// bound and it counts as neither a success nor a failure.
+    Cancelled,
+}
+
+/// Rolling statistics of the recent requests to a provider
+#[derive(Clone, Debug, Default)]
+pub struct ProviderStats {
+    /// Latency and outcome of the most recent requests, oldest first
+    samples: VecDeque<(Duration, Outcome)>,
+    /// Last block height reported by the provider and when it was reported
+    block_height: Option<(u64, Instant)>,
+    /// Whether the block height didn't increase within the max block time
+    stalled: bool,
+    /// Whether the provider was deprioritized after too many consecutive
+    /// errors and didn't succeed since
+    deprioritized: bool,
+}
+
+impl ProviderStats {
+    /// Record the outcome of a request, keeping the `window` most recent ones
+    pub fn record(&mut self, latency: Duration, success: bool, window: usize) {
+        let outcome = if success {
+            self.deprioritized = false;
+            Outcome::Success
+        } else {
+            Outcome::Failure
+        };
+        self.push_sample(latency, outcome, window);
+    }
+
+    /// Record a request which was cancelled after `latency`, keeping the
+    /// `window` most recent ones
+    pub fn record_cancelled(&mut self, latency: Duration, window: usize) {
+        self.push_sample(latency, Outcome::Cancelled, window);
+    }
+
+    fn push_sample(&mut self, latency: Duration, outcome: Outcome, window: usize) {
+        self.samples.push_back((latency, outcome));
+        while self.samples.len() > window.max(1) {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Record the block height reported by the provider
+    pub fn record_block_height(&mut self, block_height: u64) {
+        self.block_height = Some((block_height, Instant::now()));
+        self.stalled = false;
+    }
+
+    /// Record that the block height of the provider stopped increasing
+    pub fn record_stalled(&mut self) {
+        self.stalled = true;
+    }
+
+    /// Record that the provider was deprioritized after too many consecutive
+    /// errors
+    pub fn record_deprioritized(&mut self) {
+        self.deprioritized = true;
+    }
+
+    /// Last block height reported by the provider
+    pub fn block_height(&self) -> Option<u64> {
+        self.block_height.map(|(height, _)| height)
+    }
+
+    /// Whether any request to the provider was recorded
+    pub fn has_samples(&self) -> bool {
+        !self.samples.is_empty()
+    }
+
+    /// Latency at `percentile` of the recent requests
+    pub fn latency_percentile(&self, percentile: f64) -> Duration {
+        let mut latencies = self
+            .samples
+            .iter()
+            .map(|(latency, _)| *latency)
+            .collect::<Vec<_>>();
+        latencies.sort();
+        let last = latencies.len().saturating_sub(1);
+        let rank = (percentile.clamp(0.0, 1.0) * last as f64).round() as usize;
+        latencies.get(rank).copied().unwrap_or_default()
+    }
+
+    /// Share of the recent completed requests which failed
+    pub fn error_rate(&self) -> f64 {
+        let completed = self
+            .samples
+            .iter()
+            .filter(|(_, outcome)| *outcome != Outcome::Cancelled)
+            .count();
+        if completed == 0 {
+            return 0.0;
+        }
+        let errors = self
+            .samples
+            .iter()
+            .filter(|(_, outcome)| *outcome == Outcome::Failure)
+            .count();
+        errors as f64 / completed as f64
+    }
+
+    /// Number of blocks the provider is behind the highest block which any of
+    /// `stats` reported before it. Heights reported later are not compared,
+    /// as the chain may have advanced since.
+    pub fn block_lag(&self, stats: &[ProviderStats]) -> u64 {
+        let Some((height, reported_at)) = self.block_height else {
+            return 0;
+        };
+        stats
+            .iter()
+            .filter_map(|other| other.block_height)
+            .filter(|(_, other_reported_at)| *other_reported_at <= reported_at)
+            .map(|(other_height, _)| other_height.saturating_sub(height))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Score of the provider, lower is better. `None` if no request to the
+    /// provider was recorded yet.
+    pub fn score(&self, config: &ScoringConfig, block_lag: u64) -> Option<f64> {
+        if !self.has_samples() {
+            return None;
+        }
+        let latency = self
+            .latency_percentile(config.latency_percentile)
+            .as_secs_f64();
+        let errors = self.error_rate() * config.error_penalty.as_secs_f64();
+        let lag = block_lag as f64 * config.block_lag_penalty.as_secs_f64();
+        let penalty = |applies: bool, penalty: Duration| {
+            if applies {
+                penalty.as_secs_f64()
+            } else {
+                0.0
+            }
+        };
+        let stalled = penalty(self.stalled, config.stalled_penalty);
+        let deprioritized = penalty(self.deprioritized, config.deprioritized_penalty);
+        Some(latency + errors + lag + stalled + deprioritized)
+    }
+}
+
+/// Score every provider. Providers without recorded requests get the best
+/// score, so they keep their place until they were tried.
+pub fn score_providers(stats: &[ProviderStats], config: &ScoringConfig) -> Vec<f64> {
+    let scores = stats
+        .iter()
+        .map(|s| s.score(config, s.block_lag(stats)))
+        .collect::<Vec<_>>();
+    let best = scores
+        .iter()
+        .flatten()
+        .copied()
+        .fold(None, |best: Option<f64>, score| {
+            Some(best.map_or(score, |best| best.min(score)))
+        })
+        .unwrap_or_default();
+    scores
+        .into_iter()
+        .map(|score| score.unwrap_or(best))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(samples: &[(u64, bool)]) -> ProviderStats {
+        let mut stats = ProviderStats::default();
+        for (millis, success) in samples {
+            stats.record(Duration::from_millis(*millis), *success, 100);
+        }
+        stats
+    }
+
+    #[test]
+    fn test_latency_percentile() {
+        let stats = stats(&[(50, true), (10, true), (40, true), (20, true), (30, true)]);
+        assert_eq!(stats.latency_percentile(0.0), Duration::from_millis(10));
+        assert_eq!(stats.latency_percentile(0.5), Duration::from_millis(30));
+        assert_eq!(stats.latency_percentile(1.0), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_window_drops_oldest_samples() {
+        let mut stats = ProviderStats::default();
+        stats.record(Duration::from_millis(10), false, 2);
+        stats.record(Duration::from_millis(10), true, 2);
+        stats.record(Duration::from_millis(10), true, 2);
+        assert_eq!(stats.error_rate(), 0.0);
+    }
+
+    #[test]
+    fn test_score_providers() {
+        let config = ScoringConfig::default();
+        let fast = stats(&[(10, true), (20, true)]);
+        let failing = stats(&[(5, false), (5, true)]);
+        let mut fresh = stats(&[(30, true)]);
+        fresh.record_block_height(100);
+        let mut lagging = stats(&[(10, true)]);
+        lagging.record_block_height(90);
+        let untried = ProviderStats::default();
+
+        let scores = score_providers(&[fast, failing, lagging, fresh, untried], &config);
+        assert_eq!(scores[0], 0.02);
+        // half of the requests failed
+        assert_eq!(scores[1], 0.005 + 1.0);
+        // 10 blocks behind
+        assert_eq!(scores[2], 0.01 + 1.0);
+        assert_eq!(scores[3], 0.03);
+        // untried providers get the best score
+        assert_eq!(scores[4], 0.02);
+    }
+
+    #[test]
+    fn test_block_lag_only_compares_earlier_heights() {
+        let mut behind = ProviderStats::default();
+        behind.record_block_height(90);
+        let mut ahead = ProviderStats::default();
+        ahead.record_block_height(100);
+        // `ahead` reported its height after `behind`, so the chain may have
+        // advanced in between
+        let stats = [behind.clone(), ahead.clone()];
+        assert_eq!(behind.block_lag(&stats), 0);
+        assert_eq!(ahead.block_lag(&stats), 0);
+
+        behind.record_block_height(95);
+        let stats = [behind.clone(), ahead];
+        assert_eq!(behind.block_lag(&stats), 5);
+    }
+
+    #[test]
+    fn test_stalled_provider_is_penalized_until_it_advances() {
+        let config = ScoringConfig::default();
+        let mut stats = stats(&[(10, true)]);
+        stats.record_stalled();
+        assert_eq!(stats.score(&config, 0), Some(0.01 + 60.0));
+        stats.record_block_height(100);
+        assert_eq!(stats.score(&config, 0), Some(0.01));
+    }
+
+    #[test]
+    fn test_cancelled_requests_only_count_towards_latency() {
+        let mut stats = stats(&[(10, false)]);
+        stats.record_cancelled(Duration::from_millis(50), 100);
+        assert_eq!(stats.error_rate(), 1.0);
+        assert_eq!(stats.latency_percentile(1.0), Duration::from_millis(50));
+
+        stats.record(Duration::from_millis(10), true, 100);
+        assert_eq!(stats.error_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_deprioritized_provider_is_penalized_until_it_succeeds() {
+        let config = ScoringConfig::default();
+        let mut stats = stats(&[(10, false)]);
+        stats.record_deprioritized();
+        assert_eq!(stats.score(&config, 0), Some(0.01 + 2.0 + 30.0));
+        stats.record(Duration::from_millis(10), false, 100);
+        assert_eq!(stats.score(&config, 0), Some(0.01 + 2.0 + 30.0));
+        stats.record(Duration::from_millis(10), true, 100);
+        assert_eq!(stats.score(&config, 0), Some(0.01 + 4.0 / 3.0));
+    }
+}
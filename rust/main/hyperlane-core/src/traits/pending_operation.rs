@@ -177,6 +177,14 @@ pub trait PendingOperation: Send + Sync + Debug + TryBatchAs<HyperlaneMessage> {
         None
     }
 
+    /// Whether this operation was restored from a persisted snapshot and can
+    /// skip preparation. Restored operations are queued according to their
+    /// status: `ReadyToSubmit` goes to the submit queue and `Confirm` to the
+    /// confirm queue.
+    fn is_restored_from_snapshot(&self) -> bool {
+        false
+    }
+
     /// Creates payload for the operation
     async fn payload(&self) -> ChainResult<Vec<u8>>;
 
@@ -311,6 +319,9 @@ pub enum ReprepareReason {
     #[strum(to_string = "Awaiting ICA reveal commit confirmation")]
     /// ICA reveal commit not yet confirmed on-chain; polling until visible
     AwaitingIcaReveal,
+    #[strum(to_string = "Relayer balance below configured minimum")]
+    /// Submission to the destination is paused until the relayer wallet is funded
+    InsufficientRelayerBalance,
 }
 
 #[derive(Display, Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -330,6 +341,54 @@ pub enum ConfirmReason {
     ErrorRecordingProcessSuccess,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Compact snapshot of the processing state of an operation, persisted so that
+/// it can be put back into the right queue after a restart.
+/// WARNING: This struct is serialized to JSON and stored in the database, so to keep backwards compatibility, we shouldn't remove or rename any fields.
+/// Adding new optional fields is fine.
+pub struct PendingOperationSnapshot {
+    /// Status of the operation when the snapshot was taken
+    pub status: PendingOperationStatus,
+    /// Number of times the operation has been retried
+    pub num_retries: u32,
+    /// Unix timestamp in seconds before which the operation should not be attempted
+    pub next_attempt_at: Option<u64>,
+    /// Unix timestamp in seconds at which the snapshot was taken
+    pub taken_at: u64,
+    /// Metadata built for the operation, if any
+    pub metadata: Option<Vec<u8>>,
+    /// Gas limit estimated for the operation, if it is ready to be submitted
+    pub gas_limit: Option<U256>,
+}
+
+impl Encode for PendingOperationSnapshot {
+    fn write_to<W>(&self, writer: &mut W) -> std::io::Result<usize>
+    where
+        W: Write,
+    {
+        #[allow(clippy::io_other_error)] // ignore this lint for this line
+        let serialized = serde_json::to_vec(self)
+            .map_err(|_| std::io::Error::new(std::io::ErrorKind::Other, "Failed to serialize"))?;
+        writer.write(&serialized)
+    }
+}
+
+impl Decode for PendingOperationSnapshot {
+    fn read_from<R>(reader: &mut R) -> Result<Self, HyperlaneProtocolError>
+    where
+        R: std::io::Read,
+        Self: Sized,
+    {
+        serde_json::from_reader(reader).map_err(|err| {
+            #[allow(clippy::io_other_error)] // ignore this lint for this line
+            HyperlaneProtocolError::IoError(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("Failed to deserialize. Error: {err}"),
+            ))
+        })
+    }
+}
+
 /// Utility fn to calculate the total estimated cost of an operation batch
 pub fn total_estimated_cost(ops: &[Box<dyn PendingOperation>]) -> U256 {
     ops.iter()
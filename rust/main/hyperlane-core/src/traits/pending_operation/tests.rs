@@ -102,6 +102,21 @@ fn test_encoding_pending_operation_status() {
     assert_eq!(status, decoded);
 }
 
+#[test]
+fn test_encoding_pending_operation_snapshot() {
+    let snapshot = PendingOperationSnapshot {
+        status: PendingOperationStatus::ReadyToSubmit,
+        num_retries: 3,
+        next_attempt_at: Some(1_700_000_100),
+        taken_at: 1_700_000_000,
+        metadata: Some(vec![1, 2, 3]),
+        gas_limit: Some(U256::from(150_000)),
+    };
+    let encoded = snapshot.to_vec();
+    let decoded = PendingOperationSnapshot::read_from(&mut &encoded[..]).unwrap();
+    assert_eq!(snapshot, decoded);
+}
+
 #[test]
 fn test_queue_operation_ord_without_mixing() {
     env::set_var("HYPERLANE_RELAYER_MIXING_ENABLED", "false");
@@ -4,7 +4,7 @@ use std::{fmt::Debug, time::Instant};
 
 use derive_builder::Builder;
 use maplit::hashmap;
-use prometheus::{CounterVec, IntCounterVec};
+use prometheus::{CounterVec, GaugeVec, IntCounterVec};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
@@ -34,6 +34,24 @@ pub const REQUEST_DURATION_SECONDS_LABELS: &[&str] =
 /// Help string for the metric.
 pub const REQUEST_DURATION_SECONDS_HELP: &str = "Total number of seconds spent making requests";
 
+/// Expected label names for the metric.
+pub const PROVIDER_SCORE_LABELS: &[&str] = &["provider_node", "chain"];
+/// Help string for the metric.
+pub const PROVIDER_SCORE_HELP: &str =
+    "Estimated seconds a request to a provider of a fallback provider takes";
+
+/// Expected label names for the metric.
+pub const RPC_REMAINING_DAILY_CREDITS_LABELS: &[&str] = &["provider_node", "chain"];
+/// Help string for the metric.
+pub const RPC_REMAINING_DAILY_CREDITS_HELP: &str =
+    "Credits left of the self-imposed daily budget of an RPC endpoint";
+
+/// Expected label names for the metric.
+pub const RPC_THROTTLED_SECONDS_LABELS: &[&str] = &["provider_node", "chain"];
+/// Help string for the metric.
+pub const RPC_THROTTLED_SECONDS_HELP: &str =
+    "Total number of seconds requests waited for the self-imposed rate limit of an RPC endpoint";
+
 /// Container for all the relevant rpc client metrics.
 #[derive(Clone, Builder, Default)]
 pub struct PrometheusClientMetrics {
@@ -70,6 +88,31 @@ pub struct PrometheusClientMetrics {
     ///   might still be an "error" but not one with the transport layer.
     #[builder(setter(into, strip_option), default)]
     pub request_duration_seconds: Option<CounterVec>,
+
+    /// Score of each provider of a fallback provider, lower is better.
+    /// - `provider_node`: node this is connecting to, e.g. `alchemy.com`,
+    ///   `quicknode.pro`, or `localhost:8545`.
+    /// - `chain`: chain name (or chain id if the name is unknown) of the chain
+    ///   the provider is for.
+    #[builder(setter(into, strip_option), default)]
+    pub provider_score: Option<GaugeVec>,
+
+    /// Credits left of the self-imposed daily budget of an RPC endpoint.
+    /// - `provider_node`: node this is connecting to, e.g. `alchemy.com`,
+    ///   `quicknode.pro`, or `localhost:8545`.
+    /// - `chain`: chain name (or chain id if the name is unknown) of the chain
+    ///   the endpoint is for.
+    #[builder(setter(into, strip_option), default)]
+    pub rpc_remaining_daily_credits: Option<GaugeVec>,
+
+    /// Total number of seconds requests waited for the self-imposed rate
+    /// limit of an RPC endpoint.
+    /// - `provider_node`: node this is connecting to, e.g. `alchemy.com`,
+    ///   `quicknode.pro`, or `localhost:8545`.
+    /// - `chain`: chain name (or chain id if the name is unknown) of the chain
+    ///   the endpoint is for.
+    #[builder(setter(into, strip_option), default)]
+    pub rpc_throttled_seconds: Option<CounterVec>,
 }
 
 impl PrometheusClientMetrics {
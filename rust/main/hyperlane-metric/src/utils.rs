@@ -16,6 +16,14 @@ pub fn url_to_host_info(url: &Url) -> Option<String> {
     }
 }
 
+/// converts urls into the host:port strings identifying their providers in
+/// the `provider_node` label
+pub fn urls_to_provider_nodes<'a>(urls: impl IntoIterator<Item = &'a Url>) -> Vec<String> {
+    urls.into_iter()
+        .map(|url| url_to_host_info(url).unwrap_or_else(|| "unknown".to_owned()))
+        .collect()
+}
+
 fn schemed_url_to_host_info(url: &Url) -> Option<String> {
     if let Some(host) = url.host_str() {
         let mut s = String::new();
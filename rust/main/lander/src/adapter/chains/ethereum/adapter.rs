@@ -31,7 +31,8 @@ use hyperlane_core::{
 };
 use hyperlane_ethereum::multicall::BatchCache;
 use hyperlane_ethereum::{
-    multicall, EthereumReorgPeriod, EvmProviderForLander, LanderProviderBuilder,
+    multicall, EthereumReorgPeriod, EvmProviderForLander, LanderProviderBuilder, PrivateRelay,
+    PrivateRelayClient, Signers,
 };
 
 use crate::adapter::chains::ethereum::metrics::{
@@ -47,14 +48,21 @@ use crate::{
 };
 
 use super::{
-    gas_price::GasPrice, metrics::EthereumAdapterMetrics, nonce::NonceManager,
-    transaction::Precursor, EthereumTxPrecursor,
+    gas_price::GasPrice,
+    metrics::EthereumAdapterMetrics,
+    nonce::NonceManager,
+    signer_pool::{NonceLane, SignerPool, INSUFFICIENT_FUNDS_ERRORS},
+    transaction::Precursor,
+    EthereumTxPrecursor,
 };
 
 mod gas_limit_estimator;
 mod gas_price;
+mod private_relay;
 mod tx_status_checker;
 
+pub use private_relay::PrivateRelaySubmitter;
+
 /// Error strings that indicate a nonce conflict or duplicate transaction.
 /// Matched case-insensitively against the lowercased error message.
 const NONCE_TOO_LOW_ERRORS: [&str; 4] = [
@@ -65,6 +73,7 @@ const NONCE_TOO_LOW_ERRORS: [&str; 4] = [
     "tx already exist in the fetch queue",
 ];
 const DEFAULT_MINIMUM_TIME_BETWEEN_RESUBMISSIONS: Duration = Duration::from_secs(1);
+const MINIMUM_TIME_BETWEEN_BALANCE_REFRESHES: Duration = Duration::from_secs(30);
 
 pub struct EthereumAdapter {
     pub estimated_block_time: Duration,
@@ -73,13 +82,17 @@ pub struct EthereumAdapter {
     pub submission_config: OpSubmissionConfig,
     pub provider: Arc<dyn EvmProviderForLander>,
     pub reorg_period: EthereumReorgPeriod,
-    pub nonce_manager: NonceManager,
+    /// Nonce manager of the signer configured for the chain, shared with the
+    /// primary lane of the signer pool
+    pub nonce_manager: Arc<NonceManager>,
+    pub signer_pool: SignerPool,
     pub batch_cache: Arc<Mutex<BatchCache>>,
     pub batch_contract_address: H256,
     pub payload_db: Arc<dyn PayloadDb>,
     pub signer: H160,
     pub minimum_time_between_resubmissions: Duration,
     pub metrics: EthereumAdapterMetrics,
+    pub private_relay: Option<PrivateRelaySubmitter>,
 }
 
 impl EthereumAdapter {
@@ -110,18 +123,77 @@ impl EthereumAdapter {
             .get_signer()
             .ok_or_else(|| eyre!("No signer found in provider for domain {}", domain))?;
 
-        let metrics = EthereumAdapterMetrics::new(
-            conf.domain.clone(),
-            dispatcher_metrics.get_batched_transactions(),
-            dispatcher_metrics.get_finalized_nonce(domain, &signer.to_string()),
-            dispatcher_metrics.get_upper_nonce(domain, &signer.to_string()),
-            dispatcher_metrics.get_mismatched_nonce(domain, &signer.to_string()),
+        let primary_lane = Self::build_lane(
+            &conf,
+            signer,
+            provider.clone(),
+            db.clone(),
+            &dispatcher_metrics,
+        )?;
+        let adapter_metrics = primary_lane.metrics.clone();
+        let nonce_manager = primary_lane.nonce_manager.clone();
+
+        let mut additional_lanes: Vec<NonceLane> = Vec::new();
+        for signer_conf in &conf.additional_signers {
+            let lane_signer: Signers = signer_conf.build().await?;
+            let lane_provider = conf
+                .build_ethereum_with_signer(
+                    &connection_conf,
+                    &locator,
+                    metrics,
+                    LanderProviderBuilder {},
+                    Some(lane_signer),
+                )
+                .await?;
+            let address = lane_provider
+                .get_signer()
+                .ok_or_else(|| eyre!("No signer found in provider for domain {}", domain))?;
+            if address == signer || additional_lanes.iter().any(|l| l.address == address) {
+                warn!(?address, domain, "skipping duplicate signer");
+                continue;
+            }
+            additional_lanes.push(Self::build_lane(
+                &conf,
+                address,
+                lane_provider,
+                db.clone(),
+                &dispatcher_metrics,
+            )?);
+        }
+        if !additional_lanes.is_empty() {
+            info!(
+                domain,
+                lanes = additional_lanes.len().saturating_add(1),
+                "submitting transactions with multiple signers"
+            );
+        }
+        let signer_pool = SignerPool::new(
+            primary_lane,
+            additional_lanes,
+            conf.estimated_block_time
+                .max(MINIMUM_TIME_BETWEEN_BALANCE_REFRESHES),
         );
 
         let payload_db = db.clone() as Arc<dyn PayloadDb>;
 
         let reorg_period = EthereumReorgPeriod::try_from(&conf.reorg_period)?;
-        let nonce_manager = NonceManager::new(&conf, db, provider.clone(), metrics.clone()).await?;
+
+        let private_relay = match &connection_conf.private_relay {
+            Some(relay_conf) => {
+                // the relayer key also authenticates requests to the private relay
+                let relay_signer = match &conf.signer {
+                    Some(signer_conf) => Some(signer_conf.build::<Signers>().await?),
+                    None => None,
+                };
+                let relay = PrivateRelayClient::new(relay_conf.clone(), relay_signer);
+                info!(?relay, "submitting transactions through private relay");
+                Some(PrivateRelaySubmitter::new(
+                    relay_conf.clone(),
+                    Arc::new(relay) as Arc<dyn PrivateRelay>,
+                ))
+            }
+            None => None,
+        };
 
         let adapter = Self {
             estimated_block_time: conf.estimated_block_time,
@@ -131,19 +203,68 @@ impl EthereumAdapter {
             provider,
             reorg_period,
             nonce_manager,
+            signer_pool,
             batch_cache: Default::default(),
             batch_contract_address: connection_conf.batch_contract_address(),
             payload_db,
             signer,
             minimum_time_between_resubmissions: DEFAULT_MINIMUM_TIME_BETWEEN_RESUBMISSIONS,
-            metrics,
+            metrics: adapter_metrics,
+            private_relay,
         };
 
         Ok(adapter)
     }
 
-    async fn calculate_nonce(&self, tx: &Transaction) -> Result<U256, LanderError> {
-        self.nonce_manager.calculate_next_nonce(tx).await
+    fn build_lane(
+        conf: &ChainConf,
+        address: Address,
+        provider: Arc<dyn EvmProviderForLander>,
+        db: Arc<HyperlaneRocksDB>,
+        dispatcher_metrics: &DispatcherMetrics,
+    ) -> eyre::Result<NonceLane> {
+        let domain = conf.domain.name();
+        let signer = address.to_string();
+        let metrics = EthereumAdapterMetrics::new(
+            conf.domain.clone(),
+            dispatcher_metrics.get_batched_transactions(),
+            dispatcher_metrics.get_finalized_nonce(domain, &signer),
+            dispatcher_metrics.get_upper_nonce(domain, &signer),
+            dispatcher_metrics.get_mismatched_nonce(domain, &signer),
+            dispatcher_metrics.get_signer_balance(domain, &signer),
+            dispatcher_metrics.get_signer_quarantined(domain, &signer),
+        );
+        let nonce_manager =
+            NonceManager::new(address, conf, db, provider.clone(), metrics.clone())?;
+        Ok(NonceLane::new(
+            address,
+            provider,
+            Arc::new(nonce_manager),
+            metrics,
+        ))
+    }
+
+    /// Returns the lane of the signer which submits the transaction.
+    /// A transaction which has not been assigned to a lane nor a nonce yet is
+    /// moved to the least loaded lane first, so that its gas is estimated and
+    /// its nonce is assigned for the same signer.
+    async fn assign_lane(&self, tx: &mut Transaction) -> Result<&NonceLane, LanderError> {
+        let precursor = tx.precursor_mut();
+        if !precursor.lane_assigned && precursor.tx.nonce().is_none() {
+            let lane = self.signer_pool.least_loaded_lane().await?;
+            precursor.tx.set_from(lane.address);
+            precursor.lane_assigned = true;
+            return Ok(lane);
+        }
+
+        let from = precursor.tx.from().ok_or(LanderError::TxSubmissionError(
+            "Transaction missing address".to_string(),
+        ))?;
+        self.signer_pool.lane(from).ok_or_else(|| {
+            LanderError::TxSubmissionError(format!(
+                "No signer configured for transaction address {from:?}"
+            ))
+        })
     }
 
     async fn estimate_gas_price(&self, tx: &Transaction) -> Result<GasPrice, LanderError> {
@@ -313,10 +434,10 @@ impl EthereumAdapter {
         Ok(payloads)
     }
 
-    fn create_precursors(&self, payloads: &[FullPayload]) -> Vec<EthereumTxPrecursor> {
+    fn create_precursors(payloads: &[FullPayload], signer: Address) -> Vec<EthereumTxPrecursor> {
         payloads
             .iter()
-            .map(|p| EthereumTxPrecursor::from_payload(p, self.signer))
+            .map(|p| EthereumTxPrecursor::from_payload(p, signer))
             .collect::<Vec<_>>()
     }
 
@@ -383,6 +504,55 @@ impl EthereumAdapter {
         Ok(vec![tx_building_result])
     }
 
+    async fn get_reprocess_txs_for_lane(lane: &NonceLane) -> Result<Vec<Transaction>, LanderError> {
+        let old_finalized_nonce = lane
+            .nonce_manager
+            .state
+            .get_finalized_nonce()
+            .await?
+            .unwrap_or_default();
+        lane.nonce_manager.nonce_updater.update_boundaries().await?;
+        let new_finalized_nonce = lane
+            .nonce_manager
+            .state
+            .get_finalized_nonce()
+            .await?
+            .unwrap_or_default();
+
+        if new_finalized_nonce >= old_finalized_nonce {
+            return Ok(Vec::new());
+        }
+
+        warn!(
+            ?old_finalized_nonce,
+            ?new_finalized_nonce,
+            signer = ?lane.address,
+            "New finalized nonce is lower than old finalized nonce"
+        );
+
+        let mut txs = Vec::new();
+        let mut nonce = new_finalized_nonce.saturating_add(U256::one());
+        while nonce <= old_finalized_nonce {
+            let tx_uuid = lane.nonce_manager.state.get_tracked_tx_uuid(&nonce).await?;
+            if tx_uuid == TransactionUuid::default() {
+                debug!(
+                    ?nonce,
+                    "No tracked transaction UUID for nonce in reorg range"
+                );
+            } else if let Some(tx) = lane.nonce_manager.state.get_tracked_tx(&tx_uuid).await? {
+                txs.push(tx);
+            } else {
+                debug!(
+                    ?nonce,
+                    ?tx_uuid,
+                    "No transaction found for nonce in reorg range"
+                );
+            }
+            nonce = nonce.saturating_add(U256::one());
+        }
+        Ok(txs)
+    }
+
     pub fn metrics(&self) -> &EthereumAdapterMetrics {
         &self.metrics
     }
@@ -597,6 +767,8 @@ impl AdaptsChain for EthereumAdapter {
     }
 
     async fn estimate_tx(&self, tx: &mut Transaction) -> Result<(), LanderError> {
+        let lane = self.assign_lane(tx).await?;
+
         if tx.precursor().tx.gas().is_some() {
             debug!(
                 ?tx,
@@ -609,7 +781,7 @@ impl AdaptsChain for EthereumAdapter {
             // No batching, estimate gas limit for the single payload
             let precursor = tx.precursor_mut();
             return gas_limit_estimator::estimate_gas_limit(
-                self.provider.clone(),
+                lane.provider.clone(),
                 precursor,
                 &self.transaction_overrides,
                 &self.domain,
@@ -620,13 +792,13 @@ impl AdaptsChain for EthereumAdapter {
 
         // Batching case, estimate batch
         let payloads = self.load_payloads(tx).await?;
-        let mut precursors = self.create_precursors(&payloads);
+        let mut precursors = Self::create_precursors(&payloads, lane.address);
 
         let payload_estimate_futures = precursors
             .iter_mut()
             .map(|p| {
                 gas_limit_estimator::estimate_gas_limit(
-                    self.provider.clone(),
+                    lane.provider.clone(),
                     p,
                     &self.transaction_overrides,
                     &self.domain,
@@ -643,7 +815,7 @@ impl AdaptsChain for EthereumAdapter {
             .map(|p| (p.tx, p.function))
             .collect::<Vec<_>>();
 
-        let gas_limit = self
+        let gas_limit = lane
             .provider
             .estimate_batch(multi_precursor, precursors)
             .await?;
@@ -657,11 +829,13 @@ impl AdaptsChain for EthereumAdapter {
         use super::transaction::Precursor;
         use LanderError::TxAlreadyExists;
 
+        let lane = self.assign_lane(tx).await?;
+
         let tx_for_nonce = tx.clone();
         let tx_for_gas_price = tx.clone();
 
         let (nonce, gas_price) = try_join!(
-            self.calculate_nonce(&tx_for_nonce),
+            lane.nonce_manager.calculate_next_nonce(&tx_for_nonce),
             self.estimate_gas_price(&tx_for_gas_price)
         )?;
 
@@ -669,7 +843,36 @@ impl AdaptsChain for EthereumAdapter {
         // This ensures the nonce is stored even if we decide not to resubmit due to gas price limits
         Self::update_tx_nonce(tx, nonce);
 
-        Self::check_if_resubmission_makes_sense(tx, &gas_price)?;
+        let private_relay = self
+            .private_relay
+            .as_ref()
+            .filter(|relay| relay.is_active_for(tx, chrono::Utc::now()));
+
+        // A transaction which has not landed through the private relay before its deadline
+        // must reach the public mempool, even if its gas price has not changed
+        let falling_back_to_public = private_relay.is_none() && tx.precursor().submitted_privately;
+        // The relay drops a transaction it could not include by its max block, which
+        // usually comes well before the fallback deadline, so it is sent again
+        let private_max_block_passed = match (private_relay, tx.precursor().private_relay_max_block)
+        {
+            (Some(relay), Some(max_block)) if tx.precursor().submitted_privately => {
+                relay.max_block_passed(&lane.provider, max_block).await?
+            }
+            _ => false,
+        };
+        if falling_back_to_public {
+            info!(
+                ?tx,
+                "private relay deadline passed, falling back to public mempool"
+            );
+        } else if private_max_block_passed {
+            info!(
+                ?tx,
+                "private relay max block passed, submitting transaction to private relay again"
+            );
+        } else {
+            Self::check_if_resubmission_makes_sense(tx, &gas_price)?;
+        }
 
         Self::update_tx_gas_price(tx, gas_price);
 
@@ -677,12 +880,28 @@ impl AdaptsChain for EthereumAdapter {
 
         let precursor = tx.precursor();
 
-        let send_result = self.provider.send(&precursor.tx, &precursor.function).await;
-        let hash = match send_result {
-            Ok(hash) => hash,
+        let send_result = match private_relay {
+            Some(relay) => relay
+                .submit(&lane.provider, &precursor.tx)
+                .await
+                .map(|(hash, max_block)| (hash, Some(max_block))),
+            None => lane
+                .provider
+                .send(&precursor.tx, &precursor.function)
+                .await
+                .map(|hash| (hash, None)),
+        };
+        let (hash, private_relay_max_block) = match send_result {
+            Ok(sent) => sent,
             Err(e) => {
                 warn!(?e, "submitting transaction error");
                 let err_str = e.to_string().to_lowercase();
+                if INSUFFICIENT_FUNDS_ERRORS
+                    .iter()
+                    .any(|s| err_str.contains(s))
+                {
+                    lane.quarantine().await;
+                }
                 return if NONCE_TOO_LOW_ERRORS.iter().any(|s| err_str.contains(s)) {
                     Err(TxAlreadyExists)
                 } else {
@@ -692,6 +911,8 @@ impl AdaptsChain for EthereumAdapter {
         };
 
         tx.tx_hashes.push(hash.into());
+        tx.precursor_mut().submitted_privately = private_relay.is_some();
+        tx.precursor_mut().private_relay_max_block = private_relay_max_block;
 
         info!(?tx, "submitted transaction");
 
@@ -739,49 +960,9 @@ impl AdaptsChain for EthereumAdapter {
     }
 
     async fn get_reprocess_txs(&self) -> Result<Vec<Transaction>, LanderError> {
-        let old_finalized_nonce = self
-            .nonce_manager
-            .state
-            .get_finalized_nonce()
-            .await?
-            .unwrap_or_default();
-        self.nonce_manager.nonce_updater.update_boundaries().await?;
-        let new_finalized_nonce = self
-            .nonce_manager
-            .state
-            .get_finalized_nonce()
-            .await?
-            .unwrap_or_default();
-
-        if new_finalized_nonce >= old_finalized_nonce {
-            return Ok(Vec::new());
-        }
-
-        warn!(
-            ?old_finalized_nonce,
-            ?new_finalized_nonce,
-            "New finalized nonce is lower than old finalized nonce"
-        );
-
         let mut txs = Vec::new();
-        let mut nonce = new_finalized_nonce.saturating_add(U256::one());
-        while nonce <= old_finalized_nonce {
-            let tx_uuid = self.nonce_manager.state.get_tracked_tx_uuid(&nonce).await?;
-            if tx_uuid == TransactionUuid::default() {
-                debug!(
-                    ?nonce,
-                    "No tracked transaction UUID for nonce in reorg range"
-                );
-            } else if let Some(tx) = self.nonce_manager.state.get_tracked_tx(&tx_uuid).await? {
-                txs.push(tx);
-            } else {
-                debug!(
-                    ?nonce,
-                    ?tx_uuid,
-                    "No transaction found for nonce in reorg range"
-                );
-            }
-            nonce = nonce.saturating_add(U256::one());
+        for lane in self.signer_pool.lanes() {
+            txs.extend(Self::get_reprocess_txs_for_lane(lane).await?);
         }
         Ok(txs)
     }
@@ -800,11 +981,10 @@ impl AdaptsChain for EthereumAdapter {
     }
 
     async fn post_finalized(&self) -> Result<(), LanderError> {
-        self.nonce_manager
-            .nonce_updater
-            .update_boundaries()
-            .await
-            .map_err(Into::into)
+        for lane in self.signer_pool.lanes() {
+            lane.nonce_manager.nonce_updater.update_boundaries().await?;
+        }
+        Ok(())
     }
 
     async fn run_command(&self, action: AdaptsChainAction) -> Result<(), LanderError> {
@@ -815,6 +995,18 @@ impl AdaptsChain for EthereumAdapter {
                     .overwrite_upper_nonce(nonce)
                     .await?;
             }
+            AdaptsChainAction::OverwriteSignerUpperNonce { signer, nonce } => {
+                let lane = self.signer_pool.lane(&signer.into()).ok_or_else(|| {
+                    LanderError::NonRetryableError(format!(
+                        "No signer {signer:?} configured for domain {}",
+                        self.domain
+                    ))
+                })?;
+                lane.nonce_manager
+                    .state
+                    .overwrite_upper_nonce(nonce)
+                    .await?;
+            }
         }
         Ok(())
     }
@@ -124,6 +124,9 @@ pub mod tests {
                 constant: None,
                 state_mutability: ethers::abi::StateMutability::Payable,
             },
+            lane_assigned: false,
+            submitted_privately: false,
+            private_relay_max_block: None,
         };
         let mut provider = MockEvmProvider::new();
 
@@ -171,6 +174,9 @@ pub mod tests {
                 constant: None,
                 state_mutability: ethers::abi::StateMutability::Payable,
             },
+            lane_assigned: false,
+            submitted_privately: false,
+            private_relay_max_block: None,
         };
         let mut provider = MockEvmProvider::new();
 
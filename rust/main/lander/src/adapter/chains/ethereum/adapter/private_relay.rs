@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::BlockNumber;
+use tracing::{info, warn};
+
+use hyperlane_core::{ChainCommunicationError, ChainResult, H256};
+use hyperlane_ethereum::{
+    EvmProviderForLander, PrivateRelay, PrivateRelayConf, PrivateRelayMethod,
+};
+
+use crate::transaction::Transaction;
+
+/// Submits transactions to a private relay until the fallback deadline
+/// of a transaction has passed.
+pub struct PrivateRelaySubmitter {
+    pub conf: PrivateRelayConf,
+    pub relay: Arc<dyn PrivateRelay>,
+}
+
+impl PrivateRelaySubmitter {
+    pub fn new(conf: PrivateRelayConf, relay: Arc<dyn PrivateRelay>) -> Self {
+        Self { conf, relay }
+    }
+
+    /// Time after which the transaction is submitted to the public mempool
+    pub fn fallback_deadline(&self, tx: &Transaction) -> DateTime<Utc> {
+        chrono::Duration::from_std(self.conf.fallback_timeout)
+            .ok()
+            .and_then(|timeout| tx.creation_timestamp.checked_add_signed(timeout))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC)
+    }
+
+    /// Whether the transaction should still be routed through the private relay
+    pub fn is_active_for(&self, tx: &Transaction, now: DateTime<Utc>) -> bool {
+        now < self.fallback_deadline(tx)
+    }
+
+    /// Whether the chain head has moved past the last block in which the relay
+    /// may include a transaction submitted with `max_block`. The relay drops
+    /// such a transaction, so it has to be submitted again.
+    pub async fn max_block_passed(
+        &self,
+        provider: &Arc<dyn EvmProviderForLander>,
+        max_block: u64,
+    ) -> ChainResult<bool> {
+        Ok(latest_block_number(provider).await? > max_block)
+    }
+
+    /// Signs the transaction and hands it over to the relay, targeting inclusion
+    /// within the configured number of blocks past the current head.
+    /// Returns the hash of the signed transaction and the last targeted block.
+    pub async fn submit(
+        &self,
+        provider: &Arc<dyn EvmProviderForLander>,
+        tx: &TypedTransaction,
+    ) -> ChainResult<(H256, u64)> {
+        let (hash, raw_tx) = provider.sign_transaction(tx).await?;
+        let current_block = latest_block_number(provider).await?;
+        let max_block = current_block.saturating_add(self.conf.target_blocks.max(1));
+
+        match self.conf.method {
+            PrivateRelayMethod::PrivateTransaction => {
+                self.relay
+                    .send_private_transaction(&raw_tx, max_block)
+                    .await?;
+            }
+            PrivateRelayMethod::Bundle => {
+                // bundles are only valid for a single block, so one is sent for
+                // every block in the target range
+                let mut accepted = 0usize;
+                let mut last_err = None;
+                for block in current_block.saturating_add(1)..=max_block {
+                    match self.relay.send_bundle(&[raw_tx.clone()], block).await {
+                        Ok(_) => accepted = accepted.saturating_add(1),
+                        Err(err) => {
+                            warn!(?err, block, "Failed to send bundle to private relay");
+                            last_err = Some(err);
+                        }
+                    }
+                }
+                if accepted == 0 {
+                    return Err(last_err.unwrap_or_else(|| {
+                        ChainCommunicationError::CustomError(
+                            "No bundle was sent to private relay".to_string(),
+                        )
+                    }));
+                }
+            }
+        }
+
+        info!(
+            ?hash,
+            method = ?self.conf.method,
+            current_block,
+            max_block,
+            "submitted transaction to private relay"
+        );
+        Ok((hash, max_block))
+    }
+}
+
+async fn latest_block_number(provider: &Arc<dyn EvmProviderForLander>) -> ChainResult<u64> {
+    provider
+        .get_block(BlockNumber::Latest)
+        .await?
+        .and_then(|block| block.number)
+        .map(|number| number.as_u64())
+        .ok_or_else(|| ChainCommunicationError::CustomError("Latest block not found".to_string()))
+}
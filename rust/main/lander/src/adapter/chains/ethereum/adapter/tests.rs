@@ -1,4 +1,6 @@
 mod check_if_resubmission_makes_sense;
 mod tests_build;
+mod tests_private_relay;
+mod tests_signer_pool;
 mod tests_submit;
 mod vm_specific_metrics;
@@ -0,0 +1,230 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use ethers::types::{Address, Bytes};
+use hyperlane_core::{ChainResult, H256, H512};
+use hyperlane_ethereum::{PrivateRelay, PrivateRelayConf, PrivateRelayMethod};
+
+use crate::adapter::chains::ethereum::adapter::PrivateRelaySubmitter;
+use crate::adapter::chains::ethereum::tests::MockEvmProvider;
+use crate::adapter::chains::ethereum::transaction::Precursor;
+use crate::adapter::chains::ethereum::EthereumAdapter;
+use crate::adapter::AdaptsChain;
+use crate::dispatcher::PayloadDb;
+use crate::tests::evm::test_utils::{
+    build_process_tx, mock_ethereum_adapter, mocked_submission_provider,
+};
+use crate::tests::test_utils::tmp_dbs;
+
+/// Latest block of the mocked submission provider
+const CURRENT_BLOCK: u64 = 42;
+
+mockall::mock! {
+    pub Relay {}
+
+    #[async_trait]
+    impl PrivateRelay for Relay {
+        async fn send_private_transaction(
+            &self,
+            raw_tx: &Bytes,
+            max_block_number: u64,
+        ) -> ChainResult<H256>;
+
+        async fn send_bundle(&self, raw_txs: &[Bytes], block_number: u64) -> ChainResult<H256>;
+    }
+}
+
+fn relay_conf(method: PrivateRelayMethod, fallback_timeout: Duration) -> PrivateRelayConf {
+    PrivateRelayConf {
+        url: "http://localhost:8545".parse().unwrap(),
+        method,
+        target_blocks: 3,
+        fallback_timeout,
+    }
+}
+
+fn mocked_provider(signed_hash: H256) -> MockEvmProvider {
+    let mut provider = mocked_submission_provider();
+    provider
+        .expect_sign_transaction()
+        .returning(move |_| Ok((signed_hash, Bytes::from(vec![0x02, 0x01]))));
+    provider
+}
+
+fn adapter_with_relay(
+    provider: MockEvmProvider,
+    relay: MockRelay,
+    conf: PrivateRelayConf,
+) -> (EthereumAdapter, Arc<dyn PayloadDb>) {
+    let (payload_db, tx_db, nonce_db) = tmp_dbs();
+    let mut adapter = mock_ethereum_adapter(
+        provider,
+        payload_db.clone(),
+        tx_db,
+        nonce_db,
+        Address::random(),
+        Duration::from_millis(100),
+        Duration::from_millis(100),
+    );
+    adapter.private_relay = Some(PrivateRelaySubmitter::new(conf, Arc::new(relay)));
+    (adapter, payload_db)
+}
+
+#[tokio::test]
+async fn test_submit_sends_private_transaction_to_relay() {
+    let signed_hash = H256::random();
+    let mut provider = mocked_provider(signed_hash);
+    provider.expect_send().never();
+
+    let mut relay = MockRelay::new();
+    relay
+        .expect_send_private_transaction()
+        .withf(|raw_tx, max_block| raw_tx.to_vec() == vec![0x02, 0x01] && *max_block == 45)
+        .times(1)
+        .returning(move |_, _| Ok(signed_hash));
+
+    let conf = relay_conf(
+        PrivateRelayMethod::PrivateTransaction,
+        Duration::from_secs(600),
+    );
+    let (adapter, payload_db) = adapter_with_relay(provider, relay, conf);
+    let mut tx = build_process_tx(&adapter, &payload_db).await;
+
+    adapter.submit(&mut tx).await.unwrap();
+
+    assert_eq!(tx.tx_hashes, vec![H512::from(signed_hash)]);
+    assert!(tx.precursor().submitted_privately);
+}
+
+#[tokio::test]
+async fn test_submit_sends_bundle_for_each_target_block() {
+    let signed_hash = H256::random();
+    let mut provider = mocked_provider(signed_hash);
+    provider.expect_send().never();
+
+    let targeted_blocks = Arc::new(Mutex::new(Vec::new()));
+    let targeted_blocks_clone = targeted_blocks.clone();
+    let mut relay = MockRelay::new();
+    relay
+        .expect_send_bundle()
+        .times(3)
+        .returning(move |raw_txs, block| {
+            assert_eq!(raw_txs.len(), 1);
+            targeted_blocks_clone.lock().unwrap().push(block);
+            Ok(H256::random())
+        });
+
+    let conf = relay_conf(PrivateRelayMethod::Bundle, Duration::from_secs(600));
+    let (adapter, payload_db) = adapter_with_relay(provider, relay, conf);
+    let mut tx = build_process_tx(&adapter, &payload_db).await;
+
+    adapter.submit(&mut tx).await.unwrap();
+
+    assert_eq!(*targeted_blocks.lock().unwrap(), vec![43, 44, 45]);
+    // the transaction hash is tracked, rather than the bundle hash
+    assert_eq!(tx.tx_hashes, vec![H512::from(signed_hash)]);
+    assert!(tx.precursor().submitted_privately);
+}
+
+#[tokio::test]
+async fn test_submit_falls_back_to_public_mempool_after_deadline() {
+    let signed_hash = H256::random();
+    let public_hash = H256::random();
+    let mut provider = mocked_provider(signed_hash);
+    provider
+        .expect_send()
+        .times(1)
+        .returning(move |_, _| Ok(public_hash));
+
+    let mut relay = MockRelay::new();
+    relay
+        .expect_send_private_transaction()
+        .times(1)
+        .returning(move |_, _| Ok(signed_hash));
+
+    let conf = relay_conf(
+        PrivateRelayMethod::PrivateTransaction,
+        Duration::from_secs(600),
+    );
+    let (mut adapter, payload_db) = adapter_with_relay(provider, relay, conf);
+    let mut tx = build_process_tx(&adapter, &payload_db).await;
+
+    adapter.submit(&mut tx).await.unwrap();
+    assert!(tx.precursor().submitted_privately);
+
+    // the transaction was not included before the deadline
+    if let Some(relay) = adapter.private_relay.as_mut() {
+        relay.conf.fallback_timeout = Duration::ZERO;
+    }
+    adapter.submit(&mut tx).await.unwrap();
+
+    assert_eq!(
+        tx.tx_hashes,
+        vec![H512::from(signed_hash), H512::from(public_hash)]
+    );
+    assert!(!tx.precursor().submitted_privately);
+}
+
+#[tokio::test]
+async fn test_submit_errors_when_relay_rejects_all_bundles() {
+    let signed_hash = H256::random();
+    let mut provider = mocked_provider(signed_hash);
+    provider.expect_send().never();
+
+    let mut relay = MockRelay::new();
+    relay.expect_send_bundle().times(3).returning(|_, _| {
+        Err(hyperlane_core::ChainCommunicationError::CustomError(
+            "bundle rejected".to_string(),
+        ))
+    });
+
+    let conf = relay_conf(PrivateRelayMethod::Bundle, Duration::from_secs(600));
+    let (adapter, payload_db) = adapter_with_relay(provider, relay, conf);
+    let mut tx = build_process_tx(&adapter, &payload_db).await;
+
+    let result = adapter.submit(&mut tx).await;
+
+    assert!(result.is_err());
+    assert!(tx.tx_hashes.is_empty());
+}
+
+#[tokio::test]
+async fn test_submit_resends_to_relay_once_max_block_passed() {
+    let signed_hash = H256::random();
+    let mut provider = mocked_provider(signed_hash);
+    provider.expect_send().never();
+
+    let mut relay = MockRelay::new();
+    relay
+        .expect_send_private_transaction()
+        .times(2)
+        .returning(move |_, _| Ok(signed_hash));
+
+    let conf = relay_conf(
+        PrivateRelayMethod::PrivateTransaction,
+        Duration::from_secs(600),
+    );
+    let (mut adapter, payload_db) = adapter_with_relay(provider, relay, conf);
+    // the gas price can't be escalated past the estimate
+    adapter.transaction_overrides.gas_price_cap_multiplier = Some(1.into());
+    let mut tx = build_process_tx(&adapter, &payload_db).await;
+
+    adapter.submit(&mut tx).await.unwrap();
+    assert_eq!(tx.precursor().private_relay_max_block, Some(45));
+
+    // the relay may still include the transaction, so it isn't sent again
+    // with the same gas price
+    assert!(adapter.submit(&mut tx).await.is_err());
+
+    // the chain moved past the targeted blocks before the fallback deadline
+    tx.precursor_mut().private_relay_max_block = Some(CURRENT_BLOCK - 1);
+    adapter.submit(&mut tx).await.unwrap();
+
+    assert_eq!(
+        tx.tx_hashes,
+        vec![H512::from(signed_hash), H512::from(signed_hash)]
+    );
+    assert!(tx.precursor().submitted_privately);
+    assert_eq!(tx.precursor().private_relay_max_block, Some(45));
+}
@@ -0,0 +1,242 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::{Address, U256 as EthersU256};
+use hyperlane_core::{ChainCommunicationError, H256, U256};
+
+use crate::adapter::chains::ethereum::tests::MockEvmProvider;
+use crate::adapter::chains::ethereum::transaction::Precursor;
+use crate::adapter::chains::ethereum::{EthereumAdapter, SignerPool};
+use crate::adapter::AdaptsChain;
+use crate::dispatcher::PayloadDb;
+use crate::tests::evm::test_utils::{
+    build_process_tx, mock_ethereum_adapter, mock_nonce_lane, mocked_submission_provider,
+};
+use crate::tests::test_utils::tmp_dbs;
+use crate::AdaptsChainAction;
+
+fn mocked_provider() -> MockEvmProvider {
+    let mut provider = mocked_submission_provider();
+    provider
+        .expect_get_native_balance()
+        .returning(|_| Ok(U256::from(1000)));
+    provider
+}
+
+/// Adapter with one additional signer next to the primary one
+fn adapter_with_additional_lane(
+    primary_provider: MockEvmProvider,
+    lane_provider: MockEvmProvider,
+) -> (EthereumAdapter, Arc<dyn PayloadDb>, Address) {
+    let (payload_db, tx_db, nonce_db) = tmp_dbs();
+    let block_time = Duration::from_millis(100);
+    let mut adapter = mock_ethereum_adapter(
+        primary_provider,
+        payload_db.clone(),
+        tx_db.clone(),
+        nonce_db.clone(),
+        Address::random(),
+        block_time,
+        block_time,
+    );
+
+    let primary = mock_nonce_lane(
+        adapter.provider.clone(),
+        tx_db.clone(),
+        nonce_db.clone(),
+        adapter.signer,
+        block_time,
+    );
+    let lane_address = Address::random();
+    let lane = mock_nonce_lane(
+        Arc::new(lane_provider),
+        tx_db,
+        nonce_db,
+        lane_address,
+        block_time,
+    );
+    adapter.nonce_manager = primary.nonce_manager.clone();
+    adapter.signer_pool = SignerPool::new(primary, vec![lane], Duration::ZERO);
+
+    (adapter, payload_db, lane_address)
+}
+
+async fn make_primary_busy(adapter: &EthereumAdapter) {
+    let state = &adapter.nonce_manager.state;
+    state
+        .set_finalized_nonce_test(&U256::from(6))
+        .await
+        .unwrap();
+    state.set_upper_nonce_test(&U256::from(20)).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_submit_assigns_transaction_to_least_loaded_lane() {
+    let mut primary_provider = mocked_provider();
+    primary_provider.expect_send().never();
+    let mut lane_provider = mocked_provider();
+    lane_provider
+        .expect_send()
+        .times(1)
+        .returning(|_, _| Ok(H256::random()));
+
+    let (adapter, payload_db, lane_address) =
+        adapter_with_additional_lane(primary_provider, lane_provider);
+    make_primary_busy(&adapter).await;
+    let mut tx = build_process_tx(&adapter, &payload_db).await;
+
+    adapter.submit(&mut tx).await.unwrap();
+
+    let precursor = tx.precursor();
+    assert_eq!(precursor.tx.from(), Some(&lane_address));
+    // the lane has its own nonce sequence
+    assert_eq!(precursor.tx.nonce(), Some(&EthersU256::from(7)));
+}
+
+#[tokio::test]
+async fn test_submit_keeps_transaction_on_its_lane_when_resubmitting() {
+    let mut primary_provider = mocked_provider();
+    primary_provider.expect_send().never();
+    let mut lane_provider = mocked_provider();
+    lane_provider
+        .expect_send()
+        .times(2)
+        .returning(|_, _| Ok(H256::random()));
+
+    let (adapter, payload_db, lane_address) =
+        adapter_with_additional_lane(primary_provider, lane_provider);
+    make_primary_busy(&adapter).await;
+    let mut tx = build_process_tx(&adapter, &payload_db).await;
+    adapter.submit(&mut tx).await.unwrap();
+
+    // the lane becomes busier than the primary one
+    let state = &adapter
+        .signer_pool
+        .lane(&lane_address)
+        .unwrap()
+        .nonce_manager
+        .state;
+    state.set_upper_nonce_test(&U256::from(50)).await.unwrap();
+    adapter.submit(&mut tx).await.unwrap();
+
+    assert_eq!(tx.precursor().tx.from(), Some(&lane_address));
+}
+
+#[tokio::test]
+async fn test_submit_quarantines_lane_without_funds() {
+    let mut primary_provider = mocked_provider();
+    primary_provider.expect_send().never();
+    let mut lane_provider = mocked_provider();
+    lane_provider.expect_send().returning(|_, _| {
+        Err(ChainCommunicationError::CustomError(
+            "insufficient funds for gas * price + value".to_string(),
+        ))
+    });
+
+    let (adapter, payload_db, lane_address) =
+        adapter_with_additional_lane(primary_provider, lane_provider);
+    make_primary_busy(&adapter).await;
+    let mut tx = build_process_tx(&adapter, &payload_db).await;
+
+    let result = adapter.submit(&mut tx).await;
+
+    assert!(result.is_err());
+    let lane = adapter.signer_pool.lane(&lane_address).unwrap();
+    assert!(lane.is_quarantined().await);
+}
+
+#[tokio::test]
+async fn test_estimate_assigns_lane_whose_signer_submits() {
+    let mut primary_provider = mocked_provider();
+    primary_provider.expect_estimate_gas_limit().never();
+    primary_provider.expect_send().never();
+    let mut lane_provider = mocked_provider();
+    lane_provider
+        .expect_estimate_gas_limit()
+        .times(1)
+        .returning(|_, _| Ok(U256::from(50000)));
+    lane_provider
+        .expect_send()
+        .times(1)
+        .returning(|_, _| Ok(H256::random()));
+
+    let (adapter, payload_db, lane_address) =
+        adapter_with_additional_lane(primary_provider, lane_provider);
+    make_primary_busy(&adapter).await;
+    let mut tx = build_process_tx(&adapter, &payload_db).await;
+    // the gas limit wasn't estimated yet
+    if let TypedTransaction::Eip1559(request) = &mut tx.precursor_mut().tx {
+        request.gas = None;
+    }
+
+    adapter.estimate_tx(&mut tx).await.unwrap();
+    assert_eq!(tx.precursor().tx.from(), Some(&lane_address));
+
+    // the primary lane becomes the least loaded one before submission
+    let lane_state = &adapter
+        .signer_pool
+        .lane(&lane_address)
+        .unwrap()
+        .nonce_manager
+        .state;
+    lane_state
+        .set_upper_nonce_test(&U256::from(50))
+        .await
+        .unwrap();
+    adapter.submit(&mut tx).await.unwrap();
+
+    assert_eq!(tx.precursor().tx.from(), Some(&lane_address));
+}
+
+#[tokio::test]
+async fn test_overwrite_upper_nonce_of_additional_signer() {
+    let (adapter, _, lane_address) =
+        adapter_with_additional_lane(mocked_provider(), mocked_provider());
+    make_primary_busy(&adapter).await;
+    let lane_state = &adapter
+        .signer_pool
+        .lane(&lane_address)
+        .unwrap()
+        .nonce_manager
+        .state;
+    lane_state
+        .set_finalized_nonce_test(&U256::from(6))
+        .await
+        .unwrap();
+    lane_state
+        .set_upper_nonce_test(&U256::from(40))
+        .await
+        .unwrap();
+
+    adapter
+        .run_command(AdaptsChainAction::OverwriteSignerUpperNonce {
+            signer: lane_address.into(),
+            nonce: Some(30),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        lane_state.get_upper_nonce_test().await.unwrap(),
+        U256::from(30)
+    );
+    // the signer configured for the chain is left alone
+    assert_eq!(
+        adapter
+            .nonce_manager
+            .state
+            .get_upper_nonce_test()
+            .await
+            .unwrap(),
+        U256::from(20)
+    );
+
+    let unknown_signer = adapter
+        .run_command(AdaptsChainAction::OverwriteSignerUpperNonce {
+            signer: Address::random().into(),
+            nonce: Some(10),
+        })
+        .await;
+    assert!(unknown_signer.is_err());
+}
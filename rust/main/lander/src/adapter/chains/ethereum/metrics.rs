@@ -1,11 +1,12 @@
 use ethers_core::abi::Int;
 use prometheus::{
-    opts, register_int_gauge_vec_with_registry, register_int_gauge_with_registry, Encoder,
+    opts, register_int_gauge_vec_with_registry, register_int_gauge_with_registry, Encoder, Gauge,
     IntCounterVec, IntGauge, IntGaugeVec, Registry,
 };
 use serde_json::to_string;
 
-use hyperlane_core::{HyperlaneDomain, U256};
+use hyperlane_core::metrics::agent::u256_as_scaled_f64;
+use hyperlane_core::{HyperlaneDomain, HyperlaneDomainProtocol, U256};
 
 pub const LABEL_BATCHED_TRANSACTION_SUCCESS: &str = "success";
 pub const LABEL_BATCHED_TRANSACTION_FAILED: &str = "failed";
@@ -22,6 +23,10 @@ pub struct EthereumAdapterMetrics {
     /// Counts how many times we've noticed the nonce in tx is different from nonce
     /// stored in db
     mismatch_nonce: IntGauge,
+    /// Native token balance of the signer
+    signer_balance: Gauge,
+    /// Whether the signer is quarantined
+    signer_quarantined: IntGauge,
 }
 
 impl EthereumAdapterMetrics {
@@ -31,6 +36,8 @@ impl EthereumAdapterMetrics {
         finalized_nonce: IntGauge,
         upper_nonce: IntGauge,
         mismatch_nonce: IntGauge,
+        signer_balance: Gauge,
+        signer_quarantined: IntGauge,
     ) -> Self {
         Self {
             domain,
@@ -38,6 +45,8 @@ impl EthereumAdapterMetrics {
             finalized_nonce,
             upper_nonce,
             mismatch_nonce,
+            signer_balance,
+            signer_quarantined,
         }
     }
 
@@ -66,6 +75,17 @@ impl EthereumAdapterMetrics {
     pub fn get_mismatched_nonce(&self) -> &IntGauge {
         &self.mismatch_nonce
     }
+
+    pub fn set_signer_balance(&self, value: &U256) {
+        self.signer_balance.set(u256_as_scaled_f64(
+            *value,
+            HyperlaneDomainProtocol::Ethereum,
+        ));
+    }
+
+    pub fn set_signer_quarantined(&self, quarantined: bool) {
+        self.signer_quarantined.set(quarantined as i64);
+    }
 }
 
 #[cfg(test)]
@@ -83,6 +103,8 @@ impl EthereumAdapterMetrics {
             dispatcher_metrics.get_finalized_nonce(domain, signer),
             dispatcher_metrics.get_upper_nonce(domain, signer),
             dispatcher_metrics.get_mismatched_nonce(domain, signer),
+            dispatcher_metrics.get_signer_balance(domain, signer),
+            dispatcher_metrics.get_signer_quarantined(domain, signer),
         )
     }
 
@@ -93,4 +115,8 @@ impl EthereumAdapterMetrics {
     pub fn get_upper_nonce(&self) -> i64 {
         self.upper_nonce.get()
     }
+
+    pub fn get_signer_quarantined(&self) -> i64 {
+        self.signer_quarantined.get()
+    }
 }
@@ -1,14 +1,13 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use ethers::signers::Signer;
 use ethers_core::types::Address;
 use tracing::{debug, info};
 
 use hyperlane_base::db::HyperlaneRocksDB;
-use hyperlane_base::settings::{ChainConf, SignerConf};
+use hyperlane_base::settings::ChainConf;
 use hyperlane_core::U256;
-use hyperlane_ethereum::{EthereumReorgPeriod, EvmProviderForLander, Signers};
+use hyperlane_ethereum::{EthereumReorgPeriod, EvmProviderForLander};
 
 use crate::dispatcher::TransactionDb;
 use crate::transaction::{Transaction, TransactionUuid};
@@ -28,13 +27,13 @@ pub struct NonceManager {
 }
 
 impl NonceManager {
-    pub async fn new(
+    pub fn new(
+        address: Address,
         chain_conf: &ChainConf,
         db: Arc<HyperlaneRocksDB>,
         provider: Arc<dyn EvmProviderForLander>,
         metrics: EthereumAdapterMetrics,
     ) -> eyre::Result<Self> {
-        let address = Self::address(chain_conf).await?;
         let reorg_period = EthereumReorgPeriod::try_from(&chain_conf.reorg_period)?;
         let block_time = chain_conf.estimated_block_time;
 
@@ -107,16 +106,6 @@ impl NonceManager {
             }
         }
     }
-
-    async fn address(chain_conf: &ChainConf) -> eyre::Result<Address> {
-        let signer_conf = chain_conf
-            .signer
-            .as_ref()
-            .ok_or_else(|| eyre::eyre!("Signer configuration is missing"))?;
-        let signer: Signers = signer_conf.build().await?;
-        let address = signer.address();
-        Ok(address)
-    }
 }
 
 #[cfg(test)]
@@ -30,6 +30,15 @@ impl NonceManagerState {
 
         Ok(())
     }
+
+    /// Number of nonces which were assigned to transactions but are not finalized yet.
+    pub(crate) async fn in_flight_nonces(&self) -> NonceResult<U256> {
+        let (finalized_nonce, upper_nonce) = self.get_boundary_nonces().await?;
+        let first_unfinalized_nonce = finalized_nonce
+            .map(|nonce| nonce.saturating_add(U256::one()))
+            .unwrap_or_default();
+        Ok(upper_nonce.saturating_sub(first_unfinalized_nonce))
+    }
 }
 
 #[cfg(test)]
@@ -219,3 +219,26 @@ async fn test_update_boundary_nonces_multiple_calls_and_idempotency() {
         (finalized3 + 1).as_u64()
     );
 }
+
+#[tokio::test]
+async fn test_in_flight_nonces() {
+    let (_, tx_db, nonce_db) = tmp_dbs();
+    let address = Address::random();
+    let metrics = EthereumAdapterMetrics::dummy_instance();
+    let state = Arc::new(NonceManagerState::new(nonce_db, tx_db, address, metrics));
+
+    // Nothing was assigned yet
+    assert_eq!(state.in_flight_nonces().await.unwrap(), U256::zero());
+
+    // Nonces 0..=3 were assigned, none of them finalized
+    state.set_upper_nonce(&U256::from(4)).await.unwrap();
+    assert_eq!(state.in_flight_nonces().await.unwrap(), U256::from(4));
+
+    // Nonces 0..=1 were finalized
+    state.update_boundary_nonces(&U256::from(1)).await.unwrap();
+    assert_eq!(state.in_flight_nonces().await.unwrap(), U256::from(2));
+
+    // All nonces were finalized
+    state.update_boundary_nonces(&U256::from(3)).await.unwrap();
+    assert_eq!(state.in_flight_nonces().await.unwrap(), U256::zero());
+}
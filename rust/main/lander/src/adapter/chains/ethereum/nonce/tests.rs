@@ -23,6 +23,9 @@ pub fn dummy_tx(
             constant: None,
             state_mutability: Default::default(),
         },
+        lane_assigned: false,
+        submitted_privately: false,
+        private_relay_max_block: None,
     };
     if let Some(n) = nonce {
         precursor.tx.set_nonce(n);
@@ -13,6 +13,18 @@ use ethers_core::types::transaction::eip2718::TypedTransaction::{Eip1559, Eip293
 pub struct EthereumTxPrecursor {
     pub tx: TypedTransaction,
     pub function: Function,
+    /// whether the transaction was assigned to the lane of its `from` signer,
+    /// which then signs every submission of it
+    #[serde(default)]
+    pub lane_assigned: bool,
+    /// whether the latest submission went to a private relay rather than
+    /// to the public mempool
+    #[serde(default)]
+    pub submitted_privately: bool,
+    /// last block in which the private relay may include the latest private
+    /// submission
+    #[serde(default)]
+    pub private_relay_max_block: Option<u64>,
 }
 
 impl Debug for EthereumTxPrecursor {
@@ -29,6 +41,9 @@ impl Debug for EthereumTxPrecursor {
             .field("tx.chain_id", &self.tx.chain_id())
             .field("tx.value", &self.tx.value())
             .field("function.name", &self.function.name)
+            .field("lane_assigned", &self.lane_assigned)
+            .field("submitted_privately", &self.submitted_privately)
+            .field("private_relay_max_block", &self.private_relay_max_block)
             .finish()
     }
 }
@@ -40,6 +55,9 @@ impl PartialEq for EthereumTxPrecursor {
             && self.function.inputs == other.function.inputs
             && self.function.outputs == other.function.outputs
             && self.function.state_mutability == other.function.state_mutability
+            && self.lane_assigned == other.lane_assigned
+            && self.submitted_privately == other.submitted_privately
+            && self.private_relay_max_block == other.private_relay_max_block
     }
 }
 
@@ -53,7 +71,13 @@ impl From<EthereumTxPrecursor> for VmSpecificTxData {
 
 impl EthereumTxPrecursor {
     pub fn new(tx: TypedTransaction, function: Function) -> Self {
-        Self { tx, function }
+        Self {
+            tx,
+            function,
+            lane_assigned: false,
+            submitted_privately: false,
+            private_relay_max_block: None,
+        }
     }
 
     pub fn from_payload(payload: &FullPayload, signer: H160) -> Self {
@@ -0,0 +1,181 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use ethers_core::types::Address;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+use hyperlane_core::U256;
+use hyperlane_ethereum::EvmProviderForLander;
+
+use crate::LanderError;
+
+use super::metrics::EthereumAdapterMetrics;
+use super::nonce::NonceManager;
+
+/// Error strings that indicate the signer cannot pay for the transaction.
+/// Matched case-insensitively against the lowercased error message.
+pub const INSUFFICIENT_FUNDS_ERRORS: [&str; 2] =
+    ["insufficient funds", "sender doesn't have enough funds"];
+
+/// A signer together with its own nonce state. Transactions assigned to a lane
+/// are always signed and submitted by the lane's signer, so a stuck transaction
+/// only blocks the lane it belongs to.
+pub struct NonceLane {
+    pub address: Address,
+    pub provider: Arc<dyn EvmProviderForLander>,
+    pub nonce_manager: Arc<NonceManager>,
+    pub metrics: EthereumAdapterMetrics,
+    funds: Mutex<LaneFunds>,
+}
+
+#[derive(Debug, Default)]
+struct LaneFunds {
+    /// Last observed balance of the signer
+    balance: Option<U256>,
+    /// Balance of the signer at the moment the lane was quarantined.
+    /// The lane is released once the balance grows above this value.
+    quarantined_at: Option<U256>,
+}
+
+impl NonceLane {
+    pub fn new(
+        address: Address,
+        provider: Arc<dyn EvmProviderForLander>,
+        nonce_manager: Arc<NonceManager>,
+        metrics: EthereumAdapterMetrics,
+    ) -> Self {
+        Self {
+            address,
+            provider,
+            nonce_manager,
+            metrics,
+            funds: Mutex::new(LaneFunds::default()),
+        }
+    }
+
+    pub async fn is_quarantined(&self) -> bool {
+        self.funds.lock().await.quarantined_at.is_some()
+    }
+
+    /// Stops assigning new transactions to the lane until its signer is topped up
+    pub async fn quarantine(&self) {
+        let mut funds = self.funds.lock().await;
+        if funds.quarantined_at.is_none() {
+            warn!(
+                signer = ?self.address,
+                balance = ?funds.balance,
+                "quarantining signer lane, signer ran out of funds"
+            );
+            funds.quarantined_at = Some(funds.balance.unwrap_or_default());
+        }
+        self.metrics.set_signer_quarantined(true);
+    }
+
+    async fn update_balance(&self) -> Result<(), LanderError> {
+        let balance = self.provider.get_native_balance(&self.address).await?;
+        self.metrics.set_signer_balance(&balance);
+
+        let mut funds = self.funds.lock().await;
+        funds.balance = Some(balance);
+        match funds.quarantined_at {
+            Some(quarantined_at) if balance > quarantined_at => {
+                info!(
+                    signer = ?self.address,
+                    ?balance,
+                    "signer was topped up, releasing lane from quarantine"
+                );
+                funds.quarantined_at = None;
+            }
+            None if balance.is_zero() => {
+                warn!(signer = ?self.address, "quarantining signer lane, signer has no funds");
+                funds.quarantined_at = Some(balance);
+            }
+            _ => {}
+        }
+        self.metrics
+            .set_signer_quarantined(funds.quarantined_at.is_some());
+        Ok(())
+    }
+}
+
+/// Signers which submit transactions to a single destination.
+/// The primary lane belongs to the signer configured for the chain and is
+/// used when no additional signers are configured.
+pub struct SignerPool {
+    primary: NonceLane,
+    additional: Vec<NonceLane>,
+    balance_refresh_interval: Duration,
+    balances_updated: Mutex<Option<Instant>>,
+}
+
+impl SignerPool {
+    pub fn new(
+        primary: NonceLane,
+        additional: Vec<NonceLane>,
+        balance_refresh_interval: Duration,
+    ) -> Self {
+        Self {
+            primary,
+            additional,
+            balance_refresh_interval,
+            balances_updated: Mutex::new(None),
+        }
+    }
+
+    pub fn lanes(&self) -> impl Iterator<Item = &NonceLane> {
+        std::iter::once(&self.primary).chain(self.additional.iter())
+    }
+
+    pub fn lane(&self, address: &Address) -> Option<&NonceLane> {
+        self.lanes().find(|lane| &lane.address == address)
+    }
+
+    /// Picks the lane with the fewest transactions in flight, skipping quarantined
+    /// lanes. Falls back to the primary lane if every lane is quarantined.
+    pub async fn least_loaded_lane(&self) -> Result<&NonceLane, LanderError> {
+        if self.additional.is_empty() {
+            return Ok(&self.primary);
+        }
+
+        self.update_balances().await;
+
+        let mut least_loaded: Option<(&NonceLane, U256)> = None;
+        for lane in self.lanes() {
+            if lane.is_quarantined().await {
+                continue;
+            }
+            let in_flight = lane.nonce_manager.state.in_flight_nonces().await?;
+            if least_loaded.is_none_or(|(_, min)| in_flight < min) {
+                least_loaded = Some((lane, in_flight));
+            }
+        }
+
+        match least_loaded {
+            Some((lane, _)) => Ok(lane),
+            None => {
+                warn!("all signer lanes are quarantined, falling back to primary signer");
+                Ok(&self.primary)
+            }
+        }
+    }
+
+    /// Refreshes balances of all signers, at most once per refresh interval
+    async fn update_balances(&self) {
+        let mut updated = self.balances_updated.lock().await;
+        if updated.is_some_and(|instant| instant.elapsed() < self.balance_refresh_interval) {
+            return;
+        }
+
+        for lane in self.lanes() {
+            if let Err(err) = lane.update_balance().await {
+                warn!(signer = ?lane.address, ?err, "failed to update signer balance");
+            }
+        }
+        *updated = Some(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,155 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use ethers_core::types::Address;
+
+use hyperlane_core::U256;
+
+use crate::adapter::chains::ethereum::tests::MockEvmProvider;
+use crate::tests::evm::test_utils::mock_nonce_lane;
+use crate::tests::test_utils::tmp_dbs;
+
+use super::{NonceLane, SignerPool};
+
+fn lane_with_balance(balance: Arc<Mutex<U256>>) -> NonceLane {
+    let mut provider = MockEvmProvider::new();
+    provider
+        .expect_get_native_balance()
+        .returning(move |_| Ok(*balance.lock().unwrap()));
+    let (_, tx_db, nonce_db) = tmp_dbs();
+    mock_nonce_lane(
+        Arc::new(provider),
+        tx_db,
+        nonce_db,
+        Address::random(),
+        Duration::from_secs(1),
+    )
+}
+
+async fn set_in_flight(lane: &NonceLane, finalized: u64, upper: u64) {
+    let state = &lane.nonce_manager.state;
+    state
+        .set_finalized_nonce_test(&U256::from(finalized))
+        .await
+        .unwrap();
+    state
+        .set_upper_nonce_test(&U256::from(upper))
+        .await
+        .unwrap();
+}
+
+fn funded() -> Arc<Mutex<U256>> {
+    Arc::new(Mutex::new(U256::from(10).pow(U256::from(18))))
+}
+
+#[tokio::test]
+async fn test_single_lane_does_not_query_balances() {
+    let mut provider = MockEvmProvider::new();
+    provider.expect_get_native_balance().never();
+    let (_, tx_db, nonce_db) = tmp_dbs();
+    let signer = Address::random();
+    let lane = mock_nonce_lane(
+        Arc::new(provider),
+        tx_db,
+        nonce_db,
+        signer,
+        Duration::from_secs(1),
+    );
+    let pool = SignerPool::new(lane, Vec::new(), Duration::ZERO);
+
+    let lane = pool.least_loaded_lane().await.unwrap();
+
+    assert_eq!(lane.address, signer);
+}
+
+#[tokio::test]
+async fn test_least_loaded_lane_is_picked() {
+    let primary = lane_with_balance(funded());
+    let busy = lane_with_balance(funded());
+    let idle = lane_with_balance(funded());
+    set_in_flight(&primary, 10, 15).await;
+    set_in_flight(&busy, 10, 20).await;
+    set_in_flight(&idle, 10, 12).await;
+    let idle_address = idle.address;
+
+    let pool = SignerPool::new(primary, vec![busy, idle], Duration::ZERO);
+
+    let lane = pool.least_loaded_lane().await.unwrap();
+
+    assert_eq!(lane.address, idle_address);
+}
+
+#[tokio::test]
+async fn test_lane_without_funds_is_quarantined_until_topped_up() {
+    let primary = lane_with_balance(funded());
+    let empty_balance = Arc::new(Mutex::new(U256::zero()));
+    let empty = lane_with_balance(empty_balance.clone());
+    // the primary lane is busier, so it is only picked because the other lane has no funds
+    set_in_flight(&primary, 10, 20).await;
+    set_in_flight(&empty, 10, 11).await;
+    let primary_address = primary.address;
+    let empty_address = empty.address;
+
+    let pool = SignerPool::new(primary, vec![empty], Duration::ZERO);
+
+    let lane = pool.least_loaded_lane().await.unwrap();
+    assert_eq!(lane.address, primary_address);
+    assert!(pool.lane(&empty_address).unwrap().is_quarantined().await);
+    assert_eq!(
+        pool.lane(&empty_address)
+            .unwrap()
+            .metrics
+            .get_signer_quarantined(),
+        1
+    );
+
+    // the signer is topped up
+    *empty_balance.lock().unwrap() = U256::from(1000);
+
+    let lane = pool.least_loaded_lane().await.unwrap();
+    assert_eq!(lane.address, empty_address);
+    assert!(!pool.lane(&empty_address).unwrap().is_quarantined().await);
+}
+
+#[tokio::test]
+async fn test_lane_quarantined_after_failed_submission_is_released_after_top_up() {
+    let primary = lane_with_balance(funded());
+    let balance = Arc::new(Mutex::new(U256::from(1000)));
+    let lane = lane_with_balance(balance.clone());
+    set_in_flight(&primary, 10, 20).await;
+    set_in_flight(&lane, 10, 11).await;
+    let lane_address = lane.address;
+
+    let pool = SignerPool::new(primary, vec![lane], Duration::ZERO);
+    assert_eq!(
+        pool.least_loaded_lane().await.unwrap().address,
+        lane_address
+    );
+
+    // the balance is not enough to pay for the transaction
+    pool.lane(&lane_address).unwrap().quarantine().await;
+    assert_ne!(
+        pool.least_loaded_lane().await.unwrap().address,
+        lane_address
+    );
+
+    // the signer is topped up
+    *balance.lock().unwrap() = U256::from(5000);
+    assert_eq!(
+        pool.least_loaded_lane().await.unwrap().address,
+        lane_address
+    );
+}
+
+#[tokio::test]
+async fn test_primary_lane_is_used_when_all_lanes_are_quarantined() {
+    let primary = lane_with_balance(Arc::new(Mutex::new(U256::zero())));
+    let other = lane_with_balance(Arc::new(Mutex::new(U256::zero())));
+    let primary_address = primary.address;
+
+    let pool = SignerPool::new(primary, vec![other], Duration::ZERO);
+
+    let lane = pool.least_loaded_lane().await.unwrap();
+
+    assert_eq!(lane.address, primary_address);
+}
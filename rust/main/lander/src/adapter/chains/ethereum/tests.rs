@@ -5,7 +5,9 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use ethers::abi::Function;
 use ethers::prelude::{Block, BlockNumber, FeeHistory, TransactionReceipt, U256 as EthersU256};
-use ethers::types::{transaction::eip2718::TypedTransaction, Address, H160, H256 as EthersH256};
+use ethers::types::{
+    transaction::eip2718::TypedTransaction, Address, Bytes, H160, H256 as EthersH256,
+};
 use ethers_core::abi::{Param, ParamType, StateMutability};
 use ethers_core::types::{Eip1559TransactionRequest, TransactionRequest};
 
@@ -69,12 +71,19 @@ mockall::mock! {
         /// Send transaction into blockchain
         async fn send(&self, tx: &TypedTransaction, function: &Function) -> ChainResult<H256>;
 
+        /// Sign transaction without sending it, returning its hash and
+        /// RLP-encoded signed bytes
+        async fn sign_transaction(&self, tx: &TypedTransaction) -> ChainResult<(H256, Bytes)>;
+
         /// Read-only call into blockchain which returns a boolean
         async fn check(&self, tx: &TypedTransaction, function: &Function) -> ChainResult<bool>;
 
         /// Get the next nonce to use for a given address (using the finalized block)
         async fn get_next_nonce_on_finalized_block(&self, address: &Address, reorg_period: &EthereumReorgPeriod) -> ChainResult<U256>;
 
+        /// Get the native token balance of a given address at the latest block
+        async fn get_native_balance(&self, address: &Address) -> ChainResult<U256>;
+
         /// Get the fee history
         async fn fee_history(
             &self,
@@ -165,7 +174,7 @@ pub fn dummy_tx_precursor(tx_type: ExpectedTxType, signer: H160) -> EthereumTxPr
         }),
         ExpectedTxType::Eip2930 => todo!(),
     };
-    EthereumTxPrecursor { tx, function }
+    EthereumTxPrecursor::new(tx, function)
 }
 
 pub fn dummy_evm_function() -> Function {
@@ -3,7 +3,7 @@
 
 use std::sync::Arc;
 
-use eyre::Result;
+use eyre::{eyre, Result};
 
 use hyperlane_base::{
     db::HyperlaneRocksDB,
@@ -56,6 +56,9 @@ impl AdapterFactory {
             }
             ChainConnectionConf::Starknet(_) => todo!(),
             ChainConnectionConf::CosmosNative(_) => todo!(),
+            ChainConnectionConf::Aptos(_) => {
+                return Err(eyre!("Lander does not support Aptos chains yet"));
+            }
             ChainConnectionConf::Radix(connection_conf) => {
                 let adapter = RadixAdapter::from_conf(conf, core_metrics, &connection_conf)?;
                 Arc::new(adapter)
@@ -42,6 +42,7 @@ mod tests {
         ChainConf {
             domain,
             signer,
+            additional_signers: vec![],
             submitter: SubmitterType::Lander,
             estimated_block_time: Duration::from_secs(1),
             reorg_period: ReorgPeriod::None,
@@ -103,6 +104,9 @@ mod tests {
                 transaction_overrides: TransactionOverrides::default(),
                 op_submission_config: OpSubmissionConfig::default(),
                 consider_null_transaction_receipt: false,
+                private_relay: None,
+                rpc_rate_limits: Default::default(),
+                rpc_fallback: Default::default(),
             }),
         );
 
@@ -89,6 +89,7 @@ impl SealevelAdapter {
         let client = SealevelFallbackRpcClient::from_urls(
             chain_info.clone(),
             urls.clone(),
+            &connection_conf.rpc_rate_limits,
             client_metrics.clone(),
         );
 
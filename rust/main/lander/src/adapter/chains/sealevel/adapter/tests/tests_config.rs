@@ -21,6 +21,7 @@ fn test_configuration_fields() {
         signer: Some(SignerConf::HexKey {
             key: Default::default(),
         }),
+        additional_signers: vec![],
         submitter: SubmitterType::Lander,
         estimated_block_time: expected_estimated_block_time,
         reorg_period: expected_reorg_period.clone(),
@@ -37,6 +38,7 @@ fn test_configuration_fields() {
             transaction_submitter: Default::default(),
             mailbox_process_alt: None,
             process_alt_overrides: vec![],
+            rpc_rate_limits: Default::default(),
         }),
         metrics_conf: Default::default(),
         index: Default::default(),
@@ -1,11 +1,14 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use async_trait::async_trait;
-use tracing::debug;
+use tracing::{debug, info, warn};
 
 use hyperlane_base::{settings::ChainConf, CoreMetrics};
-use hyperlane_core::{ContractLocator, H256, H512};
-use hyperlane_tron::{TronProvider, TronProviderForLander};
+use hyperlane_core::{ContractLocator, HyperlaneDomain, H256, H512};
+use hyperlane_tron::{AccountResources, TronProvider, TronProviderForLander};
 
 use crate::adapter::chains::tron::{
     conf::create_signer, precursor::Precursor, submit::submit_transaction,
@@ -20,6 +23,11 @@ use crate::{
 pub struct TronAdapter<P: TronProviderForLander> {
     pub provider: Arc<P>,
     pub estimated_block_time: Duration,
+    pub domain: HyperlaneDomain,
+    /// How long a new transaction may wait for staked energy to regenerate
+    pub max_energy_wait: Option<Duration>,
+    /// Last observed resources of the signer, reported in metrics
+    pub account_resources: Mutex<Option<AccountResources>>,
 }
 
 impl TronAdapter<TronProvider> {
@@ -50,10 +58,74 @@ impl TronAdapter<TronProvider> {
         Ok(Self {
             provider: Arc::new(provider),
             estimated_block_time: conf.estimated_block_time,
+            domain: conf.domain.clone(),
+            max_energy_wait: connection_conf.max_energy_wait,
+            account_resources: Mutex::new(None),
         })
     }
 }
 
+impl<P: TronProviderForLander> TronAdapter<P> {
+    async fn refresh_account_resources(&self) -> Result<AccountResources, LanderError> {
+        let resources = self.provider.get_account_resources().await?;
+        if let Ok(mut guard) = self.account_resources.lock() {
+            *guard = Some(resources);
+        }
+        Ok(resources)
+    }
+
+    /// Returns true if the transaction should be held back until the signer's
+    /// staked energy has regenerated enough to cover it, instead of burning TRX.
+    /// Transactions are only held back for `max_energy_wait` after their creation.
+    async fn waiting_for_energy(&self, tx: &Transaction) -> bool {
+        let Some(max_energy_wait) = self.max_energy_wait else {
+            return false;
+        };
+        let age = chrono::Utc::now()
+            .signed_duration_since(tx.creation_timestamp)
+            .to_std()
+            .unwrap_or_default();
+        if age >= max_energy_wait {
+            return false;
+        }
+
+        let resources = match self.refresh_account_resources().await {
+            Ok(resources) => resources,
+            Err(err) => {
+                warn!(
+                    ?err,
+                    "Failed to query account resources, not waiting for energy"
+                );
+                return false;
+            }
+        };
+
+        let precursor = tx.precursor();
+        let required_energy = match precursor.tx.gas() {
+            Some(gas) => *gas,
+            None => match self.provider.estimate_gas(&precursor.tx).await {
+                Ok(gas) => gas,
+                Err(err) => {
+                    warn!(?err, "Failed to estimate energy, not waiting for energy");
+                    return false;
+                }
+            },
+        };
+        let required_energy = required_energy.min(u64::MAX.into()).as_u64();
+
+        if resources.available_energy < required_energy {
+            info!(
+                tx_uuid = ?tx.uuid,
+                required_energy,
+                available_energy = resources.available_energy,
+                "Delaying transaction until staked energy regenerates"
+            );
+            return true;
+        }
+        false
+    }
+}
+
 #[async_trait]
 impl<P: TronProviderForLander> AdaptsChain for TronAdapter<P> {
     /// Simulates Payload and returns its gas limit. Called in the Building Stage (PayloadDispatcher)
@@ -111,7 +183,11 @@ impl<P: TronProviderForLander> AdaptsChain for TronAdapter<P> {
 
     /// Sets / escalates gas price, sets nonce / blockhash and broadcasts the Transaction. Even if broadcasting fails, the Transaction struct remains mutated with the new estimates. Called in the Inclusion Stage (PayloadDispatcher)
     async fn submit(&self, tx: &mut Transaction) -> Result<(), LanderError> {
-        submit_transaction(&*self.provider, tx).await
+        submit_transaction(&*self.provider, tx).await?;
+        if let Err(err) = self.refresh_account_resources().await {
+            warn!(?err, "Failed to query account resources after submission");
+        }
+        Ok(())
     }
 
     async fn get_tx_hash_status(&self, hash: H512) -> Result<TransactionStatus, LanderError> {
@@ -185,7 +261,7 @@ impl<P: TronProviderForLander> AdaptsChain for TronAdapter<P> {
     /// Defaults to true, since most chains don't have special rules for tx resubmission.
     async fn tx_ready_for_resubmission(&self, tx: &Transaction) -> bool {
         match tx.last_submission_attempt {
-            None => return true,
+            None => !self.waiting_for_energy(tx).await,
             Some(last_attempt) => {
                 let elapsed = chrono::Utc::now()
                     .signed_duration_since(last_attempt)
@@ -204,7 +280,16 @@ impl<P: TronProviderForLander> AdaptsChain for TronAdapter<P> {
     }
 
     /// Update any metrics related to sent transactions, such as gas price, nonce, etc.
-    fn update_vm_specific_metrics(&self, _tx: &Transaction, _metrics: &DispatcherMetrics) {}
+    fn update_vm_specific_metrics(&self, _tx: &Transaction, metrics: &DispatcherMetrics) {
+        let resources = self.account_resources.lock().ok().and_then(|guard| *guard);
+        if let Some(resources) = resources {
+            metrics.update_available_resources_metric(
+                resources.available_energy,
+                resources.available_bandwidth,
+                self.domain.as_ref(),
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -7,14 +7,16 @@ use ethers::abi::{Detokenize, Function, Param, ParamType, StateMutability, Token
 use ethers::types::transaction::eip2718::TypedTransaction;
 use ethers::types::{TransactionReceipt, TransactionRequest, U256, U64};
 
-use hyperlane_core::{ChainCommunicationError, ChainResult, H256, H512};
-use hyperlane_tron::TronProviderForLander;
+use hyperlane_core::{
+    ChainCommunicationError, ChainResult, HyperlaneDomain, KnownHyperlaneDomain, H256, H512,
+};
+use hyperlane_tron::{AccountResources, TronProviderForLander};
 
 use crate::adapter::chains::tron::{Precursor, TronTxPrecursor};
 use crate::adapter::AdaptsChain;
 use crate::payload::{FullPayload, PayloadDetails, PayloadStatus};
 use crate::transaction::{Transaction, TransactionStatus, VmSpecificTxData};
-use crate::{LanderError, PayloadUuid, TransactionUuid};
+use crate::{DispatcherMetrics, LanderError, PayloadUuid, TransactionUuid};
 
 /// Configurable mock provider for testing TronAdapter
 ///
@@ -37,6 +39,8 @@ pub struct MockTronProvider {
     call_result: Arc<Mutex<Result<bool, String>>>,
     /// Track submitted tx hashes
     submitted_tx_hash: Arc<Mutex<Option<H256>>>,
+    /// Resources available to the signer
+    account_resources: Arc<Mutex<Result<AccountResources, String>>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -46,6 +50,7 @@ pub enum MockCall {
     SubmitTx,
     EstimateGas,
     Call,
+    GetAccountResources,
 }
 
 impl Default for MockTronProvider {
@@ -65,6 +70,7 @@ impl MockTronProvider {
             estimate_error: Arc::new(Mutex::new(None)),
             call_result: Arc::new(Mutex::new(Ok(true))),
             submitted_tx_hash: Arc::new(Mutex::new(None)),
+            account_resources: Arc::new(Mutex::new(Ok(AccountResources::default()))),
         }
     }
 
@@ -116,6 +122,16 @@ impl MockTronProvider {
         *self.submitted_tx_hash.lock().unwrap() = Some(hash);
         self
     }
+
+    pub fn with_account_resources(self, resources: AccountResources) -> Self {
+        *self.account_resources.lock().unwrap() = Ok(resources);
+        self
+    }
+
+    pub fn with_account_resources_error(self, error: &str) -> Self {
+        *self.account_resources.lock().unwrap() = Err(error.to_string());
+        self
+    }
 }
 
 #[async_trait]
@@ -197,6 +213,16 @@ impl TronProviderForLander for MockTronProvider {
             Err(e) => Err(ChainCommunicationError::from_other_str(&e)),
         }
     }
+
+    async fn get_account_resources(&self) -> ChainResult<AccountResources> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(MockCall::GetAccountResources);
+
+        let result = self.account_resources.lock().unwrap().clone();
+        result.map_err(|e| ChainCommunicationError::from_other_str(&e))
+    }
 }
 
 // ============================================================================
@@ -313,9 +339,19 @@ fn create_test_transaction_with_criteria() -> Transaction {
 }
 
 fn create_test_adapter(provider: MockTronProvider) -> super::TronAdapter<MockTronProvider> {
+    create_test_adapter_with_energy_wait(provider, None)
+}
+
+fn create_test_adapter_with_energy_wait(
+    provider: MockTronProvider,
+    max_energy_wait: Option<Duration>,
+) -> super::TronAdapter<MockTronProvider> {
     super::TronAdapter {
         provider: Arc::new(provider),
         estimated_block_time: Duration::from_secs(3),
+        domain: HyperlaneDomain::Known(KnownHyperlaneDomain::Test1),
+        max_energy_wait,
+        account_resources: std::sync::Mutex::new(None),
     }
 }
 
@@ -762,3 +798,112 @@ async fn test_estimated_block_time() {
     let block_time = adapter.estimated_block_time();
     assert_eq!(*block_time, Duration::from_secs(3));
 }
+
+// ============================================================================
+// Energy-aware submission tests
+// ============================================================================
+
+fn resources(available_energy: u64, available_bandwidth: u64) -> AccountResources {
+    AccountResources {
+        available_energy,
+        available_bandwidth,
+    }
+}
+
+#[tokio::test]
+async fn test_new_tx_waits_for_energy() {
+    let provider = MockTronProvider::new()
+        .with_gas_estimate(U256::from(100_000))
+        .with_account_resources(resources(50_000, 1_000));
+    let adapter =
+        create_test_adapter_with_energy_wait(provider.clone(), Some(Duration::from_secs(600)));
+
+    let tx = create_test_transaction();
+    assert!(!adapter.tx_ready_for_resubmission(&tx).await);
+    assert!(provider.get_calls().contains(&MockCall::EstimateGas));
+}
+
+#[tokio::test]
+async fn test_new_tx_submitted_when_energy_is_available() {
+    let provider = MockTronProvider::new()
+        .with_gas_estimate(U256::from(100_000))
+        .with_account_resources(resources(100_000, 1_000));
+    let adapter = create_test_adapter_with_energy_wait(provider, Some(Duration::from_secs(600)));
+
+    let tx = create_test_transaction();
+    assert!(adapter.tx_ready_for_resubmission(&tx).await);
+}
+
+#[tokio::test]
+async fn test_new_tx_uses_existing_energy_estimate() {
+    let provider = MockTronProvider::new().with_account_resources(resources(50_000, 1_000));
+    let adapter =
+        create_test_adapter_with_energy_wait(provider.clone(), Some(Duration::from_secs(600)));
+
+    let mut tx = create_test_transaction();
+    tx.precursor_mut().tx.set_gas(U256::from(40_000));
+    assert!(adapter.tx_ready_for_resubmission(&tx).await);
+    assert!(!provider.get_calls().contains(&MockCall::EstimateGas));
+}
+
+#[tokio::test]
+async fn test_new_tx_stops_waiting_after_max_energy_wait() {
+    let provider = MockTronProvider::new()
+        .with_gas_estimate(U256::from(100_000))
+        .with_account_resources(resources(0, 0));
+    let adapter =
+        create_test_adapter_with_energy_wait(provider.clone(), Some(Duration::from_secs(60)));
+
+    let mut tx = create_test_transaction();
+    tx.creation_timestamp = chrono::Utc::now() - chrono::Duration::seconds(120);
+    assert!(adapter.tx_ready_for_resubmission(&tx).await);
+    assert!(!provider
+        .get_calls()
+        .contains(&MockCall::GetAccountResources));
+}
+
+#[tokio::test]
+async fn test_new_tx_not_delayed_when_resources_unavailable() {
+    let provider = MockTronProvider::new().with_account_resources_error("node unavailable");
+    let adapter = create_test_adapter_with_energy_wait(provider, Some(Duration::from_secs(600)));
+
+    let tx = create_test_transaction();
+    assert!(adapter.tx_ready_for_resubmission(&tx).await);
+}
+
+#[tokio::test]
+async fn test_new_tx_not_delayed_without_max_energy_wait() {
+    let provider = MockTronProvider::new().with_account_resources(resources(0, 0));
+    let adapter = create_test_adapter(provider.clone());
+
+    let tx = create_test_transaction();
+    assert!(adapter.tx_ready_for_resubmission(&tx).await);
+    assert!(provider.get_calls().is_empty());
+}
+
+#[tokio::test]
+async fn test_submit_updates_resource_metrics() {
+    let provider = MockTronProvider::new().with_account_resources(resources(12_345, 678));
+    let adapter = create_test_adapter(provider);
+    let metrics = DispatcherMetrics::dummy_instance();
+    let domain = adapter.domain.name().to_owned();
+
+    let mut tx = create_test_transaction();
+    adapter.submit(&mut tx).await.unwrap();
+    adapter.update_vm_specific_metrics(&tx, &metrics);
+
+    assert_eq!(
+        metrics
+            .available_energy
+            .with_label_values(&[domain.as_str()])
+            .get(),
+        12_345
+    );
+    assert_eq!(
+        metrics
+            .available_bandwidth
+            .with_label_values(&[domain.as_str()])
+            .get(),
+        678
+    );
+}
@@ -1,7 +1,7 @@
 use tracing::{error, info};
 
 use hyperlane_core::ChainCommunicationError;
-use hyperlane_tron::{TronProvider, TronProviderForLander};
+use hyperlane_tron::{HyperlaneTronError, TronProvider, TronProviderForLander};
 
 use crate::adapter::chains::tron::Precursor;
 use crate::transaction::Transaction;
@@ -12,6 +12,14 @@ use crate::LanderError;
 /// Based on Tron node error responses documented at:
 /// https://developers.tron.network/docs/faq#12-broadcast-response-code
 fn classify_tron_error(err: ChainCommunicationError) -> LanderError {
+    // Our own per-transaction fee limit refused to burn more TRX
+    if matches!(
+        HyperlaneTronError::from_chain_error(&err),
+        Some(HyperlaneTronError::FeeLimitExceeded { .. })
+    ) {
+        return LanderError::TxGasCapReached;
+    }
+
     let err_str = err.to_string();
 
     // Check for retryable errors (temporary conditions)
@@ -24,6 +32,7 @@ fn classify_tron_error(err: ChainCommunicationError) -> LanderError {
         return LanderError::TxSubmissionError(err_str);
     }
 
+    // The node refused to burn more TRX for bandwidth
     if err_str.contains("BANDWITH_ERROR") {
         return LanderError::TxGasCapReached;
     }
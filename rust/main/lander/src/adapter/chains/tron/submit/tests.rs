@@ -6,7 +6,7 @@ use ethers::types::transaction::eip2718::TypedTransaction;
 use ethers::types::{TransactionReceipt, TransactionRequest, U256};
 
 use hyperlane_core::{ChainCommunicationError, ChainResult, H256, H512};
-use hyperlane_tron::TronProviderForLander;
+use hyperlane_tron::{AccountResources, HyperlaneTronError, TronProviderForLander};
 
 use crate::adapter::chains::tron::{Precursor, TronTxPrecursor};
 use crate::payload::PayloadDetails;
@@ -22,13 +22,18 @@ use super::submit_transaction;
 /// Mock provider that can return configurable errors for testing error classification
 #[derive(Clone)]
 struct MockProviderWithError {
-    error_message: String,
+    error: Arc<dyn Fn() -> ChainCommunicationError + Send + Sync>,
 }
 
 impl MockProviderWithError {
     fn new(error_message: impl Into<String>) -> Self {
+        let error_message = error_message.into();
+        Self::with_error(move || ChainCommunicationError::from_other_str(&error_message))
+    }
+
+    fn with_error(error: impl Fn() -> ChainCommunicationError + Send + Sync + 'static) -> Self {
         Self {
-            error_message: error_message.into(),
+            error: Arc::new(error),
         }
     }
 }
@@ -51,7 +56,7 @@ impl TronProviderForLander for MockProviderWithError {
     }
 
     async fn submit_tx(&self, _tx: &TypedTransaction) -> ChainResult<H256> {
-        Err(ChainCommunicationError::from_other_str(&self.error_message))
+        Err((self.error)())
     }
 
     async fn estimate_gas(&self, _tx: &TypedTransaction) -> ChainResult<U256> {
@@ -69,6 +74,12 @@ impl TronProviderForLander for MockProviderWithError {
             "Mock: not implemented",
         ))
     }
+
+    async fn get_account_resources(&self) -> ChainResult<AccountResources> {
+        Err(ChainCommunicationError::from_other_str(
+            "Mock: not implemented",
+        ))
+    }
 }
 
 /// Mock provider that succeeds and tracks calls
@@ -155,6 +166,14 @@ impl TronProviderForLander for MockProvider {
             "Mock: call not implemented",
         ))
     }
+
+    async fn get_account_resources(&self) -> ChainResult<AccountResources> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push("get_account_resources".to_string());
+        Ok(AccountResources::default())
+    }
 }
 
 // ============================================================================
@@ -324,6 +343,43 @@ async fn test_tron_error_bandwidth_error() {
     );
 }
 
+#[tokio::test]
+async fn test_tron_error_fee_limit_exceeded() {
+    let provider = MockProviderWithError::with_error(|| {
+        HyperlaneTronError::FeeLimitExceeded {
+            estimated: 2_000_000,
+            max: 1_000_000,
+        }
+        .into()
+    });
+    let mut tx = create_test_transaction();
+
+    let result = submit_transaction(&provider, &mut tx).await;
+
+    let err = result.unwrap_err();
+    assert!(
+        matches!(err, LanderError::TxGasCapReached),
+        "Expected TxGasCapReached for exceeded fee limit, got: {:?}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_tron_error_fee_limit_text_is_not_classified() {
+    // Only the typed error of our own fee limit check caps the gas
+    let provider = MockProviderWithError::new("contract reverted: exceeds the max fee limit");
+    let mut tx = create_test_transaction();
+
+    let result = submit_transaction(&provider, &mut tx).await;
+
+    let err = result.unwrap_err();
+    assert!(
+        matches!(err, LanderError::ChainCommunicationError(_)),
+        "Expected ChainCommunicationError for an untyped error, got: {:?}",
+        err
+    );
+}
+
 #[tokio::test]
 async fn test_tron_error_server_busy() {
     let provider = MockProviderWithError::new("SERVER_BUSY");
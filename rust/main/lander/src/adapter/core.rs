@@ -10,7 +10,7 @@ use tokio::sync::mpsc::error::SendError;
 use tracing::{info, instrument};
 use uuid::Uuid;
 
-use hyperlane_core::{H256, H512, U256};
+use hyperlane_core::{H160, H256, H512, U256};
 
 use crate::{
     error::LanderError,
@@ -23,7 +23,10 @@ pub type GasLimit = U256;
 
 #[derive(Clone, Debug)]
 pub enum AdaptsChainAction {
+    /// Overwrites the upper nonce of the signer configured for the chain
     OverwriteUpperNonce { nonce: Option<u64> },
+    /// Overwrites the upper nonce of one of the signers submitting transactions
+    OverwriteSignerUpperNonce { signer: H160, nonce: Option<u64> },
 }
 
 #[derive(new, Debug, Clone, PartialEq)]
@@ -6,8 +6,9 @@ use std::time::UNIX_EPOCH;
 use hyperlane_core::U256;
 use prometheus::{
     core::{AtomicU64, GenericGauge},
-    labels, opts, register_int_counter_vec_with_registry, register_int_gauge_vec_with_registry,
-    Encoder, IntCounterVec, IntGauge, IntGaugeVec, Registry,
+    labels, opts, register_gauge_vec_with_registry, register_int_counter_vec_with_registry,
+    register_int_gauge_vec_with_registry, Encoder, Gauge, GaugeVec, IntCounterVec, IntGauge,
+    IntGaugeVec, Registry,
 };
 use tracing::{debug, info, warn};
 
@@ -59,8 +60,16 @@ pub struct DispatcherMetrics {
     /// Counts how many times we've noticed the nonce in tx is different from nonce
     /// stored in db
     mismatched_nonce: IntGaugeVec,
+    /// Native token balance of each signer, scaled by the token decimals
+    signer_balance: GaugeVec,
+    /// Whether a signer is quarantined and does not receive new transactions
+    signer_quarantined: IntGaugeVec,
     /// Gas limit set for the transaction, if applicable
     pub gas_limit: IntGaugeVec,
+    /// Staked energy available to the signer, only applies to Tron
+    pub available_energy: IntGaugeVec,
+    /// Staked and free bandwidth available to the signer, only applies to Tron
+    pub available_bandwidth: IntGaugeVec,
 }
 
 impl DispatcherMetrics {
@@ -184,6 +193,22 @@ impl DispatcherMetrics {
             &["destination",],
             registry.clone()
         )?;
+        let available_energy = register_int_gauge_vec_with_registry!(
+            opts!(
+                namespaced("available_energy"),
+                "Staked energy available to the signer, if applicable",
+            ),
+            &["destination",],
+            registry.clone()
+        )?;
+        let available_bandwidth = register_int_gauge_vec_with_registry!(
+            opts!(
+                namespaced("available_bandwidth"),
+                "Bandwidth available to the signer, if applicable",
+            ),
+            &["destination",],
+            registry.clone()
+        )?;
         let finalized_nonce = register_int_gauge_vec_with_registry!(
             opts!(
                 namespaced("finalized_nonce"),
@@ -208,6 +233,22 @@ impl DispatcherMetrics {
             &["destination", "signer",],
             registry.clone()
         )?;
+        let signer_balance = register_gauge_vec_with_registry!(
+            opts!(
+                namespaced("signer_balance"),
+                "Native token balance of each signer used to submit transactions",
+            ),
+            &["destination", "signer",],
+            registry.clone()
+        )?;
+        let signer_quarantined = register_int_gauge_vec_with_registry!(
+            opts!(
+                namespaced("signer_quarantined"),
+                "Whether a signer is quarantined because it ran out of funds",
+            ),
+            &["destination", "signer",],
+            registry.clone()
+        )?;
         Ok(Self {
             registry: registry.clone(),
             task_liveness,
@@ -226,7 +267,11 @@ impl DispatcherMetrics {
             finalized_nonce,
             upper_nonce,
             mismatched_nonce,
+            signer_balance,
+            signer_quarantined,
             gas_limit,
+            available_energy,
+            available_bandwidth,
             inclusion_stage_error,
         })
     }
@@ -306,6 +351,15 @@ impl DispatcherMetrics {
             .set(gas_limit as i64);
     }
 
+    pub fn update_available_resources_metric(&self, energy: u64, bandwidth: u64, domain: &str) {
+        self.available_energy
+            .with_label_values(&[domain])
+            .set(energy as i64);
+        self.available_bandwidth
+            .with_label_values(&[domain])
+            .set(bandwidth as i64);
+    }
+
     pub fn update_inclusion_stage_error_metric(
         &self,
         domain: &str,
@@ -339,6 +393,18 @@ impl DispatcherMetrics {
             .clone()
     }
 
+    pub fn get_signer_balance(&self, destination: &str, signer: &str) -> Gauge {
+        self.signer_balance
+            .with_label_values(&[destination, signer])
+            .clone()
+    }
+
+    pub fn get_signer_quarantined(&self, destination: &str, signer: &str) -> IntGauge {
+        self.signer_quarantined
+            .with_label_values(&[destination, signer])
+            .clone()
+    }
+
     pub fn set_post_inclusion_metrics(
         &self,
         vm_metrics: &PostInclusionMetricsSource,
@@ -11,7 +11,7 @@ use crate::{
     transaction::Transaction,
 };
 
-use super::super::utils::call_until_success_or_nonretryable_error;
+use super::super::utils::{call_until_success_or_nonretryable_error, link_payload_handoffs};
 use super::super::DispatcherState;
 use super::queue::BuildingStageQueue;
 
@@ -51,6 +51,7 @@ impl BuildingStage {
 
     #[instrument(skip_all, fields(payload_and_message_ids = ?payloads.iter().map(|p| (p.details.uuid.to_string(), p.details.metadata.clone())).collect::<Vec<_>>()))]
     async fn build_transactions(&self, payloads: &Vec<FullPayload>) {
+        link_payload_handoffs(payloads.iter().map(|p| &p.details));
         info!(?payloads, "Building transactions from payloads");
         let tx_building_results = self.state.adapter.build_transactions(payloads).await;
 
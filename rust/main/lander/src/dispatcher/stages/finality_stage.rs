@@ -22,7 +22,8 @@ use crate::{
 };
 
 use super::{
-    building_stage::BuildingStageQueue, utils::call_until_success_or_nonretryable_error,
+    building_stage::BuildingStageQueue,
+    utils::{call_until_success_or_nonretryable_error, link_payload_handoffs},
     DispatcherState,
 };
 
@@ -158,6 +159,7 @@ impl FinalityStage {
         building_stage_queue: BuildingStageQueue,
         state: &DispatcherState,
     ) -> Result<(), LanderError> {
+        link_payload_handoffs(&tx.payload_details);
         info!(?tx, "Processing finality stage transaction");
         let tx_status = match &tx.status {
             TransactionStatus::Finalized => tx.status.clone(),
@@ -20,7 +20,10 @@ use crate::{
     transaction::{DropReason as TxDropReason, Transaction, TransactionStatus, TransactionUuid},
 };
 
-use super::{utils::call_until_success_or_nonretryable_error, DispatcherState};
+use super::{
+    utils::{call_until_success_or_nonretryable_error, link_payload_handoffs},
+    DispatcherState,
+};
 
 #[cfg(test)]
 pub mod tests;
@@ -285,6 +288,7 @@ impl InclusionStage {
         state: &DispatcherState,
         pool: &InclusionStagePool,
     ) -> Result<(), LanderError> {
+        link_payload_handoffs(&tx.payload_details);
         info!(?tx, "Processing inclusion stage transaction");
 
         // Update the last status check timestamp before querying
@@ -3,14 +3,25 @@ use std::{future::Future, time::Duration};
 use tokio::time::sleep;
 use tracing::{error, info, instrument};
 
+use hyperlane_base::settings::otlp::link_handoff;
+
 use crate::{
     dispatcher::metrics::DispatcherMetrics,
     error::{IsRetryable, LanderError},
+    payload::PayloadDetails,
     transaction::{Transaction, TransactionStatus},
 };
 
 use super::DispatcherState;
 
+/// Link the current span to the spans which handed over the payloads, so a
+/// message can be followed from the relayer through the stages of Lander
+pub fn link_payload_handoffs<'a>(payloads: impl IntoIterator<Item = &'a PayloadDetails>) {
+    for payload in payloads {
+        link_handoff(&payload.uuid.to_string());
+    }
+}
+
 pub async fn call_until_success_or_nonretryable_error<F, T, Fut>(
     f: F,
     action: &str,
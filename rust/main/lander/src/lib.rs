@@ -4,13 +4,17 @@
 pub use adapter::AdaptsChainAction;
 pub use dispatcher::command_entrypoint::CommandEntrypoint;
 pub use dispatcher::entrypoint::{DispatcherEntrypoint, Entrypoint};
-pub use dispatcher::{DatabaseOrPath, Dispatcher, DispatcherMetrics, DispatcherSettings};
+pub use dispatcher::{
+    DatabaseOrPath, Dispatcher, DispatcherMetrics, DispatcherSettings, PayloadDb, TransactionDb,
+};
 pub use error::LanderError;
 pub use payload::{
     DropReason as PayloadDropReason, FullPayload, PayloadStatus, PayloadUuid,
     RetryReason as PayloadRetryReason,
 };
-pub use transaction::{DropReason as TransactionDropReason, TransactionStatus, TransactionUuid};
+pub use transaction::{
+    DropReason as TransactionDropReason, Transaction, TransactionStatus, TransactionUuid,
+};
 
 mod adapter;
 mod dispatcher;
@@ -29,9 +33,6 @@ mod testing;
 #[cfg(feature = "integration_test")]
 #[doc(hidden)]
 pub use adapter::AdaptsChain;
-#[cfg(feature = "integration_test")]
-#[doc(hidden)]
-pub use dispatcher::{PayloadDb, TransactionDb};
 
 // Re-export integration test factory functions
 #[cfg(feature = "integration_test")]
@@ -1,17 +1,23 @@
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use ethers::abi::{Function, StateMutability};
 use ethers::types::transaction::eip2718::TypedTransaction;
-use ethers::types::{TransactionReceipt, H160, H256 as EthersH256};
+use ethers::types::transaction::eip2930::AccessList;
+use ethers::types::{
+    Address, Eip1559TransactionRequest, NameOrAddress, TransactionReceipt, H160,
+    H256 as EthersH256, U256 as EthersU256, U64,
+};
 use hyperlane_core::config::OpSubmissionConfig;
 use hyperlane_core::{HyperlaneDomain, KnownHyperlaneDomain, H256, U256};
-use hyperlane_ethereum::EthereumReorgPeriod;
+use hyperlane_ethereum::{EthereumReorgPeriod, EvmProviderForLander};
 
 use crate::adapter::chains::ethereum::{
     tests::{dummy_evm_tx, ExpectedTxType, MockEvmProvider},
-    EthereumAdapter, EthereumAdapterMetrics, NonceDb, NonceManager, NonceManagerState,
-    NonceUpdater,
+    EthereumAdapter, EthereumAdapterMetrics, NonceDb, NonceLane, NonceManager, NonceManagerState,
+    NonceUpdater, SignerPool,
 };
+use crate::adapter::AdaptsChain;
 use crate::dispatcher::{DispatcherState, PayloadDb, TransactionDb};
 use crate::tests::test_utils::tmp_dbs;
 use crate::transaction::Transaction;
@@ -47,6 +53,59 @@ pub fn mocked_evm_provider() -> MockEvmProvider {
     mock_evm_provider
 }
 
+/// Provider for submitting transactions, whose latest block is 42 and whose
+/// signer's next nonce on the finalized block is 7
+pub fn mocked_submission_provider() -> MockEvmProvider {
+    let mut provider = MockEvmProvider::new();
+    provider
+        .expect_get_finalized_block_number()
+        .returning(|_| Ok(40));
+    provider
+        .expect_get_next_nonce_on_finalized_block()
+        .returning(|_, _| Ok(U256::from(7)));
+    provider
+        .expect_get_block()
+        .returning(|_| Ok(Some(mock_block(42, 100))));
+    mock_default_fee_history(&mut provider);
+    provider
+}
+
+/// Builds a transaction for a single stored payload calling `process`
+pub async fn build_process_tx(
+    adapter: &EthereumAdapter,
+    payload_db: &Arc<dyn PayloadDb>,
+) -> Transaction {
+    let typed_tx = TypedTransaction::Eip1559(Eip1559TransactionRequest {
+        from: Some(Address::random()),
+        to: Some(NameOrAddress::Address(Address::random())),
+        gas: Some(EthersU256::from(21000)),
+        value: None,
+        data: None,
+        nonce: None,
+        access_list: AccessList::default(),
+        max_fee_per_gas: None,
+        max_priority_fee_per_gas: None,
+        chain_id: Some(U64::from(1)),
+    });
+    #[allow(deprecated)]
+    let function = Function {
+        name: "process".into(),
+        inputs: Vec::new(),
+        outputs: Vec::new(),
+        constant: None,
+        state_mutability: StateMutability::NonPayable,
+    };
+
+    let mut payload = FullPayload::random();
+    payload.data = serde_json::to_vec(&(typed_tx, function)).unwrap();
+    payload_db.store_payload_by_uuid(&payload).await.unwrap();
+
+    adapter.build_transactions(&[payload]).await[0]
+        .maybe_tx
+        .clone()
+        .unwrap()
+}
+
 pub async fn mock_evm_txs(
     num: usize,
     payload_db: &Arc<dyn PayloadDb>,
@@ -103,6 +162,43 @@ pub fn mock_ethereum_adapter(
 ) -> EthereumAdapter {
     let domain: HyperlaneDomain = TEST_DOMAIN.into();
     let provider = Arc::new(provider);
+    let reorg_period = EthereumReorgPeriod::Blocks(1);
+    let lane = mock_nonce_lane(provider.clone(), tx_db, nonce_db, signer, block_time);
+    let metrics = lane.metrics.clone();
+    let nonce_manager = lane.nonce_manager.clone();
+    let signer_pool = SignerPool::new(lane, Vec::new(), block_time);
+
+    let op_submission_config = OpSubmissionConfig::default();
+    let batch_contract_address = op_submission_config
+        .batch_contract_address
+        .unwrap_or_default();
+
+    EthereumAdapter {
+        estimated_block_time: block_time,
+        domain,
+        transaction_overrides: Default::default(),
+        submission_config: op_submission_config,
+        provider,
+        reorg_period,
+        nonce_manager,
+        signer_pool,
+        batch_cache: Default::default(),
+        batch_contract_address,
+        payload_db,
+        signer,
+        minimum_time_between_resubmissions,
+        metrics,
+        private_relay: None,
+    }
+}
+
+pub fn mock_nonce_lane(
+    provider: Arc<dyn EvmProviderForLander>,
+    tx_db: Arc<dyn TransactionDb>,
+    nonce_db: Arc<dyn NonceDb>,
+    signer: H160,
+    block_time: Duration,
+) -> NonceLane {
     let reorg_period = EthereumReorgPeriod::Blocks(1);
     let metrics = EthereumAdapterMetrics::dummy_instance();
     let state = Arc::new(NonceManagerState::new(
@@ -126,26 +222,7 @@ pub fn mock_ethereum_adapter(
         nonce_updater,
     };
 
-    let op_submission_config = OpSubmissionConfig::default();
-    let batch_contract_address = op_submission_config
-        .batch_contract_address
-        .unwrap_or_default();
-
-    EthereumAdapter {
-        estimated_block_time: block_time,
-        domain,
-        transaction_overrides: Default::default(),
-        submission_config: op_submission_config,
-        provider,
-        reorg_period,
-        nonce_manager,
-        batch_cache: Default::default(),
-        batch_contract_address,
-        payload_db,
-        signer,
-        minimum_time_between_resubmissions,
-        metrics,
-    }
+    NonceLane::new(signer, provider, Arc::new(nonce_manager), metrics)
 }
 
 pub fn mock_fee_history(base_fee: u32, prio_fee: u32) -> ethers::types::FeeHistory {
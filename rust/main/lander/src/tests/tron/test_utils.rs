@@ -7,7 +7,7 @@ use ethers::types::transaction::eip2718::TypedTransaction;
 use ethers::types::{TransactionReceipt, TransactionRequest, U256, U64};
 
 use hyperlane_core::{ChainCommunicationError, ChainResult, H256, H512};
-use hyperlane_tron::TronProviderForLander;
+use hyperlane_tron::{AccountResources, TronProviderForLander};
 
 /// Mock Tron provider for integration testing
 ///
@@ -147,6 +147,10 @@ impl TronProviderForLander for MockTronProvider {
         Ok(H256::random())
     }
 
+    async fn get_account_resources(&self) -> ChainResult<AccountResources> {
+        Ok(AccountResources::default())
+    }
+
     async fn estimate_gas(&self, _tx: &TypedTransaction) -> ChainResult<U256> {
         let error = self.estimate_error.lock().unwrap().clone();
         if let Some(err) = error {
@@ -5,7 +5,7 @@ use std::time::Duration;
 use ethers::abi::Function;
 use ethers::types::transaction::eip2718::TypedTransaction;
 
-use hyperlane_core::H256;
+use hyperlane_core::{HyperlaneDomain, KnownHyperlaneDomain, H256};
 
 use crate::adapter::chains::tron::TronAdapter;
 use crate::dispatcher::{BuildingStage, BuildingStageQueue, DispatcherState};
@@ -52,6 +52,9 @@ fn setup_building_stage() -> (
     let adapter = TronAdapter {
         provider: Arc::new(mock_provider),
         estimated_block_time: Duration::from_millis(10),
+        domain: HyperlaneDomain::Known(KnownHyperlaneDomain::Test1),
+        max_energy_wait: None,
+        account_resources: Default::default(),
     };
 
     let state = DispatcherState::new(
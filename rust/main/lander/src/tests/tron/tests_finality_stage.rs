@@ -7,8 +7,10 @@ use ethers::types::transaction::eip2718::TypedTransaction;
 use ethers::types::U256;
 use tracing_test::traced_test;
 
-use hyperlane_core::{ChainCommunicationError, ChainResult, H256, H512};
-use hyperlane_tron::TronProviderForLander;
+use hyperlane_core::{
+    ChainCommunicationError, ChainResult, HyperlaneDomain, KnownHyperlaneDomain, H256, H512,
+};
+use hyperlane_tron::{AccountResources, TronProviderForLander};
 
 use crate::adapter::chains::tron::{TronAdapter, TronTxPrecursor};
 use crate::dispatcher::{BuildingStageQueue, DispatcherState, FinalityStage, FinalityStagePool};
@@ -72,6 +74,10 @@ impl TronProviderForLander for MockTronProviderForFinality {
         Ok(H256::random())
     }
 
+    async fn get_account_resources(&self) -> ChainResult<AccountResources> {
+        Ok(AccountResources::default())
+    }
+
     async fn estimate_gas(&self, _tx: &TypedTransaction) -> ChainResult<U256> {
         Ok(U256::from(21_000))
     }
@@ -103,6 +109,9 @@ where
     let adapter = TronAdapter {
         provider: Arc::new(provider),
         estimated_block_time: block_time,
+        domain: HyperlaneDomain::Known(KnownHyperlaneDomain::Test1),
+        max_energy_wait: None,
+        account_resources: Default::default(),
     };
     DispatcherState::new(
         payload_db,
@@ -429,6 +438,10 @@ async fn test_tron_tx_finalized_multiple_payloads_mixed_results() {
             Ok(H256::random())
         }
 
+        async fn get_account_resources(&self) -> ChainResult<AccountResources> {
+            Ok(AccountResources::default())
+        }
+
         async fn estimate_gas(&self, _tx: &TypedTransaction) -> ChainResult<U256> {
             Ok(U256::from(21_000))
         }
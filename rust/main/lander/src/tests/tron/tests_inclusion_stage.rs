@@ -9,8 +9,10 @@ use ethers::types::U256;
 use tokio::sync::mpsc;
 use tracing_test::traced_test;
 
-use hyperlane_core::{ChainCommunicationError, ChainResult, KnownHyperlaneDomain, H256, H512};
-use hyperlane_tron::TronProviderForLander;
+use hyperlane_core::{
+    ChainCommunicationError, ChainResult, HyperlaneDomain, KnownHyperlaneDomain, H256, H512,
+};
+use hyperlane_tron::{AccountResources, TronProviderForLander};
 
 use crate::adapter::chains::tron::{TronAdapter, TronTxPrecursor};
 use crate::dispatcher::{DispatcherState, InclusionStage, PayloadDb, TransactionDb};
@@ -123,6 +125,10 @@ impl TronProviderForLander for ConfigurableMockProvider {
         Ok(H256::random())
     }
 
+    async fn get_account_resources(&self) -> ChainResult<AccountResources> {
+        Ok(AccountResources::default())
+    }
+
     async fn estimate_gas(&self, _tx: &TypedTransaction) -> ChainResult<U256> {
         Ok(U256::from(21_000))
     }
@@ -146,6 +152,9 @@ where
     let adapter = TronAdapter {
         provider: Arc::new(provider),
         estimated_block_time: block_time,
+        domain: HyperlaneDomain::Known(TEST_DOMAIN),
+        max_energy_wait: None,
+        account_resources: Default::default(),
     };
     DispatcherState::new(
         payload_db,
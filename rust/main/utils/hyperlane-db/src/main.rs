@@ -0,0 +1,199 @@
+//! Offline inspection and repair of the database of a relayer or validator.
+//!
+//! Commands which write to the database need the agent using it to be stopped,
+//! since RocksDB only lets one process open it for writing. The others open it
+//! read-only. Every command works on the entries of one chain: messages,
+//! statuses and merkle tree insertions are kept under their origin chain, while
+//! Lander payloads and transactions are kept under their destination chain.
+//!
+//! ```bash
+//! hyperlane-db --db /usr/share/hyperlane --chain ethereum message --nonce 42
+//! hyperlane-db --db /usr/share/hyperlane --chain ethereum export --from 0 --to 99 --out msgs.json
+//! hyperlane-db --db /usr/share/hyperlane --chain ethereum verify
+//! ```
+
+#![forbid(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::panic)]
+#![deny(clippy::arithmetic_side_effects)]
+
+use std::{fs, path::PathBuf};
+
+use clap::{Parser, Subcommand};
+use eyre::{eyre, Result};
+use serde::Serialize;
+use uuid::Uuid;
+
+use hyperlane_base::db::{HyperlaneDb, HyperlaneRocksDB, DB};
+use hyperlane_core::{
+    HyperlaneDomain, HyperlaneDomainProtocol, HyperlaneDomainTechnicalStack, HyperlaneDomainType,
+    PendingOperationStatus, H256,
+};
+use lander::{PayloadUuid, TransactionUuid};
+
+use crate::{payloads::PayloadRecord, record::MessageRecord};
+
+mod payloads;
+mod record;
+mod verify;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "hyperlane-db",
+    about = "Inspect and repair a Hyperlane agent database"
+)]
+struct Cli {
+    /// Path of the agent database, `db` in the agent config
+    #[arg(long)]
+    db: PathBuf,
+    /// Name of the chain whose entries are used
+    #[arg(long)]
+    chain: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Show a message with its status, retry count and leaf index
+    Message {
+        #[arg(long, conflicts_with = "id", required_unless_present = "id")]
+        nonce: Option<u32>,
+        #[arg(long)]
+        id: Option<H256>,
+    },
+    /// Show the messages with a nonce in `from..=to`
+    Messages {
+        #[arg(long)]
+        from: u32,
+        #[arg(long)]
+        to: u32,
+    },
+    /// Show the Lander payloads and transactions submitted for a message
+    Payloads {
+        #[arg(long)]
+        message_id: H256,
+    },
+    /// Show a Lander payload and its transaction
+    Payload { uuid: Uuid },
+    /// Show a Lander transaction
+    Transaction { uuid: Uuid },
+    /// Reset the status and retry count of a message, so that the relayer
+    /// prepares it again right away
+    ResetStatus {
+        #[arg(long)]
+        id: H256,
+    },
+    /// Export the messages with a nonce in `from..=to` to JSON
+    Export {
+        #[arg(long)]
+        from: u32,
+        #[arg(long)]
+        to: u32,
+        /// File to write to, stdout if not set
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Import messages exported with `export`, overwriting the stored ones
+    Import { file: PathBuf },
+    /// Check for nonce gaps and mismatched messages or leaf indices
+    Verify,
+}
+
+impl Command {
+    /// Whether the command writes to the database
+    fn writes(&self) -> bool {
+        matches!(self, Self::ResetStatus { .. } | Self::Import { .. })
+    }
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    // opening a missing path would create an empty database
+    if !cli.db.is_dir() {
+        return Err(eyre!("No database at {}", cli.db.display()));
+    }
+    let db = if cli.command.writes() {
+        DB::from_path(&cli.db)?
+    } else {
+        DB::from_path_read_only(&cli.db)?
+    };
+    let db = HyperlaneRocksDB::new(&chain_domain(&cli.chain), db);
+
+    match cli.command {
+        Command::Message { nonce, id } => {
+            let record = match (nonce, id) {
+                (Some(nonce), _) => MessageRecord::load_by_nonce(&db, nonce)?,
+                (None, Some(id)) => MessageRecord::load_by_id(&db, id)?,
+                (None, None) => None,
+            };
+            print_json(&record.ok_or_else(|| eyre!("Message not found"))?)?;
+        }
+        Command::Messages { from, to } => {
+            print_json(&MessageRecord::load_range(&db, from, to)?)?;
+        }
+        Command::Payloads { message_id } => {
+            print_json(&PayloadRecord::load_by_message_id(&db, message_id).await?)?;
+        }
+        Command::Payload { uuid } => {
+            print_json(&PayloadRecord::load(&db, PayloadUuid::new(uuid)).await?)?;
+        }
+        Command::Transaction { uuid } => {
+            let transaction = payloads::load_transaction(&db, TransactionUuid::new(uuid)).await?;
+            print_json(&transaction.ok_or_else(|| eyre!("Transaction not found"))?)?;
+        }
+        Command::ResetStatus { id } => {
+            if db.retrieve_message_by_id(&id)?.is_none() {
+                return Err(eyre!("Message not found"));
+            }
+            db.store_status_by_message_id(&id, &PendingOperationStatus::FirstPrepareAttempt)?;
+            db.store_pending_message_retry_count_by_message_id(&id, &0)?;
+            println!("Reset the status of message {id:?}");
+        }
+        Command::Export { from, to, out } => {
+            let records = MessageRecord::load_range(&db, from, to)?;
+            match out {
+                Some(path) => {
+                    fs::write(&path, serde_json::to_string_pretty(&records)?)?;
+                    println!("Exported {} messages to {}", records.len(), path.display());
+                }
+                None => print_json(&records)?,
+            }
+        }
+        Command::Import { file } => {
+            let records: Vec<MessageRecord> = serde_json::from_slice(&fs::read(&file)?)?;
+            for record in &records {
+                record.store(&db)?;
+            }
+            println!("Imported {} messages", records.len());
+        }
+        Command::Verify => {
+            let issues = verify::verify(&db)?;
+            for issue in &issues {
+                println!("{issue}");
+            }
+            if !issues.is_empty() {
+                return Err(eyre!("Found {} issues", issues.len()));
+            }
+            println!("No issues found");
+        }
+    }
+    Ok(())
+}
+
+/// Only the name of the domain is part of the database keys, so any chain can
+/// be inspected without its config
+fn chain_domain(name: &str) -> HyperlaneDomain {
+    HyperlaneDomain::Unknown {
+        domain_id: 0,
+        domain_name: name.to_owned(),
+        domain_type: HyperlaneDomainType::Unknown,
+        domain_protocol: HyperlaneDomainProtocol::Ethereum,
+        domain_technical_stack: HyperlaneDomainTechnicalStack::Other,
+    }
+}
+
+fn print_json(value: &impl Serialize) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
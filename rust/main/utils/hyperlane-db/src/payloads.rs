@@ -0,0 +1,51 @@
+use eyre::Result;
+use serde::Serialize;
+
+use hyperlane_base::db::{HyperlaneDb, HyperlaneRocksDB};
+use hyperlane_core::H256;
+use lander::{FullPayload, PayloadDb, PayloadUuid, Transaction, TransactionDb, TransactionUuid};
+
+/// A Lander payload and the transaction it was included in, if any
+#[derive(Debug, Clone, Serialize)]
+pub struct PayloadRecord {
+    pub uuid: PayloadUuid,
+    pub payload: Option<FullPayload>,
+    pub transaction: Option<Transaction>,
+}
+
+impl PayloadRecord {
+    /// Load a payload of the destination chain database `db`
+    pub async fn load(db: &HyperlaneRocksDB, uuid: PayloadUuid) -> Result<Self> {
+        let payload = db.retrieve_payload_by_uuid(&uuid).await?;
+        let transaction = match db.retrieve_tx_uuid_by_payload_uuid(&uuid).await? {
+            Some(tx_uuid) => db.retrieve_transaction_by_uuid(&tx_uuid).await?,
+            None => None,
+        };
+        Ok(Self {
+            uuid,
+            payload,
+            transaction,
+        })
+    }
+
+    /// Load the payloads submitted for a message, which are kept in the
+    /// database of its destination chain
+    pub async fn load_by_message_id(db: &HyperlaneRocksDB, message_id: H256) -> Result<Vec<Self>> {
+        let mut records = vec![];
+        for uuid in db
+            .retrieve_payload_uuids_by_message_id(&message_id)?
+            .unwrap_or_default()
+        {
+            records.push(Self::load(db, uuid).await?);
+        }
+        Ok(records)
+    }
+}
+
+/// Load a transaction of the destination chain database `db`
+pub async fn load_transaction(
+    db: &HyperlaneRocksDB,
+    uuid: TransactionUuid,
+) -> Result<Option<Transaction>> {
+    Ok(db.retrieve_transaction_by_uuid(&uuid).await?)
+}
@@ -0,0 +1,190 @@
+use eyre::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use hyperlane_base::db::{HyperlaneDb, HyperlaneRocksDB};
+use hyperlane_core::{HyperlaneMessage, MerkleTreeInsertion, PendingOperationStatus, H256};
+
+/// A message and what the relayer stored about it, as exported to JSON
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageRecord {
+    pub message_id: H256,
+    pub message: HyperlaneMessage,
+    pub dispatched_block_number: Option<u64>,
+    pub processed: bool,
+    /// Whether the data of the delivered message was pruned
+    #[serde(default)]
+    pub pruned: bool,
+    pub status: Option<PendingOperationStatus>,
+    pub retry_count: Option<u32>,
+    pub insertion: Option<InsertionRecord>,
+}
+
+/// The merkle tree insertion of a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InsertionRecord {
+    pub leaf_index: u32,
+    pub block_number: Option<u64>,
+}
+
+impl MessageRecord {
+    /// Load the message with the given nonce, if it was indexed
+    pub fn load_by_nonce(db: &HyperlaneRocksDB, nonce: u32) -> Result<Option<Self>> {
+        let Some(message_id) = db.retrieve_message_id_by_nonce(&nonce)? else {
+            return Ok(None);
+        };
+        Self::load_by_id(db, message_id)
+    }
+
+    /// Load the message with the given id, if it was indexed
+    pub fn load_by_id(db: &HyperlaneRocksDB, message_id: H256) -> Result<Option<Self>> {
+        let Some(message) = db.retrieve_message_by_id(&message_id)? else {
+            return Ok(None);
+        };
+        let nonce = message.nonce;
+        let insertion = match db.retrieve_merkle_leaf_index_by_message_id(&message_id)? {
+            Some(leaf_index) => Some(InsertionRecord {
+                leaf_index,
+                block_number: db
+                    .retrieve_merkle_tree_insertion_block_number_by_leaf_index(&leaf_index)?,
+            }),
+            None => None,
+        };
+        Ok(Some(Self {
+            message_id,
+            dispatched_block_number: db.retrieve_dispatched_block_number_by_nonce(&nonce)?,
+            processed: db.retrieve_processed_by_nonce(&nonce)?.unwrap_or_default(),
+            pruned: db
+                .retrieve_message_pruned_by_nonce(&nonce)?
+                .unwrap_or_default(),
+            status: db.retrieve_status_by_message_id(&message_id)?,
+            retry_count: db.retrieve_pending_message_retry_count_by_message_id(&message_id)?,
+            insertion,
+            message,
+        }))
+    }
+
+    /// Load the messages with a nonce in `from..=to`, skipping the missing ones
+    pub fn load_range(db: &HyperlaneRocksDB, from: u32, to: u32) -> Result<Vec<Self>> {
+        let mut records = vec![];
+        for nonce in from..=to {
+            records.extend(Self::load_by_nonce(db, nonce)?);
+        }
+        Ok(records)
+    }
+
+    /// Store the record, overwriting what is stored about the message
+    pub fn store(&self, db: &HyperlaneRocksDB) -> Result<()> {
+        // a pruned message has no body to compute its id from
+        if !self.pruned && self.message.id() != self.message_id {
+            bail!(
+                "Message with nonce {} doesn't match its id {:?}",
+                self.message.nonce,
+                self.message_id
+            );
+        }
+        let nonce = self.message.nonce;
+        db.upsert_message_with_id(
+            self.message_id,
+            &self.message,
+            self.dispatched_block_number.unwrap_or_default(),
+        )?;
+        db.store_processed_by_nonce(&nonce, &self.processed)?;
+        db.store_message_pruned_by_nonce(&nonce, &self.pruned)?;
+        if let Some(status) = &self.status {
+            db.store_status_by_message_id(&self.message_id, status)?;
+        }
+        if let Some(retry_count) = &self.retry_count {
+            db.store_pending_message_retry_count_by_message_id(&self.message_id, retry_count)?;
+        }
+        if let Some(insertion) = &self.insertion {
+            db.store_tree_insertion(
+                &MerkleTreeInsertion::new(insertion.leaf_index, self.message_id),
+                insertion.block_number.unwrap_or_default(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyperlane_base::db::test_utils::run_test_db;
+    use hyperlane_core::{HyperlaneDomain, ReprepareReason};
+
+    use super::*;
+
+    fn record(nonce: u32) -> MessageRecord {
+        let message = HyperlaneMessage {
+            nonce,
+            body: vec![nonce as u8],
+            ..Default::default()
+        };
+        MessageRecord {
+            message_id: message.id(),
+            message,
+            dispatched_block_number: Some(100 + nonce as u64),
+            processed: nonce == 0,
+            pruned: false,
+            status: Some(PendingOperationStatus::Retry(
+                ReprepareReason::CouldNotFetchMetadata,
+            )),
+            retry_count: Some(nonce),
+            insertion: Some(InsertionRecord {
+                leaf_index: nonce,
+                block_number: Some(200),
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn records_survive_export_and_import() {
+        let records = vec![record(0), record(1)];
+        let json = serde_json::to_string(&records).unwrap();
+
+        run_test_db(|db| async move {
+            let db = HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("test"), db);
+            let imported: Vec<MessageRecord> = serde_json::from_str(&json).unwrap();
+            for record in &imported {
+                record.store(&db).unwrap();
+            }
+            assert_eq!(MessageRecord::load_range(&db, 0, 5).unwrap(), records);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn pruned_records_survive_export_and_import() {
+        run_test_db(|source| async move {
+            let domain = HyperlaneDomain::new_test_domain("test");
+            let source = HyperlaneRocksDB::new(&domain, source).with_pruning();
+            record(0).store(&source).unwrap();
+            source.store_processed_by_nonce(&0, &true).unwrap();
+            assert_eq!(source.prune_delivered_messages(u64::MAX, 10).unwrap(), 1);
+            let exported = MessageRecord::load_range(&source, 0, 0).unwrap();
+            assert!(exported[0].pruned);
+            assert!(exported[0].message.body.is_empty());
+            assert_eq!(exported[0].message_id, record(0).message_id);
+            assert_eq!(exported[0].status, None);
+
+            run_test_db(|target| async move {
+                let target = HyperlaneRocksDB::new(&domain, target);
+                exported[0].store(&target).unwrap();
+                assert_eq!(MessageRecord::load_range(&target, 0, 0).unwrap(), exported);
+            })
+            .await;
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn records_with_a_wrong_id_are_rejected() {
+        run_test_db(|db| async move {
+            let db = HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("test"), db);
+            let mut record = record(0);
+            record.message.body = vec![42];
+            assert!(record.store(&db).is_err());
+            assert_eq!(MessageRecord::load_by_nonce(&db, 0).unwrap(), None);
+        })
+        .await;
+    }
+}
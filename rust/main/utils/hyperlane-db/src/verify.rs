@@ -0,0 +1,192 @@
+use std::fmt::{Display, Formatter};
+
+use eyre::Result;
+
+use hyperlane_base::db::{HyperlaneDb, HyperlaneRocksDB};
+use hyperlane_core::H256;
+
+/// An inconsistency found in the database of a chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    /// No message id is stored for a nonce below the highest seen nonce
+    NonceGap { nonce: u32 },
+    /// The message id of a nonce has no message
+    MissingMessage { nonce: u32, message_id: H256 },
+    /// The message stored under a nonce has another nonce or id
+    MismatchedMessage { nonce: u32, message_id: H256 },
+    /// The leaf index of a message has no insertion
+    MissingInsertion { leaf_index: u32, message_id: H256 },
+    /// The insertion at a leaf index is for another message than the one
+    /// mapped to that leaf index
+    MismatchedLeafIndex {
+        leaf_index: u32,
+        message_id: H256,
+        inserted_message_id: H256,
+    },
+    /// No insertion is stored for a leaf index below the highest known one
+    LeafIndexGap { leaf_index: u32 },
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NonceGap { nonce } => write!(f, "nonce {nonce}: no message"),
+            Self::MissingMessage { nonce, message_id } => {
+                write!(f, "nonce {nonce}: message {message_id:?} is missing")
+            }
+            Self::MismatchedMessage { nonce, message_id } => write!(
+                f,
+                "nonce {nonce}: message {message_id:?} doesn't match its nonce or id"
+            ),
+            Self::MissingInsertion {
+                leaf_index,
+                message_id,
+            } => write!(
+                f,
+                "leaf index {leaf_index}: no insertion for message {message_id:?}"
+            ),
+            Self::MismatchedLeafIndex {
+                leaf_index,
+                message_id,
+                inserted_message_id,
+            } => write!(
+                f,
+                "leaf index {leaf_index}: mapped to message {message_id:?} but inserted for {inserted_message_id:?}"
+            ),
+            Self::LeafIndexGap { leaf_index } => write!(f, "leaf index {leaf_index}: no insertion"),
+        }
+    }
+}
+
+/// Check that every nonce up to the highest seen one has its message, and
+/// that the messages and merkle tree insertions point at each other
+pub fn verify(db: &HyperlaneRocksDB) -> Result<Vec<Issue>> {
+    let mut issues = vec![];
+    let mut highest_leaf_index = None;
+    let highest_nonce = db.retrieve_highest_seen_message_nonce()?;
+
+    for nonce in highest_nonce
+        .map(|highest| 0..=highest)
+        .into_iter()
+        .flatten()
+    {
+        let Some(message_id) = db.retrieve_message_id_by_nonce(&nonce)? else {
+            issues.push(Issue::NonceGap { nonce });
+            continue;
+        };
+        match db.retrieve_message_by_id(&message_id)? {
+            None => issues.push(Issue::MissingMessage { nonce, message_id }),
+            Some(message) => {
+                // a pruned message has no body to compute its id from
+                let pruned = db
+                    .retrieve_message_pruned_by_nonce(&nonce)?
+                    .unwrap_or_default();
+                if message.nonce != nonce || (!pruned && message.id() != message_id) {
+                    issues.push(Issue::MismatchedMessage { nonce, message_id });
+                }
+            }
+        }
+
+        let Some(leaf_index) = db.retrieve_merkle_leaf_index_by_message_id(&message_id)? else {
+            continue;
+        };
+        highest_leaf_index = highest_leaf_index.max(Some(leaf_index));
+        match db.retrieve_merkle_tree_insertion_by_leaf_index(&leaf_index)? {
+            None => issues.push(Issue::MissingInsertion {
+                leaf_index,
+                message_id,
+            }),
+            Some(insertion) if insertion.message_id() != message_id => {
+                issues.push(Issue::MismatchedLeafIndex {
+                    leaf_index,
+                    message_id,
+                    inserted_message_id: insertion.message_id(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+
+    // insertions are indexed independently of the messages, so only the
+    // leaf indices of known messages tell where the tree has a gap
+    let mut leaf_index: u32 = 0;
+    while db
+        .retrieve_merkle_tree_insertion_by_leaf_index(&leaf_index)?
+        .is_some()
+    {
+        leaf_index = leaf_index.saturating_add(1);
+    }
+    if highest_leaf_index.is_some_and(|highest| highest > leaf_index) {
+        issues.push(Issue::LeafIndexGap { leaf_index });
+    }
+    Ok(issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use hyperlane_base::db::test_utils::run_test_db;
+    use hyperlane_core::{HyperlaneDomain, HyperlaneMessage, MerkleTreeInsertion};
+
+    use super::*;
+
+    fn message(nonce: u32) -> HyperlaneMessage {
+        HyperlaneMessage {
+            nonce,
+            body: vec![nonce as u8],
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn consistent_db_has_no_issues() {
+        run_test_db(|db| async move {
+            let db =
+                HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("test"), db).with_pruning();
+            for nonce in 0..3 {
+                let message = message(nonce);
+                db.store_message(&message, 0).unwrap();
+                db.process_tree_insertion(&MerkleTreeInsertion::new(nonce, message.id()), 0)
+                    .unwrap();
+            }
+            // the pruned message no longer matches its id without its body
+            db.record_message_delivery(0, 100).unwrap();
+            assert_eq!(db.prune_delivered_messages(u64::MAX, 10).unwrap(), 1);
+            assert_eq!(verify(&db).unwrap(), vec![]);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn gaps_and_mismatches_are_reported() {
+        run_test_db(|db| async move {
+            let db = HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("test"), db);
+            let messages = (0..4).map(message).collect::<Vec<_>>();
+            for message in [&messages[0], &messages[2], &messages[3]] {
+                db.store_message(message, 0).unwrap();
+            }
+            // the second message is stored under the id of the first one
+            db.store_message_id_by_nonce(&2, &messages[0].id()).unwrap();
+            db.process_tree_insertion(&MerkleTreeInsertion::new(0, messages[0].id()), 0)
+                .unwrap();
+            db.store_merkle_leaf_index_by_message_id(&messages[3].id(), &2)
+                .unwrap();
+
+            assert_eq!(
+                verify(&db).unwrap(),
+                vec![
+                    Issue::NonceGap { nonce: 1 },
+                    Issue::MismatchedMessage {
+                        nonce: 2,
+                        message_id: messages[0].id(),
+                    },
+                    Issue::MissingInsertion {
+                        leaf_index: 2,
+                        message_id: messages[3].id(),
+                    },
+                    Issue::LeafIndexGap { leaf_index: 1 },
+                ]
+            );
+        })
+        .await;
+    }
+}
@@ -356,6 +356,7 @@ export {
   AgentSealevelPriorityFeeOracleType,
   AgentSealevelTransactionSubmitter,
   AgentSealevelTransactionSubmitterType,
+  AgentSecretReference,
   AgentSigner,
   AgentSignerAwsKey,
   AgentSignerHexKey,
@@ -398,6 +399,8 @@ export {
   mergeChainMetadata,
   mergeChainMetadataMap,
   NativeToken,
+  RpcRateLimit,
+  RpcRateLimitSchema,
   RpcUrl,
   RpcUrlSchema,
 } from './metadata/chainMetadataTypes.js';
@@ -10,7 +10,10 @@ import { ProtocolType, isEmptyAddress } from '@hyperlane-xyz/utils';
 import { MultiProvider } from '../providers/MultiProvider.js';
 import { ChainMap, ChainName } from '../types.js';
 
-import { ChainMetadataSchemaObject } from './chainMetadataTypes.js';
+import {
+  ChainMetadataSchemaObject,
+  RpcRateLimitSchema,
+} from './chainMetadataTypes.js';
 import { ZHash, ZNzUint, ZUWei, ZUint } from './customZodTypes.js';
 import {
   HyperlaneDeploymentArtifacts,
@@ -57,6 +60,7 @@ export enum AgentSignerKeyType {
 export enum AgentSealevelPriorityFeeOracleType {
   Helius = 'helius',
   Constant = 'constant',
+  RecentPrioritizationFees = 'recentPrioritizationFees',
 }
 
 export enum AgentSealevelHeliusFeeLevel {
@@ -72,12 +76,41 @@ export enum AgentSealevelHeliusFeeLevel {
 export enum AgentSealevelTransactionSubmitterType {
   Rpc = 'rpc',
   Jito = 'jito',
+  JitoBundle = 'jitoBundle',
 }
 
+const AgentSecretReferenceSchema = z
+  .union([
+    z
+      .object({ $secretFile: z.string() })
+      .strict()
+      .describe('Read the secret from a file'),
+    z
+      .object({ $secretEnv: z.string() })
+      .strict()
+      .describe('Read the secret from an environment variable'),
+    z
+      .object({
+        $secretStore: z.string(),
+        $secretField: z.string().optional(),
+      })
+      .strict()
+      .describe(
+        'Fetch the secret at this path from the configured secret store, optionally selecting a single field',
+      ),
+  ])
+  .describe(
+    'A reference to a secret, resolved when the agent loads its config',
+  );
+
+export type AgentSecretReference = z.infer<typeof AgentSecretReferenceSchema>;
+
+const ZAgentSecretHash = ZHash.or(AgentSecretReferenceSchema);
+
 const AgentSignerHexKeySchema = z
   .object({
     type: z.literal(AgentSignerKeyType.Hex).optional(),
-    key: ZHash,
+    key: ZAgentSecretHash,
   })
   .describe('A local hex key');
 const AgentSignerAwsKeySchema = z
@@ -93,14 +126,14 @@ const AgentSignerCosmosKeySchema = z
   .object({
     type: z.literal(AgentSignerKeyType.Cosmos),
     prefix: z.string().describe('The bech32 prefix for the cosmos address'),
-    key: ZHash,
+    key: ZAgentSecretHash,
   })
   .describe('Cosmos key');
 const AgentSignerRadixKeySchema = z
   .object({
     type: z.literal(AgentSignerKeyType.Radix),
     suffix: z.string().describe('The network suffix for the signer'),
-    key: ZHash,
+    key: ZAgentSecretHash,
   })
   .describe('Radix key');
 const AgentSignerNodeSchema = z
@@ -165,6 +198,26 @@ const AgentSealevelChainMetadataSchema = z.object({
         // In microlamports
         fee: ZUWei,
       }),
+      z.object({
+        type: z.literal(
+          AgentSealevelPriorityFeeOracleType.RecentPrioritizationFees,
+        ),
+        url: z
+          .string()
+          .optional()
+          .describe('The RPC to query, defaults to the first chain RPC'),
+        percentile: z
+          .number()
+          .int()
+          .min(0)
+          .max(100)
+          .optional()
+          .describe('The percentile of the recent fees to use, defaults to 75'),
+        // In microlamports
+        minFee: ZUWei.optional(),
+        // In microlamports
+        maxFee: ZUWei.optional(),
+      }),
     ])
     .optional(),
   transactionSubmitter: z
@@ -213,6 +266,42 @@ export const AgentChainMetadataSchema = ChainMetadataSchemaObject.merge(
       .nativeEnum(RpcConsensusType)
       .describe('The consensus type to use when multiple RPCs are configured.')
       .optional(),
+    rpcRateLimit: RpcRateLimitSchema.optional().describe(
+      'Request budget of every RPC URL without its own rateLimit, including the custom RPC URLs.',
+    ),
+    rpcFallback: z
+      .object({
+        hedgeAfterMs: ZUint.optional().describe(
+          'Delay after which a request which got no response is also sent to the next RPC URL. Requests are not hedged if unset.',
+        ),
+        scoringWindow: ZNzUint.optional().describe(
+          'Number of recent requests the latency and error rate of an RPC URL are computed over.',
+        ),
+        latencyPercentile: z
+          .number()
+          .min(0)
+          .max(1)
+          .optional()
+          .describe(
+            'Latency percentile of the recent requests which is scored.',
+          ),
+        errorPenaltyMs: ZUint.optional().describe(
+          'Penalty of an RPC URL of which every recent request failed.',
+        ),
+        blockLagPenaltyMs: ZUint.optional().describe(
+          'Penalty for each block an RPC URL is behind the others.',
+        ),
+        stalledPenaltyMs: ZUint.optional().describe(
+          'Penalty of an RPC URL whose block height stopped increasing.',
+        ),
+        deprioritizedPenaltyMs: ZUint.optional().describe(
+          'Penalty of an RPC URL after too many consecutive errors, until a request to it succeeds.',
+        ),
+      })
+      .optional()
+      .describe(
+        'How the RPC URLs of a fallback connection are ordered and hedged. Only used by EVM chains.',
+      ),
     signer: AgentSignerSchema.optional().describe(
       'The signer to use for this chain',
     ),
@@ -320,6 +409,24 @@ export const AgentConfigSchema = z.object({
   defaultSigner: AgentSignerSchema.optional().describe(
     'Default signer to use for any chains that have not defined their own.',
   ),
+  secretStore: z
+    .object({
+      url: z.string().url().describe('The base URL of the secret store'),
+      token: z
+        .string()
+        .or(AgentSecretReferenceSchema)
+        .describe('The token to authenticate with the secret store'),
+      authHeader: z
+        .string()
+        .optional()
+        .describe(
+          'The header carrying the token. Defaults to `Authorization` with a bearer token.',
+        ),
+    })
+    .optional()
+    .describe(
+      'An HTTP secret store, e.g. HashiCorp Vault, resolving `$secretStore` references.',
+    ),
   log: z
     .object({
       format: z
@@ -330,6 +437,32 @@ export const AgentConfigSchema = z.object({
         .nativeEnum(AgentLogLevel)
         .optional()
         .describe("The log level to use for the agent's logs."),
+      otlp: z
+        .object({
+          endpoint: z
+            .string()
+            .url()
+            .describe('The gRPC endpoint of the OpenTelemetry collector.'),
+          serviceName: z
+            .string()
+            .optional()
+            .describe('The service name to report. Defaults to the agent.'),
+          sampleRatio: z
+            .number()
+            .min(0)
+            .max(1)
+            .optional()
+            .describe('The share of traces to export, between 0 and 1.'),
+          exportMetrics: z
+            .boolean()
+            .optional()
+            .describe('Whether to export the prometheus metrics as well.'),
+          metricsInterval: ZUint.optional().describe(
+            'The interval between metric exports, in seconds.',
+          ),
+        })
+        .optional()
+        .describe('Export spans and metrics to an OpenTelemetry collector.'),
     })
     .optional(),
 });
@@ -446,12 +579,49 @@ const IsmCacheConfigSchema = z.object({
 });
 export type IsmCacheConfig = z.infer<typeof IsmCacheConfigSchema>;
 
+const BalanceGuardrailSchema = z.object({
+  minBalance: ZUWei.describe(
+    'Submission to the chain is paused while the relayer balance is below this amount.',
+  ),
+  checkInterval: ZNzUint.optional().describe(
+    'How often the relayer balance is checked, in seconds. Defaults to 60.',
+  ),
+  topUp: z
+    .object({
+      threshold: ZUWei.describe(
+        'The relayer wallet is topped up when its balance is below this amount. Must not be lower than `minBalance`.',
+      ),
+      amount: ZUWei.describe('Amount transferred with every top up.'),
+      minInterval: ZUint.optional().describe(
+        'Minimum time between two top ups, in seconds. Defaults to 3600.',
+      ),
+      maxAmountPerWindow: ZUWei.optional().describe(
+        'Most that can be transferred within `window`, top ups stop once it is reached. Must not be lower than `amount`, which it defaults to.',
+      ),
+      window: ZNzUint.optional().describe(
+        'Window over which `maxAmountPerWindow` applies, in seconds. Defaults to 86400.',
+      ),
+      funder: AgentSignerSchema.describe(
+        'Signer of the wallet which funds the top ups. Only supported on EVM chains.',
+      ),
+    })
+    .optional()
+    .describe('Automatic top up of the relayer wallet from a funder key.'),
+});
+export type BalanceGuardrailConfig = z.infer<typeof BalanceGuardrailSchema>;
+
 export const RelayerAgentConfigSchema = AgentConfigSchema.extend({
   db: z
     .string()
     .min(1)
     .optional()
     .describe('The path to the relayer database.'),
+  dbBackend: z
+    .enum(['rocksdb', 'memory'])
+    .optional()
+    .describe(
+      'Where the relayer database is kept: in RocksDB at the db path, or in memory, lost on restart. Defaults to rocksdb.',
+    ),
   relayChains: CommaSeparatedChainList.describe(
     'Comma separated list of chains to relay messages between.',
   ),
@@ -507,6 +677,52 @@ export const RelayerAgentConfigSchema = AgentConfigSchema.extend({
     .describe(
       'If true, allows caching of certain contract calls that can be appropriately cached.',
     ),
+  contractCallCache: z
+    .object({
+      backend: z
+        .enum(['local', 'db', 'redis'])
+        .optional()
+        .describe(
+          'Where cached contract calls are kept: in memory, in the relayer database to survive restarts, or in a Redis server shared by several relayers. Defaults to local.',
+        ),
+      url: z
+        .string()
+        .url()
+        .optional()
+        .describe(
+          'The redis:// url of the server, required by the redis backend.',
+        ),
+    })
+    .optional()
+    .describe('The backend of the contract call cache.'),
+  dbPruning: z
+    .object({
+      retentionDays: z
+        .number()
+        .int()
+        .positive()
+        .describe(
+          'Days after their delivery when the bodies, gas payments and processing state of messages are pruned.',
+        ),
+      interval: z
+        .number()
+        .int()
+        .positive()
+        .optional()
+        .describe('Seconds between two pruning runs. Defaults to 3600.'),
+      batchSize: z
+        .number()
+        .int()
+        .positive()
+        .optional()
+        .describe(
+          'The maximum number of messages pruned per chain in a run. Defaults to 10000.',
+        ),
+    })
+    .optional()
+    .describe(
+      'If set, prunes delivered messages from the relayer database, keeping what is needed to prove and skip them.',
+    ),
   txIdIndexingEnabled: z
     .boolean()
     .optional()
@@ -554,6 +770,12 @@ export const RelayerAgentConfigSchema = AgentConfigSchema.extend({
     .describe(
       'Relay API allowed CORS origins, comma-separated. Defaults to https://nexus.hyperlane.xyz.',
     ),
+  balanceGuardrails: z
+    .record(BalanceGuardrailSchema)
+    .optional()
+    .describe(
+      'Balance guardrails for the relayer wallet, keyed by destination chain name.',
+    ),
 }).superRefine((config, ctx) => {
   // Mirror the Rust relayer gate: the current IGP event does not expose the
   // token address, so exact non-native `feeToken` enforcement is rejected.
@@ -597,67 +819,156 @@ export const ScraperAgentConfigSchema = AgentConfigSchema.extend({
     .describe(
       'Per-domain CCR router → underlying ERC20 token mapping. Domain ID → { router_address → token_address }. Auto-populated from registry.',
     ),
+  queryApiEnabled: z
+    .boolean()
+    .optional()
+    .describe(
+      'Whether to serve the HTTP API for querying messages, gas payments and per-domain stats. Defaults to false.',
+    ),
 });
 
 export type ScraperConfig = z.infer<typeof ScraperAgentConfigSchema>;
 
+const CheckpointSyncerSchema = z.discriminatedUnion('type', [
+  z
+    .object({
+      type: z.literal('localStorage'),
+      path: z.string().min(1).describe('Path to the local storage location'),
+    })
+    .describe('A local checkpoint syncer'),
+  z
+    .object({
+      type: z.literal('s3'),
+      bucket: z.string().min(1),
+      region: z.string().min(1),
+      folder: z
+        .string()
+        .min(1)
+        .optional()
+        .describe(
+          'The folder/key-prefix to use, defaults to the root of the bucket',
+        ),
+    })
+    .describe('A checkpoint syncer that uses S3'),
+  z
+    .object({
+      type: z.literal('gcs'),
+      bucket: z.string().min(1),
+      folder: z
+        .string()
+        .min(1)
+        .optional()
+        .describe('The folder to use, defaults to the root of the bucket'),
+      service_account_key: z
+        .string()
+        .min(1)
+        .optional()
+        .describe('The path to GCS service account key file'),
+      user_secrets: z
+        .string()
+        .min(1)
+        .optional()
+        .describe('The path to GCS user secret file'),
+    })
+    .describe('A checkpoint syncer that uses Google Cloud Storage'),
+]);
+
+const ValidatorKeyRotationSchema = z
+  .object({
+    validator: AgentSignerSchema.describe('The new validator attestation signer'),
+    checkpointSyncer: CheckpointSyncerSchema.describe(
+      'Where checkpoints signed by the new key are written, apart from the current ones',
+    ),
+    endsAt: ZUint.describe(
+      'Unix timestamp in seconds at which the current key stops signing checkpoints',
+    ),
+  })
+  .describe(
+    'Rotation to a new validator key. Until `endsAt`, both keys sign every checkpoint.',
+  );
+
+const ValidatorOriginSchema = z.object({
+  checkpointSyncer: CheckpointSyncerSchema,
+  validator: AgentSignerSchema.optional().describe(
+    'The validator attestation signer for this origin, defaults to `validator`',
+  ),
+  reorgPeriod: z
+    .union([ZUint, z.string()])
+    .optional()
+    .describe(
+      'Number of blocks or block tag to wait before signing a checkpoint, defaults to the chain reorg period',
+    ),
+  signingJournalImport: z
+    .string()
+    .min(1)
+    .optional()
+    .describe(
+      'The path to a signing journal exported from another host, merged into the local journal for this origin on startup.',
+    ),
+  rotation: ValidatorKeyRotationSchema.optional(),
+});
+
 export const ValidatorAgentConfigSchema = AgentConfigSchema.extend({
   db: z
     .string()
     .min(1)
     .optional()
     .describe('The path to the validator database.'),
+  signingJournalDb: z
+    .string()
+    .min(1)
+    .optional()
+    .describe(
+      'The path to the database recording every checkpoint the validator signed, used to refuse conflicting signatures.',
+    ),
+  signingJournalImport: z
+    .string()
+    .min(1)
+    .optional()
+    .describe(
+      'The path to a signing journal exported from another host, merged into the local journal on startup.',
+    ),
   originChainName: z
     .string()
     .min(1)
-    .describe('Name of the chain to validate messages on'),
-  validator: AgentSignerSchema.describe('The validator attestation signer'),
-  checkpointSyncer: z.discriminatedUnion('type', [
-    z
-      .object({
-        type: z.literal('localStorage'),
-        path: z.string().min(1).describe('Path to the local storage location'),
-      })
-      .describe('A local checkpoint syncer'),
-    z
-      .object({
-        type: z.literal('s3'),
-        bucket: z.string().min(1),
-        region: z.string().min(1),
-        folder: z
-          .string()
-          .min(1)
-          .optional()
-          .describe(
-            'The folder/key-prefix to use, defaults to the root of the bucket',
-          ),
-      })
-      .describe('A checkpoint syncer that uses S3'),
-    z
-      .object({
-        type: z.literal('gcs'),
-        bucket: z.string().min(1),
-        folder: z
-          .string()
-          .min(1)
-          .optional()
-          .describe('The folder to use, defaults to the root of the bucket'),
-        service_account_key: z
-          .string()
-          .min(1)
-          .optional()
-          .describe('The path to GCS service account key file'),
-        user_secrets: z
-          .string()
-          .min(1)
-          .optional()
-          .describe('The path to GCS user secret file'),
-      })
-      .describe('A checkpoint syncer that uses Google Cloud Storage'),
-  ]),
+    .optional()
+    .describe(
+      'Name of the chain to validate messages on. Required unless `origins` is set.',
+    ),
+  validator: AgentSignerSchema.optional().describe(
+    'The validator attestation signer. With `origins`, the default signer of every origin.',
+  ),
+  checkpointSyncer: CheckpointSyncerSchema.optional().describe(
+    'Where to write signed checkpoints. Required unless `origins` is set.',
+  ),
+  rotation: ValidatorKeyRotationSchema.optional(),
+  origins: z
+    .record(ValidatorOriginSchema)
+    .optional()
+    .describe(
+      'Origin chains to validate, keyed by chain name. Used instead of `originChainName` to validate several chains from one process.',
+    ),
   interval: ZUint.optional().describe(
     'How long to wait between checking for new checkpoints in seconds.',
   ),
+  audit: z
+    .object({
+      fromIndex: ZUint.optional().describe(
+        'First checkpoint index to verify, defaults to 0',
+      ),
+      toIndex: ZUint.optional().describe(
+        'Last checkpoint index to verify, defaults to the latest index in the checkpoint syncer',
+      ),
+      output: z
+        .string()
+        .min(1)
+        .optional()
+        .describe('Where to write the JSON report, defaults to stdout'),
+    })
+    .optional()
+    .describe(
+      'Settings for the `audit` subcommand, which verifies published checkpoints against the origin chain.',
+    ),
 });
 
 export type ValidatorConfig = z.infer<typeof ValidatorAgentConfigSchema>;
@@ -66,6 +66,35 @@ export enum ChainDisabledReason {
 // A type that also allows for literal values of the enum
 export type ExplorerFamilyValue = `${ExplorerFamily}`;
 
+export const RpcRateLimitSchema = z.object({
+  creditsPerSecond: z
+    .number()
+    .positive()
+    .optional()
+    .describe(
+      'Credits which may be spent per second. Requests beyond it wait rather than fail.',
+    ),
+  burst: z
+    .number()
+    .positive()
+    .optional()
+    .describe(
+      'Credits which may be spent at once. Defaults to creditsPerSecond.',
+    ),
+  dailyCredits: ZUint.optional().describe(
+    'Credits which may be spent per UTC day. Requests beyond it fail.',
+  ),
+  defaultCost: ZUint.optional().describe(
+    'Credits a request costs unless its method is in methodCosts. Defaults to 1.',
+  ),
+  methodCosts: z
+    .record(ZUint)
+    .optional()
+    .describe('Credits a request costs by RPC method, e.g. eth_getLogs.'),
+});
+
+export type RpcRateLimit = z.infer<typeof RpcRateLimitSchema>;
+
 export const RpcUrlSchema = z.object({
   http: z
     .string()
@@ -106,6 +135,9 @@ export const RpcUrlSchema = z.object({
     .describe(
       'Default retry settings to be used by a provider such as MultiProvider.',
     ),
+  rateLimit: RpcRateLimitSchema.optional().describe(
+    'Self-imposed request budget of the RPC, shared by all agent clients.',
+  ),
   public: z
     .boolean()
     .optional()
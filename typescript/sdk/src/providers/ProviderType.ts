@@ -130,6 +130,12 @@ type ProtocolTypesMapping = {
     contract: null;
     receipt: EthersV5TransactionReceipt;
   };
+  [ProtocolType.Aptos]: {
+    transaction: never;
+    provider: never;
+    contract: never;
+    receipt: never;
+  };
   [ProtocolType.Unknown]: {
     transaction: never;
     provider: never;
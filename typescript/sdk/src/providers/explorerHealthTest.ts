@@ -19,6 +19,8 @@ const PROTOCOL_TO_ADDRESS: Record<ProtocolType, Address> = {
   [ProtocolType.Aleo]:
     'aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc',
   [ProtocolType.Tron]: 'T9yD14Nj9j7xAB4dbGeiX9h8unkKHxuWwb',
+  [ProtocolType.Aptos]:
+    '0x0000000000000000000000000000000000000000000000000000000000000001',
   [ProtocolType.Unknown]: '0x0000000000000000000000000000000000000000',
 };
 
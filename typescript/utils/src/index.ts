@@ -207,6 +207,7 @@ export { isNullish, isNumeric } from './typeof.js';
 export {
   Address,
   AddressBytes32,
+  AgentOnlyProtocolType,
   Annotated,
   Announcement,
   CallData,
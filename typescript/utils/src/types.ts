@@ -10,12 +10,18 @@ export enum ProtocolType {
   Radix = 'radix',
   Aleo = 'aleo',
   Tron = 'tron',
+  Aptos = 'aptos',
   Unknown = 'unknown',
 }
 // A type that also allows for literal values of the enum
 export type ProtocolTypeValue = `${ProtocolType}`;
-// All protocol types except Unknown - for use in mappings that only support known protocols
-export type KnownProtocolType = Exclude<ProtocolType, ProtocolType.Unknown>;
+// Protocols run by the agents that the TypeScript SDK cannot interact with yet
+export type AgentOnlyProtocolType = ProtocolType.Aptos;
+// All protocol types except Unknown and agent-only ones - for use in mappings that only support known protocols
+export type KnownProtocolType = Exclude<
+  ProtocolType,
+  ProtocolType.Unknown | AgentOnlyProtocolType
+>;
 
 export function isEVMLike(protocol: ProtocolType): boolean {
   return protocol === ProtocolType.Ethereum || protocol === ProtocolType.Tron;
@@ -30,6 +36,7 @@ export const ProtocolSmallestUnit = {
   [ProtocolType.Radix]: 'attos',
   [ProtocolType.Aleo]: 'microcredits',
   [ProtocolType.Tron]: 'SUN',
+  [ProtocolType.Aptos]: 'octas',
   [ProtocolType.Unknown]: 'unknown',
 };
 